@@ -21,6 +21,7 @@ use snarkvm_dpc::{
     Account,
     AccountScheme,
     DPCScheme,
+    Network,
     Storage,
 };
 use snarkvm_parameters::{LedgerMerkleTreeParameters, Parameter};
@@ -31,8 +32,9 @@ use std::sync::Arc;
 
 pub type MerkleTreeLedger<S> = Ledger<Tx, CommitmentMerkleParameters, S>;
 
-pub fn setup_or_load_parameters<R: Rng, S: Storage>(
+pub fn setup_or_load_parameters<R: Rng, S: Storage + Sync>(
     verify_only: bool,
+    network: Network,
     rng: &mut R,
 ) -> (
     Arc<CommitmentMerkleParameters>,
@@ -45,20 +47,21 @@ pub fn setup_or_load_parameters<R: Rng, S: Storage>(
     let merkle_tree_hash_parameters = <CommitmentMerkleParameters as MerkleParameters>::H::from(crh_parameters);
     let ledger_merkle_tree_parameters = Arc::new(From::from(merkle_tree_hash_parameters));
 
-    let parameters = match <InstantiatedDPC as DPCScheme<MerkleTreeLedger<S>>>::NetworkParameters::load(verify_only) {
-        Ok(parameters) => parameters,
-        Err(err) => {
-            println!("error - {}, re-running parameter Setup", err);
-            <InstantiatedDPC as DPCScheme<MerkleTreeLedger<S>>>::setup(&ledger_merkle_tree_parameters, rng)
-                .expect("DPC setup failed")
-        }
-    };
+    let parameters =
+        match <InstantiatedDPC as DPCScheme<MerkleTreeLedger<S>>>::NetworkParameters::load(verify_only, network) {
+            Ok(parameters) => parameters,
+            Err(err) => {
+                println!("error - {}, re-running parameter Setup", err);
+                <InstantiatedDPC as DPCScheme<MerkleTreeLedger<S>>>::setup(&ledger_merkle_tree_parameters, network, rng)
+                    .expect("DPC setup failed")
+            }
+        };
 
     (ledger_merkle_tree_parameters, parameters)
 }
 
-pub fn load_verifying_parameters() -> PublicParameters<Components> {
-    PublicParameters::<Components>::load_vk_direct().unwrap()
+pub fn load_verifying_parameters(network: Network) -> PublicParameters<Components> {
+    PublicParameters::<Components>::load_vk_direct(network).unwrap()
 }
 
 pub fn generate_test_accounts<R: Rng, S: Storage>(
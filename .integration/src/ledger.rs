@@ -26,6 +26,7 @@ use snarkvm_utilities::{
 use parking_lot::RwLock;
 use std::{
     collections::HashSet,
+    convert::TryFrom,
     fs,
     marker::PhantomData,
     path::Path,
@@ -41,6 +42,7 @@ pub struct Ledger<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage>
     pub current_block_height: AtomicU32,
     pub ledger_parameters: Arc<P>,
     pub cm_merkle_tree: RwLock<MerkleTree<P>>,
+    pub sn_accumulator: RwLock<SerialNumberAccumulator<P>>,
     pub storage: S,
     pub _transaction: PhantomData<T>,
 }
@@ -78,14 +80,14 @@ impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> Ledger<T, P,
     /// Get the block hash given a block number.
     pub fn get_block_hash(&self, block_number: u32) -> Result<BlockHeaderHash, StorageError> {
         match self.storage.get(COL_BLOCK_LOCATOR, &block_number.to_le_bytes())? {
-            Some(block_header_hash) => Ok(BlockHeaderHash::new(block_header_hash)),
+            Some(block_header_hash) => Ok(BlockHeaderHash::try_from(&block_header_hash[..])?),
             None => Err(StorageError::MissingBlockHash(block_number)),
         }
     }
 
     /// Get the list of transaction ids given a block hash.
     pub fn get_block_transactions(&self, block_hash: &BlockHeaderHash) -> Result<Transactions<T>, StorageError> {
-        match self.storage.get(COL_BLOCK_TRANSACTIONS, &block_hash.0)? {
+        match self.storage.get(COL_BLOCK_TRANSACTIONS, block_hash.as_ref())? {
             Some(encoded_block_transactions) => Ok(Transactions::read(&encoded_block_transactions[..])?),
             None => Err(StorageError::MissingBlockTransactions(block_hash.to_string())),
         }
@@ -96,7 +98,7 @@ impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> Ledger<T, P,
         &self,
         parent_header: &BlockHeaderHash,
     ) -> Result<Vec<BlockHeaderHash>, StorageError> {
-        match self.storage.get(COL_CHILD_HASHES, &parent_header.0)? {
+        match self.storage.get(COL_CHILD_HASHES, parent_header.as_ref())? {
             Some(encoded_child_block_hashes) => Ok(bincode::deserialize(&encoded_child_block_hashes[..])?),
             None => Ok(vec![]),
         }
@@ -109,7 +111,7 @@ impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> Ledger<T, P,
 
     /// Get the block number given a block hash.
     pub fn get_block_number(&self, block_hash: &BlockHeaderHash) -> Result<u32, StorageError> {
-        match self.storage.get(COL_BLOCK_LOCATOR, &block_hash.0)? {
+        match self.storage.get(COL_BLOCK_LOCATOR, block_hash.as_ref())? {
             Some(block_num_bytes) => Ok(bytes_to_u32(&block_num_bytes)),
             None => Err(StorageError::MissingBlockNumber(block_hash.to_string())),
         }
@@ -126,7 +128,7 @@ impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> Ledger<T, P,
 
     /// Get a block header given the block hash.
     pub fn get_block_header(&self, block_hash: &BlockHeaderHash) -> Result<BlockHeader, StorageError> {
-        match self.storage.get(COL_BLOCK_HEADER, &block_hash.0)? {
+        match self.storage.get(COL_BLOCK_HEADER, block_hash.as_ref())? {
             Some(block_header_bytes) => Ok(BlockHeader::read(&block_header_bytes[..])?),
             None => Err(StorageError::MissingBlockHeader(block_hash.to_string())),
         }
@@ -242,6 +244,7 @@ impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> LedgerScheme
     type MerkleParameters = P;
     type MerklePath = MerklePath<Self::MerkleParameters>;
     type MerkleTreeDigest = MerkleTreeDigest<Self::MerkleParameters>;
+    type NonMembershipProof = SerialNumberNonMembershipProof<Self::MerkleParameters>;
     type SerialNumber = T::SerialNumber;
     type Transaction = T;
 
@@ -267,11 +270,13 @@ impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> LedgerScheme
 
         let leaves: &[[u8; 32]] = &[];
         let empty_cm_merkle_tree = MerkleTree::<Self::MerkleParameters>::new(parameters.clone(), leaves)?;
+        let empty_sn_accumulator = SerialNumberAccumulator::new(parameters.clone())?;
 
         let ledger_storage = Self {
             current_block_height: Default::default(),
             storage,
             cm_merkle_tree: RwLock::new(empty_cm_merkle_tree),
+            sn_accumulator: RwLock::new(empty_sn_accumulator),
             ledger_parameters: parameters,
             _transaction: PhantomData,
         };
@@ -336,6 +341,35 @@ impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> LedgerScheme
     ) -> bool {
         witness.verify(&digest, cm).unwrap()
     }
+
+    /// Returns the digest of the serial numbers in the ledger
+    /// as of the given block height, if the block exists.
+    fn sn_digest(&self, block_height: u32) -> Option<Self::MerkleTreeDigest> {
+        self.sn_accumulator.read().root(block_height)
+    }
+
+    /// Returns a proof that the given serial number did not exist
+    /// in the ledger as of the given block height.
+    fn prove_non_membership(
+        &self,
+        sn: &Self::SerialNumber,
+        block_height: u32,
+    ) -> anyhow::Result<Self::NonMembershipProof> {
+        let result = self.sn_accumulator.read().prove_non_membership(sn, block_height)?;
+
+        Ok(result)
+    }
+
+    /// Returns true if the given proof is a valid witness that the given
+    /// serial number does not exist for the given serial number digest.
+    fn verify_non_membership(
+        _parameters: &Arc<Self::MerkleParameters>,
+        digest: &Self::MerkleTreeDigest,
+        sn: &Self::SerialNumber,
+        witness: &Self::NonMembershipProof,
+    ) -> bool {
+        SerialNumberAccumulator::verify_non_membership(digest, sn, witness).unwrap()
+    }
 }
 
 impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> Ledger<T, P, S> {
@@ -442,7 +476,7 @@ impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> Ledger<T, P,
         for (index, transaction) in block.transactions.0.iter().enumerate() {
             let transaction_location = TransactionLocation {
                 index: index as u32,
-                block_hash: block.header.get_hash().0,
+                block_hash: block.header.get_hash().into(),
             };
             database_transaction.push(Op::Insert {
                 col: COL_TRANSACTION_LOCATION,
@@ -453,12 +487,12 @@ impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> Ledger<T, P,
 
         database_transaction.push(Op::Insert {
             col: COL_BLOCK_HEADER,
-            key: block_hash.0.to_vec(),
+            key: block_hash.as_ref().to_vec(),
             value: to_bytes![block.header]?.to_vec(),
         });
         database_transaction.push(Op::Insert {
             col: COL_BLOCK_TRANSACTIONS,
-            key: block.header.get_hash().0.to_vec(),
+            key: block.header.get_hash().as_ref().to_vec(),
             value: to_bytes![block.transactions]?.to_vec(),
         });
 
@@ -469,14 +503,14 @@ impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> Ledger<T, P,
 
             database_transaction.push(Op::Insert {
                 col: COL_CHILD_HASHES,
-                key: block.header.previous_block_hash.0.to_vec(),
+                key: block.header.previous_block_hash.as_ref().to_vec(),
                 value: bincode::serialize(&child_hashes)?,
             });
         }
 
         database_transaction.push(Op::Insert {
             col: COL_BLOCK_TRANSACTIONS,
-            key: block.header.get_hash().0.to_vec(),
+            key: block.header.get_hash().as_ref().to_vec(),
             value: to_bytes![block.transactions]?.to_vec(),
         });
 
@@ -559,8 +593,7 @@ impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> Ledger<T, P,
 
         let height = self.get_current_block_height();
 
-        let is_genesis =
-            block.header.previous_block_hash == BlockHeaderHash([0u8; 32]) && height == 0 && self.is_empty();
+        let is_genesis = block.header.previous_block_hash == BlockHeaderHash::zero() && height == 0 && self.is_empty();
 
         let mut new_best_block_number = 0;
         if !is_genesis {
@@ -577,19 +610,23 @@ impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> Ledger<T, P,
 
         database_transaction.push(Op::Insert {
             col: COL_BLOCK_LOCATOR,
-            key: block.header.get_hash().0.to_vec(),
+            key: block.header.get_hash().as_ref().to_vec(),
             value: new_best_block_number.to_le_bytes().to_vec(),
         });
         database_transaction.push(Op::Insert {
             col: COL_BLOCK_LOCATOR,
             key: new_best_block_number.to_le_bytes().to_vec(),
-            value: block.header.get_hash().0.to_vec(),
+            value: block.header.get_hash().as_ref().to_vec(),
         });
 
         // Rebuild the new commitment merkle tree
         self.rebuild_merkle_tree(transaction_cms)?;
         let new_digest = self.cm_merkle_tree.read().root();
 
+        // Add the serial numbers of the block to the serial number accumulator
+        let serial_numbers = block.transactions.0.iter().flat_map(|tx| tx.old_serial_numbers());
+        self.sn_accumulator.write().apply_block(serial_numbers)?;
+
         database_transaction.push(Op::Insert {
             col: COL_DIGEST,
             key: to_bytes![new_digest]?.to_vec(),
@@ -623,6 +660,141 @@ impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> Ledger<T, P,
         self.commit(block)
     }
 
+    /// Insert a block into the storage and commit it as part of the longest chain,
+    /// returning the new ledger digest.
+    pub fn apply_block(&self, block: &Block<T>) -> Result<MerkleTreeDigest<P>, StorageError> {
+        self.insert_and_commit(block)?;
+
+        Ok(self.cm_merkle_tree.read().root())
+    }
+
+    /// Remove the latest block from the canon chain, returning the ledger digest preceding it.
+    /// The block itself is kept in storage, and may be committed again.
+    pub fn rollback_block(&self) -> Result<MerkleTreeDigest<P>, StorageError> {
+        let current_block_height = self.get_current_block_height();
+        if current_block_height == 0 {
+            return Err(StorageError::InvalidBlockDecommit);
+        }
+
+        let block_hash = self.get_block_hash(current_block_height)?;
+        let block_transactions = self.get_block_transactions(&block_hash)?;
+        let current_digest = self.current_digest()?;
+
+        let mut sn_index = self.current_sn_index()?;
+        let mut cm_index = self.current_cm_index()?;
+        let mut memo_index = self.current_memo_index()?;
+
+        let mut database_transaction = DatabaseTransaction::new();
+
+        for transaction in block_transactions.0.iter() {
+            for sn in transaction.old_serial_numbers() {
+                database_transaction.push(Op::Delete {
+                    col: COL_SERIAL_NUMBER,
+                    key: to_bytes![sn]?.to_vec(),
+                });
+                sn_index -= 1;
+            }
+
+            for cm in transaction.new_commitments() {
+                database_transaction.push(Op::Delete {
+                    col: COL_COMMITMENT,
+                    key: to_bytes![cm]?.to_vec(),
+                });
+                cm_index -= 1;
+            }
+
+            database_transaction.push(Op::Delete {
+                col: COL_MEMO,
+                key: to_bytes![transaction.memorandum()]?.to_vec(),
+            });
+            memo_index -= 1;
+        }
+
+        // Update the database state for current indexes
+
+        database_transaction.push(Op::Insert {
+            col: COL_META,
+            key: KEY_CURR_SN_INDEX.as_bytes().to_vec(),
+            value: (sn_index as u32).to_le_bytes().to_vec(),
+        });
+        database_transaction.push(Op::Insert {
+            col: COL_META,
+            key: KEY_CURR_CM_INDEX.as_bytes().to_vec(),
+            value: (cm_index as u32).to_le_bytes().to_vec(),
+        });
+        database_transaction.push(Op::Insert {
+            col: COL_META,
+            key: KEY_CURR_MEMO_INDEX.as_bytes().to_vec(),
+            value: (memo_index as u32).to_le_bytes().to_vec(),
+        });
+
+        // Update the best block number
+
+        let new_best_block_number = current_block_height - 1;
+
+        database_transaction.push(Op::Insert {
+            col: COL_META,
+            key: KEY_BEST_BLOCK_NUMBER.as_bytes().to_vec(),
+            value: new_best_block_number.to_le_bytes().to_vec(),
+        });
+
+        // Remove the block location
+
+        database_transaction.push(Op::Delete {
+            col: COL_BLOCK_LOCATOR,
+            key: block_hash.as_ref().to_vec(),
+        });
+        database_transaction.push(Op::Delete {
+            col: COL_BLOCK_LOCATOR,
+            key: current_block_height.to_le_bytes().to_vec(),
+        });
+
+        // Rebuild the previous commitment merkle tree from the remaining commitments
+
+        let mut cm_and_indices = vec![];
+        for (commitment_key, index_value) in self.storage.get_col(COL_COMMITMENT)? {
+            let index = bytes_to_u32(&index_value) as usize;
+            if index < cm_index {
+                let commitment: T::Commitment = FromBytes::read(&commitment_key[..])?;
+                cm_and_indices.push((commitment, index));
+            }
+        }
+
+        cm_and_indices.sort_by_key(|&(_, i)| i);
+        let remaining_commitments = cm_and_indices.into_iter().map(|(cm, _)| cm);
+
+        let previous_tree = { self.cm_merkle_tree.read().rebuild(remaining_commitments, &[])? };
+        let previous_digest = previous_tree.root();
+        let previous_digest_bytes = to_bytes![previous_digest]?.to_vec();
+
+        // The digest is unchanged if the block has no commitments, in which case it remains valid.
+        if previous_digest_bytes == current_digest {
+            database_transaction.push(Op::Insert {
+                col: COL_DIGEST,
+                key: previous_digest_bytes.clone(),
+                value: new_best_block_number.to_le_bytes().to_vec(),
+            });
+        } else {
+            database_transaction.push(Op::Delete {
+                col: COL_DIGEST,
+                key: current_digest,
+            });
+        }
+        database_transaction.push(Op::Insert {
+            col: COL_META,
+            key: KEY_CURR_DIGEST.as_bytes().to_vec(),
+            value: previous_digest_bytes,
+        });
+
+        self.storage.batch(database_transaction)?;
+
+        *self.cm_merkle_tree.write() = previous_tree;
+        self.sn_accumulator.write().rollback_block()?;
+        self.current_block_height.fetch_sub(1, Ordering::SeqCst);
+
+        Ok(previous_digest)
+    }
+
     /// Returns true if the block exists in the canon chain.
     pub fn is_canon(&self, block_hash: &BlockHeaderHash) -> bool {
         self.block_hash_exists(block_hash) && self.get_block_number(block_hash).is_ok()
@@ -15,29 +15,55 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_algorithms::{
-    merkle_tree::MerklePath,
-    traits::{MerkleParameters, CRH, SNARK},
+    merkle_tree::{MerklePath, MerkleTree},
+    prf::PoseidonPRF,
+    snark::{gm17::GM17, groth16::Groth16},
+    traits::{CommitmentScheme, MerkleParameters, CRH, SNARK},
+};
+use snarkvm_curves::{
+    bls12_377::{Fq, Fr},
+    edwards_bls12::{EdwardsParameters, EdwardsProjective},
 };
-use snarkvm_curves::bls12_377::{Fq, Fr};
 use snarkvm_dpc::{
     prelude::*,
     testnet1::{
         execute_inner_proof_gadget,
         execute_outer_proof_gadget,
-        inner_circuit::InnerCircuit,
+        inner_circuit::{InnerCircuit, InnerCircuitVerifierInput},
         instantiated::*,
-        parameters::{NoopProgramSNARKParameters, SystemParameters},
-        program::NoopProgram,
+        outer_circuit::{OuterCircuit, OuterCircuitVerifierInput},
+        parameters::{NoopProgramSNARKParameters, PublicParameters, SystemParameters},
+        program::{
+            EnforceRecipientCircuit,
+            NoopProgram,
+            PrivateProgramInput,
+            ProgramLocalData,
+            TimeLockCircuit,
+            TimeLockProgram,
+        },
         record::{payload::Payload, record_encryption::RecordEncryption},
+        AleoAmount,
         BaseDPCComponents,
+        LocalData,
+        Record,
+        Transaction,
         TransactionKernel,
         DPC,
     },
 };
+use snarkvm_fields::ToConstraintField;
+use snarkvm_gadgets::algorithms::prf::PoseidonPRFGadget;
 use snarkvm_integration::{dpc::*, ledger::*, memdb::MemDb, storage::*};
-use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+use snarkvm_r1cs::{
+    ConstraintCounter,
+    ConstraintSynthesizer,
+    ConstraintSystem,
+    TestConstraintChecker,
+    TestConstraintSystem,
+};
 use snarkvm_utilities::{
     bytes::{FromBytes, ToBytes},
+    rand::RngRecording,
     to_bytes,
 };
 
@@ -46,7 +72,7 @@ use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 use std::{
     sync::Arc,
-    time::{SystemTime, UNIX_EPOCH},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 type L = Ledger<Tx, CommitmentMerkleParameters, MemDb>;
@@ -56,7 +82,7 @@ fn dpc_testnet1_integration_test() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
     // Generate or load parameters for the ledger, commitment schemes, and CRH
-    let (ledger_parameters, parameters) = setup_or_load_parameters::<_, MemDb>(false, &mut rng);
+    let (ledger_parameters, parameters) = setup_or_load_parameters::<_, MemDb>(false, Network::Mainnet, &mut rng);
 
     // Generate accounts
     let [genesis_account, recipient, _] = generate_test_accounts::<_, MemDb>(&parameters, &mut rng);
@@ -68,9 +94,9 @@ fn dpc_testnet1_integration_test() {
 
     let genesis_block = Block {
         header: BlockHeader {
-            previous_block_hash: BlockHeaderHash([0u8; 32]),
-            merkle_root_hash: MerkleRootHash([0u8; 32]),
-            pedersen_merkle_root_hash: PedersenMerkleRootHash([0u8; 32]),
+            previous_block_hash: BlockHeaderHash::zero(),
+            merkle_root_hash: MerkleRootHash::zero(),
+            pedersen_merkle_root_hash: PedersenMerkleRootHash::zero(),
             time: 0,
             difficulty_target: 0x07FF_FFFF_FFFF_FFFF_u64,
             nonce: 0,
@@ -99,12 +125,12 @@ fn dpc_testnet1_integration_test() {
             &[64u8 + (i as u8); 1],
         )
         .unwrap();
-        let old_record = DPC::generate_record(
+        let old_record = DPC::generate_record_with_amount(
             &parameters.system_parameters,
             old_sn_nonce,
             genesis_account.address.clone(),
             true, // The input record is dummy
-            0,
+            AleoAmount::ZERO,
             Payload::default(),
             noop_program_id.clone(),
             noop_program_id.clone(),
@@ -124,7 +150,7 @@ fn dpc_testnet1_integration_test() {
     let new_birth_program_ids = vec![noop_program_id.clone(); NUM_OUTPUT_RECORDS];
     let new_death_program_ids = vec![noop_program_id.clone(); NUM_OUTPUT_RECORDS];
 
-    let memo = [4u8; 32];
+    let memo = Memo::new([4u8; 32]);
 
     // Offline execution to generate a DPC transaction kernel
     let transaction_kernel = <InstantiatedDPC as DPCScheme<L>>::execute_offline(
@@ -152,8 +178,8 @@ fn dpc_testnet1_integration_test() {
     let mut old_death_program_proofs = vec![];
     for i in 0..NUM_INPUT_RECORDS {
         let private_input = noop_program
-            .execute(
-                &parameters.noop_program_snark_parameters.proving_key,
+            .execute_with_prepared_key(
+                parameters.noop_program_snark_parameters.prepared_proving_key().unwrap(),
                 &parameters.noop_program_snark_parameters.verification_key,
                 &local_data,
                 i as u8,
@@ -167,8 +193,8 @@ fn dpc_testnet1_integration_test() {
     let mut new_birth_program_proofs = vec![];
     for j in 0..NUM_OUTPUT_RECORDS {
         let private_input = noop_program
-            .execute(
-                &parameters.noop_program_snark_parameters.proving_key,
+            .execute_with_prepared_key(
+                parameters.noop_program_snark_parameters.prepared_proving_key().unwrap(),
                 &parameters.noop_program_snark_parameters.verification_key,
                 &local_data,
                 (NUM_INPUT_RECORDS + j) as u8,
@@ -179,7 +205,7 @@ fn dpc_testnet1_integration_test() {
         new_birth_program_proofs.push(private_input);
     }
 
-    let (new_records, transaction) = InstantiatedDPC::execute_online(
+    let (new_records, transaction, receipt) = InstantiatedDPC::execute_online_with_receipt(
         &parameters,
         transaction_kernel,
         old_death_program_proofs,
@@ -195,6 +221,36 @@ fn dpc_testnet1_integration_test() {
 
     assert_eq!(transaction, recovered_transaction);
 
+    // Check that the execution receipt describes the transaction
+    assert_eq!(receipt.ledger_digest, hex::encode(to_bytes![transaction.ledger_digest].unwrap()));
+    assert_eq!(receipt.num_real_input_records, 0);
+    assert_eq!(receipt.num_dummy_input_records, NUM_INPUT_RECORDS);
+    assert_eq!(receipt.num_real_output_records, NUM_OUTPUT_RECORDS);
+    assert_eq!(receipt.num_dummy_output_records, 0);
+    assert_eq!(receipt.transaction_size, transaction_bytes.len());
+    assert_eq!(
+        receipt.transaction_proof_size,
+        to_bytes![transaction.transaction_proof].unwrap().len()
+    );
+    assert_eq!(receipt.signatures_size, to_bytes![transaction.signatures].unwrap().len());
+    assert_eq!(
+        receipt.encrypted_records_size,
+        to_bytes![transaction.encrypted_records].unwrap().len()
+    );
+    assert!(receipt.inner_proof_size > 0);
+
+    let phase_durations = [
+        receipt.ledger_witness_duration,
+        receipt.signature_duration,
+        receipt.encryption_witness_duration,
+        receipt.inner_proof_duration,
+        receipt.inner_proof_verification_duration,
+        receipt.outer_proof_duration,
+    ];
+    assert!(receipt.inner_proof_duration > Duration::from_secs(0));
+    assert!(receipt.outer_proof_duration > Duration::from_secs(0));
+    assert!(phase_durations.iter().sum::<Duration>() <= receipt.total_duration);
+
     {
         // Check that new_records can be decrypted from the transaction
 
@@ -226,7 +282,12 @@ fn dpc_testnet1_integration_test() {
     let mut transactions = Transactions::new();
     transactions.push(transaction);
 
-    let transaction_ids = transactions.to_transaction_ids().unwrap();
+    let transaction_ids: Vec<[u8; 32]> = transactions
+        .to_transaction_ids()
+        .unwrap()
+        .into_iter()
+        .map(Into::into)
+        .collect();
 
     let mut merkle_root_bytes = [0u8; 32];
     merkle_root_bytes[..].copy_from_slice(&merkle_root(&transaction_ids));
@@ -238,32 +299,217 @@ fn dpc_testnet1_integration_test() {
 
     let header = BlockHeader {
         previous_block_hash: previous_block.header.get_hash(),
-        merkle_root_hash: MerkleRootHash(merkle_root_bytes),
+        merkle_root_hash: MerkleRootHash::new(merkle_root_bytes),
         time,
         difficulty_target: previous_block.header.difficulty_target,
         nonce: 0,
-        pedersen_merkle_root_hash: PedersenMerkleRootHash([0u8; 32]),
+        pedersen_merkle_root_hash: PedersenMerkleRootHash::zero(),
         proof: ProofOfSuccinctWork([0u8; 972]),
     };
 
     assert!(InstantiatedDPC::verify_transactions(&parameters, &transactions.0, &ledger).unwrap());
 
+    // A verifier configured for another network rejects the transactions.
+    let testnet1_parameters = load_verifying_parameters(Network::Testnet1);
+    assert!(!InstantiatedDPC::verify_transactions(&testnet1_parameters, &transactions.0, &ledger).unwrap());
+
+    // A block that spends the same serial numbers twice is rejected.
+    let duplicated_transactions = vec![transactions[0].clone(), transactions[0].clone()];
+    assert!(!InstantiatedDPC::verify_transactions(&parameters, &duplicated_transactions, &ledger).unwrap());
+
     let block = Block { header, transactions };
 
     ledger.insert_and_commit(&block).unwrap();
     assert_eq!(ledger.len(), 2);
 }
 
+#[test]
+#[ignore] // Generates the 4-input, 4-output SNARK parameters, which is too slow and memory-hungry for CI.
+fn dpc_testnet1_large_components_integration_test() {
+    type LargeL = Ledger<LargeTx, CommitmentMerkleParameters, MemDb>;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // Generate parameters for the ledger, commitment schemes, CRH, and the SNARKs.
+    let ledger_parameters = Arc::new(CommitmentMerkleParameters::setup(&mut rng));
+    let parameters =
+        <LargeInstantiatedDPC as DPCScheme<LargeL>>::setup(&ledger_parameters, Network::Mainnet, &mut rng).unwrap();
+    let system_parameters = &parameters.system_parameters;
+
+    // Generate accounts
+    let genesis_account =
+        <LargeInstantiatedDPC as DPCScheme<LargeL>>::create_account(system_parameters, &mut rng).unwrap();
+    let recipient = <LargeInstantiatedDPC as DPCScheme<LargeL>>::create_account(system_parameters, &mut rng).unwrap();
+
+    let genesis_block = Block {
+        header: BlockHeader {
+            previous_block_hash: BlockHeaderHash::zero(),
+            merkle_root_hash: MerkleRootHash::zero(),
+            pedersen_merkle_root_hash: PedersenMerkleRootHash::zero(),
+            time: 0,
+            difficulty_target: 0x07FF_FFFF_FFFF_FFFF_u64,
+            nonce: 0,
+            proof: ProofOfSuccinctWork([0u8; 972]),
+        },
+        transactions: Transactions::new(),
+    };
+
+    let ledger = initialize_test_blockchain::<LargeTx, CommitmentMerkleParameters, MemDb>(
+        ledger_parameters,
+        genesis_block,
+    );
+
+    let noop_program_id = to_bytes![
+        ProgramVerificationKeyCRH::hash(
+            &system_parameters.program_verification_key_crh,
+            &to_bytes![parameters.noop_program_snark_parameters().verification_key].unwrap()
+        )
+        .unwrap()
+    ]
+    .unwrap();
+
+    // Generate dummy input records having as address the genesis address.
+    let old_account_private_keys = vec![genesis_account.private_key.clone(); LARGE_NUM_INPUT_RECORDS];
+    let mut old_records = vec![];
+    for i in 0..LARGE_NUM_INPUT_RECORDS {
+        let old_sn_nonce =
+            LargeSerialNumberNonce::hash(&system_parameters.serial_number_nonce, &[64u8 + (i as u8); 1]).unwrap();
+        let old_record = LargeInstantiatedDPC::generate_record_with_amount(
+            system_parameters,
+            old_sn_nonce,
+            genesis_account.address.clone(),
+            true, // The input record is dummy
+            AleoAmount::ZERO,
+            Payload::default(),
+            noop_program_id.clone(),
+            noop_program_id.clone(),
+            &mut rng,
+        )
+        .unwrap();
+        old_records.push(old_record);
+    }
+
+    // Construct new records.
+
+    let new_record_owners = vec![recipient.address.clone(); LARGE_NUM_OUTPUT_RECORDS];
+    let new_is_dummy_flags = vec![false; LARGE_NUM_OUTPUT_RECORDS];
+    let new_values = vec![10; LARGE_NUM_OUTPUT_RECORDS];
+    let new_payloads = vec![Payload::default(); LARGE_NUM_OUTPUT_RECORDS];
+    let new_birth_program_ids = vec![noop_program_id.clone(); LARGE_NUM_OUTPUT_RECORDS];
+    let new_death_program_ids = vec![noop_program_id.clone(); LARGE_NUM_OUTPUT_RECORDS];
+
+    let memo = Memo::new([4u8; 32]);
+
+    // Offline execution to generate a DPC transaction kernel
+    let transaction_kernel = <LargeInstantiatedDPC as DPCScheme<LargeL>>::execute_offline(
+        system_parameters.clone(),
+        old_records,
+        old_account_private_keys,
+        new_record_owners,
+        &new_is_dummy_flags,
+        &new_values,
+        new_payloads,
+        new_birth_program_ids,
+        new_death_program_ids,
+        memo,
+        0,
+        &mut rng,
+    )
+    .unwrap();
+
+    // Check that the transaction kernel is serialized and deserialized correctly
+    let transaction_kernel_bytes = to_bytes![transaction_kernel].unwrap();
+    let recovered_transaction_kernel =
+        TransactionKernel::read_with_parameters(&transaction_kernel_bytes[..], system_parameters.clone()).unwrap();
+    assert_eq!(transaction_kernel, recovered_transaction_kernel);
+
+    let local_data = transaction_kernel.into_local_data();
+    assert_eq!(
+        local_data.local_data_merkle_tree.leaves().len(),
+        LARGE_NUM_INPUT_RECORDS + LARGE_NUM_OUTPUT_RECORDS
+    );
+
+    // Generate the program proofs
+
+    let noop_program =
+        NoopProgram::<_, <ComponentsLarge as BaseDPCComponents>::NoopProgramSNARK>::new(noop_program_id);
+
+    let mut old_death_program_proofs = vec![];
+    for i in 0..LARGE_NUM_INPUT_RECORDS {
+        let private_input = noop_program
+            .execute_with_prepared_key(
+                parameters.noop_program_snark_parameters.prepared_proving_key().unwrap(),
+                &parameters.noop_program_snark_parameters.verification_key,
+                &local_data,
+                i as u8,
+                &mut rng,
+            )
+            .unwrap();
+
+        old_death_program_proofs.push(private_input);
+    }
+
+    let mut new_birth_program_proofs = vec![];
+    for j in 0..LARGE_NUM_OUTPUT_RECORDS {
+        let private_input = noop_program
+            .execute_with_prepared_key(
+                parameters.noop_program_snark_parameters.prepared_proving_key().unwrap(),
+                &parameters.noop_program_snark_parameters.verification_key,
+                &local_data,
+                (LARGE_NUM_INPUT_RECORDS + j) as u8,
+                &mut rng,
+            )
+            .unwrap();
+
+        new_birth_program_proofs.push(private_input);
+    }
+
+    let (new_records, transaction) = LargeInstantiatedDPC::execute_online(
+        &parameters,
+        transaction_kernel,
+        old_death_program_proofs,
+        new_birth_program_proofs,
+        &ledger,
+        &mut rng,
+    )
+    .unwrap();
+
+    assert_eq!(transaction.old_serial_numbers().len(), LARGE_NUM_INPUT_RECORDS);
+    assert_eq!(transaction.new_commitments().len(), LARGE_NUM_OUTPUT_RECORDS);
+
+    // Check that the transaction is serialized and deserialized correctly
+    let transaction_bytes = to_bytes![transaction].unwrap();
+    let recovered_transaction = LargeTx::read(&transaction_bytes[..]).unwrap();
+
+    assert_eq!(transaction, recovered_transaction);
+
+    // Check that new_records can be decrypted from the transaction
+    let account_view_key = AccountViewKey::from_private_key(
+        &system_parameters.account_signature,
+        &system_parameters.account_commitment,
+        &recipient.private_key,
+    )
+    .unwrap();
+
+    for (encrypted_record, new_record) in transaction.encrypted_records().iter().zip(new_records) {
+        let decrypted_record =
+            RecordEncryption::decrypt_record(system_parameters, &account_view_key, encrypted_record).unwrap();
+
+        assert_eq!(decrypted_record, new_record);
+    }
+
+    assert!(LargeInstantiatedDPC::verify(&parameters, &transaction, &ledger).unwrap());
+}
+
 /// Generates and returns noop program parameters and its corresponding program id.
-fn generate_test_noop_program_parameters<R: Rng>(
-    system_parameters: &SystemParameters<Components>,
+fn generate_test_noop_program_parameters<C: BaseDPCComponents, R: Rng>(
+    system_parameters: &SystemParameters<C>,
     rng: &mut R,
-) -> (NoopProgramSNARKParameters<Components>, Vec<u8>) {
-    let noop_program_snark_pp =
-        InstantiatedDPC::generate_noop_program_snark_parameters(&system_parameters, rng).unwrap();
+) -> (NoopProgramSNARKParameters<C>, Vec<u8>) {
+    let noop_program_snark_pp = DPC::<C>::generate_noop_program_snark_parameters(system_parameters, rng).unwrap();
 
     let noop_program_id = to_bytes![
-        ProgramVerificationKeyCRH::hash(
+        C::ProgramVerificationKeyCRH::hash(
             &system_parameters.program_verification_key_crh,
             &to_bytes![noop_program_snark_pp.verification_key].unwrap()
         )
@@ -294,12 +540,12 @@ fn test_transaction_kernel_serialization() {
     .unwrap();
 
     let sn_nonce = SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &[0u8; 1]).unwrap();
-    let old_record = DPC::generate_record(
+    let old_record = DPC::generate_record_with_amount(
         &system_parameters,
         sn_nonce,
         test_account.address.clone(),
         true,
-        0,
+        AleoAmount::ZERO,
         Payload::default(),
         noop_program_id.clone(),
         noop_program_id.clone(),
@@ -319,7 +565,7 @@ fn test_transaction_kernel_serialization() {
     let new_payloads = vec![Payload::default(); NUM_OUTPUT_RECORDS];
     let new_birth_program_ids = vec![noop_program_id.clone(); NUM_OUTPUT_RECORDS];
     let new_death_program_ids = vec![noop_program_id; NUM_OUTPUT_RECORDS];
-    let memo = [0u8; 32];
+    let memo = Memo::empty();
 
     // Generate transaction kernel
     let transaction_kernel = <InstantiatedDPC as DPCScheme<L>>::execute_offline(
@@ -347,8 +593,27 @@ fn test_transaction_kernel_serialization() {
     assert_eq!(transaction_kernel, recovered_transaction_kernel);
 }
 
-#[test]
-fn test_execute_base_dpc_constraints() {
+/// Runs a transaction through the inner and outer circuits of components that share the
+/// testnet1 primitives, and returns the number of constraints in the inner circuit.
+fn execute_base_dpc_constraints<C>() -> usize
+where
+    C: BaseDPCComponents<
+        InnerField = Fr,
+        OuterField = Fq,
+        AccountCommitment = AccountCommitment,
+        AccountEncryption = AccountEncryption,
+        AccountSignature = AccountSignature,
+        EncryptedRecordCRH = EncryptedRecordCRH,
+        ProgramVerificationKeyCommitment = ProgramVerificationKeyCommitment,
+        RecordCommitment = RecordCommitment,
+        MerkleParameters = CommitmentMerkleParameters,
+    >,
+    <C::LocalDataCRH as CRH>::Parameters: ToConstraintField<Fr>,
+    <C::LocalDataCRH as CRH>::Output: ToConstraintField<Fr>,
+    <C::SerialNumberNonceCRH as CRH>::Parameters: ToConstraintField<Fr>,
+    PublicParameters<C>: Sync,
+    Transaction<C>: Sync,
+{
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
     // Specify network_id
@@ -357,7 +622,7 @@ fn test_execute_base_dpc_constraints() {
     // Generate parameters for the ledger, commitment schemes, CRH, and the
     // "always-accept" program.
     let ledger_parameters = Arc::new(CommitmentMerkleParameters::setup(&mut rng));
-    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+    let system_parameters = DPC::<C>::generate_system_parameters(&mut rng).unwrap();
 
     let (noop_program_snark_pp, noop_program_id) = generate_test_noop_program_parameters(&system_parameters, &mut rng);
     let (alternate_noop_program_snark_pp, alternate_noop_program_id) =
@@ -378,27 +643,30 @@ fn test_execute_base_dpc_constraints() {
 
     let genesis_block = Block {
         header: BlockHeader {
-            previous_block_hash: BlockHeaderHash([0u8; 32]),
-            merkle_root_hash: MerkleRootHash([0u8; 32]),
+            previous_block_hash: BlockHeaderHash::zero(),
+            merkle_root_hash: MerkleRootHash::zero(),
             time: 0,
             difficulty_target: 0x07FF_FFFF_FFFF_FFFF_u64,
             nonce: 0,
-            pedersen_merkle_root_hash: PedersenMerkleRootHash([0u8; 32]),
+            pedersen_merkle_root_hash: PedersenMerkleRootHash::zero(),
             proof: ProofOfSuccinctWork([0u8; 972]),
         },
         transactions: Transactions::new(),
     };
 
     // Use genesis record, serial number, and memo to initialize the ledger.
-    let ledger = initialize_test_blockchain::<Tx, CommitmentMerkleParameters, MemDb>(ledger_parameters, genesis_block);
+    let ledger = initialize_test_blockchain::<Transaction<C>, CommitmentMerkleParameters, MemDb>(
+        ledger_parameters,
+        genesis_block,
+    );
 
-    let sn_nonce = SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &[0u8; 1]).unwrap();
-    let old_record = DPC::generate_record(
+    let sn_nonce = C::SerialNumberNonceCRH::hash(&system_parameters.serial_number_nonce, &[0u8; 1]).unwrap();
+    let old_record = DPC::<C>::generate_record_with_amount(
         &system_parameters,
         sn_nonce,
         dummy_account.address,
         true,
-        0,
+        AleoAmount::ZERO,
         Payload::default(),
         alternate_noop_program_id.clone(),
         alternate_noop_program_id.clone(),
@@ -407,8 +675,8 @@ fn test_execute_base_dpc_constraints() {
     .unwrap();
 
     // Set the input records for our transaction to be the initial dummy records.
-    let old_records = vec![old_record; NUM_INPUT_RECORDS];
-    let old_account_private_keys = vec![dummy_account.private_key; NUM_INPUT_RECORDS];
+    let old_records = vec![old_record; C::NUM_INPUT_RECORDS];
+    let old_account_private_keys = vec![dummy_account.private_key; C::NUM_INPUT_RECORDS];
 
     // Construct new records.
 
@@ -424,40 +692,40 @@ fn test_execute_base_dpc_constraints() {
 
     // Set the new record's program to be the "always-accept" program.
 
-    let new_record_owners = vec![new_account.address; NUM_OUTPUT_RECORDS];
-    let new_is_dummy_flags = vec![false; NUM_OUTPUT_RECORDS];
-    let new_values = vec![10; NUM_OUTPUT_RECORDS];
-    let new_payloads = vec![Payload::default(); NUM_OUTPUT_RECORDS];
-    let new_birth_program_ids = vec![noop_program_id.clone(); NUM_OUTPUT_RECORDS];
-    let new_death_program_ids = vec![noop_program_id.clone(); NUM_OUTPUT_RECORDS];
-    let memo = [0u8; 32];
+    let new_record_owners = vec![new_account.address; C::NUM_OUTPUT_RECORDS];
+    let new_is_dummy_flags = vec![false; C::NUM_OUTPUT_RECORDS];
+    let new_values = vec![10; C::NUM_OUTPUT_RECORDS];
+    let new_payloads = vec![Payload::default(); C::NUM_OUTPUT_RECORDS];
+    let new_birth_program_ids = vec![noop_program_id.clone(); C::NUM_OUTPUT_RECORDS];
+    let new_death_program_ids = vec![noop_program_id.clone(); C::NUM_OUTPUT_RECORDS];
+    let memo = Memo::empty();
 
-    let transaction_kernel = <InstantiatedDPC as DPCScheme<L>>::execute_offline(
-        system_parameters.clone(),
-        old_records,
-        old_account_private_keys,
-        new_record_owners,
-        &new_is_dummy_flags,
-        &new_values,
-        new_payloads,
-        new_birth_program_ids,
-        new_death_program_ids,
-        memo,
-        network_id,
-        &mut rng,
-    )
-    .unwrap();
+    let transaction_kernel =
+        <DPC<C> as DPCScheme<Ledger<Transaction<C>, CommitmentMerkleParameters, MemDb>>>::execute_offline(
+            system_parameters.clone(),
+            old_records,
+            old_account_private_keys,
+            new_record_owners,
+            &new_is_dummy_flags,
+            &new_values,
+            new_payloads,
+            new_birth_program_ids,
+            new_death_program_ids,
+            memo,
+            network_id,
+            &mut rng,
+        )
+        .unwrap();
 
     let local_data = transaction_kernel.into_local_data();
 
     // Generate the program proofs
 
-    let noop_program = NoopProgram::<_, <Components as BaseDPCComponents>::NoopProgramSNARK>::new(noop_program_id);
-    let alternate_noop_program =
-        NoopProgram::<_, <Components as BaseDPCComponents>::NoopProgramSNARK>::new(alternate_noop_program_id);
+    let noop_program = NoopProgram::<_, C::NoopProgramSNARK>::new(noop_program_id);
+    let alternate_noop_program = NoopProgram::<_, C::NoopProgramSNARK>::new(alternate_noop_program_id);
 
     let mut old_proof_and_vk = vec![];
-    for i in 0..NUM_INPUT_RECORDS {
+    for i in 0..C::NUM_INPUT_RECORDS {
         let private_input = alternate_noop_program
             .execute(
                 &alternate_noop_program_snark_pp.proving_key,
@@ -472,13 +740,13 @@ fn test_execute_base_dpc_constraints() {
     }
 
     let mut new_proof_and_vk = vec![];
-    for j in 0..NUM_OUTPUT_RECORDS {
+    for j in 0..C::NUM_OUTPUT_RECORDS {
         let private_input = noop_program
             .execute(
                 &noop_program_snark_pp.proving_key,
                 &noop_program_snark_pp.verification_key,
                 &local_data,
-                (NUM_INPUT_RECORDS + j) as u8,
+                (C::NUM_INPUT_RECORDS + j) as u8,
                 &mut rng,
             )
             .unwrap();
@@ -517,7 +785,7 @@ fn test_execute_base_dpc_constraints() {
     let ledger_digest = ledger.digest().expect("could not get digest");
 
     // Generate the ledger membership witnesses
-    let mut old_witnesses = Vec::with_capacity(NUM_INPUT_RECORDS);
+    let mut old_witnesses = Vec::with_capacity(C::NUM_INPUT_RECORDS);
 
     // Compute the ledger membership witness and serial number from the old records.
     for record in old_records.iter() {
@@ -530,7 +798,7 @@ fn test_execute_base_dpc_constraints() {
     }
 
     // Prepare record encryption components used in the inner SNARK
-    let mut new_records_encryption_gadget_components = Vec::with_capacity(NUM_OUTPUT_RECORDS);
+    let mut new_records_encryption_gadget_components = Vec::with_capacity(C::NUM_OUTPUT_RECORDS);
     for (record, ciphertext_randomness) in new_records.iter().zip_eq(&new_records_encryption_randomness) {
         let record_encryption_gadget_components =
             RecordEncryption::prepare_encryption_gadget_components(&system_parameters, &record, ciphertext_randomness)
@@ -562,7 +830,7 @@ fn test_execute_base_dpc_constraints() {
         &program_randomness,
         &local_data_root,
         &local_data_commitment_randomizers,
-        &memo,
+        memo.as_bytes(),
         value_balance,
         network_id,
     )
@@ -587,52 +855,77 @@ fn test_execute_base_dpc_constraints() {
 
     assert!(core_cs.is_satisfied());
 
+    // The named constraint system is large, so release it before generating the proofs.
+    let num_constraints = core_cs.num_constraints();
+    drop(core_cs);
+
     // Generate inner snark parameters and proof for verification in the outer snark
-    let inner_snark_parameters = <Components as BaseDPCComponents>::InnerSNARK::setup(
-        &InnerCircuit::blank(&system_parameters, ledger.parameters()),
-        &mut rng,
-    )
-    .unwrap();
+    let inner_snark_parameters =
+        C::InnerSNARK::setup(&InnerCircuit::blank(&system_parameters, ledger.parameters()), &mut rng).unwrap();
 
-    let inner_snark_vk: <<Components as BaseDPCComponents>::InnerSNARK as SNARK>::VerifyingKey =
-        inner_snark_parameters.1.clone().into();
+    let inner_snark_vk: <C::InnerSNARK as SNARK>::VerifyingKey = inner_snark_parameters.1.clone().into();
 
-    let inner_snark_id = InnerCircuitIDCRH::hash(
+    let inner_snark_id = C::InnerCircuitIDCRH::hash(
         &system_parameters.inner_circuit_id_crh,
         &to_bytes![inner_snark_vk].unwrap(),
     )
     .unwrap();
 
-    let inner_snark_proof = <Components as BaseDPCComponents>::InnerSNARK::prove(
-        &inner_snark_parameters.0,
-        &InnerCircuit::new(
-            system_parameters.clone(),
-            ledger.parameters().clone(),
-            ledger_digest,
-            old_records,
-            old_witnesses,
-            old_account_private_keys,
-            old_serial_numbers.clone(),
-            new_records,
-            new_sn_nonce_randomness,
-            new_commitments.clone(),
-            new_records_encryption_randomness,
-            new_records_encryption_gadget_components,
-            new_encrypted_record_hashes.clone(),
-            program_commitment,
-            program_randomness,
-            local_data_root,
-            local_data_commitment_randomizers,
-            memo,
-            value_balance,
-            network_id,
-        ),
-        &mut rng,
-    )
-    .unwrap();
+    let inner_circuit = InnerCircuit::new(
+        system_parameters.clone(),
+        ledger.parameters().clone(),
+        ledger_digest,
+        old_records,
+        old_witnesses,
+        old_account_private_keys,
+        old_serial_numbers.clone(),
+        new_records,
+        new_sn_nonce_randomness,
+        new_commitments.clone(),
+        new_records_encryption_randomness,
+        new_records_encryption_gadget_components,
+        new_encrypted_record_hashes.clone(),
+        program_commitment,
+        program_randomness,
+        local_data_root,
+        local_data_commitment_randomizers,
+        *memo.as_bytes(),
+        value_balance,
+        network_id,
+    );
+
+    // The blank inner circuit used in the setup must have the same shape as the witnessed one.
+    let mut inner_witnessed_cs = ConstraintCounter::default();
+    inner_circuit.generate_constraints(&mut inner_witnessed_cs).unwrap();
+    let mut inner_blank_cs = ConstraintCounter::default();
+    InnerCircuit::blank(&system_parameters, ledger.parameters())
+        .generate_constraints(&mut inner_blank_cs)
+        .unwrap();
+    assert_eq!(inner_witnessed_cs.num_constraints, inner_blank_cs.num_constraints);
+    assert_eq!(inner_witnessed_cs.num_public_variables, inner_blank_cs.num_public_variables);
+    assert_eq!(inner_witnessed_cs.num_private_variables, inner_blank_cs.num_private_variables);
+
+    let inner_snark_proof = C::InnerSNARK::prove(&inner_snark_parameters.0, &inner_circuit, &mut rng).unwrap();
+
+    // The parameters generated from the blank inner circuit verify the witnessed proof.
+    let inner_snark_input = InnerCircuitVerifierInput {
+        system_parameters: system_parameters.clone(),
+        ledger_parameters: ledger.parameters().clone(),
+        ledger_digest,
+        old_serial_numbers: old_serial_numbers.clone(),
+        new_commitments: new_commitments.clone(),
+        new_encrypted_record_hashes: new_encrypted_record_hashes.clone(),
+        memo: *memo.as_bytes(),
+        program_commitment,
+        local_data_root,
+        value_balance,
+        network_id,
+    };
+    assert!(C::InnerSNARK::verify(&inner_snark_parameters.1, &inner_snark_input, &inner_snark_proof).unwrap());
 
-    // Check that the proof check constraint system was satisfied.
-    let mut pf_check_cs = TestConstraintSystem::<Fq>::new();
+    // Check that the proof check constraint system was satisfied. The constraints are checked as they
+    // are enforced, as the outer circuit of the large components does not fit in memory otherwise.
+    let mut pf_check_cs = TestConstraintChecker::<Fq>::new();
 
     execute_outer_proof_gadget::<_, _>(
         &mut pf_check_cs.ns(|| "Check program proofs"),
@@ -642,11 +935,11 @@ fn test_execute_base_dpc_constraints() {
         &old_serial_numbers,
         &new_commitments,
         &new_encrypted_record_hashes,
-        &memorandum,
+        memorandum.as_bytes(),
         value_balance,
         network_id,
         &inner_snark_vk,
-        &inner_snark_proof,
+        Some(&inner_snark_proof),
         &old_proof_and_vk,
         &new_proof_and_vk,
         &program_commitment,
@@ -665,7 +958,6 @@ fn test_execute_base_dpc_constraints() {
     }
     if pf_check_cs.is_satisfied() {
         println!("\n\n\n\nAll Proof check constraints:");
-        // pf_check_cs.print_named_objects();
         println!("num constraints: {:?}", pf_check_cs.num_constraints());
     }
     println!("=========================================================");
@@ -673,4 +965,838 @@ fn test_execute_base_dpc_constraints() {
     println!("=========================================================");
 
     assert!(pf_check_cs.is_satisfied());
+    drop(pf_check_cs);
+
+    // The blank outer circuit used in the setup must have the same shape as the witnessed one.
+    let mut outer_witnessed_cs = ConstraintCounter::default();
+    OuterCircuit::new(
+        system_parameters.clone(),
+        ledger.parameters().clone(),
+        ledger_digest,
+        old_serial_numbers,
+        new_commitments,
+        new_encrypted_record_hashes,
+        *memorandum.as_bytes(),
+        value_balance,
+        network_id,
+        inner_snark_vk.clone(),
+        inner_snark_proof,
+        old_proof_and_vk,
+        new_proof_and_vk,
+        program_commitment,
+        program_randomness,
+        local_data_root,
+        inner_snark_id,
+    )
+    .generate_constraints(&mut outer_witnessed_cs)
+    .unwrap();
+    let mut outer_blank_cs = ConstraintCounter::default();
+    OuterCircuit::blank(
+        system_parameters,
+        ledger.parameters().clone(),
+        inner_snark_vk,
+        to_bytes![noop_program_snark_pp.verification_key].unwrap(),
+    )
+    .generate_constraints(&mut outer_blank_cs)
+    .unwrap();
+    assert_eq!(outer_witnessed_cs.num_constraints, outer_blank_cs.num_constraints);
+    assert_eq!(outer_witnessed_cs.num_public_variables, outer_blank_cs.num_public_variables);
+    assert_eq!(outer_witnessed_cs.num_private_variables, outer_blank_cs.num_private_variables);
+
+    num_constraints
+}
+
+#[test]
+fn test_execute_base_dpc_constraints() {
+    execute_base_dpc_constraints::<Components>();
+}
+
+#[test]
+fn test_execute_base_dpc_constraints_with_large_components() {
+    execute_base_dpc_constraints::<ComponentsLarge>();
+}
+
+/// The testnet1 components, with the Poseidon PRF in place of Blake2s for serial numbers.
+struct PoseidonPRFComponents;
+
+impl DPCComponents for PoseidonPRFComponents {
+    type AccountCommitment = AccountCommitment;
+    type AccountCommitmentGadget = AccountCommitmentGadget;
+    type AccountEncryption = AccountEncryption;
+    type AccountEncryptionGadget = AccountEncryptionGadget;
+    type AccountSignature = AccountSignature;
+    type AccountSignatureGadget = AccountSignatureGadget;
+    type EncryptedRecordCRH = EncryptedRecordCRH;
+    type EncryptedRecordCRHGadget = EncryptedRecordCRHGadget;
+    type InnerCircuitIDCRH = InnerCircuitIDCRH;
+    type InnerCircuitIDCRHGadget = InnerCircuitIDCRHGadget;
+    type InnerField = InnerField;
+    type LocalDataCRH = LocalDataCRH;
+    type LocalDataCRHGadget = LocalDataCRHGadget;
+    type LocalDataCommitment = LocalDataCommitment;
+    type LocalDataCommitmentGadget = LocalDataCommitmentGadget;
+    type OuterField = OuterField;
+    type PRF = PoseidonPRF<InnerField, 2>;
+    type PRFGadget = PoseidonPRFGadget<InnerField, 2>;
+    type ProgramVerificationKeyCRH = ProgramVerificationKeyCRH;
+    type ProgramVerificationKeyCRHGadget = ProgramVerificationKeyCRHGadget;
+    type ProgramVerificationKeyCommitment = ProgramVerificationKeyCommitment;
+    type ProgramVerificationKeyCommitmentGadget = ProgramVerificationKeyCommitmentGadget;
+    type RecordCommitment = RecordCommitment;
+    type RecordCommitmentGadget = RecordCommitmentGadget;
+    type SerialNumberNonceCRH = SerialNumberNonce;
+    type SerialNumberNonceCRHGadget = SerialNumberNonceGadget;
+
+    const NUM_INPUT_RECORDS: usize = NUM_INPUT_RECORDS;
+    const NUM_OUTPUT_RECORDS: usize = NUM_OUTPUT_RECORDS;
+}
+
+impl BaseDPCComponents for PoseidonPRFComponents {
+    type EncryptionGroup = EdwardsProjective;
+    type EncryptionModelParameters = EdwardsParameters;
+    type InnerSNARK = Groth16<InnerPairing, InnerCircuit<Self>, InnerCircuitVerifierInput<Self>>;
+    type InnerSNARKGadget = InnerSNARKGadget;
+    type MerkleHashGadget = MerkleTreeCRHGadget;
+    type MerkleParameters = CommitmentMerkleParameters;
+    type NoopProgramSNARK = NoopProgramSNARK<Self>;
+    type OuterSNARK = Groth16<OuterPairing, OuterCircuit<Self>, OuterCircuitVerifierInput<Self>>;
+    type ProgramSNARKGadget = ProgramSNARKGadget;
+}
+
+/// Returns the number of constraints in the inner circuit of the given components.
+fn inner_circuit_num_constraints<C: BaseDPCComponents<MerkleParameters = CommitmentMerkleParameters>>() -> usize {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let ledger_parameters = Arc::new(CommitmentMerkleParameters::setup(&mut rng));
+    let system_parameters = DPC::<C>::generate_system_parameters(&mut rng).unwrap();
+
+    let mut counter = ConstraintCounter::default();
+    InnerCircuit::blank(&system_parameters, &ledger_parameters)
+        .generate_constraints(&mut counter)
+        .unwrap();
+    counter.num_constraints
+}
+
+#[test]
+fn test_execute_base_dpc_constraints_with_poseidon_prf() {
+    let num_constraints = execute_base_dpc_constraints::<PoseidonPRFComponents>();
+    assert_eq!(
+        num_constraints,
+        inner_circuit_num_constraints::<PoseidonPRFComponents>()
+    );
+}
+
+#[test]
+fn test_poseidon_prf_reduces_inner_circuit_constraints() {
+    let blake2s_constraints = inner_circuit_num_constraints::<Components>();
+    let poseidon_constraints = inner_circuit_num_constraints::<PoseidonPRFComponents>();
+    assert_eq!(blake2s_constraints, 418189);
+    assert_eq!(poseidon_constraints, 377755);
+
+    // Each input record evaluates the PRF once to derive its serial number.
+    let saved_per_evaluation = (blake2s_constraints - poseidon_constraints) / NUM_INPUT_RECORDS;
+    assert_eq!(saved_per_evaluation, 20217);
+}
+
+#[test]
+fn test_enforce_recipient_program() {
+    type EnforceRecipientSNARK = GM17<InnerPairing, EnforceRecipientCircuit<Components>, ProgramLocalData<Components>>;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // Generate or load parameters for the ledger, commitment schemes, and CRH
+    let (ledger_parameters, parameters) = setup_or_load_parameters::<_, MemDb>(false, Network::Mainnet, &mut rng);
+    let system_parameters = &parameters.system_parameters;
+
+    // Generate accounts
+    let [genesis_account, recipient, other] = generate_test_accounts::<_, MemDb>(&parameters, &mut rng);
+
+    let genesis_block = Block {
+        header: BlockHeader {
+            previous_block_hash: BlockHeaderHash::zero(),
+            merkle_root_hash: MerkleRootHash::zero(),
+            pedersen_merkle_root_hash: PedersenMerkleRootHash::zero(),
+            time: 0,
+            difficulty_target: 0x07FF_FFFF_FFFF_FFFF_u64,
+            nonce: 0,
+            proof: ProofOfSuccinctWork([0u8; 972]),
+        },
+        transactions: Transactions::new(),
+    };
+
+    let ledger = initialize_test_blockchain::<Tx, CommitmentMerkleParameters, MemDb>(ledger_parameters, genesis_block);
+
+    // Set up the program that only accepts records owned by the recipient.
+    let (enforce_recipient_pk, enforce_recipient_pvk) = EnforceRecipientSNARK::setup(
+        &EnforceRecipientCircuit::blank(system_parameters, &recipient.address),
+        &mut rng,
+    )
+    .unwrap();
+    let enforce_recipient_vk: <EnforceRecipientSNARK as SNARK>::VerifyingKey = enforce_recipient_pvk.clone().into();
+    let enforce_recipient_program_id = to_bytes![
+        ProgramVerificationKeyCRH::hash(
+            &system_parameters.program_verification_key_crh,
+            &to_bytes![enforce_recipient_vk].unwrap()
+        )
+        .unwrap()
+    ]
+    .unwrap();
+
+    let noop_program_id = to_bytes![
+        ProgramVerificationKeyCRH::hash(
+            &system_parameters.program_verification_key_crh,
+            &to_bytes![parameters.noop_program_snark_parameters().verification_key].unwrap()
+        )
+        .unwrap()
+    ]
+    .unwrap();
+
+    // Generate dummy input records having as address the genesis address.
+    let old_account_private_keys = vec![genesis_account.private_key.clone(); NUM_INPUT_RECORDS];
+    let mut old_records = vec![];
+    for i in 0..NUM_INPUT_RECORDS {
+        let old_sn_nonce =
+            SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &[64u8 + (i as u8); 1]).unwrap();
+        let old_record = DPC::generate_record_with_amount(
+            system_parameters,
+            old_sn_nonce,
+            genesis_account.address.clone(),
+            true, // The input record is dummy
+            AleoAmount::ZERO,
+            Payload::default(),
+            noop_program_id.clone(),
+            noop_program_id.clone(),
+            &mut rng,
+        )
+        .unwrap();
+        old_records.push(old_record);
+    }
+
+    // Construct new records that are born with the enforce recipient program.
+    let transaction_kernel = <InstantiatedDPC as DPCScheme<L>>::execute_offline(
+        system_parameters.clone(),
+        old_records,
+        old_account_private_keys,
+        vec![recipient.address.clone(); NUM_OUTPUT_RECORDS],
+        &[false; NUM_OUTPUT_RECORDS],
+        &[10; NUM_OUTPUT_RECORDS],
+        vec![Payload::default(); NUM_OUTPUT_RECORDS],
+        vec![enforce_recipient_program_id; NUM_OUTPUT_RECORDS],
+        vec![noop_program_id.clone(); NUM_OUTPUT_RECORDS],
+        Memo::new([5u8; 32]),
+        0,
+        &mut rng,
+    )
+    .unwrap();
+
+    let local_data = transaction_kernel.into_local_data();
+
+    // Generate the program proofs
+    let noop_program = NoopProgram::<_, <Components as BaseDPCComponents>::NoopProgramSNARK>::new(noop_program_id);
+
+    let mut old_death_program_proofs = vec![];
+    for i in 0..NUM_INPUT_RECORDS {
+        let private_input = noop_program
+            .execute(
+                &parameters.noop_program_snark_parameters.proving_key,
+                &parameters.noop_program_snark_parameters.verification_key,
+                &local_data,
+                i as u8,
+                &mut rng,
+            )
+            .unwrap();
+
+        old_death_program_proofs.push(private_input);
+    }
+
+    // The input records are owned by the genesis account, so the circuit is unsatisfied for them.
+    for i in 0..NUM_INPUT_RECORDS {
+        let position = i as u8;
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        EnforceRecipientCircuit::new(&local_data, position, &recipient.address)
+            .generate_constraints(&mut cs.ns(|| "Enforce recipient on a foreign record"))
+            .unwrap();
+        assert!(!cs.is_satisfied());
+
+        // Claiming a record owned by the recipient fails to open the local data commitment at the position.
+        let mut forged_circuit = EnforceRecipientCircuit::new(&local_data, position, &recipient.address);
+        forged_circuit.record = local_data.new_records[0].clone();
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        forged_circuit
+            .generate_constraints(&mut cs.ns(|| "Enforce recipient on a forged record"))
+            .unwrap();
+        assert!(!cs.is_satisfied());
+    }
+
+    let mut new_birth_program_proofs = vec![];
+    for j in 0..NUM_OUTPUT_RECORDS {
+        let position = (NUM_INPUT_RECORDS + j) as u8;
+
+        // The circuit is unsatisfied for any address other than the recipient.
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        EnforceRecipientCircuit::new(&local_data, position, &other.address)
+            .generate_constraints(&mut cs.ns(|| "Enforce other recipient"))
+            .unwrap();
+        assert!(!cs.is_satisfied());
+
+        let circuit = EnforceRecipientCircuit::new(&local_data, position, &recipient.address);
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        circuit.generate_constraints(&mut cs.ns(|| "Enforce recipient")).unwrap();
+        assert!(cs.is_satisfied());
+
+        let proof = EnforceRecipientSNARK::prove(&enforce_recipient_pk, &circuit, &mut rng).unwrap();
+
+        let program_local_data = ProgramLocalData {
+            local_data_commitment_parameters: system_parameters.local_data_commitment.parameters().clone(),
+            local_data_root: local_data.local_data_merkle_tree.root(),
+            position,
+        };
+        assert!(EnforceRecipientSNARK::verify(&enforce_recipient_pvk, &program_local_data, &proof).unwrap());
+
+        new_birth_program_proofs.push(PrivateProgramInput {
+            verification_key: to_bytes![enforce_recipient_vk].unwrap(),
+            proof: to_bytes![proof].unwrap(),
+        });
+    }
+
+    let (_new_records, transaction) = InstantiatedDPC::execute_online(
+        &parameters,
+        transaction_kernel,
+        old_death_program_proofs,
+        new_birth_program_proofs,
+        &ledger,
+        &mut rng,
+    )
+    .unwrap();
+
+    assert!(InstantiatedDPC::verify(&parameters, &transaction, &ledger).unwrap());
+}
+
+/// Commits a block with the given transactions on top of the latest block of the ledger.
+fn commit_test_block(ledger: &L, transactions: Transactions<Tx>) {
+    let previous_block = ledger.get_latest_block().unwrap();
+
+    let merkle_root_hash = match transactions.is_empty() {
+        true => MerkleRootHash::zero(),
+        false => transactions.to_merkle_root().unwrap(),
+    };
+
+    let header = BlockHeader {
+        previous_block_hash: previous_block.header.get_hash(),
+        merkle_root_hash,
+        time: previous_block.header.time + 1,
+        difficulty_target: previous_block.header.difficulty_target,
+        nonce: 0,
+        pedersen_merkle_root_hash: PedersenMerkleRootHash::zero(),
+        proof: ProofOfSuccinctWork([0u8; 972]),
+    };
+
+    ledger.insert_and_commit(&Block { header, transactions }).unwrap();
+}
+
+#[test]
+fn test_time_lock_program() {
+    type TimeLockSNARK = GM17<InnerPairing, TimeLockCircuit<Components>, ProgramLocalData<Components>>;
+    type Program = TimeLockProgram<Components, TimeLockSNARK>;
+
+    const LOCK_HEIGHT: u32 = 2;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // Generate or load parameters for the ledger, commitment schemes, and CRH
+    let (ledger_parameters, parameters) = setup_or_load_parameters::<_, MemDb>(false, Network::Mainnet, &mut rng);
+    let system_parameters = &parameters.system_parameters;
+
+    // Generate accounts
+    let [genesis_account, recipient, _] = generate_test_accounts::<_, MemDb>(&parameters, &mut rng);
+
+    let genesis_block = Block {
+        header: BlockHeader {
+            previous_block_hash: BlockHeaderHash::zero(),
+            merkle_root_hash: MerkleRootHash::zero(),
+            pedersen_merkle_root_hash: PedersenMerkleRootHash::zero(),
+            time: 0,
+            difficulty_target: 0x07FF_FFFF_FFFF_FFFF_u64,
+            nonce: 0,
+            proof: ProofOfSuccinctWork([0u8; 972]),
+        },
+        transactions: Transactions::new(),
+    };
+
+    let ledger = initialize_test_blockchain::<Tx, CommitmentMerkleParameters, MemDb>(ledger_parameters, genesis_block);
+
+    // Set up the time lock program.
+    let (time_lock_program, time_lock_pk, time_lock_vk) = Program::setup(system_parameters, &mut rng).unwrap();
+    let time_lock_program_id = time_lock_program.into_compact_repr();
+    assert_eq!(
+        time_lock_program_id,
+        generate_test_program_id(system_parameters, &to_bytes![time_lock_vk].unwrap())
+    );
+
+    let noop_program_id = generate_test_program_id(
+        system_parameters,
+        &to_bytes![parameters.noop_program_snark_parameters().verification_key].unwrap(),
+    );
+    let noop_program =
+        NoopProgram::<_, <Components as BaseDPCComponents>::NoopProgramSNARK>::new(noop_program_id.clone());
+
+    // Returns the noop program proofs for the given positions.
+    let execute_noop_program =
+        |local_data: &LocalData<Components>, positions: std::ops::Range<usize>, rng: &mut XorShiftRng| {
+            positions
+                .map(|position| {
+                    noop_program
+                        .execute(
+                            &parameters.noop_program_snark_parameters.proving_key,
+                            &parameters.noop_program_snark_parameters.verification_key,
+                            local_data,
+                            position as u8,
+                            rng,
+                        )
+                        .unwrap()
+                })
+                .collect::<Vec<_>>()
+        };
+
+    // The record builder of the program sets the lock height and the death program of a record.
+    let sn_nonce = SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &[0u8; 1]).unwrap();
+    let record = time_lock_program
+        .record_builder(LOCK_HEIGHT)
+        .owner(recipient.address.clone())
+        .amount(AleoAmount(10))
+        .birth_program_id(noop_program_id.clone())
+        .serial_number_nonce(sn_nonce)
+        .calculate_commitment(&system_parameters.record_commitment, &mut rng)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(Program::lock_height(record.payload()), LOCK_HEIGHT);
+    assert_eq!(record.death_program_id(), &time_lock_program_id[..]);
+
+    // Create records for the recipient that are locked until `LOCK_HEIGHT`, by spending dummy records.
+    let old_records = generate_test_input_records(
+        system_parameters,
+        &genesis_account.address,
+        true,
+        &noop_program_id,
+        &mut rng,
+    );
+    let transaction_kernel = <InstantiatedDPC as DPCScheme<L>>::execute_offline(
+        system_parameters.clone(),
+        old_records,
+        vec![genesis_account.private_key.clone(); NUM_INPUT_RECORDS],
+        vec![recipient.address.clone(); NUM_OUTPUT_RECORDS],
+        &[false; NUM_OUTPUT_RECORDS],
+        &[10; NUM_OUTPUT_RECORDS],
+        vec![Program::lock_payload(LOCK_HEIGHT); NUM_OUTPUT_RECORDS],
+        vec![noop_program_id.clone(); NUM_OUTPUT_RECORDS],
+        vec![time_lock_program_id.clone(); NUM_OUTPUT_RECORDS],
+        Program::memorandum(ledger.get_current_block_height(), &mut rng),
+        0,
+        &mut rng,
+    )
+    .unwrap();
+
+    let local_data = transaction_kernel.into_local_data();
+    let old_death_program_proofs = execute_noop_program(&local_data, 0..NUM_INPUT_RECORDS, &mut rng);
+    let new_birth_program_proofs = execute_noop_program(
+        &local_data,
+        NUM_INPUT_RECORDS..NUM_INPUT_RECORDS + NUM_OUTPUT_RECORDS,
+        &mut rng,
+    );
+
+    let (locked_records, transaction) = InstantiatedDPC::execute_online(
+        &parameters,
+        transaction_kernel,
+        old_death_program_proofs,
+        new_birth_program_proofs,
+        &ledger,
+        &mut rng,
+    )
+    .unwrap();
+    assert!(InstantiatedDPC::verify(&parameters, &transaction, &ledger).unwrap());
+
+    let mut transactions = Transactions::new();
+    transactions.push(transaction);
+    commit_test_block(&ledger, transactions);
+    assert_eq!(ledger.get_current_block_height(), 1);
+
+    // Returns the local data of a transaction spending the locked records into dummy records.
+    let spend_locked_records = |memorandum: Memo, rng: &mut XorShiftRng| {
+        <InstantiatedDPC as DPCScheme<L>>::execute_offline(
+            system_parameters.clone(),
+            locked_records.clone(),
+            vec![recipient.private_key.clone(); NUM_INPUT_RECORDS],
+            vec![recipient.address.clone(); NUM_OUTPUT_RECORDS],
+            &[true; NUM_OUTPUT_RECORDS],
+            &[0; NUM_OUTPUT_RECORDS],
+            vec![Payload::default(); NUM_OUTPUT_RECORDS],
+            vec![noop_program_id.clone(); NUM_OUTPUT_RECORDS],
+            vec![noop_program_id.clone(); NUM_OUTPUT_RECORDS],
+            memorandum,
+            0,
+            rng,
+        )
+        .unwrap()
+    };
+
+    // Before the lock height, the program circuit is unsatisfied and its proofs do not verify.
+    let memorandum = Program::memorandum(ledger.get_current_block_height(), &mut rng);
+    let local_data = spend_locked_records(memorandum, &mut rng).into_local_data();
+    for position in 0..NUM_INPUT_RECORDS as u8 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        TimeLockCircuit::new(&local_data, position)
+            .generate_constraints(&mut cs)
+            .unwrap();
+        assert!(!cs.is_satisfied());
+        assert!(
+            cs.which_is_unsatisfied()
+                .unwrap()
+                .starts_with("Enforce that the current height has reached the lock height")
+        );
+
+        let proof =
+            TimeLockSNARK::prove(&time_lock_pk, &TimeLockCircuit::new(&local_data, position), &mut rng).unwrap();
+        let program_local_data = ProgramLocalData {
+            local_data_commitment_parameters: system_parameters.local_data_commitment.parameters().clone(),
+            local_data_root: local_data.local_data_merkle_tree.root(),
+            position,
+        };
+        let time_lock_pvk = time_lock_vk.clone().into();
+        assert!(!TimeLockSNARK::verify(&time_lock_pvk, &program_local_data, &proof).unwrap());
+
+        assert!(matches!(
+            time_lock_program.execute(&time_lock_pk, &time_lock_vk, &local_data, position, &mut rng),
+            Err(DPCError::InvalidProgramProof(p)) if p == position
+        ));
+    }
+
+    // Claiming the lock height early satisfies the program, but the memorandum is rejected against the ledger.
+    let memorandum = Program::memorandum(LOCK_HEIGHT, &mut rng);
+    assert!(!Program::is_valid_memorandum(
+        &memorandum,
+        ledger.get_current_block_height()
+    ));
+
+    // Once the ledger reaches the lock height, the records can be spent.
+    commit_test_block(&ledger, Transactions::new());
+    assert_eq!(ledger.get_current_block_height(), LOCK_HEIGHT);
+    assert!(Program::is_valid_memorandum(
+        &memorandum,
+        ledger.get_current_block_height()
+    ));
+
+    let transaction_kernel = spend_locked_records(memorandum, &mut rng);
+    let local_data = transaction_kernel.into_local_data();
+
+    // The circuit has the same shape with and without a witness.
+    let mut witnessed_cs = TestConstraintSystem::<Fr>::new();
+    TimeLockCircuit::new(&local_data, 1)
+        .generate_constraints(&mut witnessed_cs)
+        .unwrap();
+    assert!(witnessed_cs.is_satisfied());
+    let mut blank_cs = ConstraintCounter::default();
+    TimeLockCircuit::blank(system_parameters)
+        .generate_constraints(&mut blank_cs)
+        .unwrap();
+    assert_eq!(witnessed_cs.num_constraints(), blank_cs.num_constraints);
+
+    let old_death_program_proofs = (0..NUM_INPUT_RECORDS)
+        .map(|position| {
+            time_lock_program
+                .execute(&time_lock_pk, &time_lock_vk, &local_data, position as u8, &mut rng)
+                .unwrap()
+        })
+        .collect();
+    let new_birth_program_proofs = execute_noop_program(
+        &local_data,
+        NUM_INPUT_RECORDS..NUM_INPUT_RECORDS + NUM_OUTPUT_RECORDS,
+        &mut rng,
+    );
+
+    let (_new_records, transaction) = InstantiatedDPC::execute_online(
+        &parameters,
+        transaction_kernel,
+        old_death_program_proofs,
+        new_birth_program_proofs,
+        &ledger,
+        &mut rng,
+    )
+    .unwrap();
+    assert!(InstantiatedDPC::verify(&parameters, &transaction, &ledger).unwrap());
+}
+
+/// Returns the program id for the given program verification key bytes.
+fn generate_test_program_id(system_parameters: &SystemParameters<Components>, verification_key: &[u8]) -> Vec<u8> {
+    to_bytes![
+        ProgramVerificationKeyCRH::hash(&system_parameters.program_verification_key_crh, verification_key).unwrap()
+    ]
+    .unwrap()
+}
+
+/// Generates input records owned by the given address, with the given birth and death program id.
+fn generate_test_input_records<R: Rng>(
+    system_parameters: &SystemParameters<Components>,
+    owner: &AccountAddress<Components>,
+    is_dummy: bool,
+    program_id: &[u8],
+    rng: &mut R,
+) -> Vec<Record<Components>> {
+    (0..NUM_INPUT_RECORDS)
+        .map(|i| {
+            let sn_nonce = SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &[i as u8; 1]).unwrap();
+            DPC::generate_record_with_amount(
+                system_parameters,
+                sn_nonce,
+                owner.clone(),
+                is_dummy,
+                if is_dummy { AleoAmount::ZERO } else { AleoAmount(10) },
+                Payload::default(),
+                program_id.to_vec(),
+                program_id.to_vec(),
+                rng,
+            )
+            .unwrap()
+        })
+        .collect()
+}
+
+/// Runs `execute_offline` on the given input records, spending them into two dummy output records.
+fn execute_offline_with_input_records<R: Rng>(
+    system_parameters: &SystemParameters<Components>,
+    old_records: Vec<Record<Components>>,
+    old_account_private_keys: Vec<AccountPrivateKey<Components>>,
+    owner: &AccountAddress<Components>,
+    program_id: &[u8],
+    rng: &mut R,
+) -> anyhow::Result<TransactionKernel<Components>> {
+    <InstantiatedDPC as DPCScheme<L>>::execute_offline(
+        system_parameters.clone(),
+        old_records,
+        old_account_private_keys,
+        vec![owner.clone(); NUM_OUTPUT_RECORDS],
+        &[true; NUM_OUTPUT_RECORDS],
+        &[0; NUM_OUTPUT_RECORDS],
+        vec![Payload::default(); NUM_OUTPUT_RECORDS],
+        vec![program_id.to_vec(); NUM_OUTPUT_RECORDS],
+        vec![program_id.to_vec(); NUM_OUTPUT_RECORDS],
+        Memo::empty(),
+        0,
+        rng,
+    )
+}
+
+#[test]
+fn test_execute_offline_rejects_mismatched_record_owner() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+    let program_id = generate_test_program_id(&system_parameters, &[0u8; 32]);
+
+    let owner = <InstantiatedDPC as DPCScheme<L>>::create_account(&system_parameters, &mut rng).unwrap();
+    let other = <InstantiatedDPC as DPCScheme<L>>::create_account(&system_parameters, &mut rng).unwrap();
+
+    let old_records = generate_test_input_records(&system_parameters, &owner.address, true, &program_id, &mut rng);
+
+    // The owner's private keys are accepted.
+    let old_account_private_keys = vec![owner.private_key.clone(); NUM_INPUT_RECORDS];
+    execute_offline_with_input_records(
+        &system_parameters,
+        old_records.clone(),
+        old_account_private_keys,
+        &owner.address,
+        &program_id,
+        &mut rng,
+    )
+    .unwrap();
+
+    // A private key for a different account is rejected for the record it is paired with.
+    let mut old_account_private_keys = vec![owner.private_key.clone(); NUM_INPUT_RECORDS];
+    old_account_private_keys[1] = other.private_key.clone();
+    let error = execute_offline_with_input_records(
+        &system_parameters,
+        old_records,
+        old_account_private_keys,
+        &owner.address,
+        &program_id,
+        &mut rng,
+    )
+    .unwrap_err();
+
+    match error.downcast_ref::<DPCError>() {
+        Some(DPCError::InvalidRecordOwner(1, record_owner, derived_address)) => {
+            assert_eq!(record_owner, &owner.address.to_string());
+            assert_eq!(derived_address, &other.address.to_string());
+        }
+        _ => panic!("unexpected error: {}", error),
+    }
+}
+
+#[test]
+fn test_execute_offline_rejects_invalid_record_commitment() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+    let program_id = generate_test_program_id(&system_parameters, &[0u8; 32]);
+
+    let owner = <InstantiatedDPC as DPCScheme<L>>::create_account(&system_parameters, &mut rng).unwrap();
+
+    let mut old_records = generate_test_input_records(&system_parameters, &owner.address, false, &program_id, &mut rng);
+
+    // Tamper with the value of the first record, which follows the 32-byte owner and the dummy flag.
+    let mut record_bytes = to_bytes![old_records[0]].unwrap();
+    record_bytes[33] ^= 1;
+    old_records[0] = Record::read(&record_bytes[..]).unwrap();
+
+    let error = execute_offline_with_input_records(
+        &system_parameters,
+        old_records,
+        vec![owner.private_key.clone(); NUM_INPUT_RECORDS],
+        &owner.address,
+        &program_id,
+        &mut rng,
+    )
+    .unwrap_err();
+
+    match error.downcast_ref::<DPCError>() {
+        Some(DPCError::InvalidRecordCommitment(0)) => {}
+        _ => panic!("unexpected error: {}", error),
+    }
+}
+
+/// Spends two dummy records of a new account offline and then fails, as if the online execution
+/// had failed, with an error that contains the transaction kernel.
+fn execute_offline_and_fail<R: Rng>(
+    system_parameters: &SystemParameters<Components>,
+    program_id: &[u8],
+    rng: &mut R,
+) -> anyhow::Result<()> {
+    let owner = <InstantiatedDPC as DPCScheme<L>>::create_account(system_parameters, rng)?;
+    let old_records = generate_test_input_records(system_parameters, &owner.address, true, program_id, rng);
+
+    let transaction_kernel = execute_offline_with_input_records(
+        system_parameters,
+        old_records,
+        vec![owner.private_key.clone(); NUM_INPUT_RECORDS],
+        &owner.address,
+        program_id,
+        rng,
+    )?;
+
+    Err(anyhow::anyhow!(
+        "injected failure after the offline execution of {}",
+        hex::encode(to_bytes![transaction_kernel]?)
+    ))
+}
+
+#[test]
+fn test_execute_recorded_replays_failure() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+    let program_id = generate_test_program_id(&system_parameters, &[0u8; 32]);
+
+    let error = InstantiatedDPC::execute_recorded(&mut rand::thread_rng(), 1 << 20, |rng| {
+        execute_offline_and_fail(&system_parameters, &program_id, rng)
+    })
+    .unwrap_err();
+
+    // Replaying the recorded randomness reproduces the same failure, down to the transaction kernel.
+    let mut replay_rng = error.randomness.replay().unwrap();
+    let replayed_error = execute_offline_and_fail(&system_parameters, &program_id, &mut replay_rng).unwrap_err();
+    assert_eq!(replayed_error.to_string(), error.error.to_string());
+    assert_eq!(replay_rng.remaining(), 0);
+}
+
+#[test]
+fn test_execute_recorded_is_bounded() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+    let program_id = generate_test_program_id(&system_parameters, &[0u8; 32]);
+
+    let max_recorded_bytes = 64;
+    let error = InstantiatedDPC::execute_recorded(&mut rng, max_recorded_bytes, |rng| {
+        execute_offline_and_fail(&system_parameters, &program_id, rng)
+    })
+    .unwrap_err();
+
+    match error.randomness {
+        RngRecording::Stream { bytes, truncated } => {
+            assert!(truncated);
+            assert!(bytes.len() <= max_recorded_bytes);
+        }
+        RngRecording::Seed(_) => panic!("expected a recorded stream"),
+    }
+}
+
+#[test]
+fn test_check_ledger_witnesses() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+    let program_id = generate_test_program_id(&system_parameters, &[0u8; 32]);
+
+    let owner = <InstantiatedDPC as DPCScheme<L>>::create_account(&system_parameters, &mut rng).unwrap();
+
+    let old_records = generate_test_input_records(&system_parameters, &owner.address, false, &program_id, &mut rng);
+    let other_records = generate_test_input_records(&system_parameters, &owner.address, false, &program_id, &mut rng);
+    let commitments: Vec<_> = old_records
+        .iter()
+        .chain(&other_records)
+        .map(|record| record.commitment())
+        .collect();
+
+    let ledger_parameters = Arc::new(CommitmentMerkleParameters::setup(&mut rng));
+    let tree = MerkleTree::new(ledger_parameters, &commitments).unwrap();
+    let witnesses: Vec<_> = commitments
+        .iter()
+        .enumerate()
+        .map(|(i, commitment)| tree.generate_proof(i, commitment).unwrap())
+        .collect();
+
+    InstantiatedDPC::check_ledger_witnesses(&tree.root(), &old_records, &witnesses[..NUM_INPUT_RECORDS]).unwrap();
+
+    // The witness is valid, but for another record on the ledger.
+    let mismatched_witnesses = vec![witnesses[NUM_INPUT_RECORDS].clone(), witnesses[1].clone()];
+    match InstantiatedDPC::check_ledger_witnesses(&tree.root(), &old_records, &mismatched_witnesses) {
+        Err(DPCError::InvalidLedgerWitness(0)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+
+    // Dummy records are not checked against the ledger.
+    let dummy_records = generate_test_input_records(&system_parameters, &owner.address, true, &program_id, &mut rng);
+    InstantiatedDPC::check_ledger_witnesses(&tree.root(), &dummy_records, &mismatched_witnesses).unwrap();
+}
+
+#[test]
+fn test_check_program_ids() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+
+    let program_input = |verification_key: &[u8]| PrivateProgramInput {
+        verification_key: verification_key.to_vec(),
+        proof: vec![],
+    };
+    let program = program_input(&[0u8; 32]);
+    let other_program = program_input(&[1u8; 32]);
+    let program_id = generate_test_program_id(&system_parameters, &program.verification_key);
+
+    let owner = <InstantiatedDPC as DPCScheme<L>>::create_account(&system_parameters, &mut rng).unwrap();
+    let records = generate_test_input_records(&system_parameters, &owner.address, true, &program_id, &mut rng);
+
+    let programs = vec![program.clone(), program.clone()];
+    InstantiatedDPC::check_program_ids(&system_parameters, &records, &records, &programs, &programs).unwrap();
+
+    let mismatched_programs = vec![program.clone(), other_program];
+    match InstantiatedDPC::check_program_ids(&system_parameters, &records, &records, &mismatched_programs, &programs) {
+        Err(DPCError::MismatchedDeathProgramId(1, ..)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    match InstantiatedDPC::check_program_ids(&system_parameters, &records, &records, &programs, &mismatched_programs) {
+        Err(DPCError::MismatchedBirthProgramId(1, ..)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
 }
@@ -0,0 +1,405 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_algorithms::{
+    crh::{PedersenCompressedCRH, PedersenSize},
+    define_merkle_tree_parameters,
+    merkle_tree::{MerkleTreeDigest, SortedLeaf},
+    traits::{LoadableMerkleParameters, MerkleParameters},
+};
+use snarkvm_curves::edwards_bls12::EdwardsProjective;
+use snarkvm_dpc::{prelude::*, testnet1::instantiated::CommitmentMerkleParameters};
+use snarkvm_integration::{ledger::*, memdb::MemDb, storage::*};
+use snarkvm_utilities::bytes::{FromBytes, ToBytes};
+
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use std::{
+    io::{Read, Result as IoResult, Write},
+    sync::Arc,
+};
+
+type L = Ledger<RandomTx, CommitmentMerkleParameters, MemDb>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TwoToOneSize;
+impl PedersenSize for TwoToOneSize {
+    const NUM_WINDOWS: usize = 128;
+    const WINDOW_SIZE: usize = 4;
+}
+
+/// A transaction with random serial numbers, commitments, and memo, and no proofs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct RandomTx {
+    serial_numbers: Vec<[u8; 32]>,
+    commitments: Vec<[u8; 32]>,
+    memo: [u8; 32],
+}
+
+impl RandomTx {
+    fn new<R: Rng>(rng: &mut R) -> Self {
+        let num_serial_numbers = rng.gen_range(0..3);
+        // The ledger rejects blocks in which two transactions have the same list of commitments, including an empty one.
+        let num_commitments = rng.gen_range(1..3);
+
+        Self {
+            serial_numbers: (0..num_serial_numbers).map(|_| rng.gen()).collect(),
+            commitments: (0..num_commitments).map(|_| rng.gen()).collect(),
+            memo: rng.gen(),
+        }
+    }
+}
+
+impl TransactionScheme for RandomTx {
+    type Commitment = [u8; 32];
+    type Digest = [u8; 32];
+    type EncryptedRecord = [u8; 32];
+    type InnerCircuitID = [u8; 32];
+    type LocalDataRoot = [u8; 32];
+    type Memorandum = [u8; 32];
+    type ProgramCommitment = [u8; 32];
+    type SerialNumber = [u8; 32];
+    type ValueBalance = i64;
+
+    fn transaction_id(&self) -> Result<[u8; 32], TransactionError> {
+        Ok(self.memo)
+    }
+
+    fn network_id(&self) -> u8 {
+        0
+    }
+
+    fn ledger_digest(&self) -> &Self::Digest {
+        &[0u8; 32]
+    }
+
+    fn inner_circuit_id(&self) -> &Self::InnerCircuitID {
+        &[0u8; 32]
+    }
+
+    fn old_serial_numbers(&self) -> &[Self::SerialNumber] {
+        &self.serial_numbers
+    }
+
+    fn new_commitments(&self) -> &[Self::Commitment] {
+        &self.commitments
+    }
+
+    fn program_commitment(&self) -> &Self::ProgramCommitment {
+        &[0u8; 32]
+    }
+
+    fn local_data_root(&self) -> &Self::LocalDataRoot {
+        &[0u8; 32]
+    }
+
+    fn value_balance(&self) -> i64 {
+        0
+    }
+
+    fn memorandum(&self) -> &Self::Memorandum {
+        &self.memo
+    }
+
+    fn encrypted_records(&self) -> &[Self::EncryptedRecord] {
+        &[]
+    }
+
+    fn size(&self) -> usize {
+        2 + 32 * (self.serial_numbers.len() + self.commitments.len() + 1)
+    }
+}
+
+impl ToBytes for RandomTx {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.serial_numbers.len() as u8).write(&mut writer)?;
+        for serial_number in &self.serial_numbers {
+            serial_number.write(&mut writer)?;
+        }
+
+        (self.commitments.len() as u8).write(&mut writer)?;
+        for commitment in &self.commitments {
+            commitment.write(&mut writer)?;
+        }
+
+        self.memo.write(&mut writer)
+    }
+}
+
+impl FromBytes for RandomTx {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let num_serial_numbers: u8 = FromBytes::read(&mut reader)?;
+        let mut serial_numbers = Vec::with_capacity(num_serial_numbers as usize);
+        for _ in 0..num_serial_numbers {
+            serial_numbers.push(FromBytes::read(&mut reader)?);
+        }
+
+        let num_commitments: u8 = FromBytes::read(&mut reader)?;
+        let mut commitments = Vec::with_capacity(num_commitments as usize);
+        for _ in 0..num_commitments {
+            commitments.push(FromBytes::read(&mut reader)?);
+        }
+
+        let memo: [u8; 32] = FromBytes::read(&mut reader)?;
+
+        Ok(Self {
+            serial_numbers,
+            commitments,
+            memo,
+        })
+    }
+}
+
+fn random_block<R: Rng>(previous_block_hash: BlockHeaderHash, rng: &mut R) -> Block<RandomTx> {
+    let num_transactions = rng.gen_range(0..4);
+
+    Block {
+        header: BlockHeader {
+            previous_block_hash,
+            merkle_root_hash: MerkleRootHash::zero(),
+            pedersen_merkle_root_hash: PedersenMerkleRootHash::zero(),
+            time: rng.gen(),
+            difficulty_target: 0x07FF_FFFF_FFFF_FFFF_u64,
+            nonce: rng.gen(),
+            proof: ProofOfSuccinctWork([0u8; 972]),
+        },
+        transactions: Transactions((0..num_transactions).map(|_| RandomTx::new(rng)).collect()),
+    }
+}
+
+fn genesis_block() -> Block<RandomTx> {
+    Block {
+        header: BlockHeader {
+            previous_block_hash: BlockHeaderHash::zero(),
+            merkle_root_hash: MerkleRootHash::zero(),
+            pedersen_merkle_root_hash: PedersenMerkleRootHash::zero(),
+            time: 0,
+            difficulty_target: 0x07FF_FFFF_FFFF_FFFF_u64,
+            nonce: 0,
+            proof: ProofOfSuccinctWork([0u8; 972]),
+        },
+        transactions: Transactions::new(),
+    }
+}
+
+/// Applies the given number of random blocks on top of the latest block, returning them along with their digests.
+fn apply_random_blocks<P: LoadableMerkleParameters, R: Rng>(
+    ledger: &Ledger<RandomTx, P, MemDb>,
+    num_blocks: usize,
+    rng: &mut R,
+) -> Vec<(Block<RandomTx>, MerkleTreeDigest<P>)> {
+    let mut blocks = Vec::with_capacity(num_blocks);
+
+    for _ in 0..num_blocks {
+        let block = random_block(ledger.get_latest_block().unwrap().header.get_hash(), rng);
+        let digest = ledger.apply_block(&block).unwrap();
+
+        assert_eq!(ledger.digest().unwrap(), digest);
+        assert!(ledger.validate_digest(&digest));
+
+        blocks.push((block, digest));
+    }
+
+    blocks
+}
+
+fn assert_block_membership(ledger: &L, block: &Block<RandomTx>, expected: bool) {
+    for transaction in &block.transactions.0 {
+        for serial_number in transaction.old_serial_numbers() {
+            assert_eq!(ledger.contains_sn(serial_number), expected);
+        }
+        for commitment in transaction.new_commitments() {
+            assert_eq!(ledger.contains_cm(commitment), expected);
+        }
+        assert_eq!(ledger.contains_memo(transaction.memorandum()), expected);
+    }
+}
+
+#[test]
+fn test_apply_and_rollback_blocks() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let parameters = Arc::new(CommitmentMerkleParameters::setup(&mut rng));
+    let ledger =
+        initialize_test_blockchain::<RandomTx, CommitmentMerkleParameters, MemDb>(parameters.clone(), genesis_block());
+    let genesis_digest = ledger.digest().unwrap();
+
+    for _ in 0..3 {
+        let num_blocks = rng.gen_range(1..8);
+        let blocks = apply_random_blocks(&ledger, num_blocks, &mut rng);
+        assert_eq!(ledger.get_current_block_height() as usize, num_blocks);
+
+        for (block, _) in &blocks {
+            assert_block_membership(&ledger, block, true);
+        }
+
+        // Roll back every block, checking that the preceding state is restored at each step.
+        for i in (0..num_blocks).rev() {
+            let previous_digest = match i {
+                0 => genesis_digest,
+                _ => blocks[i - 1].1,
+            };
+
+            assert_eq!(ledger.rollback_block().unwrap(), previous_digest);
+            assert_eq!(ledger.digest().unwrap(), previous_digest);
+            assert_eq!(ledger.get_current_block_height() as usize, i);
+            assert_eq!(ledger.get_latest_block().unwrap().header.get_hash(), match i {
+                0 => genesis_block().header.get_hash(),
+                _ => blocks[i - 1].0.header.get_hash(),
+            });
+
+            assert_block_membership(&ledger, &blocks[i].0, false);
+            for (block, digest) in &blocks[..i] {
+                assert_block_membership(&ledger, block, true);
+                assert!(ledger.validate_digest(digest));
+            }
+            assert!(ledger.validate_digest(&genesis_digest));
+            if blocks[i].1 != previous_digest {
+                assert!(!ledger.validate_digest(&blocks[i].1));
+            }
+        }
+
+        // Re-applying the rolled back blocks yields the same digests.
+        for (block, digest) in &blocks {
+            assert_eq!(&ledger.apply_block(block).unwrap(), digest);
+        }
+        for _ in 0..num_blocks {
+            ledger.rollback_block().unwrap();
+        }
+    }
+
+    // The rolled back ledger matches a fresh ledger with the same blocks applied.
+    let blocks = apply_random_blocks(&ledger, 5, &mut rng);
+    let fresh_ledger =
+        initialize_test_blockchain::<RandomTx, CommitmentMerkleParameters, MemDb>(parameters, genesis_block());
+    for (block, digest) in &blocks {
+        assert_eq!(&fresh_ledger.apply_block(block).unwrap(), digest);
+    }
+    assert_eq!(ledger.past_digests().unwrap(), fresh_ledger.past_digests().unwrap());
+}
+
+#[test]
+fn test_rollback_genesis_block() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let parameters = Arc::new(CommitmentMerkleParameters::setup(&mut rng));
+    let ledger = initialize_test_blockchain::<RandomTx, CommitmentMerkleParameters, MemDb>(parameters, genesis_block());
+
+    assert!(matches!(
+        ledger.rollback_block(),
+        Err(StorageError::InvalidBlockDecommit)
+    ));
+
+    apply_random_blocks(&ledger, 1, &mut rng);
+    ledger.rollback_block().unwrap();
+
+    assert!(matches!(
+        ledger.rollback_block(),
+        Err(StorageError::InvalidBlockDecommit)
+    ));
+}
+
+#[test]
+fn test_serial_number_non_membership() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // The hash of the Merkle tree takes both children of a node as input.
+    define_merkle_tree_parameters!(TwoToOneMerkleParameters, PedersenCompressedCRH<EdwardsProjective, TwoToOneSize>, 32);
+    type TwoToOneLedger = Ledger<RandomTx, TwoToOneMerkleParameters, MemDb>;
+
+    let parameters = Arc::new(TwoToOneMerkleParameters::setup(&mut rng));
+    let ledger =
+        initialize_test_blockchain::<RandomTx, TwoToOneMerkleParameters, MemDb>(parameters.clone(), genesis_block());
+
+    let verify = TwoToOneLedger::verify_non_membership;
+    let block_serial_numbers = |block: &Block<RandomTx>| -> Vec<[u8; 32]> {
+        let transactions = block.transactions.0.iter();
+        transactions.flat_map(|tx| tx.serial_numbers.clone()).collect()
+    };
+
+    // Apply blocks until the ledger has two serial numbers, and the latest block reveals one.
+    let mut blocks: Vec<(Block<RandomTx>, _)> = vec![];
+    let mut serial_numbers = vec![];
+    while serial_numbers.len() < 2 || block_serial_numbers(&blocks.last().unwrap().0).is_empty() {
+        let (block, digest) = apply_random_blocks(&ledger, 1, &mut rng).remove(0);
+        serial_numbers.extend(block_serial_numbers(&block));
+        blocks.push((block, digest));
+    }
+    let height = ledger.get_current_block_height();
+    let digest = ledger.sn_digest(height).unwrap();
+    let leaf = |sn: &[u8; 32]| SerialNumberLeaf::from_serial_number(sn).unwrap();
+    let mut leaves: Vec<_> = serial_numbers.iter().map(leaf).collect();
+    leaves.sort();
+
+    // Find serial numbers whose leaves are below, between, and above the leaves of the ledger.
+    let mut below = None;
+    let mut between = None;
+    let mut above = None;
+    while below.is_none() || between.is_none() || above.is_none() {
+        let sn: [u8; 32] = rng.gen();
+        if leaf(&sn) < leaves[0] {
+            below = Some(sn);
+        } else if leaf(&sn) > leaves[leaves.len() - 1] {
+            above = Some(sn);
+        } else {
+            between = Some(sn);
+        }
+    }
+
+    for sn in &[below.unwrap(), between.unwrap(), above.unwrap()] {
+        let proof = ledger.prove_non_membership(sn, height).unwrap();
+        assert!(verify(&parameters, &digest, sn, &proof));
+        assert!(proof.left_leaf < leaf(sn) && leaf(sn) < proof.right_leaf);
+
+        // The proof does not verify for another digest, nor for a serial number in the ledger.
+        assert!(!verify(&parameters, &ledger.sn_digest(0).unwrap(), sn, &proof));
+        for serial_number in &serial_numbers {
+            assert!(!verify(&parameters, &digest, serial_number, &proof));
+        }
+    }
+    let proof = ledger.prove_non_membership(&below.unwrap(), height).unwrap();
+    assert_eq!(proof.left_leaf, SerialNumberLeaf::MIN_LEAF);
+    let proof = ledger.prove_non_membership(&above.unwrap(), height).unwrap();
+    assert_eq!(proof.right_leaf, SerialNumberLeaf::MAX_LEAF);
+
+    // The serial numbers in the ledger cannot be proven to be absent.
+    for serial_number in &serial_numbers {
+        assert!(ledger.prove_non_membership(serial_number, height).is_err());
+    }
+    assert!(ledger.prove_non_membership(&below.unwrap(), height + 1).is_err());
+
+    // The serial numbers of the latest block were absent as of the previous block.
+    let previous_digest = ledger.sn_digest(height - 1).unwrap();
+    let (latest_block, latest_digest) = blocks.last().unwrap();
+    for sn in &block_serial_numbers(latest_block) {
+        let proof = ledger.prove_non_membership(sn, height - 1).unwrap();
+        assert!(verify(&parameters, &previous_digest, sn, &proof));
+        assert!(!verify(&parameters, &digest, sn, &proof));
+    }
+
+    // Rolling back the latest block restores the previous serial number digest.
+    ledger.rollback_block().unwrap();
+    assert!(ledger.sn_digest(height).is_none());
+    assert_eq!(ledger.sn_digest(height - 1).unwrap(), previous_digest);
+    for sn in &block_serial_numbers(latest_block) {
+        let proof = ledger.prove_non_membership(sn, height - 1).unwrap();
+        assert!(verify(&parameters, &previous_digest, sn, &proof));
+    }
+    assert_eq!(&ledger.apply_block(latest_block).unwrap(), latest_digest);
+    assert_eq!(ledger.sn_digest(height).unwrap(), digest);
+}
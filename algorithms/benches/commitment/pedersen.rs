@@ -19,10 +19,11 @@ extern crate criterion;
 
 use snarkvm_algorithms::{
     commitment::pedersen::{PedersenCommitment, PedersenSize},
-    traits::CommitmentScheme,
+    traits::{CommitmentScheme, CRH},
 };
-use snarkvm_curves::edwards_bls12::EdwardsProjective;
-use snarkvm_utilities::rand::UniformRand;
+use snarkvm_curves::edwards_bls12::{EdwardsProjective, Fr};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{bititerator::BitIteratorBE, rand::UniformRand};
 
 use criterion::Criterion;
 use rand::{
@@ -38,6 +39,24 @@ impl PedersenSize for CommitmentSize {
     const WINDOW_SIZE: usize = 256;
 }
 
+/// The window sizes of the record commitment in the DPC.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecordCommitmentSize;
+
+impl PedersenSize for RecordCommitmentSize {
+    const NUM_WINDOWS: usize = 8;
+    const WINDOW_SIZE: usize = 233;
+}
+
+/// The window sizes of the inner SNARK verifying key hash in the DPC.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VkHashCommitmentSize;
+
+impl PedersenSize for VkHashCommitmentSize {
+    const NUM_WINDOWS: usize = 296;
+    const WINDOW_SIZE: usize = 63;
+}
+
 fn pedersen_commitment_setup(c: &mut Criterion) {
     let rng = &mut thread_rng();
 
@@ -46,6 +65,25 @@ fn pedersen_commitment_setup(c: &mut Criterion) {
     });
 }
 
+fn pedersen_vk_hash_commitment_setup(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+
+    c.bench_function("Pedersen VK Hash Commitment Setup", move |b| {
+        b.iter(|| <PedersenCommitment<EdwardsProjective, VkHashCommitmentSize> as CommitmentScheme>::setup(rng))
+    });
+}
+
+fn pedersen_vk_hash_commitment_evaluation(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+    let commitment = PedersenCommitment::<EdwardsProjective, VkHashCommitmentSize>::setup(rng);
+    let input = vec![127u8; 63 * 296 / 8];
+    let randomness = Fr::rand(rng);
+
+    c.bench_function("Pedersen VK Hash Commitment Evaluation", move |b| {
+        b.iter(|| commitment.commit(&input, &randomness).unwrap())
+    });
+}
+
 fn pedersen_commitment_evaluation(c: &mut Criterion) {
     let rng = &mut thread_rng();
     let parameters = <PedersenCommitment<EdwardsProjective, CommitmentSize> as CommitmentScheme>::setup(rng);
@@ -65,16 +103,45 @@ fn pedersen_commitment_evaluation(c: &mut Criterion) {
     });
 }
 
+fn pedersen_record_commitment_evaluation(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+    let commitment = PedersenCommitment::<EdwardsProjective, RecordCommitmentSize>::setup(rng);
+    commitment.parameters.precompute();
+    let input = vec![127u8; 233];
+    let randomness = Fr::rand(rng);
+
+    let commitment_bitwise = commitment.clone();
+    let input_bitwise = input.clone();
+    c.bench_function("Pedersen Record Commitment Evaluation (bitwise)", move |b| {
+        b.iter(|| {
+            // The per-bit addition of the random base powers, as performed without the table.
+            let mut output = commitment_bitwise.parameters.crh.hash(&input_bitwise).unwrap();
+            let mut scalar_bits = BitIteratorBE::new(randomness.into_repr()).collect::<Vec<_>>();
+            scalar_bits.reverse();
+            for (bit, power) in scalar_bits.into_iter().zip(&commitment_bitwise.parameters.random_base) {
+                if bit {
+                    output += power
+                }
+            }
+            output
+        })
+    });
+
+    c.bench_function("Pedersen Record Commitment Evaluation (windowed)", move |b| {
+        b.iter(|| commitment.commit(&input, &randomness).unwrap())
+    });
+}
+
 criterion_group! {
     name = commitment_setup;
     config = Criterion::default().sample_size(50);
-    targets = pedersen_commitment_setup
+    targets = pedersen_commitment_setup, pedersen_vk_hash_commitment_setup
 }
 
 criterion_group! {
     name = commitment_evaluation;
     config = Criterion::default().sample_size(50);
-    targets = pedersen_commitment_evaluation
+    targets = pedersen_commitment_evaluation, pedersen_record_commitment_evaluation, pedersen_vk_hash_commitment_evaluation
 }
 
 criterion_main!(commitment_setup, commitment_evaluation);
@@ -37,6 +37,15 @@ impl PedersenSize for CRHSize {
     const WINDOW_SIZE: usize = 32;
 }
 
+/// The window sizes of the inner SNARK verifying key hash in the DPC.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VkHashCRHSize;
+
+impl PedersenSize for VkHashCRHSize {
+    const NUM_WINDOWS: usize = 296;
+    const WINDOW_SIZE: usize = 63;
+}
+
 fn pedersen_crh_setup(c: &mut Criterion) {
     let rng = &mut thread_rng();
 
@@ -55,6 +64,16 @@ fn pedersen_crh_hash(c: &mut Criterion) {
     });
 }
 
+fn pedersen_vk_hash_crh_hash(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+    let parameters = <PedersenCRH<EdwardsProjective, VkHashCRHSize> as CRH>::setup(rng);
+    let input = vec![127u8; 63 * 296 / 8];
+
+    c.bench_function("Pedersen VK Hash Evaluation", move |b| {
+        b.iter(|| <PedersenCRH<EdwardsProjective, VkHashCRHSize> as CRH>::hash(&parameters, &input).unwrap())
+    });
+}
+
 criterion_group! {
     name = crh_setup;
     config = Criterion::default().sample_size(50);
@@ -64,7 +83,7 @@ criterion_group! {
 criterion_group! {
     name = crh_hash;
     config = Criterion::default().sample_size(50);
-    targets = pedersen_crh_hash
+    targets = pedersen_crh_hash, pedersen_vk_hash_crh_hash
 }
 
 criterion_main!(crh_setup, crh_hash);
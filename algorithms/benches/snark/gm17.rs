@@ -17,7 +17,10 @@
 #[macro_use]
 extern crate criterion;
 
-use snarkvm_algorithms::{snark::gm17::GM17, traits::SNARK};
+use snarkvm_algorithms::{
+    snark::gm17::{prepare_proving_key, GM17},
+    traits::SNARK,
+};
 use snarkvm_curves::bls12_377::{Bls12_377, Fr};
 use snarkvm_fields::Field;
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
@@ -129,10 +132,52 @@ fn snark_prove(c: &mut Criterion) {
     });
 }
 
+fn snark_prove_four_consecutive(c: &mut Criterion) {
+    let num_inputs = 100;
+    let num_constraints = num_inputs;
+    let rng = &mut thread_rng();
+    let mut inputs: Vec<Option<Fr>> = Vec::with_capacity(num_inputs);
+    for _ in 0..num_inputs {
+        inputs.push(Some(rng.gen()));
+    }
+
+    let params = GM17SNARK::setup(
+        &Benchmark::<Fr> {
+            inputs: vec![None; num_inputs],
+            num_constraints,
+        },
+        rng,
+    )
+    .unwrap();
+
+    let circuit = Benchmark {
+        inputs,
+        num_constraints,
+    };
+
+    c.bench_function("snark_prove_four_consecutive", |b| {
+        b.iter(|| {
+            for _ in 0..4 {
+                GM17SNARK::prove(&params.0, &circuit, rng).unwrap();
+            }
+        })
+    });
+
+    // The proving key is prepared once per iteration, and shared by the four proofs.
+    c.bench_function("snark_prove_four_consecutive_with_prepared_key", |b| {
+        b.iter(|| {
+            let prepared_proving_key = prepare_proving_key(params.0.clone()).unwrap();
+            for _ in 0..4 {
+                GM17SNARK::prove_with_prepared_key(&prepared_proving_key, &circuit, rng).unwrap();
+            }
+        })
+    });
+}
+
 criterion_group! {
     name = gm17_snark;
     config = Criterion::default().sample_size(50);
-    targets = snark_setup, snark_prove
+    targets = snark_setup, snark_prove, snark_prove_four_consecutive
 }
 
 criterion_main!(gm17_snark);
@@ -26,5 +26,8 @@ pub use pedersen_compressed::*;
 pub mod pedersen_parameters;
 pub use pedersen_parameters::*;
 
+pub mod poseidon;
+pub use poseidon::*;
+
 #[cfg(test)]
 mod tests;
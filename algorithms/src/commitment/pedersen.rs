@@ -22,8 +22,6 @@ use crate::{
     traits::{CommitmentScheme, CRH},
 };
 use snarkvm_curves::traits::Group;
-use snarkvm_fields::PrimeField;
-use snarkvm_utilities::bititerator::BitIteratorBE;
 
 use rand::Rng;
 
@@ -44,8 +42,27 @@ impl<G: Group, S: PedersenSize> CommitmentScheme for PedersenCommitment<G, S> {
     }
 
     fn commit(&self, input: &[u8], randomness: &Self::Randomness) -> Result<Self::Output, CommitmentError> {
+        Self::commit_with_parameters(&self.parameters, input, randomness)
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        &self.parameters
+    }
+
+    fn validate(&self) -> Result<(), CommitmentError> {
+        self.parameters.validate()
+    }
+}
+
+impl<G: Group, S: PedersenSize> PedersenCommitment<G, S> {
+    /// Commits to the given input with the given parameters, without taking ownership of them.
+    pub(crate) fn commit_with_parameters(
+        parameters: &PedersenCommitmentParameters<G, S>,
+        input: &[u8],
+        randomness: &G::ScalarField,
+    ) -> Result<G, CommitmentError> {
         // If the input is too long, return an error.
-        if input.len() > S::WINDOW_SIZE * S::NUM_WINDOWS {
+        if (input.len() * 8) > S::WINDOW_SIZE * S::NUM_WINDOWS {
             return Err(CommitmentError::IncorrectInputLength(
                 input.len(),
                 S::WINDOW_SIZE,
@@ -53,23 +70,13 @@ impl<G: Group, S: PedersenSize> CommitmentScheme for PedersenCommitment<G, S> {
             ));
         }
 
-        let mut output = self.parameters.crh.hash(&input)?;
+        let mut output = parameters.crh.hash(&input)?;
 
         // Compute h^r.
-        let mut scalar_bits = BitIteratorBE::new(randomness.into_repr()).collect::<Vec<_>>();
-        scalar_bits.reverse();
-        for (bit, power) in scalar_bits.into_iter().zip(&self.parameters.random_base) {
-            if bit {
-                output += power
-            }
-        }
+        output += parameters.random_base_mul(randomness);
 
         Ok(output)
     }
-
-    fn parameters(&self) -> &Self::Parameters {
-        &self.parameters
-    }
 }
 
 impl<G: Group, S: PedersenSize> From<PedersenCommitmentParameters<G, S>> for PedersenCommitment<G, S> {
@@ -43,11 +43,7 @@ impl<G: Group + ProjectiveCurve, S: PedersenSize> CommitmentScheme for PedersenC
 
     /// Returns the affine x-coordinate as the commitment.
     fn commit(&self, input: &[u8], randomness: &Self::Randomness) -> Result<Self::Output, CommitmentError> {
-        let commitment = PedersenCommitment::<G, S> {
-            parameters: self.parameters.clone(),
-        };
-
-        let output = commitment.commit(input, randomness)?;
+        let output = PedersenCommitment::<G, S>::commit_with_parameters(&self.parameters, input, randomness)?;
         let affine = output.into_affine();
         debug_assert!(affine.is_in_correct_subgroup_assuming_on_curve());
         Ok(affine.to_x_coordinate())
@@ -56,6 +52,10 @@ impl<G: Group + ProjectiveCurve, S: PedersenSize> CommitmentScheme for PedersenC
     fn parameters(&self) -> &Self::Parameters {
         &self.parameters
     }
+
+    fn validate(&self) -> Result<(), CommitmentError> {
+        self.parameters.validate()
+    }
 }
 
 impl<G: Group + ProjectiveCurve, S: PedersenSize> From<PedersenCommitmentParameters<G, S>>
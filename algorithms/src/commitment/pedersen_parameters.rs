@@ -17,46 +17,194 @@
 pub use crate::crh::pedersen_parameters::PedersenSize;
 
 use crate::{
-    crh::{PedersenCRH, PedersenCRHParameters},
+    crh::{
+        pedersen_parameters::{validate_powers, validate_windows, InvalidPowers},
+        PedersenCRH,
+        PedersenCRHParameters,
+    },
+    errors::CommitmentError,
     traits::CRH,
 };
-use snarkvm_curves::traits::Group;
-use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField};
-use snarkvm_utilities::bytes::{FromBytes, ToBytes};
+use snarkvm_curves::traits::{Group, ProjectiveCurve};
+use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
+use snarkvm_utilities::{
+    bititerator::BitIteratorLE,
+    bytes::{FromBytes, ToBytes},
+};
 
+use once_cell::sync::OnceCell;
 use rand::Rng;
-use std::io::{Read, Result as IoResult, Write};
+use std::{
+    cmp::Ordering,
+    io::{Read, Result as IoResult, Write},
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The number of randomness bits that are resolved with a single lookup into the random base table.
+pub const PEDERSEN_RANDOM_BASE_TABLE_WINDOW: usize = 4;
+
+/// A lazily-computed lookup table for the powers of the random base.
+///
+/// The table is derived entirely from `random_base`, so it is never serialized and is ignored in comparisons.
+#[derive(Debug, Clone, Default)]
+struct RandomBaseTable<G: Group>(OnceCell<Vec<Vec<G>>>);
+
+impl<G: Group> PartialEq for RandomBaseTable<G> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<G: Group> Eq for RandomBaseTable<G> {}
+
+impl<G: Group> PartialOrd for RandomBaseTable<G> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<G: Group> Ord for RandomBaseTable<G> {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct PedersenCommitmentParameters<G: Group, S: PedersenSize> {
     pub bases: Vec<Vec<G>>,
     pub random_base: Vec<G>,
     pub crh: PedersenCRH<G, S>,
+    random_base_table: RandomBaseTable<G>,
 }
 
 impl<G: Group, S: PedersenSize> PedersenCommitmentParameters<G, S> {
+    /// Samples the bases and, as the window that follows the last of them, the random base.
     pub fn setup<R: Rng>(rng: &mut R) -> Self {
-        let bases = (0..S::NUM_WINDOWS)
-            .map(|_| Self::base(S::WINDOW_SIZE, rng))
-            .collect::<Vec<Vec<G>>>();
-        let random_base = Self::base(S::WINDOW_SIZE, rng);
+        let mut bases = PedersenCRHParameters::<G, S>::windows(S::NUM_WINDOWS + 1, rng);
+        let random_base = bases.pop().unwrap();
         let crh_parameters = PedersenCRHParameters::from(bases.clone());
         let crh = PedersenCRH::from(crh_parameters);
         Self {
             bases,
             random_base,
             crh,
+            random_base_table: RandomBaseTable::default(),
+        }
+    }
+}
+
+impl<G: ProjectiveCurve, S: PedersenSize> PedersenCommitmentParameters<G, S> {
+    /// Derives the parameters deterministically from a personalization string.
+    ///
+    /// The bases match those of `PedersenCRHParameters::setup_from_seed` for the same string,
+    /// and the random base is hashed from the window index that follows the last of them.
+    pub fn setup_from_seed(personalization: &[u8]) -> Self {
+        let crh_parameters = PedersenCRHParameters::<G, S>::setup_from_seed(personalization);
+        let bases = crh_parameters.bases.clone();
+        let random_base =
+            PedersenCRHParameters::<G, S>::base_from_seed(personalization, S::NUM_WINDOWS as u32, S::WINDOW_SIZE);
+        let crh = PedersenCRH::from(crh_parameters);
+        Self {
+            bases,
+            random_base,
+            crh,
+            random_base_table: RandomBaseTable::default(),
         }
     }
+}
 
-    fn base<R: Rng>(num_powers: usize, rng: &mut R) -> Vec<G> {
-        let mut powers = Vec::with_capacity(num_powers);
-        let mut base = G::rand(rng);
-        for _ in 0..num_powers {
-            powers.push(base);
-            base.double_in_place();
+impl<G: Group, S: PedersenSize> PedersenCommitmentParameters<G, S> {
+    /// Checks that the bases, the random base, and the bases of the CRH each consist of
+    /// `S::WINDOW_SIZE` doublings of a base that is neither the identity nor of small order,
+    /// and that there are `S::NUM_WINDOWS` windows of bases.
+    pub fn validate(&self) -> Result<(), CommitmentError> {
+        validate_windows::<G, S>(&self.bases, 1)?;
+        validate_powers(&self.random_base, S::WINDOW_SIZE, 1).map_err(|error| match error {
+            InvalidPowers::Length(length) => CommitmentError::IncorrectRandomBaseSize(length, S::WINDOW_SIZE),
+            InvalidPowers::SmallOrder(i) => CommitmentError::InvalidRandomBase(i),
+            InvalidPowers::NotDoubling(i) => CommitmentError::RandomBaseNotADoublingChain(i),
+        })?;
+        self.crh.parameters().validate()?;
+        Ok(())
+    }
+
+    /// Reads the parameters and checks that they are well-formed with `validate`.
+    pub fn read_checked<R: Read>(reader: R) -> Result<Self, CommitmentError> {
+        let parameters = Self::read(reader)?;
+        parameters.validate()?;
+        Ok(parameters)
+    }
+
+    /// Computes the random base table ahead of time, so that the first commitment does not pay for it.
+    pub fn precompute(&self) {
+        self.random_base_table();
+    }
+
+    /// Returns `h^r`, where `h` is the random base and `r` is the given randomness.
+    ///
+    /// Equivalent to adding the powers of the random base selected by the bits of `r`,
+    /// but resolves `PEDERSEN_RANDOM_BASE_TABLE_WINDOW` bits per group addition.
+    /// The bits of each window are recoded into a signed digit, so that a window of `w` bits
+    /// only needs the multiples `1..=2^(w - 1)` of its lowest power in the table.
+    pub fn random_base_mul(&self, randomness: &G::ScalarField) -> G {
+        let mut scalar_bits = BitIteratorLE::new(randomness.into_repr());
+
+        let mut output = G::zero();
+        let mut carry = false;
+        for (table, powers) in self
+            .random_base_table()
+            .iter()
+            .zip(self.random_base.chunks(PEDERSEN_RANDOM_BASE_TABLE_WINDOW))
+        {
+            // The final window may cover fewer powers than the window size, so only use the bits it covers.
+            let width = powers.len();
+            let digit = scalar_bits
+                .by_ref()
+                .take(width)
+                .enumerate()
+                .fold(carry as usize, |digit, (i, bit)| digit + ((bit as usize) << i));
+
+            // A digit above `2^(w - 1)` is replaced by `digit - 2^w`, carrying `2^w` into the next window.
+            carry = digit > table.len();
+            if carry {
+                let negated_digit = (1 << width) - digit;
+                if negated_digit != 0 {
+                    output -= &table[negated_digit - 1];
+                }
+            } else if digit != 0 {
+                output += &table[digit - 1];
+            }
+        }
+
+        // A carry out of the final window is the power that follows the last power of the random base.
+        if carry {
+            if let Some(last_power) = self.random_base.last() {
+                output += &last_power.double();
+            }
         }
-        powers
+        output
+    }
+
+    /// Returns the random base table, computing it on first use.
+    ///
+    /// Entry `k - 1` of window `j` is `k` times the lowest power `random_base[j * w]` of the window,
+    /// for `k` in `1..=2^(w - 1)`, where `w` is the number of powers covered by the window.
+    fn random_base_table(&self) -> &Vec<Vec<G>> {
+        self.random_base_table.0.get_or_init(|| {
+            cfg_chunks!(self.random_base, PEDERSEN_RANDOM_BASE_TABLE_WINDOW)
+                .map(|powers| {
+                    let table_size = 1 << (powers.len() - 1);
+                    let mut table = Vec::with_capacity(table_size);
+                    table.push(powers[0]);
+                    for k in 1..table_size {
+                        table.push(table[k - 1] + powers[0]);
+                    }
+                    table
+                })
+                .collect()
+        })
     }
 }
 
@@ -122,6 +270,7 @@ impl<G: Group, S: PedersenSize> FromBytes for PedersenCommitmentParameters<G, S>
             bases,
             random_base,
             crh,
+            random_base_table: RandomBaseTable::default(),
         })
     }
 }
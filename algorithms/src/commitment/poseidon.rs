@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crh::{PoseidonCRH, PoseidonParameters},
+    errors::{CRHError, CommitmentError},
+    traits::{CommitmentScheme, CRH},
+};
+use snarkvm_fields::{ConstraintFieldError, PoseidonMDSField, PrimeField, ToConstraintField};
+
+use rand::Rng;
+
+/// The Poseidon commitment to a message under randomness `r`, which is the Poseidon hash
+/// of `r` followed by the message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonCommitment<F: PrimeField + PoseidonMDSField, const RATE: usize> {
+    pub crh: PoseidonCRH<F, RATE>,
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> PoseidonCommitment<F, RATE> {
+    /// Returns the commitment to the given field elements.
+    pub fn commit_field_elements(&self, input: &[F], randomness: &F) -> F {
+        let mut elements = Vec::with_capacity(input.len() + 1);
+        elements.push(*randomness);
+        elements.extend_from_slice(input);
+        self.crh.hash_field_elements(&elements)
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> CommitmentScheme for PoseidonCommitment<F, RATE> {
+    type Output = F;
+    type Parameters = PoseidonParameters<F, RATE>;
+    type Randomness = F;
+
+    fn setup<R: Rng>(rng: &mut R) -> Self {
+        Self {
+            crh: PoseidonCRH::setup(rng),
+        }
+    }
+
+    fn commit(&self, input: &[u8], randomness: &Self::Randomness) -> Result<Self::Output, CommitmentError> {
+        let input: Vec<F> = input
+            .to_field_elements()
+            .map_err(|error| CRHError::Message(error.to_string()))?;
+        Ok(self.commit_field_elements(&input, randomness))
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        self.crh.parameters()
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> From<PoseidonParameters<F, RATE>>
+    for PoseidonCommitment<F, RATE>
+{
+    fn from(parameters: PoseidonParameters<F, RATE>) -> Self {
+        Self {
+            crh: PoseidonCRH::from(parameters),
+        }
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> ToConstraintField<F> for PoseidonCommitment<F, RATE> {
+    #[inline]
+    fn to_field_elements(&self) -> Result<Vec<F>, ConstraintFieldError> {
+        self.crh.to_field_elements()
+    }
+}
@@ -15,18 +15,29 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    commitment::{PedersenCommitment, PedersenCompressedCommitment},
-    crh::PedersenSize,
-    traits::CommitmentScheme,
+    commitment::{PedersenCommitment, PedersenCommitmentParameters, PedersenCompressedCommitment, PoseidonCommitment},
+    crh::{PedersenCRHParameters, PedersenSize},
+    errors::{CRHError, CommitmentError},
+    traits::{CommitmentScheme, CRH},
 };
-use snarkvm_curves::edwards_bls12::EdwardsProjective;
+use snarkvm_curves::{
+    bls12_377::Fq,
+    edwards_bls12::{EdwardsAffine, EdwardsProjective, Fq as EdwardsFq, Fr},
+    traits::{AffineCurve, Group, ProjectiveCurve},
+};
+use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{
+    bititerator::BitIteratorBE,
     bytes::{FromBytes, ToBytes},
+    rand::UniformRand,
     to_bytes,
 };
 
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
 use rand_xorshift::XorShiftRng;
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub(super) struct Size;
@@ -57,3 +68,344 @@ fn pedersen_commitment_parameters_serialization() {
 fn pedersen_compressed_commitment_parameters_serialization() {
     commitment_parameters_serialization::<PedersenCompressedCommitment<EdwardsProjective, Size>>();
 }
+
+#[test]
+fn poseidon_commitment_parameters_serialization() {
+    commitment_parameters_serialization::<PoseidonCommitment<Fq, 2>>();
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(super) struct RecordSize;
+
+// The window sizes of the record commitment, whose random base does not fill the last table window.
+impl PedersenSize for RecordSize {
+    const NUM_WINDOWS: usize = 8;
+    const WINDOW_SIZE: usize = 233;
+}
+
+/// Computes the commitment by adding the powers of the random base bit by bit.
+fn bitwise_pedersen_commitment<S: PedersenSize>(
+    parameters: &PedersenCommitmentParameters<EdwardsProjective, S>,
+    input: &[u8],
+    randomness: &Fr,
+) -> EdwardsProjective {
+    let mut output = parameters.crh.hash(input).unwrap();
+
+    let mut scalar_bits = BitIteratorBE::new(randomness.into_repr()).collect::<Vec<_>>();
+    scalar_bits.reverse();
+    for (bit, power) in scalar_bits.into_iter().zip(&parameters.random_base) {
+        if bit {
+            output += power
+        }
+    }
+    output
+}
+
+fn pedersen_commitment_matches_bitwise<S: PedersenSize>() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let commitment = PedersenCommitment::<EdwardsProjective, S>::setup(rng);
+    let compressed_commitment =
+        PedersenCompressedCommitment::<EdwardsProjective, S>::from(commitment.parameters.clone());
+
+    for _ in 0..10 {
+        let input: Vec<u8> = (0..(S::WINDOW_SIZE * S::NUM_WINDOWS / 8)).map(|_| rng.gen()).collect();
+        let randomness = Fr::rand(rng);
+
+        let expected = bitwise_pedersen_commitment(&commitment.parameters, &input, &randomness);
+        assert_eq!(commitment.commit(&input, &randomness).unwrap(), expected);
+        assert_eq!(
+            compressed_commitment.commit(&input, &randomness).unwrap(),
+            expected.into_affine().to_x_coordinate()
+        );
+    }
+
+    // The randomness that sets every bit covered by the random base carries out of every table window.
+    let all_powers = (0..S::WINDOW_SIZE).fold(Fr::zero(), |randomness, _| randomness * Fr::from(2u64) + Fr::one());
+
+    // The extreme randomness values select no powers and all powers.
+    for randomness in &[Fr::from(0u64), -Fr::from(1u64), all_powers] {
+        let expected = bitwise_pedersen_commitment(&commitment.parameters, &[], randomness);
+        assert_eq!(commitment.commit(&[], randomness).unwrap(), expected);
+    }
+}
+
+#[test]
+fn pedersen_commitment_random_base_table() {
+    pedersen_commitment_matches_bitwise::<Size>();
+    pedersen_commitment_matches_bitwise::<RecordSize>();
+}
+
+#[test]
+fn pedersen_commitment_parameters_serialization_ignores_random_base_table() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let parameters = PedersenCommitmentParameters::<EdwardsProjective, RecordSize>::setup(rng);
+    let parameters_bytes = to_bytes![parameters].unwrap();
+
+    parameters.precompute();
+    assert_eq!(to_bytes![parameters].unwrap(), parameters_bytes);
+
+    let recovered_parameters =
+        PedersenCommitmentParameters::<EdwardsProjective, RecordSize>::read(&parameters_bytes[..]).unwrap();
+    assert_eq!(parameters, recovered_parameters);
+
+    let randomness = Fr::rand(rng);
+    assert_eq!(
+        parameters.random_base_mul(&randomness),
+        recovered_parameters.random_base_mul(&randomness)
+    );
+}
+
+#[test]
+fn pedersen_commitment_parameters_setup_is_valid() {
+    for seed in 0..20 {
+        let rng = &mut XorShiftRng::seed_from_u64(seed);
+        PedersenCommitmentParameters::<EdwardsProjective, RecordSize>::setup(rng)
+            .validate()
+            .unwrap();
+    }
+}
+
+#[test]
+fn pedersen_commitment_parameters_validate_rejects_invalid_bases() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let parameters = PedersenCommitmentParameters::<EdwardsProjective, RecordSize>::setup(rng);
+
+    // The point `(0, -1)` is of order two.
+    let order_two = EdwardsAffine::new(EdwardsFq::zero(), -EdwardsFq::one()).into_projective();
+
+    // The corrupted parameters still deserialize, but fail validation.
+    let corrupt = |corrupt: &dyn Fn(&mut PedersenCommitmentParameters<EdwardsProjective, RecordSize>)| {
+        let mut corrupted = parameters.clone();
+        corrupt(&mut corrupted);
+        let corrupted_bytes = to_bytes![corrupted].unwrap();
+        PedersenCommitmentParameters::<EdwardsProjective, RecordSize>::read(&corrupted_bytes[..])
+            .unwrap()
+            .validate()
+    };
+
+    assert!(matches!(
+        corrupt(&|p| p.bases[1][5] = EdwardsProjective::zero()),
+        Err(CommitmentError::CRHError(CRHError::InvalidBase(1, 5)))
+    ));
+    assert!(matches!(
+        corrupt(&|p| p.random_base[0] = EdwardsProjective::zero()),
+        Err(CommitmentError::InvalidRandomBase(0))
+    ));
+    assert!(matches!(
+        corrupt(&|p| p.random_base[100] = order_two),
+        Err(CommitmentError::InvalidRandomBase(100))
+    ));
+    assert!(matches!(
+        corrupt(&|p| p.crh.parameters.bases[7][0] = order_two),
+        Err(CommitmentError::CRHError(CRHError::InvalidBase(7, 0)))
+    ));
+}
+
+#[test]
+fn pedersen_commitment_parameters_read_checked_rejects_malformed_parameters() {
+    type Parameters = PedersenCommitmentParameters<EdwardsProjective, RecordSize>;
+
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let parameters = Parameters::setup(rng);
+
+    let read_checked = |corrupt: &dyn Fn(&mut Parameters)| {
+        let mut corrupted = parameters.clone();
+        corrupt(&mut corrupted);
+        Parameters::read_checked(&to_bytes![corrupted].unwrap()[..])
+    };
+    assert_eq!(read_checked(&|_| ()).unwrap(), parameters);
+
+    let errors = [
+        read_checked(&|p| p.random_base[0] = EdwardsProjective::zero()).unwrap_err(),
+        read_checked(&|p| {
+            p.random_base.pop();
+        })
+        .unwrap_err(),
+        read_checked(&|p| p.random_base[42] = p.random_base[41]).unwrap_err(),
+        read_checked(&|p| {
+            p.bases[3].truncate(100);
+        })
+        .unwrap_err(),
+        read_checked(&|p| p.crh.parameters.bases[5][1] = p.crh.parameters.bases[5][0]).unwrap_err(),
+    ];
+    assert!(matches!(errors[0], CommitmentError::InvalidRandomBase(0)));
+    assert!(matches!(errors[1], CommitmentError::IncorrectRandomBaseSize(232, 233)));
+    assert!(matches!(errors[2], CommitmentError::RandomBaseNotADoublingChain(42)));
+    assert!(matches!(
+        errors[3],
+        CommitmentError::CRHError(CRHError::IncorrectWindowSize(3, 100, 233))
+    ));
+    assert!(matches!(
+        errors[4],
+        CommitmentError::CRHError(CRHError::NotADoublingChain(5, 1))
+    ));
+
+    let messages: HashSet<_> = errors.iter().map(|error| error.to_string()).collect();
+    assert_eq!(messages.len(), errors.len());
+}
+
+/// Samples the windows of `PedersenCRHParameters::windows` one after another.
+fn serial_windows<S: PedersenSize, R: Rng>(num_windows: usize, rng: &mut R) -> Vec<Vec<EdwardsProjective>> {
+    let seed: [u8; 32] = rng.gen();
+    (0..num_windows)
+        .map(|window| {
+            let mut rng = ChaChaRng::from_seed(seed);
+            rng.set_stream(window as u64);
+            PedersenCRHParameters::<EdwardsProjective, S>::base(S::WINDOW_SIZE, &mut rng)
+        })
+        .collect()
+}
+
+#[test]
+fn pedersen_commitment_parameters_setup_parallel_matches_serial() {
+    // The window sizes of the inner SNARK verifying key hash.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct VkHashWindow;
+
+    impl PedersenSize for VkHashWindow {
+        const NUM_WINDOWS: usize = 296;
+        const WINDOW_SIZE: usize = 63;
+    }
+
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let parameters = PedersenCommitmentParameters::<EdwardsProjective, VkHashWindow>::setup(&mut rng.clone());
+
+    // The random base is sampled as the window that follows the last of the bases.
+    let mut bases = serial_windows::<VkHashWindow, _>(VkHashWindow::NUM_WINDOWS + 1, rng);
+    let random_base = bases.pop().unwrap();
+    assert_eq!(to_bytes![parameters.bases].unwrap(), to_bytes![bases].unwrap());
+    assert_eq!(
+        to_bytes![parameters.random_base].unwrap(),
+        to_bytes![random_base].unwrap()
+    );
+    assert_eq!(parameters.crh.parameters.bases, bases);
+}
+
+#[test]
+fn pedersen_commitment_parameters_setup_from_seed() {
+    type Parameters = PedersenCommitmentParameters<EdwardsProjective, RecordSize>;
+
+    let parameters = Parameters::setup_from_seed(b"snarkVM pedersen test");
+    let repeated = Parameters::setup_from_seed(b"snarkVM pedersen test");
+    let other = Parameters::setup_from_seed(b"snarkVM pedersen test 2");
+
+    assert_eq!(to_bytes![parameters].unwrap(), to_bytes![repeated].unwrap());
+    assert_ne!(to_bytes![parameters].unwrap(), to_bytes![other].unwrap());
+    parameters.validate().unwrap();
+
+    // The bases are shared with the CRH, and the random base is a doubling chain distinct from all of them.
+    assert_eq!(parameters.bases, parameters.crh.parameters.bases);
+    assert!(
+        parameters
+            .random_base
+            .windows(2)
+            .all(|pair| pair[1] == pair[0].double())
+    );
+    assert!(
+        parameters
+            .bases
+            .iter()
+            .all(|powers| powers[0] != parameters.random_base[0])
+    );
+}
+
+/// Returns the SHA-256 digest of the commitments to an empty, a short, and a full-capacity input.
+fn pedersen_commitment_output_digest<S: PedersenSize>() -> String {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let commitment = PedersenCompressedCommitment::<EdwardsProjective, S>::setup(rng);
+
+    let full_input: Vec<u8> = (0..S::WINDOW_SIZE * S::NUM_WINDOWS / 8).map(|_| rng.gen()).collect();
+
+    let mut hasher = Sha256::new();
+    for input in &[&[][..], &[1u8, 2, 3][..], &full_input[..]] {
+        let randomness = Fr::rand(rng);
+        hasher.update(to_bytes![commitment.commit(input, &randomness).unwrap()].unwrap());
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+#[test]
+fn pedersen_commitment_outputs_are_pinned() {
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct AccountWindow;
+
+    impl PedersenSize for AccountWindow {
+        const NUM_WINDOWS: usize = 8;
+        const WINDOW_SIZE: usize = 192;
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct LocalDataCommitmentWindow;
+
+    impl PedersenSize for LocalDataCommitmentWindow {
+        const NUM_WINDOWS: usize = 8;
+        const WINDOW_SIZE: usize = 129;
+    }
+
+    assert_eq!(
+        pedersen_commitment_output_digest::<Size>(),
+        "eb4288464d0c8f645ec842cc1222ebbb611c6a0101ac5b1d95d4bdbd19ddc0a3"
+    );
+    assert_eq!(
+        pedersen_commitment_output_digest::<RecordSize>(),
+        "3b95642aa31ffde34f9b36a82498c2b7fa9874d98b9f56bf6de17ef3e529b0e8"
+    );
+    assert_eq!(
+        pedersen_commitment_output_digest::<AccountWindow>(),
+        "6c1773692b62c51703abeb83b0a2c38f26b824385556028163f3d843b3408f1d"
+    );
+    assert_eq!(
+        pedersen_commitment_output_digest::<LocalDataCommitmentWindow>(),
+        "4b564edef4b151d2c94da74a35c58db89329c5201853daa60a87c61dafa55132"
+    );
+}
+
+#[test]
+fn poseidon_commitment_hashes_randomness_and_input() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let commitment = PoseidonCommitment::<Fq, 2>::setup(rng);
+
+    for length in &[0, 3, 96] {
+        let input: Vec<u8> = (0..*length).map(|_| rng.gen()).collect();
+        let randomness = Fq::rand(rng);
+
+        let mut elements = vec![randomness];
+        elements.extend(ToConstraintField::<Fq>::to_field_elements(&input[..]).unwrap());
+        assert_eq!(
+            commitment.commit(&input, &randomness).unwrap(),
+            commitment.crh.hash_field_elements(&elements)
+        );
+    }
+}
+
+#[test]
+fn poseidon_commitment_collision_freeness() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let commitment = PoseidonCommitment::<Fq, 2>::setup(rng);
+
+    let input: Vec<u8> = (0..96).map(|_| rng.gen()).collect();
+    let num_samples = 100;
+
+    // Commitments to the same input under distinct randomness.
+    let randomness: HashSet<Fq> = (0..num_samples).map(|_| Fq::rand(rng)).collect();
+    let outputs: HashSet<Fq> = randomness
+        .iter()
+        .map(|randomness| commitment.commit(&input, randomness).unwrap())
+        .collect();
+    assert_eq!(randomness.len(), outputs.len());
+
+    // Commitments to distinct inputs under the same randomness.
+    let randomness = Fq::rand(rng);
+    let inputs: HashSet<Vec<u8>> = (0..num_samples)
+        .map(|_| {
+            let mut candidate = input.clone();
+            candidate[rng.gen_range(0..input.len())] = rng.gen();
+            candidate
+        })
+        .collect();
+    let outputs: HashSet<Fq> = inputs
+        .iter()
+        .map(|input| commitment.commit(input, &randomness).unwrap())
+        .collect();
+    assert_eq!(inputs.len(), outputs.len());
+}
@@ -37,8 +37,11 @@ pub struct CommitmentMerkleTree<C: CommitmentScheme, H: CRH> {
     /// The internal hashes of the commitment Merkle tree
     inner_hashes: (<H as CRH>::Output, <H as CRH>::Output),
 
-    /// The leaves of the commitment Merkle tree
-    leaves: [<C as CommitmentScheme>::Output; 4],
+    /// The leaves of the commitment Merkle tree, starting with the leaves under the left inner hash
+    leaves: Vec<<C as CommitmentScheme>::Output>,
+
+    /// The number of leaves under the left inner hash
+    num_left_leaves: usize,
 
     /// The CRH parameters used to construct the Merkle tree
     #[derivative(PartialEq = "ignore", Debug = "ignore")]
@@ -48,18 +51,30 @@ pub struct CommitmentMerkleTree<C: CommitmentScheme, H: CRH> {
 impl<C: CommitmentScheme, H: CRH> CommitmentMerkleTree<C, H> {
     /// Construct a new commitment Merkle tree.
     pub fn new(parameters: H, leaves: &[<C as CommitmentScheme>::Output; 4]) -> Result<Self, MerkleError> {
-        let input_1 = to_bytes![leaves[0], leaves[1]]?;
-        let inner_hash1 = H::hash(&parameters, &input_1)?;
+        Self::from_subtrees(parameters, &leaves[..2], &leaves[2..])
+    }
 
-        let input_2 = to_bytes![leaves[2], leaves[3]]?;
-        let inner_hash2 = H::hash(&parameters, &input_2)?;
+    /// Construct a new commitment Merkle tree, whose left and right inner hashes are
+    /// the hashes of the concatenation of the given left and right leaves, respectively.
+    pub fn from_subtrees(
+        parameters: H,
+        left_leaves: &[<C as CommitmentScheme>::Output],
+        right_leaves: &[<C as CommitmentScheme>::Output],
+    ) -> Result<Self, MerkleError> {
+        let inner_hash1 = H::hash(&parameters, &to_bytes![left_leaves]?)?;
+        let inner_hash2 = H::hash(&parameters, &to_bytes![right_leaves]?)?;
 
         let root = H::hash(&parameters, &to_bytes![inner_hash1, inner_hash2]?)?;
 
+        let mut leaves = Vec::with_capacity(left_leaves.len() + right_leaves.len());
+        leaves.extend_from_slice(left_leaves);
+        leaves.extend_from_slice(right_leaves);
+
         Ok(Self {
             root,
             inner_hashes: (inner_hash1, inner_hash2),
-            leaves: leaves.clone(),
+            leaves,
+            num_left_leaves: left_leaves.len(),
             parameters,
         })
     }
@@ -75,14 +90,24 @@ impl<C: CommitmentScheme, H: CRH> CommitmentMerkleTree<C, H> {
     }
 
     #[inline]
-    pub fn leaves(&self) -> [<C as CommitmentScheme>::Output; 4] {
+    pub fn leaves(&self) -> Vec<<C as CommitmentScheme>::Output> {
         self.leaves.clone()
     }
 
+    /// Returns the Merkle path of the given leaf. Only trees with two leaves under
+    /// each inner hash have Merkle paths.
     pub fn generate_proof(
         &self,
         leaf: &<C as CommitmentScheme>::Output,
     ) -> Result<CommitmentMerklePath<C, H>, MerkleError> {
+        if self.num_left_leaves != 2 || self.leaves.len() != 4 {
+            return Err(MerkleError::Message(format!(
+                "Commitment Merkle paths require 2 leaves under each inner hash, found {} and {}",
+                self.num_left_leaves,
+                self.leaves.len() - self.num_left_leaves
+            )));
+        }
+
         let leaf_index = match self.leaves.iter().position(|l| l == leaf) {
             Some(index) => index,
             _ => return Err(MerkleError::InvalidLeaf),
@@ -103,7 +128,18 @@ impl<C: CommitmentScheme, H: CRH> CommitmentMerkleTree<C, H> {
         Ok(CommitmentMerklePath { leaves, inner_hashes })
     }
 
-    pub fn from_bytes<R: Read>(mut reader: R, parameters: H) -> IoResult<Self> {
+    /// Reads a commitment Merkle tree with two leaves under each inner hash.
+    pub fn from_bytes<R: Read>(reader: R, parameters: H) -> IoResult<Self> {
+        Self::from_bytes_with_subtrees(reader, parameters, 2, 2)
+    }
+
+    /// Reads a commitment Merkle tree with the given number of leaves under each inner hash.
+    pub fn from_bytes_with_subtrees<R: Read>(
+        mut reader: R,
+        parameters: H,
+        num_left_leaves: usize,
+        num_right_leaves: usize,
+    ) -> IoResult<Self> {
         let root = <H as CRH>::Output::read(&mut reader)?;
 
         let left_inner_hash = <H as CRH>::Output::read(&mut reader)?;
@@ -111,25 +147,17 @@ impl<C: CommitmentScheme, H: CRH> CommitmentMerkleTree<C, H> {
 
         let inner_hashes = (left_inner_hash, right_inner_hash);
 
-        let mut leaves = vec![];
-        for _ in 0..4 {
+        let mut leaves = Vec::with_capacity(num_left_leaves + num_right_leaves);
+        for _ in 0..(num_left_leaves + num_right_leaves) {
             let leaf = <C as CommitmentScheme>::Output::read(&mut reader)?;
             leaves.push(leaf);
         }
 
-        assert_eq!(leaves.len(), 4);
-
-        let leaves = [
-            leaves[0].clone(),
-            leaves[1].clone(),
-            leaves[2].clone(),
-            leaves[3].clone(),
-        ];
-
         Ok(Self {
             root,
             inner_hashes,
             leaves,
+            num_left_leaves,
             parameters,
         })
     }
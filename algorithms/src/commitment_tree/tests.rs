@@ -135,3 +135,44 @@ fn test_serialize_commitment_path() {
         assert!(recovered_proof.verify(&crh, &merkle_tree.root(), &leaf).unwrap());
     }
 }
+
+#[test]
+fn test_commitment_tree_from_subtrees() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let commitment = C::setup(rng);
+    let crh = H::setup(rng);
+
+    // A tree with two leaves under each inner hash is the same as the one from `new`.
+    let merkle_tree = generate_merkle_tree(&commitment, &crh, rng);
+    let leaves = merkle_tree.leaves();
+    let recomputed_merkle_tree =
+        CommitmentMerkleTree::<C, H>::from_subtrees(crh.clone(), &leaves[..2], &leaves[2..]).unwrap();
+    assert!(merkle_tree == recomputed_merkle_tree);
+
+    // Each inner hash is the hash of the concatenation of its leaves.
+    let leaves: Vec<_> = (0..3)
+        .map(|_| {
+            let leaf_input: [u8; 32] = rng.gen();
+            let randomness = <C as CommitmentScheme>::Randomness::rand(rng);
+            commitment.commit(&leaf_input, &randomness).unwrap()
+        })
+        .collect();
+    let merkle_tree = CommitmentMerkleTree::<C, H>::from_subtrees(crh.clone(), &leaves[..2], &leaves[2..]).unwrap();
+
+    let (left_inner_hash, right_inner_hash) = merkle_tree.inner_hashes();
+    assert_eq!(left_inner_hash, crh.hash(&to_bytes![&leaves[..2]].unwrap()).unwrap());
+    assert_eq!(right_inner_hash, crh.hash(&to_bytes![&leaves[2..]].unwrap()).unwrap());
+    assert_eq!(
+        merkle_tree.root(),
+        crh.hash(&to_bytes![left_inner_hash, right_inner_hash].unwrap()).unwrap()
+    );
+
+    // A tree with uneven subtrees has no Merkle paths, but is serialized like any other tree.
+    assert!(merkle_tree.generate_proof(&leaves[0]).is_err());
+
+    let merkle_tree_bytes = to_bytes![merkle_tree].unwrap();
+    let recovered_merkle_tree =
+        CommitmentMerkleTree::<C, H>::from_bytes_with_subtrees(&merkle_tree_bytes[..], crh, 2, 1).unwrap();
+    assert!(merkle_tree == recovered_merkle_tree);
+}
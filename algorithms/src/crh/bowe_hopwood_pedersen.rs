@@ -18,7 +18,12 @@ pub use crate::crh::pedersen_parameters::PedersenSize;
 
 use super::bowe_hopwood_pedersen_parameters::*;
 use crate::{
-    crh::{PedersenCRH, PedersenCRHParameters},
+    crh::{
+        pedersen::pad_input_and_bitify,
+        pedersen_parameters::validate_windows,
+        PedersenCRH,
+        PedersenCRHParameters,
+    },
     errors::CRHError,
     traits::CRH,
 };
@@ -26,13 +31,8 @@ use snarkvm_curves::Group;
 use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
 use snarkvm_utilities::biginteger::biginteger::BigInteger;
 
-use bitvec::{order::Lsb0, view::BitView};
 use rand::Rng;
 
-// we cant use these in array sizes since they are from a trait (and cant be refered to at const time)
-const MAX_WINDOW_SIZE: usize = 256;
-const MAX_NUM_WINDOWS: usize = 296;
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BoweHopwoodPedersenCRH<G: Group, S: PedersenSize> {
     pub parameters: PedersenCRHParameters<G, S>,
@@ -47,7 +47,7 @@ impl<G: Group, S: PedersenSize> BoweHopwoodPedersenCRH<G, S> {
             let mut base = G::rand(rng);
             for _ in 0..S::WINDOW_SIZE {
                 generators_for_segment.push(base);
-                for _ in 0..4 {
+                for _ in 0..BOWE_HOPWOOD_GENERATOR_DOUBLINGS {
                     base.double_in_place();
                 }
             }
@@ -107,27 +107,14 @@ impl<G: Group, S: PedersenSize> CRH for BoweHopwoodPedersenCRH<G, S> {
     fn hash(&self, input: &[u8]) -> Result<Self::Output, CRHError> {
         let eval_time = start_timer!(|| "BoweHopwoodPedersenCRH::Eval");
 
-        if (input.len() * 8) > S::WINDOW_SIZE * S::NUM_WINDOWS {
-            return Err(CRHError::IncorrectInputLength(
-                input.len(),
-                S::WINDOW_SIZE,
-                S::NUM_WINDOWS,
-            ));
-        }
-        assert!(S::WINDOW_SIZE <= MAX_WINDOW_SIZE);
-        assert!(S::NUM_WINDOWS <= MAX_NUM_WINDOWS);
-
-        // overzealous but stack allocation
-        let mut buffer = [0u8; MAX_WINDOW_SIZE * MAX_NUM_WINDOWS / 8 + BOWE_HOPWOOD_CHUNK_SIZE + 1];
-        buffer[..input.len()].copy_from_slice(input);
-        let buf_slice = (&buffer[..]).view_bits::<Lsb0>();
-
-        let mut bit_len = S::WINDOW_SIZE * S::NUM_WINDOWS;
-        if bit_len % BOWE_HOPWOOD_CHUNK_SIZE != 0 {
-            bit_len += BOWE_HOPWOOD_CHUNK_SIZE - (bit_len % BOWE_HOPWOOD_CHUNK_SIZE);
+        // Pad the input bits to a whole number of chunks.
+        let mut input_in_bits = pad_input_and_bitify::<S>(input)?;
+        if input_in_bits.len() % BOWE_HOPWOOD_CHUNK_SIZE != 0 {
+            let padding = BOWE_HOPWOOD_CHUNK_SIZE - (input_in_bits.len() % BOWE_HOPWOOD_CHUNK_SIZE);
+            input_in_bits.resize(input_in_bits.len() + padding, false);
         }
 
-        assert_eq!(bit_len % BOWE_HOPWOOD_CHUNK_SIZE, 0);
+        assert_eq!(input_in_bits.len() % BOWE_HOPWOOD_CHUNK_SIZE, 0);
 
         assert_eq!(
             self.parameters.bases.len(),
@@ -153,7 +140,7 @@ impl<G: Group, S: PedersenSize> CRH for BoweHopwoodPedersenCRH<G, S> {
         // (1-2*c_{i,j,2})*(1+c_{i,j,0}+2*c_{i,j,1})*2^{4*(j-1)} for all j in segment}
         // for all i. Described in section 5.4.1.7 in the Zcash protocol
         // specification.
-        let result = buf_slice[..bit_len]
+        let result = input_in_bits
             .chunks(S::WINDOW_SIZE * BOWE_HOPWOOD_CHUNK_SIZE)
             .zip(base_lookup)
             .map(|(segment_bits, segment_generators)| {
@@ -176,6 +163,12 @@ impl<G: Group, S: PedersenSize> CRH for BoweHopwoodPedersenCRH<G, S> {
     fn parameters(&self) -> &Self::Parameters {
         &self.parameters
     }
+
+    /// Checks that each segment holds `S::WINDOW_SIZE` generators, each of which is the preceding
+    /// generator doubled `BOWE_HOPWOOD_GENERATOR_DOUBLINGS` times, starting from a base of prime order.
+    fn validate(&self) -> Result<(), CRHError> {
+        validate_windows::<G, S>(&self.parameters.bases, BOWE_HOPWOOD_GENERATOR_DOUBLINGS)
+    }
 }
 
 impl<G: Group, S: PedersenSize> From<PedersenCRHParameters<G, S>> for BoweHopwoodPedersenCRH<G, S> {
@@ -17,7 +17,14 @@
 pub use crate::crh::pedersen_parameters::PedersenSize;
 
 use crate::{
-    crh::{BoweHopwoodPedersenCRH, BoweHopwoodPedersenCRHParameters, PedersenCRH, PedersenCRHParameters},
+    crh::{
+        pedersen_parameters::validate_windows,
+        BoweHopwoodPedersenCRH,
+        BoweHopwoodPedersenCRHParameters,
+        PedersenCRH,
+        PedersenCRHParameters,
+        BOWE_HOPWOOD_GENERATOR_DOUBLINGS,
+    },
     errors::CRHError,
     traits::CRH,
 };
@@ -65,6 +72,12 @@ impl<G: Group + ProjectiveCurve, S: PedersenSize> CRH for BoweHopwoodPedersenCom
     fn parameters(&self) -> &Self::Parameters {
         &self.parameters
     }
+
+    /// Checks that each segment holds `S::WINDOW_SIZE` generators, each of which is the preceding
+    /// generator doubled `BOWE_HOPWOOD_GENERATOR_DOUBLINGS` times, starting from a base of prime order.
+    fn validate(&self) -> Result<(), CRHError> {
+        validate_windows::<G, S>(&self.parameters.bases, BOWE_HOPWOOD_GENERATOR_DOUBLINGS)
+    }
 }
 
 impl<G: Group + ProjectiveCurve, S: PedersenSize> From<PedersenCRHParameters<G, S>>
@@ -20,6 +20,8 @@ use super::{PedersenCRHParameters, PedersenSize};
 
 pub const BOWE_HOPWOOD_CHUNK_SIZE: usize = 3;
 pub const BOWE_HOPWOOD_LOOKUP_SIZE: usize = 2usize.pow(BOWE_HOPWOOD_CHUNK_SIZE as u32);
+/// The number of doublings from each generator of a segment to the next.
+pub const BOWE_HOPWOOD_GENERATOR_DOUBLINGS: usize = BOWE_HOPWOOD_CHUNK_SIZE + 1;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -32,8 +32,32 @@ pub use pedersen_compressed::*;
 pub mod pedersen_parameters;
 pub use pedersen_parameters::*;
 
+pub mod poseidon;
+pub use poseidon::*;
+
+pub mod poseidon_parameters;
+pub use poseidon_parameters::*;
+
+pub mod poseidon_sponge;
+pub use poseidon_sponge::*;
+
 pub mod sha256;
 pub use sha256::*;
 
 #[cfg(test)]
 mod tests;
+
+use blake2::{digest::Digest, Blake2s};
+use snarkvm_utilities::ToBytes;
+
+/// Returns the Blake2s digest of the serialized parameters, as their `CRHParameters::digest`.
+pub(crate) fn parameters_digest<P: ToBytes>(parameters: &P) -> [u8; 32] {
+    let mut bytes = vec![];
+    parameters
+        .write(&mut bytes)
+        .expect("serializing to a vector does not fail");
+
+    let mut digest = [0u8; 32];
+    digest.copy_from_slice(&Blake2s::digest(&bytes));
+    digest
+}
@@ -21,11 +21,14 @@ use crate::{
     errors::CRHError,
     traits::{CRHParameters, CRH},
 };
-use bitvec::{order::Lsb0, view::BitView};
 use snarkvm_curves::Group;
 use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField};
 
 use rand::Rng;
+use std::mem::size_of;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct PedersenCRH<G: Group, S: PedersenSize> {
@@ -45,37 +48,129 @@ impl<G: Group, S: PedersenSize> CRH for PedersenCRH<G, S> {
     }
 
     fn hash(&self, input: &[u8]) -> Result<Self::Output, CRHError> {
-        if (input.len() * 8) > S::WINDOW_SIZE * S::NUM_WINDOWS {
-            return Err(CRHError::IncorrectInputLength(
-                input.len(),
+        Self::hash_with_parameters(&self.parameters, input)
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        &self.parameters
+    }
+
+    fn validate(&self) -> Result<(), CRHError> {
+        self.parameters.validate()
+    }
+}
+
+impl<G: Group, S: PedersenSize> PedersenCRH<G, S> {
+    /// Hashes the given input, prefixed with its bit-length, so that inputs of different lengths
+    /// have different hashes, even if one is the other extended with zero bytes.
+    /// The input and its prefix must fit in `S::WINDOW_SIZE * S::NUM_WINDOWS` bits.
+    pub fn hash_variable_length(&self, input: &[u8]) -> Result<G, CRHError> {
+        Self::hash_variable_length_with_parameters(&self.parameters, input)
+    }
+
+    /// Hashes the given input with the given parameters, as in `hash_variable_length`.
+    pub(crate) fn hash_variable_length_with_parameters(
+        parameters: &PedersenCRHParameters<G, S>,
+        input: &[u8],
+    ) -> Result<G, CRHError> {
+        let bits = pad_variable_length_input_and_bitify::<S>(input)?;
+        Self::hash_bits_with_parameters(parameters, &bits)
+    }
+
+    /// Hashes the given input with the given parameters, without taking ownership of them.
+    pub(crate) fn hash_with_parameters(parameters: &PedersenCRHParameters<G, S>, input: &[u8]) -> Result<G, CRHError> {
+        let bits = pad_input_and_bitify::<S>(input)?;
+        Self::hash_bits_with_parameters(parameters, &bits)
+    }
+
+    /// Evaluates the masked Pedersen hash of the given `input` natively, with the same algorithm
+    /// as `MaskedCRHGadget::check_evaluation_gadget_masked`. The mask must be half the length of
+    /// the input, as it is extended such that 0 => 01, 1 => 10. As the masking cancels out, the
+    /// result is the hash of the input, which allows cross-checking the masked evaluation.
+    pub fn evaluate_masked(
+        &self,
+        input: &[u8],
+        mask_parameters: &PedersenCRHParameters<G, S>,
+        mask: &[u8],
+    ) -> Result<G, CRHError> {
+        Self::evaluate_masked_with_parameters(&self.parameters, input, mask_parameters, mask)
+    }
+
+    /// Evaluates the masked hash of the given input with the given parameters, as in `evaluate_masked`.
+    pub(crate) fn evaluate_masked_with_parameters(
+        parameters: &PedersenCRHParameters<G, S>,
+        input: &[u8],
+        mask_parameters: &PedersenCRHParameters<G, S>,
+        mask: &[u8],
+    ) -> Result<G, CRHError> {
+        if input.len() != mask.len() * 2 {
+            return Err(CRHError::IncorrectMaskLength(mask.len(), input.len()));
+        }
+        if mask_parameters.bases.len() != S::NUM_WINDOWS {
+            return Err(CRHError::IncorrectParameterSize(
+                mask_parameters.bases[0].len(),
+                mask_parameters.bases.len(),
                 S::WINDOW_SIZE,
                 S::NUM_WINDOWS,
             ));
         }
 
-        // Pad the input if it is not the current length.
-        let mut input = input;
-        let mut padded_input = vec![];
-        if (input.len() * 8) < S::WINDOW_SIZE * S::NUM_WINDOWS {
-            padded_input.extend_from_slice(input);
-            padded_input.resize((S::WINDOW_SIZE * S::NUM_WINDOWS) / 8, 0u8);
-            input = padded_input.as_slice();
+        let mask = extend_mask(mask);
+        // H(p) = sum of g_i^{p_i} for all i.
+        let mask_hash = Self::hash_with_parameters(parameters, &mask)?;
+
+        let input_bits = pad_input_and_bitify::<S>(input)?;
+        let mask_bits = pad_input_and_bitify::<S>(&mask)?;
+
+        let mut masked_output = G::zero();
+        let mut mask_symmetric_hash = G::zero();
+        for ((input_bits, mask_bits), (powers, mask_powers)) in input_bits
+            .chunks(S::WINDOW_SIZE)
+            .zip(mask_bits.chunks(S::WINDOW_SIZE))
+            .zip(parameters.bases.iter().zip(mask_parameters.bases.iter()))
+        {
+            for (((&bit, &mask_bit), base), mask_base) in input_bits.iter().zip(mask_bits).zip(powers).zip(mask_powers)
+            {
+                // The result is incremented by:
+                //   1. h_i^-1 if the input bit is 0 and the mask is 0.
+                //   2. g_i*h_i^-1 if the input bit is 1 and the mask is 0.
+                //   3. g_i^-1*h_i if the input bit is 0 and the mask is 1.
+                //   4. h_i if the input bit is 1 and the mask is 1.
+                masked_output += match (bit, mask_bit) {
+                    (false, false) => -*mask_base,
+                    (true, false) => *base - mask_base,
+                    (false, true) => *mask_base - base,
+                    (true, true) => *mask_base,
+                };
+
+                // H_2(p) = sum of h_i^{1-2*p_i} for all i.
+                if mask_bit {
+                    mask_symmetric_hash -= mask_base;
+                } else {
+                    mask_symmetric_hash += mask_base;
+                }
+            }
         }
 
-        if self.parameters.bases.len() != S::NUM_WINDOWS {
+        Ok(masked_output + mask_hash + mask_symmetric_hash)
+    }
+
+    /// Hashes exactly `S::WINDOW_SIZE * S::NUM_WINDOWS` bits with the given parameters.
+    fn hash_bits_with_parameters(parameters: &PedersenCRHParameters<G, S>, bits: &[bool]) -> Result<G, CRHError> {
+        debug_assert_eq!(bits.len(), S::WINDOW_SIZE * S::NUM_WINDOWS);
+
+        if parameters.bases.len() != S::NUM_WINDOWS {
             return Err(CRHError::IncorrectParameterSize(
-                self.parameters.bases[0].len(),
-                self.parameters.bases.len(),
+                parameters.bases[0].len(),
+                parameters.bases.len(),
                 S::WINDOW_SIZE,
                 S::NUM_WINDOWS,
             ));
         }
 
         // Compute sum of h_i^{m_i} for all i.
-        let bits = input.view_bits::<Lsb0>();
-        let result = bits
-            .chunks(S::WINDOW_SIZE)
-            .zip(&self.parameters.bases)
+        let window_sums: Vec<G> = cfg_chunks!(bits, S::WINDOW_SIZE)
+            .zip(cfg_iter!(parameters.bases))
             .map(|(bits, powers)| {
                 let mut encoded = G::zero();
                 for (bit, base) in bits.iter().zip(powers.iter()) {
@@ -85,14 +180,75 @@ impl<G: Group, S: PedersenSize> CRH for PedersenCRH<G, S> {
                 }
                 encoded
             })
-            .fold(G::zero(), |a, b| a + b);
+            .collect();
+
+        // The window sums are added in order, so that the representation of the result does not
+        // depend on how the windows were scheduled.
+        let result = window_sums.into_iter().fold(G::zero(), |a, b| a + b);
 
         Ok(result)
     }
+}
 
-    fn parameters(&self) -> &Self::Parameters {
-        &self.parameters
+/// Returns the little-endian bits of `input`, padded with zeros to exactly
+/// `S::WINDOW_SIZE * S::NUM_WINDOWS` bits, which need not be a multiple of 8.
+pub(crate) fn pad_input_and_bitify<S: PedersenSize>(input: &[u8]) -> Result<Vec<bool>, CRHError> {
+    let input_size_bits = S::WINDOW_SIZE * S::NUM_WINDOWS;
+    if input.len() * 8 > input_size_bits {
+        return Err(CRHError::IncorrectInputLength(
+            input.len(),
+            S::WINDOW_SIZE,
+            S::NUM_WINDOWS,
+        ));
     }
+
+    let mut bits: Vec<bool> = input
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+        .collect();
+    bits.resize(input_size_bits, false);
+    Ok(bits)
+}
+
+/// Extends the mask such that 0 => 01, 1 => 10, doubling its length, as in `MaskedCRHGadget::extend_mask`.
+pub fn extend_mask(mask: &[u8]) -> Vec<u8> {
+    let extend_nibble = |nibble: u8| {
+        (0..4).fold(0u8, |extended, i| {
+            let bit = (nibble >> i) & 1;
+            extended | (bit << (2 * i)) | ((1 - bit) << (2 * i + 1))
+        })
+    };
+
+    mask.iter()
+        .flat_map(|byte| vec![extend_nibble(byte & 0x0f), extend_nibble(byte >> 4)])
+        .collect()
+}
+
+/// Returns the little-endian bit-length of an input of `input_len` bytes, as prefixed to it by
+/// `PedersenCRH::hash_variable_length`. The prefix has the fewest bits that can express any
+/// bit-length up to `S::WINDOW_SIZE * S::NUM_WINDOWS`, and the input must fit after it.
+pub fn variable_length_prefix<S: PedersenSize>(input_len: usize) -> Result<Vec<bool>, CRHError> {
+    let input_size_bits = S::WINDOW_SIZE * S::NUM_WINDOWS;
+    let prefix_size_bits = size_of::<usize>() * 8 - input_size_bits.leading_zeros() as usize;
+    if prefix_size_bits + input_len * 8 > input_size_bits {
+        return Err(CRHError::IncorrectInputLength(
+            input_len,
+            S::WINDOW_SIZE,
+            S::NUM_WINDOWS,
+        ));
+    }
+
+    let bit_length = input_len * 8;
+    Ok((0..prefix_size_bits).map(|i| (bit_length >> i) & 1 == 1).collect())
+}
+
+/// Returns the bit-length prefix of `input` followed by its little-endian bits, padded with
+/// zeros to exactly `S::WINDOW_SIZE * S::NUM_WINDOWS` bits.
+fn pad_variable_length_input_and_bitify<S: PedersenSize>(input: &[u8]) -> Result<Vec<bool>, CRHError> {
+    let mut bits = variable_length_prefix::<S>(input.len())?;
+    bits.extend(input.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)));
+    bits.resize(S::WINDOW_SIZE * S::NUM_WINDOWS, false);
+    Ok(bits)
 }
 
 impl<G: Group, S: PedersenSize> From<PedersenCRHParameters<G, S>> for PedersenCRH<G, S> {
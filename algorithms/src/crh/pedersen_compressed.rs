@@ -45,11 +45,7 @@ impl<G: Group + ProjectiveCurve, S: PedersenSize> CRH for PedersenCompressedCRH<
 
     /// Returns the affine x-coordinate as the collision-resistant hash output.
     fn hash(&self, input: &[u8]) -> Result<Self::Output, CRHError> {
-        let crh = PedersenCRH::<G, S> {
-            parameters: self.parameters.clone(),
-        };
-
-        let output = crh.hash(input)?;
+        let output = PedersenCRH::<G, S>::hash_with_parameters(&self.parameters, input)?;
         let affine = output.into_affine();
         debug_assert!(affine.is_in_correct_subgroup_assuming_on_curve());
         Ok(affine.to_x_coordinate())
@@ -58,6 +54,34 @@ impl<G: Group + ProjectiveCurve, S: PedersenSize> CRH for PedersenCompressedCRH<
     fn parameters(&self) -> &Self::Parameters {
         &self.parameters
     }
+
+    fn validate(&self) -> Result<(), CRHError> {
+        self.parameters.validate()
+    }
+}
+
+impl<G: Group + ProjectiveCurve, S: PedersenSize> PedersenCompressedCRH<G, S> {
+    /// Returns the affine x-coordinate of `PedersenCRH::hash_variable_length` on the given input.
+    pub fn hash_variable_length(&self, input: &[u8]) -> Result<<G::Affine as AffineCurve>::BaseField, CRHError> {
+        let output = PedersenCRH::<G, S>::hash_variable_length_with_parameters(&self.parameters, input)?;
+        let affine = output.into_affine();
+        debug_assert!(affine.is_in_correct_subgroup_assuming_on_curve());
+        Ok(affine.to_x_coordinate())
+    }
+
+    /// Returns the affine x-coordinate of `PedersenCRH::evaluate_masked` on the given input and mask.
+    pub fn evaluate_masked(
+        &self,
+        input: &[u8],
+        mask_parameters: &PedersenCRHParameters<G, S>,
+        mask: &[u8],
+    ) -> Result<<G::Affine as AffineCurve>::BaseField, CRHError> {
+        let output =
+            PedersenCRH::<G, S>::evaluate_masked_with_parameters(&self.parameters, input, mask_parameters, mask)?;
+        let affine = output.into_affine();
+        debug_assert!(affine.is_in_correct_subgroup_assuming_on_curve());
+        Ok(affine.to_x_coordinate())
+    }
 }
 
 impl<G: Group + ProjectiveCurve, S: PedersenSize> From<PedersenCRHParameters<G, S>> for PedersenCompressedCRH<G, S> {
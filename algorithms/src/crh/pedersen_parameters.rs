@@ -14,18 +14,28 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::traits::crh::CRHParameters;
-use snarkvm_curves::Group;
-use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField};
+use crate::{crh::parameters_digest, errors::CRHError, traits::crh::CRHParameters};
+use snarkvm_curves::{
+    traits::{AffineCurve, ProjectiveCurve},
+    Group,
+};
+use snarkvm_fields::{ConstraintFieldError, Field, One, ToConstraintField};
 use snarkvm_utilities::bytes::{FromBytes, ToBytes};
 
-use rand::Rng;
+use blake2::Blake2s;
+use digest::Digest;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
 use std::{
     fmt::Debug,
+    hash::{Hash, Hasher},
     io::{Read, Result as IoResult, Write},
     marker::PhantomData,
 };
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 pub trait PedersenSize: Clone + Debug + Eq {
     const NUM_WINDOWS: usize;
     const WINDOW_SIZE: usize;
@@ -38,6 +48,8 @@ pub struct PedersenCRHParameters<G: Group, S: PedersenSize> {
 }
 
 impl<G: Group, S: PedersenSize> CRHParameters for PedersenCRHParameters<G, S> {
+    /// Samples the windows one after another from `rng`, so that the parameters of the block and
+    /// PoSW Merkle trees, which are derived from a fixed seed, do not change.
     fn setup<R: Rng>(rng: &mut R) -> Self {
         let bases = (0..S::NUM_WINDOWS).map(|_| Self::base(S::WINDOW_SIZE, rng)).collect();
         Self {
@@ -45,6 +57,16 @@ impl<G: Group, S: PedersenSize> CRHParameters for PedersenCRHParameters<G, S> {
             _size: PhantomData,
         }
     }
+
+    fn digest(&self) -> [u8; 32] {
+        parameters_digest(self)
+    }
+}
+
+impl<G: Group, S: PedersenSize> Hash for PedersenCRHParameters<G, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bases.hash(state);
+    }
 }
 
 impl<G: Group, S: PedersenSize> PedersenCRHParameters<G, S> {
@@ -55,9 +77,70 @@ impl<G: Group, S: PedersenSize> PedersenCRHParameters<G, S> {
         }
     }
 
-    fn base<R: Rng>(num_powers: usize, rng: &mut R) -> Vec<G> {
+    /// Checks that there are `S::NUM_WINDOWS` windows of `S::WINDOW_SIZE` bases, and that each
+    /// window is the doubling chain of a base that is neither the identity nor of small order.
+    ///
+    /// Points off the curve are already rejected when the bases are read.
+    pub fn validate(&self) -> Result<(), CRHError> {
+        validate_windows::<G, S>(&self.bases, 1)
+    }
+
+    /// Reads the parameters and checks that they are well-formed with `validate`.
+    pub fn read_checked<R: Read>(reader: R) -> Result<Self, CRHError> {
+        let parameters = Self::read(reader)?;
+        parameters.validate()?;
+        Ok(parameters)
+    }
+
+    /// Samples `num_windows` bases and returns the first `S::WINDOW_SIZE` doublings of each.
+    ///
+    /// Unlike `setup`, each window samples its base from its own ChaCha stream under a seed drawn
+    /// from `rng`, so that the windows can be sampled in parallel without changing the output.
+    pub(crate) fn windows<R: Rng>(num_windows: usize, rng: &mut R) -> Vec<Vec<G>> {
+        let seed: <ChaChaRng as SeedableRng>::Seed = rng.gen();
+        cfg_into_iter!(0..num_windows)
+            .map(|window| {
+                let mut rng = ChaChaRng::from_seed(seed);
+                rng.set_stream(window as u64);
+                Self::base(S::WINDOW_SIZE, &mut rng)
+            })
+            .collect()
+    }
+
+    /// Samples a base, resampling the identity and points of small order, and returns its first `num_powers` doublings.
+    pub(crate) fn base<R: Rng>(num_powers: usize, rng: &mut R) -> Vec<G> {
+        let mut powers = Vec::with_capacity(num_powers);
+        let mut base = loop {
+            let base = G::rand(rng);
+            if is_prime_order(&base) {
+                break base;
+            }
+        };
+        for _ in 0..num_powers {
+            powers.push(base);
+            base.double_in_place();
+        }
+        powers
+    }
+}
+
+impl<G: ProjectiveCurve, S: PedersenSize> PedersenCRHParameters<G, S> {
+    /// Derives the parameters deterministically from a personalization string, so that they
+    /// can be regenerated from the string instead of being distributed in serialized form.
+    pub fn setup_from_seed(personalization: &[u8]) -> Self {
+        let bases = cfg_into_iter!(0..S::NUM_WINDOWS)
+            .map(|window| Self::base_from_seed(personalization, window as u32, S::WINDOW_SIZE))
+            .collect();
+        Self {
+            bases,
+            _size: PhantomData,
+        }
+    }
+
+    /// Hashes the personalization and index to a base of prime order, and returns its first `num_powers` doublings.
+    pub(crate) fn base_from_seed(personalization: &[u8], index: u32, num_powers: usize) -> Vec<G> {
         let mut powers = Vec::with_capacity(num_powers);
-        let mut base = G::rand(rng);
+        let mut base = hash_to_prime_order_point::<G>(personalization, index);
         for _ in 0..num_powers {
             powers.push(base);
             base.double_in_place();
@@ -66,6 +149,100 @@ impl<G: Group, S: PedersenSize> PedersenCRHParameters<G, S> {
     }
 }
 
+/// The number of hash output bytes fed to `from_random_bytes`, which covers the base field of every supported curve.
+const HASH_TO_CURVE_NUM_BYTES: usize = 128;
+
+/// Hashes the personalization and index to a point of prime order by try-and-increment.
+///
+/// Each attempt expands `Blake2s(len(personalization) || personalization || index || attempt || block)`
+/// over successive blocks, decodes the output with `from_random_bytes`, and clears the cofactor.
+fn hash_to_prime_order_point<G: ProjectiveCurve>(personalization: &[u8], index: u32) -> G {
+    let mut attempt = 0u32;
+    loop {
+        let mut bytes = Vec::with_capacity(HASH_TO_CURVE_NUM_BYTES);
+        for block in 0..(HASH_TO_CURVE_NUM_BYTES / 32) as u8 {
+            let mut hasher = Blake2s::new();
+            hasher.update(&(personalization.len() as u64).to_le_bytes());
+            hasher.update(personalization);
+            hasher.update(&index.to_le_bytes());
+            hasher.update(&attempt.to_le_bytes());
+            hasher.update(&[block]);
+            bytes.extend_from_slice(&hasher.finalize());
+        }
+
+        if let Some(point) = G::Affine::from_random_bytes(&bytes) {
+            let point = point.mul_by_cofactor_to_projective();
+            if is_prime_order(&point) {
+                return point;
+            }
+        }
+        attempt += 1;
+    }
+}
+
+/// Returns `true` if `g` lies in the prime order subgroup and is not the identity.
+///
+/// Multiplying by `-1` in the scalar field computes `[r - 1] g`, which is `-g` exactly when `[r] g` is the identity.
+fn is_prime_order<G: Group>(g: &G) -> bool {
+    !g.is_zero() && *g * -G::ScalarField::one() == -*g
+}
+
+/// The reason a window of powers of a base is malformed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum InvalidPowers {
+    /// The window holds the given number of powers.
+    Length(usize),
+    /// The power at the given index is the identity or of small order.
+    SmallOrder(usize),
+    /// The power at the given index is of prime order, but does not follow the preceding power in the chain.
+    NotDoubling(usize),
+}
+
+/// Checks that there are `S::NUM_WINDOWS` windows of bases, each of which passes `validate_powers`.
+pub(crate) fn validate_windows<G: Group, S: PedersenSize>(
+    bases: &[Vec<G>],
+    doublings: usize,
+) -> Result<(), CRHError> {
+    if bases.len() != S::NUM_WINDOWS {
+        return Err(CRHError::IncorrectNumberOfWindows(S::NUM_WINDOWS, bases.len()));
+    }
+    for (i, powers) in bases.iter().enumerate() {
+        validate_powers(powers, S::WINDOW_SIZE, doublings).map_err(|error| match error {
+            InvalidPowers::Length(length) => CRHError::IncorrectWindowSize(i, length, S::WINDOW_SIZE),
+            InvalidPowers::SmallOrder(j) => CRHError::InvalidBase(i, j),
+            InvalidPowers::NotDoubling(j) => CRHError::NotADoublingChain(i, j),
+        })?;
+    }
+    Ok(())
+}
+
+/// Checks that there are `num_powers` powers of a base of prime order, each of which is the preceding power
+/// doubled `doublings` times.
+///
+/// The doubling of a point of prime order is also of prime order, so only the first power,
+/// and any power that does not follow the preceding power, pays for a scalar multiplication.
+pub(crate) fn validate_powers<G: Group>(
+    powers: &[G],
+    num_powers: usize,
+    doublings: usize,
+) -> Result<(), InvalidPowers> {
+    if powers.len() != num_powers {
+        return Err(InvalidPowers::Length(powers.len()));
+    }
+    for (i, power) in powers.iter().enumerate() {
+        if i > 0 && (0..doublings).fold(powers[i - 1], |power, _| power.double()) == *power {
+            continue;
+        }
+        if !is_prime_order(power) {
+            return Err(InvalidPowers::SmallOrder(i));
+        }
+        if i > 0 {
+            return Err(InvalidPowers::NotDoubling(i));
+        }
+    }
+    Ok(())
+}
+
 impl<G: Group, S: PedersenSize> ToBytes for PedersenCRHParameters<G, S> {
     fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
         (self.bases.len() as u32).write(&mut writer)?;
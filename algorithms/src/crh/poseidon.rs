@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crh::{PoseidonParameters, PoseidonSponge},
+    errors::CRHError,
+    traits::{AlgebraicSponge, CRH},
+};
+use snarkvm_fields::{ConstraintFieldError, FieldParameters, PoseidonMDSField, PrimeField, ToConstraintField};
+
+use rand::Rng;
+use std::sync::Arc;
+
+/// The Poseidon sponge as a CRH, which absorbs its input and squeezes out a single field element.
+///
+/// Byte inputs are packed into field elements, and inputs of field elements are absorbed as they are.
+/// As the input is not padded with its length, inputs that differ only in trailing zero elements
+/// have the same hash, so inputs should be of a fixed length, as they are for the Pedersen CRHs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonCRH<F: PrimeField + PoseidonMDSField, const RATE: usize> {
+    pub parameters: Arc<PoseidonParameters<F, RATE>>,
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> PoseidonCRH<F, RATE> {
+    /// Returns the hash of the given field elements.
+    pub fn hash_field_elements(&self, input: &[F]) -> F {
+        let mut sponge = PoseidonSponge::with_parameters(self.parameters.clone());
+        sponge.absorb(input);
+        sponge.squeeze(1)[0]
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> CRH for PoseidonCRH<F, RATE> {
+    type Output = F;
+    type Parameters = PoseidonParameters<F, RATE>;
+
+    // Poseidon accepts inputs of any length. The input size is set to two serialized outputs,
+    // which is the input size of a Merkle tree node.
+    const INPUT_SIZE_BITS: usize = 2 * ((<F as PrimeField>::Parameters::MODULUS_BITS as usize + 63) / 64) * 64;
+
+    fn setup<R: Rng>(rng: &mut R) -> Self {
+        Self {
+            parameters: Arc::new(PoseidonParameters::new(rng)),
+        }
+    }
+
+    fn hash(&self, input: &[u8]) -> Result<Self::Output, CRHError> {
+        let input: Vec<F> = input
+            .to_field_elements()
+            .map_err(|error| CRHError::Message(error.to_string()))?;
+        Ok(self.hash_field_elements(&input))
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        &self.parameters
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> From<PoseidonParameters<F, RATE>> for PoseidonCRH<F, RATE> {
+    fn from(parameters: PoseidonParameters<F, RATE>) -> Self {
+        Self {
+            parameters: Arc::new(parameters),
+        }
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> ToConstraintField<F> for PoseidonCRH<F, RATE> {
+    #[inline]
+    fn to_field_elements(&self) -> Result<Vec<F>, ConstraintFieldError> {
+        self.parameters.to_field_elements()
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{crh::parameters_digest, traits::crh::CRHParameters};
+use snarkvm_fields::{ConstraintFieldError, PoseidonMDSField, PrimeField, ToConstraintField};
+use snarkvm_utilities::bytes::{FromBytes, ToBytes};
+
+use rand::{Rng, SeedableRng};
+use std::io::{Error, ErrorKind, Read, Result as IoResult, Write};
+
+/// The seed of the round constants that are shared by the Poseidon CRH, the Poseidon commitment,
+/// and the Poseidon sponge of the Fiat-Shamir transform.
+const POSEIDON_DEFAULT_SEED: u64 = 123456789u64;
+
+/// The parameters of a Poseidon permutation over a state of `RATE` elements and one capacity element.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonParameters<F: PrimeField + PoseidonMDSField, const RATE: usize> {
+    /// Number of rounds in a full-round operation
+    pub full_rounds: u32,
+    /// Number of rounds in a partial-round operation
+    pub partial_rounds: u32,
+    /// Exponent used in S-boxes
+    pub alpha: u64,
+    /// Additive Round keys. These are added before each MDS matrix application to make it an affine shift.
+    /// They are indexed by ark[round_num][state_element_index]
+    pub ark: Vec<Vec<F>>,
+    /// Maximally Distance Separating Matrix.
+    pub mds: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> PoseidonParameters<F, RATE> {
+    /// The capacity of the sponge.
+    pub const CAPACITY: usize = 1;
+    /// The number of elements of the state.
+    pub const WIDTH: usize = RATE + Self::CAPACITY;
+
+    /// Samples the round constants from the given RNG. The number of rounds and the S-box are those
+    /// of the field. The MDS matrix is that of the field if it has the width of the state, and a
+    /// Cauchy matrix otherwise.
+    pub fn new<R: Rng>(rng: &mut R) -> Self {
+        let full_rounds = F::poseidon_number_full_rounds();
+        let partial_rounds = F::poseidon_number_partial_rounds();
+        let alpha = F::poseidon_alpha();
+
+        let ark = (0..(full_rounds + partial_rounds))
+            .map(|_| (0..Self::WIDTH).map(|_| F::rand(rng)).collect())
+            .collect();
+
+        let mds = match F::poseidon_mds_matrix() {
+            mds if mds.len() == Self::WIDTH => mds,
+            _ => Self::cauchy_matrix(),
+        };
+
+        Self {
+            full_rounds,
+            partial_rounds,
+            alpha,
+            ark,
+            mds,
+        }
+    }
+
+    /// Returns the Cauchy matrix `1 / (x_i + y_j)` for `x_i = i` and `y_j = WIDTH + j`,
+    /// which is MDS as the `x_i + y_j` are nonzero and the `x_i` and `y_j` are distinct.
+    fn cauchy_matrix() -> Vec<Vec<F>> {
+        (0..Self::WIDTH)
+            .map(|i| {
+                (0..Self::WIDTH)
+                    .map(|j| F::from((Self::WIDTH + i + j) as u64).inverse().unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> Default for PoseidonParameters<F, RATE> {
+    /// Returns the parameters whose round constants are derived from a fixed seed,
+    /// which are the parameters of the Fiat-Shamir sponge for a rate of 2.
+    fn default() -> Self {
+        Self::new(&mut rand_chacha::ChaChaRng::seed_from_u64(POSEIDON_DEFAULT_SEED))
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> CRHParameters for PoseidonParameters<F, RATE> {
+    fn setup<R: Rng>(rng: &mut R) -> Self {
+        Self::new(rng)
+    }
+
+    fn digest(&self) -> [u8; 32] {
+        parameters_digest(self)
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> ToBytes for PoseidonParameters<F, RATE> {
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.full_rounds.write(&mut writer)?;
+        self.partial_rounds.write(&mut writer)?;
+        self.alpha.write(&mut writer)?;
+
+        for row in self.ark.iter().chain(self.mds.iter()) {
+            for element in row {
+                element.write(&mut writer)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> FromBytes for PoseidonParameters<F, RATE> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let full_rounds: u32 = FromBytes::read(&mut reader)?;
+        let partial_rounds: u32 = FromBytes::read(&mut reader)?;
+        let alpha: u64 = FromBytes::read(&mut reader)?;
+
+        let num_rounds = full_rounds
+            .checked_add(partial_rounds)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "too many Poseidon rounds"))?;
+
+        let mut read_rows = |num_rows: usize| -> IoResult<Vec<Vec<F>>> {
+            let mut rows = Vec::new();
+            for _ in 0..num_rows {
+                rows.push(
+                    (0..Self::WIDTH)
+                        .map(|_| FromBytes::read(&mut reader))
+                        .collect::<IoResult<_>>()?,
+                );
+            }
+            Ok(rows)
+        };
+
+        let ark = read_rows(num_rounds as usize)?;
+        let mds = read_rows(Self::WIDTH)?;
+
+        Ok(Self {
+            full_rounds,
+            partial_rounds,
+            alpha,
+            ark,
+            mds,
+        })
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> ToConstraintField<F> for PoseidonParameters<F, RATE> {
+    #[inline]
+    fn to_field_elements(&self) -> Result<Vec<F>, ConstraintFieldError> {
+        Ok(Vec::new())
+    }
+}
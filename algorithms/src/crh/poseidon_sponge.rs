@@ -0,0 +1,280 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//
+// Acknowledgements
+//
+// This implementation of Poseidon is entirely from Fractal's implementation
+// ([COS20]: https://eprint.iacr.org/2019/1076) with small syntax changes.
+//
+
+use crate::{
+    crh::PoseidonParameters,
+    traits::{AlgebraicSponge, TranscriptCost, TranscriptOp},
+};
+use snarkvm_fields::{PoseidonMDSField, PrimeField};
+
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PoseidonSpongeState {
+    Absorbing { next_absorb_index: usize },
+    Squeezing { next_squeeze_index: usize },
+}
+
+#[derive(Clone, Debug)]
+/// The sponge for Poseidon
+pub struct PoseidonSponge<F: PrimeField + PoseidonMDSField, const RATE: usize> {
+    /// The parameters of the permutation
+    pub parameters: Arc<PoseidonParameters<F, RATE>>,
+    /// The sponge's state
+    pub state: Vec<F>,
+    /// The mode
+    pub mode: PoseidonSpongeState,
+    /// The cost of the transcript so far
+    cost: TranscriptCost,
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> PoseidonSponge<F, RATE> {
+    /// Initializes a sponge with the given parameters.
+    pub fn with_parameters(parameters: Arc<PoseidonParameters<F, RATE>>) -> Self {
+        Self {
+            parameters,
+            state: vec![F::zero(); PoseidonParameters::<F, RATE>::WIDTH],
+            mode: PoseidonSpongeState::Absorbing { next_absorb_index: 0 },
+            cost: TranscriptCost::default(),
+        }
+    }
+
+    /// Returns the number of permutations invoked so far.
+    pub fn permutation_count(&self) -> usize {
+        self.cost.permutations
+    }
+
+    fn apply_s_box(&self, state: &mut [F], is_full_round: bool) {
+        // Full rounds apply the S Box (x^alpha) to every element of state
+        if is_full_round {
+            for elem in state {
+                *elem = elem.pow([self.parameters.alpha]);
+            }
+        }
+        // Partial rounds apply the S Box (x^alpha) to just the final element of state
+        else {
+            state[state.len() - 1] = state[state.len() - 1].pow([self.parameters.alpha]);
+        }
+    }
+
+    fn apply_ark(&self, state: &mut [F], round_number: usize) {
+        for (i, state_elem) in state.iter_mut().enumerate() {
+            state_elem.add_assign(self.parameters.ark[round_number][i]);
+        }
+    }
+
+    fn apply_mds(&self, state: &mut [F]) {
+        let mut new_state = Vec::new();
+        for i in 0..state.len() {
+            let mut cur = F::zero();
+            for (j, state_elem) in state.iter().enumerate() {
+                let term = state_elem.mul(&self.parameters.mds[i][j]);
+                cur.add_assign(term);
+            }
+            new_state.push(cur);
+        }
+        state.clone_from_slice(&new_state[..state.len()])
+    }
+
+    fn permute(&mut self) {
+        let full_rounds = self.parameters.full_rounds;
+        let partial_rounds = self.parameters.partial_rounds;
+        let full_rounds_over_2 = full_rounds / 2;
+        let mut state = self.state.clone();
+        for i in 0..full_rounds_over_2 {
+            self.apply_ark(&mut state, i as usize);
+            self.apply_s_box(&mut state, true);
+            self.apply_mds(&mut state);
+        }
+
+        for i in full_rounds_over_2..(full_rounds_over_2 + partial_rounds) {
+            self.apply_ark(&mut state, i as usize);
+            self.apply_s_box(&mut state, false);
+            self.apply_mds(&mut state);
+        }
+
+        for i in (full_rounds_over_2 + partial_rounds)..(partial_rounds + full_rounds) {
+            self.apply_ark(&mut state, i as usize);
+            self.apply_s_box(&mut state, true);
+            self.apply_mds(&mut state);
+        }
+        self.state = state;
+        self.cost.permutations += 1;
+    }
+
+    // Absorbs everything in elements, this does not end in an absorbtion.
+    fn absorb_internal(&mut self, rate_start_index: usize, elements: &[F]) {
+        // if we can finish in this call
+        if rate_start_index + elements.len() <= RATE {
+            for (i, element) in elements.iter().enumerate() {
+                self.state[i + rate_start_index] += element;
+            }
+            self.mode = PoseidonSpongeState::Absorbing {
+                next_absorb_index: rate_start_index + elements.len(),
+            };
+
+            return;
+        }
+        // otherwise absorb (rate - rate_start_index) elements
+        let num_elements_absorbed = RATE - rate_start_index;
+        for (i, element) in elements.iter().enumerate().take(num_elements_absorbed) {
+            self.state[i + rate_start_index] += element;
+        }
+        self.permute();
+        // Tail recurse, with the input elements being truncated by num elements absorbed
+        self.absorb_internal(0, &elements[num_elements_absorbed..]);
+    }
+
+    // Squeeze |output| many elements. This does not end in a squeeze
+    fn squeeze_internal(&mut self, rate_start_index: usize, output: &mut [F]) {
+        // if we can finish in this call
+        if rate_start_index + output.len() <= RATE {
+            output.clone_from_slice(&self.state[rate_start_index..(output.len() + rate_start_index)]);
+            self.mode = PoseidonSpongeState::Squeezing {
+                next_squeeze_index: rate_start_index + output.len(),
+            };
+            return;
+        }
+        // otherwise squeeze (rate - rate_start_index) elements
+        let num_elements_squeezed = RATE - rate_start_index;
+        output[..num_elements_squeezed]
+            .clone_from_slice(&self.state[rate_start_index..(num_elements_squeezed + rate_start_index)]);
+
+        // Unless we are done with squeezing in this call, permute.
+        if output.len() != RATE {
+            self.permute();
+        }
+        // Tail recurse, with the correct change to indices in output happening due to changing the slice
+        self.squeeze_internal(0, &mut output[num_elements_squeezed..]);
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> AlgebraicSponge<F> for PoseidonSponge<F, RATE> {
+    fn new() -> Self {
+        Self::with_parameters(Arc::new(PoseidonParameters::default()))
+    }
+
+    fn absorb(&mut self, elems: &[F]) {
+        if elems.is_empty() {
+            return;
+        }
+        self.cost.absorbed_elements += elems.len();
+
+        match self.mode {
+            PoseidonSpongeState::Absorbing { next_absorb_index } => {
+                let mut absorb_index = next_absorb_index;
+                if absorb_index == RATE {
+                    self.permute();
+                    absorb_index = 0;
+                }
+                self.absorb_internal(absorb_index, elems);
+            }
+            PoseidonSpongeState::Squeezing { next_squeeze_index: _ } => {
+                self.permute();
+                self.absorb_internal(0, elems);
+            }
+        };
+    }
+
+    fn squeeze(&mut self, num: usize) -> Vec<F> {
+        let mut squeezed_elems = vec![F::zero(); num];
+        self.cost.squeezed_elements += num;
+        match self.mode {
+            PoseidonSpongeState::Absorbing { next_absorb_index: _ } => {
+                self.permute();
+                self.squeeze_internal(0, &mut squeezed_elems);
+            }
+            PoseidonSpongeState::Squeezing { next_squeeze_index } => {
+                let mut squeeze_index = next_squeeze_index;
+                if squeeze_index == RATE {
+                    self.permute();
+                    squeeze_index = 0;
+                }
+                self.squeeze_internal(squeeze_index, &mut squeezed_elems);
+            }
+        };
+        squeezed_elems
+    }
+
+    fn transcript_cost(&self) -> TranscriptCost {
+        self.cost
+    }
+
+    fn estimate_cost(schedule: &[TranscriptOp]) -> TranscriptCost {
+        // Replays the modes of `absorb` and `squeeze`, without applying the permutation.
+        let mut cost = TranscriptCost::default();
+        let mut mode = PoseidonSpongeState::Absorbing { next_absorb_index: 0 };
+        for op in schedule {
+            match *op {
+                TranscriptOp::Absorb(0) => {}
+                TranscriptOp::Absorb(num) => {
+                    cost.absorbed_elements += num;
+
+                    let mut index = match mode {
+                        PoseidonSpongeState::Absorbing { next_absorb_index } if next_absorb_index != RATE => {
+                            next_absorb_index
+                        }
+                        _ => {
+                            cost.permutations += 1;
+                            0
+                        }
+                    };
+                    let mut remaining = num;
+                    while index + remaining > RATE {
+                        remaining -= RATE - index;
+                        index = 0;
+                        cost.permutations += 1;
+                    }
+                    mode = PoseidonSpongeState::Absorbing {
+                        next_absorb_index: index + remaining,
+                    };
+                }
+                TranscriptOp::Squeeze(num) => {
+                    cost.squeezed_elements += num;
+
+                    let mut index = match mode {
+                        PoseidonSpongeState::Squeezing { next_squeeze_index } if next_squeeze_index != RATE => {
+                            next_squeeze_index
+                        }
+                        _ => {
+                            cost.permutations += 1;
+                            0
+                        }
+                    };
+                    let mut remaining = num;
+                    while index + remaining > RATE {
+                        if remaining != RATE {
+                            cost.permutations += 1;
+                        }
+                        remaining -= RATE - index;
+                        index = 0;
+                    }
+                    mode = PoseidonSpongeState::Squeezing {
+                        next_squeeze_index: index + remaining,
+                    };
+                }
+            }
+        }
+        cost
+    }
+}
@@ -14,7 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{
+    crh::parameters_digest,
+    errors::CRHError,
+    traits::{CRHParameters, CRH},
+};
+use snarkvm_utilities::bytes::{FromBytes, ToBytes};
+
+use rand::Rng;
 use sha2::{Digest, Sha256};
+use std::{
+    fmt,
+    io::{Read, Result as IoResult, Write},
+};
 
 pub fn sha256(data: &[u8]) -> [u8; 32] {
     let digest = Sha256::digest(&data);
@@ -32,9 +44,92 @@ pub fn double_sha256(data: &[u8]) -> [u8; 32] {
     ret
 }
 
-pub fn sha256d_to_u64(data: &[u8]) -> u64 {
-    let hash_slice = double_sha256(data);
-    let mut hash = [0u8; 8];
-    hash[..].copy_from_slice(&hash_slice[..8]);
-    u64::from_le_bytes(hash)
+/// The digest of the SHA-256 CRH.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Sha256Output(pub [u8; 32]);
+
+impl ToBytes for Sha256Output {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write(writer)
+    }
+}
+
+impl FromBytes for Sha256Output {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Self(FromBytes::read(reader)?))
+    }
+}
+
+impl fmt::Display for Sha256Output {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// SHA-256 has no parameters, so they are empty.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Sha256Parameters;
+
+impl CRHParameters for Sha256Parameters {
+    fn setup<R: Rng>(_rng: &mut R) -> Self {
+        Self
+    }
+
+    fn digest(&self) -> [u8; 32] {
+        parameters_digest(self)
+    }
+}
+
+impl ToBytes for Sha256Parameters {
+    #[inline]
+    fn write<W: Write>(&self, _writer: W) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+impl FromBytes for Sha256Parameters {
+    #[inline]
+    fn read<R: Read>(_reader: R) -> IoResult<Self> {
+        Ok(Self)
+    }
+}
+
+/// SHA-256 as a CRH, for programs that verify digests produced by external systems.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Sha256CRH {
+    pub parameters: Sha256Parameters,
+}
+
+impl CRH for Sha256CRH {
+    type Output = Sha256Output;
+    type Parameters = Sha256Parameters;
+
+    // SHA-256 accepts inputs of any length. The input size is set to two digests,
+    // which is the input size of a Merkle tree node.
+    const INPUT_SIZE_BITS: usize = 512;
+
+    fn setup<R: Rng>(rng: &mut R) -> Self {
+        Self {
+            parameters: Sha256Parameters::setup(rng),
+        }
+    }
+
+    fn hash(&self, input: &[u8]) -> Result<Self::Output, CRHError> {
+        Ok(Sha256Output(sha256(input)))
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        &self.parameters
+    }
+}
+
+impl From<Sha256Parameters> for Sha256CRH {
+    fn from(parameters: Sha256Parameters) -> Self {
+        Self { parameters }
+    }
 }
@@ -15,17 +15,40 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    crh::{BoweHopwoodPedersenCRH, BoweHopwoodPedersenCompressedCRH, PedersenCRH, PedersenCompressedCRH, PedersenSize},
-    traits::CRH,
+    crh::{
+        extend_mask,
+        BoweHopwoodPedersenCRH,
+        BoweHopwoodPedersenCompressedCRH,
+        PedersenCRH,
+        PedersenCRHParameters,
+        PedersenCompressedCRH,
+        PedersenSize,
+        PoseidonCRH,
+        PoseidonParameters,
+        PoseidonSponge,
+        Sha256CRH,
+    },
+    errors::CRHError,
+    traits::{AlgebraicSponge, CRHParameters, TranscriptCost, TranscriptOp, CRH},
 };
-use snarkvm_curves::edwards_bls12::EdwardsProjective;
+use snarkvm_curves::{
+    bls12_377::Fq,
+    edwards_bls12::{EdwardsAffine, EdwardsProjective, Fq as EdwardsFq, Fr},
+    edwards_sw6::EdwardsProjective as EdwardsSW,
+    traits::{AffineCurve, ProjectiveCurve},
+    Group,
+};
+use snarkvm_fields::{One, ToConstraintField, Zero};
 use snarkvm_utilities::{
     bytes::{FromBytes, ToBytes},
+    rand::UniformRand,
     to_bytes,
 };
 
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub(super) struct Size;
@@ -75,6 +98,167 @@ fn bowe_hopwood_compressed_crh_parameters_serialization() {
     crh_parameters_serialization::<BoweHopwoodPedersenCompressedCRH<EdwardsProjective, BoweHopwoodSize>>();
 }
 
+/// Returns `(0, -1)`, which is the point of order two on the Edwards BLS12 curve.
+fn edwards_point_of_order_two() -> EdwardsProjective {
+    EdwardsAffine::new(EdwardsFq::zero(), -EdwardsFq::one()).into_projective()
+}
+
+#[test]
+fn pedersen_crh_parameters_setup_is_valid() {
+    for seed in 0..100 {
+        let rng = &mut XorShiftRng::seed_from_u64(seed);
+        let crh = PedersenCRH::<EdwardsProjective, Size>::setup(rng);
+        crh.parameters.validate().unwrap();
+    }
+}
+
+#[test]
+fn pedersen_crh_parameters_validate_rejects_invalid_bases() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let parameters = PedersenCRH::<EdwardsProjective, Size>::setup(rng).parameters;
+
+    let identity = EdwardsProjective::zero();
+    let order_two = edwards_point_of_order_two();
+    for &(window, power, invalid) in &[
+        (0, 0, identity),
+        (3, 0, order_two),
+        (5, 17, identity),
+        (7, 127, order_two),
+    ] {
+        let mut corrupted = parameters.clone();
+        corrupted.bases[window][power] = invalid;
+
+        // The corrupted parameters still deserialize, but fail validation.
+        let corrupted_bytes = to_bytes![corrupted].unwrap();
+        let recovered = PedersenCRHParameters::<EdwardsProjective, Size>::read(&corrupted_bytes[..]).unwrap();
+        assert!(matches!(
+            recovered.validate(),
+            Err(CRHError::InvalidBase(i, j)) if (i, j) == (window, power)
+        ));
+    }
+
+    // A window of identity bases, as would result from doubling an identity base.
+    let mut corrupted = parameters;
+    corrupted.bases[2] = vec![identity; Size::WINDOW_SIZE];
+    assert!(matches!(corrupted.validate(), Err(CRHError::InvalidBase(2, 0))));
+}
+
+#[test]
+fn pedersen_crh_parameters_read_checked_rejects_malformed_parameters() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let parameters = PedersenCRH::<EdwardsProjective, Size>::setup(rng).parameters;
+
+    let read_checked = |parameters: &PedersenCRHParameters<EdwardsProjective, Size>| {
+        PedersenCRHParameters::<EdwardsProjective, Size>::read_checked(&to_bytes![parameters].unwrap()[..])
+    };
+    let recovered = read_checked(&parameters).unwrap();
+    assert_eq!(to_bytes![recovered].unwrap(), to_bytes![parameters].unwrap());
+
+    let mut zero_base = parameters.clone();
+    zero_base.bases[1][0] = EdwardsProjective::zero();
+    let zero_base = read_checked(&zero_base).unwrap_err();
+    assert!(matches!(zero_base, CRHError::InvalidBase(1, 0)));
+
+    let mut truncated_window = parameters.clone();
+    truncated_window.bases[4].pop();
+    let truncated_window = read_checked(&truncated_window).unwrap_err();
+    assert!(matches!(truncated_window, CRHError::IncorrectWindowSize(4, 127, 128)));
+
+    let mut missing_window = parameters.clone();
+    missing_window.bases.pop();
+    let missing_window = read_checked(&missing_window).unwrap_err();
+    assert!(matches!(missing_window, CRHError::IncorrectNumberOfWindows(8, 7)));
+
+    // A base of prime order that does not continue the doubling chain.
+    let mut broken_chain = parameters;
+    broken_chain.bases[6][9] = broken_chain.bases[6][9].double();
+    let broken_chain = read_checked(&broken_chain).unwrap_err();
+    assert!(matches!(broken_chain, CRHError::NotADoublingChain(6, 9)));
+
+    let messages: HashSet<_> = [&zero_base, &truncated_window, &missing_window, &broken_chain]
+        .iter()
+        .map(|error| error.to_string())
+        .collect();
+    assert_eq!(messages.len(), 4);
+}
+
+#[test]
+fn bowe_hopwood_pedersen_crh_validate() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let crh = BoweHopwoodPedersenCompressedCRH::<EdwardsProjective, BoweHopwoodSize>::setup(rng);
+    crh.validate().unwrap();
+    BoweHopwoodPedersenCRH::<EdwardsProjective, BoweHopwoodSize>::from(crh.parameters.clone())
+        .validate()
+        .unwrap();
+
+    // The generators of a segment are not a chain of single doublings.
+    assert!(matches!(crh.parameters.validate(), Err(CRHError::NotADoublingChain(0, 1))));
+
+    let mut identity_base = crh.clone();
+    identity_base.parameters.bases[3][0] = EdwardsProjective::zero();
+    assert!(matches!(identity_base.validate(), Err(CRHError::InvalidBase(3, 0))));
+
+    let mut broken_chain = crh;
+    broken_chain.parameters.bases[5][9] = broken_chain.parameters.bases[5][9].double();
+    assert!(matches!(broken_chain.validate(), Err(CRHError::NotADoublingChain(5, 9))));
+}
+
+#[test]
+fn pedersen_crh_parameters_setup_from_seed() {
+    let parameters = PedersenCRHParameters::<EdwardsProjective, Size>::setup_from_seed(b"snarkVM pedersen test");
+    let repeated = PedersenCRHParameters::<EdwardsProjective, Size>::setup_from_seed(b"snarkVM pedersen test");
+    let other = PedersenCRHParameters::<EdwardsProjective, Size>::setup_from_seed(b"snarkVM pedersen test 2");
+
+    assert_eq!(to_bytes![parameters].unwrap(), to_bytes![repeated].unwrap());
+    assert_ne!(to_bytes![parameters].unwrap(), to_bytes![other].unwrap());
+    parameters.validate().unwrap();
+
+    // Each window is a doubling chain of a distinct base.
+    assert_eq!(parameters.bases.len(), Size::NUM_WINDOWS);
+    let mut first_powers = HashSet::new();
+    for powers in &parameters.bases {
+        assert_eq!(powers.len(), Size::WINDOW_SIZE);
+        assert!(powers.windows(2).all(|pair| pair[1] == pair[0].double()));
+        assert!(first_powers.insert(powers[0]));
+    }
+
+    // The parameters derived from a seed are reproducible across nodes and releases.
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&to_bytes![parameters].unwrap())),
+        "7d01b6ab27cab5dad39560e0adbc5276baa65ed1a2f0f4ac37fcd820fa8fa172"
+    );
+}
+
+#[test]
+fn pedersen_crh_parameters_digest() {
+    let parameters = PedersenCRHParameters::<EdwardsProjective, Size>::setup_from_seed(b"snarkVM pedersen test");
+    let repeated = PedersenCRHParameters::<EdwardsProjective, Size>::setup_from_seed(b"snarkVM pedersen test");
+    let other = PedersenCRHParameters::<EdwardsProjective, Size>::setup_from_seed(b"snarkVM pedersen test 2");
+
+    // Equal parameters have equal digests and hashes.
+    assert_eq!(parameters.digest(), repeated.digest());
+    assert_eq!(parameters.digest(), parameters.clone().digest());
+    assert_eq!([&parameters, &repeated].iter().collect::<HashSet<_>>().len(), 1);
+
+    // Distinct parameters, including those differing in a single base, have distinct digests.
+    assert_ne!(parameters.digest(), other.digest());
+    let mut bases = parameters.bases.clone();
+    bases[Size::NUM_WINDOWS - 1][Size::WINDOW_SIZE - 1].double_in_place();
+    let modified = PedersenCRHParameters::<EdwardsProjective, Size>::from(bases);
+    assert_ne!(parameters.digest(), modified.digest());
+    assert!(parameters != modified);
+
+    // The digests of other parameter types are deterministic.
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let poseidon = PoseidonCRH::<Fq, 2>::setup(rng);
+    assert_eq!(poseidon.parameters().digest(), poseidon.parameters().clone().digest());
+    let sha256 = Sha256CRH::setup(rng);
+    assert_eq!(
+        sha256.parameters().digest(),
+        Sha256CRH::setup(rng).parameters().digest()
+    );
+}
+
 #[test]
 fn simple_bowe_hopwood_crh() {
     type BoweHopwoodCRH = BoweHopwoodPedersenCRH<EdwardsProjective, BoweHopwoodSize>;
@@ -85,3 +269,422 @@ fn simple_bowe_hopwood_crh() {
 
     BoweHopwoodCRH::hash(&parameters, &[1, 2, 3]).unwrap();
 }
+
+#[test]
+fn poseidon_crh_parameters_serialization() {
+    crh_parameters_serialization::<PoseidonCRH<Fq, 2>>();
+    crh_parameters_serialization::<PoseidonCRH<Fq, 4>>();
+}
+
+#[test]
+fn sha256_crh_parameters_serialization() {
+    crh_parameters_serialization::<Sha256CRH>();
+}
+
+#[test]
+fn sha256_crh_matches_sha2() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let crh = Sha256CRH::setup(rng);
+
+    // The empty string, "abc", the largest single-block input, and multi-block inputs.
+    let mut inputs = vec![vec![], b"abc".to_vec()];
+    for length in &[55, 56, 64, 119, 128, 200] {
+        inputs.push((0..*length).map(|_| rng.gen()).collect());
+    }
+
+    for input in inputs {
+        let output = crh.hash(&input).unwrap();
+        assert_eq!(&output.0[..], &Sha256::digest(&input)[..]);
+    }
+
+    assert_eq!(
+        crh.hash(b"abc").unwrap().to_string(),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+macro_rules! pedersen_size {
+    ($name:ident, $window_size:expr, $num_windows:expr) => {
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        struct $name;
+
+        impl PedersenSize for $name {
+            const NUM_WINDOWS: usize = $num_windows;
+            const WINDOW_SIZE: usize = $window_size;
+        }
+    };
+}
+
+/// Returns the SHA-256 digest of the hashes of an empty, a short, and a full-capacity input.
+fn crh_output_digest<C: CRH>() -> String {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let crh = C::setup(rng);
+
+    let full_input: Vec<u8> = (0..C::INPUT_SIZE_BITS / 8).map(|_| rng.gen()).collect();
+
+    let mut hasher = Sha256::new();
+    for input in &[&[][..], &[1u8, 2, 3][..], &full_input[..]] {
+        hasher.update(to_bytes![crh.hash(input).unwrap()].unwrap());
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+#[test]
+fn pedersen_crh_outputs_are_pinned() {
+    // The window sizes of the testnet1 hashes.
+    pedersen_size!(TwoToOneWindow, 32, 8);
+    pedersen_size!(EncryptedRecordWindow, 44, 48);
+    pedersen_size!(SnNonceWindow, 63, 32);
+    pedersen_size!(LocalDataCRHWindow, 32, 16);
+    pedersen_size!(InnerSNARKVkHashWindow, 63, 296);
+    pedersen_size!(ProgramVkHashWindow, 63, 144);
+
+    assert_eq!(
+        crh_output_digest::<PedersenCRH<EdwardsProjective, Size>>(),
+        "bb021cae8310ca6982674cca31c9101d23446b9f30d3a2c29d88d2a80682bb68"
+    );
+    assert_eq!(
+        crh_output_digest::<BoweHopwoodPedersenCRH<EdwardsProjective, BoweHopwoodSize>>(),
+        "4ed07d6960602adab098d73a8785b9204921e8a5a4f6d19f1894e662806dbd13"
+    );
+    assert_eq!(
+        crh_output_digest::<BoweHopwoodPedersenCompressedCRH<EdwardsProjective, TwoToOneWindow>>(),
+        "9e169bd3c3afa0e568b1b934aa58201ea2d30cfeaa5f70d187b1c9c760ec1867"
+    );
+    assert_eq!(
+        crh_output_digest::<BoweHopwoodPedersenCompressedCRH<EdwardsProjective, EncryptedRecordWindow>>(),
+        "e6d61b388d9495df1c1b827e1ecb81fcde21e39afc8040ff95bc0676e5612c71"
+    );
+    assert_eq!(
+        crh_output_digest::<BoweHopwoodPedersenCompressedCRH<EdwardsProjective, SnNonceWindow>>(),
+        "893dbe63cd839d607e7e5cde7ecd6168bbe502e0668baf0842a89d547e5eb94e"
+    );
+    assert_eq!(
+        crh_output_digest::<BoweHopwoodPedersenCompressedCRH<EdwardsProjective, LocalDataCRHWindow>>(),
+        "70b331276436c7e6aa36718d51ccb68317b8acc93bb62a2b636adac3a85dec29"
+    );
+    assert_eq!(
+        crh_output_digest::<BoweHopwoodPedersenCompressedCRH<EdwardsSW, InnerSNARKVkHashWindow>>(),
+        "6b1c97bc18cb77dac49b90c74eed86792106b22e7b8a9ba8d01e8266f53ab2ac"
+    );
+    assert_eq!(
+        crh_output_digest::<BoweHopwoodPedersenCompressedCRH<EdwardsSW, ProgramVkHashWindow>>(),
+        "322556774c3c8308c351725aa9459a5b006fc22847cbd0f5551a904ab50eb61a"
+    );
+}
+
+/// Returns the little-endian bits of `input`.
+fn to_bits_le(input: &[u8]) -> Vec<bool> {
+    input
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+        .collect()
+}
+
+#[test]
+fn pedersen_crh_hash_parallel_matches_serial() {
+    // The window sizes of the inner SNARK verifying key hash.
+    pedersen_size!(VkHashWindow, 63, 296);
+
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let crh = PedersenCRH::<EdwardsProjective, VkHashWindow>::setup(rng);
+
+    let compressed = PedersenCompressedCRH::from(crh.parameters.clone());
+    for length in &[0, 1, 100, VkHashWindow::WINDOW_SIZE * VkHashWindow::NUM_WINDOWS / 8] {
+        let input: Vec<u8> = (0..*length).map(|_| rng.gen()).collect();
+
+        let mut bits = to_bits_le(&input);
+        bits.resize(VkHashWindow::WINDOW_SIZE * VkHashWindow::NUM_WINDOWS, false);
+
+        let mut expected = EdwardsProjective::zero();
+        for (bits, powers) in bits.chunks(VkHashWindow::WINDOW_SIZE).zip(&crh.parameters.bases) {
+            let mut encoded = EdwardsProjective::zero();
+            for (bit, base) in bits.iter().zip(powers) {
+                if *bit {
+                    encoded += base;
+                }
+            }
+            expected += encoded;
+        }
+
+        let output = crh.hash(&input).unwrap();
+        assert_eq!(to_bytes![output].unwrap(), to_bytes![expected].unwrap());
+        assert_eq!(
+            compressed.hash(&input).unwrap(),
+            expected.into_affine().to_x_coordinate()
+        );
+    }
+}
+
+#[test]
+fn pedersen_crh_non_byte_aligned_window() {
+    // 35 bits of capacity, which is neither a multiple of 8 nor of the Bowe-Hopwood chunk size.
+    pedersen_size!(UnalignedWindow, 7, 5);
+
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let pedersen = PedersenCRH::<EdwardsProjective, UnalignedWindow>::setup(rng);
+    let bowe_hopwood = BoweHopwoodPedersenCRH::<EdwardsProjective, UnalignedWindow>::setup(rng);
+
+    for input in &[
+        vec![],
+        vec![0x5a],
+        vec![0xff; 4],
+        (0..4).map(|_| rng.gen()).collect::<Vec<u8>>(),
+    ] {
+        let bits = to_bits_le(input);
+
+        // Sum the bases selected by the input bits.
+        let mut expected = EdwardsProjective::zero();
+        for (i, bit) in bits.iter().enumerate() {
+            if *bit {
+                expected += &pedersen.parameters.bases[i / 7][i % 7];
+            }
+        }
+        assert_eq!(pedersen.hash(input).unwrap(), expected);
+
+        // Sum the signed digits (1 + c_0 + 2 * c_1) * (1 - 2 * c_2) of each 3-bit chunk, where
+        // the bits past the end of the input are zero, up to the 12 chunks covering 35 bits.
+        let mut padded_bits = bits.clone();
+        padded_bits.resize(36, false);
+        let mut expected = EdwardsProjective::zero();
+        for (i, chunk) in padded_bits.chunks(3).enumerate() {
+            let base = bowe_hopwood.parameters.bases[i / 7][i % 7];
+            let mut term = base * Fr::from(1 + chunk[0] as u64 + 2 * chunk[1] as u64);
+            if chunk[2] {
+                term = -term;
+            }
+            expected += &term;
+        }
+        assert_eq!(bowe_hopwood.hash(input).unwrap(), expected);
+
+        // Trailing zero bytes are indistinguishable from the padding.
+        let mut padded_input = input.clone();
+        padded_input.resize(4, 0u8);
+        assert_eq!(pedersen.hash(&padded_input).unwrap(), pedersen.hash(input).unwrap());
+        assert_eq!(
+            bowe_hopwood.hash(&padded_input).unwrap(),
+            bowe_hopwood.hash(input).unwrap()
+        );
+    }
+
+    // Five bytes exceed the capacity.
+    assert!(pedersen.hash(&[0u8; 5]).is_err());
+    assert!(bowe_hopwood.hash(&[0u8; 5]).is_err());
+}
+
+#[test]
+fn pedersen_crh_variable_length() {
+    // 252 bits of capacity, of which the first 8 bits hold the bit-length of the input.
+    pedersen_size!(VariableLengthWindow, 63, 4);
+    const MAX_INPUT_SIZE: usize = 30;
+
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let crh = PedersenCRH::<EdwardsProjective, VariableLengthWindow>::setup(rng);
+    let compressed = PedersenCompressedCRH::from(crh.parameters.clone());
+
+    for length in 0..=MAX_INPUT_SIZE {
+        let input: Vec<u8> = (0..length).map(|_| rng.gen()).collect();
+        let output = crh.hash_variable_length(&input).unwrap();
+
+        // The 8-bit prefix is the bit-length of the input, as a little-endian byte.
+        let mut prefixed_input = vec![(length * 8) as u8];
+        prefixed_input.extend_from_slice(&input);
+        assert_eq!(output, crh.hash(&prefixed_input).unwrap());
+        assert_eq!(
+            compressed.hash_variable_length(&input).unwrap(),
+            output.into_affine().to_x_coordinate()
+        );
+
+        // Unlike the fixed-length hash, appending a zero byte changes the hash.
+        if length < MAX_INPUT_SIZE {
+            let mut extended_input = input.clone();
+            extended_input.push(0u8);
+            assert_eq!(crh.hash(&input).unwrap(), crh.hash(&extended_input).unwrap());
+            assert_ne!(output, crh.hash_variable_length(&extended_input).unwrap());
+            assert_ne!(
+                compressed.hash_variable_length(&input).unwrap(),
+                compressed.hash_variable_length(&extended_input).unwrap()
+            );
+        }
+    }
+
+    // An input that does not fit after its prefix is rejected, as is one that does not fit at all.
+    assert!(matches!(
+        crh.hash_variable_length(&[0u8; MAX_INPUT_SIZE + 1]),
+        Err(CRHError::IncorrectInputLength(31, 63, 4))
+    ));
+    assert!(matches!(
+        compressed.hash_variable_length(&[0u8; MAX_INPUT_SIZE + 1]),
+        Err(CRHError::IncorrectInputLength(31, 63, 4))
+    ));
+    assert!(matches!(
+        crh.hash(&[0u8; 32]),
+        Err(CRHError::IncorrectInputLength(32, 63, 4))
+    ));
+}
+
+#[test]
+fn pedersen_crh_evaluate_masked() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let crh = PedersenCRH::<EdwardsProjective, Size>::setup(rng);
+    let compressed = PedersenCompressedCRH::from(crh.parameters.clone());
+    let mask_parameters = PedersenCRHParameters::<EdwardsProjective, Size>::setup(rng);
+
+    // Each mask bit is extended such that 0 => 01, 1 => 10, in little-endian order.
+    assert_eq!(extend_mask(&[0b0000_0001, 0b1111_0000]), vec![
+        0b1010_1001,
+        0b1010_1010,
+        0b1010_1010,
+        0b0101_0101
+    ]);
+
+    // The masking cancels out, for inputs that do not fill the windows as well.
+    for length in &[0, 2, 64, 128] {
+        let input: Vec<u8> = (0..*length).map(|_| rng.gen()).collect();
+        let mask: Vec<u8> = (0..*length / 2).map(|_| rng.gen()).collect();
+
+        let output = crh.evaluate_masked(&input, &mask_parameters, &mask).unwrap();
+        assert_eq!(output, crh.hash(&input).unwrap());
+        assert_eq!(
+            compressed.evaluate_masked(&input, &mask_parameters, &mask).unwrap(),
+            output.into_affine().to_x_coordinate()
+        );
+    }
+
+    // The mask must be half the length of the input.
+    assert!(matches!(
+        crh.evaluate_masked(&[0u8; 64], &mask_parameters, &[0u8; 31]),
+        Err(CRHError::IncorrectMaskLength(31, 64))
+    ));
+    assert!(matches!(
+        compressed.evaluate_masked(&[0u8; 64], &mask_parameters, &[0u8; 64]),
+        Err(CRHError::IncorrectMaskLength(64, 64))
+    ));
+}
+
+#[test]
+fn poseidon_crh_matches_fiat_shamir_sponge() {
+    let crh = PoseidonCRH::<Fq, 2>::from(PoseidonParameters::default());
+
+    // The outputs of the Fiat-Shamir sponge before it was shared with the CRH.
+    let known_answers = [
+        (
+            vec![],
+            "6246496541886741885526544556429487589407061949461291692480490570506521048778401537048802766532584203340660351360",
+        ),
+        (
+            vec![1],
+            "917618870491574511662716075245507023104745025612691000021346962579917259914914348171513948638271487507630651804335",
+        ),
+        (
+            vec![1, 2, 3],
+            "440074712613617373643765042885498609103488970154305675281046502779384055783035075168841636773296218177281092934300",
+        ),
+        (
+            vec![0, 1, 2, 3, 4],
+            "1545152010348220617102080167439038914621505322342198497815541222462228563816151907153914047806288367107472984606809",
+        ),
+    ];
+
+    for (input, expected) in known_answers.iter() {
+        let input = input.iter().map(|x| Fq::from(*x as u64)).collect::<Vec<_>>();
+        let output = crh.hash_field_elements(&input);
+        assert_eq!(&output.to_string(), expected);
+
+        let mut sponge = PoseidonSponge::<Fq, 2>::new();
+        sponge.absorb(&input);
+        assert_eq!(output, sponge.squeeze(1)[0]);
+    }
+}
+
+#[test]
+fn poseidon_crh_packs_bytes() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let crh = PoseidonCRH::<Fq, 2>::setup(rng);
+
+    for length in &[0, 1, 46, 47, 48, 96, 200] {
+        let input: Vec<u8> = (0..*length).map(|_| rng.gen()).collect();
+        let elements: Vec<Fq> = input.to_field_elements().unwrap();
+        assert_eq!(crh.hash(&input).unwrap(), crh.hash_field_elements(&elements));
+    }
+}
+
+fn poseidon_crh_collision_freeness<const RATE: usize>() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let crh = PoseidonCRH::<Fq, RATE>::setup(rng);
+
+    let num_samples = 100;
+    let input_size = PoseidonCRH::<Fq, RATE>::INPUT_SIZE_BITS / 8;
+
+    // Field element inputs of a fixed length, spanning several permutations.
+    let inputs: HashSet<Vec<Fq>> = (0..num_samples)
+        .map(|_| (0..2 * RATE + 1).map(|_| Fq::rand(rng)).collect())
+        .collect();
+    let outputs: HashSet<Fq> = inputs.iter().map(|input| crh.hash_field_elements(input)).collect();
+    assert_eq!(inputs.len(), outputs.len());
+
+    // Byte inputs of a fixed length, which differ in a single byte from a common input.
+    let base: Vec<u8> = (0..input_size).map(|_| rng.gen()).collect();
+    let inputs: HashSet<Vec<u8>> = (0..num_samples)
+        .map(|_| {
+            let mut input = base.clone();
+            input[rng.gen_range(0..input_size)] = rng.gen();
+            input
+        })
+        .collect();
+    let outputs: HashSet<Fq> = inputs.iter().map(|input| crh.hash(input).unwrap()).collect();
+    assert_eq!(inputs.len(), outputs.len());
+}
+
+#[test]
+fn poseidon_crh_collision_freeness_rate_2() {
+    poseidon_crh_collision_freeness::<2>();
+}
+
+#[test]
+fn poseidon_crh_collision_freeness_rate_4() {
+    poseidon_crh_collision_freeness::<4>();
+}
+
+fn poseidon_sponge_estimate_cost<const RATE: usize>() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..100 {
+        let schedule: Vec<TranscriptOp> = (0..rng.gen_range(0..20))
+            .map(|_| match rng.gen() {
+                true => TranscriptOp::Absorb(rng.gen_range(0..3 * RATE + 2)),
+                false => TranscriptOp::Squeeze(rng.gen_range(0..3 * RATE + 2)),
+            })
+            .collect();
+
+        let mut sponge = PoseidonSponge::<Fq, RATE>::new();
+        for op in schedule.iter() {
+            match *op {
+                TranscriptOp::Absorb(num) => sponge.absorb(&(0..num).map(|_| Fq::rand(rng)).collect::<Vec<_>>()),
+                TranscriptOp::Squeeze(num) => assert_eq!(sponge.squeeze(num).len(), num),
+            }
+        }
+
+        let cost = sponge.transcript_cost();
+        assert_eq!(cost, PoseidonSponge::<Fq, RATE>::estimate_cost(&schedule));
+        assert_eq!(cost.permutations, sponge.permutation_count());
+    }
+}
+
+#[test]
+fn poseidon_sponge_estimate_cost_rate_2() {
+    poseidon_sponge_estimate_cost::<2>();
+
+    // Absorbing three elements fills the rate once, and squeezing after absorbing permutes again.
+    let schedule = [TranscriptOp::Absorb(3), TranscriptOp::Squeeze(1)];
+    assert_eq!(PoseidonSponge::<Fq, 2>::estimate_cost(&schedule), TranscriptCost {
+        permutations: 2,
+        absorbed_elements: 3,
+        squeezed_elements: 1,
+    });
+}
+
+#[test]
+fn poseidon_sponge_estimate_cost_rate_4() {
+    poseidon_sponge_estimate_cost::<4>();
+}
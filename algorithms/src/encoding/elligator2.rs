@@ -154,6 +154,8 @@ impl<P: MontgomeryModelParameters + TEModelParameters, G: Group + ProjectiveCurv
         Ok((<G as ProjectiveCurve>::Affine::read(&to_bytes![x, y]?[..])?, sign_high))
     }
 
+    /// Returns the base field element that encodes to the given group element, or an error if
+    /// the group element is not an encoding, as is the case for about half of all group elements.
     #[allow(clippy::many_single_char_names)]
     pub fn decode(
         group_element: &<G as ProjectiveCurve>::Affine,
@@ -179,8 +181,8 @@ impl<P: MontgomeryModelParameters + TEModelParameters, G: Group + ProjectiveCurv
             let numerator = P::BaseField::one() + y;
             let denominator = P::BaseField::one() - y;
 
-            let u = numerator * (denominator.inverse().unwrap());
-            let v = numerator * ((denominator * x).inverse().unwrap());
+            let u = numerator * denominator.inverse().ok_or(EncodingError::InvalidGroupElement)?;
+            let v = numerator * (denominator * x).inverse().ok_or(EncodingError::InvalidGroupElement)?;
 
             // Ensure (u, v) is a valid Montgomery element
             #[cfg(debug_assertions)]
@@ -219,30 +221,37 @@ impl<P: MontgomeryModelParameters + TEModelParameters, G: Group + ProjectiveCurv
             assert!(u.legendre().is_qnr());
 
             // Verify that x != -A.
-            assert_ne!(x, -a);
+            if x == -a {
+                return Err(EncodingError::InvalidGroupElement);
+            }
 
             // Verify that if y is 0, then x is 0.
-            if y.is_zero() {
-                assert!(x.is_zero());
+            if y.is_zero() && !x.is_zero() {
+                return Err(EncodingError::InvalidGroupElement);
             }
 
             // Verify -ux(x + A) is a residue.
-            assert_eq!((-(u * x) * (x + a)).legendre(), LegendreSymbol::QuadraticResidue);
+            if (-(u * x) * (x + a)).legendre() != LegendreSymbol::QuadraticResidue {
+                return Err(EncodingError::InvalidGroupElement);
+            }
         }
 
-        let exists_in_sqrt_fq2 = v_reconstructed.square().sqrt().unwrap() == v_reconstructed;
+        let exists_in_sqrt_fq2 = v_reconstructed
+            .square()
+            .sqrt()
+            .ok_or(EncodingError::InvalidGroupElement)?
+            == v_reconstructed;
 
-        let element = if exists_in_sqrt_fq2 {
+        let (numerator, denominator) = if exists_in_sqrt_fq2 {
             // Let value = sqrt(-x / ((x + A) * u)).
-            let numerator = -x;
-            let denominator = (x + a) * u;
-            (numerator * denominator.inverse().unwrap()).sqrt().unwrap()
+            (-x, (x + a) * u)
         } else {
             // Let value2 = sqrt(-(x + A) / ux)).
-            let numerator = -x - a;
-            let denominator = x * u;
-            (numerator * denominator.inverse().unwrap()).sqrt().unwrap()
+            (-x - a, x * u)
         };
+        let element = (numerator * denominator.inverse().ok_or(EncodingError::InvalidGroupElement)?)
+            .sqrt()
+            .ok_or(EncodingError::InvalidGroupElement)?;
 
         let element = if sign_high {
             cmp::max(element, -element)
@@ -250,8 +259,10 @@ impl<P: MontgomeryModelParameters + TEModelParameters, G: Group + ProjectiveCurv
             cmp::min(element, -element)
         };
 
-        #[cfg(debug_assertions)]
-        assert!(&Self::encode(&element)?.0 == group_element);
+        // Verify that the element encodes to the group element.
+        if &Self::encode(&element)?.0 != group_element {
+            return Err(EncodingError::InvalidGroupElement);
+        }
 
         Ok(element)
     }
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::encoding::Elligator2;
-use snarkvm_curves::edwards_bls12::*;
+use snarkvm_curves::{edwards_bls12::*, traits::ProjectiveCurve};
 use snarkvm_fields::Zero;
 use snarkvm_utilities::rand::UniformRand;
 
@@ -46,3 +46,23 @@ fn test_elligator2_zero() {
     let decode = Elligator2::<EdwardsParameters, EdwardsProjective>::decode(&EdwardsAffine::zero(), false);
     assert!(decode.is_err());
 }
+
+#[test]
+fn test_elligator2_decode_arbitrary_group_elements() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    // About half of all group elements are not encodings, and decoding them fails instead of panicking.
+    let mut num_decoded = 0;
+    for _ in 0..ITERATIONS / 10 {
+        let element = EdwardsProjective::rand(rng).into_affine();
+        for &fq_high in &[false, true] {
+            if let Ok(decoded) = Elligator2::<EdwardsParameters, EdwardsProjective>::decode(&element, fq_high) {
+                let (encoded, _) = Elligator2::<EdwardsParameters, EdwardsProjective>::encode(&decoded).unwrap();
+                assert_eq!(encoded, element);
+                num_decoded += 1;
+            }
+        }
+    }
+    assert!(num_decoded > 0);
+    assert!(num_decoded < 2 * ITERATIONS / 10);
+}
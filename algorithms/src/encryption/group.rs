@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{encryption::GroupEncryptionParameters, errors::EncryptionError, traits::EncryptionScheme};
+use crate::{encryption::GroupEncryptionParameters, errors::EncryptionError, msm::Wnaf, traits::EncryptionScheme};
 use snarkvm_curves::traits::{AffineCurve, Group, ProjectiveCurve};
 use snarkvm_fields::{Field, One, PrimeField, Zero};
 use snarkvm_utilities::{
@@ -214,10 +214,10 @@ impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeser
 
         let blinding_exponents = self.generate_blinding_exponents(public_key, randomness, message.len())?;
 
-        for (m_i, blinding_exp) in message.iter().zip_eq(blinding_exponents) {
-            // h_i <- 1 [/] (z [+] i) * record_view_key
-            let h_i = record_view_key.mul(blinding_exp);
+        // h_i <- 1 [/] (z [+] i) * record_view_key
+        let blinding_factors = Wnaf::scalar_mul_many(&record_view_key, &blinding_exponents);
 
+        for (m_i, h_i) in message.iter().zip_eq(blinding_factors) {
             // c_i <- h_i + m_i
             let c_i = h_i + m_i;
 
@@ -245,23 +245,30 @@ impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeser
         let z = Self::Randomness::read(&z_bytes[..])?;
 
         let one = Self::Randomness::one();
-        let mut plaintext = Vec::with_capacity(ciphertext.len().saturating_sub(1));
         let mut i = Self::Randomness::one();
 
-        for c_i in ciphertext.iter().skip(1) {
-            // h_i <- 1 [/] (z [+] i) * record_view_key
-            let h_i = match &(z + i).inverse() {
-                Some(val) => record_view_key.mul(*val),
+        let mut blinding_exponents = Vec::with_capacity(ciphertext.len().saturating_sub(1));
+        for _ in ciphertext.iter().skip(1) {
+            // 1 [/] (z [+] i)
+            match (z + i).inverse() {
+                Some(val) => blinding_exponents.push(val),
                 None => return Err(EncryptionError::MissingInverse),
             };
 
-            // m_i <- c_i - h_i
-            let m_i = *c_i - h_i;
-
-            plaintext.push(m_i);
             i += one;
         }
 
+        // h_i <- 1 [/] (z [+] i) * record_view_key
+        let blinding_factors = Wnaf::scalar_mul_many(&record_view_key, &blinding_exponents);
+
+        // m_i <- c_i - h_i
+        let plaintext = ciphertext
+            .iter()
+            .skip(1)
+            .zip_eq(blinding_factors)
+            .map(|(c_i, h_i)| *c_i - h_i)
+            .collect();
+
         Ok(plaintext)
     }
 
@@ -285,3 +292,203 @@ impl<G: Group + ProjectiveCurve, SG: Group, D: Digest> From<GroupEncryptionParam
         }
     }
 }
+
+/// The randomness of a multi-recipient group encryption.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "G: Group"),
+    Clone(bound = "G: Group"),
+    PartialEq(bound = "G: Group"),
+    Eq(bound = "G: Group"),
+    Debug(bound = "G: Group")
+)]
+pub struct GroupEncryptionMultiRecipientRandomness<G: Group> {
+    /// The ephemeral exponent r, which is shared by all recipients.
+    pub ephemeral: G::ScalarField,
+    /// The exponent k of the payload key K = k * g.
+    pub payload_key: G::ScalarField,
+}
+
+/// A ciphertext that is encrypted to several recipients.
+///
+/// The payload is blinded once with a random payload key K, and the payload key is
+/// encapsulated for each recipient with a shared ephemeral key, so every additional
+/// recipient costs one group element.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "G: Group"),
+    PartialEq(bound = "G: Group"),
+    Eq(bound = "G: Group"),
+    Debug(bound = "G: Group")
+)]
+pub struct GroupEncryptionMultiRecipientCiphertext<G: Group> {
+    /// c_0 <- r * g
+    pub ephemeral_key: G,
+    /// t <- 1 [/] z * K
+    pub key_confirmation: G,
+    /// d_j <- K + r * pk_j
+    pub encapsulations: Vec<G>,
+    /// c_i <- m_i + 1 [/] (z [+] i) * K
+    pub payload: Vec<G>,
+}
+
+impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeserialize, D: Digest + Send + Sync>
+    GroupEncryption<G, SG, D>
+{
+    pub fn generate_multi_recipient_randomness<R: Rng>(
+        &self,
+        rng: &mut R,
+    ) -> Result<GroupEncryptionMultiRecipientRandomness<G>, EncryptionError> {
+        let ephemeral = <G as Group>::ScalarField::rand(rng);
+
+        let mut payload_key = <G as Group>::ScalarField::zero();
+        let mut z_bytes = vec![];
+
+        while <G as Group>::ScalarField::read(&z_bytes[..]).is_err() {
+            payload_key = <G as Group>::ScalarField::rand(rng);
+
+            let affine = self.generator_mul(&payload_key)?.into_affine();
+            debug_assert!(affine.is_in_correct_subgroup_assuming_on_curve());
+            z_bytes = to_bytes![affine.to_x_coordinate()]?;
+        }
+
+        Ok(GroupEncryptionMultiRecipientRandomness { ephemeral, payload_key })
+    }
+
+    /// Returns the blinding exponents `1 [/] (z [+] i)` for `i` in `0..=message_length`,
+    /// where the first exponent blinds the key confirmation.
+    pub fn generate_multi_recipient_blinding_exponents(
+        &self,
+        randomness: &GroupEncryptionMultiRecipientRandomness<G>,
+        message_length: usize,
+    ) -> Result<Vec<<G as Group>::ScalarField>, EncryptionError> {
+        let payload_key = self.generator_mul(&randomness.payload_key)?;
+        let z = Self::x_coordinate_to_scalar(&payload_key)?;
+
+        let one = <G as Group>::ScalarField::one();
+        let mut i = <G as Group>::ScalarField::zero();
+
+        let mut blinding_exponents = Vec::with_capacity(message_length + 1);
+        for _ in 0..=message_length {
+            // 1 [/] (z [+] i)
+            match (z + i).inverse() {
+                Some(val) => blinding_exponents.push(val),
+                None => return Err(EncryptionError::MissingInverse),
+            };
+
+            i += one;
+        }
+
+        Ok(blinding_exponents)
+    }
+
+    pub fn encrypt_multi_recipient(
+        &self,
+        public_keys: &[GroupEncryptionPublicKey<G>],
+        randomness: &GroupEncryptionMultiRecipientRandomness<G>,
+        message: &[G],
+    ) -> Result<GroupEncryptionMultiRecipientCiphertext<G>, EncryptionError> {
+        if public_keys.is_empty() {
+            return Err(EncryptionError::Message("Multi-recipient encryption requires a recipient".into()));
+        }
+
+        let ephemeral_key = self.generator_mul(&randomness.ephemeral)?;
+        let payload_key = self.generator_mul(&randomness.payload_key)?;
+
+        // d_j <- K + r * pk_j
+        let encapsulations = public_keys
+            .iter()
+            .map(|public_key| payload_key + public_key.0.mul(randomness.ephemeral))
+            .collect();
+
+        let blinding_exponents = self.generate_multi_recipient_blinding_exponents(randomness, message.len())?;
+
+        // h_i <- 1 [/] (z [+] i) * K
+        let mut blinding_factors = Wnaf::scalar_mul_many(&payload_key, &blinding_exponents).into_iter();
+        let key_confirmation = blinding_factors.next().ok_or(EncryptionError::MissingInverse)?;
+
+        // c_i <- h_i + m_i
+        let payload = message.iter().zip_eq(blinding_factors).map(|(m_i, h_i)| h_i + m_i).collect();
+
+        Ok(GroupEncryptionMultiRecipientCiphertext {
+            ephemeral_key,
+            key_confirmation,
+            encapsulations,
+            payload,
+        })
+    }
+
+    /// Decrypts the payload with the first encapsulation that opens to a payload key
+    /// matching the key confirmation, or fails if the private key is not a recipient.
+    pub fn decrypt_multi_recipient(
+        &self,
+        private_key: &<G as Group>::ScalarField,
+        ciphertext: &GroupEncryptionMultiRecipientCiphertext<G>,
+    ) -> Result<Vec<G>, EncryptionError> {
+        let shared_key = ciphertext.ephemeral_key.mul(*private_key);
+
+        for d_j in &ciphertext.encapsulations {
+            // K <- d_j - sk * c_0
+            let payload_key = *d_j - shared_key;
+
+            let z = match Self::x_coordinate_to_scalar(&payload_key) {
+                Ok(z) => z,
+                Err(_) => continue,
+            };
+
+            // z * t == K
+            if ciphertext.key_confirmation.mul(z) != payload_key {
+                continue;
+            }
+
+            let one = <G as Group>::ScalarField::one();
+            let mut i = <G as Group>::ScalarField::one();
+
+            let mut blinding_exponents = Vec::with_capacity(ciphertext.payload.len());
+            for _ in &ciphertext.payload {
+                // 1 [/] (z [+] i)
+                match (z + i).inverse() {
+                    Some(val) => blinding_exponents.push(val),
+                    None => return Err(EncryptionError::MissingInverse),
+                };
+
+                i += one;
+            }
+
+            // h_i <- 1 [/] (z [+] i) * K
+            let blinding_factors = Wnaf::scalar_mul_many(&payload_key, &blinding_exponents);
+
+            // m_i <- c_i - h_i
+            let plaintext = ciphertext
+                .payload
+                .iter()
+                .zip_eq(blinding_factors)
+                .map(|(c_i, h_i)| *c_i - h_i)
+                .collect();
+
+            return Ok(plaintext);
+        }
+
+        Err(EncryptionError::Message("The private key is not a recipient of the ciphertext".into()))
+    }
+
+    /// Returns `scalar * g` using the generator powers of the parameters.
+    fn generator_mul(&self, scalar: &<G as Group>::ScalarField) -> Result<G, EncryptionError> {
+        let mut output = G::zero();
+        for (bit, base_power) in bytes_to_bits(&to_bytes![scalar]?).zip_eq(&self.parameters.generator_powers) {
+            if bit {
+                output += base_power;
+            }
+        }
+        Ok(output)
+    }
+
+    /// Reads the x-coordinate of the given element as a scalar.
+    fn x_coordinate_to_scalar(element: &G) -> Result<<G as Group>::ScalarField, EncryptionError> {
+        let affine = element.into_affine();
+        debug_assert!(affine.is_in_correct_subgroup_assuming_on_curve());
+        let z_bytes = to_bytes![affine.to_x_coordinate()]?;
+
+        Ok(<G as Group>::ScalarField::read(&z_bytes[..])?)
+    }
+}
@@ -68,3 +68,77 @@ fn encryption_public_key_serialization() {
         assert_eq!(public_key, recovered_public_key);
     }
 }
+
+fn multi_recipient_encryption(num_recipients: usize) {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let encryption_scheme = TestEncryptionScheme::setup(rng);
+
+    let private_keys: Vec<_> = (0..num_recipients)
+        .map(|_| encryption_scheme.generate_private_key(rng))
+        .collect();
+    let public_keys: Vec<_> = private_keys
+        .iter()
+        .map(|private_key| encryption_scheme.generate_public_key(private_key).unwrap())
+        .collect();
+
+    let randomness = encryption_scheme.generate_multi_recipient_randomness(rng).unwrap();
+    let message = generate_input(32, rng);
+
+    let ciphertext = encryption_scheme
+        .encrypt_multi_recipient(&public_keys, &randomness, &message)
+        .unwrap();
+    assert_eq!(ciphertext.encapsulations.len(), num_recipients);
+    assert_eq!(ciphertext.payload.len(), message.len());
+
+    for private_key in &private_keys {
+        let decrypted_message = encryption_scheme
+            .decrypt_multi_recipient(private_key, &ciphertext)
+            .unwrap();
+        assert_eq!(message, decrypted_message);
+    }
+}
+
+#[test]
+fn multi_recipient_encryption_one_recipient() {
+    multi_recipient_encryption(1);
+}
+
+#[test]
+fn multi_recipient_encryption_two_recipients() {
+    multi_recipient_encryption(2);
+}
+
+#[test]
+fn multi_recipient_encryption_five_recipients() {
+    multi_recipient_encryption(5);
+}
+
+#[test]
+fn multi_recipient_decryption_fails_for_non_recipient() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let encryption_scheme = TestEncryptionScheme::setup(rng);
+
+    let public_keys: Vec<_> = (0..2)
+        .map(|_| {
+            let private_key = encryption_scheme.generate_private_key(rng);
+            encryption_scheme.generate_public_key(&private_key).unwrap()
+        })
+        .collect();
+    let non_recipient_private_key = encryption_scheme.generate_private_key(rng);
+
+    let randomness = encryption_scheme.generate_multi_recipient_randomness(rng).unwrap();
+    let message = generate_input(8, rng);
+
+    let ciphertext = encryption_scheme
+        .encrypt_multi_recipient(&public_keys, &randomness, &message)
+        .unwrap();
+
+    assert!(
+        encryption_scheme
+            .decrypt_multi_recipient(&non_recipient_private_key, &ciphertext)
+            .is_err()
+    );
+    assert!(encryption_scheme.encrypt_multi_recipient(&[], &randomness, &message).is_err());
+}
@@ -17,34 +17,35 @@
 use std::io::{Error, ErrorKind};
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum CommitmentError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("{}", _0)]
+    CRHError(#[from] crate::CRHError),
+
     #[error("incorrect input length {} for window params {}x{}", _0, _1, _2)]
     IncorrectInputLength(usize, usize, usize),
 
-    #[error("{}", _0)]
-    CRHError(crate::CRHError),
+    #[error("random base has {} powers, expected {}", _0, _1)]
+    IncorrectRandomBaseSize(usize, usize),
+
+    #[error("random base {} is the identity or of small order", _0)]
+    InvalidRandomBase(usize),
+
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] Error),
 
     #[error("{}", _0)]
     Message(String),
-}
 
-impl From<crate::CRHError> for CommitmentError {
-    fn from(error: crate::CRHError) -> Self {
-        CommitmentError::CRHError(error)
-    }
-}
-
-impl From<Error> for CommitmentError {
-    fn from(error: Error) -> Self {
-        CommitmentError::Crate("std::io", format!("{:?}", error))
-    }
+    #[error("random base {} is not the double of the preceding random base", _0)]
+    RandomBaseNotADoublingChain(usize),
 }
 
 impl From<CommitmentError> for Error {
     fn from(error: CommitmentError) -> Error {
-        Error::new(ErrorKind::Other, error.to_string())
+        Error::new(ErrorKind::Other, error)
     }
 }
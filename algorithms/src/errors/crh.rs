@@ -17,6 +17,7 @@
 use std::io::{Error, ErrorKind};
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum CRHError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
@@ -24,21 +25,37 @@ pub enum CRHError {
     #[error("incorrect input length {} x 8 for window params {}x{}", _0, _1, _2)]
     IncorrectInputLength(usize, usize, usize),
 
+    #[error(
+        "incorrect mask length {} for input length {}, expected half the input length",
+        _0,
+        _1
+    )]
+    IncorrectMaskLength(usize, usize),
+
     #[error("incorrect parameter size {}x{} for window params {}x{}", _0, _1, _2, _3)]
     IncorrectParameterSize(usize, usize, usize, usize),
 
+    #[error("expected {} windows of bases, found {}", _0, _1)]
+    IncorrectNumberOfWindows(usize, usize),
+
+    #[error("window {} has {} bases, expected {}", _0, _1, _2)]
+    IncorrectWindowSize(usize, usize, usize),
+
+    #[error("base {} of window {} is the identity or of small order", _1, _0)]
+    InvalidBase(usize, usize),
+
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] Error),
+
     #[error("{}", _0)]
     Message(String),
-}
 
-impl From<Error> for CRHError {
-    fn from(error: Error) -> Self {
-        CRHError::Crate("std::io", format!("{:?}", error))
-    }
+    #[error("base {} of window {} does not follow the preceding base in the chain of doublings", _1, _0)]
+    NotADoublingChain(usize, usize),
 }
 
 impl From<CRHError> for Error {
     fn from(error: CRHError) -> Error {
-        Error::new(ErrorKind::Other, error.to_string())
+        Error::new(ErrorKind::Other, error)
     }
 }
@@ -17,6 +17,7 @@
 use std::io::{Error, ErrorKind};
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum EncodingError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
@@ -27,18 +28,15 @@ pub enum EncodingError {
     #[error("Invalid group element")]
     InvalidGroupElement,
 
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] Error),
+
     #[error("{}", _0)]
     Message(String),
 }
 
-impl From<Error> for EncodingError {
-    fn from(error: Error) -> Self {
-        EncodingError::Crate("std::io", format!("{:?}", error))
-    }
-}
-
 impl From<EncodingError> for Error {
     fn from(error: EncodingError) -> Error {
-        Error::new(ErrorKind::Other, error.to_string())
+        Error::new(ErrorKind::Other, error)
     }
 }
@@ -15,10 +15,14 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum EncryptionError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] std::io::Error),
+
     #[error("Missing inverse for group element")]
     MissingInverse,
 
@@ -26,14 +30,8 @@ pub enum EncryptionError {
     Message(String),
 }
 
-impl From<std::io::Error> for EncryptionError {
-    fn from(error: std::io::Error) -> Self {
-        EncryptionError::Crate("std::io", format!("{:?}", error))
-    }
-}
-
 impl From<EncryptionError> for std::io::Error {
     fn from(error: EncryptionError) -> Self {
-        std::io::Error::new(std::io::ErrorKind::Other, format!("{}", error))
+        std::io::Error::new(std::io::ErrorKind::Other, error)
     }
 }
@@ -15,12 +15,16 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum MerkleError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
     #[error("{}", _0)]
-    CRHError(crate::CRHError),
+    CRHError(#[from] crate::CRHError),
+
+    #[error("Existing leaf: {}", _0)]
+    ExistingLeaf(String),
 
     #[error("Incorrect leaf index: {}", _0)]
     IncorrectLeafIndex(usize),
@@ -37,18 +41,9 @@ pub enum MerkleError {
     #[error("Invalid tree depth: {}. Must be less than or equal to: {}", _0, _1)]
     InvalidTreeDepth(usize, usize),
 
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] std::io::Error),
+
     #[error("{}", _0)]
     Message(String),
 }
-
-impl From<crate::CRHError> for MerkleError {
-    fn from(error: crate::CRHError) -> Self {
-        MerkleError::CRHError(error)
-    }
-}
-
-impl From<std::io::Error> for MerkleError {
-    fn from(error: std::io::Error) -> Self {
-        MerkleError::Crate("std::io", format!("{:?}", error))
-    }
-}
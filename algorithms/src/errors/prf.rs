@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum PRFError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
@@ -17,31 +17,23 @@
 use std::io::{Error, ErrorKind};
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum SignatureError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
     #[error("{}", _0)]
-    EncryptionError(crate::EncryptionError),
+    EncryptionError(#[from] crate::EncryptionError),
+
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] Error),
 
     #[error("{}", _0)]
     Message(String),
 }
 
-impl From<crate::EncryptionError> for SignatureError {
-    fn from(error: crate::EncryptionError) -> Self {
-        SignatureError::EncryptionError(error)
-    }
-}
-
-impl From<Error> for SignatureError {
-    fn from(error: Error) -> Self {
-        SignatureError::Crate("std::io", format!("{:?}", error))
-    }
-}
-
 impl From<SignatureError> for Error {
     fn from(error: SignatureError) -> Error {
-        Error::new(ErrorKind::Other, error.to_string())
+        Error::new(ErrorKind::Other, error)
     }
 }
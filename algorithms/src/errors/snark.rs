@@ -18,28 +18,20 @@ use snarkvm_fields::ConstraintFieldError;
 use snarkvm_r1cs::SynthesisError;
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum SNARKError {
     #[error("{}", _0)]
-    ConstraintFieldError(ConstraintFieldError),
+    ConstraintFieldError(#[from] ConstraintFieldError),
 
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] std::io::Error),
+
     #[error("{}", _0)]
     Message(String),
 
     #[error("{}", _0)]
-    SynthesisError(SynthesisError),
-}
-
-impl From<ConstraintFieldError> for SNARKError {
-    fn from(error: ConstraintFieldError) -> Self {
-        SNARKError::ConstraintFieldError(error)
-    }
-}
-
-impl From<SynthesisError> for SNARKError {
-    fn from(error: SynthesisError) -> Self {
-        SNARKError::SynthesisError(error)
-    }
+    SynthesisError(#[from] SynthesisError),
 }
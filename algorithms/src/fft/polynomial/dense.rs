@@ -267,17 +267,22 @@ impl<F: Field> Neg for DensePolynomial<F> {
     }
 }
 
+impl<F: Field> Neg for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    #[inline]
+    fn neg(self) -> DensePolynomial<F> {
+        DensePolynomial::from_coefficients_vec(self.coeffs.iter().map(|coeff| -*coeff).collect())
+    }
+}
+
 impl<'a, 'b, F: Field> Sub<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
     type Output = DensePolynomial<F>;
 
     #[inline]
     fn sub(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
         if self.is_zero() {
-            let mut result = other.clone();
-            for coeff in &mut result.coeffs {
-                *coeff = -(*coeff);
-            }
-            result
+            -other
         } else if other.is_zero() {
             self.clone()
         } else if self.degree() >= other.degree() {
@@ -73,6 +73,20 @@ pub mod snark;
 pub mod traits;
 pub use traits::*;
 
+/// Whether `snarkvm-algorithms` was compiled with the `parallel` feature.
+pub const PARALLEL_ENABLED: bool = cfg!(feature = "parallel");
+
+/// Registers `snarkvm-algorithms` and the crates it depends on.
+pub fn register_capabilities(capabilities: &mut snarkvm_utilities::Capabilities) {
+    capabilities.register(snarkvm_utilities::CrateCapabilities::new("snarkvm-algorithms", &[
+        ("parallel", PARALLEL_ENABLED),
+        ("wasm", cfg!(feature = "wasm")),
+        ("cuda", cfg!(feature = "cuda")),
+        ("print-trace", cfg!(feature = "print-trace")),
+    ]));
+    snarkvm_r1cs::register_capabilities(capabilities);
+}
+
 pub mod prelude {
     pub use crate::{errors::*, traits::*};
 }
@@ -37,6 +37,12 @@ pub struct MerkleTree<P: MerkleParameters> {
     /// keeps both the roots the siblings that are used to get to the desired depth.
     padding_tree: Vec<(MerkleTreeDigest<P>, MerkleTreeDigest<P>)>,
 
+    /// The number of leaves that have been inserted into the Merkle tree.
+    leaves_count: usize,
+
+    /// The hashes of empty subtrees, indexed by their height, computed once from the parameters.
+    empty_hashes: Arc<Vec<MerkleTreeDigest<P>>>,
+
     /// The Merkle tree parameters (e.g. the hash function).
     parameters: Arc<P>,
 }
@@ -124,19 +130,9 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
 
         // Finished computing actual tree.
         // Now, we compute the dummy nodes until we hit our DEPTH goal.
-        let mut current_depth = tree_depth;
-        let mut padding_tree = Vec::with_capacity((Self::DEPTH as usize).saturating_sub(current_depth + 1));
-        let mut current_hash = tree[0].clone();
-        while current_depth < Self::DEPTH as usize {
-            current_hash = parameters.hash_inner_node(&current_hash, &empty_hash, &mut buffer)?;
+        let (padding_tree, root_hash) = Self::compute_padding(&*parameters, &tree[0], tree_depth, &mut buffer)?;
 
-            // do not pad at the top-level of the tree
-            if current_depth < Self::DEPTH as usize - 1 {
-                padding_tree.push((current_hash.clone(), empty_hash.clone()));
-            }
-            current_depth += 1;
-        }
-        let root_hash = current_hash;
+        let empty_hashes = Arc::new(Self::compute_empty_hashes(&*parameters, &mut buffer)?);
 
         end_timer!(new_time);
 
@@ -144,6 +140,8 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
             tree,
             padding_tree,
             hashed_leaves_index: last_level_index,
+            leaves_count: leaves.len(),
+            empty_hashes,
             parameters,
             root: Some(root_hash),
         })
@@ -189,9 +187,10 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
         // The new leaves require hashing.
         let subsections = Self::hash_row(&*self.parameters, new_leaves)?;
 
-        for (i, subsection) in subsections.into_iter().enumerate() {
-            tree[last_level_index + old_leaves.len() + i..last_level_index + old_leaves.len() + i + subsection.len()]
-                .copy_from_slice(&subsection[..]);
+        let mut subsection_index = last_level_index + old_leaves.len();
+        for subsection in subsections.into_iter() {
+            tree[subsection_index..subsection_index + subsection.len()].copy_from_slice(&subsection[..]);
+            subsection_index += subsection.len();
         }
 
         // Compute the hash values for every node in the tree.
@@ -261,6 +260,8 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
             root: Some(root_hash),
             tree,
             hashed_leaves_index: last_level_index,
+            leaves_count: old_leaves.len() + new_leaves.len(),
+            empty_hashes: self.empty_hashes.clone(),
             padding_tree: if let Some(padding_tree) = new_padding_tree {
                 padding_tree
             } else {
@@ -270,6 +271,85 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
         })
     }
 
+    /// Appends a leaf to the Merkle tree and returns the new root, hashing only the
+    /// path from the new leaf to the root. The last level of the tree is doubled in size
+    /// when it is full, in which case the added subtree is filled with empty hashes.
+    pub fn insert<L: ToBytes>(&mut self, leaf: &L) -> Result<MerkleTreeDigest<P>, MerkleError> {
+        let insert_time = start_timer!(|| "MerkleTree::insert");
+
+        let hash_input_size_in_bytes = (P::H::INPUT_SIZE_BITS / 8) * 2;
+        let mut buffer = vec![0u8; hash_input_size_in_bytes];
+
+        let index = self.leaves_count;
+        let leaf_hash = self.parameters.hash_leaf(leaf, &mut buffer)?;
+
+        let mut tree_depth = tree_depth(self.tree.len());
+
+        // Double the size of the last level if it is full.
+        let grown_tree = if index >= 1 << tree_depth {
+            if tree_depth + 1 > Self::DEPTH as usize {
+                return Err(MerkleError::InvalidTreeDepth(tree_depth + 1, Self::DEPTH as usize));
+            }
+
+            let grown_tree = self.grow()?;
+            tree_depth += 1;
+            Some(grown_tree)
+        } else {
+            None
+        };
+
+        let tree = grown_tree.as_ref().unwrap_or(&self.tree);
+        let tree_index = convert_index_to_last_level(index, tree_depth);
+        let (path, padding_tree, root_hash) =
+            self.compute_path(tree, tree_index, leaf_hash, tree_depth, &mut buffer)?;
+
+        // Update the values at the very end so the tree is not altered in case of failure.
+        if let Some(grown_tree) = grown_tree {
+            self.tree = grown_tree;
+            self.hashed_leaves_index = convert_index_to_last_level(0, tree_depth);
+        }
+        for (node_index, hash) in path {
+            self.tree[node_index] = hash;
+        }
+        self.padding_tree = padding_tree;
+        self.root = Some(root_hash);
+        self.leaves_count += 1;
+
+        end_timer!(insert_time);
+
+        Ok(root_hash)
+    }
+
+    /// Replaces the leaf at the given index and returns the new root, hashing only the
+    /// path from the updated leaf to the root.
+    pub fn update<L: ToBytes>(&mut self, index: usize, new_leaf: &L) -> Result<MerkleTreeDigest<P>, MerkleError> {
+        let update_time = start_timer!(|| "MerkleTree::update");
+
+        if index >= self.leaves_count {
+            return Err(MerkleError::IncorrectLeafIndex(index));
+        }
+
+        let hash_input_size_in_bytes = (P::H::INPUT_SIZE_BITS / 8) * 2;
+        let mut buffer = vec![0u8; hash_input_size_in_bytes];
+
+        let leaf_hash = self.parameters.hash_leaf(new_leaf, &mut buffer)?;
+
+        let tree_depth = tree_depth(self.tree.len());
+        let tree_index = convert_index_to_last_level(index, tree_depth);
+        let (path, padding_tree, root_hash) =
+            self.compute_path(&self.tree, tree_index, leaf_hash, tree_depth, &mut buffer)?;
+
+        for (node_index, hash) in path {
+            self.tree[node_index] = hash;
+        }
+        self.padding_tree = padding_tree;
+        self.root = Some(root_hash);
+
+        end_timer!(update_time);
+
+        Ok(root_hash)
+    }
+
     #[inline]
     pub fn root(&self) -> <P::H as CRH>::Output {
         self.root.clone().unwrap()
@@ -285,6 +365,11 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
         &self.tree[self.hashed_leaves_index..]
     }
 
+    #[inline]
+    pub fn parameters(&self) -> &Arc<P> {
+        &self.parameters
+    }
+
     pub fn generate_proof<L: ToBytes>(&self, index: usize, leaf: &L) -> Result<MerklePath<P>, MerkleError> {
         let prove_time = start_timer!(|| "MerkleTree::generate_proof");
         let mut path = vec![];
@@ -339,6 +424,103 @@ impl<P: MerkleParameters + Send + Sync> MerkleTree<P> {
             })
         }
     }
+
+    /// Returns the hashes of empty subtrees of height `0` up to `DEPTH - 1`.
+    fn compute_empty_hashes(parameters: &P, buffer: &mut [u8]) -> Result<Vec<MerkleTreeDigest<P>>, MerkleError> {
+        let mut empty_hashes = Vec::with_capacity(Self::DEPTH as usize);
+        let mut current_hash = parameters.hash_empty()?;
+        for _ in 0..Self::DEPTH {
+            let next_hash = parameters.hash_inner_node(&current_hash, &current_hash, buffer)?;
+            empty_hashes.push(current_hash);
+            current_hash = next_hash;
+        }
+        Ok(empty_hashes)
+    }
+
+    /// Returns the padding tree and the root, given the root of the actual tree and its depth.
+    #[allow(clippy::type_complexity)]
+    fn compute_padding(
+        parameters: &P,
+        tree_root: &MerkleTreeDigest<P>,
+        tree_depth: usize,
+        buffer: &mut [u8],
+    ) -> Result<(Vec<(MerkleTreeDigest<P>, MerkleTreeDigest<P>)>, MerkleTreeDigest<P>), MerkleError> {
+        let empty_hash = parameters.hash_empty()?;
+
+        let mut current_depth = tree_depth;
+        let mut padding_tree = Vec::with_capacity((Self::DEPTH as usize).saturating_sub(current_depth + 1));
+        let mut current_hash = *tree_root;
+        while current_depth < Self::DEPTH as usize {
+            current_hash = parameters.hash_inner_node(&current_hash, &empty_hash, buffer)?;
+
+            // do not pad at the top-level of the tree
+            if current_depth < Self::DEPTH as usize - 1 {
+                padding_tree.push((current_hash, empty_hash));
+            }
+            current_depth += 1;
+        }
+
+        Ok((padding_tree, current_hash))
+    }
+
+    /// Returns the tree with its last level doubled in size. The current tree becomes the
+    /// left subtree of the new root, and the right subtree is filled with empty hashes.
+    /// The new root is left as-is, as it is recomputed when a leaf is set.
+    fn grow(&self) -> Result<Vec<MerkleTreeDigest<P>>, MerkleError> {
+        let tree_depth = tree_depth(self.tree.len());
+        if self.empty_hashes.len() <= tree_depth {
+            return Err(MerkleError::InvalidTreeDepth(tree_depth + 1, self.empty_hashes.len()));
+        }
+
+        let mut tree = Vec::with_capacity(2 * self.tree.len() + 1);
+        tree.push(self.tree[0]);
+        for level in 0..=tree_depth {
+            let level_index = convert_index_to_last_level(0, level);
+            tree.extend_from_slice(&self.tree[level_index..][..1 << level]);
+            tree.resize(tree.len() + (1 << level), self.empty_hashes[tree_depth - level]);
+        }
+        Ok(tree)
+    }
+
+    /// Returns the updated hashes from the given leaf to the root of the tree,
+    /// followed by the updated padding tree and the new root.
+    #[allow(clippy::type_complexity)]
+    fn compute_path(
+        &self,
+        tree: &[MerkleTreeDigest<P>],
+        tree_index: usize,
+        leaf_hash: MerkleTreeDigest<P>,
+        tree_depth: usize,
+        buffer: &mut [u8],
+    ) -> Result<
+        (
+            Vec<(usize, MerkleTreeDigest<P>)>,
+            Vec<(MerkleTreeDigest<P>, MerkleTreeDigest<P>)>,
+            MerkleTreeDigest<P>,
+        ),
+        MerkleError,
+    > {
+        let mut path = Vec::with_capacity(tree_depth + 1);
+
+        let mut current_node = tree_index;
+        let mut current_hash = leaf_hash;
+        while let Some(parent_node) = parent(current_node) {
+            let sibling_hash = &tree[sibling(current_node).unwrap()];
+            let parent_hash = if is_left_child(current_node) {
+                self.parameters.hash_inner_node(&current_hash, sibling_hash, buffer)?
+            } else {
+                self.parameters.hash_inner_node(sibling_hash, &current_hash, buffer)?
+            };
+            path.push((current_node, current_hash));
+            current_node = parent_node;
+            current_hash = parent_hash;
+        }
+
+        let (padding_tree, root_hash) = Self::compute_padding(&*self.parameters, &current_hash, tree_depth, buffer)?;
+        path.push((current_node, current_hash));
+
+        Ok((path, padding_tree, root_hash))
+    }
 }
 
 /// Returns the depth of the tree, given the size of the tree.
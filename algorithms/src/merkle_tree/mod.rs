@@ -22,6 +22,9 @@ pub use merkle_path::*;
 pub mod merkle_tree;
 pub use merkle_tree::*;
 
+pub mod sorted_merkle_tree;
+pub use sorted_merkle_tree::*;
+
 #[cfg(test)]
 pub mod tests;
 
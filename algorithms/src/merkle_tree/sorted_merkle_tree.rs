@@ -0,0 +1,228 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::MerkleError,
+    merkle_tree::{MerklePath, MerkleTree, MerkleTreeDigest},
+    traits::{MerkleParameters, CRH},
+};
+use snarkvm_utilities::ToBytes;
+use std::{fmt::Display, sync::Arc};
+
+/// A leaf of a `SortedMerkleTree`, which is totally ordered and bounded by two sentinels.
+pub trait SortedLeaf: Clone + Display + Ord + ToBytes + Send + Sync {
+    /// The sentinel preceding every other leaf.
+    const MIN_LEAF: Self;
+    /// The sentinel following every other leaf.
+    const MAX_LEAF: Self;
+}
+
+impl SortedLeaf for u64 {
+    const MAX_LEAF: Self = u64::MAX;
+    const MIN_LEAF: Self = u64::MIN;
+}
+
+/// A Merkle tree over distinct values, stored as leaves in ascending order.
+///
+/// The leaves are bounded by the sentinels `MIN_LEAF` and `MAX_LEAF`, so any value that is not
+/// a leaf lies strictly between two adjacent leaves. This includes values outside the range of
+/// the inserted values, and any value of an empty tree. The sentinels themselves cannot be inserted.
+pub struct SortedMerkleTree<P: MerkleParameters, L: SortedLeaf = u64> {
+    /// The Merkle tree over the sorted leaves.
+    tree: MerkleTree<P>,
+
+    /// The sorted leaves, including the sentinels.
+    leaves: Vec<L>,
+}
+
+impl<P: MerkleParameters + Send + Sync, L: SortedLeaf> SortedMerkleTree<P, L> {
+    pub const MAX_LEAF: L = L::MAX_LEAF;
+    pub const MIN_LEAF: L = L::MIN_LEAF;
+
+    pub fn new(parameters: Arc<P>, values: &[L]) -> Result<Self, MerkleError> {
+        if values
+            .iter()
+            .any(|value| *value == Self::MIN_LEAF || *value == Self::MAX_LEAF)
+        {
+            return Err(MerkleError::InvalidLeaf);
+        }
+
+        let mut leaves = Vec::with_capacity(values.len() + 2);
+        leaves.push(Self::MIN_LEAF);
+        leaves.extend_from_slice(values);
+        leaves.push(Self::MAX_LEAF);
+        leaves.sort_unstable();
+        leaves.dedup();
+
+        let tree = MerkleTree::new(parameters, &leaves)?;
+
+        Ok(Self { tree, leaves })
+    }
+
+    #[inline]
+    pub fn root(&self) -> MerkleTreeDigest<P> {
+        self.tree.root()
+    }
+
+    /// Returns the sorted leaves of the tree, including the sentinels.
+    #[inline]
+    pub fn leaves(&self) -> &[L] {
+        &self.leaves
+    }
+
+    #[inline]
+    pub fn contains(&self, value: L) -> bool {
+        self.leaves.binary_search(&value).is_ok()
+    }
+
+    /// Inserts the given values into the tree, and returns the new root.
+    ///
+    /// Inserting a value shifts every larger leaf by one position, so the tree is rebuilt from the merged leaves.
+    pub fn insert(&mut self, values: &[L]) -> Result<MerkleTreeDigest<P>, MerkleError> {
+        let mut values = values.to_vec();
+        values.sort_unstable();
+        values.dedup();
+
+        for value in &values {
+            if *value == Self::MIN_LEAF || *value == Self::MAX_LEAF {
+                return Err(MerkleError::InvalidLeaf);
+            }
+            if self.leaves.binary_search(value).is_ok() {
+                return Err(MerkleError::ExistingLeaf(value.to_string()));
+            }
+        }
+
+        let mut leaves = Vec::with_capacity(self.leaves.len() + values.len());
+        let mut values = values.into_iter().peekable();
+        for leaf in self.leaves.drain(..) {
+            while let Some(value) = values.next_if(|value| *value < leaf) {
+                leaves.push(value);
+            }
+            leaves.push(leaf);
+        }
+
+        self.tree = MerkleTree::new(self.tree.parameters().clone(), &leaves)?;
+        self.leaves = leaves;
+
+        Ok(self.tree.root())
+    }
+
+    pub fn prove_membership(&self, value: L) -> Result<MerklePath<P>, MerkleError> {
+        match self.leaves.binary_search(&value) {
+            Ok(index) => self.tree.generate_proof(index, &value),
+            Err(_) => Err(MerkleError::InvalidLeaf),
+        }
+    }
+
+    /// Returns a proof that the given value is not a leaf of the tree, consisting of
+    /// the two adjacent leaves surrounding the value and their Merkle paths.
+    pub fn prove_non_membership(&self, value: L) -> Result<NonMembershipProof<P, L>, MerkleError> {
+        // As the sentinels are leaves, a value that is not a leaf is always preceded and followed by one.
+        let index = match self.leaves.binary_search(&value) {
+            Ok(_) => return Err(MerkleError::ExistingLeaf(value.to_string())),
+            Err(index) => index,
+        };
+
+        let left_leaf = self.leaves[index - 1].clone();
+        let right_leaf = self.leaves[index].clone();
+
+        Ok(NonMembershipProof {
+            left_path: self.tree.generate_proof(index - 1, &left_leaf)?,
+            left_leaf,
+            right_path: self.tree.generate_proof(index, &right_leaf)?,
+            right_leaf,
+        })
+    }
+}
+
+/// Proves that a value is not a leaf of a `SortedMerkleTree`, by showing that
+/// two adjacent leaves of the tree are respectively smaller and larger than the value.
+#[derive(Clone, Debug)]
+pub struct NonMembershipProof<P: MerkleParameters, L: SortedLeaf = u64> {
+    pub left_leaf: L,
+    pub left_path: MerklePath<P>,
+    pub right_leaf: L,
+    pub right_path: MerklePath<P>,
+}
+
+impl<P: MerkleParameters, L: SortedLeaf> NonMembershipProof<P, L> {
+    pub fn verify(&self, root_hash: &MerkleTreeDigest<P>, value: L) -> Result<bool, MerkleError> {
+        // Check that the value lies strictly between the two leaves.
+        if !(self.left_leaf < value && value < self.right_leaf) {
+            return Ok(false);
+        }
+
+        let left_position = match verify_with_position(&self.left_path, root_hash, &self.left_leaf)? {
+            Some(position) => position,
+            None => return Ok(false),
+        };
+        let right_position = match verify_with_position(&self.right_path, root_hash, &self.right_leaf)? {
+            Some(position) => position,
+            None => return Ok(false),
+        };
+
+        // Check that the right leaf immediately follows the left leaf.
+        Ok(is_successor(&left_position, &right_position))
+    }
+}
+
+/// Verifies the Merkle path of the given leaf, and returns the position of the leaf in
+/// the tree as little-endian bits, where a set bit denotes a right child.
+fn verify_with_position<P: MerkleParameters, L: ToBytes>(
+    path: &MerklePath<P>,
+    root_hash: &MerkleTreeDigest<P>,
+    leaf: &L,
+) -> Result<Option<Vec<bool>>, MerkleError> {
+    if path.path.len() != P::DEPTH || path.path.is_empty() {
+        return Ok(None);
+    }
+
+    let hash_input_size_in_bytes = (P::H::INPUT_SIZE_BITS / 8) * 2;
+    let mut buffer = vec![0u8; hash_input_size_in_bytes];
+
+    let mut position = Vec::with_capacity(P::DEPTH);
+    let mut previous_hash = path.parameters.hash_leaf::<L>(leaf, &mut buffer)?;
+    for (hash, sibling_hash) in &path.path {
+        if &previous_hash == hash {
+            position.push(false);
+        } else if &previous_hash == sibling_hash {
+            position.push(true);
+        } else {
+            return Ok(None);
+        }
+        previous_hash = path.parameters.hash_inner_node(hash, sibling_hash, &mut buffer)?;
+    }
+
+    if root_hash != &previous_hash {
+        return Ok(None);
+    }
+
+    Ok(Some(position))
+}
+
+/// Returns true iff the position `right` is the position `left` incremented by one.
+fn is_successor(left: &[bool], right: &[bool]) -> bool {
+    // Incrementing clears the trailing set bits and sets the lowest unset bit.
+    let carry = match left.iter().position(|&bit| !bit) {
+        Some(carry) => carry,
+        None => return false,
+    };
+
+    left.len() == right.len()
+        && right[..carry].iter().all(|&bit| !bit)
+        && right[carry]
+        && left[carry + 1..] == right[carry + 1..]
+}
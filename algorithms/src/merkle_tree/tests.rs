@@ -19,11 +19,15 @@ use std::sync::Arc;
 use crate::{
     crh::{PedersenCRH, PedersenCompressedCRH, PedersenSize},
     define_merkle_tree_parameters,
-    merkle_tree::MerkleTree,
+    errors::MerkleError,
+    merkle_tree::{MerkleTree, NonMembershipProof, SortedMerkleTree},
     traits::{crh::CRH, merkle_tree::LoadableMerkleParameters},
 };
 use snarkvm_utilities::{to_bytes, ToBytes};
 
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
 /// Generates a valid Merkle tree and verifies the Merkle path witness for each leaf.
 fn generate_merkle_tree<P: LoadableMerkleParameters, L: ToBytes + Send + Sync + Clone + Eq>(
     leaves: &[L],
@@ -169,6 +173,199 @@ fn run_padded_merkle_tree_matches_hashing_test<P: LoadableMerkleParameters>() {
     assert_eq!(merkle_tree_root, expected_root);
 }
 
+fn run_rebuild_matches_new_test<P: LoadableMerkleParameters>() {
+    let parameters = Arc::new(P::default());
+
+    // Use enough leaves for the new leaves to be hashed in several chunks.
+    let leaves = (0..1100u16)
+        .map(|i| {
+            let [a, b] = i.to_le_bytes();
+            [a, b, a, b, a, b, a, b]
+        })
+        .collect::<Vec<_>>();
+
+    let tree = MerkleTree::<P>::new(parameters.clone(), &leaves[..100]).unwrap();
+
+    // Appending leaves results in the same tree as building it from scratch.
+    let rebuilt_tree = tree.rebuild(leaves[..100].iter().cloned(), &leaves[100..]).unwrap();
+    let expected_tree = MerkleTree::<P>::new(parameters.clone(), &leaves).unwrap();
+    assert_eq!(expected_tree.root(), rebuilt_tree.root());
+    assert_eq!(expected_tree.tree(), rebuilt_tree.tree());
+
+    // Dropping the trailing leaves restores the original tree.
+    let truncated_tree = rebuilt_tree.rebuild(leaves[..100].iter().cloned(), &[]).unwrap();
+    assert_eq!(tree.root(), truncated_tree.root());
+    assert_eq!(tree.tree(), truncated_tree.tree());
+}
+
+fn run_insert_and_update_match_new_test<P: LoadableMerkleParameters>() {
+    let parameters = Arc::new(P::default());
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..3 {
+        let mut leaves: Vec<[u8; 8]> = vec![];
+        let mut tree = MerkleTree::<P>::new(parameters.clone(), &leaves).unwrap();
+
+        // Randomly interleave insertions and updates, and check against a tree built from scratch.
+        let num_operations = rng.gen_range(20..40);
+        for _ in 0..num_operations {
+            let leaf: [u8; 8] = rng.gen();
+            let root = if !leaves.is_empty() && rng.gen_bool(0.3) {
+                let index = rng.gen_range(0..leaves.len());
+                leaves[index] = leaf;
+                tree.update(index, &leaf).unwrap()
+            } else {
+                leaves.push(leaf);
+                tree.insert(&leaf).unwrap()
+            };
+
+            let expected_tree = MerkleTree::<P>::new(parameters.clone(), &leaves).unwrap();
+            assert_eq!(expected_tree.root(), root);
+            assert_eq!(expected_tree.root(), tree.root());
+            assert_eq!(expected_tree.tree(), tree.tree());
+        }
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            let proof = tree.generate_proof(i, leaf).unwrap();
+            assert_eq!(P::DEPTH, proof.path.len());
+            assert!(proof.verify(&tree.root(), leaf).unwrap());
+        }
+
+        // Updating a leaf that has not been inserted fails.
+        assert!(tree.update(leaves.len(), &[0u8; 8]).is_err());
+    }
+}
+
+fn run_insert_into_full_tree_test<P: LoadableMerkleParameters>() {
+    let parameters = Arc::new(P::default());
+
+    let leaves = (0..1u64 << P::DEPTH).map(|i| i.to_le_bytes()).collect::<Vec<_>>();
+    let mut tree = MerkleTree::<P>::new(parameters.clone(), &leaves[..1]).unwrap();
+    for leaf in &leaves[1..] {
+        tree.insert(leaf).unwrap();
+    }
+
+    // The tree is unchanged by an insertion that would exceed its depth.
+    let root = tree.root();
+    assert!(tree.insert(&[0u8; 8]).is_err());
+    assert_eq!(root, tree.root());
+    assert_eq!(MerkleTree::<P>::new(parameters, &leaves).unwrap().tree(), tree.tree());
+}
+
+fn run_sorted_merkle_tree_non_membership_test<P: LoadableMerkleParameters>() {
+    let parameters = Arc::new(P::default());
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // Test an empty tree, a full tree, and trees with a random number of values.
+    let max_num_values = (1 << P::DEPTH) - 2;
+    let mut tree_sizes = vec![0, 1, max_num_values];
+    tree_sizes.extend((0..5).map(|_| rng.gen_range(2..max_num_values)));
+
+    for num_values in tree_sizes {
+        // Keep 1 and u64::MAX - 1 free, to test values outside the range of the leaves.
+        let values = (0..num_values)
+            .map(|_| rng.gen_range(2..u64::MAX - 1))
+            .collect::<Vec<_>>();
+
+        let tree = SortedMerkleTree::new(parameters.clone(), &values).unwrap();
+        let root = tree.root();
+
+        let mut non_members = vec![1, u64::MAX - 1];
+        non_members.extend((0..10).map(|_| rng.gen_range(1..u64::MAX)));
+        for value in non_members {
+            let proof = tree.prove_non_membership(value).unwrap();
+            assert!(proof.verify(&root, value).unwrap());
+            assert!(!proof.verify(&<P::H as CRH>::Output::default(), value).unwrap());
+        }
+
+        // The leaves, including the sentinels, cannot be proven to be non-members.
+        for &leaf in tree.leaves() {
+            assert!(tree.contains(leaf));
+            assert!(matches!(
+                tree.prove_non_membership(leaf),
+                Err(MerkleError::ExistingLeaf(value)) if value == leaf.to_string()
+            ));
+        }
+    }
+
+    // The sentinels cannot be inserted in the tree.
+    assert!(SortedMerkleTree::new(parameters.clone(), &[u64::MIN]).is_err());
+    assert!(SortedMerkleTree::new(parameters, &[u64::MAX]).is_err());
+}
+
+fn run_sorted_merkle_tree_adversarial_test<P: LoadableMerkleParameters>() {
+    let parameters = Arc::new(P::default());
+
+    let tree = SortedMerkleTree::new(parameters, &[50, 10, 40, 20, 30]).unwrap();
+    let root = tree.root();
+    assert_eq!(tree.leaves(), &[u64::MIN, 10, 20, 30, 40, 50, u64::MAX]);
+
+    // Builds a proof from any two leaves of the tree.
+    let proof = |left_leaf: u64, right_leaf: u64| NonMembershipProof {
+        left_leaf,
+        left_path: tree.prove_membership(left_leaf).unwrap(),
+        right_leaf,
+        right_path: tree.prove_membership(right_leaf).unwrap(),
+    };
+
+    assert!(proof(20, 30).verify(&root, 25).unwrap());
+
+    // Non-adjacent leaves are rejected, even when they surround a non-member.
+    assert!(!proof(10, 30).verify(&root, 20).unwrap());
+    assert!(!proof(10, 30).verify(&root, 25).unwrap());
+    assert!(!proof(u64::MIN, u64::MAX).verify(&root, 25).unwrap());
+
+    // Leaves in the wrong order are rejected.
+    assert!(!proof(30, 20).verify(&root, 25).unwrap());
+    assert!(!proof(u64::MAX, u64::MIN).verify(&root, 25).unwrap());
+
+    // The value must lie strictly between the leaves.
+    assert!(!proof(20, 30).verify(&root, 20).unwrap());
+    assert!(!proof(20, 30).verify(&root, 30).unwrap());
+    assert!(!proof(20, 30).verify(&root, 35).unwrap());
+
+    // The paths must belong to the given leaves.
+    let mut bad_proof = proof(20, 30);
+    bad_proof.left_path = tree.prove_membership(10).unwrap();
+    assert!(!bad_proof.verify(&root, 25).unwrap());
+
+    let mut bad_proof = proof(20, 30);
+    bad_proof.left_leaf = 21;
+    assert!(!bad_proof.verify(&root, 25).unwrap());
+}
+
+fn run_sorted_merkle_tree_insert_test<P: LoadableMerkleParameters>() {
+    let parameters = Arc::new(P::default());
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let values = (0..12).map(|_| rng.gen_range(1..u64::MAX)).collect::<Vec<_>>();
+
+    // Inserting the values in batches yields the same tree as inserting them all at once.
+    let mut tree = SortedMerkleTree::new(parameters.clone(), &values[..2]).unwrap();
+    for batch in values[2..].chunks(5) {
+        let root = tree.insert(batch).unwrap();
+        assert_eq!(root, tree.root());
+    }
+
+    let expected_tree = SortedMerkleTree::new(parameters, &values).unwrap();
+    assert_eq!(tree.leaves(), expected_tree.leaves());
+    assert_eq!(tree.root(), expected_tree.root());
+
+    for value in (0..10).map(|_| rng.gen_range(1..u64::MAX)) {
+        let proof = tree.prove_non_membership(value).unwrap();
+        assert!(proof.verify(&expected_tree.root(), value).unwrap());
+    }
+
+    // Existing leaves and sentinels cannot be inserted, and leave the tree unchanged.
+    assert!(matches!(
+        tree.insert(&[values[0]]),
+        Err(MerkleError::ExistingLeaf(value)) if value == values[0].to_string()
+    ));
+    assert!(tree.insert(&[u64::MIN]).is_err());
+    assert!(tree.insert(&[u64::MAX]).is_err());
+    assert_eq!(tree.root(), expected_tree.root());
+}
+
 mod pedersen_crh_on_affine {
     use super::*;
     use snarkvm_curves::edwards_bls12::EdwardsAffine as Edwards;
@@ -300,4 +497,46 @@ mod pedersen_compressed_crh_on_projective {
         define_merkle_tree_parameters!(MTParameters, PedersenCompressedCRH<Edwards, Size>, 3);
         run_padded_merkle_tree_matches_hashing_test::<MTParameters>();
     }
+
+    #[test]
+    fn rebuild_matches_new_test() {
+        define_merkle_tree_parameters!(MTParameters, PedersenCompressedCRH<Edwards, Size>, 32);
+        run_rebuild_matches_new_test::<MTParameters>();
+    }
+
+    #[test]
+    fn insert_and_update_match_new_test() {
+        define_merkle_tree_parameters!(MTParameters, PedersenCompressedCRH<Edwards, Size>, 32);
+        run_insert_and_update_match_new_test::<MTParameters>();
+    }
+
+    #[test]
+    fn depth5_insert_and_update_match_new_test() {
+        define_merkle_tree_parameters!(MTParameters, PedersenCompressedCRH<Edwards, Size>, 5);
+        run_insert_and_update_match_new_test::<MTParameters>();
+    }
+
+    #[test]
+    fn depth3_insert_into_full_tree_test() {
+        define_merkle_tree_parameters!(MTParameters, PedersenCompressedCRH<Edwards, Size>, 3);
+        run_insert_into_full_tree_test::<MTParameters>();
+    }
+
+    #[test]
+    fn sorted_merkle_tree_non_membership_test() {
+        define_merkle_tree_parameters!(MTParameters, PedersenCompressedCRH<Edwards, Size>, 5);
+        run_sorted_merkle_tree_non_membership_test::<MTParameters>();
+    }
+
+    #[test]
+    fn sorted_merkle_tree_adversarial_test() {
+        define_merkle_tree_parameters!(MTParameters, PedersenCompressedCRH<Edwards, Size>, 32);
+        run_sorted_merkle_tree_adversarial_test::<MTParameters>();
+    }
+
+    #[test]
+    fn sorted_merkle_tree_insert_test() {
+        define_merkle_tree_parameters!(MTParameters, PedersenCompressedCRH<Edwards, Size>, 5);
+        run_sorted_merkle_tree_insert_test::<MTParameters>();
+    }
 }
@@ -21,6 +21,10 @@ use snarkvm_utilities::biginteger::BigInteger;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// The number of scalars multiplied between two calls to the progress callback of
+/// [`FixedBaseMSM::multi_scalar_mul_with_progress`].
+const PROGRESS_CHUNK_SIZE: usize = 1 << 10;
+
 pub struct FixedBaseMSM;
 
 impl FixedBaseMSM {
@@ -60,15 +64,14 @@ impl FixedBaseMSM {
         multiples_of_g: &[Vec<T>],
         scalar: &T::ScalarField,
     ) -> T {
-        let mut scalar_val = scalar.into_repr().to_bits_be();
-        scalar_val.reverse();
+        let scalar_val = scalar.into_repr();
 
         let mut res = multiples_of_g[0][0];
         for outer in 0..outerc {
             let mut inner = 0usize;
             for i in 0..window {
                 if outer * window + i < (<T::ScalarField as PrimeField>::Parameters::MODULUS_BITS as usize)
-                    && scalar_val[outer * window + i]
+                    && scalar_val.get_bit(outer * window + i)
                 {
                     inner |= 1 << i;
                 }
@@ -78,6 +81,16 @@ impl FixedBaseMSM {
         res
     }
 
+    /// Multiplies the base of the given window table by a full-size scalar.
+    /// The table must be computed by `get_window_table` over the bit size of the scalar field.
+    pub fn windowed_scalar_mul<T: ProjectiveCurve>(window: usize, table: &[Vec<T>], scalar: &T::ScalarField) -> T {
+        let scalar_size = <T::ScalarField as PrimeField>::size_in_bits();
+        let outerc = (scalar_size + window - 1) / window;
+        assert!(outerc <= table.len());
+
+        Self::windowed_mul::<T>(outerc, window, table, scalar)
+    }
+
     pub fn multi_scalar_mul<T: ProjectiveCurve>(
         scalar_size: usize,
         window: usize,
@@ -91,4 +104,28 @@ impl FixedBaseMSM {
             .map(|e| Self::windowed_mul::<T>(outerc, window, table, e))
             .collect::<Vec<_>>()
     }
+
+    /// Computes the same result as `multi_scalar_mul`, calling `progress` with the number
+    /// of scalars multiplied after each chunk of the input is done.
+    pub fn multi_scalar_mul_with_progress<T: ProjectiveCurve>(
+        scalar_size: usize,
+        window: usize,
+        table: &[Vec<T>],
+        v: &[T::ScalarField],
+        progress: &(dyn Fn(usize) + Sync),
+    ) -> Vec<T> {
+        let outerc = (scalar_size + window - 1) / window;
+        assert!(outerc <= table.len());
+
+        cfg_chunks!(v, PROGRESS_CHUNK_SIZE)
+            .flat_map(|chunk| {
+                let result = chunk
+                    .iter()
+                    .map(|e| Self::windowed_mul::<T>(outerc, window, table, e))
+                    .collect::<Vec<_>>();
+                progress(chunk.len());
+                result
+            })
+            .collect::<Vec<_>>()
+    }
 }
@@ -22,3 +22,6 @@ pub mod tests;
 
 pub mod variable_base;
 pub use variable_base::*;
+
+pub mod wnaf;
+pub use wnaf::*;
@@ -17,6 +17,7 @@
 use crate::msm::*;
 use snarkvm_curves::{
     bls12_377::{Fr, G1Projective},
+    edwards_bls12::EdwardsProjective,
     traits::{AffineCurve, ProjectiveCurve},
 };
 use snarkvm_fields::{PrimeField, Zero};
@@ -24,6 +25,7 @@ use snarkvm_utilities::{rand::UniformRand, BitIteratorBE};
 
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 fn naive_variable_base_msm<G: AffineCurve>(
     bases: &[G],
@@ -72,3 +74,75 @@ fn variable_base_test_with_bls12_unequal_numbers() {
 
     assert_eq!(naive.into_affine(), fast.into_affine());
 }
+
+#[test]
+fn fixed_base_test_with_progress() {
+    const SAMPLES: usize = (1 << 11) + 3;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let v = (0..SAMPLES).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+    let g = G1Projective::rand(&mut rng);
+
+    let scalar_bits = Fr::size_in_bits();
+    let window = FixedBaseMSM::get_mul_window_size(SAMPLES);
+    let table = FixedBaseMSM::get_window_table(scalar_bits, window, g);
+
+    let counted = AtomicUsize::new(0);
+    let expected = FixedBaseMSM::multi_scalar_mul(scalar_bits, window, &table, &v);
+    let candidate = FixedBaseMSM::multi_scalar_mul_with_progress(scalar_bits, window, &table, &v, &|n| {
+        counted.fetch_add(n, Ordering::Relaxed);
+    });
+
+    assert_eq!(expected, candidate);
+    assert_eq!(SAMPLES, counted.load(Ordering::Relaxed));
+}
+
+fn wnaf_test_scalars<F: PrimeField>(rng: &mut XorShiftRng) -> Vec<F> {
+    let mut scalars = (0..32).map(|_| F::rand(rng)).collect::<Vec<_>>();
+    scalars.extend_from_slice(&[F::zero(), F::one(), -F::one()]);
+    scalars
+}
+
+fn wnaf_test_with_curve<G: ProjectiveCurve>() {
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let base = G::rand(&mut rng);
+    let scalars = wnaf_test_scalars::<G::ScalarField>(&mut rng);
+    let expected = scalars
+        .iter()
+        .map(|scalar| base.into_affine().mul_bits(BitIteratorBE::new(scalar.into_repr())))
+        .collect::<Vec<_>>();
+
+    for window in 2..=8 {
+        let context = WnafContext::new(window);
+        let table = context.precompute(&base);
+        assert_eq!(table.window(), window);
+
+        for (scalar, expected) in scalars.iter().zip(&expected) {
+            assert_eq!(context.mul_with_table(&table, scalar), *expected);
+        }
+    }
+
+    assert_eq!(Wnaf::scalar_mul_many(&base, &scalars), expected);
+    assert!(Wnaf::scalar_mul_many(&base, &[]).is_empty());
+}
+
+#[test]
+fn wnaf_test_with_bls12() {
+    wnaf_test_with_curve::<G1Projective>();
+}
+
+#[test]
+fn wnaf_test_with_edwards() {
+    wnaf_test_with_curve::<EdwardsProjective>();
+}
+
+#[test]
+#[should_panic]
+fn wnaf_test_rejects_table_of_other_window() {
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let table = WnafContext::new(4).precompute(&G1Projective::rand(&mut rng));
+    WnafContext::new(5).mul_with_table(&table, &Fr::rand(&mut rng));
+}
@@ -50,14 +50,14 @@ pub fn msm_standard<G: AffineCurve>(
                 .iter()
                 .zip(bases)
                 .filter(|(s, _)| !s.is_zero())
-                .for_each(|(&scalar, base)| {
-                    if scalar == fr_one {
+                .for_each(|(scalar, base)| {
+                    if *scalar == fr_one {
                         // We only process unit scalars once in the first window.
                         if w_start == 0 {
                             res.add_assign_mixed(base);
                         }
                     } else {
-                        let mut scalar = scalar;
+                        let mut scalar = *scalar;
 
                         // We right-shift by w_start, thus getting rid of the
                         // lower bits.
@@ -70,7 +70,7 @@ pub fn msm_standard<G: AffineCurve>(
                         // bucket.
                         // (Recall that `buckets` doesn't have a zero bucket.)
                         if scalar != 0 {
-                            buckets[(scalar - 1) as usize].add_assign_mixed(&base);
+                            buckets[(scalar - 1) as usize].add_assign_mixed(base);
                         }
                     }
                 });
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::{templates::short_weierstrass::wnaf::WnafDigits, traits::ProjectiveCurve};
+use snarkvm_fields::PrimeField;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A windowed NAF context, for multiplying the same base by many scalars.
+///
+/// The odd multiples of the base are computed once by `precompute`, and every call to
+/// `mul_with_table` then only doubles and adds table entries.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WnafContext {
+    window: usize,
+}
+
+impl WnafContext {
+    /// Returns a wNAF context with the given window size, which must be between 2 and 22.
+    pub fn new(window: usize) -> Self {
+        assert!((2..=22).contains(&window), "wNAF window must be between 2 and 22");
+
+        Self { window }
+    }
+
+    /// Returns the window size of the context.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Returns the table of the odd multiples `base, 3 * base, ..., (2^(window - 1) - 1) * base`.
+    pub fn precompute<G: ProjectiveCurve>(&self, base: &G) -> WnafTable<G> {
+        let mut double = *base;
+        double.double_in_place();
+
+        let mut multiples = Vec::with_capacity(1 << (self.window - 2));
+        let mut multiple = *base;
+        for _ in 0..1 << (self.window - 2) {
+            multiples.push(multiple);
+            multiple += &double;
+        }
+
+        WnafTable {
+            window: self.window,
+            multiples,
+        }
+    }
+
+    /// Multiplies the base of `table` by `scalar`.
+    ///
+    /// The table must be computed by `precompute` with the window size of this context.
+    pub fn mul_with_table<G: ProjectiveCurve>(&self, table: &WnafTable<G>, scalar: &G::ScalarField) -> G {
+        assert_eq!(
+            table.window, self.window,
+            "wNAF table was computed for another window size"
+        );
+
+        let digits = WnafDigits::new(scalar.into_repr(), self.window).collect::<Vec<_>>();
+
+        let mut res = G::zero();
+        for digit in digits.into_iter().rev() {
+            res.double_in_place();
+            if digit > 0 {
+                res += &table.multiples[(digit / 2) as usize];
+            } else if digit < 0 {
+                res -= &table.multiples[(-digit / 2) as usize];
+            }
+        }
+
+        res
+    }
+}
+
+/// The odd multiples of a base, as computed by `WnafContext::precompute`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WnafTable<G: ProjectiveCurve> {
+    window: usize,
+    multiples: Vec<G>,
+}
+
+impl<G: ProjectiveCurve> WnafTable<G> {
+    /// Returns the window size the table was computed for.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+}
+
+pub struct Wnaf;
+
+impl Wnaf {
+    /// Multiplies `base` by every scalar in `scalars`, sharing one wNAF table among all of them.
+    ///
+    /// The window size is chosen by `ProjectiveCurve::recommended_wnaf_for_num_scalars`.
+    pub fn scalar_mul_many<G: ProjectiveCurve>(base: &G, scalars: &[G::ScalarField]) -> Vec<G> {
+        let context = WnafContext::new(G::recommended_wnaf_for_num_scalars(scalars.len()));
+        let table = context.precompute(base);
+
+        cfg_iter!(scalars)
+            .map(|scalar| context.mul_with_table(&table, scalar))
+            .collect()
+    }
+}
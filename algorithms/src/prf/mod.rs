@@ -16,3 +16,9 @@
 
 pub mod blake2s;
 pub use blake2s::*;
+
+pub mod poseidon;
+pub use poseidon::*;
+
+#[cfg(test)]
+mod tests;
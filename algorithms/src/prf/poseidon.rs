@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crh::{PoseidonCRH, PoseidonParameters},
+    errors::PRFError,
+    traits::PRF,
+};
+use snarkvm_fields::{FieldParameters, PoseidonMDSField, PrimeField};
+use snarkvm_utilities::bytes::{FromBytes, ToBytes};
+
+use std::{
+    io::{Read, Result as IoResult, Write},
+    marker::PhantomData,
+};
+
+/// Returns the number of bytes of a serialized field element.
+fn serialized_size<F: PrimeField>() -> usize {
+    (<F as PrimeField>::Parameters::MODULUS_BITS as usize + 63) / 64 * 8
+}
+
+/// Splits the bytes into chunks of the size of a serialized field element,
+/// and reads each chunk modulo the order of the field.
+pub fn bytes_to_field_elements_mod_order<F: PrimeField>(bytes: &[u8]) -> Vec<F> {
+    bytes
+        .chunks(serialized_size::<F>())
+        .map(F::from_bytes_unchecked_reduce)
+        .collect()
+}
+
+/// The seed of the Poseidon PRF. It is read from a serialized field element modulo the order of
+/// the field, so that any bytes of that size, such as a Blake2s output, are a valid seed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PoseidonPRFSeed<F: PrimeField>(pub F);
+
+impl<F: PrimeField> ToBytes for PoseidonPRFSeed<F> {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write(writer)
+    }
+}
+
+impl<F: PrimeField> FromBytes for PoseidonPRFSeed<F> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let mut bytes = vec![0u8; serialized_size::<F>()];
+        reader.read_exact(&mut bytes)?;
+        Ok(Self(F::from_bytes_unchecked_reduce(&bytes)))
+    }
+}
+
+/// The Poseidon hash of the seed followed by the input, under the default Poseidon parameters.
+#[derive(Clone, Debug, Default)]
+pub struct PoseidonPRF<F: PrimeField + PoseidonMDSField, const RATE: usize>(PhantomData<F>);
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> PoseidonPRF<F, RATE> {
+    /// Evaluates the PRF on a seed and an input given as field elements.
+    pub fn evaluate_field_elements(seed: &[F], input: &[F]) -> F {
+        let crh = PoseidonCRH::<F, RATE>::from(PoseidonParameters::default());
+        crh.hash_field_elements(&[seed, input].concat())
+    }
+
+    /// Evaluates the PRF on a seed and an input given as bytes, which are read as field elements
+    /// with `bytes_to_field_elements_mod_order`.
+    pub fn evaluate_bytes(seed: &[u8], input: &[u8]) -> F {
+        Self::evaluate_field_elements(
+            &bytes_to_field_elements_mod_order(seed),
+            &bytes_to_field_elements_mod_order(input),
+        )
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> PRF for PoseidonPRF<F, RATE> {
+    type Input = F;
+    type Output = F;
+    type Seed = PoseidonPRFSeed<F>;
+
+    fn evaluate(seed: &Self::Seed, input: &Self::Input) -> Result<Self::Output, PRFError> {
+        let eval_time = start_timer!(|| "PoseidonPRF::Eval");
+        let output = Self::evaluate_field_elements(&[seed.0], &[*input]);
+        end_timer!(eval_time);
+        Ok(output)
+    }
+}
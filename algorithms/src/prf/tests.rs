@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crh::{PoseidonCRH, PoseidonParameters},
+    prf::{bytes_to_field_elements_mod_order, PoseidonPRF, PoseidonPRFSeed},
+    traits::PRF,
+};
+use snarkvm_curves::bls12_377::Fr;
+use snarkvm_fields::{FieldParameters, PrimeField, Zero};
+use snarkvm_utilities::{
+    bytes::{FromBytes, ToBytes},
+    rand::UniformRand,
+    to_bytes,
+};
+
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+type TestPRF = PoseidonPRF<Fr, 2>;
+
+#[test]
+fn poseidon_prf_known_answers() {
+    let known_answers = [
+        (
+            0u64,
+            0u64,
+            "5617106435683759155313655341387629839170116087785763767931448210470149858719",
+        ),
+        (
+            1,
+            0,
+            "89488507903500545974750858377244777220713782003760464858028422372204805223",
+        ),
+        (
+            0,
+            1,
+            "954509848690310395183605002717707301021771872930301780175813260817300826646873",
+        ),
+        (
+            123456789,
+            987654321,
+            "1038190043900752949709606887377433481195453603169189063287576810355199015917",
+        ),
+    ];
+
+    for (seed, input, expected) in known_answers.iter() {
+        let seed = PoseidonPRFSeed(Fr::from(*seed));
+        let output = TestPRF::evaluate(&seed, &Fr::from(*input)).unwrap();
+        assert_eq!(&output.to_string(), expected);
+    }
+}
+
+#[test]
+fn poseidon_prf_is_poseidon_crh() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let crh = PoseidonCRH::<Fr, 2>::from(PoseidonParameters::default());
+
+    for _ in 0..10 {
+        let seed = PoseidonPRFSeed(Fr::rand(rng));
+        let input = Fr::rand(rng);
+        let output = TestPRF::evaluate(&seed, &input).unwrap();
+        assert_eq!(output, crh.hash_field_elements(&[seed.0, input]));
+    }
+}
+
+#[test]
+fn poseidon_prf_evaluate_bytes() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let seed = PoseidonPRFSeed(Fr::rand(rng));
+        let input = Fr::rand(rng);
+
+        // Canonical bytes of the seed and the input are read back as the same field elements.
+        let expected = TestPRF::evaluate(&seed, &input).unwrap();
+        let output = TestPRF::evaluate_bytes(&to_bytes![seed].unwrap(), &to_bytes![input].unwrap());
+        assert_eq!(output, expected);
+    }
+}
+
+#[test]
+fn poseidon_prf_seed_is_read_mod_order() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    // Canonical encodings round-trip.
+    for _ in 0..10 {
+        let seed = PoseidonPRFSeed(Fr::rand(rng));
+        let bytes = to_bytes![seed].unwrap();
+        assert_eq!(PoseidonPRFSeed::<Fr>::read(&bytes[..]).unwrap(), seed);
+    }
+
+    // Bytes above the modulus are reduced instead of rejected.
+    let bytes = [0xffu8; 32];
+    assert!(Fr::read(&bytes[..]).is_err());
+    let seed = PoseidonPRFSeed::<Fr>::read(&bytes[..]).unwrap();
+    assert_eq!(seed.0, bytes_to_field_elements_mod_order::<Fr>(&bytes)[0]);
+    let two = Fr::from(2u64);
+    let expected = (0..256).fold(Fr::zero(), |acc, _| acc * two + Fr::from(1u64));
+    assert_eq!(seed.0, expected);
+
+    // The modulus itself is read as zero.
+    let modulus = to_bytes![<Fr as PrimeField>::Parameters::MODULUS].unwrap();
+    assert!(PoseidonPRFSeed::<Fr>::read(&modulus[..]).unwrap().0.is_zero());
+}
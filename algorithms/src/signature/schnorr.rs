@@ -28,6 +28,8 @@ use snarkvm_utilities::{
 use digest::Digest;
 use itertools::Itertools;
 use rand::Rng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use std::{
     hash::Hash,
     io::{Read, Result as IoResult, Write},
@@ -238,6 +240,8 @@ where
         public_key: &Self::PublicKey,
         randomness: &[u8],
     ) -> Result<Self::PublicKey, SignatureError> {
+        self.check_randomness_size(randomness)?;
+
         let rand_pk_time = start_timer!(|| "SchnorrSignature::randomize_public_key");
 
         let mut randomized_pk = public_key.0;
@@ -256,6 +260,8 @@ where
     }
 
     fn randomize_signature(&self, signature: &Self::Output, randomness: &[u8]) -> Result<Self::Output, SignatureError> {
+        self.check_randomness_size(randomness)?;
+
         let rand_signature_time = start_timer!(|| "SchnorrSignature::randomize_signature");
         let SchnorrOutput {
             prover_response,
@@ -279,6 +285,71 @@ where
     }
 }
 
+impl<G: Group + Hash + CanonicalSerialize + CanonicalDeserialize, D: Digest + Send + Sync> SchnorrSignature<G, D>
+where
+    <G as Group>::ScalarField: PrimeField,
+{
+    /// Checks that the randomness of a randomized public key or signature has one bit for each
+    /// generator power, which is the size the public key randomization gadget expects.
+    fn check_randomness_size(&self, randomness: &[u8]) -> Result<(), SignatureError> {
+        if randomness.len() * 8 != self.parameters.generator_powers.len() {
+            return Err(SignatureError::Message(format!(
+                "Expected {} bytes of randomness, found {} bytes",
+                self.parameters.generator_powers.len() / 8,
+                randomness.len()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Verifies many signatures in parallel, and returns `false` if any signature is invalid.
+    ///
+    /// Each signature is verified individually. A signature carries the verifier challenge rather
+    /// than the prover commitment, so each commitment must be recomputed to check its challenge hash,
+    /// and the signatures cannot be folded into one random linear combination.
+    pub fn verify_many(
+        &self,
+        public_keys: &[SchnorrPublicKey<G>],
+        messages: &[&[u8]],
+        signatures: &[SchnorrOutput<G>],
+    ) -> Result<bool, SignatureError> {
+        Ok(self
+            .verify_many_with_culprits(public_keys, messages, signatures)?
+            .is_empty())
+    }
+
+    /// Verifies many signatures in parallel, and returns the indices of the invalid signatures.
+    pub fn verify_many_with_culprits(
+        &self,
+        public_keys: &[SchnorrPublicKey<G>],
+        messages: &[&[u8]],
+        signatures: &[SchnorrOutput<G>],
+    ) -> Result<Vec<usize>, SignatureError> {
+        if public_keys.len() != messages.len() || public_keys.len() != signatures.len() {
+            return Err(SignatureError::Message(format!(
+                "Mismatched numbers of {} public keys, {} messages, and {} signatures",
+                public_keys.len(),
+                messages.len(),
+                signatures.len()
+            )));
+        }
+
+        let verify_many_time = start_timer!(|| format!("SchnorrSignature::verify_many of {}", signatures.len()));
+        let is_valid = cfg_into_iter!(0..signatures.len())
+            .map(|i| self.verify(&public_keys[i], messages[i], &signatures[i]))
+            .collect::<Result<Vec<_>, _>>()?;
+        end_timer!(verify_many_time);
+
+        Ok(is_valid
+            .into_iter()
+            .enumerate()
+            .filter(|(_, is_valid)| !is_valid)
+            .map(|(i, _)| i)
+            .collect())
+    }
+}
+
 impl<G: Group, D: Digest> From<SchnorrParameters<G, D>> for SchnorrSignature<G, D> {
     fn from(parameters: SchnorrParameters<G, D>) -> Self {
         Self { parameters }
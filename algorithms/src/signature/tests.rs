@@ -108,3 +108,116 @@ fn group_encryption_signature_test() {
 fn group_encryption_signature_scheme_parameters_serialization() {
     signature_scheme_parameter_serialization::<TestGroupEncryptionSignature>();
 }
+
+fn sign_many(
+    signature_scheme: &TestSignature,
+    messages: &[&[u8]],
+) -> (
+    Vec<<TestSignature as SignatureScheme>::PublicKey>,
+    Vec<<TestSignature as SignatureScheme>::Output>,
+) {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    messages
+        .iter()
+        .map(|message| {
+            let private_key = signature_scheme.generate_private_key(rng).unwrap();
+            let public_key = signature_scheme.generate_public_key(&private_key).unwrap();
+            let signature = signature_scheme.sign(&private_key, message, rng).unwrap();
+            (public_key, signature)
+        })
+        .unzip()
+}
+
+#[test]
+fn schnorr_signature_verify_many() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let signature_scheme = TestSignature::setup(rng).unwrap();
+
+    let messages: Vec<Vec<u8>> = (0..8)
+        .map(|i| format!("Schnorr message {}", i).into_bytes())
+        .collect();
+    let messages: Vec<&[u8]> = messages.iter().map(|message| &message[..]).collect();
+    let (public_keys, signatures) = sign_many(&signature_scheme, &messages);
+
+    assert!(
+        signature_scheme
+            .verify_many(&public_keys, &messages, &signatures)
+            .unwrap()
+    );
+    assert!(
+        signature_scheme
+            .verify_many_with_culprits(&public_keys, &messages, &signatures)
+            .unwrap()
+            .is_empty()
+    );
+
+    // No signatures are trivially valid.
+    assert!(signature_scheme.verify_many(&[], &[], &[]).unwrap());
+}
+
+#[test]
+fn schnorr_signature_verify_many_with_culprits() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let signature_scheme = TestSignature::setup(rng).unwrap();
+
+    let messages: Vec<Vec<u8>> = (0..8)
+        .map(|i| format!("Schnorr message {}", i).into_bytes())
+        .collect();
+    let mut messages: Vec<&[u8]> = messages.iter().map(|message| &message[..]).collect();
+    let (public_keys, signatures) = sign_many(&signature_scheme, &messages);
+
+    messages[5] = b"Bad message";
+
+    assert!(
+        !signature_scheme
+            .verify_many(&public_keys, &messages, &signatures)
+            .unwrap()
+    );
+    assert_eq!(
+        signature_scheme
+            .verify_many_with_culprits(&public_keys, &messages, &signatures)
+            .unwrap(),
+        vec![5]
+    );
+}
+
+#[test]
+fn schnorr_signature_verify_many_mismatched_lengths() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let signature_scheme = TestSignature::setup(rng).unwrap();
+
+    let messages: Vec<&[u8]> = vec![b"first", b"second", b"third"];
+    let (public_keys, signatures) = sign_many(&signature_scheme, &messages);
+
+    assert!(
+        signature_scheme
+            .verify_many(&public_keys[..2], &messages, &signatures)
+            .is_err()
+    );
+    assert!(
+        signature_scheme
+            .verify_many_with_culprits(&public_keys, &messages[..2], &signatures)
+            .is_err()
+    );
+    assert!(
+        signature_scheme
+            .verify_many(&public_keys, &messages, &signatures[..1])
+            .is_err()
+    );
+}
+
+#[test]
+fn schnorr_signature_randomization_rejects_wrong_randomness_size() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let signature_scheme = TestSignature::setup(rng).unwrap();
+
+    let private_key = signature_scheme.generate_private_key(rng).unwrap();
+    let public_key = signature_scheme.generate_public_key(&private_key).unwrap();
+    let signature = signature_scheme.sign(&private_key, b"message", rng).unwrap();
+
+    for randomness in &[vec![], vec![1u8; 31], vec![1u8; 33]] {
+        assert!(signature_scheme.randomize_public_key(&public_key, randomness).is_err());
+        assert!(signature_scheme.randomize_signature(&signature, randomness).is_err());
+    }
+}
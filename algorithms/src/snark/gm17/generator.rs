@@ -28,7 +28,10 @@ use snarkvm_r1cs::{
 };
 use snarkvm_utilities::rand::UniformRand;
 
-use core::ops::Mul;
+use core::{
+    ops::Mul,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 use rand::Rng;
 
 #[cfg(feature = "parallel")]
@@ -50,6 +53,27 @@ where
     generate_parameters::<E, C, R>(circuit, alpha, beta, gamma, g, h, rng)
 }
 
+/// Generates a random common reference string for a circuit, calling `progress` with
+/// the fraction of the fixed-base multiplications done so far.
+pub fn generate_random_parameters_with_progress<E, C, R>(
+    circuit: &C,
+    rng: &mut R,
+    progress: &(dyn Fn(f32) + Sync),
+) -> Result<ProvingKey<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    R: Rng,
+{
+    let alpha = E::Fr::rand(rng);
+    let beta = E::Fr::rand(rng);
+    let gamma = E::Fr::one();
+    let g = E::G1Projective::rand(rng);
+    let h = E::G2Projective::rand(rng);
+
+    generate_parameters_with_progress::<E, C, R>(circuit, alpha, beta, gamma, g, h, rng, progress)
+}
+
 /// This is our assembly structure that we'll use to synthesize the
 /// circuit into a SAP.
 pub struct KeypairAssembly<E: PairingEngine> {
@@ -158,7 +182,6 @@ impl<E: PairingEngine> ConstraintSystem<E::Fr> for KeypairAssembly<E> {
 }
 
 /// Create parameters for a circuit, given some toxic waste.
-#[allow(clippy::many_single_char_names)]
 pub fn generate_parameters<E, C, R>(
     circuit: &C,
     alpha: E::Fr,
@@ -168,6 +191,27 @@ pub fn generate_parameters<E, C, R>(
     h: E::G2Projective,
     rng: &mut R,
 ) -> Result<ProvingKey<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    R: Rng,
+{
+    generate_parameters_with_progress::<E, C, R>(circuit, alpha, beta, gamma, g, h, rng, &|_| {})
+}
+
+/// Create parameters for a circuit, given some toxic waste, calling `progress` with
+/// the fraction of the fixed-base multiplications done so far.
+#[allow(clippy::many_single_char_names, clippy::too_many_arguments)]
+pub fn generate_parameters_with_progress<E, C, R>(
+    circuit: &C,
+    alpha: E::Fr,
+    beta: E::Fr,
+    gamma: E::Fr,
+    g: E::G1Projective,
+    h: E::G2Projective,
+    rng: &mut R,
+    progress: &(dyn Fn(f32) + Sync),
+) -> Result<ProvingKey<E>, SynthesisError>
 where
     E: PairingEngine,
     C: ConstraintSynthesizer<E::Fr>,
@@ -227,16 +271,25 @@ where
     let g_table = FixedBaseMSM::get_window_table::<E::G1Projective>(scalar_bits, g_window, g);
     end_timer!(g_window_time);
 
+    // Track the progress over all of the fixed-base multiplications below.
+    let num_scalars = 2 * a.len() + (m_raw + 1) + 2 * (sap_num_variables + 1);
+    let num_multiplied = AtomicUsize::new(0);
+    let report = |count: usize| {
+        let multiplied = num_multiplied.fetch_add(count, Ordering::Relaxed) + count;
+        progress(multiplied as f32 / num_scalars as f32);
+    };
+
     // Generate the R1CS proving key
     let proving_key_time = start_timer!(|| "Generate the R1CS proving key");
 
     // Compute the A-query
     let a_time = start_timer!(|| "Calculate A");
-    let mut a_query = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
+    let mut a_query = FixedBaseMSM::multi_scalar_mul_with_progress::<E::G1Projective>(
         scalar_bits,
         g_window,
         &g_table,
         &cfg_iter!(a).map(|a| *a * gamma).collect::<Vec<_>>(),
+        &report,
     );
     end_timer!(a_time);
 
@@ -254,25 +307,27 @@ where
 
     // Compute the vector G_gamma2_z_t := Z(t) * t^i * gamma^2 * G
     let gamma2_z_t = gamma_z * gamma;
-    let mut g_gamma2_z_t = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
+    let mut g_gamma2_z_t = FixedBaseMSM::multi_scalar_mul_with_progress::<E::G1Projective>(
         scalar_bits,
         g_window,
         &g_table,
         &cfg_into_iter!(0..m_raw + 1)
             .map(|i| gamma2_z_t * (t.pow([i as u64])))
             .collect::<Vec<_>>(),
+        &report,
     );
     end_timer!(g_gamma_time);
 
     // Compute the C_1-query
     let c1_time = start_timer!(|| "Calculate C1");
-    let mut result = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
+    let mut result = FixedBaseMSM::multi_scalar_mul_with_progress::<E::G1Projective>(
         scalar_bits,
         g_window,
         &g_table,
         &cfg_into_iter!(0..sap_num_variables + 1)
             .map(|i| c[i] * gamma + (a[i] * alpha_beta))
             .collect::<Vec<_>>(),
+        &report,
     );
     let (verifier_query, c_query_1) = result.split_at_mut(assembly.num_public_variables);
     end_timer!(c1_time);
@@ -280,13 +335,14 @@ where
     // Compute the C_2-query
     let c2_time = start_timer!(|| "Calculate C2");
     let double_gamma2_z = (zt * gamma.square()).double();
-    let mut c_query_2 = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
+    let mut c_query_2 = FixedBaseMSM::multi_scalar_mul_with_progress::<E::G1Projective>(
         scalar_bits,
         g_window,
         &g_table,
         &cfg_into_iter!(0..sap_num_variables + 1)
             .map(|i| a[i] * double_gamma2_z)
             .collect::<Vec<_>>(),
+        &report,
     );
     drop(g_table);
     end_timer!(c2_time);
@@ -299,8 +355,13 @@ where
 
     // Compute the B-query
     let b_time = start_timer!(|| "Calculate B");
-    let mut b_query =
-        FixedBaseMSM::multi_scalar_mul::<E::G2Projective>(scalar_bits, h_gamma_window, &h_gamma_table, &a);
+    let mut b_query = FixedBaseMSM::multi_scalar_mul_with_progress::<E::G2Projective>(
+        scalar_bits,
+        h_gamma_window,
+        &h_gamma_table,
+        &a,
+        &report,
+    );
     end_timer!(b_time);
 
     end_timer!(proving_key_time);
@@ -18,6 +18,7 @@
 //! [GM17]: https://eprint.iacr.org/2017/540
 
 use snarkvm_curves::traits::pairing_engine::{AffineCurve, PairingCurve, PairingEngine};
+use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
 use snarkvm_r1cs::errors::SynthesisResult;
 use snarkvm_utilities::{errors::SerializationError, serialize::*, FromBytes, ToBytes};
 
@@ -192,6 +193,30 @@ impl<E: PairingEngine> FromBytes for VerifyingKey<E> {
     }
 }
 
+/// Encodes the verification key as the affine coordinates of its group elements, in field
+/// order, with the length of `query` emitted before the elements of `query`.
+impl<E: PairingEngine> ToConstraintField<E::Fq> for VerifyingKey<E>
+where
+    E::G1Affine: ToConstraintField<E::Fq>,
+    E::G2Affine: ToConstraintField<E::Fq>,
+{
+    fn to_field_elements(&self) -> Result<Vec<E::Fq>, ConstraintFieldError> {
+        let mut res = Vec::new();
+        res.extend_from_slice(&self.h_g2.to_field_elements()?);
+        res.extend_from_slice(&self.g_alpha_g1.to_field_elements()?);
+        res.extend_from_slice(&self.h_beta_g2.to_field_elements()?);
+        res.extend_from_slice(&self.g_gamma_g1.to_field_elements()?);
+        res.extend_from_slice(&self.h_gamma_g2.to_field_elements()?);
+
+        res.push(E::Fq::from(self.query.len() as u64));
+        for element in &self.query {
+            res.extend_from_slice(&element.to_field_elements()?);
+        }
+
+        Ok(res)
+    }
+}
+
 impl<E: PairingEngine> Default for VerifyingKey<E> {
     fn default() -> Self {
         Self {
@@ -434,6 +459,30 @@ impl<E: PairingEngine> ProvingKey<E> {
     }
 }
 
+/// Preprocessed proving key parameters that enable faster proving
+/// at the expense of larger size in memory.
+///
+/// The fixed-base window tables for the proving key elements that are multiplied
+/// by the proof randomness are computed once, and reused across every proof
+/// created with this key.
+#[derive(Clone, Debug)]
+pub struct PreparedProvingKey<E: PairingEngine> {
+    pub proving_key: ProvingKey<E>,
+    pub(crate) window_size: usize,
+    pub(crate) g_gamma_z_table: Vec<Vec<E::G1Projective>>,
+    pub(crate) h_gamma_z_table: Vec<Vec<E::G2Projective>>,
+    pub(crate) g_ab_gamma_z_table: Vec<Vec<E::G1Projective>>,
+    pub(crate) g_gamma2_z2_table: Vec<Vec<E::G1Projective>>,
+    pub(crate) c_query_2_table: Vec<Vec<E::G1Projective>>,
+    pub(crate) g_gamma2_z_t_table: Vec<Vec<E::G1Projective>>,
+}
+
+impl<E: PairingEngine> From<PreparedProvingKey<E>> for ProvingKey<E> {
+    fn from(other: PreparedProvingKey<E>) -> Self {
+        other.proving_key
+    }
+}
+
 /// Preprocessed verification key parameters that enable faster verification
 /// at the expense of larger size in memory.
 #[derive(Clone, Debug)]
@@ -14,8 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use super::{r1cs_to_sap::R1CStoSAP, Proof, ProvingKey};
-use crate::msm::VariableBaseMSM;
+use super::{r1cs_to_sap::R1CStoSAP, PreparedProvingKey, Proof, ProvingKey};
+use crate::msm::{FixedBaseMSM, VariableBaseMSM};
 use snarkvm_curves::traits::{AffineCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, PrimeField, Zero};
 use snarkvm_r1cs::{
@@ -201,6 +201,103 @@ impl<E: PairingEngine> ConstraintSystem<E::Fr> for ProvingAssignment<E> {
     }
 }
 
+/// The window size of the fixed-base tables in a prepared proving key.
+const PREPARED_PROVING_KEY_WINDOW_SIZE: usize = 4;
+
+/// The products of the fixed proving key elements with the proof randomness.
+struct RandomnessTerms<E: PairingEngine> {
+    r_g: E::G1Projective,
+    d1_g: E::G1Projective,
+    r_h: E::G2Projective,
+    d1_h: E::G2Projective,
+    r2_g_gamma2_z2: E::G1Projective,
+    r_g_ab_gamma_z: E::G1Projective,
+    d1_g_ab_gamma_z: E::G1Projective,
+    r_c0: E::G1Projective,
+    r2_d1_g_gamma2_z2: E::G1Projective,
+    d2_g_gamma2_z_t0: E::G1Projective,
+}
+
+impl<E: PairingEngine> RandomnessTerms<E> {
+    fn new(params: &ProvingKey<E>, d1: E::Fr, d2: E::Fr, r: E::Fr) -> Result<Self, SynthesisError> {
+        let r2 = r * r;
+        let d1_r_2 = d1 * (r + r);
+
+        Ok(Self {
+            r_g: params.get_g_gamma_z()?.mul(r).into_projective(),
+            d1_g: params.get_g_gamma_z()?.mul(d1).into_projective(),
+            r_h: params.get_h_gamma_z()?.mul(r).into_projective(),
+            d1_h: params.get_h_gamma_z()?.mul(d1).into_projective(),
+            r2_g_gamma2_z2: params.get_g_gamma2_z2()?.mul(r2).into_projective(),
+            r_g_ab_gamma_z: params.get_g_ab_gamma_z()?.mul(r).into_projective(),
+            d1_g_ab_gamma_z: params.get_g_ab_gamma_z()?.mul(d1).into_projective(),
+            r_c0: params.get_c_query_2_full()?[0].mul(r).into_projective(),
+            r2_d1_g_gamma2_z2: params.get_g_gamma2_z2()?.mul(d1_r_2).into_projective(),
+            d2_g_gamma2_z_t0: params.get_g_gamma2_z_t_full()?[0].mul(d2).into_projective(),
+        })
+    }
+
+    fn new_prepared(prepared: &PreparedProvingKey<E>, d1: E::Fr, d2: E::Fr, r: E::Fr) -> Self {
+        let r2 = r * r;
+        let d1_r_2 = d1 * (r + r);
+        let window = prepared.window_size;
+
+        Self {
+            r_g: FixedBaseMSM::windowed_scalar_mul(window, &prepared.g_gamma_z_table, &r),
+            d1_g: FixedBaseMSM::windowed_scalar_mul(window, &prepared.g_gamma_z_table, &d1),
+            r_h: FixedBaseMSM::windowed_scalar_mul(window, &prepared.h_gamma_z_table, &r),
+            d1_h: FixedBaseMSM::windowed_scalar_mul(window, &prepared.h_gamma_z_table, &d1),
+            r2_g_gamma2_z2: FixedBaseMSM::windowed_scalar_mul(window, &prepared.g_gamma2_z2_table, &r2),
+            r_g_ab_gamma_z: FixedBaseMSM::windowed_scalar_mul(window, &prepared.g_ab_gamma_z_table, &r),
+            d1_g_ab_gamma_z: FixedBaseMSM::windowed_scalar_mul(window, &prepared.g_ab_gamma_z_table, &d1),
+            r_c0: FixedBaseMSM::windowed_scalar_mul(window, &prepared.c_query_2_table, &r),
+            r2_d1_g_gamma2_z2: FixedBaseMSM::windowed_scalar_mul(window, &prepared.g_gamma2_z2_table, &d1_r_2),
+            d2_g_gamma2_z_t0: FixedBaseMSM::windowed_scalar_mul(window, &prepared.g_gamma2_z_t_table, &d2),
+        }
+    }
+}
+
+/// Prepares the proving key by computing the fixed-base window tables
+/// for the proving key elements that are multiplied by the proof randomness.
+pub fn prepare_proving_key<E: PairingEngine>(
+    proving_key: ProvingKey<E>,
+) -> Result<PreparedProvingKey<E>, SynthesisError> {
+    let c_query_2_0 = *proving_key
+        .c_query_2
+        .first()
+        .ok_or(SynthesisError::MalformedProvingKey)?;
+    let g_gamma2_z_t_0 = *proving_key
+        .g_gamma2_z_t
+        .first()
+        .ok_or(SynthesisError::MalformedProvingKey)?;
+
+    let prepare_time = start_timer!(|| "Prepare proving key");
+    let scalar_bits = E::Fr::size_in_bits();
+    let window_size = PREPARED_PROVING_KEY_WINDOW_SIZE;
+
+    let g1_table = |base: E::G1Affine| FixedBaseMSM::get_window_table(scalar_bits, window_size, base.into_projective());
+    let g2_table = |base: E::G2Affine| FixedBaseMSM::get_window_table(scalar_bits, window_size, base.into_projective());
+
+    let g_gamma_z_table = g1_table(proving_key.g_gamma_z);
+    let h_gamma_z_table = g2_table(proving_key.h_gamma_z);
+    let g_ab_gamma_z_table = g1_table(proving_key.g_ab_gamma_z);
+    let g_gamma2_z2_table = g1_table(proving_key.g_gamma2_z2);
+    let c_query_2_table = g1_table(c_query_2_0);
+    let g_gamma2_z_t_table = g1_table(g_gamma2_z_t_0);
+    end_timer!(prepare_time);
+
+    Ok(PreparedProvingKey {
+        proving_key,
+        window_size,
+        g_gamma_z_table,
+        h_gamma_z_table,
+        g_ab_gamma_z_table,
+        g_gamma2_z2_table,
+        c_query_2_table,
+        g_gamma2_z_t_table,
+    })
+}
+
 pub fn create_random_proof<E, C, R>(
     circuit: &C,
     params: &ProvingKey<E>,
@@ -218,6 +315,23 @@ where
     create_proof::<E, C>(circuit, params, d1, d2, r)
 }
 
+pub fn create_random_proof_with_prepared_key<E, C, R>(
+    circuit: &C,
+    prepared_params: &PreparedProvingKey<E>,
+    rng: &mut R,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    R: Rng,
+{
+    let d1 = E::Fr::rand(rng);
+    let d2 = E::Fr::rand(rng);
+    let r = E::Fr::rand(rng);
+
+    create_proof_with_prepared_key::<E, C>(circuit, prepared_params, d1, d2, r)
+}
+
 pub fn create_proof<E, C>(
     circuit: &C,
     params: &ProvingKey<E>,
@@ -225,6 +339,39 @@ pub fn create_proof<E, C>(
     d2: E::Fr,
     r: E::Fr,
 ) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+{
+    let randomness_terms = RandomnessTerms::new(params, d1, d2, r)?;
+    create_proof_with_randomness_terms::<E, C>(circuit, params, randomness_terms, d1, d2, r)
+}
+
+/// Creates a proof using the fixed-base window tables of the prepared proving key.
+/// The resulting proof is identical to the one returned by `create_proof` for the same randomness.
+pub fn create_proof_with_prepared_key<E, C>(
+    circuit: &C,
+    prepared_params: &PreparedProvingKey<E>,
+    d1: E::Fr,
+    d2: E::Fr,
+    r: E::Fr,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+{
+    let randomness_terms = RandomnessTerms::new_prepared(prepared_params, d1, d2, r);
+    create_proof_with_randomness_terms::<E, C>(circuit, &prepared_params.proving_key, randomness_terms, d1, d2, r)
+}
+
+fn create_proof_with_randomness_terms<E, C>(
+    circuit: &C,
+    params: &ProvingKey<E>,
+    randomness_terms: RandomnessTerms<E>,
+    d1: E::Fr,
+    d2: E::Fr,
+    r: E::Fr,
+) -> Result<Proof<E>, SynthesisError>
 where
     E: PairingEngine,
     C: ConstraintSynthesizer<E::Fr>,
@@ -281,12 +428,9 @@ where
     let a_inputs_acc = VariableBaseMSM::multi_scalar_mul(a_inputs_source, &input_assignment);
     let a_aux_acc = VariableBaseMSM::multi_scalar_mul(a_aux_source, &aux_assignment);
 
-    let r_g = params.get_g_gamma_z()?.mul(r);
-    let d1_g = params.get_g_gamma_z()?.mul(d1);
-
-    let mut g_a = r_g.into_projective();
+    let mut g_a = randomness_terms.r_g;
     g_a.add_assign(params.get_a_query_full()?[0].into_projective());
-    g_a.add_assign(d1_g.into_projective());
+    g_a.add_assign(randomness_terms.d1_g);
     g_a.add_assign(a_inputs_acc);
     g_a.add_assign(a_aux_acc);
     end_timer!(a_acc_time);
@@ -298,22 +442,15 @@ where
     let b_inputs_acc = VariableBaseMSM::multi_scalar_mul(b_inputs_source, &input_assignment);
     let b_aux_acc = VariableBaseMSM::multi_scalar_mul(b_aux_source, &aux_assignment);
 
-    let r_h = params.get_h_gamma_z()?.mul(r);
-    let d1_h = params.get_h_gamma_z()?.mul(d1);
-
-    let mut g_b = r_h.into_projective();
+    let mut g_b = randomness_terms.r_h;
     g_b.add_assign(params.get_b_query_full()?[0].into_projective());
-    g_b.add_assign(d1_h.into_projective());
+    g_b.add_assign(randomness_terms.d1_h);
     g_b.add_assign(b_inputs_acc);
     g_b.add_assign(b_aux_acc);
     end_timer!(b_acc_time);
 
     // Compute C
     let c_acc_time = start_timer!(|| "Compute C");
-    let r_2 = r + r;
-    let r2 = r * r;
-    let d1_r_2 = d1 * r_2;
-
     let c1_acc_time = start_timer!(|| "Compute C1");
     let (_, c1_aux_source) = params.get_c_query_1(0)?;
     let c1_acc = VariableBaseMSM::multi_scalar_mul(c1_aux_source, &aux_assignment);
@@ -338,23 +475,17 @@ where
     let g_acc = g_inputs_acc + g_aux_acc;
     end_timer!(g_acc_time);
 
-    let r2_g_gamma2_z2 = params.get_g_gamma2_z2()?.mul(r2);
-    let r_g_ab_gamma_z = params.get_g_ab_gamma_z()?.mul(r);
-    let d1_g_ab_gamma_z = params.get_g_ab_gamma_z()?.mul(d1);
-    let r_c0 = params.get_c_query_2_full()?[0].mul(r);
-    let r2_d1_g_gamma2_z2 = params.get_g_gamma2_z2()?.mul(d1_r_2);
-    let d2_g_gamma2_z_t0 = params.get_g_gamma2_z_t_full()?[0].mul(d2);
     let mut r_c2_exp = c2_acc;
     r_c2_exp.mul_assign(r);
 
     let mut g_c = c1_acc;
-    g_c.add_assign(r2_g_gamma2_z2.into_projective());
-    g_c.add_assign(r_g_ab_gamma_z.into_projective());
-    g_c.add_assign(d1_g_ab_gamma_z.into_projective());
-    g_c.add_assign(r_c0.into_projective());
-    g_c.add_assign(r2_d1_g_gamma2_z2.into_projective());
+    g_c.add_assign(randomness_terms.r2_g_gamma2_z2);
+    g_c.add_assign(randomness_terms.r_g_ab_gamma_z);
+    g_c.add_assign(randomness_terms.d1_g_ab_gamma_z);
+    g_c.add_assign(randomness_terms.r_c0);
+    g_c.add_assign(randomness_terms.r2_d1_g_gamma2_z2);
     g_c.add_assign(r_c2_exp);
-    g_c.add_assign(d2_g_gamma2_z_t0.into_projective());
+    g_c.add_assign(randomness_terms.d2_g_gamma2_z_t0);
     g_c.add_assign(g_acc);
     end_timer!(c_acc_time);
 
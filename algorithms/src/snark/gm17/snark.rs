@@ -16,9 +16,13 @@
 
 use super::{
     create_random_proof,
+    create_random_proof_with_prepared_key,
     generate_random_parameters,
+    generate_random_parameters_with_progress,
+    prepare_proving_key,
     prepare_verifying_key,
     verify_proof,
+    PreparedProvingKey,
     PreparedVerifyingKey,
     Proof,
     ProvingKey,
@@ -44,6 +48,7 @@ pub struct GM17<E: PairingEngine, C: ConstraintSynthesizer<E::Fr>, V: ToConstrai
 impl<E: PairingEngine, C: ConstraintSynthesizer<E::Fr>, V: ToConstraintField<E::Fr> + ?Sized> SNARK for GM17<E, C, V> {
     type AllocatedCircuit = C;
     type Circuit = C;
+    type PreparedProvingKey = PreparedProvingKey<E>;
     type PreparedVerifyingKey = PreparedVerifyingKey<E>;
     type Proof = Proof<E>;
     type ProvingKey = ProvingKey<E>;
@@ -61,6 +66,18 @@ impl<E: PairingEngine, C: ConstraintSynthesizer<E::Fr>, V: ToConstraintField<E::
         Ok((pp, vk))
     }
 
+    fn setup_with_progress<R: Rng>(
+        circuit: &Self::Circuit,
+        rng: &mut R,
+        progress: &(dyn Fn(f32) + Sync),
+    ) -> Result<(Self::ProvingKey, Self::PreparedVerifyingKey), SNARKError> {
+        let setup_time = start_timer!(|| "{Groth-Maller 2017}::Setup");
+        let pp = generate_random_parameters_with_progress::<E, Self::Circuit, R>(circuit, rng, progress)?;
+        let vk = prepare_verifying_key(pp.vk.clone());
+        end_timer!(setup_time);
+        Ok((pp, vk))
+    }
+
     fn prove<R: Rng>(
         proving_key: &Self::ProvingKey,
         input_and_witness: &Self::AllocatedCircuit,
@@ -72,6 +89,21 @@ impl<E: PairingEngine, C: ConstraintSynthesizer<E::Fr>, V: ToConstraintField<E::
         Ok(result)
     }
 
+    fn prepare_proving_key(proving_key: Self::ProvingKey) -> Result<Self::PreparedProvingKey, SNARKError> {
+        Ok(prepare_proving_key(proving_key)?)
+    }
+
+    fn prove_with_prepared_key<R: Rng>(
+        prepared_proving_key: &Self::PreparedProvingKey,
+        input_and_witness: &Self::AllocatedCircuit,
+        rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        let proof_time = start_timer!(|| "{Groth-Maller 2017}::Prove with prepared key");
+        let result = create_random_proof_with_prepared_key::<E, _, _>(input_and_witness, prepared_proving_key, rng)?;
+        end_timer!(proof_time);
+        Ok(result)
+    }
+
     fn verify(
         verifying_key: &Self::PreparedVerifyingKey,
         input: &Self::VerifierInput,
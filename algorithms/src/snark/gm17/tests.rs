@@ -45,11 +45,23 @@ impl<F: Field> ConstraintSynthesizer<F> for MySillyCircuit<F> {
 
 mod bls12_377 {
     use super::*;
-    use crate::snark::gm17::{create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof};
+    use crate::snark::gm17::{
+        create_proof,
+        create_proof_with_prepared_key,
+        create_random_proof,
+        create_random_proof_with_prepared_key,
+        generate_random_parameters,
+        generate_random_parameters_with_progress,
+        prepare_proving_key,
+        prepare_verifying_key,
+        verify_proof,
+    };
     use snarkvm_curves::bls12_377::{Bls12_377, Fr};
     use snarkvm_utilities::rand::{test_rng, UniformRand};
 
-    use std::ops::MulAssign;
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+    use std::{ops::MulAssign, sync::Mutex};
 
     #[test]
     fn prove_and_verify() {
@@ -71,6 +83,78 @@ mod bls12_377 {
             assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
         }
     }
+
+    #[test]
+    fn prove_with_prepared_key_and_verify() {
+        let rng = &mut test_rng();
+
+        let params = generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+
+        let pvk = prepare_verifying_key::<Bls12_377>(params.vk.clone());
+        let prepared_params = prepare_proving_key(params.clone()).unwrap();
+
+        for _ in 0..10 {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let c = a * b;
+            let circuit = MySillyCircuit { a: Some(a), b: Some(b) };
+
+            // The prepared and unprepared provers must produce the same proof for the same randomness.
+            let (d1, d2, r) = (Fr::rand(rng), Fr::rand(rng), Fr::rand(rng));
+            let proof = create_proof(&circuit, &params, d1, d2, r).unwrap();
+            let prepared_proof = create_proof_with_prepared_key(&circuit, &prepared_params, d1, d2, r).unwrap();
+            assert_eq!(proof, prepared_proof);
+
+            let proof = create_random_proof_with_prepared_key(&circuit, &prepared_params, rng).unwrap();
+            assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
+            assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
+        }
+    }
+
+    #[test]
+    fn prepare_proving_key_rejects_malformed_key() {
+        let rng = &mut test_rng();
+
+        let params = generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+
+        let mut malformed = params.clone();
+        malformed.c_query_2.clear();
+        assert!(matches!(
+            prepare_proving_key(malformed),
+            Err(SynthesisError::MalformedProvingKey)
+        ));
+
+        let mut malformed = params;
+        malformed.g_gamma2_z_t.clear();
+        assert!(matches!(
+            prepare_proving_key(malformed),
+            Err(SynthesisError::MalformedProvingKey)
+        ));
+    }
+
+    #[test]
+    fn generate_parameters_with_progress() {
+        let circuit = MySillyCircuit { a: None, b: None };
+
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+        let expected = generate_random_parameters::<Bls12_377, _, _>(&circuit, rng).unwrap();
+
+        let reported = Mutex::new(vec![]);
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+        let candidate = generate_random_parameters_with_progress::<Bls12_377, _, _>(&circuit, rng, &|fraction| {
+            reported.lock().unwrap().push(fraction)
+        })
+        .unwrap();
+
+        // Reporting the progress does not change the parameters.
+        assert_eq!(expected, candidate);
+
+        // The progress is reported once per fixed-base multiplication, and reaches completion.
+        let reported = reported.into_inner().unwrap();
+        assert_eq!(reported.len(), 5);
+        assert!(reported.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(reported.last(), Some(&1.0));
+    }
 }
 
 mod bw6 {
@@ -22,7 +22,10 @@ use snarkvm_profiler::{end_timer, start_timer};
 use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, Index, LinearCombination, SynthesisError, Variable};
 use snarkvm_utilities::{errors::SerializationError, rand::UniformRand, serialize::*};
 
-use core::ops::Mul;
+use core::{
+    ops::Mul,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 use rand::Rng;
 
 #[cfg(feature = "parallel")]
@@ -43,6 +46,26 @@ where
     generate_parameters::<E, C, R>(circuit, alpha, beta, gamma, delta, rng)
 }
 
+/// Generates a random common reference string for a circuit, calling `progress` with
+/// the fraction of the fixed-base multiplications done so far.
+pub fn generate_random_parameters_with_progress<E, C, R>(
+    circuit: &C,
+    rng: &mut R,
+    progress: &(dyn Fn(f32) + Sync),
+) -> Result<ProvingKey<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    R: Rng,
+{
+    let alpha = E::Fr::rand(rng);
+    let beta = E::Fr::rand(rng);
+    let gamma = E::Fr::rand(rng);
+    let delta = E::Fr::rand(rng);
+
+    generate_parameters_with_progress::<E, C, R>(circuit, alpha, beta, gamma, delta, rng, progress)
+}
+
 /// This is our assembly structure that we'll use to synthesize the
 /// circuit into a QAP.
 #[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
@@ -133,7 +156,6 @@ impl<E: PairingEngine> ConstraintSystem<E::Fr> for KeypairAssembly<E> {
 }
 
 /// Create parameters for a circuit, given some toxic waste.
-#[allow(clippy::many_single_char_names)]
 pub fn generate_parameters<E, C, R>(
     circuit: &C,
     alpha: E::Fr,
@@ -142,6 +164,26 @@ pub fn generate_parameters<E, C, R>(
     delta: E::Fr,
     rng: &mut R,
 ) -> Result<ProvingKey<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    R: Rng,
+{
+    generate_parameters_with_progress::<E, C, R>(circuit, alpha, beta, gamma, delta, rng, &|_| {})
+}
+
+/// Create parameters for a circuit, given some toxic waste, calling `progress` with
+/// the fraction of the fixed-base multiplications done so far.
+#[allow(clippy::many_single_char_names)]
+pub fn generate_parameters_with_progress<E, C, R>(
+    circuit: &C,
+    alpha: E::Fr,
+    beta: E::Fr,
+    gamma: E::Fr,
+    delta: E::Fr,
+    rng: &mut R,
+    progress: &(dyn Fn(f32) + Sync),
+) -> Result<ProvingKey<E>, SynthesisError>
 where
     E: PairingEngine,
     C: ConstraintSynthesizer<E::Fr>,
@@ -212,6 +254,14 @@ where
     let g1_table = FixedBaseMSM::get_window_table::<E::G1Projective>(scalar_bits, g1_window, g1_generator);
     end_timer!(g1_window_time);
 
+    // Track the progress over all of the fixed-base multiplications below.
+    let num_scalars = a.len() + 2 * b.len() + (m_raw - 1) + l.len() + gamma_abc.len();
+    let num_multiplied = AtomicUsize::new(0);
+    let report = |count: usize| {
+        let multiplied = num_multiplied.fetch_add(count, Ordering::Relaxed) + count;
+        progress(multiplied as f32 / num_scalars as f32);
+    };
+
     // Generate the R1CS proving key
     let proving_key_time = start_timer!(|| "Generate the R1CS proving key");
 
@@ -223,12 +273,14 @@ where
 
     // Compute the A-query
     let a_time = start_timer!(|| "Calculate A");
-    let mut a_query = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(scalar_bits, g1_window, &g1_table, &a);
+    let mut a_query =
+        FixedBaseMSM::multi_scalar_mul_with_progress::<E::G1Projective>(scalar_bits, g1_window, &g1_table, &a, &report);
     end_timer!(a_time);
 
     // Compute the B-query in G1
     let b_g1_time = start_timer!(|| "Calculate B G1");
-    let mut b_g1_query = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(scalar_bits, g1_window, &g1_table, &b);
+    let mut b_g1_query =
+        FixedBaseMSM::multi_scalar_mul_with_progress::<E::G1Projective>(scalar_bits, g1_window, &g1_table, &b, &report);
     end_timer!(b_g1_time);
 
     // Compute B window table
@@ -239,25 +291,28 @@ where
 
     // Compute the B-query in G2
     let b_g2_time = start_timer!(|| "Calculate B G2");
-    let mut b_g2_query = FixedBaseMSM::multi_scalar_mul::<E::G2Projective>(scalar_bits, g2_window, &g2_table, &b);
+    let mut b_g2_query =
+        FixedBaseMSM::multi_scalar_mul_with_progress::<E::G2Projective>(scalar_bits, g2_window, &g2_table, &b, &report);
     end_timer!(b_g2_time);
 
     // Compute the H-query
     let h_time = start_timer!(|| "Calculate H");
-    let mut h_query = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
+    let mut h_query = FixedBaseMSM::multi_scalar_mul_with_progress::<E::G1Projective>(
         scalar_bits,
         g1_window,
         &g1_table,
         &cfg_into_iter!(0..m_raw - 1)
             .map(|i| zt * delta_inverse * t.pow([i as u64]))
             .collect::<Vec<_>>(),
+        &report,
     );
 
     end_timer!(h_time);
 
     // Compute the L-query
     let l_time = start_timer!(|| "Calculate L");
-    let l_query = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(scalar_bits, g1_window, &g1_table, &l);
+    let l_query =
+        FixedBaseMSM::multi_scalar_mul_with_progress::<E::G1Projective>(scalar_bits, g1_window, &g1_table, &l, &report);
     let mut l_query = l_query[assembly.num_public_variables..].to_vec();
     end_timer!(l_time);
 
@@ -266,7 +321,13 @@ where
     // Generate R1CS verification key
     let verifying_key_time = start_timer!(|| "Generate the R1CS verification key");
     let gamma_g2 = g2_generator.mul(gamma);
-    let gamma_abc_g1 = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(scalar_bits, g1_window, &g1_table, &gamma_abc);
+    let gamma_abc_g1 = FixedBaseMSM::multi_scalar_mul_with_progress::<E::G1Projective>(
+        scalar_bits,
+        g1_window,
+        &g1_table,
+        &gamma_abc,
+        &report,
+    );
 
     drop(g1_table);
 
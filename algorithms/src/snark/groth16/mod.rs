@@ -19,7 +19,7 @@
 //! [`Groth16`]: https://eprint.iacr.org/2016/260.pdf
 
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine};
-use snarkvm_fields::Field;
+use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField};
 use snarkvm_r1cs::{Index, LinearCombination};
 use snarkvm_utilities::{errors::SerializationError, serialize::*, FromBytes, ToBytes};
 
@@ -193,6 +193,29 @@ impl<E: PairingEngine> FromBytes for VerifyingKey<E> {
     }
 }
 
+/// Encodes the verification key as the affine coordinates of `alpha_g1`, `beta_g2`, `gamma_g2`
+/// and `delta_g2`, followed by the length of `gamma_abc_g1` and the coordinates of its elements.
+impl<E: PairingEngine> ToConstraintField<E::Fq> for VerifyingKey<E>
+where
+    E::G1Affine: ToConstraintField<E::Fq>,
+    E::G2Affine: ToConstraintField<E::Fq>,
+{
+    fn to_field_elements(&self) -> Result<Vec<E::Fq>, ConstraintFieldError> {
+        let mut res = Vec::new();
+        res.extend_from_slice(&self.alpha_g1.to_field_elements()?);
+        res.extend_from_slice(&self.beta_g2.to_field_elements()?);
+        res.extend_from_slice(&self.gamma_g2.to_field_elements()?);
+        res.extend_from_slice(&self.delta_g2.to_field_elements()?);
+
+        res.push(E::Fq::from(self.gamma_abc_g1.len() as u64));
+        for element in &self.gamma_abc_g1 {
+            res.extend_from_slice(&element.to_field_elements()?);
+        }
+
+        Ok(res)
+    }
+}
+
 impl<E: PairingEngine> From<ProvingKey<E>> for VerifyingKey<E> {
     fn from(other: ProvingKey<E>) -> Self {
         other.vk
@@ -396,6 +419,32 @@ impl<E: PairingEngine> ProvingKey<E> {
     }
 }
 
+/// Preprocessed proving key parameters that enable faster proving
+/// at the expense of larger size in memory.
+///
+/// The fixed-base window tables for the proving key elements that are multiplied
+/// by the proof randomness are computed once, and reused across every proof
+/// created with this key.
+#[derive(Clone, Debug)]
+pub struct PreparedProvingKey<E: PairingEngine> {
+    pub proving_key: ProvingKey<E>,
+    pub(crate) window_size: usize,
+    pub(crate) delta_g1_table: Vec<Vec<E::G1Projective>>,
+    pub(crate) delta_g2_table: Vec<Vec<E::G2Projective>>,
+}
+
+impl<E: PairingEngine> From<ProvingKey<E>> for PreparedProvingKey<E> {
+    fn from(other: ProvingKey<E>) -> Self {
+        prepare_proving_key(other)
+    }
+}
+
+impl<E: PairingEngine> From<PreparedProvingKey<E>> for ProvingKey<E> {
+    fn from(other: PreparedProvingKey<E>) -> Self {
+        other.proving_key
+    }
+}
+
 /// Preprocessed verification key parameters that enable faster verification
 /// at the expense of larger size in memory.
 #[derive(Clone, Debug)]
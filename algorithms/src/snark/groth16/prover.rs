@@ -14,15 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use super::{push_constraints, r1cs_to_qap::R1CStoQAP, Proof, ProvingKey};
-use crate::{cfg_into_iter, msm::VariableBaseMSM};
+use super::{push_constraints, r1cs_to_qap::R1CStoQAP, PreparedProvingKey, Proof, ProvingKey};
+use crate::{
+    cfg_into_iter,
+    msm::{FixedBaseMSM, VariableBaseMSM},
+};
 use snarkvm_curves::traits::{AffineCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, PrimeField, Zero};
 use snarkvm_r1cs::errors::SynthesisError;
 
 use snarkvm_profiler::{end_timer, start_timer};
-use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, Index, LinearCombination, Variable};
-use snarkvm_utilities::rand::UniformRand;
+use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, Index, LinearCombination, PackedAssignment, Variable};
+use snarkvm_utilities::{capabilities::warn_if_sequential, rand::UniformRand};
 
 use core::ops::Mul;
 use rand::Rng;
@@ -30,15 +33,30 @@ use rand::Rng;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// The number of constraints above which proving without the `parallel` feature prints a warning.
+const LARGE_CIRCUIT_NUM_CONSTRAINTS: usize = 1 << 16;
+
 pub struct ProvingAssignment<E: PairingEngine> {
     // Constraints
     pub(crate) at: Vec<Vec<(E::Fr, Index)>>,
     pub(crate) bt: Vec<Vec<(E::Fr, Index)>>,
     pub(crate) ct: Vec<Vec<(E::Fr, Index)>>,
 
-    // Assignments of variables
-    pub(crate) public_variables: Vec<E::Fr>,
-    pub(crate) private_variables: Vec<E::Fr>,
+    // Assignments of variables; Booleans are stored as single bits
+    pub(crate) public_variables: PackedAssignment<E::Fr>,
+    pub(crate) private_variables: PackedAssignment<E::Fr>,
+}
+
+impl<E: PairingEngine> ProvingAssignment<E> {
+    pub(crate) fn new() -> Self {
+        Self {
+            at: vec![],
+            bt: vec![],
+            ct: vec![],
+            public_variables: PackedAssignment::new(),
+            private_variables: PackedAssignment::new(),
+        }
+    }
 }
 
 impl<E: PairingEngine> ConstraintSystem<E::Fr> for ProvingAssignment<E> {
@@ -111,6 +129,60 @@ impl<E: PairingEngine> ConstraintSystem<E::Fr> for ProvingAssignment<E> {
     }
 }
 
+/// The window size of the fixed-base tables in a prepared proving key.
+const PREPARED_PROVING_KEY_WINDOW_SIZE: usize = 4;
+
+/// The products of the fixed proving key elements with the proof randomness.
+struct RandomnessTerms<E: PairingEngine> {
+    r_g1: E::G1Projective,
+    s_g1: E::G1Projective,
+    s_g2: E::G2Projective,
+    r_s_delta_g1: E::G1Projective,
+}
+
+impl<E: PairingEngine> RandomnessTerms<E> {
+    fn new(params: &ProvingKey<E>, r: E::Fr, s: E::Fr) -> Self {
+        Self {
+            r_g1: params.delta_g1.mul(r).into_projective(),
+            s_g1: params.delta_g1.mul(s).into_projective(),
+            s_g2: params.vk.delta_g2.mul(s).into_projective(),
+            r_s_delta_g1: params.delta_g1.into_projective().mul(r).mul(s),
+        }
+    }
+
+    fn new_prepared(prepared: &PreparedProvingKey<E>, r: E::Fr, s: E::Fr) -> Self {
+        let window = prepared.window_size;
+
+        Self {
+            r_g1: FixedBaseMSM::windowed_scalar_mul(window, &prepared.delta_g1_table, &r),
+            s_g1: FixedBaseMSM::windowed_scalar_mul(window, &prepared.delta_g1_table, &s),
+            s_g2: FixedBaseMSM::windowed_scalar_mul(window, &prepared.delta_g2_table, &s),
+            r_s_delta_g1: FixedBaseMSM::windowed_scalar_mul(window, &prepared.delta_g1_table, &(r * s)),
+        }
+    }
+}
+
+/// Prepares the proving key by computing the fixed-base window tables
+/// for the proving key elements that are multiplied by the proof randomness.
+pub fn prepare_proving_key<E: PairingEngine>(proving_key: ProvingKey<E>) -> PreparedProvingKey<E> {
+    let prepare_time = start_timer!(|| "Prepare proving key");
+    let scalar_bits = E::Fr::size_in_bits();
+    let window_size = PREPARED_PROVING_KEY_WINDOW_SIZE;
+
+    let delta_g1_table =
+        FixedBaseMSM::get_window_table(scalar_bits, window_size, proving_key.delta_g1.into_projective());
+    let delta_g2_table =
+        FixedBaseMSM::get_window_table(scalar_bits, window_size, proving_key.vk.delta_g2.into_projective());
+    end_timer!(prepare_time);
+
+    PreparedProvingKey {
+        proving_key,
+        window_size,
+        delta_g1_table,
+        delta_g2_table,
+    }
+}
+
 pub fn create_random_proof<E, C, R>(
     circuit: &C,
     params: &ProvingKey<E>,
@@ -127,6 +199,22 @@ where
     create_proof::<E, C>(circuit, params, r, s)
 }
 
+pub fn create_random_proof_with_prepared_key<E, C, R>(
+    circuit: &C,
+    prepared_params: &PreparedProvingKey<E>,
+    rng: &mut R,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+    R: Rng,
+{
+    let r = E::Fr::rand(rng);
+    let s = E::Fr::rand(rng);
+
+    create_proof_with_prepared_key::<E, C>(circuit, prepared_params, r, s)
+}
+
 pub fn create_proof_no_zk<E, C>(circuit: &C, params: &ProvingKey<E>) -> Result<Proof<E>, SynthesisError>
 where
     E: PairingEngine,
@@ -136,18 +224,42 @@ where
 }
 
 pub fn create_proof<E, C>(circuit: &C, params: &ProvingKey<E>, r: E::Fr, s: E::Fr) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+{
+    create_proof_with_randomness_terms::<E, C>(circuit, params, RandomnessTerms::new(params, r, s), r, s)
+}
+
+/// Creates a proof using the fixed-base window tables of the prepared proving key.
+/// The resulting proof is identical to the one returned by `create_proof` for the same randomness.
+pub fn create_proof_with_prepared_key<E, C>(
+    circuit: &C,
+    prepared_params: &PreparedProvingKey<E>,
+    r: E::Fr,
+    s: E::Fr,
+) -> Result<Proof<E>, SynthesisError>
+where
+    E: PairingEngine,
+    C: ConstraintSynthesizer<E::Fr>,
+{
+    let randomness_terms = RandomnessTerms::new_prepared(prepared_params, r, s);
+    create_proof_with_randomness_terms::<E, C>(circuit, &prepared_params.proving_key, randomness_terms, r, s)
+}
+
+fn create_proof_with_randomness_terms<E, C>(
+    circuit: &C,
+    params: &ProvingKey<E>,
+    randomness_terms: RandomnessTerms<E>,
+    r: E::Fr,
+    s: E::Fr,
+) -> Result<Proof<E>, SynthesisError>
 where
     E: PairingEngine,
     C: ConstraintSynthesizer<E::Fr>,
 {
     let prover_time = start_timer!(|| "Prover");
-    let mut prover = ProvingAssignment {
-        at: vec![],
-        bt: vec![],
-        ct: vec![],
-        public_variables: vec![],
-        private_variables: vec![],
-    };
+    let mut prover = ProvingAssignment::new();
 
     // Allocate the "one" input variable
     prover.alloc_input(|| "", || Ok(E::Fr::one()))?;
@@ -157,19 +269,22 @@ where
     circuit.generate_constraints(&mut prover)?;
     end_timer!(synthesis_time);
 
+    if prover.num_constraints() > LARGE_CIRCUIT_NUM_CONSTRAINTS {
+        warn_if_sequential("Groth16 proving", crate::PARALLEL_ENABLED);
+    }
+
     let witness_map_time = start_timer!(|| "R1CS to QAP witness map");
     let h = R1CStoQAP::witness_map::<E>(&prover)?;
     end_timer!(witness_map_time);
 
-    let input_assignment = prover
-        .public_variables
-        .iter()
-        .skip(1)
-        .map(|s| s.into_repr())
+    let public_variables = &prover.public_variables;
+    let input_assignment = cfg_into_iter!(1..public_variables.len())
+        .map(|i| public_variables.get(i).into_repr())
         .collect::<Vec<_>>();
 
-    let aux_assignment = cfg_into_iter!(prover.private_variables)
-        .map(|s| s.into_repr())
+    let private_variables = &prover.private_variables;
+    let aux_assignment = cfg_into_iter!(0..private_variables.len())
+        .map(|i| private_variables.get(i).into_repr())
         .collect::<Vec<_>>();
 
     let assignment = [&input_assignment[..], &aux_assignment[..]].concat();
@@ -179,19 +294,16 @@ where
     // Compute A
     let a_acc_time = start_timer!(|| "Compute A");
     let a_query = &params.a_query;
-    let r_g1 = params.delta_g1.mul(r);
-
-    let g_a = calculate_coeff(r_g1.into(), a_query, params.vk.alpha_g1, &assignment);
+    let g_a = calculate_coeff(randomness_terms.r_g1, a_query, params.vk.alpha_g1, &assignment);
 
     end_timer!(a_acc_time);
 
     // Compute B in G1 if needed
     let g1_b = if r != E::Fr::zero() {
         let b_g1_acc_time = start_timer!(|| "Compute B in G1");
-        let s_g1 = params.delta_g1.mul(s);
         let b_query = &params.b_g1_query;
 
-        let g1_b = calculate_coeff(s_g1.into(), b_query, params.beta_g1, &assignment);
+        let g1_b = calculate_coeff(randomness_terms.s_g1, b_query, params.beta_g1, &assignment);
 
         end_timer!(b_g1_acc_time);
 
@@ -203,8 +315,7 @@ where
     // Compute B in G2
     let b_g2_acc_time = start_timer!(|| "Compute B in G2");
     let b_query = &params.b_g2_query;
-    let s_g2 = params.vk.delta_g2.mul(s);
-    let g2_b = calculate_coeff(s_g2.into(), &b_query, params.vk.beta_g2, &assignment);
+    let g2_b = calculate_coeff(randomness_terms.s_g2, &b_query, params.vk.beta_g2, &assignment);
 
     end_timer!(b_g2_acc_time);
 
@@ -219,11 +330,10 @@ where
 
     let s_g_a = g_a.mul(s);
     let r_g1_b = g1_b.mul(r);
-    let r_s_delta_g1 = params.delta_g1.into_projective().mul(r).mul(s);
 
     let mut g_c = s_g_a;
     g_c += r_g1_b;
-    g_c -= &r_s_delta_g1;
+    g_c -= &randomness_terms.r_s_delta_g1;
     g_c += l_aux_acc;
     g_c += h_acc;
     end_timer!(c_acc_time);
@@ -22,17 +22,22 @@ use snarkvm_r1cs::{
     errors::{SynthesisError, SynthesisResult},
     ConstraintSystem,
     Index,
+    PackedAssignment,
 };
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-fn evaluate_constraint<E: PairingEngine>(terms: &[(E::Fr, Index)], assignment: &[E::Fr], num_input: usize) -> E::Fr {
+fn evaluate_constraint<E: PairingEngine>(
+    terms: &[(E::Fr, Index)],
+    public_variables: &PackedAssignment<E::Fr>,
+    private_variables: &PackedAssignment<E::Fr>,
+) -> E::Fr {
     let mut acc = E::Fr::zero();
     for &(coeff, index) in terms {
         let val = match index {
-            Index::Public(i) => assignment[i],
-            Index::Private(i) => assignment[num_input + i],
+            Index::Public(i) => public_variables.get(i),
+            Index::Private(i) => private_variables.get(i),
         };
         acc += val * coeff;
     }
@@ -103,11 +108,11 @@ impl R1CStoQAP {
     #[inline]
     pub(crate) fn witness_map<E: PairingEngine>(prover: &ProvingAssignment<E>) -> SynthesisResult<Vec<E::Fr>> {
         let zero = E::Fr::zero();
-        let num_inputs = prover.public_variables.len();
+        let public_variables = &prover.public_variables;
+        let private_variables = &prover.private_variables;
+        let num_inputs = public_variables.len();
         let num_constraints = prover.num_constraints();
 
-        let full_input_assignment = [&prover.public_variables[..], &prover.private_variables[..]].concat();
-
         let domain = EvaluationDomain::<E::Fr>::new(num_constraints + num_inputs)
             .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
         let domain_size = domain.size();
@@ -120,11 +125,14 @@ impl R1CStoQAP {
             .zip(cfg_iter!(&prover.at))
             .zip(cfg_iter!(&prover.bt))
             .for_each(|(((a, b), at_i), bt_i)| {
-                *a = evaluate_constraint::<E>(&at_i, &full_input_assignment, num_inputs);
-                *b = evaluate_constraint::<E>(&bt_i, &full_input_assignment, num_inputs);
+                *a = evaluate_constraint::<E>(&at_i, public_variables, private_variables);
+                *b = evaluate_constraint::<E>(&bt_i, public_variables, private_variables);
             });
 
-        a[num_constraints..(num_inputs + num_constraints)].clone_from_slice(&full_input_assignment[..num_inputs]);
+        a[num_constraints..(num_inputs + num_constraints)]
+            .iter_mut()
+            .zip(public_variables.iter())
+            .for_each(|(a, input)| *a = input);
 
         domain.ifft_in_place(&mut a);
         domain.ifft_in_place(&mut b);
@@ -140,7 +148,7 @@ impl R1CStoQAP {
         cfg_iter_mut!(c[..prover.num_constraints()])
             .enumerate()
             .for_each(|(i, c)| {
-                *c = evaluate_constraint::<E>(&prover.ct[i], &full_input_assignment, num_inputs);
+                *c = evaluate_constraint::<E>(&prover.ct[i], public_variables, private_variables);
             });
 
         domain.ifft_in_place(&mut c);
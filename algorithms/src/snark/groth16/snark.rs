@@ -16,9 +16,13 @@
 
 use super::{
     create_random_proof,
+    create_random_proof_with_prepared_key,
     generate_random_parameters,
+    generate_random_parameters_with_progress,
+    prepare_proving_key,
     prepare_verifying_key,
     verify_proof,
+    PreparedProvingKey,
     PreparedVerifyingKey,
     Proof,
     ProvingKey,
@@ -46,6 +50,7 @@ impl<E: PairingEngine, C: ConstraintSynthesizer<E::Fr>, V: ToConstraintField<E::
 {
     type AllocatedCircuit = C;
     type Circuit = C;
+    type PreparedProvingKey = PreparedProvingKey<E>;
     type PreparedVerifyingKey = PreparedVerifyingKey<E>;
     type Proof = Proof<E>;
     type ProvingKey = ProvingKey<E>;
@@ -63,6 +68,18 @@ impl<E: PairingEngine, C: ConstraintSynthesizer<E::Fr>, V: ToConstraintField<E::
         Ok((pp, vk))
     }
 
+    fn setup_with_progress<R: Rng>(
+        circuit: &Self::Circuit,
+        rng: &mut R,
+        progress: &(dyn Fn(f32) + Sync),
+    ) -> Result<(Self::ProvingKey, Self::PreparedVerifyingKey), SNARKError> {
+        let setup_time = start_timer!(|| "{Groth 2016}::Setup");
+        let pp = generate_random_parameters_with_progress::<E, Self::Circuit, R>(circuit, rng, progress)?;
+        let vk = prepare_verifying_key(pp.vk.clone());
+        end_timer!(setup_time);
+        Ok((pp, vk))
+    }
+
     fn prove<R: Rng>(
         proving_key: &Self::ProvingKey,
         input_and_witness: &Self::AllocatedCircuit,
@@ -74,6 +91,21 @@ impl<E: PairingEngine, C: ConstraintSynthesizer<E::Fr>, V: ToConstraintField<E::
         Ok(result)
     }
 
+    fn prepare_proving_key(proving_key: Self::ProvingKey) -> Result<Self::PreparedProvingKey, SNARKError> {
+        Ok(prepare_proving_key(proving_key))
+    }
+
+    fn prove_with_prepared_key<R: Rng>(
+        prepared_proving_key: &Self::PreparedProvingKey,
+        input_and_witness: &Self::AllocatedCircuit,
+        rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        let proof_time = start_timer!(|| "{Groth 2016}::Prove with prepared key");
+        let result = create_random_proof_with_prepared_key::<E, _, _>(input_and_witness, prepared_proving_key, rng)?;
+        end_timer!(proof_time);
+        Ok(result)
+    }
+
     fn verify(
         verifying_key: &Self::PreparedVerifyingKey,
         input: &Self::VerifierInput,
@@ -89,4 +121,11 @@ impl<E: PairingEngine, C: ConstraintSynthesizer<E::Fr>, V: ToConstraintField<E::
         end_timer!(verify_time);
         Ok(result)
     }
+
+    /// Accepts the lengths of both the compressed and the uncompressed proof encodings.
+    fn is_valid_proof_size(size: usize) -> bool {
+        [Proof::<E>::compressed_proof_size(), Proof::<E>::uncompressed_proof_size()]
+            .iter()
+            .any(|expected_size| matches!(expected_size, Ok(expected_size) if *expected_size == size))
+    }
 }
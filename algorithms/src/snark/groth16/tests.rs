@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_fields::{Field, Zero};
-use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem, LinearCombination};
 
 struct MySillyCircuit<F: Field> {
     a: Option<F>,
@@ -48,12 +48,77 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for MySillyCircuit<C
     }
 }
 
+/// A circuit that allocates `bits` as Booleans and packs every 64 of them into a private
+/// field element; the sum of the packed elements is the public input.
+struct PackingCircuit {
+    bits: Vec<bool>,
+}
+
+impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for PackingCircuit {
+    fn generate_constraints<CS: ConstraintSystem<ConstraintF>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let mut sum_lc = LinearCombination::zero();
+        let mut sum = ConstraintF::zero();
+
+        for (i, chunk) in self.bits.chunks(64).enumerate() {
+            let mut packed_lc = LinearCombination::zero();
+            let mut packed = ConstraintF::zero();
+            let mut coeff = ConstraintF::one();
+
+            for (j, bit) in chunk.iter().enumerate() {
+                let value = if *bit { ConstraintF::one() } else { ConstraintF::zero() };
+                let b = cs.alloc(|| format!("bit {} {}", i, j), || Ok(value))?;
+                cs.enforce(|| format!("boolean {} {}", i, j), |lc| lc + b, |lc| lc + b, |lc| lc + b);
+
+                packed_lc = packed_lc + (coeff, b);
+                packed += &(value * &coeff);
+                coeff.double_in_place();
+            }
+
+            let packed_var = cs.alloc(|| format!("packed {}", i), || Ok(packed))?;
+            cs.enforce(
+                || format!("pack {}", i),
+                |_| packed_lc,
+                |lc| lc + CS::one(),
+                |lc| lc + packed_var,
+            );
+
+            sum_lc = sum_lc + packed_var;
+            sum += &packed;
+        }
+
+        let sum_var = cs.alloc_input(|| "sum", || Ok(sum))?;
+        cs.enforce(|| "sum", |_| sum_lc, |lc| lc + CS::one(), |lc| lc + sum_var);
+
+        Ok(())
+    }
+}
+
 mod bls12_377 {
     use super::*;
-    use crate::snark::groth16::{create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof};
-    use core::ops::MulAssign;
-    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
-    use snarkvm_utilities::rand::{test_rng, UniformRand};
+    use crate::snark::groth16::{
+        create_proof,
+        create_proof_with_prepared_key,
+        create_random_proof,
+        create_random_proof_with_prepared_key,
+        generate_random_parameters,
+        generate_random_parameters_with_progress,
+        prepare_proving_key,
+        prepare_verifying_key,
+        verify_proof,
+        ProvingAssignment,
+    };
+    use core::{mem::size_of, ops::MulAssign};
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_fields::ToConstraintField;
+    use snarkvm_utilities::{
+        bytes::ToBytes,
+        rand::{test_rng, UniformRand},
+        to_bytes,
+    };
+
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+    use std::sync::Mutex;
 
     #[test]
     fn prove_and_verify() {
@@ -75,6 +140,119 @@ mod bls12_377 {
             assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
         }
     }
+
+    #[test]
+    fn prove_with_prepared_key_and_verify() {
+        let rng = &mut test_rng();
+
+        let parameters =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+        let pvk = prepare_verifying_key::<Bls12_377>(parameters.vk.clone());
+        let prepared_parameters = prepare_proving_key(parameters.clone());
+
+        for _ in 0..10 {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let c = a * b;
+            let circuit = MySillyCircuit { a: Some(a), b: Some(b) };
+
+            // The prepared and unprepared provers must produce the same proof for the same randomness.
+            let (r, s) = (Fr::rand(rng), Fr::rand(rng));
+            let proof = create_proof(&circuit, &parameters, r, s).unwrap();
+            let prepared_proof = create_proof_with_prepared_key(&circuit, &prepared_parameters, r, s).unwrap();
+            assert_eq!(proof, prepared_proof);
+
+            let proof = create_random_proof_with_prepared_key(&circuit, &prepared_parameters, rng).unwrap();
+            assert!(verify_proof(&pvk, &proof, &[c]).unwrap());
+            assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
+        }
+    }
+
+    #[test]
+    fn prove_mixed_assignment_is_deterministic() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+
+        let bits = (0..200).map(|_| rng.gen()).collect::<Vec<bool>>();
+        let circuit = PackingCircuit { bits };
+
+        let parameters = generate_random_parameters::<Bls12_377, _, _>(&circuit, rng).unwrap();
+        let (r, s) = (Fr::rand(rng), Fr::rand(rng));
+        let proof = create_proof(&circuit, &parameters, r, s).unwrap();
+
+        let proof_hex = to_bytes![proof]
+            .unwrap()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+        // The proof for a circuit mixing Boolean and general assignments is pinned, so that
+        // changes to how the prover stores and reads the assignment cannot alter its output.
+        let expected_proof_hex = "e1fcf6197fd02c42d8913d903cd905df9fad78cc552418262c2fa61b341ac5665f25831a7b84bd110c9885f15896550111dcc9f86b06fec89dcfac849af6d682a7637d3c7ea0d4c3d90ebdc1c27b8b453225d5c2f671a8e014be49398a7f9d0195e3277e51892a3d23ee7cb8cbb230b0e305f65e6a908a8966ea813660dbf05a1d4d8f79e908fc9243d9eab9fa5433807ca19ea48b873461880018e48526565444ae7dfe67aad911ad90ee4f3170ce04eff47fdcefd9286c40aac35ee376638001";
+        assert_eq!(proof_hex, expected_proof_hex);
+    }
+
+    #[test]
+    fn generate_parameters_with_progress() {
+        let bits = (0..5000).map(|i| i % 5 == 0).collect::<Vec<bool>>();
+        let circuit = PackingCircuit { bits };
+
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+        let expected = generate_random_parameters::<Bls12_377, _, _>(&circuit, rng).unwrap();
+
+        let reported = Mutex::new(vec![]);
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+        let candidate = generate_random_parameters_with_progress::<Bls12_377, _, _>(&circuit, rng, &|fraction| {
+            reported.lock().unwrap().push(fraction)
+        })
+        .unwrap();
+
+        // Reporting the progress does not change the parameters.
+        assert_eq!(to_bytes![expected].unwrap(), to_bytes![candidate].unwrap());
+
+        // The progress is reported more than once, and reaches completion.
+        let reported = reported.into_inner().unwrap();
+        assert!(reported.len() > 1);
+        assert!(reported.iter().all(|fraction| *fraction > 0.0 && *fraction <= 1.0));
+        assert_eq!(reported.iter().cloned().fold(0.0, f32::max), 1.0);
+    }
+
+    #[test]
+    fn prove_packs_boolean_assignments() {
+        const NUM_BOOLEANS: usize = 1 << 20;
+
+        let bits = (0..NUM_BOOLEANS).map(|i| i % 3 == 0).collect();
+        let circuit = PackingCircuit { bits };
+
+        let mut prover = ProvingAssignment::<Bls12_377>::new();
+        circuit.generate_constraints(&mut prover).unwrap();
+        assert!(prover.num_constraints() > NUM_BOOLEANS);
+
+        // Compare against storing every assignment as a full field element.
+        let num_variables = prover.num_public_variables() + prover.num_private_variables();
+        let unpacked_size = num_variables * size_of::<Fr>();
+        let packed_size = prover.public_variables.heap_size() + prover.private_variables.heap_size();
+        println!(
+            "{} variables: {} bytes packed, {} bytes unpacked",
+            num_variables, packed_size, unpacked_size
+        );
+        assert!(packed_size * 16 < unpacked_size);
+    }
+
+    #[test]
+    fn verifying_key_to_field_elements() {
+        let rng = &mut test_rng();
+
+        let parameters =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+        let vk = &parameters.vk;
+        let elements: Vec<Fq> = vk.to_field_elements().unwrap();
+
+        // A G1 element is encoded as (x, y, infinity), and a G2 element as (x.c0, x.c1, y.c0, y.c1, infinity).
+        assert_eq!(elements.len(), 3 + 3 * 5 + 1 + 3 * vk.gamma_abc_g1.len());
+        assert_eq!(&elements[..3], &vk.alpha_g1.to_field_elements().unwrap()[..]);
+        assert_eq!(&elements[3..8], &vk.beta_g2.to_field_elements().unwrap()[..]);
+        assert_eq!(elements[18], Fq::from(vk.gamma_abc_g1.len() as u64));
+        assert_eq!(&elements[19..22], &vk.gamma_abc_g1[0].to_field_elements().unwrap()[..]);
+    }
 }
 
 mod bw6_761 {
@@ -105,7 +283,10 @@ mod bw6_761 {
 
 mod serialization {
     use super::*;
-    use crate::snark::groth16::{create_random_proof, generate_random_parameters, Proof};
+    use crate::{
+        snark::groth16::{create_random_proof, generate_random_parameters, Groth16, Proof},
+        traits::SNARK,
+    };
     use snarkvm_curves::bls12_377::{Bls12_377, Fr};
     use snarkvm_utilities::{
         bytes::{FromBytes, ToBytes},
@@ -161,4 +342,18 @@ mod serialization {
         let recovered_proof: Proof<Bls12_377> = FromBytes::read(&uncompressed_serialization[..]).unwrap();
         assert_eq!(recovered_proof.compressed, false);
     }
+
+    #[test]
+    fn test_is_valid_proof_size() {
+        type TestSNARK = Groth16<Bls12_377, MySillyCircuit<Fr>, [Fr]>;
+
+        let compressed_proof_size = Proof::<Bls12_377>::compressed_proof_size().unwrap();
+        let uncompressed_proof_size = Proof::<Bls12_377>::uncompressed_proof_size().unwrap();
+
+        assert!(TestSNARK::is_valid_proof_size(compressed_proof_size));
+        assert!(TestSNARK::is_valid_proof_size(uncompressed_proof_size));
+        assert!(!TestSNARK::is_valid_proof_size(0));
+        assert!(!TestSNARK::is_valid_proof_size(compressed_proof_size + 1));
+        assert!(!TestSNARK::is_valid_proof_size(uncompressed_proof_size - 1));
+    }
 }
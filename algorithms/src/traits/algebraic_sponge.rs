@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::PrimeField;
+
+/// An operation on an algebraic sponge, recorded by the number of field elements it takes in or out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TranscriptOp {
+    /// Absorbs the given number of field elements.
+    Absorb(usize),
+    /// Squeezes the given number of field elements.
+    Squeeze(usize),
+}
+
+/// The cost of a transcript on an algebraic sponge.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TranscriptCost {
+    /// The number of permutations invoked.
+    pub permutations: usize,
+    /// The number of field elements absorbed.
+    pub absorbed_elements: usize,
+    /// The number of field elements squeezed.
+    pub squeezed_elements: usize,
+}
+
+pub trait AlgebraicSponge<BaseField: PrimeField>: Clone {
+    /// Initializes an algebraic sponge.
+    fn new() -> Self;
+    /// Takes in field elements.
+    fn absorb(&mut self, elems: &[BaseField]);
+    /// Takes out field elements.
+    fn squeeze(&mut self, num: usize) -> Vec<BaseField>;
+    /// Returns the cost of the transcript run on this sponge so far.
+    fn transcript_cost(&self) -> TranscriptCost;
+    /// Returns the cost of running the given schedule on a new sponge, without running it.
+    fn estimate_cost(schedule: &[TranscriptOp]) -> TranscriptCost;
+}
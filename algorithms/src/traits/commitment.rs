@@ -33,4 +33,10 @@ pub trait CommitmentScheme: Sized + Clone + From<<Self as CommitmentScheme>::Par
     fn commit(&self, input: &[u8], randomness: &Self::Randomness) -> Result<Self::Output, CommitmentError>;
 
     fn parameters(&self) -> &Self::Parameters;
+
+    /// Checks that the parameters are well-formed, e.g. after reading them from bytes.
+    /// Schemes whose parameters have no structure to check accept any parameters.
+    fn validate(&self) -> Result<(), CommitmentError> {
+        Ok(())
+    }
 }
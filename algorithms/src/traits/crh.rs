@@ -25,6 +25,10 @@ use std::{
 
 pub trait CRHParameters: Clone + Debug + ToBytes + FromBytes + Eq {
     fn setup<R: Rng>(r: &mut R) -> Self;
+
+    /// Returns a digest of the parameters, which identifies them: parameters are equal
+    /// if and only if their digests are equal, barring a collision of the digest.
+    fn digest(&self) -> [u8; 32];
 }
 
 pub trait CRH: Clone + From<<Self as CRH>::Parameters> {
@@ -38,4 +42,10 @@ pub trait CRH: Clone + From<<Self as CRH>::Parameters> {
     fn hash(&self, input: &[u8]) -> Result<Self::Output, CRHError>;
 
     fn parameters(&self) -> &Self::Parameters;
+
+    /// Checks that the parameters are well-formed, e.g. after reading them from bytes.
+    /// Schemes whose parameters have no structure to check accept any parameters.
+    fn validate(&self) -> Result<(), CRHError> {
+        Ok(())
+    }
 }
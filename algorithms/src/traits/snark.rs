@@ -23,6 +23,7 @@ use std::fmt::Debug;
 pub trait SNARK {
     type AllocatedCircuit;
     type Circuit;
+    type PreparedProvingKey: Clone;
     type PreparedVerifyingKey: Clone + From<Self::ProvingKey> + From<Self::VerifyingKey>;
     type Proof: Clone + Debug + ToBytes + FromBytes;
     type ProvingKey: Clone + ToBytes + FromBytes;
@@ -34,15 +35,42 @@ pub trait SNARK {
         rng: &mut R,
     ) -> Result<(Self::ProvingKey, Self::PreparedVerifyingKey), SNARKError>;
 
+    /// Runs `setup`, calling `progress` with the fraction of the work done so far.
+    /// By default, the progress is only reported once the setup is complete.
+    fn setup_with_progress<R: Rng>(
+        circuit: &Self::Circuit,
+        rng: &mut R,
+        progress: &(dyn Fn(f32) + Sync),
+    ) -> Result<(Self::ProvingKey, Self::PreparedVerifyingKey), SNARKError> {
+        let result = Self::setup(circuit, rng)?;
+        progress(1.0);
+        Ok(result)
+    }
+
     fn prove<R: Rng>(
         proving_key: &Self::ProvingKey,
         input_and_witness: &Self::AllocatedCircuit,
         rng: &mut R,
     ) -> Result<Self::Proof, SNARKError>;
 
+    /// Prepares the proving key for repeated use in `prove_with_prepared_key`.
+    fn prepare_proving_key(proving_key: Self::ProvingKey) -> Result<Self::PreparedProvingKey, SNARKError>;
+
+    fn prove_with_prepared_key<R: Rng>(
+        prepared_proving_key: &Self::PreparedProvingKey,
+        input_and_witness: &Self::AllocatedCircuit,
+        rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError>;
+
     fn verify(
         verifying_key: &Self::PreparedVerifyingKey,
         input: &Self::VerifierInput,
         proof: &Self::Proof,
     ) -> Result<bool, SNARKError>;
+
+    /// Returns `true` if a proof encoding of `size` bytes may be read back as a `Self::Proof`.
+    /// By default, any non-empty encoding is accepted.
+    fn is_valid_proof_size(size: usize) -> bool {
+        size > 0
+    }
 }
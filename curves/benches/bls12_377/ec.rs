@@ -17,12 +17,14 @@
 pub(crate) mod g1 {
     use snarkvm_curves::{
         bls12_377::{Fr, G1Affine, G1Projective as G1},
-        traits::ProjectiveCurve,
+        templates::short_weierstrass::wnaf::double_and_add,
+        traits::{AffineCurve, ProjectiveCurve},
         Group,
     };
+    use snarkvm_fields::{PrimeField, Zero};
     use snarkvm_utilities::rand::UniformRand;
 
-    use criterion::Criterion;
+    use criterion::{BenchmarkId, Criterion};
     use rand::SeedableRng;
     use rand_xorshift::XorShiftRng;
     use std::ops::{AddAssign, MulAssign};
@@ -49,6 +51,40 @@ pub(crate) mod g1 {
         });
     }
 
+    pub fn bench_g1_mul_double_and_add(c: &mut Criterion) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let v: Vec<(G1, Fr)> = (0..SAMPLES).map(|_| (G1::rand(&mut rng), Fr::rand(&mut rng))).collect();
+
+        let mut count = 0;
+        c.bench_function("bls12_377: g1_mul_double_and_add", |c| {
+            c.iter(|| {
+                let tmp = double_and_add(&v[count].0, v[count].1.into_repr());
+                count = (count + 1) % SAMPLES;
+                tmp
+            })
+        });
+    }
+
+    pub fn bench_g1_scale_by_cofactor(c: &mut Criterion) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let v: Vec<G1Affine> = (0..SAMPLES).map(|_| G1::rand(&mut rng).into_affine()).collect();
+
+        let mut count = 0;
+        c.bench_function("bls12_377: g1_scale_by_cofactor", |c| {
+            c.iter(|| {
+                let tmp = v[count].scale_by_cofactor();
+                count = (count + 1) % SAMPLES;
+                tmp
+            })
+        });
+    }
+
     pub fn bench_g1_add_assign(c: &mut Criterion) {
         const SAMPLES: usize = 1000;
 
@@ -87,6 +123,31 @@ pub(crate) mod g1 {
         });
     }
 
+    pub fn bench_g1_add_assign_mixed_many(c: &mut Criterion) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let start = G1::rand(&mut rng);
+        let v: Vec<G1Affine> = (0..SAMPLES).map(|_| G1::rand(&mut rng).into()).collect();
+
+        c.bench_function("bls12_377: g1_add_assign_mixed_loop", |c| {
+            c.iter(|| {
+                let mut tmp = start;
+                v.iter().for_each(|point| tmp.add_assign_mixed(point));
+                tmp
+            })
+        });
+
+        c.bench_function("bls12_377: g1_add_assign_mixed_many", |c| {
+            c.iter(|| {
+                let mut tmp = start;
+                tmp.add_assign_mixed_many(&v);
+                tmp
+            })
+        });
+    }
+
     pub fn bench_g1_double(c: &mut Criterion) {
         const SAMPLES: usize = 1000;
 
@@ -104,6 +165,32 @@ pub(crate) mod g1 {
             })
         });
     }
+
+    /// Compares `ProjectiveCurve::msm` with the naive sum of products. On a single thread,
+    /// the multi-scalar multiplication overtakes the naive sum at around 8 scalars.
+    pub fn bench_g1_msm(c: &mut Criterion) {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let mut group = c.benchmark_group("bls12_377: g1_msm");
+        for size in [1, 2, 4, 8, 16, 64, 256, 1024].iter() {
+            let bases: Vec<G1Affine> = (0..*size).map(|_| G1::rand(&mut rng).into_affine()).collect();
+            let scalars: Vec<Fr> = (0..*size).map(|_| Fr::rand(&mut rng)).collect();
+            let scalar_reprs: Vec<_> = scalars.iter().map(|scalar| scalar.into_repr()).collect();
+
+            group.bench_with_input(BenchmarkId::new("msm", size), size, |c, _| {
+                c.iter(|| G1::msm(&bases, &scalar_reprs))
+            });
+            group.bench_with_input(BenchmarkId::new("naive", size), size, |c, _| {
+                c.iter(|| {
+                    bases
+                        .iter()
+                        .zip(&scalars)
+                        .fold(G1::zero(), |sum, (base, scalar)| sum + base.into_projective() * *scalar)
+                })
+            });
+        }
+        group.finish();
+    }
 }
 
 pub(crate) mod g2 {
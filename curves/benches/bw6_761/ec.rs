@@ -17,9 +17,11 @@
 pub(crate) mod g1 {
     use snarkvm_curves::{
         bw6_761::{Fr, G1Affine, G1Projective as G1},
+        templates::short_weierstrass::wnaf::double_and_add,
         traits::ProjectiveCurve,
         Group,
     };
+    use snarkvm_fields::PrimeField;
     use snarkvm_utilities::rand::UniformRand;
 
     use criterion::Criterion;
@@ -50,6 +52,23 @@ pub(crate) mod g1 {
         });
     }
 
+    pub fn bench_g1_mul_double_and_add(c: &mut Criterion) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let v: Vec<(G1, Fr)> = (0..SAMPLES).map(|_| (G1::rand(&mut rng), Fr::rand(&mut rng))).collect();
+
+        let mut count = 0;
+        c.bench_function("bw6_761: g1_mul_double_and_add", |c| {
+            c.iter(|| {
+                let tmp = double_and_add(&v[count].0, v[count].1.into_repr());
+                count = (count + 1) % SAMPLES;
+                tmp
+            })
+        });
+    }
+
     pub fn bench_g1_add_assign(c: &mut Criterion) {
         const SAMPLES: usize = 1000;
 
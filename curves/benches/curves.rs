@@ -23,9 +23,13 @@ criterion_group!(
     bls12_377_ec,
     bls12_377::ec::g1::bench_g1_rand,
     bls12_377::ec::g1::bench_g1_mul_assign,
+    bls12_377::ec::g1::bench_g1_mul_double_and_add,
+    bls12_377::ec::g1::bench_g1_scale_by_cofactor,
     bls12_377::ec::g1::bench_g1_add_assign,
     bls12_377::ec::g1::bench_g1_add_assign_mixed,
+    bls12_377::ec::g1::bench_g1_add_assign_mixed_many,
     bls12_377::ec::g1::bench_g1_double,
+    bls12_377::ec::g1::bench_g1_msm,
     bls12_377::ec::g2::bench_g2_rand,
     bls12_377::ec::g2::bench_g2_mul_assign,
     bls12_377::ec::g2::bench_g2_add_assign,
@@ -103,6 +107,7 @@ criterion_group!(
     bw6_761_ec,
     bw6_761::ec::g1::bench_g1_rand,
     bw6_761::ec::g1::bench_g1_mul_assign,
+    bw6_761::ec::g1::bench_g1_mul_double_and_add,
     bw6_761::ec::g1::bench_g1_add_assign,
     bw6_761::ec::g1::bench_g1_add_assign_mixed,
     bw6_761::ec::g1::bench_g1_double,
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_fields::{FftParameters, FieldParameters, Fp256, Fp256Parameters};
+use snarkvm_fields::{FftParameters, FieldParameters, Fp256, Fp256Parameters, PoseidonMDSParameters};
 use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
 
 /// BLS12-377 scalar field.
@@ -137,3 +137,72 @@ impl FieldParameters for FrParameters {
         0x12ab,
     ]);
 }
+
+// The MDS matrix is the Cauchy matrix `1 / (i + j + 3)`, in Montgomery form.
+impl PoseidonMDSParameters for FrParameters {
+    const POSEIDON_ALPHA: u64 = 17;
+    const POSEIDON_FULL_ROUNDS: u32 = 8;
+    const POSEIDON_MDS: [[BigInteger; 3]; 3] = [
+        [
+            BigInteger([
+                15302903146332116305u64,
+                8895355854553175376u64,
+                548696419140371791u64,
+                319366418254432479u64,
+            ]),
+            BigInteger([
+                16270238816294404093u64,
+                17510165790819614716u64,
+                15988650198697570299u64,
+                575844906362867708u64,
+            ]),
+            BigInteger([
+                2093244959304135473u64,
+                7921656493611837028u64,
+                14184576022206158435u64,
+                729731999227928846u64,
+            ]),
+        ],
+        [
+            BigInteger([
+                16270238816294404093u64,
+                17510165790819614716u64,
+                15988650198697570299u64,
+                575844906362867708u64,
+            ]),
+            BigInteger([
+                2093244959304135473u64,
+                7921656493611837028u64,
+                14184576022206158435u64,
+                729731999227928846u64,
+            ]),
+            BigInteger([
+                17237574486256691881u64,
+                7678231653376502440u64,
+                12981859904545217192u64,
+                832323394471302938u64,
+            ]),
+        ],
+        [
+            BigInteger([
+                2093244959304135473u64,
+                7921656493611837028u64,
+                14184576022206158435u64,
+                729731999227928846u64,
+            ]),
+            BigInteger([
+                17237574486256691881u64,
+                7678231653376502440u64,
+                12981859904545217192u64,
+                832323394471302938u64,
+            ]),
+            BigInteger([
+                14878706952858838161u64,
+                15410104227655356999u64,
+                17393275270132988194u64,
+                905602962502284432u64,
+            ]),
+        ],
+    ];
+    const POSEIDON_PARTIAL_ROUNDS: u32 = 31;
+}
@@ -14,12 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_fields::{field, Zero};
+use snarkvm_fields::{field, One, Zero};
 use snarkvm_utilities::biginteger::{BigInteger256, BigInteger384};
 
 use crate::{
     bls12_377::{Fq, Fr},
-    traits::{ModelParameters, SWModelParameters},
+    traits::{ModelParameters, SWModelParameters, SignConvention},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -60,11 +60,20 @@ impl SWModelParameters for Bls12_377G1Parameters {
             1114629510922847535,
         ])
     );
+    /// SIGN_CONVENTION = lexicographically largest, as in zexe and arkworks, whose
+    /// compressed BLS12-377 points are read by the Aleo parameter files.
+    const SIGN_CONVENTION: SignConvention = SignConvention::LexicographicallyLargest;
 
     #[inline(always)]
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    /// SVDW_Z = 1
+    #[inline(always)]
+    fn svdw_z() -> Self::BaseField {
+        Self::BaseField::one()
+    }
 }
 
 /// G1_GENERATOR_X =
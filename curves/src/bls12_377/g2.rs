@@ -19,7 +19,7 @@ use snarkvm_utilities::biginteger::{BigInteger256, BigInteger384};
 
 use crate::{
     bls12_377::{g1::Bls12_377G1Parameters, Fq, Fq2, Fr},
-    traits::{ModelParameters, SWModelParameters},
+    traits::{ModelParameters, SWModelParameters, SignConvention},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -79,6 +79,9 @@ impl SWModelParameters for Bls12_377G2Parameters {
             549402535258503313,
         ])
     );
+    /// SIGN_CONVENTION = lexicographically largest, matching G1 and the zexe and
+    /// arkworks encoding of BLS12-377.
+    const SIGN_CONVENTION: SignConvention = SignConvention::LexicographicallyLargest;
 
     #[inline(always)]
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
@@ -33,17 +33,22 @@ use crate::{
         G2Affine,
         G2Projective,
     },
-    templates::{short_weierstrass::tests::sw_tests, twisted_edwards_extended::tests::edwards_test},
+    templates::{
+        short_weierstrass::tests::{sw_generator_sign_test, sw_tests},
+        twisted_edwards_extended::tests::edwards_test,
+    },
     traits::{
-        tests_curve::curve_tests,
+        tests_curve::{curve_tests, msm_test},
         tests_group::group_test,
         AffineCurve,
+        HashToCurve,
         PairingEngine,
         ProjectiveCurve,
         SWModelParameters,
     },
 };
 use snarkvm_fields::{
+    field_ref_ops_test,
     fp6_3over2::Fp6Parameters,
     tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
     FftField,
@@ -53,6 +58,7 @@ use snarkvm_fields::{
     Fp2Parameters,
     LegendreSymbol::*,
     One,
+    PoseidonMDSField,
     PrimeField,
     SquareRootField,
     Zero,
@@ -67,6 +73,7 @@ use rand_xorshift::XorShiftRng;
 use std::{
     cmp::Ordering,
     ops::{AddAssign, MulAssign, SubAssign},
+    str::FromStr,
 };
 
 pub(crate) const ITERATIONS: usize = 5;
@@ -77,18 +84,32 @@ fn test_bls12_377_fr() {
         let a: Fr = rand::random();
         let b: Fr = rand::random();
         field_test(a, b);
+        field_ref_ops_test!(a, b);
         primefield_test::<Fr>();
         sqrt_field_test(b);
         field_serialization_test::<Fr>();
     }
 }
 
+#[test]
+fn test_bls12_377_fr_poseidon_mds_is_cauchy() {
+    let mds = Fr::poseidon_mds_matrix();
+    assert_eq!(mds.len(), 3);
+    for (i, row) in mds.iter().enumerate() {
+        assert_eq!(row.len(), 3);
+        for (j, element) in row.iter().enumerate() {
+            assert_eq!(*element * Fr::from((i + j + 3) as u64), Fr::one());
+        }
+    }
+}
+
 #[test]
 fn test_bls12_377_fq() {
     for _ in 0..ITERATIONS {
         let a: Fq = rand::random();
         let b: Fq = rand::random();
         field_test(a, b);
+        field_ref_ops_test!(a, b);
         primefield_test::<Fq>();
         sqrt_field_test(a);
         field_serialization_test::<Fq>();
@@ -101,6 +122,7 @@ fn test_bls12_377_fq2() {
         let a: Fq2 = rand::random();
         let b: Fq2 = rand::random();
         field_test(a, b);
+        field_ref_ops_test!(a, b);
         sqrt_field_test(a);
     }
     frobenius_test::<Fq2, _>(Fq::characteristic(), 13);
@@ -113,6 +135,7 @@ fn test_bls12_377_fq6() {
         let g: Fq6 = rand::random();
         let h: Fq6 = rand::random();
         field_test(g, h);
+        field_ref_ops_test!(g, h);
     }
     frobenius_test::<Fq6, _>(Fq::characteristic(), 13);
     field_serialization_test::<Fq6>();
@@ -124,6 +147,7 @@ fn test_bls12_377_fq12() {
         let g: Fq12 = rand::random();
         let h: Fq12 = rand::random();
         field_test(g, h);
+        field_ref_ops_test!(g, h);
     }
     frobenius_test::<Fq12, _>(Fq::characteristic(), 13);
     field_serialization_test::<Fq12>();
@@ -569,6 +593,11 @@ fn test_g1_projective_curve() {
     sw_tests::<Bls12_377G1Parameters>();
 }
 
+#[test]
+fn test_g1_msm() {
+    msm_test::<G1Projective>(10_000);
+}
+
 #[test]
 fn test_g1_projective_group() {
     let a: G1Projective = rand::random();
@@ -583,6 +612,50 @@ fn test_g1_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g1_generator_sign() {
+    sw_generator_sign_test::<Bls12_377G1Parameters>(true, false);
+}
+
+#[test]
+fn test_g1_hash_to_curve() {
+    // Known answers for `hash_to_curve`, as (message, domain, x, y).
+    let vectors: [(&[u8], &[u8], &str, &str); 4] = [
+        (
+            b"",
+            b"snarkVM-test",
+            "44512558137571742608812798995144448904489595985346272173910864181115945889532641026056383378129009293454002855746",
+            "72274259363831471893817312000662965153118332685952337394917498692234639822116166095515340409892727055020970284199",
+        ),
+        (
+            b"abc",
+            b"snarkVM-test",
+            "214253403972960150687751545436470964178820527822216084959690229319959144380589275983832977341660551073531322493095",
+            "102860609996808982215765928657799971649549406280859830084774506940532681789373377109471782588319275402133174246260",
+        ),
+        (
+            b"abcdef0123456789",
+            b"snarkVM-test",
+            "155030918991451756197119638118800792856701547601044033055843142511370799524269685664825934951510585086566766307730",
+            "126222843470754185791230675917635184346085520075953804994667463951744892342060866244437489276419441921536495833541",
+        ),
+        (
+            b"abc",
+            b"snarkVM-other",
+            "164197748567026988924060848172978832607770269393962598826141066657780710306993446121988762450890389362889686566415",
+            "136661231109123766078199737314266623184497239550385770814983859897519433033196851122198309253645452781313005717930",
+        ),
+    ];
+
+    for (message, domain, x, y) in vectors.iter() {
+        let point = G1Affine::hash_to_curve(message, domain);
+        assert_eq!(
+            point,
+            G1Affine::new(Fq::from_str(x).unwrap(), Fq::from_str(y).unwrap(), false)
+        );
+    }
+}
+
 #[test]
 fn test_g2_projective_curve() {
     curve_tests::<G2Projective>();
@@ -603,6 +676,11 @@ fn test_g2_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g2_generator_sign() {
+    sw_generator_sign_test::<Bls12_377G2Parameters>(true, true);
+}
+
 #[test]
 fn test_bilinearity() {
     let a: G1Projective = rand::random();
@@ -19,7 +19,7 @@ use snarkvm_utilities::biginteger::{BigInteger384, BigInteger768};
 
 use crate::{
     bw6_761::{Fq, Fr},
-    traits::{ModelParameters, SWModelParameters},
+    traits::{ModelParameters, SWModelParameters, SignConvention},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -76,6 +76,9 @@ impl SWModelParameters for BW6_761G1Parameters {
             71512347676739162,
         ])
     );
+    /// SIGN_CONVENTION = lexicographically largest. BW6-761 is only implemented in zexe and
+    /// arkworks, which use this convention, and the stored SNARK parameters depend on it.
+    const SIGN_CONVENTION: SignConvention = SignConvention::LexicographicallyLargest;
 
     #[inline(always)]
     fn mul_by_a(_elem: &Self::BaseField) -> Self::BaseField {
@@ -19,7 +19,7 @@ use snarkvm_utilities::biginteger::{BigInteger384, BigInteger768};
 
 use crate::{
     bw6_761::{Fq, Fr},
-    traits::{ModelParameters, SWModelParameters},
+    traits::{ModelParameters, SWModelParameters, SignConvention},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -76,6 +76,8 @@ impl SWModelParameters for Bls12_377G2Parameters {
             26723985783783076,
         ])
     );
+    /// SIGN_CONVENTION = lexicographically largest, matching G1.
+    const SIGN_CONVENTION: SignConvention = SignConvention::LexicographicallyLargest;
 
     #[inline(always)]
     fn mul_by_a(_elem: &Self::BaseField) -> Self::BaseField {
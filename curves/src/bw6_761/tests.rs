@@ -28,10 +28,11 @@ use crate::{
         G2Projective,
         BW6_761,
     },
-    templates::short_weierstrass::tests::sw_tests,
+    templates::short_weierstrass::tests::{sw_generator_sign_test, sw_tests},
     traits::{tests_curve::curve_tests, tests_group::group_test, AffineCurve, PairingEngine},
 };
 use snarkvm_fields::{
+    field_ref_ops_test,
     tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
     Field,
     One,
@@ -43,6 +44,7 @@ fn test_bw6_761_fr() {
     let a: Fr = rand::random();
     let b: Fr = rand::random();
     field_test(a, b);
+    field_ref_ops_test!(a, b);
     sqrt_field_test(a);
     primefield_test::<Fr>();
 }
@@ -52,6 +54,7 @@ fn test_bw6_761_fq() {
     let a: Fq = rand::random();
     let b: Fq = rand::random();
     field_test(a, b);
+    field_ref_ops_test!(a, b);
     primefield_test::<Fq>();
     sqrt_field_test(a);
     field_serialization_test::<Fq>();
@@ -62,6 +65,7 @@ fn test_bw6_761_fq3() {
     let a: Fq3 = rand::random();
     let b: Fq3 = rand::random();
     field_test(a, b);
+    field_ref_ops_test!(a, b);
     sqrt_field_test(a);
     frobenius_test::<Fq3, _>(Fq::characteristic(), 13);
 }
@@ -71,6 +75,7 @@ fn test_bw6_761_fq6() {
     let a: Fq6 = rand::random();
     let b: Fq6 = rand::random();
     field_test(a, b);
+    field_ref_ops_test!(a, b);
     frobenius_test::<Fq6, _>(Fq::characteristic(), 13);
 }
 
@@ -95,6 +100,11 @@ fn test_g1_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g1_generator_sign() {
+    sw_generator_sign_test::<BW6_761G1Parameters>(false, true);
+}
+
 #[test]
 fn test_g2_projective_curve() {
     curve_tests::<G2Projective>();
@@ -116,6 +126,11 @@ fn test_g2_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g2_generator_sign() {
+    sw_generator_sign_test::<Bls12_377G2Parameters>(false, true);
+}
+
 #[test]
 fn test_bilinearity() {
     let a: G1Projective = rand::random();
@@ -15,12 +15,13 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum GroupError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
     #[error("{}", _0)]
-    FieldError(snarkvm_fields::FieldError),
+    FieldError(#[from] snarkvm_fields::FieldError),
 
     #[error("Invalid group element")]
     InvalidGroupElement,
@@ -28,6 +29,9 @@ pub enum GroupError {
     #[error("Attempting to parse an invalid string into a group element")]
     InvalidString,
 
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] std::io::Error),
+
     #[error("{}", _0)]
     Message(String),
 
@@ -38,20 +42,8 @@ pub enum GroupError {
     ParsingNonDigitCharacter,
 }
 
-impl From<snarkvm_fields::FieldError> for GroupError {
-    fn from(error: snarkvm_fields::FieldError) -> Self {
-        GroupError::FieldError(error)
-    }
-}
-
-impl From<std::io::Error> for GroupError {
-    fn from(error: std::io::Error) -> Self {
-        GroupError::Crate("std::io", format!("{:?}", error))
-    }
-}
-
 impl From<GroupError> for std::io::Error {
     fn from(error: GroupError) -> Self {
-        std::io::Error::new(std::io::ErrorKind::Other, format!("{}", error))
+        std::io::Error::new(std::io::ErrorKind::Other, error)
     }
 }
@@ -71,3 +71,15 @@ pub mod templates;
 #[cfg_attr(test, macro_use)]
 pub mod traits;
 pub use traits::*;
+
+/// Whether `snarkvm-curves` was compiled with the `parallel` feature.
+pub const PARALLEL_ENABLED: bool = cfg!(feature = "parallel");
+
+/// Registers `snarkvm-curves` and the crates it depends on.
+pub fn register_capabilities(capabilities: &mut snarkvm_utilities::Capabilities) {
+    capabilities.register(snarkvm_utilities::CrateCapabilities::new("snarkvm-curves", &[
+        ("parallel", PARALLEL_ENABLED),
+        ("sw6", cfg!(feature = "sw6")),
+    ]));
+    snarkvm_fields::register_capabilities(capabilities);
+}
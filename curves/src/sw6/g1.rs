@@ -17,7 +17,7 @@
 use crate::{
     sw6::{Fq, Fq6, Fr, G2Affine, SW6},
     templates::short_weierstrass::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
-    traits::{ModelParameters, PairingCurve, PairingEngine, SWModelParameters},
+    traits::{ModelParameters, PairingCurve, PairingEngine, SWModelParameters, SignConvention},
 };
 use snarkvm_fields::field;
 use snarkvm_utilities::biginteger::{BigInteger384, BigInteger832};
@@ -113,6 +113,8 @@ impl SWModelParameters for SW6G1Parameters {
             87030208545296111,
         ])
     );
+    /// SIGN_CONVENTION = lexicographically largest, as in the zexe implementation of SW6.
+    const SIGN_CONVENTION: SignConvention = SignConvention::LexicographicallyLargest;
 }
 
 /// G1_GENERATOR_X =
@@ -17,7 +17,7 @@
 use crate::{
     sw6::{Fq, Fq3, Fq6, Fr, G1Affine, FQ_ZERO, SW6},
     templates::short_weierstrass::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
-    traits::{ModelParameters, PairingCurve, PairingEngine, SWModelParameters},
+    traits::{ModelParameters, PairingCurve, PairingEngine, SWModelParameters, SignConvention},
 };
 use snarkvm_fields::field;
 use snarkvm_utilities::biginteger::{BigInteger384, BigInteger832};
@@ -149,6 +149,8 @@ impl SWModelParameters for SW6G2Parameters {
             4115759498196698,
         ])
     );
+    /// SIGN_CONVENTION = lexicographically largest, matching G1.
+    const SIGN_CONVENTION: SignConvention = SignConvention::LexicographicallyLargest;
 }
 
 const G2_GENERATOR_X: Fq3 = field!(Fq3, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1, G2_GENERATOR_X_C2);
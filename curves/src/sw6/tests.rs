@@ -16,10 +16,11 @@
 
 use crate::{
     sw6::*,
-    templates::short_weierstrass::tests::sw_tests,
+    templates::short_weierstrass::tests::{sw_generator_sign_test, sw_tests},
     traits::{tests_curve::curve_tests, tests_group::group_test, AffineCurve, PairingEngine},
 };
 use snarkvm_fields::{
+    field_ref_ops_test,
     tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
     Field,
     One,
@@ -31,6 +32,7 @@ fn test_sw6_fr() {
     let a: Fr = rand::random();
     let b: Fr = rand::random();
     field_test(a, b);
+    field_ref_ops_test!(a, b);
     sqrt_field_test(a);
     primefield_test::<Fr>();
     field_serialization_test::<Fr>();
@@ -41,6 +43,7 @@ fn test_sw6_fq() {
     let a: Fq = rand::random();
     let b: Fq = rand::random();
     field_test(a, b);
+    field_ref_ops_test!(a, b);
     primefield_test::<Fq>();
     sqrt_field_test(a);
     field_serialization_test::<Fq>();
@@ -51,6 +54,7 @@ fn test_sw6_fq3() {
     let a: Fq3 = rand::random();
     let b: Fq3 = rand::random();
     field_test(a, b);
+    field_ref_ops_test!(a, b);
     sqrt_field_test(a);
     frobenius_test::<Fq3, _>(Fq::characteristic(), 13);
     field_serialization_test::<Fq3>();
@@ -61,6 +65,7 @@ fn test_sw6_fq6() {
     let a: Fq6 = rand::random();
     let b: Fq6 = rand::random();
     field_test(a, b);
+    field_ref_ops_test!(a, b);
     frobenius_test::<Fq6, _>(Fq::characteristic(), 13);
     field_serialization_test::<Fq6>();
 }
@@ -85,6 +90,11 @@ fn test_g1_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g1_generator_sign() {
+    sw_generator_sign_test::<SW6G1Parameters>(false, true);
+}
+
 #[test]
 fn test_g2_projective_curve() {
     curve_tests::<G2Projective>();
@@ -105,6 +115,11 @@ fn test_g2_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g2_generator_sign() {
+    sw_generator_sign_test::<SW6G2Parameters>(false, true);
+}
+
 #[test]
 fn test_bilinearity() {
     let a: G1Projective = rand::random();
@@ -20,7 +20,8 @@
 #[macro_export]
 macro_rules! impl_sw_curve_serializer {
     ($params: ident) => {
-        // Projective Group point implementations delegate to the Affine version
+        // Projective Group point implementations delegate to the Affine version,
+        // which is implemented in `short_weierstrass::affine`.
         impl<P: $params> CanonicalSerialize for GroupProjective<P> {
             #[allow(unused_qualifications)]
             #[inline]
@@ -72,97 +73,6 @@ macro_rules! impl_sw_curve_serializer {
             const SERIALIZED_SIZE: usize = <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
             const UNCOMPRESSED_SIZE: usize = 2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
         }
-
-        impl<P: $params> CanonicalSerialize for GroupAffine<P> {
-            #[allow(unused_qualifications)]
-            #[inline]
-            fn serialize<W: snarkvm_utilities::io::Write>(
-                &self,
-                writer: &mut W,
-            ) -> Result<(), snarkvm_utilities::errors::SerializationError> {
-                if self.is_zero() {
-                    let flags = snarkvm_utilities::serialize::SWFlags::infinity();
-                    // Serialize 0.
-                    P::BaseField::zero().serialize_with_flags(writer, flags)
-                } else {
-                    let flags = snarkvm_utilities::serialize::SWFlags::from_y_sign(self.y > -self.y);
-                    self.x.serialize_with_flags(writer, flags)
-                }
-            }
-
-            #[inline]
-            fn serialized_size(&self) -> usize {
-                Self::SERIALIZED_SIZE
-            }
-
-            #[allow(unused_qualifications)]
-            #[inline]
-            fn serialize_uncompressed<W: snarkvm_utilities::io::Write>(
-                &self,
-                writer: &mut W,
-            ) -> Result<(), snarkvm_utilities::errors::SerializationError> {
-                let flags = if self.is_zero() {
-                    snarkvm_utilities::serialize::SWFlags::infinity()
-                } else {
-                    snarkvm_utilities::serialize::SWFlags::default()
-                };
-                CanonicalSerialize::serialize(&self.x, writer)?;
-                self.y.serialize_with_flags(writer, flags)?;
-                Ok(())
-            }
-
-            #[inline]
-            fn uncompressed_size(&self) -> usize {
-                Self::UNCOMPRESSED_SIZE
-            }
-        }
-
-        impl<P: $params> ConstantSerializedSize for GroupAffine<P> {
-            const SERIALIZED_SIZE: usize = <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
-            const UNCOMPRESSED_SIZE: usize = 2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
-        }
-
-        impl<P: $params> CanonicalDeserialize for GroupAffine<P> {
-            #[allow(unused_qualifications)]
-            fn deserialize<R: snarkvm_utilities::io::Read>(
-                reader: &mut R,
-            ) -> Result<Self, snarkvm_utilities::errors::SerializationError> {
-                let (x, flags): (P::BaseField, snarkvm_utilities::serialize::SWFlags) =
-                    CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
-                if flags.is_infinity() {
-                    Ok(Self::zero())
-                } else {
-                    let p = GroupAffine::<P>::from_x_coordinate(x, flags.is_positive().unwrap())
-                        .ok_or(snarkvm_utilities::errors::SerializationError::InvalidData)?;
-                    if !snarkvm_utilities::PROCESSING_SNARK_PARAMS
-                        .with(|p| p.load(std::sync::atomic::Ordering::Relaxed))
-                    {
-                        if !p.is_in_correct_subgroup_assuming_on_curve() {
-                            return Err(snarkvm_utilities::errors::SerializationError::InvalidData);
-                        }
-                    } else {
-                        snarkvm_utilities::SNARK_PARAMS_AFFINE_COUNT
-                            .with(|p| p.fetch_add(1, std::sync::atomic::Ordering::Relaxed));
-                    }
-                    Ok(p)
-                }
-            }
-
-            #[allow(unused_qualifications)]
-            fn deserialize_uncompressed<R: snarkvm_utilities::io::Read>(
-                reader: &mut R,
-            ) -> Result<Self, snarkvm_utilities::errors::SerializationError> {
-                let x: P::BaseField = CanonicalDeserialize::deserialize(reader)?;
-                let (y, flags): (P::BaseField, snarkvm_utilities::serialize::SWFlags) =
-                    CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
-
-                let p = GroupAffine::<P>::new(x, y, flags.is_infinity());
-                if !p.is_in_correct_subgroup_assuming_on_curve() {
-                    return Err(snarkvm_utilities::errors::SerializationError::InvalidData);
-                }
-                Ok(p)
-            }
-        }
     };
 }
 
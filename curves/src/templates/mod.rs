@@ -16,6 +16,7 @@
 
 pub mod bls12;
 pub mod bw6;
+pub mod msm;
 pub mod short_weierstrass;
 pub mod to_field_vec;
 pub mod twisted_edwards_extended;
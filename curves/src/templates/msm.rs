@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::traits::ProjectiveCurve;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::biginteger::BigInteger;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Returns the sum of `bases[i] * scalars[i]`, computed with Pippenger's algorithm.
+///
+/// The scalars are split into windows of `c` bits, where `2^c - 1` buckets are about as many
+/// points as the precomputed table of a wNAF multiplication with the window size recommended by
+/// `G::recommended_wnaf_for_num_scalars`. Each window sorts the bases into buckets by its digit of
+/// the scalar, and the windows are then combined with `c` doublings each.
+///
+/// The scalars are expected in canonical form, so bits beyond the size of the scalar field are
+/// ignored. If the slices differ in length, the longer one is truncated to the shorter one.
+pub fn pippenger<G: ProjectiveCurve>(bases: &[G::Affine], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G {
+    let size = bases.len().min(scalars.len());
+    if size == 0 {
+        return G::zero();
+    }
+    let (bases, scalars) = (&bases[..size], &scalars[..size]);
+
+    let c = G::recommended_wnaf_for_num_scalars(size).saturating_sub(2).max(1);
+    let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+
+    // Returns the sum of the bases weighted by the digits of their scalars in the window starting at `start`.
+    let window_sum = |start: usize| {
+        // The zero digit needs no bucket, so there are 2^c - 1 buckets.
+        let mut buckets = vec![G::zero(); (1 << c) - 1];
+        for (base, scalar) in bases.iter().zip(scalars) {
+            let mut scalar = *scalar;
+            scalar.divn(start as u32);
+            let digit = scalar.as_ref()[0] % (1 << c);
+            if digit != 0 {
+                buckets[digit as usize - 1].add_assign_mixed(base);
+            }
+        }
+
+        // The running sum of the buckets from the highest digit down adds each bucket once per digit.
+        let mut running_sum = G::zero();
+        let mut sum = G::zero();
+        for bucket in buckets.into_iter().rev() {
+            running_sum += bucket;
+            sum += running_sum;
+        }
+        sum
+    };
+
+    let window_starts = (0..num_bits).step_by(c).collect::<Vec<_>>();
+    #[cfg(feature = "parallel")]
+    let window_sums = window_starts.into_par_iter().map(window_sum).collect::<Vec<G>>();
+    #[cfg(not(feature = "parallel"))]
+    let window_sums = window_starts.into_iter().map(window_sum).collect::<Vec<G>>();
+
+    // Combine the windows from the highest down.
+    window_sums.into_iter().rev().fold(G::zero(), |mut total, window_sum| {
+        for _ in 0..c {
+            total.double_in_place();
+        }
+        total + window_sum
+    })
+}
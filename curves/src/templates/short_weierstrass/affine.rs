@@ -0,0 +1,436 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    impl_sw_compressed_bytes,
+    impl_sw_from_random_bytes,
+    traits::{AffineCurve, Group, ProjectiveCurve, SWModelParameters as Parameters},
+};
+use snarkvm_fields::{impl_additive_ops_from_ref, Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_utilities::{
+    bititerator::BitIteratorBE,
+    bytes::{FromBytes, ToBytes},
+    errors::SerializationError,
+    rand::UniformRand,
+    serialize::*,
+};
+
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{Error, ErrorKind, Read, Result as IoResult, Write},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// A projective representation of a short Weierstrass curve, which converts to and from `GroupAffine`.
+pub trait Projective<P: Parameters>:
+    ProjectiveCurve<Affine = GroupAffine<P, Self>, BaseField = P::BaseField, ScalarField = P::ScalarField>
+{
+}
+
+/// An affine point on a short Weierstrass curve, whose projective representation is `G`.
+///
+/// The affine representation does not depend on `G`, which only selects the projective type
+/// that points are converted to for arithmetic.
+#[derive(Derivative, Serialize, Deserialize)]
+#[derivative(
+    Copy(bound = "P: Parameters"),
+    Clone(bound = "P: Parameters"),
+    PartialEq(bound = "P: Parameters"),
+    Eq(bound = "P: Parameters"),
+    Debug(bound = "P: Parameters"),
+    Hash(bound = "P: Parameters")
+)]
+pub struct GroupAffine<P: Parameters, G> {
+    pub x: P::BaseField,
+    pub y: P::BaseField,
+    pub infinity: bool,
+    #[derivative(Debug = "ignore")]
+    _params: PhantomData<(P, G)>,
+}
+
+impl<P: Parameters, G: Projective<P>> Display for GroupAffine<P, G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.infinity {
+            write!(f, "GroupAffine(Infinity)")
+        } else {
+            write!(f, "GroupAffine(x={}, y={})", self.x, self.y)
+        }
+    }
+}
+
+impl<P: Parameters, G: Projective<P>> GroupAffine<P, G> {
+    impl_sw_compressed_bytes!();
+
+    pub fn new(x: P::BaseField, y: P::BaseField, infinity: bool) -> Self {
+        Self {
+            x,
+            y,
+            infinity,
+            _params: PhantomData,
+        }
+    }
+
+    pub fn scale_by_cofactor(&self) -> G {
+        self.mul_bits(BitIteratorBE::new_without_leading_zeros(P::COFACTOR))
+    }
+
+    /// Checks that the current point is on the elliptic curve.
+    pub fn is_on_curve(&self) -> bool {
+        if self.is_zero() {
+            true
+        } else {
+            // Check that the point is on the curve
+            let y2 = self.y.square();
+            let x3b = P::add_b(&((self.x.square() * self.x) + P::mul_by_a(&self.x)));
+            y2 == x3b
+        }
+    }
+
+    /// Reads a point with `FromBytes`, and additionally checks that it is in the prime order subgroup.
+    pub fn read_checked<R: Read>(reader: R) -> IoResult<Self> {
+        let point = Self::read(reader)?;
+        if !point.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Point is not in the prime order subgroup",
+            ));
+        }
+        Ok(point)
+    }
+}
+
+impl<P: Parameters, G: Projective<P>> Zero for GroupAffine<P, G> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(P::BaseField::zero(), P::BaseField::one(), true)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.infinity
+    }
+}
+
+impl<P: Parameters, G: Projective<P>> AffineCurve for GroupAffine<P, G> {
+    type BaseField = P::BaseField;
+    type Projective = G;
+
+    impl_sw_from_random_bytes!();
+
+    #[inline]
+    fn prime_subgroup_generator() -> Self {
+        Self::new(P::AFFINE_GENERATOR_COEFFS.0, P::AFFINE_GENERATOR_COEFFS.1, false)
+    }
+
+    /// Attempts to construct an affine point given an x-coordinate. The
+    /// point is not guaranteed to be in the prime order subgroup.
+    ///
+    /// If and only if `greatest` is set will the lexicographically
+    /// largest y-coordinate be selected.
+    fn from_x_coordinate(x: Self::BaseField, greatest: bool) -> Option<Self> {
+        // Compute x^3 + ax + b
+        let x3b = P::add_b(&((x.square() * x) + P::mul_by_a(&x)));
+
+        x3b.sqrt().map(|y| {
+            let negy = -y;
+
+            let y = if (y < negy) ^ greatest { y } else { negy };
+            Self::new(x, y, false)
+        })
+    }
+
+    /// Attempts to construct an affine point given a y-coordinate. The
+    /// point is not guaranteed to be in the prime order subgroup.
+    ///
+    /// If and only if `greatest` is set will the lexicographically
+    /// largest y-coordinate be selected.
+    fn from_y_coordinate(_y: Self::BaseField, _greatest: bool) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn mul_bits<S: AsRef<[u64]>>(&self, bits: BitIteratorBE<S>) -> G {
+        let mut res = G::zero();
+        for i in bits {
+            res.double_in_place();
+            if i {
+                res.add_assign_mixed(self)
+            }
+        }
+        res
+    }
+
+    fn mul_by_cofactor_to_projective(&self) -> Self::Projective {
+        self.scale_by_cofactor()
+    }
+
+    fn mul_by_cofactor_inv(&self) -> Self {
+        self.mul(P::COFACTOR_INV)
+    }
+
+    #[inline]
+    fn into_projective(&self) -> G {
+        (*self).into()
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+        self.mul_bits(BitIteratorBE::new_without_leading_zeros(
+            P::ScalarField::characteristic(),
+        ))
+        .is_zero()
+    }
+
+    fn to_x_coordinate(&self) -> Self::BaseField {
+        self.x
+    }
+
+    fn to_y_coordinate(&self) -> Self::BaseField {
+        self.y
+    }
+
+    /// Checks that the current point is on the elliptic curve.
+    fn is_on_curve(&self) -> bool {
+        if self.is_zero() {
+            true
+        } else {
+            // Check that the point is on the curve
+            let y2 = self.y.square();
+            let x3b = P::add_b(&((self.x.square() * self.x) + P::mul_by_a(&self.x)));
+            y2 == x3b
+        }
+    }
+}
+
+impl<P: Parameters, G: Projective<P>> Group for GroupAffine<P, G> {
+    type ScalarField = P::ScalarField;
+
+    #[inline]
+    fn double(&self) -> Self {
+        let mut tmp = *self;
+        tmp += self;
+        tmp
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) {
+        let tmp = *self;
+        *self = tmp.double();
+    }
+}
+
+impl<P: Parameters, G: Projective<P>> Neg for GroupAffine<P, G> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        if !self.is_zero() {
+            Self::new(self.x, -self.y, false)
+        } else {
+            self
+        }
+    }
+}
+
+impl_additive_ops_from_ref!(GroupAffine<P, G>, P: Parameters, G: Projective<P>);
+
+impl<'a, P: Parameters, G: Projective<P>> Add<&'a Self> for GroupAffine<P, G> {
+    type Output = Self;
+
+    fn add(self, other: &'a Self) -> Self {
+        let mut copy = self;
+        copy += other;
+        copy
+    }
+}
+
+impl<'a, P: Parameters, G: Projective<P>> AddAssign<&'a Self> for GroupAffine<P, G> {
+    fn add_assign(&mut self, other: &'a Self) {
+        let mut projective = G::from(*self);
+        projective.add_assign_mixed(other);
+        *self = projective.into_affine();
+    }
+}
+
+impl<'a, P: Parameters, G: Projective<P>> Sub<&'a Self> for GroupAffine<P, G> {
+    type Output = Self;
+
+    fn sub(self, other: &'a Self) -> Self {
+        let mut copy = self;
+        copy -= other;
+        copy
+    }
+}
+
+impl<'a, P: Parameters, G: Projective<P>> SubAssign<&'a Self> for GroupAffine<P, G> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self += &(-(*other));
+    }
+}
+
+impl<P: Parameters, G: Projective<P>> Mul<P::ScalarField> for GroupAffine<P, G> {
+    type Output = Self;
+
+    fn mul(self, other: P::ScalarField) -> Self {
+        if other.is_zero() {
+            return Self::zero();
+        }
+
+        self.mul_bits(BitIteratorBE::new_without_leading_zeros(other.into_repr()))
+            .into_affine()
+    }
+}
+
+impl<P: Parameters, G: Projective<P>> MulAssign<P::ScalarField> for GroupAffine<P, G> {
+    fn mul_assign(&mut self, other: P::ScalarField) {
+        *self = self.mul(other)
+    }
+}
+
+impl<P: Parameters, G: Projective<P>> ToBytes for GroupAffine<P, G> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.x.write(&mut writer)?;
+        self.y.write(&mut writer)?;
+        self.infinity.write(writer)
+    }
+}
+
+impl<P: Parameters, G: Projective<P>> FromBytes for GroupAffine<P, G> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let x = P::BaseField::read(&mut reader)?;
+        let y = P::BaseField::read(&mut reader)?;
+        let infinity = bool::read(&mut reader)?;
+
+        let point = Self::new(x, y, infinity);
+        if infinity {
+            // The point at infinity must be written with its canonical coordinates.
+            if point != Self::zero() {
+                return Err(Error::new(ErrorKind::InvalidData, "Infinity flag is not valid"));
+            }
+        } else if !point.is_on_curve() {
+            return Err(Error::new(ErrorKind::InvalidData, "Point is not on the curve"));
+        }
+        Ok(point)
+    }
+}
+
+impl<P: Parameters, G: Projective<P>> Default for GroupAffine<P, G> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: Parameters, G: Projective<P>> Distribution<GroupAffine<P, G>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GroupAffine<P, G> {
+        loop {
+            let x = P::BaseField::rand(rng);
+            let greatest = rng.gen();
+
+            if let Some(p) = GroupAffine::<P, G>::from_x_coordinate(x, greatest) {
+                return p.scale_by_cofactor().into_affine();
+            }
+        }
+    }
+}
+
+// Each projective representation defines its own conversion in `ProjectiveCurve::into_affine`.
+impl<P: Parameters, G: Projective<P>> From<G> for GroupAffine<P, G> {
+    #[inline]
+    fn from(p: G) -> Self {
+        p.into_affine()
+    }
+}
+
+impl<P: Parameters, G: Projective<P>> CanonicalSerialize for GroupAffine<P, G> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        if self.is_zero() {
+            let flags = SWFlags::infinity();
+            // Serialize 0.
+            P::BaseField::zero().serialize_with_flags(writer, flags)
+        } else {
+            let flags = SWFlags::from_y_sign(P::SIGN_CONVENTION.sign(&self.y));
+            self.x.serialize_with_flags(writer, flags)
+        }
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        Self::SERIALIZED_SIZE
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        let flags = if self.is_zero() {
+            SWFlags::infinity()
+        } else {
+            SWFlags::default()
+        };
+        CanonicalSerialize::serialize(&self.x, writer)?;
+        self.y.serialize_with_flags(writer, flags)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        Self::UNCOMPRESSED_SIZE
+    }
+}
+
+impl<P: Parameters, G: Projective<P>> ConstantSerializedSize for GroupAffine<P, G> {
+    const SERIALIZED_SIZE: usize = <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+    const UNCOMPRESSED_SIZE: usize = 2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+}
+
+impl<P: Parameters, G: Projective<P>> CanonicalDeserialize for GroupAffine<P, G> {
+    fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let (x, flags): (P::BaseField, SWFlags) = CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
+        if flags.is_infinity() {
+            Ok(Self::zero())
+        } else {
+            let p = Self::from_x_coordinate_with_sign(x, flags.is_positive().unwrap(), P::SIGN_CONVENTION)
+                .ok_or(SerializationError::InvalidData)?;
+            if !snarkvm_utilities::PROCESSING_SNARK_PARAMS.with(|p| p.load(std::sync::atomic::Ordering::Relaxed)) {
+                if !p.is_in_correct_subgroup_assuming_on_curve() {
+                    return Err(SerializationError::InvalidData);
+                }
+            } else {
+                snarkvm_utilities::SNARK_PARAMS_AFFINE_COUNT
+                    .with(|p| p.fetch_add(1, std::sync::atomic::Ordering::Relaxed));
+            }
+            Ok(p)
+        }
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let x: P::BaseField = CanonicalDeserialize::deserialize(reader)?;
+        let (y, flags): (P::BaseField, SWFlags) = CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
+
+        let p = Self::new(x, y, flags.is_infinity());
+        if !p.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(p)
+    }
+}
@@ -0,0 +1,242 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The hash to curve construction of RFC 9380 for short Weierstrass curves, using
+//! `expand_message_xmd` with SHA-256 and the Shallue-van de Woestijne map.
+//!
+//! The Shallue-van de Woestijne map applies to every short Weierstrass curve, including the
+//! `a = 0` curves for which the simplified SWU map would require an isogeny. It performs the
+//! same sequence of field operations for every input, unlike the rejection sampling of
+//! `AffineCurve::from_random_bytes`.
+
+use crate::traits::{SWModelParameters, SignConvention};
+use snarkvm_fields::{Field, One, SquareRootField, Zero};
+
+use sha2::{Digest, Sha256};
+
+/// The security parameter, in bits, of `hash_to_field`.
+const SECURITY_PARAMETER: usize = 128;
+
+/// The output size, in bytes, of SHA-256.
+const SHA256_OUTPUT_SIZE: usize = 32;
+
+/// The input block size, in bytes, of SHA-256.
+const SHA256_BLOCK_SIZE: usize = 64;
+
+/// Returns `len_in_bytes` uniformly random bytes derived from `message` and `domain`, as
+/// specified by `expand_message_xmd` in RFC 9380 with SHA-256.
+pub fn expand_message_xmd(message: &[u8], domain: &[u8], len_in_bytes: usize) -> Vec<u8> {
+    let ell = len_in_bytes.div_ceil(SHA256_OUTPUT_SIZE);
+    assert!(ell <= 255 && len_in_bytes <= u16::MAX as usize);
+
+    // Domains longer than 255 bytes are hashed down, as in section 5.3.3 of RFC 9380.
+    let oversize_domain;
+    let domain = if domain.len() > 255 {
+        oversize_domain = Sha256::new().chain(b"H2C-OVERSIZE-DST-").chain(domain).finalize();
+        &oversize_domain[..]
+    } else {
+        domain
+    };
+    let domain_prime = [domain, &[domain.len() as u8]].concat();
+
+    let b_0 = Sha256::new()
+        .chain(&[0u8; SHA256_BLOCK_SIZE][..])
+        .chain(message)
+        .chain((len_in_bytes as u16).to_be_bytes())
+        .chain([0u8])
+        .chain(&domain_prime)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * SHA256_OUTPUT_SIZE);
+    let mut b_i = Sha256::new().chain(b_0).chain([1u8]).chain(&domain_prime).finalize();
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let xored = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect::<Vec<u8>>();
+        b_i = Sha256::new()
+            .chain(xored)
+            .chain([i as u8])
+            .chain(&domain_prime)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// Hashes `message` to `count` field elements, as specified by `hash_to_field` in RFC 9380.
+///
+/// Each coefficient of each field element is reduced from `SECURITY_PARAMETER` more bits
+/// than the characteristic, so the outputs are statistically close to uniform.
+pub fn hash_to_field<F: Field>(message: &[u8], domain: &[u8], count: usize) -> Vec<F> {
+    let characteristic = F::characteristic();
+    let characteristic_bits =
+        characteristic.len() * 64 - characteristic.last().map_or(64, |limb| limb.leading_zeros() as usize);
+    let coefficient_len = (characteristic_bits + SECURITY_PARAMETER).div_ceil(8);
+
+    // The basis of `F` over its prime subfield, in the coefficient order of `ToBytes`.
+    let coefficient_size = characteristic.len() * 8;
+    let mut serialized = vec![];
+    F::zero()
+        .write(&mut serialized)
+        .expect("failed to write a field element to a vector");
+    let basis = (0..serialized.len() / coefficient_size)
+        .map(|i| {
+            let mut bytes = vec![0u8; serialized.len()];
+            bytes[i * coefficient_size] = 1;
+            F::from_random_bytes(&bytes).expect("failed to construct a basis element")
+        })
+        .collect::<Vec<F>>();
+
+    let uniform_bytes = expand_message_xmd(message, domain, count * basis.len() * coefficient_len);
+    let radix = F::from(256u16);
+    uniform_bytes
+        .chunks(basis.len() * coefficient_len)
+        .map(|element| {
+            element
+                .chunks(coefficient_len)
+                .zip(&basis)
+                .map(|(coefficient, basis_element)| {
+                    // Interpret the coefficient as a big-endian integer, reduced by the field arithmetic.
+                    let coefficient = coefficient
+                        .iter()
+                        .fold(F::zero(), |acc, byte| acc * radix + F::from(*byte));
+                    coefficient * basis_element
+                })
+                .sum()
+        })
+        .collect()
+}
+
+/// Returns `x^3 + ax + b`.
+fn curve_equation<P: SWModelParameters + ?Sized>(x: &P::BaseField) -> P::BaseField {
+    P::add_b(&((x.square() * x) + P::mul_by_a(x)))
+}
+
+/// Returns `true` if `element` is a square, including zero.
+fn is_square<F: SquareRootField>(element: &F) -> bool {
+    !element.legendre().is_qnr()
+}
+
+/// Returns the constant `Z` of the Shallue-van de Woestijne map chosen by `find_z_svdw` in RFC 9380,
+/// which is the first of `1, -1, 2, -2, ...` that satisfies the criteria of the map.
+pub fn find_svdw_z<P: SWModelParameters + ?Sized>() -> P::BaseField {
+    let three = P::BaseField::from(3u8);
+    let four = P::BaseField::from(4u8);
+    let two_inv = P::BaseField::from(2u8)
+        .inverse()
+        .expect("the characteristic must not be two");
+
+    let is_valid = |z: &P::BaseField| {
+        let g_z = curve_equation::<P>(z);
+        if g_z.is_zero() {
+            return false;
+        }
+        let h_z = -(three * z.square() + four * P::COEFF_A) * (four * g_z).inverse().unwrap();
+        !h_z.is_zero() && is_square(&h_z) && (is_square(&g_z) || is_square(&curve_equation::<P>(&(-*z * two_inv))))
+    };
+
+    let mut counter = P::BaseField::one();
+    loop {
+        for candidate in [counter, -counter].iter() {
+            if is_valid(candidate) {
+                return *candidate;
+            }
+        }
+        counter += P::BaseField::one();
+    }
+}
+
+/// Maps `u` to a point on the curve with the Shallue-van de Woestijne map of RFC 9380,
+/// returning the affine coordinates of the point.
+///
+/// The point is on the curve, but not necessarily in the prime order subgroup.
+pub fn map_to_curve_svdw<P: SWModelParameters>(u: P::BaseField) -> (P::BaseField, P::BaseField) {
+    let one = P::BaseField::one();
+    let z = P::svdw_z();
+
+    // The constants of the map, which depend only on the curve.
+    let c1 = curve_equation::<P>(&z);
+    let c2 = -z * P::BaseField::from(2u8).inverse().unwrap();
+    let three_z2_4a = P::BaseField::from(3u8) * z.square() + P::BaseField::from(4u8) * P::COEFF_A;
+    let mut c3 = (-c1 * three_z2_4a)
+        .sqrt()
+        .expect("invalid Shallue-van de Woestijne constant");
+    if SignConvention::Parity.sign(&c3) {
+        c3 = -c3;
+    }
+    let c4 = -P::BaseField::from(4u8) * c1 * three_z2_4a.inverse().unwrap();
+
+    let tv1 = u.square() * c1;
+    let tv2 = one + tv1;
+    let tv1 = one - tv1;
+    let tv3 = (tv1 * tv2).inverse().unwrap_or_else(P::BaseField::zero);
+    let tv4 = u * tv1 * tv3 * c3;
+
+    let x1 = c2 - tv4;
+    let e1 = is_square(&curve_equation::<P>(&x1));
+    let x2 = c2 + tv4;
+    let e2 = is_square(&curve_equation::<P>(&x2)) && !e1;
+    let x3 = (tv2.square() * tv3).square() * c4 + z;
+
+    let x = if e1 {
+        x1
+    } else if e2 {
+        x2
+    } else {
+        x3
+    };
+
+    let y = curve_equation::<P>(&x)
+        .sqrt()
+        .expect("the Shallue-van de Woestijne map always yields a square");
+    let y = if SignConvention::Parity.sign(&u) == SignConvention::Parity.sign(&y) {
+        y
+    } else {
+        -y
+    };
+
+    (x, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_to_hex(message: &[u8], domain: &[u8], len_in_bytes: usize) -> String {
+        expand_message_xmd(message, domain, len_in_bytes)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    #[test]
+    fn test_expand_message_xmd() {
+        // The SHA-256 test vectors of appendix K.1 of RFC 9380.
+        const DOMAIN: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+        let expected = "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235";
+        assert_eq!(expand_to_hex(b"", DOMAIN, 0x20), expected);
+
+        let expected = "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615";
+        assert_eq!(expand_to_hex(b"abc", DOMAIN, 0x20), expected);
+
+        let expected = "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd\
+                        4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc\
+                        c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced";
+        assert_eq!(expand_to_hex(b"", DOMAIN, 0x80), expected);
+    }
+}
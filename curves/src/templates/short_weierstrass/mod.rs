@@ -14,9 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod affine;
+pub mod hash_to_curve;
 pub mod short_weierstrass_jacobian;
 pub mod short_weierstrass_projective;
 pub mod tests;
+pub mod wnaf;
+
+use crate::traits::{SWModelParameters, SignConvention};
+use short_weierstrass_jacobian::GroupAffine;
+use snarkvm_fields::Zero;
+use snarkvm_utilities::{
+    errors::SerializationError,
+    serialize::{CanonicalDeserializeWithFlags, CanonicalSerializeWithFlags, SWFlags},
+};
 
 // Copied from https://github.com/scipr-lab/zexe/blob/4b3f08c6c0a08c5392ed8aa3fd3c32f28da402c4/algebra-core/src/curves/models/short_weierstrass_jacobian.rs#L160-L173.
 #[macro_export]
@@ -32,9 +43,158 @@ macro_rules! impl_sw_from_random_bytes {
                     Some(Self::zero())
                 } else {
                     let is_positive = flags & positive_flag_mask != 0;
-                    Self::from_x_coordinate(x, is_positive)
+                    Self::from_x_coordinate_with_sign(x, is_positive, P::SIGN_CONVENTION)
                 }
             })
         }
     };
 }
+
+/// Implements `HashToCurve` for a short Weierstrass `GroupAffine` and `GroupProjective`.
+///
+/// The message is hashed to two field elements, which are mapped to the curve with the
+/// Shallue-van de Woestijne map. The sum of the two points is multiplied by the cofactor.
+#[macro_export]
+macro_rules! impl_sw_hash_to_curve {
+    ($params: ident) => {
+        impl<P: $params> $crate::traits::HashToCurve for GroupProjective<P> {
+            fn hash_to_curve(message: &[u8], domain: &[u8]) -> Self {
+                let mut point = Self::zero();
+                for u in $crate::templates::short_weierstrass::hash_to_curve::hash_to_field(message, domain, 2) {
+                    let (x, y) = $crate::templates::short_weierstrass::hash_to_curve::map_to_curve_svdw::<P>(u);
+                    point.add_assign_mixed(&GroupAffine::new(x, y, false));
+                }
+                point.into_affine().scale_by_cofactor()
+            }
+        }
+
+        impl<P: $params> $crate::traits::HashToCurve for GroupAffine<P> {
+            fn hash_to_curve(message: &[u8], domain: &[u8]) -> Self {
+                <GroupProjective<P> as $crate::traits::HashToCurve>::hash_to_curve(message, domain).into_affine()
+            }
+        }
+    };
+}
+
+/// Implements `write_compressed` and `read_compressed` for a short Weierstrass `GroupAffine`.
+///
+/// The compressed encoding is the x-coordinate, written with `ToBytes`, followed by a single
+/// flag byte holding the `SWFlags` bitmask: `0x40` for the point at infinity (with a zero
+/// x-coordinate), `0x80` if the sign bit of the y-coordinate under `P::SIGN_CONVENTION` is set,
+/// and `0x00` otherwise. All other bits of the flag byte must be unset.
+///
+/// Also implements `from_x_coordinate_with_sign`, which the serializers use to recover the
+/// y-coordinate from its sign bit.
+#[macro_export]
+macro_rules! impl_sw_compressed_bytes {
+    () => {
+        /// Attempts to construct an affine point given an x-coordinate and the sign bit of its
+        /// y-coordinate under `convention`. The point is not guaranteed to be in the prime order
+        /// subgroup.
+        pub fn from_x_coordinate_with_sign(
+            x: P::BaseField,
+            sign: bool,
+            convention: $crate::traits::SignConvention,
+        ) -> Option<Self> {
+            Self::from_x_coordinate(x, true).map(|point| {
+                if convention.sign(&point.y) == sign {
+                    point
+                } else {
+                    -point
+                }
+            })
+        }
+
+        /// Writes the point in compressed form, as its x-coordinate followed by a flag byte.
+        pub fn write_compressed<W: Write>(&self, mut writer: W) -> IoResult<()> {
+            let flags = if self.is_zero() {
+                SWFlags::infinity()
+            } else {
+                SWFlags::from_y_sign(P::SIGN_CONVENTION.sign(&self.y))
+            };
+            let x = if self.is_zero() {
+                P::BaseField::zero()
+            } else {
+                self.x
+            };
+
+            x.write(&mut writer)?;
+            flags.u8_bitmask().write(&mut writer)
+        }
+
+        /// Reads a point written by `write_compressed`, checking that it is on the curve
+        /// and in the prime order subgroup.
+        pub fn read_compressed<R: Read>(mut reader: R) -> IoResult<Self> {
+            let x = P::BaseField::read(&mut reader)?;
+            let flag_byte = u8::read(&mut reader)?;
+
+            let flags = SWFlags::from_u8(flag_byte);
+            if flags.u8_bitmask() != flag_byte {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "Invalid compressed point flags",
+                ));
+            }
+
+            let point = match flags.is_positive() {
+                None if x.is_zero() => return Ok(Self::zero()),
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "Compressed point at infinity has a nonzero x-coordinate",
+                    ));
+                }
+                Some(sign) => Self::from_x_coordinate_with_sign(x, sign, P::SIGN_CONVENTION).ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidData,
+                        "Compressed x-coordinate does not correspond to a point on the curve",
+                    )
+                })?,
+            };
+
+            if !point.is_on_curve() {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "Compressed point is not on the curve",
+                ));
+            }
+            if !point.is_in_correct_subgroup_assuming_on_curve() {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "Compressed point is not in the prime order subgroup",
+                ));
+            }
+            Ok(point)
+        }
+    };
+}
+
+/// Converts a point in the compressed `CanonicalSerialize` encoding from one y-coordinate sign
+/// convention to another, leaving the x-coordinate and the point itself unchanged.
+///
+/// The x-coordinate must correspond to a point on the curve, but prime order subgroup
+/// membership is not checked; that is left to the deserializer of the re-encoded point.
+pub fn reencode_compressed<P: SWModelParameters>(
+    bytes: &[u8],
+    from_convention: SignConvention,
+    to_convention: SignConvention,
+) -> Result<Vec<u8>, SerializationError> {
+    let mut reader = bytes;
+    let (x, flags): (P::BaseField, SWFlags) = CanonicalDeserializeWithFlags::deserialize_with_flags(&mut reader)?;
+    if !reader.is_empty() {
+        return Err(SerializationError::InvalidData);
+    }
+
+    let (x, flags) = match flags.is_positive() {
+        None => (P::BaseField::zero(), SWFlags::infinity()),
+        Some(sign) => {
+            let point = GroupAffine::<P>::from_x_coordinate_with_sign(x, sign, from_convention)
+                .ok_or(SerializationError::InvalidData)?;
+            (x, SWFlags::from_y_sign(to_convention.sign(&point.y)))
+        }
+    };
+
+    let mut reencoded = Vec::with_capacity(bytes.len());
+    x.serialize_with_flags(&mut reencoded, flags)?;
+    Ok(reencoded)
+}
@@ -14,21 +14,34 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use super::short_weierstrass_jacobian::{GroupAffine, GroupProjective};
+use super::{
+    affine,
+    hash_to_curve::{find_svdw_z, map_to_curve_svdw},
+    reencode_compressed,
+    short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    short_weierstrass_projective,
+    wnaf::{double_and_add, wnaf_digits, wnaf_mul},
+};
 
 use snarkvm_utilities::{
-    io::Cursor,
+    bititerator::BitIteratorBE,
+    bytes::{FromBytes, ToBytes},
+    io::{Cursor, ErrorKind},
     rand::UniformRand,
     serialize::{CanonicalDeserialize, CanonicalSerialize},
+    to_bytes,
 };
 
 use crate::traits::{
     pairing_engine::{AffineCurve, ProjectiveCurve},
+    Group,
+    HashToCurve,
     SWModelParameters,
+    SignConvention,
 };
-use snarkvm_fields::Zero;
+use snarkvm_fields::{Field, One, PrimeField, Zero};
 
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 
 pub const ITERATIONS: usize = 10;
@@ -36,6 +49,15 @@ pub const ITERATIONS: usize = 10;
 pub fn sw_tests<P: SWModelParameters>() {
     sw_curve_serialization_test::<P>();
     sw_from_random_bytes::<P>();
+    sw_add_assign_mixed_many_test::<P>();
+    sw_batch_normalization_into_affine_test::<P>();
+    sw_compressed_bytes_test::<P>();
+    sw_sign_convention_test::<P>();
+    sw_from_bytes_validation_test::<P>();
+    sw_scalar_mul_test::<P>();
+    sw_cofactor_mul_test::<P>();
+    sw_hash_to_curve_test::<P>();
+    sw_shared_affine_test::<P>();
 }
 
 pub fn sw_curve_serialization_test<P: SWModelParameters>() {
@@ -141,3 +163,422 @@ pub fn sw_from_random_bytes<P: SWModelParameters>() {
         }
     }
 }
+
+pub fn sw_add_assign_mixed_many_test<P: SWModelParameters>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for num_points in [0, 1, 2, 300].iter() {
+        let mut points: Vec<GroupAffine<P>> = (0..*num_points)
+            .map(|_| GroupProjective::<P>::rand(&mut rng).into_affine())
+            .collect();
+
+        // Exercise the infinity, doubling, and cancellation cases.
+        if *num_points > 2 {
+            let a = points[0];
+            points.insert(1, a);
+            points.insert(2, GroupAffine::<P>::zero());
+            points.insert(3, -(a.into_projective().double().into_affine()));
+            points.push(GroupAffine::<P>::zero());
+        }
+
+        for start in [GroupProjective::<P>::zero(), GroupProjective::<P>::rand(&mut rng)].iter() {
+            let mut expected = *start;
+            points.iter().for_each(|point| expected.add_assign_mixed(point));
+
+            let mut candidate = *start;
+            candidate.add_assign_mixed_many(&points);
+
+            assert_eq!(expected.x, candidate.x);
+            assert_eq!(expected.y, candidate.y);
+            assert_eq!(expected.z, candidate.z);
+        }
+
+        let points: Vec<short_weierstrass_projective::GroupAffine<P>> = points
+            .iter()
+            .map(|point| short_weierstrass_projective::GroupAffine::new(point.x, point.y, point.infinity))
+            .collect();
+
+        for start in [
+            short_weierstrass_projective::GroupProjective::<P>::zero(),
+            short_weierstrass_projective::GroupProjective::<P>::rand(&mut rng),
+        ]
+        .iter()
+        {
+            let mut expected = *start;
+            points.iter().for_each(|point| expected.add_assign_mixed(point));
+
+            let mut candidate = *start;
+            candidate.add_assign_mixed_many(&points);
+
+            assert_eq!(expected.x, candidate.x);
+            assert_eq!(expected.y, candidate.y);
+            assert_eq!(expected.z, candidate.z);
+        }
+    }
+}
+
+/// Checks the batch conversions of `points` against converting each point with `into_affine`.
+fn sw_batch_normalization_into_affine<G: ProjectiveCurve>(points: Vec<G>) {
+    let expected: Vec<G::Affine> = points.iter().map(|point| point.into_affine()).collect();
+
+    // Once normalized, `into_affine` reads the coordinates directly.
+    let mut normalized = points.clone();
+    G::batch_normalization(&mut normalized);
+    for (point, expected) in normalized.iter().zip(&expected) {
+        assert!(point.is_normalized());
+        assert_eq!(&point.into_affine(), expected);
+    }
+
+    assert_eq!(G::batch_normalization_into_affine(points), expected);
+}
+
+pub fn sw_batch_normalization_into_affine_test<P: SWModelParameters>() {
+    fn mixed_points<G: ProjectiveCurve, R: Rng>(rng: &mut R, size: usize) -> Vec<G> {
+        (0..size)
+            .map(|_| match rng.gen_range(0..3) {
+                0 => G::zero(),
+                1 => G::rand(rng).into_affine().into_projective(),
+                _ => G::rand(rng),
+            })
+            .collect()
+    }
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for size in [0, 1, 2, ITERATIONS].iter() {
+        for _ in 0..ITERATIONS {
+            sw_batch_normalization_into_affine(mixed_points::<GroupProjective<P>, _>(&mut rng, *size));
+            sw_batch_normalization_into_affine(mixed_points::<short_weierstrass_projective::GroupProjective<P>, _>(
+                &mut rng, *size,
+            ));
+        }
+    }
+
+    // Vectors of only points at infinity or only normalized points require no inversion.
+    sw_batch_normalization_into_affine(vec![GroupProjective::<P>::zero(); ITERATIONS]);
+    sw_batch_normalization_into_affine(vec![
+        short_weierstrass_projective::GroupProjective::<P>::zero();
+        ITERATIONS
+    ]);
+    sw_batch_normalization_into_affine(vec![GroupProjective::<P>::prime_subgroup_generator(); ITERATIONS]);
+    sw_batch_normalization_into_affine(vec![
+        short_weierstrass_projective::GroupProjective::<P>::prime_subgroup_generator();
+        ITERATIONS
+    ]);
+}
+
+pub fn sw_compressed_bytes_test<P: SWModelParameters>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let mut points: Vec<GroupAffine<P>> = (0..ITERATIONS)
+        .map(|_| GroupProjective::<P>::rand(&mut rng).into_affine())
+        .collect();
+    points.push(GroupAffine::<P>::zero());
+
+    for a in points.iter() {
+        let mut serialized = vec![];
+        a.write_compressed(&mut serialized).unwrap();
+        assert_eq!(serialized.len(), to_bytes![a.x].unwrap().len() + 1);
+        assert_eq!(*a, GroupAffine::<P>::read_compressed(&serialized[..]).unwrap());
+
+        let a = short_weierstrass_projective::GroupAffine::<P>::new(a.x, a.y, a.infinity);
+        let mut projective_serialized = vec![];
+        a.write_compressed(&mut projective_serialized).unwrap();
+        assert_eq!(serialized, projective_serialized);
+        assert_eq!(
+            a,
+            short_weierstrass_projective::GroupAffine::<P>::read_compressed(&serialized[..]).unwrap()
+        );
+    }
+
+    // An x-coordinate with no corresponding y-coordinate on the curve is rejected.
+    let x = loop {
+        let x = P::BaseField::rand(&mut rng);
+        if GroupAffine::<P>::from_x_coordinate(x, false).is_none() {
+            break x;
+        }
+    };
+    let mut serialized = to_bytes![x].unwrap();
+    serialized.push(0);
+    let error = GroupAffine::<P>::read_compressed(&serialized[..]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    let error = short_weierstrass_projective::GroupAffine::<P>::read_compressed(&serialized[..]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+    // Unknown flag bits are rejected.
+    let mut serialized = vec![];
+    points[0].write_compressed(&mut serialized).unwrap();
+    *serialized.last_mut().unwrap() |= 1;
+    let error = GroupAffine::<P>::read_compressed(&serialized[..]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+    // The point at infinity must have a zero x-coordinate.
+    let mut serialized = vec![];
+    points[0].write_compressed(&mut serialized).unwrap();
+    *serialized.last_mut().unwrap() = 1 << 6;
+    let error = GroupAffine::<P>::read_compressed(&serialized[..]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
+
+pub fn sw_from_bytes_validation_test<P: SWModelParameters>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = GroupProjective::<P>::rand(&mut rng).into_affine();
+
+        // Valid points and the point at infinity round trip.
+        for point in [a, GroupAffine::<P>::zero()].iter() {
+            let bytes = to_bytes![point].unwrap();
+            assert_eq!(*point, GroupAffine::<P>::read(&bytes[..]).unwrap());
+            assert_eq!(*point, GroupAffine::<P>::read_checked(&bytes[..]).unwrap());
+            assert_eq!(
+                short_weierstrass_projective::GroupAffine::<P>::new(point.x, point.y, point.infinity),
+                short_weierstrass_projective::GroupAffine::<P>::read(&bytes[..]).unwrap()
+            );
+        }
+
+        // The infinity flag is only valid with the canonical coordinates (0, 1).
+        let malformed = [
+            to_bytes![a.x, a.y, true].unwrap(),
+            to_bytes![P::BaseField::zero(), a.y, true].unwrap(),
+            to_bytes![a.x, P::BaseField::one(), true].unwrap(),
+            // A point that is not on the curve.
+            to_bytes![a.x, a.y + P::BaseField::one(), false].unwrap(),
+        ];
+        for bytes in malformed.iter() {
+            assert!(GroupAffine::<P>::read(&bytes[..]).is_err());
+            assert!(short_weierstrass_projective::GroupAffine::<P>::read(&bytes[..]).is_err());
+        }
+    }
+
+    // A point with coordinates (0, 1) is not the point at infinity, and is accepted exactly when it is on the curve.
+    let bytes = to_bytes![P::BaseField::zero(), P::BaseField::one(), false].unwrap();
+    let point = GroupAffine::<P>::new(P::BaseField::zero(), P::BaseField::one(), false);
+    assert_eq!(point.is_on_curve(), GroupAffine::<P>::read(&bytes[..]).is_ok());
+    assert_eq!(
+        point.is_on_curve(),
+        short_weierstrass_projective::GroupAffine::<P>::read(&bytes[..]).is_ok()
+    );
+
+    // A point on the curve outside of the prime order subgroup is only rejected by `read_checked`.
+    if P::COFACTOR != [1] {
+        let point = loop {
+            let x = P::BaseField::rand(&mut rng);
+            if let Some(point) = GroupAffine::<P>::from_x_coordinate(x, false) {
+                if !point.is_in_correct_subgroup_assuming_on_curve() {
+                    break point;
+                }
+            }
+        };
+        let bytes = to_bytes![point].unwrap();
+        assert_eq!(point, GroupAffine::<P>::read(&bytes[..]).unwrap());
+        assert!(GroupAffine::<P>::read_checked(&bytes[..]).is_err());
+        assert!(short_weierstrass_projective::GroupAffine::<P>::read_checked(&bytes[..]).is_err());
+    }
+}
+
+pub fn sw_scalar_mul_test<P: SWModelParameters>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let mut scalars = vec![P::ScalarField::zero(), P::ScalarField::one(), -P::ScalarField::one()];
+    scalars.extend((0..ITERATIONS).map(|_| P::ScalarField::rand(&mut rng)));
+    scalars.extend((0..ITERATIONS).map(|_| P::ScalarField::from(rng.gen::<u32>() as u64)));
+
+    for scalar in scalars {
+        let repr = scalar.into_repr();
+
+        let a = GroupProjective::<P>::rand(&mut rng);
+        let expected = double_and_add(&a, repr);
+        assert_eq!(a * scalar, expected);
+        assert_eq!((a.into_affine() * scalar).into_projective(), expected);
+
+        let b = short_weierstrass_projective::GroupProjective::<P>::rand(&mut rng);
+        let expected = double_and_add(&b, repr);
+        assert_eq!(b * scalar, expected);
+        assert_eq!((b.into_affine() * scalar).into_projective(), expected);
+
+        for window in 2..=6 {
+            let digits = wnaf_digits(repr, window);
+            assert!(digits
+                .iter()
+                .all(|&d| d == 0 || (d % 2 != 0 && d.abs() < 1 << (window - 1))));
+            assert_eq!(wnaf_mul(&a, repr, window), double_and_add(&a, repr));
+        }
+    }
+}
+
+pub fn sw_cofactor_mul_test<P: SWModelParameters>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // The cofactor reduced into the scalar field, which acts as the cofactor on the prime order subgroup.
+    let mut cofactor = P::ScalarField::zero();
+    for bit in BitIteratorBE::new(P::COFACTOR) {
+        cofactor = cofactor.double();
+        if bit {
+            cofactor += &P::ScalarField::one();
+        }
+    }
+
+    for _ in 0..ITERATIONS {
+        let a = GroupProjective::<P>::rand(&mut rng);
+        let expected = a.into_affine().mul_bits(BitIteratorBE::new(P::COFACTOR));
+        assert_eq!(a.into_affine().scale_by_cofactor(), expected);
+        assert_eq!(a * cofactor, expected);
+
+        let b = short_weierstrass_projective::GroupProjective::<P>::rand(&mut rng);
+        let expected = b.into_affine().mul_bits(BitIteratorBE::new(P::COFACTOR));
+        assert_eq!(b.into_affine().scale_by_cofactor(), expected);
+        assert_eq!(b * cofactor, expected);
+    }
+}
+
+pub fn sw_sign_convention_test<P: SWModelParameters>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let conventions = [SignConvention::LexicographicallyLargest, SignConvention::Parity];
+
+    let mut points: Vec<GroupAffine<P>> = (0..ITERATIONS)
+        .map(|_| GroupProjective::<P>::rand(&mut rng).into_affine())
+        .collect();
+    points.push(GroupAffine::<P>::prime_subgroup_generator());
+
+    for a in points.iter() {
+        let mut serialized = vec![];
+        a.serialize(&mut serialized).unwrap();
+
+        for convention in conventions.iter() {
+            // The sign bit distinguishes y from -y, and recovers the point from its x-coordinate.
+            let sign = convention.sign(&a.y);
+            assert_ne!(sign, convention.sign(&-a.y));
+            assert_eq!(
+                *a,
+                GroupAffine::<P>::from_x_coordinate_with_sign(a.x, sign, *convention).unwrap()
+            );
+            assert_eq!(
+                -*a,
+                GroupAffine::<P>::from_x_coordinate_with_sign(a.x, !sign, *convention).unwrap()
+            );
+
+            // Re-encoding only changes the sign bit, and round-trips.
+            let reencoded = reencode_compressed::<P>(&serialized, P::SIGN_CONVENTION, *convention).unwrap();
+            assert_eq!(reencoded.len(), serialized.len());
+            assert_eq!(reencoded[..reencoded.len() - 1], serialized[..serialized.len() - 1]);
+            assert_eq!(
+                serialized,
+                reencode_compressed::<P>(&reencoded, *convention, P::SIGN_CONVENTION).unwrap()
+            );
+            if *convention == P::SIGN_CONVENTION {
+                assert_eq!(serialized, reencoded);
+            }
+        }
+    }
+
+    // The point at infinity is unaffected by the sign convention.
+    let mut serialized = vec![];
+    GroupAffine::<P>::zero().serialize(&mut serialized).unwrap();
+    for from in conventions.iter() {
+        for to in conventions.iter() {
+            assert_eq!(serialized, reencode_compressed::<P>(&serialized, *from, *to).unwrap());
+        }
+    }
+
+    // Trailing bytes are rejected.
+    serialized.push(0);
+    assert!(reencode_compressed::<P>(&serialized, P::SIGN_CONVENTION, SignConvention::Parity).is_err());
+}
+
+/// Checks the y-coordinate sign bits of the prime subgroup generator under both conventions,
+/// and that its compressed encoding carries the bit selected by `P::SIGN_CONVENTION`.
+pub fn sw_generator_sign_test<P: SWModelParameters>(lexicographically_largest: bool, parity: bool) {
+    let generator = GroupAffine::<P>::prime_subgroup_generator();
+    assert_eq!(
+        lexicographically_largest,
+        SignConvention::LexicographicallyLargest.sign(&generator.y)
+    );
+    assert_eq!(parity, SignConvention::Parity.sign(&generator.y));
+
+    let mut serialized = vec![];
+    generator.serialize(&mut serialized).unwrap();
+    let expected_sign = match P::SIGN_CONVENTION {
+        SignConvention::LexicographicallyLargest => lexicographically_largest,
+        SignConvention::Parity => parity,
+    };
+    assert_eq!(expected_sign, serialized.last().unwrap() & (1 << 7) != 0);
+}
+
+pub fn sw_hash_to_curve_test<P: SWModelParameters>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // Overrides of the map constant must agree with the default search.
+    assert_eq!(P::svdw_z(), find_svdw_z::<P>());
+
+    // The map lands on the curve for every input, including zero.
+    for u in (0..ITERATIONS)
+        .map(|_| P::BaseField::rand(&mut rng))
+        .chain(Some(P::BaseField::zero()))
+    {
+        let (x, y) = map_to_curve_svdw::<P>(u);
+        assert!(GroupAffine::<P>::new(x, y, false).is_on_curve());
+    }
+
+    for i in 0..ITERATIONS {
+        let message = (0..i * 7).map(|_| rng.gen()).collect::<Vec<u8>>();
+
+        let a = GroupAffine::<P>::hash_to_curve(&message, b"snarkVM-test");
+        assert!(!a.is_zero());
+        assert!(a.is_on_curve());
+        assert!(a.is_in_correct_subgroup_assuming_on_curve());
+
+        // The output is deterministic, and agrees across the templates.
+        assert_eq!(a, GroupAffine::<P>::hash_to_curve(&message, b"snarkVM-test"));
+        assert_eq!(
+            a,
+            GroupProjective::<P>::hash_to_curve(&message, b"snarkVM-test").into_affine()
+        );
+        let b = short_weierstrass_projective::GroupAffine::<P>::hash_to_curve(&message, b"snarkVM-test");
+        assert_eq!((a.x, a.y), (b.x, b.y));
+
+        // Distinct messages and domains yield distinct points.
+        assert_ne!(a, GroupAffine::<P>::hash_to_curve(&message, b"snarkVM-other"));
+        let mut other_message = message.clone();
+        other_message.push(0);
+        assert_ne!(a, GroupAffine::<P>::hash_to_curve(&other_message, b"snarkVM-test"));
+    }
+}
+
+/// Compiles only if both projective representations use the affine type of `affine`.
+#[allow(dead_code)]
+fn sw_shared_affine_type<P: SWModelParameters>(
+    jacobian: GroupAffine<P>,
+    projective: short_weierstrass_projective::GroupAffine<P>,
+) -> (
+    affine::GroupAffine<P, GroupProjective<P>>,
+    affine::GroupAffine<P, short_weierstrass_projective::GroupProjective<P>>,
+) {
+    (jacobian, projective)
+}
+
+pub fn sw_shared_affine_test<P: SWModelParameters>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = GroupProjective::<P>::rand(&mut rng).into_affine();
+        let b = GroupProjective::<P>::rand(&mut rng).into_affine();
+        let scalar = P::ScalarField::rand(&mut rng);
+
+        let a_projective = short_weierstrass_projective::GroupAffine::<P>::new(a.x, a.y, a.infinity);
+        let b_projective = short_weierstrass_projective::GroupAffine::<P>::new(b.x, b.y, b.infinity);
+
+        // The affine results do not depend on the projective representation.
+        for (expected, candidate) in [
+            (a + b, a_projective + b_projective),
+            (a - b, a_projective - b_projective),
+            (a.double(), a_projective.double()),
+            (a * scalar, a_projective * scalar),
+            (a.scale_by_cofactor().into_affine(), a_projective.scale_by_cofactor().into_affine()),
+        ]
+        .iter()
+        {
+            assert_eq!(to_bytes![expected].unwrap(), to_bytes![candidate].unwrap());
+        }
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::traits::Group;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{biginteger::BigInteger, bititerator::BitIteratorBE};
+
+/// The smallest recommended wNAF window for which scalar multiplication uses the windowed NAF
+/// method. Scalars with a smaller recommended window fall back to double-and-add.
+pub const WNAF_MIN_WINDOW: usize = 3;
+
+/// The largest wNAF window supported by `wnaf_mul`, which keeps its odd multiples on the stack.
+pub const WNAF_MAX_WINDOW: usize = 6;
+
+/// Multiplies `base` by `scalar` with the binary double-and-add method.
+pub fn double_and_add<G: Group>(base: &G, scalar: <G::ScalarField as PrimeField>::BigInteger) -> G {
+    let mut res = G::zero();
+
+    for i in BitIteratorBE::new_without_leading_zeros(scalar) {
+        res.double_in_place();
+        if i {
+            res += base;
+        }
+    }
+
+    res
+}
+
+/// Iterates over the windowed non-adjacent form of a scalar, least significant digit first.
+///
+/// Every nonzero digit is odd and lies strictly between `-2^(window - 1)` and `2^(window - 1)`.
+/// The scalar must leave at least one spare bit in its representation, which holds for any
+/// reduced prime field element.
+#[derive(Clone, Debug)]
+pub struct WnafDigits<B: BigInteger> {
+    scalar: B,
+    window: usize,
+}
+
+impl<B: BigInteger> WnafDigits<B> {
+    pub fn new(scalar: B, window: usize) -> Self {
+        assert!((2..=22).contains(&window), "wNAF window must be between 2 and 22");
+
+        Self { scalar, window }
+    }
+}
+
+impl<B: BigInteger> Iterator for WnafDigits<B> {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<i64> {
+        if self.scalar.is_zero() {
+            return None;
+        }
+
+        let modulus = 1u64 << self.window;
+        let half_modulus = modulus >> 1;
+
+        let digit = if self.scalar.is_odd() {
+            let low_bits = self.scalar.as_ref()[0] % modulus;
+            if low_bits >= half_modulus {
+                let carry = self.scalar.add_nocarry(&B::from(modulus - low_bits));
+                debug_assert!(!carry);
+                low_bits as i64 - modulus as i64
+            } else {
+                self.scalar.sub_noborrow(&B::from(low_bits));
+                low_bits as i64
+            }
+        } else {
+            0
+        };
+        self.scalar.div2();
+
+        Some(digit)
+    }
+}
+
+/// Returns the windowed non-adjacent form of `scalar`, least significant digit first.
+///
+/// Every nonzero digit is odd and lies strictly between `-2^(window - 1)` and `2^(window - 1)`.
+/// The scalar must leave at least one spare bit in its representation, which holds for any
+/// reduced prime field element.
+pub fn wnaf_digits<B: BigInteger>(scalar: B, window: usize) -> Vec<i64> {
+    let mut digits = Vec::with_capacity(scalar.num_bits() as usize + 1);
+    digits.extend(WnafDigits::new(scalar, window));
+    digits
+}
+
+/// Multiplies `base` by `scalar` with the windowed NAF method, without allocating.
+///
+/// The digits are consumed least significant first: each nonzero digit `d` at position `i`
+/// adds `±2^i * base` to the bucket of its odd multiple `|d|`, and the `2^(window - 2)` buckets
+/// are then combined as `sum_j (2j + 1) * bucket_j`.
+pub fn wnaf_mul<G: Group>(base: &G, scalar: <G::ScalarField as PrimeField>::BigInteger, window: usize) -> G {
+    assert!(
+        (2..=WNAF_MAX_WINDOW).contains(&window),
+        "wNAF window must be between 2 and {}",
+        WNAF_MAX_WINDOW
+    );
+
+    let mut buckets = [G::zero(); 1 << (WNAF_MAX_WINDOW - 2)];
+    let buckets = &mut buckets[..1 << (window - 2)];
+
+    let mut power = *base;
+    let mut digits = WnafDigits::new(scalar, window).peekable();
+    while let Some(digit) = digits.next() {
+        if digit > 0 {
+            buckets[(digit / 2) as usize] += &power;
+        } else if digit < 0 {
+            buckets[(-digit / 2) as usize] -= &power;
+        }
+
+        if digits.peek().is_some() {
+            power.double_in_place();
+        }
+    }
+
+    // The running sum adds each bucket `j + 1` times, so doubling it and subtracting
+    // every bucket once more yields `sum_j (2j + 1) * bucket_j`.
+    let mut running_sum = G::zero();
+    let mut res = G::zero();
+    for bucket in buckets.iter().rev() {
+        running_sum += bucket;
+        res += &running_sum;
+    }
+    res.double_in_place();
+    res -= &running_sum;
+
+    res
+}
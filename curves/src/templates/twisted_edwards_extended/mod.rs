@@ -38,7 +38,7 @@ use rand::{
 };
 use std::{
     fmt::{Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write},
+    io::{Error, ErrorKind, Read, Result as IoResult, Write},
     marker::PhantomData,
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
@@ -79,7 +79,7 @@ impl<P: Parameters> GroupAffine<P> {
 
     #[must_use]
     pub fn scale_by_cofactor(&self) -> <Self as AffineCurve>::Projective {
-        self.mul_bits(BitIteratorBE::new(P::COFACTOR))
+        self.mul_bits(BitIteratorBE::new_without_leading_zeros(P::COFACTOR))
     }
 
     /// Checks that the current point is on the elliptic curve.
@@ -189,8 +189,10 @@ impl<P: Parameters> AffineCurve for GroupAffine<P> {
     }
 
     fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
-        self.mul_bits(BitIteratorBE::new(P::ScalarField::characteristic()))
-            .is_zero()
+        self.mul_bits(BitIteratorBE::new_without_leading_zeros(
+            P::ScalarField::characteristic(),
+        ))
+        .is_zero()
     }
 
     fn to_x_coordinate(&self) -> Self::BaseField {
@@ -289,7 +291,12 @@ impl<P: Parameters> Mul<P::ScalarField> for GroupAffine<P> {
     type Output = Self;
 
     fn mul(self, other: P::ScalarField) -> Self {
-        self.mul_bits(BitIteratorBE::new(other.into_repr())).into()
+        if other.is_zero() {
+            return Self::zero();
+        }
+
+        self.mul_bits(BitIteratorBE::new_without_leading_zeros(other.into_repr()))
+            .into()
     }
 }
 
@@ -312,7 +319,12 @@ impl<P: Parameters> FromBytes for GroupAffine<P> {
     fn read<R: Read>(mut reader: R) -> IoResult<Self> {
         let x = P::BaseField::read(&mut reader)?;
         let y = P::BaseField::read(&mut reader)?;
-        Ok(Self::new(x, y))
+
+        let point = Self::new(x, y);
+        if !point.is_on_curve() {
+            return Err(Error::new(ErrorKind::InvalidData, "Point is not on the curve"));
+        }
+        Ok(point)
     }
 }
 
@@ -408,7 +420,12 @@ impl<P: Parameters> FromBytes for GroupProjective<P> {
         let y = P::BaseField::read(&mut reader)?;
         let t = P::BaseField::read(&mut reader)?;
         let z = P::BaseField::read(reader)?;
-        Ok(Self::new(x, y, t, z))
+
+        let point = Self::new(x, y, t, z);
+        if !point.is_on_curve() {
+            return Err(Error::new(ErrorKind::InvalidData, "Point is not on the curve"));
+        }
+        Ok(point)
     }
 }
 
@@ -429,6 +446,23 @@ impl<P: Parameters> GroupProjective<P> {
             _params: PhantomData,
         }
     }
+
+    /// Checks that the current point is on the elliptic curve, with consistent extended coordinates.
+    pub fn is_on_curve(&self) -> bool {
+        if self.z.is_zero() || self.x * self.y != self.t * self.z {
+            return false;
+        }
+
+        // The curve equation `a * x^2 + y^2 = 1 + d * x^2 * y^2`, scaled by `Z^4`.
+        let x2 = self.x.square();
+        let y2 = self.y.square();
+        let z2 = self.z.square();
+
+        let lhs = (y2 + P::mul_by_a(&x2)) * z2;
+        let rhs = z2.square() + (P::COEFF_D * (x2 * y2));
+
+        lhs == rhs
+    }
 }
 
 impl<P: Parameters> Zero for GroupProjective<P> {
@@ -658,17 +692,14 @@ impl<P: Parameters> Mul<P::ScalarField> for GroupProjective<P> {
     #[allow(clippy::suspicious_arithmetic_impl)]
     #[inline]
     fn mul(self, other: P::ScalarField) -> Self {
-        let mut res = Self::zero();
-
-        let mut found_one = false;
+        if other.is_zero() {
+            return Self::zero();
+        }
 
-        for i in BitIteratorBE::new(other.into_repr()) {
-            if found_one {
-                res.double_in_place();
-            } else {
-                found_one = i;
-            }
+        let mut res = Self::zero();
 
+        for i in BitIteratorBE::new_without_leading_zeros(other.into_repr()) {
+            res.double_in_place();
             if i {
                 res += self;
             }
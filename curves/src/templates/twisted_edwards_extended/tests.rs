@@ -17,7 +17,8 @@
 use super::{GroupAffine, GroupProjective};
 
 use snarkvm_utilities::{
-    bytes::ToBytes,
+    bititerator::BitIteratorBE,
+    bytes::{FromBytes, ToBytes},
     io::Cursor,
     rand::UniformRand,
     serialize::{CanonicalDeserialize, CanonicalSerialize},
@@ -54,8 +55,10 @@ where
     P::BaseField: PrimeField,
 {
     edwards_curve_serialization_test::<P>();
+    edwards_from_bytes_test::<P>();
     edwards_from_random_bytes::<P>();
     edwards_from_x_and_y_coordinates::<P>();
+    edwards_cofactor_test::<P>();
 }
 
 pub fn edwards_curve_serialization_test<P: TEModelParameters>() {
@@ -121,6 +124,33 @@ pub fn edwards_curve_serialization_test<P: TEModelParameters>() {
     }
 }
 
+pub fn edwards_from_bytes_test<P: TEModelParameters>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = GroupProjective::<P>::rand(&mut rng);
+        assert_eq!(a, GroupProjective::<P>::read(&to_bytes![a].unwrap()[..]).unwrap());
+
+        let a = a.into_affine();
+        assert_eq!(a, GroupAffine::<P>::read(&to_bytes![a].unwrap()[..]).unwrap());
+
+        // Points that are off the curve are rejected.
+        let b = GroupAffine::<P>::new(a.x, a.y.double());
+        assert!(GroupAffine::<P>::read(&to_bytes![b].unwrap()[..]).is_err());
+
+        // Extended coordinates must be consistent, with a nonzero `Z`.
+        let c = GroupProjective::<P>::new(a.x, a.y, a.x * a.y.double(), P::BaseField::one());
+        assert!(GroupProjective::<P>::read(&to_bytes![c].unwrap()[..]).is_err());
+        let d = GroupProjective::<P>::new(a.x, a.y, a.x * a.y, P::BaseField::zero());
+        assert!(GroupProjective::<P>::read(&to_bytes![d].unwrap()[..]).is_err());
+    }
+
+    let zero = GroupProjective::<P>::zero();
+    assert_eq!(zero, GroupProjective::<P>::read(&to_bytes![zero].unwrap()[..]).unwrap());
+    let zero = GroupAffine::<P>::zero();
+    assert_eq!(zero, GroupAffine::<P>::read(&to_bytes![zero].unwrap()[..]).unwrap());
+}
+
 pub fn edwards_from_random_bytes<P: TEModelParameters>()
 where
     P::BaseField: PrimeField,
@@ -183,3 +213,48 @@ where
         }
     }
 }
+
+pub fn edwards_cofactor_test<P: TEModelParameters>()
+where
+    P::BaseField: PrimeField,
+{
+    // Check that the cofactor inverse is the inverse of the cofactor in the scalar field.
+    let mut cofactor = P::ScalarField::zero();
+    for bit in BitIteratorBE::new(P::COFACTOR) {
+        cofactor = cofactor.double();
+        if bit {
+            cofactor += &P::ScalarField::one();
+        }
+    }
+    assert_eq!(cofactor * P::COFACTOR_INV, P::ScalarField::one());
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        // Scaling by the cofactor and its inverse is the identity on the prime order subgroup.
+        let a = GroupProjective::<P>::rand(&mut rng).into_affine();
+        assert!(a.is_in_correct_subgroup_assuming_on_curve());
+        assert_eq!(a.mul_by_cofactor().mul_by_cofactor_inv(), a);
+
+        // Skipping the leading zeros of the cofactor does not change the result.
+        let expected = a.mul_bits(BitIteratorBE::new(P::COFACTOR));
+        assert_eq!(a.scale_by_cofactor(), expected);
+        assert_eq!(a.into_projective() * cofactor, expected);
+
+        // Scaling any point on the curve by the cofactor maps it into the prime order subgroup.
+        let b = loop {
+            let x = P::BaseField::rand(&mut rng);
+            if let Some(b) = GroupAffine::<P>::from_x_coordinate(x, true) {
+                if !b.is_in_correct_subgroup_assuming_on_curve() {
+                    break b;
+                }
+            }
+        };
+        assert!(b.is_on_curve());
+        assert!(
+            b.scale_by_cofactor()
+                .into_affine()
+                .is_in_correct_subgroup_assuming_on_curve()
+        );
+    }
+}
@@ -0,0 +1,24 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// Deterministically hashes arbitrary messages to elliptic curve points.
+pub trait HashToCurve: Sized {
+    /// Hashes `message` to a point in the prime order subgroup, under the domain separation tag `domain`.
+    ///
+    /// The output depends only on `message` and `domain`, and no discrete logarithm
+    /// relation is known between the outputs of distinct inputs.
+    fn hash_to_curve(message: &[u8], domain: &[u8]) -> Self;
+}
@@ -17,6 +17,9 @@
 pub mod group;
 pub use group::*;
 
+pub mod hash_to_curve;
+pub use hash_to_curve::*;
+
 pub mod pairing_engine;
 pub use pairing_engine::*;
 
@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::traits::Group;
+use crate::{
+    templates::{msm::pippenger, short_weierstrass::hash_to_curve::find_svdw_z},
+    traits::Group,
+};
 use snarkvm_fields::{Field, PrimeField, SquareRootField};
 use snarkvm_utilities::{biginteger::BigInteger, bytes::ToBytes, serialize::*, BitIteratorBE};
 
@@ -136,6 +139,14 @@ pub trait ProjectiveCurve:
     #[allow(clippy::wrong_self_convention)]
     fn into_affine(&self) -> Self::Affine;
 
+    /// Returns the sum of `bases[i] * scalars[i]`, computed with Pippenger's algorithm.
+    ///
+    /// If the slices differ in length, the longer one is truncated to the length of the shorter one.
+    #[must_use]
+    fn msm(bases: &[Self::Affine], scalars: &[<Self::ScalarField as PrimeField>::BigInteger]) -> Self {
+        pippenger(bases, scalars)
+    }
+
     /// Recommends a wNAF window table size given a scalar. Always returns a
     /// number between 2 and 22, inclusive.
     #[must_use]
@@ -244,12 +255,54 @@ pub trait ModelParameters: Send + Sync + 'static {
     type ScalarField: PrimeField + SquareRootField + Into<<Self::ScalarField as PrimeField>::BigInteger>;
 }
 
+/// The rule used to pick the sign bit of the y-coordinate in a compressed
+/// short Weierstrass point encoding.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SignConvention {
+    /// The sign bit is set if and only if `y > -y`, comparing field elements
+    /// lexicographically from the highest coefficient down. This is the
+    /// convention used by zexe and arkworks.
+    LexicographicallyLargest,
+    /// The sign bit is the parity of the first nonzero coefficient of `y`,
+    /// in canonical form, starting from the lowest coefficient. This is the
+    /// `sgn0` function of RFC 9380.
+    Parity,
+}
+
+impl SignConvention {
+    /// Returns the sign bit of `y` under this convention.
+    ///
+    /// The sign bits of `y` and `-y` differ for every nonzero `y`, and the sign bit of zero is unset.
+    pub fn sign<F: Field>(&self, y: &F) -> bool {
+        match self {
+            SignConvention::LexicographicallyLargest => *y > -*y,
+            SignConvention::Parity => {
+                // `ToBytes` writes each coefficient as a little-endian integer of the same
+                // width as the characteristic, starting from the lowest coefficient.
+                let coefficient_size = F::characteristic().len() * 8;
+                let mut bytes = Vec::new();
+                y.write(&mut bytes)
+                    .expect("failed to write a field element to a vector");
+                match bytes
+                    .chunks(coefficient_size)
+                    .find(|coefficient| coefficient.iter().any(|byte| *byte != 0))
+                {
+                    Some(coefficient) => coefficient[0] & 1 == 1,
+                    None => false,
+                }
+            }
+        }
+    }
+}
+
 pub trait SWModelParameters: ModelParameters {
     const COEFF_A: Self::BaseField;
     const COEFF_B: Self::BaseField;
     const COFACTOR: &'static [u64];
     const COFACTOR_INV: Self::ScalarField;
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField);
+    /// The convention for the y-coordinate sign bit in compressed point encodings.
+    const SIGN_CONVENTION: SignConvention;
 
     #[inline(always)]
     fn mul_by_a(elem: &Self::BaseField) -> Self::BaseField {
@@ -291,6 +344,13 @@ pub trait SWModelParameters: ModelParameters {
         }
         result
     }
+
+    /// Returns the constant `Z` of the Shallue-van de Woestijne map used by `HashToCurve`.
+    ///
+    /// Curves may override the default search with the precomputed constant.
+    fn svdw_z() -> Self::BaseField {
+        find_svdw_z::<Self>()
+    }
 }
 
 pub trait TEModelParameters: ModelParameters {
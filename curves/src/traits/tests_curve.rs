@@ -15,8 +15,8 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::traits::{AffineCurve, ProjectiveCurve};
-use snarkvm_fields::Zero;
-use snarkvm_utilities::rand::UniformRand;
+use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_utilities::{rand::UniformRand, BitIteratorBE};
 
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
@@ -131,6 +131,25 @@ fn random_multiplication_test<G: ProjectiveCurve>() {
     }
 }
 
+fn small_scalar_multiplication_test<G: ProjectiveCurve>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let one = G::ScalarField::one();
+    let mut scalars = vec![G::ScalarField::zero(), one, one + one, one + one + one];
+    scalars.extend((0..ITERATIONS).map(|_| G::ScalarField::rand(&mut rng)));
+
+    for scalar in scalars {
+        let a = G::rand(&mut rng);
+
+        // Iterating over every bit of the scalar, including the leading zeros.
+        let expected = a.into_affine().mul_bits(BitIteratorBE::new(scalar.into_repr()));
+
+        assert_eq!(a.mul(scalar), expected);
+        assert_eq!(a.into_affine().mul(scalar).into_projective(), expected);
+        assert_eq!(G::zero().mul(scalar), G::zero());
+    }
+}
+
 fn random_doubling_test<G: ProjectiveCurve>() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
@@ -279,7 +298,39 @@ pub fn curve_tests<G: ProjectiveCurve>() {
 
     random_addition_test::<G>();
     random_multiplication_test::<G>();
+    small_scalar_multiplication_test::<G>();
     random_doubling_test::<G>();
     random_negation_test::<G>();
     random_transformation_test::<G>();
+
+    for size in [0, 1, 17].iter() {
+        msm_test::<G>(*size);
+    }
+}
+
+/// Checks `ProjectiveCurve::msm` against the naive sum of products, for `size` random bases
+/// and random, all-zero, and all-one scalars.
+pub fn msm_test<G: ProjectiveCurve>(size: usize) {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let bases = (0..size).map(|_| G::rand(&mut rng).into_affine()).collect::<Vec<_>>();
+    let random = (0..size)
+        .map(|_| G::ScalarField::rand(&mut rng).into_repr())
+        .collect::<Vec<_>>();
+    let zeros = vec![G::ScalarField::zero().into_repr(); size];
+    let ones = vec![G::ScalarField::one().into_repr(); size];
+
+    for scalars in [random, zeros, ones].iter() {
+        let naive = bases.iter().zip(scalars).fold(G::zero(), |sum, (base, scalar)| {
+            sum + base.mul_bits(BitIteratorBE::new(*scalar))
+        });
+        assert_eq!(G::msm(&bases, scalars), naive);
+
+        // The longer slice is truncated.
+        if size > 0 {
+            let truncated = naive - bases[size - 1].mul_bits(BitIteratorBE::new(scalars[size - 1]));
+            assert_eq!(G::msm(&bases[..size - 1], scalars), truncated);
+            assert_eq!(G::msm(&bases, &scalars[..size - 1]), truncated);
+        }
+    }
 }
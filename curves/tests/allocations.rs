@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::{
+    bls12_377::{g1::Bls12_377G1Parameters, Fr, G1Projective},
+    templates::short_weierstrass::wnaf::double_and_add,
+    traits::{AffineCurve, ProjectiveCurve, SWModelParameters},
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{bititerator::BitIteratorBE, rand::UniformRand};
+
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Counts the heap allocations made by the test binary.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the result of `f`, along with the number of heap allocations it made.
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    let result = f();
+    let after = ALLOCATIONS.load(Ordering::SeqCst);
+
+    (result, after - before)
+}
+
+// The allocation counter is global, so every check runs in a single test to avoid
+// counting the allocations of concurrently running tests.
+#[test]
+fn test_bls12_377_g1_scalar_multiplication_does_not_allocate() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..10 {
+        let point = G1Projective::rand(&mut rng);
+        let affine = point.into_affine();
+        let scalar = Fr::rand(&mut rng);
+
+        let expected = affine.mul_bits(BitIteratorBE::new(Bls12_377G1Parameters::COFACTOR));
+        let (result, allocations) = count_allocations(|| affine.scale_by_cofactor());
+        assert_eq!(result, expected);
+        assert_eq!(allocations, 0, "scale_by_cofactor allocated");
+
+        let expected = double_and_add(&point, scalar.into_repr());
+        let (result, allocations) = count_allocations(|| point * scalar);
+        assert_eq!(result, expected);
+        assert_eq!(allocations, 0, "scalar multiplication allocated");
+    }
+}
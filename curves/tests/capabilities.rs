@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_utilities::Capabilities;
+
+#[test]
+fn test_curves_capabilities() {
+    let mut capabilities = Capabilities::default();
+    snarkvm_curves::register_capabilities(&mut capabilities);
+
+    let names = capabilities
+        .crates()
+        .iter()
+        .map(|registered| registered.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["snarkvm-curves", "snarkvm-fields", "snarkvm-utilities"]);
+
+    // The reported features follow the features this test is compiled with.
+    assert_eq!(snarkvm_curves::PARALLEL_ENABLED, cfg!(feature = "parallel"));
+    assert_eq!(
+        capabilities.is_enabled("snarkvm-curves", "parallel"),
+        Some(cfg!(feature = "parallel"))
+    );
+    assert_eq!(
+        capabilities.is_enabled("snarkvm-curves", "sw6"),
+        Some(cfg!(feature = "sw6"))
+    );
+
+    let json = capabilities.to_json();
+    assert!(json.contains(&format!(
+        "\"snarkvm-curves\":{{\"parallel\":{}",
+        cfg!(feature = "parallel")
+    )));
+    if cfg!(feature = "parallel") {
+        assert!(capabilities.to_string().starts_with("snarkvm-curves: parallel;"));
+    } else {
+        assert!(capabilities.to_string().starts_with("snarkvm-curves: -;"));
+    }
+}
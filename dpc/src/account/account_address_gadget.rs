@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{traits::DPCComponents, AccountAddress};
+use snarkvm_gadgets::{
+    bits::{Boolean, ToBytesGadget},
+    integers::uint::UInt8,
+    traits::{
+        algorithms::EncryptionGadget,
+        alloc::AllocGadget,
+        eq::{ConditionalEqGadget, EqGadget},
+    },
+};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use std::borrow::Borrow;
+
+type AccountEncryptionPublicKeyGadget<C> = <<C as DPCComponents>::AccountEncryptionGadget as EncryptionGadget<
+    <C as DPCComponents>::AccountEncryption,
+    <C as DPCComponents>::InnerField,
+>>::PublicKeyGadget;
+
+/// The gadget representation of an account address.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "C: DPCComponents"),
+    Debug(bound = "C: DPCComponents"),
+    PartialEq(bound = "C: DPCComponents"),
+    Eq(bound = "C: DPCComponents")
+)]
+pub struct AccountAddressGadget<C: DPCComponents> {
+    pub encryption_key: AccountEncryptionPublicKeyGadget<C>,
+}
+
+impl<C: DPCComponents> AccountAddressGadget<C> {
+    /// Wraps an allocated account encryption public key as an account address.
+    pub fn from_encryption_key(encryption_key: AccountEncryptionPublicKeyGadget<C>) -> Self {
+        Self { encryption_key }
+    }
+}
+
+impl<C: DPCComponents> AllocGadget<AccountAddress<C>, C::InnerField> for AccountAddressGadget<C> {
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<AccountAddress<C>>,
+        CS: ConstraintSystem<C::InnerField>,
+    >(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let encryption_key = value_gen()?.borrow().encryption_key.clone();
+
+        Ok(Self {
+            encryption_key: AccountEncryptionPublicKeyGadget::<C>::alloc_constant(cs, || Ok(encryption_key))?,
+        })
+    }
+
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<AccountAddress<C>>,
+        CS: ConstraintSystem<C::InnerField>,
+    >(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            encryption_key: AccountEncryptionPublicKeyGadget::<C>::alloc(cs, || {
+                value_gen().map(|address| address.borrow().encryption_key.clone())
+            })?,
+        })
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<AccountAddress<C>>,
+        CS: ConstraintSystem<C::InnerField>,
+    >(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            encryption_key: AccountEncryptionPublicKeyGadget::<C>::alloc_input(cs, || {
+                value_gen().map(|address| address.borrow().encryption_key.clone())
+            })?,
+        })
+    }
+}
+
+impl<C: DPCComponents> ToBytesGadget<C::InnerField> for AccountAddressGadget<C> {
+    /// Writes the account address bytes, matching the native `AccountAddress` encoding.
+    fn to_bytes<CS: ConstraintSystem<C::InnerField>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.encryption_key.to_bytes(&mut cs.ns(|| "to_bytes"))
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<C::InnerField>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.encryption_key.to_bytes_strict(&mut cs.ns(|| "to_bytes_strict"))
+    }
+}
+
+impl<C: DPCComponents> ConditionalEqGadget<C::InnerField> for AccountAddressGadget<C> {
+    fn conditional_enforce_equal<CS: ConstraintSystem<C::InnerField>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.encryption_key.conditional_enforce_equal(
+            &mut cs.ns(|| "conditional_enforce_equal"),
+            &other.encryption_key,
+            condition,
+        )
+    }
+
+    fn cost() -> usize {
+        <AccountEncryptionPublicKeyGadget<C> as ConditionalEqGadget<C::InnerField>>::cost()
+    }
+}
+
+impl<C: DPCComponents> EqGadget<C::InnerField> for AccountAddressGadget<C> {}
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{traits::DPCComponents, AccountAddressGadget, AccountViewKey};
+use snarkvm_gadgets::{
+    bits::ToBytesGadget,
+    integers::uint::UInt8,
+    traits::{algorithms::EncryptionGadget, alloc::AllocGadget},
+};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use std::borrow::Borrow;
+
+type AccountEncryptionGadget<C> = <C as DPCComponents>::AccountEncryptionGadget;
+type AccountDecryptionKeyGadget<C> = <AccountEncryptionGadget<C> as EncryptionGadget<
+    <C as DPCComponents>::AccountEncryption,
+    <C as DPCComponents>::InnerField,
+>>::PrivateKeyGadget;
+type AccountEncryptionParametersGadget<C> = <AccountEncryptionGadget<C> as EncryptionGadget<
+    <C as DPCComponents>::AccountEncryption,
+    <C as DPCComponents>::InnerField,
+>>::ParametersGadget;
+
+/// The gadget representation of an account view key.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "C: DPCComponents"), Debug(bound = "C: DPCComponents"))]
+pub struct AccountViewKeyGadget<C: DPCComponents> {
+    pub decryption_key: AccountDecryptionKeyGadget<C>,
+}
+
+impl<C: DPCComponents> AccountViewKeyGadget<C> {
+    /// Enforces the derivation of the account address from this view key.
+    pub fn to_address<CS: ConstraintSystem<C::InnerField>>(
+        &self,
+        mut cs: CS,
+        encryption_parameters: &AccountEncryptionParametersGadget<C>,
+    ) -> Result<AccountAddressGadget<C>, SynthesisError> {
+        let encryption_key = AccountEncryptionGadget::<C>::check_public_key_gadget(
+            &mut cs.ns(|| "Compute the account encryption key"),
+            encryption_parameters,
+            &self.decryption_key,
+        )?;
+
+        Ok(AccountAddressGadget::from_encryption_key(encryption_key))
+    }
+}
+
+impl<C: DPCComponents> AllocGadget<AccountViewKey<C>, C::InnerField> for AccountViewKeyGadget<C> {
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<AccountViewKey<C>>,
+        CS: ConstraintSystem<C::InnerField>,
+    >(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let decryption_key = value_gen()?.borrow().decryption_key.clone();
+
+        Ok(Self {
+            decryption_key: AccountDecryptionKeyGadget::<C>::alloc_constant(cs, || Ok(decryption_key))?,
+        })
+    }
+
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<AccountViewKey<C>>,
+        CS: ConstraintSystem<C::InnerField>,
+    >(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            decryption_key: AccountDecryptionKeyGadget::<C>::alloc(cs, || {
+                value_gen().map(|view_key| view_key.borrow().decryption_key.clone())
+            })?,
+        })
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<AccountViewKey<C>>,
+        CS: ConstraintSystem<C::InnerField>,
+    >(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            decryption_key: AccountDecryptionKeyGadget::<C>::alloc_input(cs, || {
+                value_gen().map(|view_key| view_key.borrow().decryption_key.clone())
+            })?,
+        })
+    }
+}
+
+impl<C: DPCComponents> ToBytesGadget<C::InnerField> for AccountViewKeyGadget<C> {
+    /// Writes the account view key bytes, matching the native `AccountViewKey` encoding.
+    fn to_bytes<CS: ConstraintSystem<C::InnerField>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.decryption_key.to_bytes(&mut cs.ns(|| "to_bytes"))
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<C::InnerField>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.decryption_key.to_bytes_strict(&mut cs.ns(|| "to_bytes_strict"))
+    }
+}
@@ -22,6 +22,11 @@ pub use account::*;
 pub mod account_address;
 pub use account_address::*;
 
+#[cfg(feature = "testnet1")]
+pub mod account_address_gadget;
+#[cfg(feature = "testnet1")]
+pub use account_address_gadget::*;
+
 pub mod account_format;
 pub use account_format::*;
 
@@ -31,5 +36,10 @@ pub use account_private_key::*;
 pub mod account_view_key;
 pub use account_view_key::*;
 
+#[cfg(feature = "testnet1")]
+pub mod account_view_key_gadget;
+#[cfg(feature = "testnet1")]
+pub use account_view_key_gadget::*;
+
 #[cfg(test)]
 pub mod tests;
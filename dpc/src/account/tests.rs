@@ -15,10 +15,17 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    account::{Account, AccountAddress, AccountPrivateKey, AccountViewKey},
+    account::{Account, AccountAddress, AccountAddressGadget, AccountPrivateKey, AccountViewKey, AccountViewKeyGadget},
     testnet1::{instantiated::Components, parameters::SystemParameters},
-    traits::account::AccountScheme,
+    traits::{account::AccountScheme, DPCComponents},
 };
+use snarkvm_curves::bls12_377::Fr;
+use snarkvm_gadgets::{
+    bits::ToBytesGadget,
+    traits::{algorithms::EncryptionGadget, alloc::AllocGadget, eq::EqGadget},
+};
+use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+use snarkvm_utilities::{to_bytes, ToBytes};
 
 use rand::thread_rng;
 use std::str::FromStr;
@@ -68,3 +75,100 @@ pub fn test_address_from_str() {
     assert!(address.is_ok());
     assert_eq!(address_string, address.unwrap().to_string());
 }
+
+#[test]
+fn test_account_address_gadget_bytes() {
+    let rng = &mut thread_rng();
+    let parameters = SystemParameters::<Components>::load().unwrap();
+
+    let account = Account::<Components>::new(
+        &parameters.account_signature,
+        &parameters.account_commitment,
+        &parameters.account_encryption,
+        rng,
+    )
+    .unwrap();
+    let expected_bytes = to_bytes![account.address].unwrap();
+
+    let mut cs = TestConstraintSystem::<Fr>::new();
+
+    let witness = AccountAddressGadget::<Components>::alloc(cs.ns(|| "alloc"), || Ok(&account.address)).unwrap();
+    let input =
+        AccountAddressGadget::<Components>::alloc_input(cs.ns(|| "alloc_input"), || Ok(&account.address)).unwrap();
+    let constant =
+        AccountAddressGadget::<Components>::alloc_constant(cs.ns(|| "alloc_constant"), || Ok(&account.address))
+            .unwrap();
+
+    for (i, address) in [&witness, &input, &constant].iter().enumerate() {
+        let bytes = address.to_bytes(cs.ns(|| format!("to_bytes {}", i))).unwrap();
+        let bytes: Vec<u8> = bytes.iter().map(|byte| byte.value.unwrap()).collect();
+        assert_eq!(expected_bytes, bytes);
+    }
+
+    witness.enforce_equal(cs.ns(|| "witness == input"), &input).unwrap();
+    witness
+        .enforce_equal(cs.ns(|| "witness == constant"), &constant)
+        .unwrap();
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_account_view_key_gadget_to_address() {
+    let rng = &mut thread_rng();
+    let parameters = SystemParameters::<Components>::load().unwrap();
+
+    let account = Account::<Components>::new(
+        &parameters.account_signature,
+        &parameters.account_commitment,
+        &parameters.account_encryption,
+        rng,
+    )
+    .unwrap();
+    let view_key = AccountViewKey::<Components>::from_private_key(
+        &parameters.account_signature,
+        &parameters.account_commitment,
+        &account.private_key,
+    )
+    .unwrap();
+
+    let mut cs = TestConstraintSystem::<Fr>::new();
+
+    let encryption_parameters = <<Components as DPCComponents>::AccountEncryptionGadget as EncryptionGadget<
+        <Components as DPCComponents>::AccountEncryption,
+        Fr,
+    >>::ParametersGadget::alloc(cs.ns(|| "encryption parameters"), || {
+        Ok(&parameters.account_encryption.parameters)
+    })
+    .unwrap();
+    let view_key_gadget = AccountViewKeyGadget::<Components>::alloc(cs.ns(|| "view key"), || Ok(&view_key)).unwrap();
+
+    let view_key_bytes = view_key_gadget.to_bytes(cs.ns(|| "view key to_bytes")).unwrap();
+    let view_key_bytes: Vec<u8> = view_key_bytes.iter().map(|byte| byte.value.unwrap()).collect();
+    assert_eq!(to_bytes![view_key].unwrap(), view_key_bytes);
+
+    let derived_address = view_key_gadget
+        .to_address(cs.ns(|| "to_address"), &encryption_parameters)
+        .unwrap();
+    let expected_address =
+        AccountAddressGadget::<Components>::alloc(cs.ns(|| "address"), || Ok(&account.address)).unwrap();
+
+    derived_address
+        .enforce_equal(cs.ns(|| "derived == expected"), &expected_address)
+        .unwrap();
+    assert!(cs.is_satisfied());
+
+    let other_account = Account::<Components>::new(
+        &parameters.account_signature,
+        &parameters.account_commitment,
+        &parameters.account_encryption,
+        rng,
+    )
+    .unwrap();
+    let other_address =
+        AccountAddressGadget::<Components>::alloc(cs.ns(|| "other address"), || Ok(&other_account.address)).unwrap();
+
+    derived_address
+        .enforce_equal(cs.ns(|| "derived == other"), &other_address)
+        .unwrap();
+    assert!(!cs.is_satisfied());
+}
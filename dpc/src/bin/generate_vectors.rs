@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_dpc::testnet1::vectors::{default_directory, write, TestVectorKind};
+
+use std::path::PathBuf;
+
+/// Regenerates the test vectors in `dpc/tests/vectors/`, or in the directory given as the first argument.
+fn main() -> anyhow::Result<()> {
+    let directory = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(default_directory);
+
+    for kind in TestVectorKind::ALL.iter() {
+        let file = kind.generate()?;
+        write(&directory, *kind, &file)?;
+        println!("Wrote {} {} test vectors to {:?}", file.vectors.len(), kind, directory);
+    }
+
+    Ok(())
+}
@@ -15,18 +15,35 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    bytes_serde,
+    merkle_root_with_subroots,
+    pedersen_merkle_root,
     traits::{BlockScheme, TransactionScheme},
     BlockError,
     BlockHeader,
+    BlockHeaderHash,
+    MerkleRootHash,
+    PoswVerifier,
+    TransactionError,
     Transactions,
+    MASKED_TREE_DEPTH,
 };
 use snarkvm_utilities::{
     bytes::{FromBytes, ToBytes},
+    error,
     to_bytes,
-    variable_length_integer::variable_length_integer,
+    variable_length_integer::{read_variable_length_integer, variable_length_integer},
 };
 
-use std::io::{Read, Result as IoResult, Write};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    collections::HashSet,
+    io::{Read, Result as IoResult, Write},
+    marker::PhantomData,
+};
+
+/// The maximum size of a serialized block in bytes, including the header.
+pub const MAX_BLOCK_SIZE: u64 = 1_000_000_000;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Block<T: TransactionScheme> {
@@ -69,15 +86,24 @@ impl<T: TransactionScheme> FromBytes for Block<T> {
     }
 }
 
+impl<T: TransactionScheme> Serialize for Block<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        bytes_serde::serialize(self, serializer)
+    }
+}
+
+impl<'de, T: TransactionScheme> Deserialize<'de> for Block<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        bytes_serde::deserialize(deserializer, "block")
+    }
+}
+
 impl<T: TransactionScheme> Block<T> {
     pub fn serialize(&self) -> Result<Vec<u8>, BlockError> {
         let mut serialization = vec![];
         serialization.extend(&self.header.serialize().to_vec());
         serialization.extend(&variable_length_integer(self.transactions.len() as u64));
-
-        for transaction in self.transactions.iter() {
-            serialization.extend(to_bytes![transaction]?)
-        }
+        self.transactions.write_all(&mut serialization)?;
 
         Ok(serialization)
     }
@@ -94,4 +120,559 @@ impl<T: TransactionScheme> Block<T> {
 
         Ok(Block { header, transactions })
     }
+
+    /// Returns the number of bytes in the serialized block, without serializing it.
+    pub fn serialized_size(&self) -> usize {
+        self.header.serialized_size() + self.transactions.serialized_size()
+    }
+
+    /// Writes the block as a frame, which is the size of the serialized block as a variable
+    /// length integer followed by the serialized block.
+    pub fn write_frame<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        variable_length_integer(self.serialized_size() as u64).write(&mut writer)?;
+        self.write(&mut writer)
+    }
+
+    /// Reads the header and the transaction count of a block frame, leaving the reader at the
+    /// first transaction. Returns the header and the number of bytes of transactions remaining
+    /// in the frame.
+    pub fn read_header_only<R: Read>(reader: R) -> IoResult<(BlockHeader, u64)> {
+        let (header, _, remaining) = read_frame_header(reader)?;
+        Ok((header, remaining))
+    }
+
+    /// Reads a block as encoded by `ToBytes`, failing instead of reading more than `max_size`
+    /// bytes, or more than `MAX_BLOCK_SIZE` bytes.
+    pub fn read_with_limit<R: Read>(mut reader: R, max_size: u64) -> Result<Self, BlockError> {
+        let max_size = max_size.min(MAX_BLOCK_SIZE);
+        if max_size <= BlockHeader::size() as u64 {
+            return Err(BlockError::BlockTooLarge(max_size));
+        }
+
+        let header: BlockHeader = FromBytes::read(&mut reader)?;
+
+        // Every transaction takes at least one byte.
+        let max_bytes = max_size - BlockHeader::size() as u64;
+        let transactions = match Transactions::read_with_limit(reader, max_bytes as usize, max_bytes) {
+            Ok(transactions) => transactions,
+            Err(TransactionError::TransactionsTooLarge(_)) => return Err(BlockError::BlockTooLarge(max_size)),
+            Err(error) => return Err(error.into()),
+        };
+
+        Ok(Self { header, transactions })
+    }
+
+    /// Checks that the block is well formed on its own, without reference to the ledger: that
+    /// it fits in `MAX_BLOCK_SIZE` bytes, that no serial number or commitment appears twice, that
+    /// the header commits to the transactions, and that the header carries a valid Proof of
+    /// Succinct Work which meets its difficulty target.
+    pub fn verify_structure<P: PoswVerifier>(&self, posw: &P) -> Result<(), BlockError> {
+        self.verify_transactions()?;
+
+        let (merkle_root, subroots) =
+            merkle_root_with_subroots(&self.transactions.transaction_id_bytes()?, MASKED_TREE_DEPTH);
+        let merkle_root = MerkleRootHash::new(merkle_root);
+        if self.header.merkle_root_hash != merkle_root {
+            return Err(BlockError::InvalidMerkleRoot(
+                self.header.merkle_root_hash.to_string(),
+                merkle_root.to_string(),
+            ));
+        }
+
+        let pedersen_merkle_root = pedersen_merkle_root(&subroots);
+        if self.header.pedersen_merkle_root_hash != pedersen_merkle_root {
+            return Err(BlockError::InvalidPedersenMerkleRoot(
+                self.header.pedersen_merkle_root_hash.to_string(),
+                pedersen_merkle_root.to_string(),
+            ));
+        }
+
+        if !self.header.meets_difficulty_target() {
+            return Err(BlockError::DifficultyTargetNotMet(
+                self.header.to_difficulty_hash(),
+                self.header.difficulty_target,
+            ));
+        }
+
+        // The proof is checked last, as it is by far the most expensive check.
+        if !posw.verify_header(&self.header) {
+            return Err(BlockError::InvalidProofOfSuccinctWork);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the canonical genesis block is well formed. The genesis block is not mined,
+    /// so only its parent and its transactions are checked.
+    pub fn verify_genesis(&self) -> Result<(), BlockError> {
+        if self.header.previous_block_hash != BlockHeaderHash::zero() {
+            return Err(BlockError::InvalidParent(
+                BlockHeaderHash::zero().to_string(),
+                self.header.previous_block_hash.to_string(),
+            ));
+        }
+
+        self.verify_transactions()
+    }
+
+    /// Checks that the block has transactions, that it fits in `MAX_BLOCK_SIZE` bytes, and that
+    /// no serial number or commitment appears twice.
+    fn verify_transactions(&self) -> Result<(), BlockError> {
+        if self.transactions.is_empty() {
+            return Err(TransactionError::EmptyTransactions.into());
+        }
+
+        if self.serialized_size() as u64 > MAX_BLOCK_SIZE {
+            return Err(BlockError::BlockTooLarge(MAX_BLOCK_SIZE));
+        }
+
+        let mut serial_numbers = HashSet::new();
+        let mut commitments = HashSet::new();
+        for transaction in self.transactions.iter() {
+            for serial_number in transaction.old_serial_numbers() {
+                if !serial_numbers.insert(serial_number) {
+                    return Err(BlockError::DuplicateSerialNumber(hex::encode(to_bytes![
+                        serial_number
+                    ]?)));
+                }
+            }
+            for commitment in transaction.new_commitments() {
+                if !commitments.insert(commitment) {
+                    return Err(BlockError::DuplicateCommitment(hex::encode(to_bytes![commitment]?)));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the frame size, header, and transaction count of a block frame, and returns the
+/// header, the transaction count, and the number of bytes of transactions in the frame.
+fn read_frame_header<R: Read>(mut reader: R) -> IoResult<(BlockHeader, usize, u64)> {
+    let size = read_variable_length_integer(&mut reader)? as u64;
+    if size > MAX_BLOCK_SIZE {
+        return Err(error("block frame exceeds the maximum block size"));
+    }
+    if size <= BlockHeader::size() as u64 {
+        return Err(error("block frame is smaller than a block"));
+    }
+
+    let header: BlockHeader = FromBytes::read(&mut reader)?;
+    let num_transactions = read_variable_length_integer(&mut reader)?;
+
+    // Every transaction takes at least one byte.
+    let remaining = (size - BlockHeader::size() as u64)
+        .checked_sub(variable_length_integer(num_transactions as u64).len() as u64)
+        .filter(|remaining| num_transactions as u64 <= *remaining)
+        .ok_or_else(|| error("transaction count exceeds the block frame"))?;
+
+    Ok((header, num_transactions, remaining))
+}
+
+/// A block read from a frame, with a decoded header and the transactions kept as raw bytes
+/// until they are decoded on demand.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BlockBytes<T: TransactionScheme> {
+    /// The block header.
+    pub header: BlockHeader,
+    /// The transaction count, followed by the serialized transactions.
+    transactions: Vec<u8>,
+    num_transactions: usize,
+    _transaction: PhantomData<T>,
+}
+
+impl<T: TransactionScheme> BlockBytes<T> {
+    /// Reads a block frame, without decoding its transactions.
+    pub fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let (header, num_transactions, remaining) = read_frame_header(&mut reader)?;
+
+        // The frame size is untrusted, so the body is read without allocating for it up front.
+        let mut transactions = variable_length_integer(num_transactions as u64);
+        let prefix_len = transactions.len() as u64;
+        reader.take(remaining).read_to_end(&mut transactions)?;
+        if transactions.len() as u64 != prefix_len + remaining {
+            return Err(error("block frame is truncated"));
+        }
+
+        Ok(Self {
+            header,
+            transactions,
+            num_transactions,
+            _transaction: PhantomData,
+        })
+    }
+
+    /// Returns the number of transactions in the block.
+    pub fn num_transactions(&self) -> usize {
+        self.num_transactions
+    }
+
+    /// Returns the transactions as encoded by `ToBytes`, including the transaction count.
+    pub fn transactions_bytes(&self) -> &[u8] {
+        &self.transactions
+    }
+
+    /// Decodes the transactions, failing if they do not take up exactly the rest of the frame.
+    pub fn decode_transactions(&self) -> Result<Transactions<T>, BlockError> {
+        let mut reader = &self.transactions[..];
+        let transactions =
+            Transactions::read_with_limit(&mut reader, self.num_transactions, self.transactions.len() as u64)?;
+        if !reader.is_empty() {
+            return Err(BlockError::TrailingBytes(reader.len()));
+        }
+
+        Ok(transactions)
+    }
+
+    /// Decodes the transactions, and returns the block.
+    pub fn into_block(self) -> Result<Block<T>, BlockError> {
+        let transactions = self.decode_transactions()?;
+
+        Ok(Block {
+            header: self.header,
+            transactions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        block::transactions::tests::{random_transactions, TestTransaction},
+        BlockHeaderHash,
+        MerkleRootHash,
+        PedersenMerkleRootHash,
+        ProofOfSuccinctWork,
+    };
+    use snarkvm_utilities::{to_bytes, variable_length_integer::variable_length_integer_size};
+
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    fn random_block<R: Rng>(num_transactions: usize, rng: &mut R) -> Block<TestTransaction> {
+        Block {
+            header: BlockHeader {
+                previous_block_hash: BlockHeaderHash::from(rng.gen::<[u8; 32]>()),
+                merkle_root_hash: MerkleRootHash::from(rng.gen::<[u8; 32]>()),
+                pedersen_merkle_root_hash: PedersenMerkleRootHash::from(rng.gen::<[u8; 32]>()),
+                proof: ProofOfSuccinctWork([7u8; ProofOfSuccinctWork::size()]),
+                time: rng.gen(),
+                difficulty_target: rng.gen(),
+                nonce: rng.gen(),
+            },
+            transactions: random_transactions(num_transactions, rng),
+        }
+    }
+
+    fn frame(block: &Block<TestTransaction>) -> Vec<u8> {
+        let mut frame = vec![];
+        block.write_frame(&mut frame).unwrap();
+        frame
+    }
+
+    #[test]
+    fn test_read_header_only() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let block = random_block(20, rng);
+        let serialized = to_bytes![block].unwrap();
+        let frame = frame(&block);
+        assert_eq!(
+            frame,
+            [variable_length_integer(serialized.len() as u64), serialized].concat()
+        );
+
+        let mut reader = &frame[..];
+        let (header, remaining) = Block::<TestTransaction>::read_header_only(&mut reader).unwrap();
+        assert_eq!(header, block.header);
+        assert_eq!(
+            remaining as usize,
+            block.transactions.serialized_size() - variable_length_integer_size(block.transactions.len() as u64)
+        );
+
+        // The reader is left at the first transaction.
+        assert_eq!(reader.len() as u64, remaining);
+        assert_eq!(TestTransaction::read(reader).unwrap(), block.transactions[0]);
+    }
+
+    #[test]
+    fn test_serialized_size() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        for num_transactions in 0..30 {
+            let block = random_block(num_transactions, rng);
+            assert_eq!(block.header.serialized_size(), to_bytes![block.header].unwrap().len());
+            assert_eq!(block.serialized_size(), to_bytes![block].unwrap().len());
+            assert_eq!(block.serialize().unwrap(), to_bytes![block].unwrap());
+        }
+    }
+
+    #[test]
+    fn test_read_header_only_does_not_read_body() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let header = random_block(0, rng).header;
+
+        // A frame that claims a body of hundreds of megabytes, of which only the header and the
+        // transaction count are present, parses without reading or allocating for the body.
+        let size = MAX_BLOCK_SIZE / 2;
+        let num_transactions = 100_000;
+        let count = variable_length_integer(num_transactions);
+        let frame = [variable_length_integer(size), to_bytes![header].unwrap(), count.clone()].concat();
+
+        let mut reader = &frame[..];
+        let (decoded, remaining) = Block::<TestTransaction>::read_header_only(&mut reader).unwrap();
+        assert!(reader.is_empty());
+        assert_eq!(decoded, header);
+        assert_eq!(remaining, size - BlockHeader::size() as u64 - count.len() as u64);
+
+        // Reading the whole frame fails once the body runs out.
+        assert!(BlockBytes::<TestTransaction>::read(&frame[..]).is_err());
+    }
+
+    #[test]
+    fn test_resumed_decode_matches_direct_decode() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let blocks = (0..3).map(|i| random_block(i * 10, rng)).collect::<Vec<_>>();
+        let stream = blocks.iter().flat_map(frame).collect::<Vec<_>>();
+        let mut reader = &stream[..];
+
+        // Skip the body of the first block after checking its header.
+        let (header, remaining) = Block::<TestTransaction>::read_header_only(&mut reader).unwrap();
+        assert_eq!(header, blocks[0].header);
+        std::io::copy(&mut (&mut reader).take(remaining), &mut std::io::sink()).unwrap();
+
+        for block in &blocks[1..] {
+            let block_bytes = BlockBytes::<TestTransaction>::read(&mut reader).unwrap();
+            assert_eq!(block_bytes.header, block.header);
+            assert_eq!(block_bytes.num_transactions(), block.transactions.len());
+            assert_eq!(
+                block_bytes.transactions_bytes(),
+                &to_bytes![block.transactions].unwrap()[..]
+            );
+            assert_eq!(block_bytes.decode_transactions().unwrap(), block.transactions);
+
+            let serialized = to_bytes![block].unwrap();
+            let direct = Block::<TestTransaction>::read(&serialized[..]).unwrap();
+            assert_eq!(block_bytes.into_block().unwrap(), direct);
+            assert_eq!(
+                Block::<TestTransaction>::read_with_limit(&serialized[..], MAX_BLOCK_SIZE).unwrap(),
+                direct
+            );
+        }
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_read_with_limit() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let block = random_block(10, rng);
+        let serialized = to_bytes![block].unwrap();
+        let size = serialized.len() as u64;
+
+        assert_eq!(
+            Block::<TestTransaction>::read_with_limit(&serialized[..], size).unwrap(),
+            block
+        );
+        assert!(matches!(
+            Block::<TestTransaction>::read_with_limit(&serialized[..], size - 1),
+            Err(BlockError::BlockTooLarge(limit)) if limit == size - 1
+        ));
+        assert!(matches!(
+            Block::<TestTransaction>::read_with_limit(&serialized[..], BlockHeader::size() as u64),
+            Err(BlockError::BlockTooLarge(_))
+        ));
+
+        // Frames larger than the maximum block size are rejected before the header is read.
+        let frame = [variable_length_integer(MAX_BLOCK_SIZE + 1), serialized].concat();
+        assert!(Block::<TestTransaction>::read_header_only(&frame[..]).is_err());
+        assert!(BlockBytes::<TestTransaction>::read(&frame[..]).is_err());
+    }
+
+    #[test]
+    fn test_malformed_transaction_count() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let block = random_block(10, rng);
+        let header = to_bytes![block.header].unwrap();
+        let body = to_bytes![block.transactions].unwrap();
+        let transactions = &body[1..];
+
+        let frame_with_count = |count: u64| {
+            let count = variable_length_integer(count);
+            let size = (header.len() + count.len() + transactions.len()) as u64;
+            [
+                variable_length_integer(size),
+                header.clone(),
+                count,
+                transactions.to_vec(),
+            ]
+            .concat()
+        };
+
+        // A count that cannot fit in the frame is rejected with the header.
+        let frame = frame_with_count(1 << 40);
+        assert!(Block::<TestTransaction>::read_header_only(&frame[..]).is_err());
+        assert!(BlockBytes::<TestTransaction>::read(&frame[..]).is_err());
+        let serialized = [header.clone(), variable_length_integer(1 << 40), transactions.to_vec()].concat();
+        assert!(matches!(
+            Block::<TestTransaction>::read_with_limit(&serialized[..], MAX_BLOCK_SIZE),
+            Err(BlockError::TransactionError(TransactionError::TooManyTransactions(..)))
+        ));
+
+        // A count that fits in the frame but is off fails when the transactions are decoded.
+        let too_few = BlockBytes::<TestTransaction>::read(&frame_with_count(9)[..]).unwrap();
+        assert!(matches!(
+            too_few.decode_transactions(),
+            Err(BlockError::TrailingBytes(_))
+        ));
+
+        let too_many = BlockBytes::<TestTransaction>::read(&frame_with_count(11)[..]).unwrap();
+        assert!(too_many.into_block().is_err());
+
+        // A frame too small for a header is rejected.
+        let frame = [variable_length_integer(BlockHeader::size() as u64), header].concat();
+        assert!(Block::<TestTransaction>::read_header_only(&frame[..]).is_err());
+    }
+
+    /// A POSW verifier which accepts or rejects every proof.
+    struct TestPoswVerifier(bool);
+
+    impl PoswVerifier for TestPoswVerifier {
+        fn verify_proof(&self, _: u32, _: &ProofOfSuccinctWork, _: &PedersenMerkleRootHash) -> bool {
+            self.0
+        }
+    }
+
+    /// Sets the merkle roots in the block header to the ones of its transactions.
+    fn commit_to_transactions(block: &mut Block<TestTransaction>) {
+        let transaction_ids = block.transactions.transaction_id_bytes().unwrap();
+        let (merkle_root, subroots) = merkle_root_with_subroots(&transaction_ids, MASKED_TREE_DEPTH);
+        block.header.merkle_root_hash = MerkleRootHash::new(merkle_root);
+        block.header.pedersen_merkle_root_hash = pedersen_merkle_root(&subroots);
+    }
+
+    fn valid_block<R: Rng>(num_transactions: usize, rng: &mut R) -> Block<TestTransaction> {
+        let mut block = random_block(num_transactions, rng);
+        block.header.difficulty_target = u64::MAX;
+        commit_to_transactions(&mut block);
+        block
+    }
+
+    #[test]
+    fn test_verify_structure() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        for num_transactions in 1..10 {
+            let block = valid_block(num_transactions, rng);
+            assert_eq!(
+                block.header.merkle_root_hash,
+                block.transactions.to_merkle_root().unwrap()
+            );
+            block.verify_structure(&TestPoswVerifier(true)).unwrap();
+        }
+
+        let mut block = valid_block(1, rng);
+        block.transactions.pop();
+        assert!(matches!(
+            block.verify_structure(&TestPoswVerifier(true)),
+            Err(BlockError::TransactionError(TransactionError::EmptyTransactions))
+        ));
+        block.transactions.push(TestTransaction::rand(rng));
+        commit_to_transactions(&mut block);
+        block.verify_structure(&TestPoswVerifier(true)).unwrap();
+    }
+
+    #[test]
+    fn test_verify_structure_rejects_invalid_header() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let block = valid_block(8, rng);
+
+        let mut wrong_merkle_root = block.clone();
+        wrong_merkle_root.header.merkle_root_hash = MerkleRootHash::from(rng.gen::<[u8; 32]>());
+        assert!(matches!(
+            wrong_merkle_root.verify_structure(&TestPoswVerifier(true)),
+            Err(BlockError::InvalidMerkleRoot(..))
+        ));
+
+        // The header no longer commits to the transactions once one of them is removed.
+        let mut missing_transaction = block.clone();
+        missing_transaction.transactions.pop();
+        assert!(matches!(
+            missing_transaction.verify_structure(&TestPoswVerifier(true)),
+            Err(BlockError::InvalidMerkleRoot(..))
+        ));
+
+        let mut wrong_pedersen_merkle_root = block.clone();
+        wrong_pedersen_merkle_root.header.pedersen_merkle_root_hash =
+            PedersenMerkleRootHash::from(rng.gen::<[u8; 32]>());
+        assert!(matches!(
+            wrong_pedersen_merkle_root.verify_structure(&TestPoswVerifier(true)),
+            Err(BlockError::InvalidPedersenMerkleRoot(..))
+        ));
+
+        let mut unmet_difficulty = block.clone();
+        unmet_difficulty.header.difficulty_target = 0;
+        assert!(matches!(
+            unmet_difficulty.verify_structure(&TestPoswVerifier(true)),
+            Err(BlockError::DifficultyTargetNotMet(difficulty, 0)) if difficulty == block.header.to_difficulty_hash()
+        ));
+
+        assert!(matches!(
+            block.verify_structure(&TestPoswVerifier(false)),
+            Err(BlockError::InvalidProofOfSuccinctWork)
+        ));
+    }
+
+    #[test]
+    fn test_verify_genesis() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let mut block = random_block(8, rng);
+        assert!(matches!(block.verify_genesis(), Err(BlockError::InvalidParent(..))));
+
+        // The genesis block is not mined, so its header is not checked against its transactions.
+        block.header.previous_block_hash = BlockHeaderHash::zero();
+        block.verify_genesis().unwrap();
+        assert!(block.verify_structure(&TestPoswVerifier(true)).is_err());
+
+        let serial_number = block.transactions[0].serial_numbers[0];
+        block.transactions[1].serial_numbers[1] = serial_number;
+        assert!(matches!(
+            block.verify_genesis(),
+            Err(BlockError::DuplicateSerialNumber(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_structure_rejects_duplicates() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let block = valid_block(8, rng);
+
+        let mut duplicate_serial_number = block.clone();
+        let serial_number = duplicate_serial_number.transactions[2].serial_numbers[1];
+        duplicate_serial_number.transactions[5].serial_numbers[0] = serial_number;
+        commit_to_transactions(&mut duplicate_serial_number);
+        assert!(matches!(
+            duplicate_serial_number.verify_structure(&TestPoswVerifier(true)),
+            Err(BlockError::DuplicateSerialNumber(hex)) if hex == hex::encode(serial_number)
+        ));
+
+        // Duplicates within a single transaction are rejected too.
+        let mut duplicate_commitment = block.clone();
+        let commitment = duplicate_commitment.transactions[3].commitments[0];
+        duplicate_commitment.transactions[3].commitments[1] = commitment;
+        commit_to_transactions(&mut duplicate_commitment);
+        assert!(matches!(
+            duplicate_commitment.verify_structure(&TestPoswVerifier(true)),
+            Err(BlockError::DuplicateCommitment(hex)) if hex == hex::encode(commitment)
+        ));
+
+        // A repeated transaction repeats its serial numbers.
+        let mut duplicate_transaction = block;
+        duplicate_transaction
+            .transactions
+            .push(duplicate_transaction.transactions[0].clone());
+        commit_to_transactions(&mut duplicate_transaction);
+        assert!(matches!(
+            duplicate_transaction.verify_structure(&TestPoswVerifier(true)),
+            Err(BlockError::DuplicateSerialNumber(_))
+        ));
+    }
 }
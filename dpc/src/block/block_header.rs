@@ -14,18 +14,34 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{BlockHeaderHash, MerkleRootHash, PedersenMerkleRootHash, ProofOfSuccinctWork};
-use snarkvm_algorithms::crh::{double_sha256, sha256d_to_u64};
+use crate::{
+    bytes_serde,
+    proof_to_difficulty_hash,
+    BlockHeaderHash,
+    DifficultyTarget,
+    MerkleRootHash,
+    PedersenMerkleRootHash,
+    ProofOfSuccinctWork,
+};
+use snarkvm_algorithms::crh::double_sha256;
 use snarkvm_utilities::bytes::{FromBytes, ToBytes};
 
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{Error as DeserializeError, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
 use std::{
+    fmt,
     io::{Read, Result as IoResult, Write},
     mem::size_of,
 };
 
 /// Block header.
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct BlockHeader {
     /// Hash of the previous block - 32 bytes
     pub previous_block_hash: BlockHeaderHash,
@@ -36,7 +52,7 @@ pub struct BlockHeader {
     /// Merkle root of the transactions in the block using a Pedersen hash - 32 bytes
     pub pedersen_merkle_root_hash: PedersenMerkleRootHash,
 
-    /// Proof of Succinct Work
+    /// Proof of Succinct Work - 972 bytes, a version 0 proof without its version byte
     pub proof: ProofOfSuccinctWork,
 
     /// The block timestamp is a Unix epoch time (UTC) when the miner
@@ -65,20 +81,25 @@ impl BlockHeader {
         HEADER_SIZE
     }
 
+    /// Returns the number of bytes in the serialized header, which is always `BlockHeader::size()`.
+    pub const fn serialized_size(&self) -> usize {
+        HEADER_SIZE
+    }
+
     pub fn serialize(&self) -> [u8; HEADER_SIZE] {
         let mut header_bytes = [0u8; HEADER_SIZE];
         let mut start = 0;
         let mut end = BlockHeaderHash::size();
 
-        header_bytes[start..end].copy_from_slice(&self.previous_block_hash.0);
+        header_bytes[start..end].copy_from_slice(self.previous_block_hash.as_ref());
 
         start = end;
         end += MerkleRootHash::size();
-        header_bytes[start..end].copy_from_slice(&self.merkle_root_hash.0);
+        header_bytes[start..end].copy_from_slice(self.merkle_root_hash.as_ref());
 
         start = end;
         end += PedersenMerkleRootHash::size();
-        header_bytes[start..end].copy_from_slice(&self.pedersen_merkle_root_hash.0);
+        header_bytes[start..end].copy_from_slice(self.pedersen_merkle_root_hash.as_ref());
 
         start = end;
         end += ProofOfSuccinctWork::size();
@@ -137,9 +158,9 @@ impl BlockHeader {
         nonce.copy_from_slice(&bytes[start..end]);
 
         Self {
-            previous_block_hash: BlockHeaderHash(previous_block_hash),
-            merkle_root_hash: MerkleRootHash(merkle_root_hash),
-            pedersen_merkle_root_hash: PedersenMerkleRootHash(pedersen_merkle_root_hash),
+            previous_block_hash: BlockHeaderHash::new(previous_block_hash),
+            merkle_root_hash: MerkleRootHash::new(merkle_root_hash),
+            pedersen_merkle_root_hash: PedersenMerkleRootHash::new(pedersen_merkle_root_hash),
             proof: ProofOfSuccinctWork(proof),
             time: i64::from_le_bytes(time),
             difficulty_target: u64::from_le_bytes(difficulty_target),
@@ -152,21 +173,26 @@ impl BlockHeader {
         let mut hash = [0u8; 32];
         hash.copy_from_slice(&double_sha256(&serialized));
 
-        BlockHeaderHash(hash)
+        BlockHeaderHash::new(hash)
     }
 
     pub fn to_difficulty_hash(&self) -> u64 {
-        sha256d_to_u64(&self.proof.0[..])
+        DifficultyTarget::difficulty_of(&proof_to_difficulty_hash(&self.proof.0[..]))
+    }
+
+    /// Returns `true` if the PoSW proof in this header meets the header's difficulty target.
+    pub fn meets_difficulty_target(&self) -> bool {
+        DifficultyTarget(self.difficulty_target).meets(&proof_to_difficulty_hash(&self.proof.0[..]))
     }
 }
 
 impl ToBytes for BlockHeader {
     #[inline]
     fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        self.previous_block_hash.0.write(&mut writer)?;
-        self.merkle_root_hash.0.write(&mut writer)?;
-        self.pedersen_merkle_root_hash.0.write(&mut writer)?;
-        self.proof.write(&mut writer)?;
+        self.previous_block_hash.as_bytes().write(&mut writer)?;
+        self.merkle_root_hash.as_bytes().write(&mut writer)?;
+        self.pedersen_merkle_root_hash.as_bytes().write(&mut writer)?;
+        self.proof.write_unversioned(&mut writer)?;
         self.time.to_le_bytes().write(&mut writer)?;
         self.difficulty_target.to_le_bytes().write(&mut writer)?;
         self.nonce.to_le_bytes().write(&mut writer)
@@ -179,23 +205,67 @@ impl FromBytes for BlockHeader {
         let previous_block_hash = <[u8; 32]>::read(&mut reader)?;
         let merkle_root_hash = <[u8; 32]>::read(&mut reader)?;
         let pedersen_merkle_root_hash = <[u8; 32]>::read(&mut reader)?;
-        let proof = ProofOfSuccinctWork::read(&mut reader)?;
+        let proof = ProofOfSuccinctWork::read_unversioned(&mut reader)?;
         let time = <[u8; 8]>::read(&mut reader)?;
         let difficulty_target = <[u8; 8]>::read(&mut reader)?;
         let nonce = <[u8; 4]>::read(&mut reader)?;
 
         Ok(Self {
-            previous_block_hash: BlockHeaderHash(previous_block_hash),
-            merkle_root_hash: MerkleRootHash(merkle_root_hash),
+            previous_block_hash: BlockHeaderHash::new(previous_block_hash),
+            merkle_root_hash: MerkleRootHash::new(merkle_root_hash),
             time: i64::from_le_bytes(time),
             difficulty_target: u64::from_le_bytes(difficulty_target),
             nonce: u32::from_le_bytes(nonce),
-            pedersen_merkle_root_hash: PedersenMerkleRootHash(pedersen_merkle_root_hash),
+            pedersen_merkle_root_hash: PedersenMerkleRootHash::new(pedersen_merkle_root_hash),
             proof,
         })
     }
 }
 
+/// Human-readable formats use the hex string of the header bytes, and binary formats use the
+/// `HEADER_SIZE` header bytes without a length prefix.
+impl Serialize for BlockHeader {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => bytes_serde::serialize(self, serializer),
+            false => {
+                let mut tuple = serializer.serialize_tuple(HEADER_SIZE)?;
+                for byte in &BlockHeader::serialize(self)[..] {
+                    tuple.serialize_element(byte)?;
+                }
+                tuple.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BlockHeader {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct HeaderVisitor;
+
+        impl<'de> Visitor<'de> for HeaderVisitor {
+            type Value = BlockHeader;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "the {} bytes of a block header", HEADER_SIZE)
+            }
+
+            fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<BlockHeader, S::Error> {
+                let mut bytes = [0u8; HEADER_SIZE];
+                for (i, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq.next_element()?.ok_or_else(|| S::Error::invalid_length(i, &self))?;
+                }
+                Ok(BlockHeader::deserialize(&bytes))
+            }
+        }
+
+        match deserializer.is_human_readable() {
+            true => bytes_serde::deserialize(deserializer, "block header"),
+            false => deserializer.deserialize_tuple(HEADER_SIZE, HeaderVisitor),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
@@ -205,12 +275,12 @@ mod tests {
     #[test]
     fn serialize() {
         let block_header = BlockHeader {
-            previous_block_hash: BlockHeaderHash([0u8; 32]),
-            merkle_root_hash: MerkleRootHash([0u8; 32]),
+            previous_block_hash: BlockHeaderHash::zero(),
+            merkle_root_hash: MerkleRootHash::zero(),
             time: Utc::now().timestamp(),
             difficulty_target: 0u64,
             nonce: 0u32,
-            pedersen_merkle_root_hash: PedersenMerkleRootHash([0u8; 32]),
+            pedersen_merkle_root_hash: PedersenMerkleRootHash::zero(),
             proof: ProofOfSuccinctWork([0u8; ProofOfSuccinctWork::size()]),
         };
 
@@ -226,4 +296,36 @@ mod tests {
         assert_eq!(block_header, result);
         assert_eq!(block_header, de);
     }
+
+    #[test]
+    fn deserialize_pre_versioning_header() {
+        use snarkvm_parameters::{testnet1::GenesisBlockHeader, Genesis};
+
+        // The genesis header was serialized before proofs carried a version byte.
+        let bytes = GenesisBlockHeader::load_bytes();
+        assert_eq!(bytes.len(), HEADER_SIZE);
+
+        let header = BlockHeader::read(&bytes[..]).unwrap();
+        assert_eq!(header.proof.version(), 0);
+
+        let mut serialized = vec![];
+        header.write(&mut serialized).unwrap();
+        assert_eq!(serialized, bytes);
+
+        // A header with a non-zero proof is laid out as the raw proof bytes between the roots and the time.
+        let proof: Vec<u8> = (0..ProofOfSuccinctWork::size()).map(|i| (i % 256) as u8 | 1).collect();
+        let mut bytes = vec![1u8; 3 * 32];
+        bytes.extend_from_slice(&proof);
+        bytes.extend_from_slice(&7i64.to_le_bytes());
+        bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+        bytes.extend_from_slice(&42u32.to_le_bytes());
+        assert_eq!(bytes.len(), BlockHeader::size());
+
+        let header = BlockHeader::read(&bytes[..]).unwrap();
+        assert_eq!(header.proof, ProofOfSuccinctWork::from(proof));
+        assert_eq!(header.time, 7);
+        assert_eq!(header.difficulty_target, u64::MAX);
+        assert_eq!(header.nonce, 42);
+        assert_eq!(&header.serialize()[..], &bytes[..]);
+    }
 }
@@ -14,31 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use serde::{Deserialize, Serialize};
-use std::fmt::{
-    Display,
-    Formatter,
-    {self},
-};
+/// The hash of a block header.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct BlockHeaderHash([u8; 32]);
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
-pub struct BlockHeaderHash(pub [u8; 32]);
-
-impl BlockHeaderHash {
-    pub fn new(hash: Vec<u8>) -> Self {
-        let mut block_hash = [0u8; 32];
-        block_hash.copy_from_slice(&hash);
-
-        Self(block_hash)
-    }
-
-    pub const fn size() -> usize {
-        32
-    }
-}
-
-impl Display for BlockHeaderHash {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}", hex::encode(self.0))
-    }
-}
+impl_hash_32_bytes!(BlockHeaderHash);
@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The policy that decides which transactions in a block, or in a block and the ledger,
+//! conflict with each other.
+//!
+//! Serial numbers and commitments must always be unique, as a repeated serial number is a
+//! double spend and a repeated commitment is an unspendable record. Memo uniqueness is a
+//! policy choice: requiring every memo to be unique lets the memo serve as a transaction tag
+//! that is never reused, while allowing repeated memos lets many transactions share a tag,
+//! such as a batch of withdrawals that are tagged alike.
+//!
+//! The policy is part of consensus. A block that is valid under one policy may be invalid
+//! under another, so every node on a network must use the same policy, and changing it is a
+//! hard fork that must activate on every node at once.
+
+use snarkvm_utilities::{
+    bytes::{FromBytes, ToBytes},
+    error,
+};
+
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Result as IoResult, Write};
+
+/// Which transaction memos must be unique across a block and the ledger.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum MemoPolicy {
+    /// Every memo must be unique.
+    Global,
+    /// Every memo must be unique, except for the empty memo, which may be repeated.
+    /// A memo is empty if every byte of its serialization is zero.
+    #[default]
+    NonEmptyOnly,
+    /// Memos may be repeated.
+    Disabled,
+}
+
+impl MemoPolicy {
+    /// Returns true if the given serialized memo must be unique under this policy.
+    pub fn requires_unique(&self, memo: &[u8]) -> bool {
+        match self {
+            MemoPolicy::Global => true,
+            MemoPolicy::NonEmptyOnly => memo.iter().any(|byte| *byte != 0),
+            MemoPolicy::Disabled => false,
+        }
+    }
+}
+
+impl ToBytes for MemoPolicy {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        let tag: u8 = match self {
+            MemoPolicy::Global => 0,
+            MemoPolicy::NonEmptyOnly => 1,
+            MemoPolicy::Disabled => 2,
+        };
+        tag.write(&mut writer)
+    }
+}
+
+impl FromBytes for MemoPolicy {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        match u8::read(&mut reader)? {
+            0 => Ok(MemoPolicy::Global),
+            1 => Ok(MemoPolicy::NonEmptyOnly),
+            2 => Ok(MemoPolicy::Disabled),
+            _ => Err(error("invalid memo policy")),
+        }
+    }
+}
+
+/// The policy for checking transactions for conflicts. The default policy requires every
+/// nonempty memo to be unique, so that transactions that leave their memo empty do not conflict.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct ConflictPolicy {
+    pub memo_uniqueness: MemoPolicy,
+}
+
+impl ToBytes for ConflictPolicy {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.memo_uniqueness.write(&mut writer)
+    }
+}
+
+impl FromBytes for ConflictPolicy {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let memo_uniqueness = FromBytes::read(&mut reader)?;
+
+        Ok(Self { memo_uniqueness })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::to_bytes;
+
+    const POLICIES: [MemoPolicy; 3] = [MemoPolicy::Global, MemoPolicy::NonEmptyOnly, MemoPolicy::Disabled];
+
+    #[test]
+    fn test_requires_unique() {
+        let empty = [0u8; 32];
+        let mut nonempty = [0u8; 32];
+        nonempty[31] = 1;
+
+        assert!(MemoPolicy::Global.requires_unique(&empty));
+        assert!(MemoPolicy::Global.requires_unique(&nonempty));
+        assert!(!MemoPolicy::NonEmptyOnly.requires_unique(&empty));
+        assert!(MemoPolicy::NonEmptyOnly.requires_unique(&nonempty));
+        assert!(!MemoPolicy::Disabled.requires_unique(&empty));
+        assert!(!MemoPolicy::Disabled.requires_unique(&nonempty));
+    }
+
+    #[test]
+    fn test_default_policy() {
+        assert_eq!(ConflictPolicy::default().memo_uniqueness, MemoPolicy::NonEmptyOnly);
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        for (tag, memo_uniqueness) in POLICIES.iter().enumerate() {
+            let policy = ConflictPolicy {
+                memo_uniqueness: *memo_uniqueness,
+            };
+            let bytes = to_bytes![policy].unwrap();
+            assert_eq!(bytes, vec![tag as u8]);
+            assert_eq!(ConflictPolicy::read(&bytes[..]).unwrap(), policy);
+        }
+
+        assert!(ConflictPolicy::read(&[3u8][..]).is_err());
+        assert!(ConflictPolicy::read(&[][..]).is_err());
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        for memo_uniqueness in POLICIES.iter() {
+            let policy = ConflictPolicy {
+                memo_uniqueness: *memo_uniqueness,
+            };
+            let json = serde_json::to_string(&policy).unwrap();
+            assert_eq!(serde_json::from_str::<ConflictPolicy>(&json).unwrap(), policy);
+        }
+
+        assert_eq!(
+            serde_json::to_string(&ConflictPolicy::default()).unwrap(),
+            r#"{"memo_uniqueness":"NonEmptyOnly"}"#
+        );
+        assert!(serde_json::from_str::<ConflictPolicy>(r#"{"memo_uniqueness":"Unique"}"#).is_err());
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_algorithms::crh::double_sha256;
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{
+    Display,
+    Formatter,
+    {self},
+};
+
+/// Returns the hash of a serialized PoSW proof that is compared against the difficulty target.
+///
+/// The difficulty hash is `SHA256(SHA256(proof_bytes))`, computed over the serialized proof only.
+/// The block header nonce is *not* included, as it is already committed to inside the proof.
+pub fn proof_to_difficulty_hash(proof_bytes: &[u8]) -> [u8; 32] {
+    double_sha256(proof_bytes)
+}
+
+/// The PoSW difficulty target of a block. A proof meets the target if the first 8 bytes
+/// of its difficulty hash, read as a little-endian `u64`, are less than or equal to the target.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct DifficultyTarget(pub u64);
+
+impl DifficultyTarget {
+    /// Returns the numeric value of the given difficulty hash.
+    pub fn difficulty_of(difficulty_hash: &[u8; 32]) -> u64 {
+        let mut value = [0u8; 8];
+        value.copy_from_slice(&difficulty_hash[..8]);
+        u64::from_le_bytes(value)
+    }
+
+    /// Returns `true` if the given difficulty hash meets this difficulty target.
+    pub fn meets(&self, difficulty_hash: &[u8; 32]) -> bool {
+        Self::difficulty_of(difficulty_hash) <= self.0
+    }
+}
+
+impl From<u64> for DifficultyTarget {
+    fn from(target: u64) -> Self {
+        Self(target)
+    }
+}
+
+impl Display for DifficultyTarget {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proof_to_difficulty_hash_golden_vectors() {
+        assert_eq!(
+            hex::encode(proof_to_difficulty_hash(&[])),
+            "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456"
+        );
+        assert_eq!(
+            hex::encode(proof_to_difficulty_hash(&[0u8; 972])),
+            "88eecfd6ab6e0d6a8bbf0fda58480697e3f00904fdb34d6eeff8d6c7b4c598ec"
+        );
+
+        let proof_bytes: Vec<u8> = (0..972).map(|i| (i % 256) as u8).collect();
+        assert_eq!(
+            hex::encode(proof_to_difficulty_hash(&proof_bytes)),
+            "47981cf99de5f02026386e8e15449464450b8fd15296a831f840c5fd8361e4e7"
+        );
+    }
+
+    #[test]
+    fn test_meets_boundary() {
+        let difficulty_hash = proof_to_difficulty_hash(&[0u8; 972]);
+        let difficulty = DifficultyTarget::difficulty_of(&difficulty_hash);
+        assert_eq!(difficulty, 0x6a0d_6eab_d6cf_ee88);
+
+        assert!(DifficultyTarget(difficulty).meets(&difficulty_hash));
+        assert!(DifficultyTarget(difficulty + 1).meets(&difficulty_hash));
+        assert!(!DifficultyTarget(difficulty - 1).meets(&difficulty_hash));
+        assert!(DifficultyTarget(u64::MAX).meets(&difficulty_hash));
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// Implements the constructors, accessors, conversions, and formatting of a 32-byte hash type,
+/// which must be declared as a tuple struct over a private `[u8; 32]`.
+///
+/// The hash is formatted as a lowercase hex string of its bytes in order, which is the form shown
+/// by block explorers. It is encoded as a hex string in human-readable formats, and as its raw
+/// bytes otherwise, including by `ToBytes`.
+macro_rules! impl_hash_32_bytes {
+    ($hash: ident) => {
+        impl $hash {
+            /// Returns a new hash with the given bytes.
+            pub const fn new(bytes: [u8; 32]) -> Self {
+                Self(bytes)
+            }
+
+            /// Returns the hash whose bytes are all zero.
+            pub const fn zero() -> Self {
+                Self([0u8; 32])
+            }
+
+            /// Returns the size of the hash in bytes.
+            pub const fn size() -> usize {
+                32
+            }
+
+            /// Returns the bytes of the hash.
+            pub const fn as_bytes(&self) -> &[u8; 32] {
+                &self.0
+            }
+        }
+
+        impl From<[u8; 32]> for $hash {
+            fn from(bytes: [u8; 32]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl From<$hash> for [u8; 32] {
+            fn from(hash: $hash) -> Self {
+                hash.0
+            }
+        }
+
+        impl std::convert::TryFrom<&[u8]> for $hash {
+            type Error = crate::errors::BlockError;
+
+            fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+                if bytes.len() != Self::size() {
+                    return Err(crate::errors::BlockError::InvalidHashLength(
+                        bytes.len(),
+                        Self::size(),
+                    ));
+                }
+
+                let mut hash = [0u8; 32];
+                hash.copy_from_slice(bytes);
+                Ok(Self(hash))
+            }
+        }
+
+        impl AsRef<[u8]> for $hash {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl PartialEq<[u8; 32]> for $hash {
+            fn eq(&self, other: &[u8; 32]) -> bool {
+                &self.0 == other
+            }
+        }
+
+        impl PartialEq<$hash> for [u8; 32] {
+            fn eq(&self, other: &$hash) -> bool {
+                self == &other.0
+            }
+        }
+
+        impl std::fmt::Display for $hash {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{}", hex::encode(self.0))
+            }
+        }
+
+        impl std::fmt::Debug for $hash {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{}({})", stringify!($hash), self)
+            }
+        }
+
+        impl std::str::FromStr for $hash {
+            type Err = crate::errors::BlockError;
+
+            fn from_str(hash: &str) -> Result<Self, Self::Err> {
+                use std::convert::TryFrom;
+
+                Self::try_from(&hex::decode(hash)?[..])
+            }
+        }
+
+        impl snarkvm_utilities::bytes::ToBytes for $hash {
+            #[inline]
+            fn write<W: std::io::Write>(&self, writer: W) -> std::io::Result<()> {
+                snarkvm_utilities::bytes::ToBytes::write(&self.0, writer)
+            }
+        }
+
+        impl snarkvm_utilities::bytes::FromBytes for $hash {
+            #[inline]
+            fn read<R: std::io::Read>(reader: R) -> std::io::Result<Self> {
+                Ok(Self(snarkvm_utilities::bytes::FromBytes::read(reader)?))
+            }
+        }
+
+        impl serde::Serialize for $hash {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match serializer.is_human_readable() {
+                    true => serializer.collect_str(self),
+                    false => serde::Serialize::serialize(&self.0, serializer),
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $hash {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                match deserializer.is_human_readable() {
+                    true => {
+                        let hash = <String as serde::Deserialize>::deserialize(deserializer)?;
+                        hash.parse().map_err(serde::de::Error::custom)
+                    }
+                    false => Ok(Self(<[u8; 32] as serde::Deserialize>::deserialize(deserializer)?)),
+                }
+            }
+        }
+    };
+}
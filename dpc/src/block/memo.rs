@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use blake2::{digest::Digest, Blake2s};
+
+/// The memorandum of a transaction, which is public data of 32 bytes chosen by the sender.
+///
+/// A memo whose bytes are all zero is empty, and marks a transaction that does not use its memo.
+/// Under the default conflict policy, empty memos may be repeated, while every other memo must be
+/// unique (see `MemoPolicy`).
+#[derive(Copy, Clone, Default, Eq, Hash, PartialEq)]
+pub struct Memo([u8; 32]);
+
+impl_hash_32_bytes!(Memo);
+
+impl Memo {
+    /// Returns the empty memo, whose bytes are all zero.
+    pub const fn empty() -> Self {
+        Self::zero()
+    }
+
+    /// Returns true if the memo is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|byte| *byte == 0)
+    }
+
+    /// Returns a memo holding the given bytes, such as a UTF-8 note. Bytes past the first 32
+    /// are dropped, and a shorter input is padded with zeros.
+    pub fn from_bytes_truncated(bytes: &[u8]) -> Self {
+        let mut memo = [0u8; 32];
+        let length = bytes.len().min(memo.len());
+        memo[..length].copy_from_slice(&bytes[..length]);
+        Self(memo)
+    }
+
+    /// Returns a memo holding the Blake2s hash of the given data, such as a payment reference.
+    pub fn hash_of(data: &[u8]) -> Self {
+        let mut memo = [0u8; 32];
+        memo.copy_from_slice(&Blake2s::digest(data));
+        Self(memo)
+    }
+}
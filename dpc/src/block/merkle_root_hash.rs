@@ -14,24 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use serde::{Deserialize, Serialize};
-use std::fmt::{
-    Display,
-    Formatter,
-    {self},
-};
+/// The root of the Merkle tree of the transaction ids in a block.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct MerkleRootHash([u8; 32]);
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
-pub struct MerkleRootHash(pub [u8; 32]);
-
-impl MerkleRootHash {
-    pub const fn size() -> usize {
-        32
-    }
-}
-
-impl Display for MerkleRootHash {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}", hex::encode(self.0))
-    }
-}
+impl_hash_32_bytes!(MerkleRootHash);
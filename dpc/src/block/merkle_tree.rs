@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::block::MerkleRootHash;
 use snarkvm_algorithms::crh::double_sha256;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -79,6 +80,56 @@ pub fn merkle_root(hashes: &[[u8; 32]]) -> [u8; 32] {
     merkle_root(&result)
 }
 
+/// A proof that a leaf is included in a Merkle tree computed with `merkle_root`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerkleInclusionProof {
+    /// The position of the leaf in the tree.
+    pub index: usize,
+    /// The leaf hash.
+    pub leaf: [u8; 32],
+    /// The sibling of each node on the path from the leaf to the root, starting at the leaf.
+    /// The last node of an odd-sized level is its own sibling.
+    pub siblings: Vec<[u8; 32]>,
+}
+
+/// Returns a proof that the leaf at the given index is included in the Merkle tree of `hashes`,
+/// or `None` if the index is out of bounds.
+pub fn merkle_inclusion_proof(hashes: &[[u8; 32]], index: usize) -> Option<MerkleInclusionProof> {
+    let leaf = *hashes.get(index)?;
+
+    let mut siblings = vec![];
+    let mut level = hashes.to_vec();
+    let mut position = index;
+    while level.len() > 1 {
+        // Duplicates the last element if there are an odd number of nodes
+        siblings.push(*level.get(position ^ 1).unwrap_or(&level[position]));
+        level = merkle_round(&level);
+        position /= 2;
+    }
+
+    Some(MerkleInclusionProof { index, leaf, siblings })
+}
+
+/// Returns `true` if the given proof shows its leaf is included in the Merkle tree with the given root.
+pub fn verify_inclusion_proof(root: &MerkleRootHash, proof: &MerkleInclusionProof) -> bool {
+    // The index must address a leaf of a tree with as many levels as the proof has siblings.
+    if proof.index.checked_shr(proof.siblings.len() as u32).unwrap_or(0) != 0 {
+        return false;
+    }
+
+    let mut node = proof.leaf;
+    let mut position = proof.index;
+    for sibling in &proof.siblings {
+        node = match position % 2 {
+            0 => merkle_hash(&node[..], &sibling[..]),
+            _ => merkle_hash(&sibling[..], &node[..]),
+        };
+        position /= 2;
+    }
+
+    *root == node
+}
+
 /// Calculate the Merkle tree hash by concatenating the left and right children nodes.
 pub fn merkle_hash(left: &[u8], right: &[u8]) -> [u8; 32] {
     let mut result = [0u8; 64];
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[macro_use]
+mod macros;
+
 pub mod block;
 pub use block::*;
 
@@ -23,6 +26,15 @@ pub use block_header::*;
 pub mod block_header_hash;
 pub use block_header_hash::*;
 
+pub mod conflict_policy;
+pub use conflict_policy::*;
+
+pub mod difficulty_target;
+pub use difficulty_target::*;
+
+pub mod memo;
+pub use memo::*;
+
 pub mod merkle_root_hash;
 pub use merkle_root_hash::*;
 
@@ -36,7 +48,19 @@ pub mod pedersen_merkle_tree;
 pub use pedersen_merkle_tree::*;
 
 pub mod posw;
-pub use posw::ProofOfSuccinctWork;
+pub use posw::{PoswVerifier, ProofOfSuccinctWork};
+
+pub mod serial_number_accumulator;
+pub use serial_number_accumulator::*;
+
+pub mod transaction_conflict_set;
+pub use transaction_conflict_set::*;
+
+pub mod transaction_id;
+pub use transaction_id::*;
 
 pub mod transactions;
 pub use transactions::*;
+
+#[cfg(test)]
+mod tests;
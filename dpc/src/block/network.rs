@@ -47,6 +47,17 @@ impl Network {
             id => Network::Custom(id),
         }
     }
+
+    /// Returns the hash of the genesis block header of the network, if it is known
+    ///
+    /// The transactions of the testnet1 genesis block carry network id 0, so it is the genesis block of `Mainnet`.
+    pub fn genesis_hash(&self) -> Option<&'static str> {
+        match self {
+            #[cfg(feature = "testnet1")]
+            Network::Mainnet => Some(crate::testnet1::genesis::GENESIS_BLOCK_HEADER_HASH),
+            _ => None,
+        }
+    }
 }
 
 impl ToBytes for Network {
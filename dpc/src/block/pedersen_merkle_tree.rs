@@ -19,15 +19,7 @@ use snarkvm_curves::{bls12_377::Fr, edwards_bls12::EdwardsProjective as EdwardsB
 use snarkvm_utilities::{bytes::ToBytes, to_bytes};
 
 use once_cell::sync::Lazy;
-use serde::{Deserialize, Serialize};
-use std::{
-    fmt::{
-        Display,
-        Formatter,
-        {self},
-    },
-    sync::Arc,
-};
+use std::sync::Arc;
 
 // Do not leak the type
 mod window {
@@ -57,20 +49,10 @@ pub static PARAMS: Lazy<Arc<MaskedMerkleTreeParameters>> =
     Lazy::new(|| Arc::new(MaskedMerkleTreeParameters::setup(&mut prng())));
 
 /// A Pedersen Merkle Root Hash
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
-pub struct PedersenMerkleRootHash(pub [u8; 32]);
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct PedersenMerkleRootHash([u8; 32]);
 
-impl PedersenMerkleRootHash {
-    pub const fn size() -> usize {
-        32
-    }
-}
-
-impl Display for PedersenMerkleRootHash {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}", hex::encode(self.0))
-    }
-}
+impl_hash_32_bytes!(PedersenMerkleRootHash);
 
 /// Calculates the root of the Merkle tree using a Pedersen Hash instantiated with a PRNG
 /// and returns it serialized
@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{errors::BlockError, BlockHeader, PedersenMerkleRootHash};
 use snarkvm_utilities::bytes::{FromBytes, ToBytes};
 
 use serde::{
@@ -37,6 +38,9 @@ use std::{
 // Marlin PoSW proof size
 const PROOF_SIZE: usize = 972;
 
+// The version of the Marlin PoSW proof encoding
+const POSW_VERSION: u8 = 0;
+
 #[derive(Clone)]
 /// A Proof of Succinct Work is a SNARK proof which
 pub struct ProofOfSuccinctWork(pub [u8; PROOF_SIZE]);
@@ -46,6 +50,45 @@ impl ProofOfSuccinctWork {
     pub const fn size() -> usize {
         PROOF_SIZE
     }
+
+    /// Returns the size of the versioned encoding, which is the version byte followed by the proof.
+    pub const fn versioned_size() -> usize {
+        1 + PROOF_SIZE
+    }
+
+    /// Returns the version of the proof encoding.
+    pub const fn version(&self) -> u8 {
+        POSW_VERSION
+    }
+
+    /// Writes the proof without its version byte, as it is laid out in a block header.
+    /// Block headers only carry version 0 proofs, so their size stays fixed.
+    pub(crate) fn write_unversioned<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (&self.0[..]).write(&mut writer)
+    }
+
+    /// Reads a version 0 proof without its version byte, as it is laid out in a block header.
+    pub(crate) fn read_unversioned<R: Read>(mut reader: R) -> IoResult<Self> {
+        let mut proof = [0; PROOF_SIZE];
+        reader.read_exact(&mut proof)?;
+        Ok(ProofOfSuccinctWork(proof))
+    }
+}
+
+/// Verifies the Proof of Succinct Work of a block header against the committed POSW parameters.
+pub trait PoswVerifier {
+    /// Returns `true` if the proof is valid for the nonce and the pedersen merkle root hash.
+    fn verify_proof(
+        &self,
+        nonce: u32,
+        proof: &ProofOfSuccinctWork,
+        pedersen_merkle_root: &PedersenMerkleRootHash,
+    ) -> bool;
+
+    /// Returns `true` if the proof in the block header is valid.
+    fn verify_header(&self, header: &BlockHeader) -> bool {
+        self.verify_proof(header.nonce, &header.proof, &header.pedersen_merkle_root_hash)
+    }
 }
 
 impl Display for ProofOfSuccinctWork {
@@ -86,6 +129,13 @@ impl<'de> Deserialize<'de> for ProofOfSuccinctWork {
             where
                 S: SeqAccess<'de>,
             {
+                let version: u8 = seq
+                    .next_element()?
+                    .ok_or_else(|| DeserializeError::custom("could not read the version"))?;
+                if version != POSW_VERSION {
+                    return Err(DeserializeError::custom(BlockError::UnsupportedPoswVersion(version)));
+                }
+
                 let mut bytes = [0u8; PROOF_SIZE];
                 for b in &mut bytes[..] {
                     *b = seq
@@ -96,13 +146,14 @@ impl<'de> Deserialize<'de> for ProofOfSuccinctWork {
             }
         }
 
-        deserializer.deserialize_tuple(PROOF_SIZE, ArrayVisitor)
+        deserializer.deserialize_tuple(ProofOfSuccinctWork::versioned_size(), ArrayVisitor)
     }
 }
 
 impl Serialize for ProofOfSuccinctWork {
     fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
-        let mut tup = s.serialize_tuple(PROOF_SIZE)?;
+        let mut tup = s.serialize_tuple(ProofOfSuccinctWork::versioned_size())?;
+        tup.serialize_element(&self.version())?;
         for byte in &self.0[..] {
             tup.serialize_element(byte)?;
         }
@@ -110,19 +161,24 @@ impl Serialize for ProofOfSuccinctWork {
     }
 }
 
+/// Writes the version byte followed by the proof.
 impl ToBytes for ProofOfSuccinctWork {
     #[inline]
     fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        (&self.0[..]).write(&mut writer)
+        self.version().write(&mut writer)?;
+        self.write_unversioned(&mut writer)
     }
 }
 
+/// Reads the version byte followed by the proof, and returns
+/// `BlockError::UnsupportedPoswVersion` for any version other than 0.
 impl FromBytes for ProofOfSuccinctWork {
     #[inline]
     fn read<R: Read>(mut reader: R) -> IoResult<Self> {
-        let mut proof = [0; PROOF_SIZE];
-        reader.read_exact(&mut proof)?;
-        Ok(ProofOfSuccinctWork(proof))
+        match u8::read(&mut reader)? {
+            POSW_VERSION => Self::read_unversioned(&mut reader),
+            version => Err(BlockError::UnsupportedPoswVersion(version).into()),
+        }
     }
 }
 
@@ -139,3 +195,49 @@ impl From<Vec<u8>> for ProofOfSuccinctWork {
         Self::from(proof.as_ref())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::{thread_rng, Rng};
+
+    fn random_proof() -> ProofOfSuccinctWork {
+        let rng = &mut thread_rng();
+        ProofOfSuccinctWork::from((0..PROOF_SIZE).map(|_| rng.gen()).collect::<Vec<u8>>())
+    }
+
+    #[test]
+    fn test_versioned_serialization() {
+        let proof = random_proof();
+
+        let mut bytes = vec![];
+        proof.write(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), ProofOfSuccinctWork::versioned_size());
+        assert_eq!(bytes[0], POSW_VERSION);
+        assert_eq!(&bytes[1..], &proof.0[..]);
+        assert_eq!(proof, ProofOfSuccinctWork::read(&bytes[..]).unwrap());
+
+        let serialized = bincode::serialize(&proof).unwrap();
+        assert_eq!(bytes, serialized);
+        assert_eq!(proof, bincode::deserialize(&serialized).unwrap());
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let mut bytes = vec![];
+        random_proof().write(&mut bytes).unwrap();
+
+        for version in [1u8, 2, u8::MAX].iter() {
+            bytes[0] = *version;
+
+            let error = ProofOfSuccinctWork::read(&bytes[..]).unwrap_err();
+            match error.get_ref().and_then(|inner| inner.downcast_ref::<BlockError>()) {
+                Some(BlockError::UnsupportedPoswVersion(found)) => assert_eq!(found, version),
+                _ => panic!("expected an unsupported version, found {:?}", error),
+            }
+
+            assert!(bincode::deserialize::<ProofOfSuccinctWork>(&bytes).is_err());
+        }
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::LedgerError;
+use snarkvm_algorithms::{
+    merkle_tree::{MerkleTreeDigest, NonMembershipProof, SortedLeaf, SortedMerkleTree},
+    traits::MerkleParameters,
+};
+use snarkvm_utilities::{to_bytes, ToBytes};
+
+use blake2::{digest::Digest, Blake2s};
+use std::sync::Arc;
+
+/// A leaf of a `SerialNumberAccumulator`, which is the Blake2s hash of a serial number.
+///
+/// Hashing gives every serial number a leaf of 32 bytes, which fits the input of the Merkle tree hash.
+/// The sentinels are the leaves whose bytes are all zero and all one, which no serial number is
+/// expected to hash to.
+#[derive(Copy, Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SerialNumberLeaf([u8; 32]);
+
+impl_hash_32_bytes!(SerialNumberLeaf);
+
+impl SerialNumberLeaf {
+    /// Returns the leaf of the given serial number.
+    pub fn from_serial_number<SN: ToBytes>(serial_number: &SN) -> Result<Self, LedgerError> {
+        let mut leaf = [0u8; 32];
+        leaf.copy_from_slice(&Blake2s::digest(&to_bytes![serial_number]?));
+        Ok(Self(leaf))
+    }
+}
+
+impl SortedLeaf for SerialNumberLeaf {
+    const MAX_LEAF: Self = Self([u8::MAX; 32]);
+    const MIN_LEAF: Self = Self([0u8; 32]);
+}
+
+/// Proves that a serial number is not in a `SerialNumberAccumulator`, by showing two adjacent
+/// leaves of the accumulator that are respectively smaller and larger than its leaf.
+pub type SerialNumberNonMembershipProof<P> = NonMembershipProof<P, SerialNumberLeaf>;
+
+/// An accumulator over the serial numbers revealed by the blocks of a ledger.
+///
+/// The serial numbers are kept as the leaves of a `SortedMerkleTree`, and the root of the tree is
+/// recorded after each block. This allows proving that a serial number had not been revealed
+/// as of a given block height, against the root of that block.
+pub struct SerialNumberAccumulator<P: MerkleParameters> {
+    /// The parameters of the Merkle tree.
+    parameters: Arc<P>,
+
+    /// The sorted Merkle tree over the serial numbers of all blocks.
+    tree: SortedMerkleTree<P, SerialNumberLeaf>,
+
+    /// The leaves of the serial numbers, in the order of their blocks.
+    leaves: Vec<SerialNumberLeaf>,
+
+    /// The number of serial numbers revealed up to each block, indexed by block height.
+    block_offsets: Vec<usize>,
+
+    /// The root of the tree after each block, indexed by block height.
+    block_roots: Vec<MerkleTreeDigest<P>>,
+}
+
+impl<P: MerkleParameters + Send + Sync> SerialNumberAccumulator<P> {
+    /// Returns an accumulator without any blocks.
+    pub fn new(parameters: Arc<P>) -> Result<Self, LedgerError> {
+        let tree = SortedMerkleTree::new(parameters.clone(), &[])?;
+
+        Ok(Self {
+            parameters,
+            tree,
+            leaves: vec![],
+            block_offsets: vec![],
+            block_roots: vec![],
+        })
+    }
+
+    /// Returns the number of blocks in the accumulator.
+    #[inline]
+    pub fn num_blocks(&self) -> usize {
+        self.block_roots.len()
+    }
+
+    /// Returns the root of the accumulator after the block at the given height, if it exists.
+    #[inline]
+    pub fn root(&self, block_height: u32) -> Option<MerkleTreeDigest<P>> {
+        self.block_roots.get(block_height as usize).cloned()
+    }
+
+    /// Adds the serial numbers of the next block to the accumulator, and returns the new root.
+    pub fn apply_block<'a, SN: ToBytes + 'a, I: IntoIterator<Item = &'a SN>>(
+        &mut self,
+        serial_numbers: I,
+    ) -> Result<MerkleTreeDigest<P>, LedgerError> {
+        let leaves = serial_numbers
+            .into_iter()
+            .map(SerialNumberLeaf::from_serial_number)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let root = self.tree.insert(&leaves)?;
+
+        self.leaves.extend(leaves);
+        self.block_offsets.push(self.leaves.len());
+        self.block_roots.push(root);
+
+        Ok(root)
+    }
+
+    /// Removes the serial numbers of the latest block from the accumulator.
+    pub fn rollback_block(&mut self) -> Result<(), LedgerError> {
+        if self.block_roots.pop().is_none() {
+            return Err(LedgerError::InvalidBlockHeight(0));
+        }
+        self.block_offsets.pop();
+
+        self.leaves.truncate(self.block_offsets.last().cloned().unwrap_or(0));
+        self.tree = SortedMerkleTree::new(self.parameters.clone(), &self.leaves)?;
+
+        Ok(())
+    }
+
+    /// Returns a proof that the given serial number had not been revealed as of the block at the given height.
+    pub fn prove_non_membership<SN: ToBytes>(
+        &self,
+        serial_number: &SN,
+        block_height: u32,
+    ) -> Result<SerialNumberNonMembershipProof<P>, LedgerError> {
+        let leaf = SerialNumberLeaf::from_serial_number(serial_number)?;
+
+        let num_blocks = self.num_blocks();
+        if block_height as usize >= num_blocks {
+            return Err(LedgerError::InvalidBlockHeight(block_height));
+        }
+
+        // Past blocks are proven against a tree over the serial numbers revealed up to them.
+        if block_height as usize + 1 == num_blocks {
+            Ok(self.tree.prove_non_membership(leaf)?)
+        } else {
+            let leaves = &self.leaves[..self.block_offsets[block_height as usize]];
+            let tree = SortedMerkleTree::new(self.parameters.clone(), leaves)?;
+            Ok(tree.prove_non_membership(leaf)?)
+        }
+    }
+
+    /// Returns true if the given proof shows that the given serial number is not in the
+    /// accumulator with the given root.
+    pub fn verify_non_membership<SN: ToBytes>(
+        root: &MerkleTreeDigest<P>,
+        serial_number: &SN,
+        proof: &SerialNumberNonMembershipProof<P>,
+    ) -> Result<bool, LedgerError> {
+        let leaf = SerialNumberLeaf::from_serial_number(serial_number)?;
+        Ok(proof.verify(root, leaf)?)
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    block::{BlockHeaderHash, Memo, MerkleRootHash, PedersenMerkleRootHash, TransactionId},
+    errors::BlockError,
+};
+use snarkvm_utilities::{
+    bytes::{FromBytes, ToBytes},
+    to_bytes,
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    convert::TryFrom,
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+const BYTES: [u8; 32] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0xf0, 0xe1, 0xd2,
+    0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69, 0x5a, 0x4b, 0x3c, 0x2d, 0x1e, 0xff,
+];
+
+const HEX: &str = "000102030405060708090a0b0c0d0e0ff0e1d2c3b4a5968778695a4b3c2d1eff";
+
+fn test_hash_conversions<H>(zero: H)
+where
+    H: Clone
+        + Debug
+        + Display
+        + Eq
+        + AsRef<[u8]>
+        + From<[u8; 32]>
+        + Into<[u8; 32]>
+        + PartialEq<[u8; 32]>
+        + for<'a> TryFrom<&'a [u8], Error = BlockError>
+        + FromStr<Err = BlockError>
+        + ToBytes
+        + FromBytes
+        + Serialize
+        + DeserializeOwned,
+{
+    assert_eq!(zero, [0u8; 32]);
+
+    // Test the conversions from and into bytes.
+    let hash = H::from(BYTES);
+    assert_eq!(hash, BYTES);
+    assert_eq!(hash.as_ref(), &BYTES[..]);
+    assert_eq!(hash.clone().into(), BYTES);
+    assert_eq!(H::try_from(&BYTES[..]).unwrap(), hash);
+
+    // Test that slices of the wrong length are rejected.
+    for len in &[0, 31, 33, 64] {
+        let bytes = vec![1u8; *len];
+        match H::try_from(&bytes[..]) {
+            Err(BlockError::InvalidHashLength(actual, expected)) => assert_eq!((actual, expected), (*len, 32)),
+            result => panic!("expected a length error for {} bytes, found {:?}", len, result),
+        }
+    }
+
+    // Test the round trip through the hex string form.
+    assert_eq!(hash.to_string(), HEX);
+    assert_eq!(HEX.parse::<H>().unwrap(), hash);
+    assert_eq!(HEX.to_uppercase().parse::<H>().unwrap(), hash);
+    assert_eq!(zero.to_string().parse::<H>().unwrap(), zero);
+    assert!(matches!("not hex".parse::<H>(), Err(BlockError::FromHexError(_))));
+    assert!(matches!(
+        HEX[1..].parse::<H>(),
+        Err(BlockError::FromHexError(hex::FromHexError::OddLength))
+    ));
+    assert!(matches!(
+        HEX.replace('f', "g").parse::<H>(),
+        Err(BlockError::FromHexError(hex::FromHexError::InvalidHexCharacter {
+            c: 'g',
+            ..
+        }))
+    ));
+    assert!(matches!(
+        HEX[2..].parse::<H>(),
+        Err(BlockError::InvalidHashLength(31, 32))
+    ));
+
+    // Test that the debug form names the type and shows the hex string.
+    assert!(format!("{:?}", hash).ends_with(&format!("({})", HEX)));
+
+    // Test that `ToBytes` writes the raw bytes in order.
+    let bytes = to_bytes![hash].unwrap();
+    assert_eq!(bytes, BYTES);
+    assert_eq!(H::read(&bytes[..]).unwrap(), hash);
+    assert!(H::read(&bytes[..31]).is_err());
+
+    // Test that human-readable formats use the hex string.
+    let json = serde_json::to_string(&hash).unwrap();
+    assert_eq!(json, format!("\"{}\"", HEX));
+    assert_eq!(serde_json::from_str::<H>(&json).unwrap(), hash);
+    assert!(serde_json::from_str::<H>(&format!("\"{}\"", &HEX[2..])).is_err());
+
+    // Test that binary formats use the raw bytes.
+    let bytes = bincode::serialize(&hash).unwrap();
+    assert_eq!(bytes, BYTES);
+    assert_eq!(bincode::deserialize::<H>(&bytes).unwrap(), hash);
+}
+
+#[test]
+fn test_block_header_hash_conversions() {
+    test_hash_conversions(BlockHeaderHash::zero());
+}
+
+#[test]
+fn test_merkle_root_hash_conversions() {
+    test_hash_conversions(MerkleRootHash::zero());
+}
+
+#[test]
+fn test_pedersen_merkle_root_hash_conversions() {
+    test_hash_conversions(PedersenMerkleRootHash::zero());
+}
+
+#[test]
+fn test_transaction_id_conversions() {
+    test_hash_conversions(TransactionId::zero());
+}
+
+#[test]
+fn test_memo_conversions() {
+    test_hash_conversions(Memo::zero());
+}
+
+#[test]
+fn test_memo_constructors() {
+    assert_eq!(Memo::empty(), Memo::zero());
+    assert_eq!(Memo::default(), Memo::empty());
+    assert!(Memo::empty().is_empty());
+    assert!(!Memo::new(BYTES).is_empty());
+
+    // A short note is padded with zeros, and a long one is truncated.
+    let note = Memo::from_bytes_truncated("paid in full".as_bytes());
+    assert_eq!(&note.as_bytes()[..12], b"paid in full");
+    assert_eq!(note.as_bytes()[12..], [0u8; 20]);
+    assert_eq!(Memo::from_bytes_truncated(&BYTES), Memo::new(BYTES));
+    assert_eq!(
+        Memo::from_bytes_truncated(&[&BYTES[..], &[0xab; 8]].concat()),
+        Memo::new(BYTES)
+    );
+    assert!(Memo::from_bytes_truncated(&[]).is_empty());
+
+    // The hash of the data is its Blake2s digest.
+    let hash = Memo::hash_of(b"invoice 42");
+    assert_eq!(hash, Memo::hash_of(b"invoice 42"));
+    assert_ne!(hash, Memo::hash_of(b"invoice 43"));
+    assert_eq!(
+        Memo::hash_of(b"").to_string(),
+        "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9"
+    );
+}
+
+#[test]
+fn test_hash_debug_names_the_type() {
+    assert_eq!(
+        format!("{:?}", BlockHeaderHash::new(BYTES)),
+        format!("BlockHeaderHash({})", HEX)
+    );
+    assert_eq!(
+        format!("{:?}", TransactionId::new(BYTES)),
+        format!("TransactionId({})", HEX)
+    );
+}
@@ -0,0 +1,331 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{block::ConflictPolicy, traits::TransactionScheme, TransactionError};
+use snarkvm_utilities::{has_duplicates, to_bytes, ToBytes};
+
+use std::{collections::HashSet, marker::PhantomData};
+
+/// The serialized serial numbers, commitments, and memo of a transaction. The memo is omitted
+/// if the conflict policy does not require it to be unique.
+struct TransactionEntries {
+    serial_numbers: Vec<Vec<u8>>,
+    commitments: Vec<Vec<u8>>,
+    memo: Option<Vec<u8>>,
+}
+
+impl TransactionEntries {
+    fn new<T: TransactionScheme>(transaction: &T, policy: &ConflictPolicy) -> Result<Self, TransactionError> {
+        let memo = to_bytes![transaction.memorandum()]?;
+
+        Ok(Self {
+            serial_numbers: transaction
+                .old_serial_numbers()
+                .iter()
+                .map(|sn| to_bytes![sn])
+                .collect::<Result<_, _>>()?,
+            commitments: transaction
+                .new_commitments()
+                .iter()
+                .map(|cm| to_bytes![cm])
+                .collect::<Result<_, _>>()?,
+            memo: match policy.memo_uniqueness.requires_unique(&memo) {
+                true => Some(memo),
+                false => None,
+            },
+        })
+    }
+}
+
+/// An index of the serial numbers, commitments, and memos of a set of transactions on the same
+/// network, keyed on their byte serialization. No two transactions in the set conflict under
+/// the conflict policy of the set, and only the memos that the policy requires to be unique
+/// are indexed.
+#[derive(Clone, Debug)]
+pub struct TransactionConflictSet<T: TransactionScheme> {
+    policy: ConflictPolicy,
+    network_id: Option<u8>,
+    num_transactions: usize,
+    serial_numbers: HashSet<Vec<u8>>,
+    commitments: HashSet<Vec<u8>>,
+    memos: HashSet<Vec<u8>>,
+    _transaction: PhantomData<T>,
+}
+
+impl<T: TransactionScheme> TransactionConflictSet<T> {
+    /// Initializes an empty conflict set with the default conflict policy.
+    pub fn new() -> Self {
+        Self::with_policy(ConflictPolicy::default())
+    }
+
+    /// Initializes an empty conflict set with the given conflict policy.
+    pub fn with_policy(policy: ConflictPolicy) -> Self {
+        Self {
+            policy,
+            network_id: None,
+            num_transactions: 0,
+            serial_numbers: HashSet::new(),
+            commitments: HashSet::new(),
+            memos: HashSet::new(),
+            _transaction: PhantomData,
+        }
+    }
+
+    /// Returns the conflict policy of the set.
+    pub fn policy(&self) -> ConflictPolicy {
+        self.policy
+    }
+
+    /// Returns the number of transactions in the set.
+    pub fn len(&self) -> usize {
+        self.num_transactions
+    }
+
+    /// Returns true if the set contains no transactions.
+    pub fn is_empty(&self) -> bool {
+        self.num_transactions == 0
+    }
+
+    /// Returns true if the given transaction conflicts with the set, i.e. it belongs to a
+    /// different network, reuses a serial number or commitment of the set, reuses a memo of
+    /// the set that the policy requires to be unique, or repeats a serial number or commitment
+    /// of its own.
+    pub fn conflicts_with(&self, transaction: &T) -> Result<bool, TransactionError> {
+        let entries = TransactionEntries::new(transaction, &self.policy)?;
+        Ok(self.conflicts_with_entries(transaction.network_id(), &entries))
+    }
+
+    /// Adds the given transaction to the set, and returns true. If the transaction conflicts
+    /// with the set, returns false and leaves the set unchanged.
+    pub fn insert_transaction(&mut self, transaction: &T) -> Result<bool, TransactionError> {
+        let entries = TransactionEntries::new(transaction, &self.policy)?;
+        if self.conflicts_with_entries(transaction.network_id(), &entries) {
+            return Ok(false);
+        }
+
+        self.network_id = Some(transaction.network_id());
+        self.num_transactions += 1;
+        self.serial_numbers.extend(entries.serial_numbers);
+        self.commitments.extend(entries.commitments);
+        self.memos.extend(entries.memo);
+
+        Ok(true)
+    }
+
+    /// Removes the given transaction, which should have been inserted into the set, and returns
+    /// true. If any of its entries is not in the set, returns false and leaves the set unchanged.
+    pub fn remove_transaction(&mut self, transaction: &T) -> Result<bool, TransactionError> {
+        let entries = TransactionEntries::new(transaction, &self.policy)?;
+
+        let is_present = self.network_id == Some(transaction.network_id())
+            && entries.memo.iter().all(|memo| self.memos.contains(memo))
+            && entries.serial_numbers.iter().all(|sn| self.serial_numbers.contains(sn))
+            && entries.commitments.iter().all(|cm| self.commitments.contains(cm));
+        if !is_present {
+            return Ok(false);
+        }
+
+        for sn in &entries.serial_numbers {
+            self.serial_numbers.remove(sn);
+        }
+        for cm in &entries.commitments {
+            self.commitments.remove(cm);
+        }
+        if let Some(memo) = &entries.memo {
+            self.memos.remove(memo);
+        }
+
+        self.num_transactions -= 1;
+        if self.num_transactions == 0 {
+            self.network_id = None;
+        }
+
+        Ok(true)
+    }
+
+    fn conflicts_with_entries(&self, network_id: u8, entries: &TransactionEntries) -> bool {
+        if matches!(self.network_id, Some(id) if id != network_id) {
+            return true;
+        }
+
+        if has_duplicates(&entries.serial_numbers) || has_duplicates(&entries.commitments) {
+            return true;
+        }
+
+        entries.memo.iter().any(|memo| self.memos.contains(memo))
+            || entries.serial_numbers.iter().any(|sn| self.serial_numbers.contains(sn))
+            || entries.commitments.iter().any(|cm| self.commitments.contains(cm))
+    }
+}
+
+impl<T: TransactionScheme> Default for TransactionConflictSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::{
+        transactions::tests::{random_transactions, TestTransaction},
+        Memo,
+        MemoPolicy,
+    };
+
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    fn conflict_set(transactions: &[TestTransaction]) -> TransactionConflictSet<TestTransaction> {
+        let mut set = TransactionConflictSet::new();
+        for transaction in transactions {
+            assert!(set.insert_transaction(transaction).unwrap());
+        }
+        set
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(20, rng);
+
+        let mut set = conflict_set(&transactions);
+        assert_eq!(set.len(), transactions.len());
+
+        // Every inserted transaction conflicts with the set, as its memo is already in it.
+        for transaction in transactions.iter() {
+            assert!(set.conflicts_with(transaction).unwrap());
+            assert!(!set.insert_transaction(transaction).unwrap());
+        }
+        assert_eq!(set.len(), transactions.len());
+
+        // A removed transaction no longer conflicts, and can be removed only once.
+        assert!(set.remove_transaction(&transactions[7]).unwrap());
+        assert!(!set.remove_transaction(&transactions[7]).unwrap());
+        assert!(!set.conflicts_with(&transactions[7]).unwrap());
+        assert_eq!(set.len(), transactions.len() - 1);
+
+        assert!(set.insert_transaction(&transactions[7]).unwrap());
+        for transaction in transactions.iter() {
+            assert!(set.remove_transaction(transaction).unwrap());
+        }
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_shared_serial_number() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(10, rng);
+        let set = conflict_set(&transactions);
+
+        let mut transaction = TestTransaction::rand(rng);
+        assert!(!set.conflicts_with(&transaction).unwrap());
+        transaction.serial_numbers[1] = transactions[4].serial_numbers[0];
+        assert!(set.conflicts_with(&transaction).unwrap());
+    }
+
+    #[test]
+    fn test_shared_commitment() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(10, rng);
+        let set = conflict_set(&transactions);
+
+        let mut transaction = TestTransaction::rand(rng);
+        transaction.commitments[0] = transactions[9].commitments[1];
+        assert!(set.conflicts_with(&transaction).unwrap());
+    }
+
+    #[test]
+    fn test_shared_memo() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(10, rng);
+        let set = conflict_set(&transactions);
+
+        let mut transaction = TestTransaction::rand(rng);
+        transaction.memo = transactions[0].memo;
+        assert!(set.conflicts_with(&transaction).unwrap());
+    }
+
+    #[test]
+    fn test_different_network_id() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(10, rng);
+        let mut set = conflict_set(&transactions);
+
+        let mut transaction = TestTransaction::rand(rng);
+        transaction.network_id = 1;
+        assert!(set.conflicts_with(&transaction).unwrap());
+
+        // An empty set accepts transactions of any network.
+        for transaction in transactions.iter() {
+            assert!(set.remove_transaction(transaction).unwrap());
+        }
+        assert!(set.insert_transaction(&transaction).unwrap());
+    }
+
+    #[test]
+    fn test_memo_policy() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let mut transactions = random_transactions(4, rng);
+        transactions[0].memo = Memo::empty();
+        transactions[1].memo = Memo::empty();
+        transactions[3].memo = transactions[2].memo;
+
+        // Both repeated memos conflict when every memo must be unique.
+        let mut set = TransactionConflictSet::with_policy(ConflictPolicy {
+            memo_uniqueness: MemoPolicy::Global,
+        });
+        assert!(set.insert_transaction(&transactions[0]).unwrap());
+        assert!(!set.insert_transaction(&transactions[1]).unwrap());
+        assert!(set.insert_transaction(&transactions[2]).unwrap());
+        assert!(!set.insert_transaction(&transactions[3]).unwrap());
+
+        // Under the default policy, only the repeated nonempty memo conflicts.
+        let mut set = TransactionConflictSet::new();
+        assert_eq!(set.policy(), ConflictPolicy::default());
+        assert!(set.insert_transaction(&transactions[0]).unwrap());
+        assert!(set.insert_transaction(&transactions[1]).unwrap());
+        assert!(set.insert_transaction(&transactions[2]).unwrap());
+        assert!(!set.insert_transaction(&transactions[3]).unwrap());
+
+        // No memo conflicts when memo uniqueness is disabled, and the transactions that share
+        // a memo can each be removed.
+        let mut set = TransactionConflictSet::with_policy(ConflictPolicy {
+            memo_uniqueness: MemoPolicy::Disabled,
+        });
+        for transaction in transactions.iter() {
+            assert!(set.insert_transaction(transaction).unwrap());
+        }
+        for transaction in transactions.iter() {
+            assert!(set.remove_transaction(transaction).unwrap());
+        }
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_repeated_entries_within_transaction() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let set = TransactionConflictSet::new();
+
+        let mut transaction = TestTransaction::rand(rng);
+        transaction.serial_numbers[1] = transaction.serial_numbers[0];
+        assert!(set.conflicts_with(&transaction).unwrap());
+
+        let mut transaction = TestTransaction::rand(rng);
+        transaction.commitments[1] = transaction.commitments[0];
+        assert!(set.conflicts_with(&transaction).unwrap());
+    }
+}
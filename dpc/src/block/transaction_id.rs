@@ -14,14 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_fields::PrimeField;
+/// The identifier of a transaction, as returned by `TransactionScheme::transaction_id`.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct TransactionId([u8; 32]);
 
-/// Trait for an algebraic sponge.
-pub trait AlgebraicSponge<BaseField: PrimeField>: Clone {
-    /// Initializes an algebraic sponge.
-    fn new() -> Self;
-    /// Takes in field elements.
-    fn absorb(&mut self, elems: &[BaseField]);
-    /// Takes out field elements.
-    fn squeeze(&mut self, num: usize) -> Vec<BaseField>;
-}
+impl_hash_32_bytes!(TransactionId);
@@ -14,19 +14,57 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{traits::TransactionScheme, TransactionError};
+use crate::{
+    block::{
+        merkle_inclusion_proof,
+        merkle_root,
+        ConflictPolicy,
+        MerkleInclusionProof,
+        MerkleRootHash,
+        TransactionConflictSet,
+        TransactionId,
+    },
+    bytes_serde,
+    traits::TransactionScheme,
+    TransactionError,
+};
 use snarkvm_utilities::{
     bytes::{FromBytes, ToBytes},
-    has_duplicates,
     to_bytes,
-    variable_length_integer::{read_variable_length_integer, variable_length_integer},
+    variable_length_integer::{read_variable_length_integer, variable_length_integer, variable_length_integer_size},
 };
 
+use itertools::Itertools;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
-    io::{Read, Result as IoResult, Write},
+    collections::{BTreeSet, HashMap},
+    io::{ErrorKind, Read, Result as IoResult, Write},
+    marker::PhantomData,
     ops::{Deref, DerefMut},
 };
 
+/// The maximum number of transactions to allocate space for up front when reading a list of
+/// transactions, so that an untrusted transaction count cannot force a huge allocation.
+const MAX_PREALLOCATED_TRANSACTIONS: usize = 1024;
+
+/// A writer that forwards bytes to an inner writer and counts how many were written.
+struct CountingWriter<W: Write> {
+    inner: W,
+    count: u64,
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        let written = self.inner.write(buf)?;
+        self.count += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        self.inner.flush()
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Transactions<T: TransactionScheme>(pub Vec<T>);
 
@@ -47,11 +85,72 @@ impl<T: TransactionScheme> Transactions<T> {
     }
 
     /// Returns the transaction ids.
-    pub fn to_transaction_ids(&self) -> Result<Vec<[u8; 32]>, TransactionError> {
+    pub fn to_transaction_ids(&self) -> Result<Vec<TransactionId>, TransactionError> {
+        self.0
+            .iter()
+            .map(|tx| Ok(TransactionId::new(tx.transaction_id()?)))
+            .collect()
+    }
+
+    /// Returns the bytes of the transaction ids.
+    #[deprecated(note = "use `to_transaction_ids`, which returns `TransactionId`s")]
+    pub fn to_raw_transaction_ids(&self) -> Result<Vec<[u8; 32]>, TransactionError> {
+        self.transaction_id_bytes()
+    }
+
+    /// Returns the bytes of the transaction ids, which are the leaves of the Merkle tree.
+    pub(crate) fn transaction_id_bytes(&self) -> Result<Vec<[u8; 32]>, TransactionError> {
         self.0.iter().map(|tx| tx.transaction_id()).collect()
     }
 
+    /// Returns the Merkle root of the transaction ids, as committed to by the block header.
+    pub fn to_merkle_root(&self) -> Result<MerkleRootHash, TransactionError> {
+        if self.0.is_empty() {
+            return Err(TransactionError::EmptyTransactions);
+        }
+
+        Ok(MerkleRootHash::new(merkle_root(&self.transaction_id_bytes()?)))
+    }
+
+    /// Returns a proof that the transaction at the given index is included in the Merkle root
+    /// of the transactions, which is verified with `verify_inclusion_proof`.
+    pub fn generate_inclusion_proof(&self, index: usize) -> Result<MerkleInclusionProof, TransactionError> {
+        merkle_inclusion_proof(&self.transaction_id_bytes()?, index)
+            .ok_or(TransactionError::InvalidTransactionIndex(index, self.0.len()))
+    }
+
+    /// Reads a list of transactions as encoded by `ToBytes`, failing instead of reading more
+    /// than `max_transactions` transactions or more than `max_bytes` bytes, including the
+    /// transaction count.
+    pub fn read_with_limit<R: Read>(
+        reader: R,
+        max_transactions: usize,
+        max_bytes: u64,
+    ) -> Result<Self, TransactionError> {
+        let mut reader = reader.take(max_bytes);
+        let result = TransactionsReader::new(&mut reader).and_then(|transactions| {
+            if transactions.remaining() > max_transactions {
+                return Err(TransactionError::TooManyTransactions(
+                    transactions.remaining(),
+                    max_transactions,
+                ));
+            }
+            transactions.collect::<Result<Vec<T>, _>>()
+        });
+
+        match result {
+            Ok(transactions) => Ok(Self(transactions)),
+            Err(TransactionError::IoError(error))
+                if error.kind() == ErrorKind::UnexpectedEof && reader.limit() == 0 =>
+            {
+                Err(TransactionError::TransactionsTooLarge(max_bytes))
+            }
+            Err(error) => Err(error),
+        }
+    }
+
     /// Serializes the transactions into byte vectors.
+    #[deprecated(note = "use `Transactions::write_all` to serialize into a single buffer")]
     pub fn serialize(&self) -> Result<Vec<Vec<u8>>, TransactionError> {
         self.0
             .iter()
@@ -59,6 +158,31 @@ impl<T: TransactionScheme> Transactions<T> {
             .collect::<Result<Vec<Vec<u8>>, TransactionError>>()
     }
 
+    /// Streams every transaction into the given writer, one after another and without a
+    /// length prefix, and returns the `(offset, length)` of each transaction in the written bytes.
+    pub fn write_all<W: Write>(&self, writer: W) -> IoResult<Vec<(u64, u64)>> {
+        let mut writer = CountingWriter {
+            inner: writer,
+            count: 0,
+        };
+
+        let mut offsets = Vec::with_capacity(self.0.len());
+        for transaction in &self.0 {
+            let offset = writer.count;
+            transaction.write(&mut writer)?;
+            offsets.push((offset, writer.count - offset));
+        }
+
+        Ok(offsets)
+    }
+
+    /// Returns the number of bytes in the serialized transactions, including the transaction
+    /// count, without serializing them.
+    pub fn serialized_size(&self) -> usize {
+        variable_length_integer_size(self.0.len() as u64)
+            + self.0.iter().map(|transaction| transaction.size()).sum::<usize>()
+    }
+
     /// Serializes the transactions into strings.
     pub fn serialize_as_str(&self) -> Result<Vec<String>, TransactionError> {
         self.0
@@ -67,52 +191,170 @@ impl<T: TransactionScheme> Transactions<T> {
             .collect::<Result<Vec<String>, TransactionError>>()
     }
 
+    /// Returns true if the given transaction conflicts with the list of transactions, i.e. it
+    /// belongs to a different network, or reuses a serial number, commitment, or memo. A list
+    /// that already conflicts with itself conflicts with every transaction.
     pub fn conflicts(&self, transaction: &T) -> bool {
-        let mut holding_serial_numbers = vec![];
-        let mut holding_commitments = vec![];
-        let mut holding_memos = Vec::with_capacity(self.0.len());
-
-        for tx in &self.0 {
-            if tx.network_id() != transaction.network_id() {
-                return true;
-            };
+        self.conflicts_with_policy(transaction, ConflictPolicy::default())
+    }
 
-            holding_serial_numbers.extend(tx.old_serial_numbers());
-            holding_commitments.extend(tx.new_commitments());
-            holding_memos.push(tx.memorandum());
+    /// Returns true if the given transaction conflicts with the list of transactions under the
+    /// given conflict policy.
+    pub fn conflicts_with_policy(&self, transaction: &T, policy: ConflictPolicy) -> bool {
+        match Self::conflict_set(&self.0, policy) {
+            Ok(Some(conflict_set)) => conflict_set.conflicts_with(transaction).unwrap_or(true),
+            _ => true,
         }
+    }
+
+    /// Returns true if any two transactions in the list conflict with each other.
+    pub fn has_conflicts(&self) -> Result<bool, TransactionError> {
+        self.has_conflicts_with_policy(ConflictPolicy::default())
+    }
 
-        let transaction_serial_numbers = transaction.old_serial_numbers();
-        let transaction_commitments = transaction.new_commitments();
-        let transaction_memo = transaction.memorandum();
+    /// Returns true if any two transactions in the list conflict with each other under the
+    /// given conflict policy.
+    pub fn has_conflicts_with_policy(&self, policy: ConflictPolicy) -> Result<bool, TransactionError> {
+        Self::contains_conflicts(&self.0, policy)
+    }
 
-        // Check if the transactions in the block have duplicate serial numbers
-        if has_duplicates(transaction_serial_numbers) {
-            return true;
+    /// Returns true if the given transactions belong to different networks, or if any serial
+    /// number, commitment, or memo that the policy requires to be unique appears more than once
+    /// across them.
+    pub(crate) fn contains_conflicts(transactions: &[T], policy: ConflictPolicy) -> Result<bool, TransactionError> {
+        Ok(Self::conflict_set(transactions, policy)?.is_none())
+    }
+
+    /// Returns the conflict set of the given transactions, or `None` if two of them conflict.
+    fn conflict_set(
+        transactions: &[T],
+        policy: ConflictPolicy,
+    ) -> Result<Option<TransactionConflictSet<T>>, TransactionError> {
+        let mut conflict_set = TransactionConflictSet::with_policy(policy);
+        for transaction in transactions {
+            if !conflict_set.insert_transaction(transaction)? {
+                return Ok(None);
+            }
         }
 
-        // Check if the transactions in the block have duplicate commitments
-        if has_duplicates(transaction_commitments) {
-            return true;
+        Ok(Some(conflict_set))
+    }
+
+    /// Returns every pair of indices `(i, j)`, with `i < j`, of transactions in the list that
+    /// conflict with each other, in ascending order.
+    pub fn find_conflicts(&self) -> Vec<(usize, usize)> {
+        self.find_conflicts_with_policy(ConflictPolicy::default())
+    }
+
+    /// Returns every pair of indices `(i, j)`, with `i < j`, of transactions in the list that
+    /// conflict with each other under the given conflict policy, in ascending order. A memo
+    /// that cannot be serialized is treated as one that must be unique.
+    pub fn find_conflicts_with_policy(&self, policy: ConflictPolicy) -> Vec<(usize, usize)> {
+        let mut network_ids = HashMap::<_, Vec<usize>>::new();
+        let mut serial_numbers = HashMap::<_, Vec<usize>>::new();
+        let mut commitments = HashMap::<_, Vec<usize>>::new();
+        let mut memos = HashMap::<_, Vec<usize>>::new();
+
+        for (i, transaction) in self.0.iter().enumerate() {
+            network_ids.entry(transaction.network_id()).or_default().push(i);
+            for sn in transaction.old_serial_numbers() {
+                serial_numbers.entry(sn).or_default().push(i);
+            }
+            for cm in transaction.new_commitments() {
+                commitments.entry(cm).or_default().push(i);
+            }
+            let requires_unique =
+                to_bytes![transaction.memorandum()].map_or(true, |memo| policy.memo_uniqueness.requires_unique(&memo));
+            if requires_unique {
+                memos.entry(transaction.memorandum()).or_default().push(i);
+            }
         }
 
-        if holding_memos.contains(&transaction_memo) {
-            return true;
+        let mut conflicts = BTreeSet::new();
+
+        // Transactions that share an entry conflict. The indices of an entry are in ascending
+        // order, and repeat when a transaction contains the entry more than once.
+        for indices in serial_numbers
+            .values()
+            .chain(commitments.values())
+            .chain(memos.values())
+        {
+            for (&i, &j) in indices.iter().tuple_combinations() {
+                if i != j {
+                    conflicts.insert((i, j));
+                }
+            }
         }
 
-        for sn in transaction_serial_numbers {
-            if holding_serial_numbers.contains(&sn) {
-                return true;
+        // Transactions for different networks conflict.
+        for (first, second) in network_ids.values().tuple_combinations() {
+            for &i in first {
+                for &j in second {
+                    conflicts.insert((i.min(j), i.max(j)));
+                }
             }
         }
 
-        for cm in transaction_commitments {
-            if holding_commitments.contains(&cm) {
-                return true;
+        conflicts.into_iter().collect()
+    }
+}
+
+/// Reads a list of transactions as encoded by `ToBytes`, one transaction at a time.
+///
+/// The transaction count is read on construction, and each transaction is only read once it
+/// is requested. The iterator ends after the first error.
+pub struct TransactionsReader<R: Read, T: TransactionScheme> {
+    reader: R,
+    remaining: usize,
+    _transaction: PhantomData<T>,
+}
+
+impl<R: Read, T: TransactionScheme> TransactionsReader<R, T> {
+    /// Reads the transaction count from the given reader.
+    pub fn new(mut reader: R) -> Result<Self, TransactionError> {
+        let remaining = read_variable_length_integer(&mut reader)?;
+
+        Ok(Self {
+            reader,
+            remaining,
+            _transaction: PhantomData,
+        })
+    }
+
+    /// Returns the number of transactions that have not been read yet.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Returns the inner reader, positioned after the last transaction read.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read, T: TransactionScheme> Iterator for TransactionsReader<R, T> {
+    type Item = Result<T, TransactionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        match T::read(&mut self.reader) {
+            Ok(transaction) => {
+                self.remaining -= 1;
+                Some(Ok(transaction))
+            }
+            Err(error) => {
+                self.remaining = 0;
+                Some(Err(error.into()))
             }
         }
+    }
 
-        false
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The count is untrusted, so it only bounds the number of transactions from above.
+        (0, Some(self.remaining))
     }
 }
 
@@ -133,7 +375,7 @@ impl<T: TransactionScheme> FromBytes for Transactions<T> {
     #[inline]
     fn read<R: Read>(mut reader: R) -> IoResult<Self> {
         let num_transactions = read_variable_length_integer(&mut reader)?;
-        let mut transactions = Vec::with_capacity(num_transactions);
+        let mut transactions = Vec::with_capacity(num_transactions.min(MAX_PREALLOCATED_TRANSACTIONS));
         for _ in 0..num_transactions {
             let transaction: T = FromBytes::read(&mut reader)?;
             transactions.push(transaction);
@@ -143,6 +385,19 @@ impl<T: TransactionScheme> FromBytes for Transactions<T> {
     }
 }
 
+/// Human-readable formats use the hex string of the `ToBytes` encoding, and binary formats use the raw bytes.
+impl<T: TransactionScheme> Serialize for Transactions<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        bytes_serde::serialize(self, serializer)
+    }
+}
+
+impl<'de, T: TransactionScheme> Deserialize<'de> for Transactions<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        bytes_serde::deserialize(deserializer, "transactions")
+    }
+}
+
 impl<T: TransactionScheme> Default for Transactions<T> {
     fn default() -> Self {
         Self::new()
@@ -162,3 +417,684 @@ impl<T: TransactionScheme> DerefMut for Transactions<T> {
         &mut self.0
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{
+        verify_inclusion_proof,
+        Block,
+        BlockHeader,
+        BlockHeaderHash,
+        Memo,
+        MemoPolicy,
+        MerkleRootHash,
+        PedersenMerkleRootHash,
+        ProofOfSuccinctWork,
+    };
+    use snarkvm_utilities::variable_length_integer::variable_length_integer;
+
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    /// A minimal transaction for exercising `Transactions` without running the DPC.
+    #[derive(Clone, Debug, Default, Eq, PartialEq)]
+    pub(crate) struct TestTransaction {
+        pub(crate) network_id: u8,
+        pub(crate) serial_numbers: Vec<[u8; 32]>,
+        pub(crate) commitments: Vec<[u8; 32]>,
+        pub(crate) memo: Memo,
+        pub(crate) payload: Vec<u8>,
+    }
+
+    impl TestTransaction {
+        pub(crate) fn rand<R: Rng>(rng: &mut R) -> Self {
+            let payload_size = rng.gen_range(0..512);
+            Self {
+                network_id: 0,
+                serial_numbers: vec![rng.gen(), rng.gen()],
+                commitments: vec![rng.gen(), rng.gen()],
+                memo: Memo::new(rng.gen()),
+                payload: (0..payload_size).map(|_| rng.gen()).collect(),
+            }
+        }
+    }
+
+    impl TransactionScheme for TestTransaction {
+        type Commitment = [u8; 32];
+        type Digest = [u8; 32];
+        type EncryptedRecord = [u8; 32];
+        type InnerCircuitID = [u8; 32];
+        type LocalDataRoot = [u8; 32];
+        type Memorandum = Memo;
+        type ProgramCommitment = [u8; 32];
+        type SerialNumber = [u8; 32];
+        type ValueBalance = i64;
+
+        fn transaction_id(&self) -> Result<[u8; 32], TransactionError> {
+            let mut id = [0u8; 32];
+            id.copy_from_slice(&snarkvm_algorithms::crh::double_sha256(&to_bytes![self]?));
+            Ok(id)
+        }
+
+        fn network_id(&self) -> u8 {
+            self.network_id
+        }
+
+        fn ledger_digest(&self) -> &Self::Digest {
+            &[0u8; 32]
+        }
+
+        fn inner_circuit_id(&self) -> &Self::InnerCircuitID {
+            &[0u8; 32]
+        }
+
+        fn old_serial_numbers(&self) -> &[Self::SerialNumber] {
+            &self.serial_numbers
+        }
+
+        fn new_commitments(&self) -> &[Self::Commitment] {
+            &self.commitments
+        }
+
+        fn program_commitment(&self) -> &Self::ProgramCommitment {
+            &[0u8; 32]
+        }
+
+        fn local_data_root(&self) -> &Self::LocalDataRoot {
+            &[0u8; 32]
+        }
+
+        fn value_balance(&self) -> i64 {
+            0
+        }
+
+        fn memorandum(&self) -> &Self::Memorandum {
+            &self.memo
+        }
+
+        fn encrypted_records(&self) -> &[Self::EncryptedRecord] {
+            &[]
+        }
+
+        fn size(&self) -> usize {
+            1 + variable_length_integer_size(self.serial_numbers.len() as u64)
+                + 32 * self.serial_numbers.len()
+                + variable_length_integer_size(self.commitments.len() as u64)
+                + 32 * self.commitments.len()
+                + 32
+                + variable_length_integer_size(self.payload.len() as u64)
+                + self.payload.len()
+        }
+    }
+
+    impl ToBytes for TestTransaction {
+        fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+            self.network_id.write(&mut writer)?;
+            variable_length_integer(self.serial_numbers.len() as u64).write(&mut writer)?;
+            for serial_number in &self.serial_numbers {
+                serial_number.write(&mut writer)?;
+            }
+            variable_length_integer(self.commitments.len() as u64).write(&mut writer)?;
+            for commitment in &self.commitments {
+                commitment.write(&mut writer)?;
+            }
+            self.memo.write(&mut writer)?;
+            variable_length_integer(self.payload.len() as u64).write(&mut writer)?;
+            self.payload.write(&mut writer)
+        }
+    }
+
+    impl FromBytes for TestTransaction {
+        fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+            let network_id = u8::read(&mut reader)?;
+            let mut serial_numbers = vec![];
+            for _ in 0..read_variable_length_integer(&mut reader)? {
+                serial_numbers.push(<[u8; 32]>::read(&mut reader)?);
+            }
+            let mut commitments = vec![];
+            for _ in 0..read_variable_length_integer(&mut reader)? {
+                commitments.push(<[u8; 32]>::read(&mut reader)?);
+            }
+            let memo = Memo::read(&mut reader)?;
+            let mut payload = vec![0u8; read_variable_length_integer(&mut reader)?];
+            reader.read_exact(&mut payload)?;
+
+            Ok(Self {
+                network_id,
+                serial_numbers,
+                commitments,
+                memo,
+                payload,
+            })
+        }
+    }
+
+    pub(crate) fn random_transactions<R: Rng>(num_transactions: usize, rng: &mut R) -> Transactions<TestTransaction> {
+        Transactions((0..num_transactions).map(|_| TestTransaction::rand(rng)).collect())
+    }
+
+    /// Counts the number of `write` calls and bytes passed to it.
+    #[derive(Default)]
+    struct WriteCounter {
+        bytes: usize,
+    }
+
+    impl Write for WriteCounter {
+        fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+            self.bytes += buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> IoResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_serialized_size() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        for num_transactions in 0..50 {
+            let transactions = random_transactions(num_transactions, rng);
+            assert_eq!(transactions.serialized_size(), to_bytes![transactions].unwrap().len());
+            for transaction in transactions.iter() {
+                assert_eq!(transaction.size(), to_bytes![transaction].unwrap().len());
+            }
+        }
+
+        // The transaction count and the lengths inside each transaction may need wider encodings.
+        let mut transactions = random_transactions(300, rng);
+        transactions[0].payload = vec![7u8; 70_000];
+        transactions[1].serial_numbers = vec![[1u8; 32]; 253];
+        assert_eq!(transactions.serialized_size(), to_bytes![transactions].unwrap().len());
+    }
+
+    #[test]
+    fn test_write_all_offsets() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(20, rng);
+
+        let mut buffer = vec![];
+        let offsets = transactions.write_all(&mut buffer).unwrap();
+        assert_eq!(offsets.len(), transactions.len());
+        assert_eq!(
+            variable_length_integer_size(transactions.len() as u64) + buffer.len(),
+            transactions.serialized_size()
+        );
+
+        let mut expected_offset = 0;
+        for ((offset, length), transaction) in offsets.iter().zip(transactions.iter()) {
+            assert_eq!(*offset, expected_offset);
+            assert_eq!(*length, transaction.size() as u64);
+
+            let bytes = &buffer[*offset as usize..(offset + length) as usize];
+            assert_eq!(bytes, &to_bytes![transaction].unwrap()[..]);
+            assert_eq!(&TestTransaction::read(bytes).unwrap(), transaction);
+
+            expected_offset += length;
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_write_all_matches_serialize() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(20, rng);
+
+        let mut buffer = vec![];
+        transactions.write_all(&mut buffer).unwrap();
+
+        let serialized = transactions.serialize().unwrap();
+        assert_eq!(serialized.len(), transactions.len());
+        for (bytes, transaction) in serialized.iter().zip(transactions.iter()) {
+            assert_eq!(bytes, &to_bytes![transaction].unwrap());
+        }
+        assert_eq!(serialized.concat(), buffer);
+
+        // The length-prefixed encoding is the prefix followed by the streamed transactions.
+        let expected = [variable_length_integer(transactions.len() as u64), buffer].concat();
+        assert_eq!(to_bytes![transactions].unwrap(), expected);
+    }
+
+    #[test]
+    fn test_has_conflicts() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+
+        assert!(!Transactions::<TestTransaction>::new().has_conflicts().unwrap());
+        assert!(!random_transactions(20, rng).has_conflicts().unwrap());
+        assert!(random_transactions(20, rng).find_conflicts().is_empty());
+
+        // Two different transactions that spend the same serial number.
+        let mut transactions = random_transactions(20, rng);
+        transactions[17].serial_numbers[1] = transactions[3].serial_numbers[0];
+        assert_ne!(transactions[3], transactions[17]);
+        assert!(transactions.has_conflicts().unwrap());
+        assert!(Transactions::from(&transactions[..17]).conflicts(&transactions[17]));
+        assert_eq!(transactions.find_conflicts(), vec![(3, 17)]);
+
+        // Two different transactions that output the same commitment.
+        let mut transactions = random_transactions(20, rng);
+        transactions[9].commitments[0] = transactions[2].commitments[1];
+        assert!(transactions.has_conflicts().unwrap());
+        assert!(Transactions::from(&transactions[..9]).conflicts(&transactions[9]));
+        assert_eq!(transactions.find_conflicts(), vec![(2, 9)]);
+
+        // Two different transactions with the same memo.
+        let mut transactions = random_transactions(20, rng);
+        transactions[19].memo = transactions[0].memo;
+        assert!(transactions.has_conflicts().unwrap());
+        assert!(Transactions::from(&transactions[..19]).conflicts(&transactions[19]));
+        assert_eq!(transactions.find_conflicts(), vec![(0, 19)]);
+
+        // Two transactions for different networks.
+        let mut transactions = random_transactions(20, rng);
+        transactions[5].network_id = 1;
+        assert!(transactions.has_conflicts().unwrap());
+        assert!(Transactions::from(&transactions[..5]).conflicts(&transactions[5]));
+        let expected: Vec<_> = (0..20).filter(|&i| i != 5).map(|i| (i.min(5), i.max(5))).collect();
+        assert_eq!(transactions.find_conflicts(), expected);
+
+        // A transaction that spends the same serial number twice.
+        let mut transactions = random_transactions(20, rng);
+        transactions[11].serial_numbers[1] = transactions[11].serial_numbers[0];
+        assert!(transactions.has_conflicts().unwrap());
+        assert!(Transactions::from(&transactions[..11]).conflicts(&transactions[11]));
+        assert!(transactions.find_conflicts().is_empty());
+    }
+
+    #[test]
+    fn test_find_conflicts_reports_every_pair() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let mut transactions = random_transactions(10, rng);
+
+        // Three transactions spend the same serial number, and two of them share a memo.
+        transactions[1].serial_numbers[0] = transactions[8].serial_numbers[1];
+        transactions[4].serial_numbers[1] = transactions[8].serial_numbers[1];
+        transactions[4].memo = transactions[1].memo;
+
+        // Another two transactions share a commitment.
+        transactions[6].commitments[1] = transactions[2].commitments[1];
+
+        assert_eq!(transactions.find_conflicts(), vec![(1, 4), (1, 8), (2, 6), (4, 8)]);
+        assert!(transactions.has_conflicts().unwrap());
+        assert!(Transactions::from(&transactions[..4]).conflicts(&transactions[4]));
+
+        // A list that conflicts with itself conflicts with any transaction.
+        assert!(transactions.conflicts(&TestTransaction::rand(rng)));
+    }
+
+    #[test]
+    fn test_conflicts_accepts_disjoint_transactions() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(20, rng);
+
+        for i in 0..transactions.len() {
+            assert!(!Transactions::from(&transactions[..i]).conflicts(&transactions[i]));
+        }
+    }
+
+    #[test]
+    fn test_conflicts_allows_repeated_empty_memos() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let mut transactions = random_transactions(2, rng);
+
+        // Distinct transactions that leave their memo empty do not conflict.
+        transactions[0].memo = Memo::empty();
+        transactions[1].memo = Memo::empty();
+        assert!(!Transactions::from(&transactions[..1]).conflicts(&transactions[1]));
+        assert!(!transactions.has_conflicts().unwrap());
+        assert!(transactions.find_conflicts().is_empty());
+
+        // Distinct transactions that share a nonempty memo still conflict.
+        transactions[0].memo = Memo::from_bytes_truncated(b"invoice 42");
+        transactions[1].memo = Memo::from_bytes_truncated(b"invoice 42");
+        assert!(Transactions::from(&transactions[..1]).conflicts(&transactions[1]));
+        assert!(transactions.has_conflicts().unwrap());
+        assert_eq!(transactions.find_conflicts(), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn test_memo_policies() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let policy = |memo_uniqueness| ConflictPolicy { memo_uniqueness };
+
+        // Two transactions share the empty memo, and another two share a nonempty memo.
+        let mut transactions = random_transactions(10, rng);
+        transactions[0].memo = Memo::empty();
+        transactions[5].memo = Memo::empty();
+        transactions[7].memo = transactions[2].memo;
+
+        let global = policy(MemoPolicy::Global);
+        assert!(transactions.has_conflicts_with_policy(global).unwrap());
+        assert_eq!(transactions.find_conflicts_with_policy(global), vec![(0, 5), (2, 7)]);
+        assert!(Transactions::from(&transactions[..5]).conflicts_with_policy(&transactions[5], global));
+        assert!(Transactions::from(&transactions[1..7]).conflicts_with_policy(&transactions[7], global));
+
+        let non_empty_only = policy(MemoPolicy::NonEmptyOnly);
+        assert_eq!(non_empty_only, ConflictPolicy::default());
+        assert!(transactions.has_conflicts_with_policy(non_empty_only).unwrap());
+        assert_eq!(transactions.find_conflicts_with_policy(non_empty_only), vec![(2, 7)]);
+        assert!(!Transactions::from(&transactions[..5]).conflicts_with_policy(&transactions[5], non_empty_only));
+        assert!(Transactions::from(&transactions[..7]).conflicts_with_policy(&transactions[7], non_empty_only));
+
+        let disabled = policy(MemoPolicy::Disabled);
+        assert!(!transactions.has_conflicts_with_policy(disabled).unwrap());
+        assert!(transactions.find_conflicts_with_policy(disabled).is_empty());
+        assert!(!Transactions::from(&transactions[..7]).conflicts_with_policy(&transactions[7], disabled));
+
+        // Without the repeated nonempty memo, only the global policy finds a conflict.
+        transactions[7].memo = Memo::new(rng.gen());
+        assert!(transactions.has_conflicts_with_policy(global).unwrap());
+        assert!(!transactions.has_conflicts_with_policy(non_empty_only).unwrap());
+        assert!(!transactions.has_conflicts_with_policy(disabled).unwrap());
+
+        // Every policy still rejects a repeated serial number.
+        transactions[9].serial_numbers[0] = transactions[4].serial_numbers[1];
+        for memo_uniqueness in &[MemoPolicy::Global, MemoPolicy::NonEmptyOnly, MemoPolicy::Disabled] {
+            let policy = policy(*memo_uniqueness);
+            assert!(transactions.has_conflicts_with_policy(policy).unwrap());
+            assert!(transactions.find_conflicts_with_policy(policy).contains(&(4, 9)));
+        }
+    }
+
+    #[test]
+    fn test_block_serialize_writes_each_transaction_once() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(20, rng);
+
+        let mut counter = WriteCounter::default();
+        transactions.write_all(&mut counter).unwrap();
+        assert_eq!(
+            variable_length_integer_size(transactions.len() as u64) + counter.bytes,
+            transactions.serialized_size()
+        );
+
+        let block = Block {
+            header: BlockHeader {
+                previous_block_hash: BlockHeaderHash::zero(),
+                merkle_root_hash: MerkleRootHash::zero(),
+                pedersen_merkle_root_hash: PedersenMerkleRootHash::zero(),
+                proof: ProofOfSuccinctWork([0u8; ProofOfSuccinctWork::size()]),
+                time: 0,
+                difficulty_target: 0,
+                nonce: 0,
+            },
+            transactions,
+        };
+
+        let serialized = block.serialize().unwrap();
+        assert_eq!(
+            serialized.len(),
+            BlockHeader::size() + 1 + counter.bytes,
+            "every transaction must be serialized exactly once"
+        );
+        assert_eq!(serialized, to_bytes![block].unwrap());
+        assert_eq!(Block::<TestTransaction>::deserialize(&serialized).unwrap(), block);
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(5, rng);
+        let header = BlockHeader {
+            previous_block_hash: BlockHeaderHash::new(rng.gen()),
+            merkle_root_hash: transactions.to_merkle_root().unwrap(),
+            pedersen_merkle_root_hash: PedersenMerkleRootHash::new(rng.gen()),
+            proof: ProofOfSuccinctWork::from(
+                &(0..ProofOfSuccinctWork::size()).map(|_| rng.gen()).collect::<Vec<u8>>()[..],
+            ),
+            time: rng.gen(),
+            difficulty_target: rng.gen(),
+            nonce: rng.gen(),
+        };
+        let block = Block { header, transactions };
+
+        // Human-readable formats use the hex string of the canonical encoding.
+        let json = serde_json::to_string(&block.header).unwrap();
+        assert_eq!(json, format!("\"{}\"", hex::encode(to_bytes![block.header].unwrap())));
+        assert_eq!(serde_json::from_str::<BlockHeader>(&json).unwrap(), block.header);
+
+        let json = serde_json::to_string(&block.transactions).unwrap();
+        assert_eq!(
+            json,
+            format!("\"{}\"", hex::encode(to_bytes![block.transactions].unwrap()))
+        );
+        assert_eq!(
+            serde_json::from_str::<Transactions<TestTransaction>>(&json).unwrap(),
+            block.transactions
+        );
+
+        let json = serde_json::to_string(&block).unwrap();
+        assert_eq!(json, format!("\"{}\"", hex::encode(to_bytes![block].unwrap())));
+        assert_eq!(serde_json::from_str::<Block<TestTransaction>>(&json).unwrap(), block);
+
+        // Binary formats use the raw bytes, with the header keeping its fixed size.
+        let bytes = bincode::serialize(&block.header).unwrap();
+        assert_eq!(bytes, to_bytes![block.header].unwrap());
+        assert_eq!(bincode::deserialize::<BlockHeader>(&bytes).unwrap(), block.header);
+
+        let bytes = bincode::serialize(&block.transactions).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Transactions<TestTransaction>>(&bytes).unwrap(),
+            block.transactions
+        );
+
+        let bytes = bincode::serialize(&block).unwrap();
+        assert_eq!(bytes[8..], to_bytes![block].unwrap()[..]);
+        assert_eq!(bincode::deserialize::<Block<TestTransaction>>(&bytes).unwrap(), block);
+
+        // Malformed encodings are rejected.
+        assert!(serde_json::from_str::<BlockHeader>("\"not hex\"").is_err());
+        assert!(serde_json::from_str::<BlockHeader>("\"00\"").is_err());
+        assert!(serde_json::from_str::<Block<TestTransaction>>("\"0g\"").is_err());
+        assert!(
+            serde_json::from_str::<Block<TestTransaction>>(&format!(
+                "\"{}00\"",
+                hex::encode(to_bytes![block].unwrap())
+            ))
+            .is_err()
+        );
+        assert!(serde_json::from_str::<Transactions<TestTransaction>>("\"ff\"").is_err());
+        assert!(bincode::deserialize::<BlockHeader>(&to_bytes![block.header].unwrap()[1..]).is_err());
+    }
+
+    #[test]
+    fn test_inclusion_proof_single_transaction() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(1, rng);
+
+        // The root of a single transaction is its id.
+        let root = transactions.to_merkle_root().unwrap();
+        assert_eq!(root, transactions[0].transaction_id().unwrap());
+
+        let proof = transactions.generate_inclusion_proof(0).unwrap();
+        assert!(proof.siblings.is_empty());
+        assert!(verify_inclusion_proof(&root, &proof));
+
+        assert!(matches!(
+            transactions.generate_inclusion_proof(1),
+            Err(TransactionError::InvalidTransactionIndex(1, 1))
+        ));
+        assert!(matches!(
+            Transactions::<TestTransaction>::new().to_merkle_root(),
+            Err(TransactionError::EmptyTransactions)
+        ));
+    }
+
+    #[test]
+    fn test_inclusion_proof_odd_number_of_transactions() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+
+        for num_transactions in &[3, 5, 7, 11] {
+            let transactions = random_transactions(*num_transactions, rng);
+            let transaction_ids = transactions.transaction_id_bytes().unwrap();
+
+            // The proofs verify against the root committed to by a block header.
+            let header = BlockHeader {
+                previous_block_hash: BlockHeaderHash::zero(),
+                merkle_root_hash: MerkleRootHash::new(merkle_root(&transaction_ids)),
+                pedersen_merkle_root_hash: PedersenMerkleRootHash::zero(),
+                proof: ProofOfSuccinctWork([0u8; ProofOfSuccinctWork::size()]),
+                time: 0,
+                difficulty_target: 0,
+                nonce: 0,
+            };
+            let header = BlockHeader::deserialize(&header.serialize());
+            assert_eq!(header.merkle_root_hash, transactions.to_merkle_root().unwrap());
+
+            for (index, transaction_id) in transaction_ids.iter().enumerate() {
+                let proof = transactions.generate_inclusion_proof(index).unwrap();
+                assert_eq!(&proof.leaf, transaction_id);
+                assert!(verify_inclusion_proof(&header.merkle_root_hash, &proof));
+            }
+
+            // The last transaction is paired with itself.
+            let proof = transactions.generate_inclusion_proof(num_transactions - 1).unwrap();
+            assert_eq!(proof.siblings[0], proof.leaf);
+        }
+    }
+
+    #[test]
+    fn test_transaction_ids_display_in_byte_order() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(4, rng);
+
+        // The ids are displayed as the hex of their bytes in order, without any reversal.
+        let transaction_ids = transactions.to_transaction_ids().unwrap();
+        for (transaction, transaction_id) in transactions.iter().zip_eq(&transaction_ids) {
+            let bytes = transaction.transaction_id().unwrap();
+            assert_eq!(*transaction_id, bytes);
+            assert_eq!(transaction_id.to_string(), hex::encode(bytes));
+            assert_eq!(transaction_id.to_string().parse::<TransactionId>().unwrap(), bytes);
+        }
+
+        // So is the hash of a block header, which is the double SHA-256 of the serialized header.
+        let header = BlockHeader {
+            previous_block_hash: BlockHeaderHash::zero(),
+            merkle_root_hash: transactions.to_merkle_root().unwrap(),
+            pedersen_merkle_root_hash: PedersenMerkleRootHash::zero(),
+            proof: ProofOfSuccinctWork([0u8; ProofOfSuccinctWork::size()]),
+            time: 0,
+            difficulty_target: 0,
+            nonce: 0,
+        };
+        let hash = snarkvm_algorithms::crh::double_sha256(&header.serialize());
+        assert_eq!(header.get_hash().to_string(), hex::encode(hash));
+    }
+
+    #[test]
+    fn test_tampered_inclusion_proof_fails() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(6, rng);
+        let root = transactions.to_merkle_root().unwrap();
+        let proof = transactions.generate_inclusion_proof(2).unwrap();
+        assert!(verify_inclusion_proof(&root, &proof));
+
+        let mut tampered = proof.clone();
+        tampered.siblings[1][0] ^= 1;
+        assert!(!verify_inclusion_proof(&root, &tampered));
+
+        let mut tampered = proof.clone();
+        tampered.leaf = transactions[3].transaction_id().unwrap();
+        assert!(!verify_inclusion_proof(&root, &tampered));
+
+        let mut tampered = proof.clone();
+        tampered.index = 3;
+        assert!(!verify_inclusion_proof(&root, &tampered));
+
+        // The same path under an index beyond the tree.
+        let mut tampered = proof.clone();
+        tampered.index += 1 << proof.siblings.len();
+        assert!(!verify_inclusion_proof(&root, &tampered));
+
+        let mut tampered = proof;
+        tampered.siblings.pop();
+        assert!(!verify_inclusion_proof(&root, &tampered));
+    }
+
+    #[test]
+    fn test_transactions_reader_matches_eager_read() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(20, rng);
+        let mut serialized = to_bytes![transactions].unwrap();
+        serialized.extend_from_slice(b"trailing");
+
+        let mut reader = TransactionsReader::<_, TestTransaction>::new(&serialized[..]).unwrap();
+        assert_eq!(reader.remaining(), 20);
+        let streamed = reader.by_ref().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(reader.into_inner(), b"trailing");
+
+        assert_eq!(Transactions(streamed), transactions);
+        assert_eq!(Transactions::read(&serialized[..]).unwrap(), transactions);
+
+        let limit = serialized.len() as u64 - 8;
+        assert_eq!(
+            Transactions::read_with_limit(&serialized[..], 20, limit).unwrap(),
+            transactions
+        );
+    }
+
+    #[test]
+    fn test_transactions_reader_truncated_stream() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(5, rng);
+        let serialized = to_bytes![transactions].unwrap();
+        let truncated = &serialized[..serialized.len() - 1];
+
+        // The first four transactions are read before the truncated one fails.
+        let results: Vec<_> = TransactionsReader::<_, TestTransaction>::new(truncated)
+            .unwrap()
+            .collect();
+        assert_eq!(results.len(), 5);
+        for (result, transaction) in results.iter().zip(transactions.iter()).take(4) {
+            assert_eq!(result.as_ref().unwrap(), transaction);
+        }
+        assert!(matches!(results[4], Err(TransactionError::IoError(_))));
+
+        assert!(Transactions::<TestTransaction>::read(truncated).is_err());
+        assert!(matches!(
+            Transactions::<TestTransaction>::read_with_limit(truncated, 5, u64::MAX),
+            Err(TransactionError::IoError(_))
+        ));
+        assert!(TransactionsReader::<_, TestTransaction>::new(&[][..]).is_err());
+    }
+
+    #[test]
+    fn test_transactions_reader_oversized_count() {
+        let serialized = variable_length_integer(1 << 60);
+
+        // Neither path allocates space for the claimed number of transactions.
+        let mut reader = TransactionsReader::<_, TestTransaction>::new(&serialized[..]).unwrap();
+        assert_eq!(reader.remaining(), 1 << 60);
+        assert_eq!(reader.size_hint(), (0, Some(1 << 60)));
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+
+        assert!(Transactions::<TestTransaction>::read(&serialized[..]).is_err());
+        assert!(matches!(
+            Transactions::<TestTransaction>::read_with_limit(&serialized[..], 1000, u64::MAX),
+            Err(TransactionError::TooManyTransactions(count, 1000)) if count == 1 << 60
+        ));
+    }
+
+    #[test]
+    fn test_read_with_limit_enforces_byte_limit() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+        let transactions = random_transactions(10, rng);
+        let serialized = to_bytes![transactions].unwrap();
+        let size = serialized.len() as u64;
+
+        assert_eq!(
+            Transactions::read_with_limit(&serialized[..], 10, size).unwrap(),
+            transactions
+        );
+        assert!(matches!(
+            Transactions::<TestTransaction>::read_with_limit(&serialized[..], 10, size - 1),
+            Err(TransactionError::TransactionsTooLarge(limit)) if limit == size - 1
+        ));
+        assert!(matches!(
+            Transactions::<TestTransaction>::read_with_limit(&serialized[..], 9, size),
+            Err(TransactionError::TooManyTransactions(10, 9))
+        ));
+    }
+}
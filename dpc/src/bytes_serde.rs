@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Serde helpers for types with a canonical `ToBytes` encoding.
+//!
+//! Human-readable formats use the hex string of the encoding, and binary formats use the raw bytes.
+//! Deserialization always goes through `FromBytes`, and rejects any trailing bytes.
+
+use snarkvm_utilities::bytes::{FromBytes, ToBytes};
+
+use serde::{
+    de::{Error as DeserializeError, SeqAccess, Visitor},
+    ser::Error as SerializeError,
+    Deserializer,
+    Serializer,
+};
+use std::fmt;
+
+/// Serializes `value` as the hex string of its `ToBytes` encoding, or as raw bytes.
+pub(crate) fn serialize<T: ToBytes, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut bytes = vec![];
+    value.write(&mut bytes).map_err(S::Error::custom)?;
+
+    match serializer.is_human_readable() {
+        true => serializer.serialize_str(&hex::encode(bytes)),
+        false => serializer.serialize_bytes(&bytes),
+    }
+}
+
+/// Deserializes a value from the hex string of its `ToBytes` encoding, or from raw bytes.
+pub(crate) fn deserialize<'de, T: FromBytes, D: Deserializer<'de>>(deserializer: D, name: &str) -> Result<T, D::Error> {
+    let bytes = match deserializer.is_human_readable() {
+        true => {
+            let string = <String as serde::Deserialize>::deserialize(deserializer)?;
+            hex::decode(string).map_err(D::Error::custom)?
+        }
+        false => deserializer.deserialize_byte_buf(BytesVisitor(name))?,
+    };
+
+    let mut reader = &bytes[..];
+    let value = T::read(&mut reader).map_err(|error| D::Error::custom(format!("invalid {}: {}", name, error)))?;
+    match reader.is_empty() {
+        true => Ok(value),
+        false => Err(D::Error::custom(format!(
+            "invalid {}: {} trailing bytes",
+            name,
+            reader.len()
+        ))),
+    }
+}
+
+/// Collects the raw bytes of a value, which binary formats may present as bytes or as a sequence.
+struct BytesVisitor<'a>(&'a str);
+
+impl<'de> Visitor<'de> for BytesVisitor<'_> {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the bytes of a {}", self.0)
+    }
+
+    fn visit_bytes<E: DeserializeError>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        Ok(bytes.to_vec())
+    }
+
+    fn visit_byte_buf<E: DeserializeError>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(bytes)
+    }
+
+    fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(1024));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
@@ -17,18 +17,25 @@
 use snarkvm_algorithms::errors::{CRHError, CommitmentError, EncryptionError, PRFError, SignatureError};
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum AccountError {
+    #[error("base58 error: {}", _0)]
+    Base58Error(#[source] Base58Error),
+
+    #[error("bech32 error: {}", _0)]
+    Bech32Error(#[from] bech32::Error),
+
     #[error("{}", _0)]
-    CommitmentError(CommitmentError),
+    CommitmentError(#[from] CommitmentError),
 
     #[error("{}", _0)]
-    CRHError(CRHError),
+    CRHError(#[from] CRHError),
 
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
     #[error("{}", _0)]
-    EncryptionError(EncryptionError),
+    EncryptionError(#[from] EncryptionError),
 
     #[error("invalid account commitment")]
     InvalidAccountCommitment,
@@ -48,60 +55,26 @@ pub enum AccountError {
     #[error("invalid account private key seed")]
     InvalidPrivateKeySeed,
 
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] std::io::Error),
+
     #[error("{}", _0)]
     Message(String),
 
     #[error("{}", _0)]
-    PRFError(PRFError),
+    PRFError(#[from] PRFError),
 
     #[error("{}", _0)]
-    SignatureError(SignatureError),
-}
-
-impl From<CommitmentError> for AccountError {
-    fn from(error: CommitmentError) -> Self {
-        AccountError::CommitmentError(error)
-    }
-}
-
-impl From<CRHError> for AccountError {
-    fn from(error: CRHError) -> Self {
-        AccountError::CRHError(error)
-    }
-}
-
-impl From<EncryptionError> for AccountError {
-    fn from(error: EncryptionError) -> Self {
-        AccountError::EncryptionError(error)
-    }
+    SignatureError(#[from] SignatureError),
 }
 
-impl From<PRFError> for AccountError {
-    fn from(error: PRFError) -> Self {
-        AccountError::PRFError(error)
-    }
-}
-
-impl From<SignatureError> for AccountError {
-    fn from(error: SignatureError) -> Self {
-        AccountError::SignatureError(error)
-    }
-}
+/// A `base58::FromBase58Error`, which does not implement `std::error::Error` itself.
+#[derive(Debug, Error)]
+#[error("{:?}", _0)]
+pub struct Base58Error(pub base58::FromBase58Error);
 
 impl From<base58::FromBase58Error> for AccountError {
     fn from(error: base58::FromBase58Error) -> Self {
-        AccountError::Crate("base58", format!("{:?}", error))
-    }
-}
-
-impl From<bech32::Error> for AccountError {
-    fn from(error: bech32::Error) -> Self {
-        AccountError::Crate("bech32", format!("{:?}", error))
-    }
-}
-
-impl From<std::io::Error> for AccountError {
-    fn from(error: std::io::Error) -> Self {
-        AccountError::Crate("std::io", format!("{:?}", error))
+        AccountError::Base58Error(Base58Error(error))
     }
 }
@@ -16,21 +16,69 @@
 
 use crate::errors::TransactionError;
 
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    io::{Error, ErrorKind},
+};
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum BlockError {
     #[error("block already exists {}", _0)]
     BlockExists(String),
 
+    #[error("the block exceeds the limit of {} bytes", _0)]
+    BlockTooLarge(u64),
+
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("the block proof has difficulty {}, which does not meet the target {}", _0, _1)]
+    DifficultyTargetNotMet(u64, u64),
+
+    #[error("the block contains the commitment {} more than once", _0)]
+    DuplicateCommitment(String),
+
+    #[error("the block contains the serial number {} more than once", _0)]
+    DuplicateSerialNumber(String),
+
+    #[error("invalid hex encoding: {}", _0)]
+    FromHexError(#[from] hex::FromHexError),
+
+    #[error("invalid hash of {} bytes, expected {} bytes", _0, _1)]
+    InvalidHashLength(usize, usize),
+
+    #[error(
+        "the block header has merkle root {}, but its transactions have merkle root {}",
+        _0,
+        _1
+    )]
+    InvalidMerkleRoot(String, String),
+
+    #[error(
+        "the block header has pedersen merkle root {}, but its transactions have pedersen merkle root {}",
+        _0,
+        _1
+    )]
+    InvalidPedersenMerkleRoot(String, String),
+
+    #[error("the block proof of succinct work is invalid")]
+    InvalidProofOfSuccinctWork,
+
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] Error),
+
     #[error("{}", _0)]
     Message(String),
 
+    #[error("the block frame has {} bytes left after its transactions", _0)]
+    TrailingBytes(usize),
+
     #[error("{}", _0)]
-    TransactionError(TransactionError),
+    TransactionError(#[from] TransactionError),
+
+    #[error("the proof of succinct work has unsupported version {}", _0)]
+    UnsupportedPoswVersion(u8),
 
     #[error("block number {} has not been mined yet", _0)]
     InvalidBlockNumber(u32),
@@ -42,14 +90,8 @@ pub enum BlockError {
     IrrelevantBlock(String),
 }
 
-impl From<std::io::Error> for BlockError {
-    fn from(error: std::io::Error) -> Self {
-        BlockError::Crate("std::io", format!("{:?}", error))
-    }
-}
-
-impl From<TransactionError> for BlockError {
-    fn from(error: TransactionError) -> Self {
-        BlockError::TransactionError(error)
+impl From<BlockError> for Error {
+    fn from(error: BlockError) -> Error {
+        Error::new(ErrorKind::InvalidData, error)
     }
 }
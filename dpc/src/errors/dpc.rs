@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::errors::{AccountError, LedgerError};
+use crate::errors::{AccountError, LedgerError, TransactionError};
 use snarkvm_algorithms::errors::{
     CRHError,
     CommitmentError,
@@ -25,124 +25,116 @@ use snarkvm_algorithms::errors::{
     SNARKError,
     SignatureError,
 };
+use snarkvm_fields::ConstraintFieldError;
 use snarkvm_parameters::errors::ParameterError;
+#[cfg(feature = "rng-recording")]
+use snarkvm_utilities::rand::RngRecording;
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum DPCError {
     #[error("{}", _0)]
-    AccountError(AccountError),
+    AccountError(#[from] AccountError),
 
     #[error("{}", _0)]
-    CommitmentError(CommitmentError),
+    CommitmentError(#[from] CommitmentError),
 
     #[error("{}", _0)]
-    CRHError(CRHError),
+    ConstraintFieldError(#[from] ConstraintFieldError),
+
+    #[error("{}", _0)]
+    CRHError(#[from] CRHError),
 
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
     #[error("{}", _0)]
-    EncodingError(EncodingError),
+    EncodingError(#[from] EncodingError),
 
     #[error("{}", _0)]
-    EncryptionError(EncryptionError),
+    EncryptionError(#[from] EncryptionError),
 
-    #[error("{}", _0)]
-    LedgerError(LedgerError),
+    #[error(
+        "the ledger membership witness for input record {} is invalid for the ledger digest",
+        _0
+    )]
+    InvalidLedgerWitness(usize),
 
-    #[error("{}", _0)]
-    MerkleError(MerkleError),
+    #[error("the program proof for the record at position {} does not verify", _0)]
+    InvalidProgramProof(u8),
 
-    #[error("{}", _0)]
-    Message(String),
+    #[error("input record {} has a commitment that does not match its contents", _0)]
+    InvalidRecordCommitment(usize),
 
-    #[error("missing inner snark proving parameters")]
-    MissingInnerSnarkProvingParameters,
+    #[error("the decrypted record does not have a valid record encoding")]
+    InvalidRecordEncoding,
 
-    #[error("missing outer snark proving parameters")]
-    MissingOuterSnarkProvingParameters,
+    #[error("input record {} is owned by {} but its account private key derives {}", _0, _1, _2)]
+    InvalidRecordOwner(usize, String, String),
 
-    #[error("{}", _0)]
-    ParameterError(ParameterError),
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] std::io::Error),
 
     #[error("{}", _0)]
-    PRFError(PRFError),
+    LedgerError(#[from] LedgerError),
 
     #[error("{}", _0)]
-    SignatureError(SignatureError),
+    MerkleError(#[from] MerkleError),
 
     #[error("{}", _0)]
-    SNARKError(SNARKError),
-}
+    Message(String),
 
-impl From<AccountError> for DPCError {
-    fn from(error: AccountError) -> Self {
-        DPCError::AccountError(error)
-    }
-}
+    #[error(
+        "output record {} has birth program id {} but its program execution is for {}",
+        _0,
+        _1,
+        _2
+    )]
+    MismatchedBirthProgramId(usize, String, String),
 
-impl From<CommitmentError> for DPCError {
-    fn from(error: CommitmentError) -> Self {
-        DPCError::CommitmentError(error)
-    }
-}
+    #[error(
+        "input record {} has death program id {} but its program execution is for {}",
+        _0,
+        _1,
+        _2
+    )]
+    MismatchedDeathProgramId(usize, String, String),
 
-impl From<CRHError> for DPCError {
-    fn from(error: CRHError) -> Self {
-        DPCError::CRHError(error)
-    }
-}
+    #[error("the decrypted record has commitment {} but the expected commitment is {}", _0, _1)]
+    MismatchedRecordCommitment(String, String),
 
-impl From<EncodingError> for DPCError {
-    fn from(error: EncodingError) -> Self {
-        DPCError::EncodingError(error)
-    }
-}
+    #[error("no valid randomizer for output record {} was derived within {} attempts", _0, _1)]
+    MissingDerivedRandomizer(usize, u32),
 
-impl From<EncryptionError> for DPCError {
-    fn from(error: EncryptionError) -> Self {
-        DPCError::EncryptionError(error)
-    }
-}
+    #[error("missing inner snark proving parameters")]
+    MissingInnerSnarkProvingParameters,
 
-impl From<LedgerError> for DPCError {
-    fn from(error: LedgerError) -> Self {
-        DPCError::LedgerError(error)
-    }
-}
+    #[error("missing outer snark proving parameters")]
+    MissingOuterSnarkProvingParameters,
 
-impl From<MerkleError> for DPCError {
-    fn from(error: MerkleError) -> Self {
-        DPCError::MerkleError(error)
-    }
-}
+    #[error("{}", _0)]
+    ParameterError(#[from] ParameterError),
 
-impl From<PRFError> for DPCError {
-    fn from(error: PRFError) -> Self {
-        DPCError::PRFError(error)
-    }
-}
+    #[error("{}", _0)]
+    PRFError(#[from] PRFError),
 
-impl From<SignatureError> for DPCError {
-    fn from(error: SignatureError) -> Self {
-        DPCError::SignatureError(error)
-    }
-}
+    #[error("{}", _0)]
+    SignatureError(#[from] SignatureError),
 
-impl From<ParameterError> for DPCError {
-    fn from(error: ParameterError) -> Self {
-        DPCError::ParameterError(error)
-    }
-}
+    #[error("{}", _0)]
+    SNARKError(#[from] SNARKError),
 
-impl From<SNARKError> for DPCError {
-    fn from(error: SNARKError) -> Self {
-        DPCError::SNARKError(error)
-    }
+    #[error("{}", _0)]
+    TransactionError(#[from] TransactionError),
 }
 
-impl From<std::io::Error> for DPCError {
-    fn from(error: std::io::Error) -> Self {
-        DPCError::Crate("std::io", format!("{:?}", error))
-    }
+/// A failed DPC execution, along with the randomness it consumed, so that the failing
+/// execution can be replayed with a `ReplayRng`.
+#[cfg(feature = "rng-recording")]
+#[derive(Debug, Error)]
+#[error("{}", error)]
+pub struct RecordedExecutionError {
+    #[source]
+    pub error: anyhow::Error,
+    pub randomness: RngRecording,
 }
@@ -18,6 +18,7 @@ use crate::errors::TransactionError;
 use snarkvm_algorithms::errors::MerkleError;
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum LedgerError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
@@ -31,36 +32,24 @@ pub enum LedgerError {
     #[error("database already exists")]
     ExistingDatabase,
 
+    #[error("invalid block height {}", _0)]
+    InvalidBlockHeight(u32),
+
     #[error("invalid cm pushed to ledger")]
     InvalidCm,
 
     #[error("invalid cm index during proving")]
     InvalidCmIndex,
 
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] std::io::Error),
+
     #[error("{}", _0)]
-    MerkleError(MerkleError),
+    MerkleError(#[from] MerkleError),
 
     #[error("{}", _0)]
     Message(String),
 
     #[error("{}", _0)]
-    TransactionError(TransactionError),
-}
-
-impl From<std::io::Error> for LedgerError {
-    fn from(error: std::io::Error) -> Self {
-        LedgerError::Crate("std::io", format!("{:?}", error))
-    }
-}
-
-impl From<MerkleError> for LedgerError {
-    fn from(error: MerkleError) -> Self {
-        LedgerError::MerkleError(error)
-    }
-}
-
-impl From<TransactionError> for LedgerError {
-    fn from(error: TransactionError) -> Self {
-        LedgerError::TransactionError(error)
-    }
+    TransactionError(#[from] TransactionError),
 }
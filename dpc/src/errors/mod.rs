@@ -34,3 +34,6 @@ pub use storage::*;
 
 pub mod transaction;
 pub use transaction::*;
+
+#[cfg(test)]
+mod tests;
@@ -19,6 +19,7 @@ use snarkvm_algorithms::{CRHError, CommitmentError, EncryptionError};
 use hex::FromHexError;
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum RecordError {
     #[error("Failed to build Record data type. See console logs for error")]
     BuilderError,
@@ -39,7 +40,7 @@ pub enum RecordError {
     DPCError(#[from] crate::DPCError),
 
     #[error("Attempted to set `value: {}` on a dummy record", _0)]
-    DummyMustBeZero(u64),
+    DummyMustBeZero(i64),
 
     #[error("{}", _0)]
     EncryptionError(#[from] EncryptionError),
@@ -50,18 +51,21 @@ pub enum RecordError {
     #[error("Attempted to build a record with an invalid commitment. Try `calculate_commitment()`")]
     InvalidCommitment,
 
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] std::io::Error),
+
     #[error("Missing Record field: {0}")]
     MissingField(String),
 
     #[error("Missing commitment randomness")]
     MissingRandomness,
 
+    #[error("Attempted to set the negative `value: {}` on a record", _0)]
+    NegativeValue(i64),
+
     #[error("Attempted to set `is_dummy: true` on a record with a non-zero value")]
     NonZeroValue,
-}
 
-impl From<std::io::Error> for RecordError {
-    fn from(error: std::io::Error) -> Self {
-        RecordError::Crate("std::io", format!("{:?}", error))
-    }
+    #[error("Attempted to set `value: {}` on a record, which exceeds the maximum amount", _0)]
+    ValueOutOfRange(u64),
 }
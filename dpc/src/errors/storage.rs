@@ -14,14 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::errors::{BlockError, TransactionError};
+use crate::errors::{BlockError, LedgerError, TransactionError};
 use snarkvm_algorithms::errors::MerkleError;
 use snarkvm_parameters::errors::ParameterError;
 
 use std::fmt::Debug;
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum StorageError {
+    #[error("bincode error: {}", _0)]
+    BincodeError(#[from] bincode::Error),
+
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
@@ -64,6 +68,9 @@ pub enum StorageError {
     #[error("missing transaction with id {}", _0)]
     InvalidTransactionId(String),
 
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] std::io::Error),
+
     #[error("{}", _0)]
     Message(String),
 
@@ -119,28 +126,19 @@ pub enum StorageError {
     NullError(()),
 
     #[error("{}", _0)]
-    BlockError(BlockError),
+    BlockError(#[from] BlockError),
 
     #[error("{}", _0)]
-    MerkleError(MerkleError),
+    LedgerError(#[from] LedgerError),
 
     #[error("{}", _0)]
-    ParameterError(ParameterError),
+    MerkleError(#[from] MerkleError),
 
     #[error("{}", _0)]
-    TransactionError(TransactionError),
-}
-
-impl From<bincode::Error> for StorageError {
-    fn from(error: bincode::Error) -> Self {
-        StorageError::Crate("bincode", error.to_string())
-    }
-}
+    ParameterError(#[from] ParameterError),
 
-impl From<std::io::Error> for StorageError {
-    fn from(error: std::io::Error) -> Self {
-        StorageError::Crate("std::io", format!("{:?}", error))
-    }
+    #[error("{}", _0)]
+    TransactionError(#[from] TransactionError),
 }
 
 impl From<()> for StorageError {
@@ -154,27 +152,3 @@ impl From<&'static str> for StorageError {
         StorageError::Message(msg.into())
     }
 }
-
-impl From<BlockError> for StorageError {
-    fn from(error: BlockError) -> Self {
-        StorageError::BlockError(error)
-    }
-}
-
-impl From<MerkleError> for StorageError {
-    fn from(error: MerkleError) -> Self {
-        StorageError::MerkleError(error)
-    }
-}
-
-impl From<ParameterError> for StorageError {
-    fn from(error: ParameterError) -> Self {
-        StorageError::ParameterError(error)
-    }
-}
-
-impl From<TransactionError> for StorageError {
-    fn from(error: TransactionError) -> Self {
-        StorageError::TransactionError(error)
-    }
-}
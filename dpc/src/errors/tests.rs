@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::{AccountError, Base58Error, DPCError, TransactionError};
+use snarkvm_algorithms::errors::{CRHError, SNARKError};
+use snarkvm_r1cs::errors::SynthesisError;
+
+use std::{
+    error::Error,
+    io::{self, ErrorKind},
+};
+
+fn unexpected_eof() -> io::Error {
+    io::Error::new(ErrorKind::UnexpectedEof, "failed to fill whole buffer")
+}
+
+#[test]
+fn test_io_error_source_chain_through_crh_error() {
+    let error = DPCError::from(CRHError::from(unexpected_eof()));
+    assert_eq!(error.to_string(), "I/O error: failed to fill whole buffer");
+
+    let crh_error = error.source().expect("DPCError should expose the CRHError");
+    assert!(matches!(
+        crh_error.downcast_ref::<CRHError>(),
+        Some(CRHError::IoError(_))
+    ));
+
+    let io_error = crh_error.source().expect("CRHError should expose the io::Error");
+    let io_error = io_error
+        .downcast_ref::<io::Error>()
+        .expect("source should be an io::Error");
+    assert_eq!(io_error.kind(), ErrorKind::UnexpectedEof);
+    assert!(io_error.source().is_none());
+}
+
+#[test]
+fn test_io_error_source_chain_through_snark_error() {
+    let error = DPCError::from(SNARKError::from(SynthesisError::from(unexpected_eof())));
+    assert_eq!(error.to_string(), "I/O error: failed to fill whole buffer");
+
+    let snark_error = error.source().unwrap();
+    assert!(snark_error.downcast_ref::<SNARKError>().is_some());
+
+    let synthesis_error = snark_error.source().unwrap();
+    assert!(matches!(
+        synthesis_error.downcast_ref::<SynthesisError>(),
+        Some(SynthesisError::IoError(_))
+    ));
+
+    let io_error = synthesis_error.source().unwrap();
+    assert_eq!(
+        io_error.downcast_ref::<io::Error>().unwrap().kind(),
+        ErrorKind::UnexpectedEof
+    );
+}
+
+#[test]
+fn test_conversion_into_io_error_preserves_source() {
+    let error = io::Error::from(CRHError::IncorrectInputLength(3, 4, 5));
+    assert_eq!(error.kind(), ErrorKind::Other);
+
+    let inner = error.get_ref().expect("io::Error should wrap the CRHError");
+    assert!(matches!(
+        inner.downcast_ref::<CRHError>(),
+        Some(CRHError::IncorrectInputLength(3, 4, 5))
+    ));
+}
+
+#[test]
+fn test_foreign_errors_are_preserved() {
+    let error = TransactionError::from("not a number".parse::<u64>().unwrap_err());
+    assert!(error.to_string().starts_with("invalid integer: "));
+    assert!(error.source().unwrap().is::<std::num::ParseIntError>());
+
+    let error = TransactionError::from("maybe".parse::<bool>().unwrap_err());
+    assert!(error.to_string().starts_with("invalid boolean: "));
+    assert!(error.source().unwrap().is::<std::str::ParseBoolError>());
+
+    let error = TransactionError::from(hex::decode("zz").unwrap_err());
+    assert!(error.to_string().starts_with("invalid hex encoding: "));
+    assert!(error.source().unwrap().is::<hex::FromHexError>());
+
+    let error = AccountError::from(base58::FromBase58Error::InvalidBase58Length);
+    assert_eq!(error.to_string(), "base58 error: InvalidBase58Length");
+    let source = error.source().unwrap().downcast_ref::<Base58Error>().unwrap();
+    assert_eq!(source.0, base58::FromBase58Error::InvalidBase58Length);
+}
+
+#[test]
+fn test_error_messages() {
+    assert_eq!(
+        SynthesisError::AssignmentMissing.to_string(),
+        "an assignment for a variable could not be computed"
+    );
+    assert_eq!(
+        DPCError::from(SNARKError::from(SynthesisError::Unsatisfiable)).to_string(),
+        "unsatisfiable constraint system"
+    );
+
+    let message = DPCError::InvalidRecordOwner(1, "aleo1a".into(), "aleo1b".into()).to_string();
+    assert!(message.contains("input record 1"));
+    assert!(message.contains("owned by aleo1a"));
+    assert!(message.contains("derives aleo1b"));
+
+    let message = DPCError::from(CRHError::IncorrectParameterSize(1, 2, 3, 4)).to_string();
+    assert_eq!(message, "incorrect parameter size 1x2 for window params 3x4");
+
+    assert!(DPCError::Message("custom".into()).source().is_none());
+}
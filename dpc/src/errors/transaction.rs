@@ -17,6 +17,7 @@
 use std::fmt::Debug;
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum TransactionError {
     #[error("UTXO has already been spent {:?} index: {:?}", _0, _1)]
     AlreadySpent(Vec<u8>, u32),
@@ -27,53 +28,95 @@ pub enum TransactionError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("the transaction contains duplicate commitments")]
+    DuplicateCommitments,
+
+    #[error("the transaction contains duplicate serial numbers")]
+    DuplicateSerialNumbers,
+
+    #[error("the list of transactions is empty")]
+    EmptyTransactions,
+
+    #[error("invalid hex encoding: {}", _0)]
+    FromHexError(#[from] hex::FromHexError),
+
     #[error("insufficient funds from input: {} to spend as output: {}", _0, _1)]
     InsufficientFunds(u64, u64),
 
     #[error("invalid coinbase transaction")]
     InvalidCoinbaseTransaction,
 
+    #[error("invalid inner circuit id of {} bytes", _0)]
+    InvalidInnerCircuitIdSize(usize),
+
+    #[error("invalid ledger digest {}", _0)]
+    InvalidLedgerDigest(String),
+
+    #[error("invalid network id {}, expected {}", _0, _1)]
+    InvalidNetworkId(u8, u8),
+
+    #[error("invalid number of commitments {}, expected {}", _0, _1)]
+    InvalidNumberOfCommitments(usize, usize),
+
+    #[error("invalid number of encrypted records {}, expected {}", _0, _1)]
+    InvalidNumberOfEncryptedRecords(usize, usize),
+
+    #[error("invalid number of serial numbers {}, expected {}", _0, _1)]
+    InvalidNumberOfSerialNumbers(usize, usize),
+
+    #[error("invalid number of signatures {}, expected {}", _0, _1)]
+    InvalidNumberOfSignatures(usize, usize),
+
+    #[error("invalid transaction proof of {} bytes", _0)]
+    InvalidProofSize(usize),
+
+    #[error("invalid transaction index {}, expected less than {}", _0, _1)]
+    InvalidTransactionIndex(usize, usize),
+
     #[error("invalid transaction id {:?}", _0)]
     InvalidTransactionId(usize),
 
+    #[error("invalid value balance {}", _0)]
+    InvalidValueBalance(i64),
+
     #[error("invalid variable size integer: {:?}", _0)]
     InvalidVariableSizeInteger(usize),
 
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] std::io::Error),
+
     #[error("{}", _0)]
     Message(String),
 
+    #[error("invalid boolean: {}", _0)]
+    ParseBoolError(#[from] std::str::ParseBoolError),
+
+    #[error("invalid integer: {}", _0)]
+    ParseIntError(#[from] std::num::ParseIntError),
+
     #[error("missing outpoint script public key")]
     MissingOutpointScriptPublicKey,
 
     #[error("the block has multiple coinbase transactions: {:?}", _0)]
     MultipleCoinbaseTransactions(u32),
 
+    #[error("the record value {} is negative", _0)]
+    NegativeRecordValue(i64),
+
     #[error("Null Error {:?}", _0)]
     NullError(()),
-}
 
-impl From<hex::FromHexError> for TransactionError {
-    fn from(error: hex::FromHexError) -> Self {
-        TransactionError::Crate("hex", format!("{:?}", error))
-    }
-}
+    #[error("the record value {} exceeds the maximum amount", _0)]
+    RecordValueOutOfRange(u64),
 
-impl From<std::io::Error> for TransactionError {
-    fn from(error: std::io::Error) -> Self {
-        TransactionError::Crate("std::io", format!("{:?}", error))
-    }
-}
+    #[error("invalid number of transactions {}, expected at most {}", _0, _1)]
+    TooManyTransactions(usize, usize),
 
-impl From<std::num::ParseIntError> for TransactionError {
-    fn from(error: std::num::ParseIntError) -> Self {
-        TransactionError::Crate("std::num", format!("{:?}", error))
-    }
-}
+    #[error("the transactions exceed the limit of {} bytes", _0)]
+    TransactionsTooLarge(u64),
 
-impl From<std::str::ParseBoolError> for TransactionError {
-    fn from(error: std::str::ParseBoolError) -> Self {
-        TransactionError::Crate("std::str", format!("{:?}", error))
-    }
+    #[error("the value balance overflows")]
+    ValueBalanceOverflow,
 }
 
 impl From<()> for TransactionError {
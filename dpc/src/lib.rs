@@ -70,6 +70,8 @@ pub use account::*;
 pub mod block;
 pub use block::*;
 
+mod bytes_serde;
+
 #[cfg(feature = "testnet1")]
 pub mod testnet1;
 
@@ -79,6 +81,22 @@ pub use errors::*;
 pub mod traits;
 pub use traits::*;
 
+/// Whether `snarkvm-dpc` was compiled with the `parallel` feature.
+pub const PARALLEL_ENABLED: bool = cfg!(feature = "parallel");
+
+/// Registers `snarkvm-dpc` and the crates it depends on.
+pub fn register_capabilities(capabilities: &mut snarkvm_utilities::Capabilities) {
+    capabilities.register(snarkvm_utilities::CrateCapabilities::new("snarkvm-dpc", &[
+        ("parallel", PARALLEL_ENABLED),
+        ("wasm", cfg!(feature = "wasm")),
+        ("print-trace", cfg!(feature = "print-trace")),
+        ("rng-recording", cfg!(feature = "rng-recording")),
+        ("testnet1", cfg!(feature = "testnet1")),
+    ]));
+    snarkvm_gadgets::register_capabilities(capabilities);
+    snarkvm_parameters::register_capabilities(capabilities);
+}
+
 #[cfg(test)]
 mod tests;
 
@@ -0,0 +1,666 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The constraint budget of each major component of the DPC circuits.
+//!
+//! Every component is synthesized in isolation from a minimal witness, mirroring the
+//! corresponding check in the inner or outer circuit. Witnesses that the full circuit
+//! declares once and shares across checks, such as the record contents, are allocated
+//! before counting. A change in any count is deliberate, and is made by updating the
+//! expected value below in the same change that causes it.
+
+use crate::{
+    account::{Account, AccountPrivateKey},
+    testnet1::{
+        instantiated::*,
+        payload::Payload,
+        record::{Record, RecordEncryption},
+        AleoAmount,
+        NoopCircuit,
+        ProgramProofVerificationGadget,
+        SystemParameters,
+        DPC,
+    },
+    traits::{AccountScheme, RecordScheme},
+};
+use snarkvm_algorithms::{
+    merkle_tree::MerkleTree,
+    traits::{CommitmentScheme, EncryptionScheme, MerkleParameters, SignatureScheme, CRH, SNARK},
+};
+use snarkvm_curves::{
+    edwards_bls12::EdwardsProjective as EdwardsBls,
+    traits::{AffineCurve, ProjectiveCurve},
+};
+use snarkvm_fields::ToConstraintField;
+use snarkvm_gadgets::{
+    algorithms::merkle_tree::merkle_path::MerklePathGadget,
+    bits::{Boolean, ToBytesGadget},
+    integers::uint::UInt8,
+    traits::{
+        algorithms::{
+            CRHGadget,
+            CommitmentGadget,
+            EncryptionGadget,
+            PRFGadget as _,
+            SNARKVerifierGadget,
+            SignaturePublicKeyRandomizationGadget,
+        },
+        alloc::{AllocBytesGadget, AllocGadget},
+        eq::EqGadget,
+        integers::integer::Integer,
+    },
+};
+use snarkvm_parameters::{testnet1::NoopProgramSNARKVKParameters, LedgerMerkleTreeParameters, Parameter};
+use snarkvm_r1cs::{assert_constraint_count, errors::SynthesisError, ConstraintSystem, TestConstraintSystem};
+use snarkvm_utilities::{
+    bits_to_bytes,
+    bytes::{FromBytes, ToBytes},
+    to_bytes,
+};
+
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use std::sync::Arc;
+
+/// The tolerance, in percent, of every constraint budget below.
+const TOLERANCE_PERCENT: usize = 1;
+
+/// Checks that the account private key opens to the record owner.
+const ACCOUNT_COMMITMENT_CONSTRAINTS: usize = 9746;
+/// Recomputes the record commitment from the record contents.
+const RECORD_COMMITMENT_CONSTRAINTS: usize = 8908;
+/// Derives the serial number of an input record from its nonce and the account private key.
+const SERIAL_NUMBER_CONSTRAINTS: usize = 22822;
+/// Encrypts an output record and checks the hash of its ciphertext.
+const ENCRYPTION_CONSTRAINTS: usize = 59111;
+/// Checks the ledger membership of a record commitment.
+const MERKLE_PATH_CONSTRAINTS: usize = 31259;
+/// Verifies one program proof in the outer field.
+const PROGRAM_PROOF_CONSTRAINTS: usize = 73208;
+
+/// A record and the account that owns it.
+struct Fixture {
+    system_parameters: SystemParameters<Components>,
+    account_private_key: AccountPrivateKey<Components>,
+    record: Record<Components>,
+}
+
+impl Fixture {
+    fn new<R: Rng>(rng: &mut R) -> Self {
+        let system_parameters = SystemParameters::<Components>::load().unwrap();
+        let account = Account::new(
+            &system_parameters.account_signature,
+            &system_parameters.account_commitment,
+            &system_parameters.account_encryption,
+            rng,
+        )
+        .unwrap();
+
+        let program_id = InstantiatedDPC::compute_program_id(
+            &system_parameters.program_verification_key_crh,
+            &NoopProgramSNARKVKParameters::load_bytes().unwrap(),
+        )
+        .unwrap();
+        let sn_nonce = SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &rng.gen::<[u8; 32]>()).unwrap();
+        let record = DPC::generate_record_with_amount(
+            &system_parameters,
+            sn_nonce,
+            account.address,
+            false,
+            AleoAmount(rng.gen_range(0..=i64::MAX)),
+            Payload::from_bytes(&rng.gen::<[u8; 32]>()),
+            program_id.clone(),
+            program_id,
+            rng,
+        )
+        .unwrap();
+
+        Self {
+            system_parameters,
+            account_private_key: account.private_key,
+            record,
+        }
+    }
+
+    fn record_owner<CS: ConstraintSystem<InnerField>>(
+        &self,
+        cs: CS,
+    ) -> <AccountEncryptionGadget as EncryptionGadget<AccountEncryption, InnerField>>::PublicKeyGadget {
+        AllocGadget::alloc(cs, || Ok(self.record.owner().into_repr())).unwrap()
+    }
+}
+
+#[test]
+fn test_account_commitment_constraint_budget() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let fixture = Fixture::new(rng);
+    let Fixture {
+        system_parameters,
+        account_private_key,
+        ..
+    } = &fixture;
+
+    let mut cs = TestConstraintSystem::<InnerField>::new();
+    let commitment_parameters =
+        <AccountCommitmentGadget as CommitmentGadget<AccountCommitment, InnerField>>::ParametersGadget::alloc_input(
+            cs.ns(|| "Declare account commitment parameters"),
+            || Ok(system_parameters.account_commitment.parameters()),
+        )
+        .unwrap();
+    let encryption_parameters =
+        <AccountEncryptionGadget as EncryptionGadget<AccountEncryption, InnerField>>::ParametersGadget::alloc_input(
+            cs.ns(|| "Declare account encryption parameters"),
+            || Ok(EncryptionScheme::parameters(&system_parameters.account_encryption)),
+        )
+        .unwrap();
+    let given_record_owner = fixture.record_owner(cs.ns(|| "given_record_owner"));
+
+    let before = cs.num_constraints();
+    {
+        let cs = &mut cs.ns(|| "Check account");
+
+        let pk_sig = <AccountSignatureGadget as SignaturePublicKeyRandomizationGadget<AccountSignature, InnerField>>::PublicKeyGadget::alloc(
+            cs.ns(|| "Declare pk_sig"),
+            || {
+                account_private_key
+                    .pk_sig(&system_parameters.account_signature)
+                    .map_err(|_| SynthesisError::AssignmentMissing)
+            },
+        )
+        .unwrap();
+        let sk_prf = PRFGadget::new_seed(cs.ns(|| "Declare sk_prf"), &account_private_key.sk_prf);
+        let r_pk =
+            <AccountCommitmentGadget as CommitmentGadget<AccountCommitment, InnerField>>::RandomnessGadget::alloc(
+                cs.ns(|| "Declare r_pk"),
+                || Ok(&account_private_key.r_pk),
+            )
+            .unwrap();
+
+        let mut account_view_key_input = pk_sig.to_bytes(cs.ns(|| "pk_sig to_bytes")).unwrap();
+        account_view_key_input.extend_from_slice(&sk_prf);
+        let candidate_account_commitment = AccountCommitmentGadget::check_commitment_gadget(
+            cs.ns(|| "Compute the account commitment"),
+            &commitment_parameters,
+            &account_view_key_input,
+            &r_pk,
+        )
+        .unwrap();
+
+        let given_account_view_key =
+            <AccountEncryptionGadget as EncryptionGadget<AccountEncryption, InnerField>>::PrivateKeyGadget::alloc(
+                cs.ns(|| "Allocate account view key"),
+                || {
+                    account_private_key
+                        .to_decryption_key(
+                            &system_parameters.account_signature,
+                            &system_parameters.account_commitment,
+                        )
+                        .map_err(|_| SynthesisError::AssignmentMissing)
+                },
+            )
+            .unwrap();
+        let given_account_view_key_bytes = given_account_view_key
+            .to_bytes(cs.ns(|| "given_account_view_key to_bytes"))
+            .unwrap();
+        candidate_account_commitment
+            .to_bytes(cs.ns(|| "candidate_account_commitment to_bytes"))
+            .unwrap()
+            .enforce_equal(
+                cs.ns(|| "Check that candidate and given account view keys are equal"),
+                &given_account_view_key_bytes,
+            )
+            .unwrap();
+
+        <AccountEncryptionGadget as EncryptionGadget<AccountEncryption, InnerField>>::check_public_key_gadget(
+            cs.ns(|| "Compute the candidate record owner"),
+            &encryption_parameters,
+            &given_account_view_key,
+        )
+        .unwrap()
+        .enforce_equal(
+            cs.ns(|| "Check that declared and computed addresses are equal"),
+            &given_record_owner,
+        )
+        .unwrap();
+    }
+    let after = cs.num_constraints();
+
+    assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
+    assert_constraint_count!(before, after, ACCOUNT_COMMITMENT_CONSTRAINTS, TOLERANCE_PERCENT);
+}
+
+#[test]
+fn test_record_commitment_constraint_budget() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let fixture = Fixture::new(rng);
+    let Fixture {
+        system_parameters,
+        record,
+        ..
+    } = &fixture;
+
+    let mut cs = TestConstraintSystem::<InnerField>::new();
+    let commitment_parameters =
+        <RecordCommitmentGadget as CommitmentGadget<RecordCommitment, InnerField>>::ParametersGadget::alloc_input(
+            cs.ns(|| "Declare record commitment parameters"),
+            || Ok(system_parameters.record_commitment.parameters()),
+        )
+        .unwrap();
+
+    // Declare the record contents.
+    let given_record_owner = fixture.record_owner(cs.ns(|| "given_record_owner"));
+    let given_commitment =
+        <RecordCommitmentGadget as CommitmentGadget<RecordCommitment, InnerField>>::OutputGadget::alloc(
+            cs.ns(|| "given_commitment"),
+            || Ok(record.commitment().into_inner()),
+        )
+        .unwrap();
+    let given_is_dummy = Boolean::alloc(cs.ns(|| "given_is_dummy"), || Ok(record.is_dummy())).unwrap();
+    let given_value = UInt8::alloc_vec(cs.ns(|| "given_value"), &to_bytes![record.value()].unwrap()).unwrap();
+    let given_payload = UInt8::alloc_vec(cs.ns(|| "given_payload"), &record.payload().to_bytes()).unwrap();
+    let given_birth_program_id =
+        UInt8::alloc_vec(cs.ns(|| "given_birth_program_id"), record.birth_program_id()).unwrap();
+    let given_death_program_id =
+        UInt8::alloc_vec(cs.ns(|| "given_death_program_id"), record.death_program_id()).unwrap();
+    let given_commitment_randomness =
+        <RecordCommitmentGadget as CommitmentGadget<RecordCommitment, InnerField>>::RandomnessGadget::alloc(
+            cs.ns(|| "given_commitment_randomness"),
+            || Ok(record.commitment_randomness()),
+        )
+        .unwrap();
+    let serial_number_nonce =
+        <SerialNumberNonceGadget as CRHGadget<SerialNumberNonce, InnerField>>::OutputGadget::alloc(
+            cs.ns(|| "serial_number_nonce"),
+            || Ok(record.serial_number_nonce()),
+        )
+        .unwrap();
+
+    let before = cs.num_constraints();
+    {
+        let cs = &mut cs.ns(|| "Check that record is well-formed");
+
+        let mut commitment_input = Vec::new();
+        commitment_input.extend_from_slice(
+            &given_record_owner
+                .to_bytes(cs.ns(|| "Convert record_owner to bytes"))
+                .unwrap(),
+        );
+        commitment_input.extend_from_slice(&given_is_dummy.to_bytes(cs.ns(|| "Convert is_dummy to bytes")).unwrap());
+        commitment_input.extend_from_slice(&given_value);
+        commitment_input.extend_from_slice(&given_payload);
+        commitment_input.extend_from_slice(&given_birth_program_id);
+        commitment_input.extend_from_slice(&given_death_program_id);
+        commitment_input.extend_from_slice(
+            &serial_number_nonce
+                .to_bytes(cs.ns(|| "Convert nonce to bytes"))
+                .unwrap(),
+        );
+
+        RecordCommitmentGadget::check_commitment_gadget(
+            cs.ns(|| "Compute commitment"),
+            &commitment_parameters,
+            &commitment_input,
+            &given_commitment_randomness,
+        )
+        .unwrap()
+        .enforce_equal(
+            cs.ns(|| "Check that declared and computed commitments are equal"),
+            &given_commitment,
+        )
+        .unwrap();
+    }
+    let after = cs.num_constraints();
+
+    assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
+    assert_constraint_count!(before, after, RECORD_COMMITMENT_CONSTRAINTS, TOLERANCE_PERCENT);
+}
+
+#[test]
+fn test_serial_number_constraint_budget() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let Fixture {
+        system_parameters,
+        account_private_key,
+        record,
+    } = Fixture::new(rng);
+    let (serial_number, _) = DPC::generate_sn(&system_parameters, &record, &account_private_key).unwrap();
+
+    let mut cs = TestConstraintSystem::<InnerField>::new();
+    let signature_parameters = <AccountSignatureGadget as SignaturePublicKeyRandomizationGadget<
+        AccountSignature,
+        InnerField,
+    >>::ParametersGadget::alloc_input(
+        cs.ns(|| "Declare account signature parameters"),
+        || Ok(system_parameters.account_signature.parameters()),
+    )
+    .unwrap();
+
+    // Declare the account and record contents shared with the other checks.
+    let pk_sig = <AccountSignatureGadget as SignaturePublicKeyRandomizationGadget<AccountSignature, InnerField>>::PublicKeyGadget::alloc(
+        cs.ns(|| "Declare pk_sig"),
+        || {
+                account_private_key
+                    .pk_sig(&system_parameters.account_signature)
+                    .map_err(|_| SynthesisError::AssignmentMissing)
+            },
+    )
+    .unwrap();
+    let sk_prf = PRFGadget::new_seed(cs.ns(|| "Declare sk_prf"), &account_private_key.sk_prf);
+    let serial_number_nonce =
+        <SerialNumberNonceGadget as CRHGadget<SerialNumberNonce, InnerField>>::OutputGadget::alloc(
+            cs.ns(|| "serial_number_nonce"),
+            || Ok(record.serial_number_nonce()),
+        )
+        .unwrap();
+
+    let before = cs.num_constraints();
+    {
+        let cs = &mut cs.ns(|| "Check that sn is derived correctly");
+
+        let serial_number_nonce_bytes = serial_number_nonce
+            .to_bytes(cs.ns(|| "Convert nonce to bytes"))
+            .unwrap();
+        let randomizer = PRFGadget::check_evaluation_gadget(
+            cs.ns(|| "Compute pk_sig randomizer"),
+            &sk_prf,
+            &serial_number_nonce_bytes,
+        )
+        .unwrap();
+        let randomizer_bytes = randomizer.to_bytes(cs.ns(|| "Convert randomizer to bytes")).unwrap();
+
+        let candidate_serial_number = AccountSignatureGadget::check_randomization_gadget(
+            cs.ns(|| "Compute serial number"),
+            &signature_parameters,
+            &pk_sig,
+            &randomizer_bytes,
+        )
+        .unwrap();
+        let given_serial_number = <AccountSignatureGadget as SignaturePublicKeyRandomizationGadget<
+            AccountSignature,
+            InnerField,
+        >>::PublicKeyGadget::alloc_input(
+            cs.ns(|| "Declare given serial number"), || Ok(&serial_number)
+        )
+        .unwrap();
+        candidate_serial_number
+            .enforce_equal(
+                cs.ns(|| "Check that given and computed serial numbers are equal"),
+                &given_serial_number,
+            )
+            .unwrap();
+    }
+    let after = cs.num_constraints();
+
+    assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
+    assert_constraint_count!(before, after, SERIAL_NUMBER_CONSTRAINTS, TOLERANCE_PERCENT);
+}
+
+/// Measures the encryption of an output record and the hash of its ciphertext. The packing
+/// of the record bits into field elements and the Elligator2 checks on their group encoding
+/// are interleaved with the record declaration in the inner circuit, and are not included.
+#[test]
+fn test_encryption_constraint_budget() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let fixture = Fixture::new(rng);
+    let Fixture {
+        system_parameters,
+        record,
+        ..
+    } = &fixture;
+
+    let (encryption_randomness, encrypted_record) =
+        RecordEncryption::encrypt_record(system_parameters, record, rng).unwrap();
+    let encrypted_record_hash = RecordEncryption::encrypted_record_hash(system_parameters, &encrypted_record).unwrap();
+    let components =
+        RecordEncryption::prepare_encryption_gadget_components(system_parameters, record, &encryption_randomness)
+            .unwrap();
+
+    let encryption_plaintext = components
+        .record_group_encoding
+        .iter()
+        .map(|(x, y)| {
+            let affine = <EdwardsBls as ProjectiveCurve>::Affine::read(&to_bytes![x, y].unwrap()[..]).unwrap();
+            <AccountEncryption as EncryptionScheme>::Text::read(&to_bytes![affine.into_projective()].unwrap()[..])
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let fq_high_selectors = &components.fq_high_selectors;
+    let selector_bytes = bits_to_bytes(
+        &[&components.ciphertext_selectors[..], &[
+            fq_high_selectors[fq_high_selectors.len() - 1]
+        ]]
+        .concat(),
+    );
+
+    let mut cs = TestConstraintSystem::<InnerField>::new();
+    let encryption_parameters =
+        <AccountEncryptionGadget as EncryptionGadget<AccountEncryption, InnerField>>::ParametersGadget::alloc_input(
+            cs.ns(|| "Declare account encryption parameters"),
+            || Ok(EncryptionScheme::parameters(&system_parameters.account_encryption)),
+        )
+        .unwrap();
+    let encrypted_record_crh_parameters =
+        <EncryptedRecordCRHGadget as CRHGadget<EncryptedRecordCRH, InnerField>>::ParametersGadget::alloc_input(
+            cs.ns(|| "Declare record ciphertext CRH parameters"),
+            || Ok(system_parameters.encrypted_record_crh.parameters()),
+        )
+        .unwrap();
+    let given_record_owner = fixture.record_owner(cs.ns(|| "given_record_owner"));
+
+    let before = cs.num_constraints();
+    {
+        let cs = &mut cs.ns(|| "Check that record encryption is well-formed");
+
+        let encryption_randomness =
+            <AccountEncryptionGadget as EncryptionGadget<AccountEncryption, InnerField>>::RandomnessGadget::alloc(
+                cs.ns(|| "encryption_randomness"),
+                || Ok(&encryption_randomness),
+            )
+            .unwrap();
+        let encryption_blinding_exponents = <AccountEncryptionGadget as EncryptionGadget<
+            AccountEncryption,
+            InnerField,
+        >>::BlindingExponentGadget::alloc(
+            cs.ns(|| "encryption_blinding_exponents"),
+            || Ok(&components.encryption_blinding_exponents),
+        )
+        .unwrap();
+        let encryption_plaintext =
+            <AccountEncryptionGadget as EncryptionGadget<AccountEncryption, InnerField>>::PlaintextGadget::alloc(
+                cs.ns(|| "encryption_plaintext"),
+                || Ok(&encryption_plaintext),
+            )
+            .unwrap();
+
+        let candidate_encrypted_record =
+            <AccountEncryptionGadget as EncryptionGadget<AccountEncryption, InnerField>>::check_encryption_gadget(
+                cs.ns(|| "check_encryption_gadget"),
+                &encryption_parameters,
+                &encryption_randomness,
+                &given_record_owner,
+                &encryption_plaintext,
+                &encryption_blinding_exponents,
+            )
+            .unwrap();
+
+        let given_encrypted_record_hash =
+            <EncryptedRecordCRHGadget as CRHGadget<EncryptedRecordCRH, InnerField>>::OutputGadget::alloc_input(
+                cs.ns(|| "encrypted record hash"),
+                || Ok(&encrypted_record_hash),
+            )
+            .unwrap();
+
+        let mut encrypted_record_hash_input = candidate_encrypted_record
+            .to_bytes(cs.ns(|| "encrypted record bytes"))
+            .unwrap();
+        encrypted_record_hash_input.extend_from_slice(
+            &UInt8::alloc_vec(cs.ns(|| "ciphertext and fq_high selector bytes"), &selector_bytes).unwrap(),
+        );
+
+        EncryptedRecordCRHGadget::check_evaluation_gadget(
+            cs.ns(|| "Compute encrypted record hash"),
+            &encrypted_record_crh_parameters,
+            encrypted_record_hash_input,
+        )
+        .unwrap()
+        .enforce_equal(cs.ns(|| "encrypted record hash is valid"), &given_encrypted_record_hash)
+        .unwrap();
+    }
+    let after = cs.num_constraints();
+
+    assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
+    assert_constraint_count!(before, after, ENCRYPTION_CONSTRAINTS, TOLERANCE_PERCENT);
+}
+
+#[test]
+fn test_merkle_path_constraint_budget() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let Fixture { record, .. } = Fixture::new(rng);
+
+    let crh_parameters =
+        <MerkleTreeCRH as CRH>::Parameters::read(&LedgerMerkleTreeParameters::load_bytes().unwrap()[..]).unwrap();
+    let ledger_parameters = CommitmentMerkleParameters::from(
+        <CommitmentMerkleParameters as MerkleParameters>::H::from(crh_parameters),
+    );
+    let commitment = record.commitment().into_inner();
+    let tree = MerkleTree::new(Arc::new(ledger_parameters.clone()), &[commitment.clone()]).unwrap();
+    let witness = tree.generate_proof(0, &commitment).unwrap();
+
+    let mut cs = TestConstraintSystem::<InnerField>::new();
+    let ledger_parameters =
+        <MerkleTreeCRHGadget as CRHGadget<MerkleTreeCRH, InnerField>>::ParametersGadget::alloc_input(
+            cs.ns(|| "Declare ledger parameters"),
+            || Ok(ledger_parameters.parameters()),
+        )
+        .unwrap();
+    let digest = <MerkleTreeCRHGadget as CRHGadget<MerkleTreeCRH, InnerField>>::OutputGadget::alloc_input(
+        cs.ns(|| "Declare ledger digest"),
+        || Ok(tree.root()),
+    )
+    .unwrap();
+    let given_commitment =
+        <RecordCommitmentGadget as CommitmentGadget<RecordCommitment, InnerField>>::OutputGadget::alloc(
+            cs.ns(|| "given_commitment"),
+            || Ok(&commitment),
+        )
+        .unwrap();
+    let given_is_dummy = Boolean::alloc(cs.ns(|| "given_is_dummy"), || Ok(false)).unwrap();
+
+    let before = cs.num_constraints();
+    {
+        let cs = &mut cs.ns(|| "Check ledger membership witness");
+
+        MerklePathGadget::<_, MerkleTreeCRHGadget, _>::alloc(cs.ns(|| "Declare membership witness"), || Ok(witness))
+            .unwrap()
+            .conditionally_check_membership(
+                cs.ns(|| "Perform ledger membership witness check"),
+                &ledger_parameters,
+                &digest,
+                &given_commitment,
+                &given_is_dummy.not(),
+            )
+            .unwrap();
+    }
+    let after = cs.num_constraints();
+
+    assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
+    assert_constraint_count!(before, after, MERKLE_PATH_CONSTRAINTS, TOLERANCE_PERCENT);
+}
+
+#[test]
+fn test_program_proof_constraint_budget() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let system_parameters = SystemParameters::<Components>::load().unwrap();
+    let noop_program_snark_parameters =
+        InstantiatedDPC::generate_noop_program_snark_parameters(&system_parameters, rng).unwrap();
+    let local_data_root = LocalDataCRH::hash(&system_parameters.local_data_crh, &rng.gen::<[u8; 32]>()).unwrap();
+
+    let position = 0;
+    let verification_key = to_bytes![noop_program_snark_parameters.verification_key].unwrap();
+    let proof = NoopProgramSNARK::<Components>::prove(
+        &noop_program_snark_parameters.proving_key,
+        &NoopCircuit::new(&system_parameters, &local_data_root, position),
+        rng,
+    )
+    .unwrap();
+    let proof = to_bytes![proof].unwrap();
+
+    let mut inputs = system_parameters
+        .local_data_commitment
+        .parameters()
+        .to_field_elements()
+        .unwrap();
+    inputs.extend(local_data_root.to_field_elements().unwrap());
+
+    let mut cs = TestConstraintSystem::<OuterField>::new();
+    let program_vk_crh_parameters =
+        <ProgramVerificationKeyCRHGadget as CRHGadget<_, OuterField>>::ParametersGadget::alloc_input(
+            cs.ns(|| "Declare program vk CRH parameters"),
+            || Ok(system_parameters.program_verification_key_crh.parameters()),
+        )
+        .unwrap();
+    let program_vk_commitment_parameters =
+        <ProgramVerificationKeyCommitmentGadget as CommitmentGadget<_, OuterField>>::ParametersGadget::alloc_input(
+            cs.ns(|| "Declare program vk commitment parameters"),
+            || Ok(system_parameters.program_verification_key_commitment.parameters()),
+        )
+        .unwrap();
+    let mut gadget =
+        ProgramProofVerificationGadget::<Components>::new(program_vk_crh_parameters, program_vk_commitment_parameters);
+    let program_input_bits = inputs
+        .iter()
+        .enumerate()
+        .map(|(i, input)| {
+            UInt8::alloc_input_vec_le(cs.ns(|| format!("Allocate input {}", i)), &to_bytes![input].unwrap())
+                .unwrap()
+                .iter()
+                .flat_map(|byte| byte.to_bits_le())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let before = cs.num_constraints();
+    {
+        let cs = &mut cs.ns(|| "Check program 0");
+
+        let verification_key =
+            <ProgramSNARKGadget as SNARKVerifierGadget<NoopProgramSNARK<Components>, OuterField>>::VerificationKeyGadget::alloc_bytes(
+                cs.ns(|| "Allocate verification key"),
+                || Ok(&verification_key),
+            )
+            .unwrap();
+        let proof =
+            <ProgramSNARKGadget as SNARKVerifierGadget<NoopProgramSNARK<Components>, OuterField>>::ProofGadget::alloc_bytes(
+                cs.ns(|| "Allocate proof"),
+                || Ok(&proof),
+            )
+            .unwrap();
+
+        gadget
+            .verify_program(
+                cs.ns(|| "Verify program"),
+                &verification_key,
+                &proof,
+                position,
+                &program_input_bits,
+            )
+            .unwrap();
+    }
+    let after = cs.num_constraints();
+
+    assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
+    assert_constraint_count!(before, after, PROGRAM_PROOF_CONSTRAINTS, TOLERANCE_PERCENT);
+}
@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::DPCError,
+    testnet1::{parameters::SystemParameters, record::Record, BaseDPCComponents},
+    traits::{DPCComponents, RecordScheme},
+};
+use snarkvm_algorithms::{
+    crh::PoseidonSponge,
+    traits::{AlgebraicSponge, EncryptionScheme, SignatureScheme},
+};
+use snarkvm_fields::ToConstraintField;
+use snarkvm_utilities::{to_bytes, FromBytes, ToBytes};
+
+/// The rate of the sponge from which randomizers are derived.
+pub const RANDOMIZER_SPONGE_RATE: usize = 2;
+
+/// The domain separator absorbed by the sponge before any other input.
+pub const RANDOMIZER_DOMAIN: &[u8] = b"AleoTransactionRandomizers";
+
+/// The number of candidates tried for each randomizer before the derivation gives up.
+pub const MAX_RANDOMIZER_ATTEMPTS: u32 = 1024;
+
+pub type RandomizerSponge<C> = PoseidonSponge<<C as DPCComponents>::InnerField, RANDOMIZER_SPONGE_RATE>;
+
+/// The use of a derived randomizer, absorbed alongside its index to separate the derivations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum RandomizerPurpose {
+    /// The randomness used to encrypt an output record.
+    RecordEncryption = 0,
+}
+
+/// Randomizers derived deterministically from a transaction kernel and an account secret.
+///
+/// The sponge absorbs the domain separator, the account secret, and the parts of the kernel
+/// that are fixed before the new records are encrypted: the old serial numbers, the new record
+/// commitments, the memorandum and the network id. Each randomizer is then squeezed from a copy
+/// of the sponge that additionally absorbs its purpose, its index and an attempt counter, which
+/// is incremented until the squeezed element is a valid randomizer.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "C: BaseDPCComponents"),
+    PartialEq(bound = "C: BaseDPCComponents"),
+    Eq(bound = "C: BaseDPCComponents"),
+    Debug(bound = "C: BaseDPCComponents")
+)]
+pub struct DerivedRandomizers<C: BaseDPCComponents> {
+    /// The encryption randomness of each output record.
+    pub encryption_randomness: Vec<<C::AccountEncryption as EncryptionScheme>::Randomness>,
+    /// The attempt at which each encryption randomness was found.
+    pub encryption_attempts: Vec<u32>,
+}
+
+impl<C: BaseDPCComponents> DerivedRandomizers<C> {
+    /// Derives the randomizers of a transaction spending `old_serial_numbers` and creating `new_records`.
+    pub fn derive(
+        system_parameters: &SystemParameters<C>,
+        account_secret: &[u8],
+        old_serial_numbers: &[<C::AccountSignature as SignatureScheme>::PublicKey],
+        new_records: &[Record<C>],
+        memorandum: &[u8; 32],
+        network_id: u8,
+    ) -> Result<Self, DPCError> {
+        let kernel = Self::kernel_bytes(old_serial_numbers, new_records, memorandum, network_id)?;
+        let sponge = Self::sponge(account_secret, &kernel)?;
+
+        let mut encryption_randomness = Vec::with_capacity(new_records.len());
+        let mut encryption_attempts = Vec::with_capacity(new_records.len());
+        for (j, record) in new_records.iter().enumerate() {
+            let public_key = record.owner().into_repr();
+
+            let (randomness, attempt) = (0..MAX_RANDOMIZER_ATTEMPTS)
+                .find_map(|attempt| {
+                    let candidate = Self::candidate(&sponge, RandomizerPurpose::RecordEncryption, j, attempt);
+                    let randomness =
+                        <C::AccountEncryption as EncryptionScheme>::Randomness::read(&to_bytes![candidate].ok()?[..])
+                            .ok()?;
+
+                    // The randomness must yield blinding exponents for the record owner.
+                    system_parameters
+                        .account_encryption
+                        .generate_blinding_exponents(public_key, &randomness, 1)
+                        .ok()?;
+
+                    Some((randomness, attempt))
+                })
+                .ok_or(DPCError::MissingDerivedRandomizer(j, MAX_RANDOMIZER_ATTEMPTS))?;
+
+            encryption_randomness.push(randomness);
+            encryption_attempts.push(attempt);
+        }
+
+        Ok(Self {
+            encryption_randomness,
+            encryption_attempts,
+        })
+    }
+
+    /// Returns the bytes of the kernel that the randomizers are bound to.
+    pub(crate) fn kernel_bytes(
+        old_serial_numbers: &[<C::AccountSignature as SignatureScheme>::PublicKey],
+        new_records: &[Record<C>],
+        memorandum: &[u8; 32],
+        network_id: u8,
+    ) -> Result<Vec<u8>, DPCError> {
+        let mut kernel = Vec::new();
+        for serial_number in old_serial_numbers {
+            kernel.extend_from_slice(&to_bytes![serial_number]?);
+        }
+        for record in new_records {
+            kernel.extend_from_slice(&to_bytes![record.commitment()]?);
+        }
+        kernel.extend_from_slice(memorandum);
+        kernel.push(network_id);
+        Ok(kernel)
+    }
+
+    /// Returns the sponge after absorbing the domain separator, the account secret and the kernel.
+    pub(crate) fn sponge(account_secret: &[u8], kernel: &[u8]) -> Result<RandomizerSponge<C>, DPCError> {
+        let mut sponge = RandomizerSponge::<C>::new();
+        sponge.absorb(&RANDOMIZER_DOMAIN.to_field_elements()?);
+        sponge.absorb(&account_secret.to_field_elements()?);
+        sponge.absorb(&kernel.to_field_elements()?);
+        Ok(sponge)
+    }
+
+    /// Returns the candidate for the randomizer with the given purpose and index at the given attempt.
+    pub(crate) fn candidate(
+        sponge: &RandomizerSponge<C>,
+        purpose: RandomizerPurpose,
+        index: usize,
+        attempt: u32,
+    ) -> C::InnerField {
+        let mut sponge = sponge.clone();
+        sponge.absorb(&[
+            C::InnerField::from(purpose as u8),
+            C::InnerField::from(index as u64),
+            C::InnerField::from(attempt),
+        ]);
+        sponge.squeeze(1)[0]
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::testnet1::derived_randomizers::{RandomizerPurpose, RANDOMIZER_DOMAIN, RANDOMIZER_SPONGE_RATE};
+use snarkvm_fields::{PoseidonMDSField, PrimeField};
+use snarkvm_gadgets::{
+    algorithms::crh::PoseidonSpongeGadget,
+    bits::ToBytesGadget,
+    fields::FpGadget,
+    integers::uint::UInt8,
+    traits::{algorithms::AlgebraicSpongeVar, alloc::AllocGadget, eq::EqGadget, fields::ToConstraintFieldGadget},
+};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+/// The in-circuit counterpart of `DerivedRandomizers`.
+#[derive(Clone)]
+pub struct DerivedRandomizersGadget<F: PrimeField + PoseidonMDSField> {
+    sponge: PoseidonSpongeGadget<F, RANDOMIZER_SPONGE_RATE>,
+}
+
+impl<F: PrimeField + PoseidonMDSField> DerivedRandomizersGadget<F> {
+    /// Absorbs the domain separator, the account secret and the kernel bytes, in the
+    /// same order and with the same packing as `DerivedRandomizers::derive`.
+    pub fn new<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        account_secret: &[UInt8],
+        kernel: &[UInt8],
+    ) -> Result<Self, SynthesisError> {
+        let domain = UInt8::constant_vec(RANDOMIZER_DOMAIN).to_constraint_field(cs.ns(|| "domain to field"))?;
+        let account_secret = account_secret.to_constraint_field(cs.ns(|| "account secret to field"))?;
+        let kernel = kernel.to_constraint_field(cs.ns(|| "kernel to field"))?;
+
+        let mut sponge = PoseidonSpongeGadget::new(cs.ns(|| "new sponge"));
+        sponge.absorb(cs.ns(|| "absorb domain"), &domain)?;
+        sponge.absorb(cs.ns(|| "absorb account secret"), &account_secret)?;
+        sponge.absorb(cs.ns(|| "absorb kernel"), &kernel)?;
+
+        Ok(Self { sponge })
+    }
+
+    /// Enforces that `randomness` is the encryption randomness of output record `index`,
+    /// squeezed at the attempt returned by `attempt`.
+    pub fn enforce_encryption_randomness<CS: ConstraintSystem<F>, R: ToBytesGadget<F>>(
+        &self,
+        mut cs: CS,
+        index: usize,
+        attempt: impl FnOnce() -> Result<u32, SynthesisError>,
+        randomness: &R,
+    ) -> Result<(), SynthesisError> {
+        let attempt = FpGadget::alloc(cs.ns(|| "attempt"), || attempt().map(F::from))?;
+
+        let mut sponge = self.sponge.clone();
+        sponge.absorb(cs.ns(|| "absorb purpose, index and attempt"), &[
+            FpGadget::Constant(F::from(RandomizerPurpose::RecordEncryption as u8)),
+            FpGadget::Constant(F::from(index as u64)),
+            attempt,
+        ])?;
+        let candidate = sponge.squeeze(cs.ns(|| "squeeze candidate"), 1)?;
+
+        let candidate_bytes = candidate[0].to_bytes_strict(cs.ns(|| "candidate to bytes"))?;
+        let randomness_bytes = randomness.to_bytes(cs.ns(|| "randomness to bytes"))?;
+        if candidate_bytes.len() < randomness_bytes.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        candidate_bytes[..randomness_bytes.len()].enforce_equal(
+            cs.ns(|| "Check that the randomness is the candidate"),
+            &randomness_bytes[..],
+        )
+    }
+}
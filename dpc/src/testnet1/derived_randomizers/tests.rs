@@ -0,0 +1,253 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+    account::{Account, AccountViewKey},
+    testnet1::{
+        instantiated::*,
+        payload::Payload,
+        record::RecordEncryption,
+        AleoAmount,
+        Record,
+        SystemParameters,
+        DPC,
+    },
+    traits::{AccountScheme, DPCComponents, RecordScheme},
+};
+use snarkvm_algorithms::traits::{EncryptionScheme, SignatureScheme, CRH};
+use snarkvm_gadgets::{
+    integers::uint::UInt8,
+    traits::{algorithms::EncryptionGadget, alloc::AllocGadget},
+};
+use snarkvm_parameters::{testnet1::NoopProgramSNARKVKParameters, Parameter};
+use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+use snarkvm_utilities::{to_bytes, ToBytes};
+
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+type RandomnessGadget = <AccountEncryptionGadget as EncryptionGadget<AccountEncryption, InnerField>>::RandomnessGadget;
+
+/// The inputs of a randomizer derivation for a transaction with two output records of one account.
+struct Fixture {
+    system_parameters: SystemParameters<Components>,
+    account: Account<Components>,
+    account_secret: Vec<u8>,
+    old_serial_numbers: Vec<<AccountSignature as SignatureScheme>::PublicKey>,
+    new_records: Vec<Record<Components>>,
+    memorandum: [u8; 32],
+    network_id: u8,
+}
+
+impl Fixture {
+    fn new<R: Rng>(rng: &mut R) -> Self {
+        let system_parameters = SystemParameters::<Components>::load().unwrap();
+        let account = Account::new(
+            &system_parameters.account_signature,
+            &system_parameters.account_commitment,
+            &system_parameters.account_encryption,
+            rng,
+        )
+        .unwrap();
+        let account_secret = to_bytes![account.private_key.sk_prf].unwrap();
+
+        let old_serial_numbers = (0..Components::NUM_INPUT_RECORDS)
+            .map(|_| {
+                let private_key = system_parameters.account_signature.generate_private_key(rng).unwrap();
+                system_parameters
+                    .account_signature
+                    .generate_public_key(&private_key)
+                    .unwrap()
+            })
+            .collect();
+
+        let program_id = InstantiatedDPC::compute_program_id(
+            &system_parameters.program_verification_key_crh,
+            &NoopProgramSNARKVKParameters::load_bytes().unwrap(),
+        )
+        .unwrap();
+        let new_records = (0..Components::NUM_OUTPUT_RECORDS)
+            .map(|_| {
+                let sn_nonce =
+                    SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &rng.gen::<[u8; 32]>()).unwrap();
+                DPC::generate_record_with_amount(
+                    &system_parameters,
+                    sn_nonce,
+                    account.address.clone(),
+                    false,
+                    AleoAmount(rng.gen_range(0..=i64::MAX)),
+                    Payload::from_bytes(&rng.gen::<[u8; 32]>()),
+                    program_id.clone(),
+                    program_id.clone(),
+                    rng,
+                )
+                .unwrap()
+            })
+            .collect();
+
+        Self {
+            system_parameters,
+            account,
+            account_secret,
+            old_serial_numbers,
+            new_records,
+            memorandum: rng.gen(),
+            network_id: 0,
+        }
+    }
+
+    fn derive(&self, account_secret: &[u8], memorandum: &[u8; 32]) -> DerivedRandomizers<Components> {
+        DerivedRandomizers::derive(
+            &self.system_parameters,
+            account_secret,
+            &self.old_serial_numbers,
+            &self.new_records,
+            memorandum,
+            self.network_id,
+        )
+        .unwrap()
+    }
+
+    /// Synthesizes the binding check of every output record against the given encryption randomness,
+    /// and returns whether the constraint system is satisfied.
+    fn check_encryption_randomness(
+        &self,
+        derived_randomizers: &DerivedRandomizers<Components>,
+        encryption_randomness: &[<AccountEncryption as EncryptionScheme>::Randomness],
+    ) -> bool {
+        let kernel = DerivedRandomizers::<Components>::kernel_bytes(
+            &self.old_serial_numbers,
+            &self.new_records,
+            &self.memorandum,
+            self.network_id,
+        )
+        .unwrap();
+
+        let mut cs = TestConstraintSystem::<InnerField>::new();
+        let account_secret = UInt8::alloc_vec(cs.ns(|| "account secret"), &self.account_secret).unwrap();
+        let kernel = UInt8::alloc_vec(cs.ns(|| "kernel"), &kernel).unwrap();
+        let gadget = DerivedRandomizersGadget::new(cs.ns(|| "derived randomizers"), &account_secret, &kernel).unwrap();
+
+        for (j, randomness) in encryption_randomness.iter().enumerate() {
+            let randomness = RandomnessGadget::alloc(cs.ns(|| format!("randomness {}", j)), || Ok(randomness)).unwrap();
+            gadget
+                .enforce_encryption_randomness(
+                    cs.ns(|| format!("enforce randomness {}", j)),
+                    j,
+                    || Ok(derived_randomizers.encryption_attempts[j]),
+                    &randomness,
+                )
+                .unwrap();
+        }
+
+        cs.is_satisfied()
+    }
+}
+
+#[test]
+fn test_derive_randomizers_is_deterministic() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let fixture = Fixture::new(rng);
+
+    let derived_randomizers = fixture.derive(&fixture.account_secret, &fixture.memorandum);
+    assert_eq!(
+        derived_randomizers,
+        fixture.derive(&fixture.account_secret, &fixture.memorandum)
+    );
+    assert_eq!(
+        derived_randomizers.encryption_randomness.len(),
+        Components::NUM_OUTPUT_RECORDS
+    );
+
+    // The randomizers depend on the account secret and on the kernel.
+    let other_secret = rng.gen::<[u8; 32]>();
+    assert_ne!(derived_randomizers, fixture.derive(&other_secret, &fixture.memorandum));
+    assert_ne!(derived_randomizers, fixture.derive(&fixture.account_secret, &rng.gen()));
+}
+
+#[test]
+fn test_derive_randomizers_is_independent_per_index() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let mut fixture = Fixture::new(rng);
+
+    // Identical output records still receive distinct randomness.
+    fixture.new_records[1] = fixture.new_records[0].clone();
+    let derived_randomizers = fixture.derive(&fixture.account_secret, &fixture.memorandum);
+    assert_ne!(
+        derived_randomizers.encryption_randomness[0],
+        derived_randomizers.encryption_randomness[1]
+    );
+
+    let sponge = DerivedRandomizers::<Components>::sponge(&fixture.account_secret, &[]).unwrap();
+    let candidate = |index, attempt| {
+        DerivedRandomizers::<Components>::candidate(&sponge, RandomizerPurpose::RecordEncryption, index, attempt)
+    };
+    assert_ne!(candidate(0, 0), candidate(1, 0));
+    assert_ne!(candidate(0, 0), candidate(0, 1));
+}
+
+#[test]
+fn test_derived_encryption_randomness_decrypts() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let fixture = Fixture::new(rng);
+    let derived_randomizers = fixture.derive(&fixture.account_secret, &fixture.memorandum);
+
+    let account_view_key = AccountViewKey::from_private_key(
+        &fixture.system_parameters.account_signature,
+        &fixture.system_parameters.account_commitment,
+        &fixture.account.private_key,
+    )
+    .unwrap();
+
+    for (record, randomness) in fixture
+        .new_records
+        .iter()
+        .zip(&derived_randomizers.encryption_randomness)
+    {
+        let encrypted_record =
+            RecordEncryption::encrypt_record_with_randomness(&fixture.system_parameters, record, randomness).unwrap();
+        let decrypted_record =
+            RecordEncryption::decrypt_record(&fixture.system_parameters, &account_view_key, &encrypted_record).unwrap();
+        assert_eq!(record.commitment(), decrypted_record.commitment());
+    }
+}
+
+#[test]
+fn test_derived_randomizers_gadget_matches_native() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let fixture = Fixture::new(rng);
+    let derived_randomizers = fixture.derive(&fixture.account_secret, &fixture.memorandum);
+
+    assert!(fixture.check_encryption_randomness(&derived_randomizers, &derived_randomizers.encryption_randomness));
+}
+
+#[test]
+fn test_reencrypted_record_is_rejected() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let fixture = Fixture::new(rng);
+    let derived_randomizers = fixture.derive(&fixture.account_secret, &fixture.memorandum);
+
+    // A relayer re-encrypting the first output record samples fresh randomness.
+    let mut encryption_randomness = derived_randomizers.encryption_randomness.clone();
+    encryption_randomness[0] = fixture
+        .system_parameters
+        .account_encryption
+        .generate_randomness(fixture.new_records[0].owner().into_repr(), rng)
+        .unwrap();
+
+    assert!(!fixture.check_encryption_randomness(&derived_randomizers, &encryption_randomness));
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::{Deserialize, Serialize};
+use std::{fmt, time::Duration};
+
+/// Timing and size metrics of one online execution of a transaction.
+///
+/// The durations are wall-clock durations of each phase of `execute_online`,
+/// and the sizes are the lengths in bytes of the serialized artifacts.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionReceipt {
+    /// The hex encoding of the ledger digest that the input records were proven against.
+    pub ledger_digest: String,
+
+    pub num_real_input_records: usize,
+    pub num_dummy_input_records: usize,
+    pub num_real_output_records: usize,
+    pub num_dummy_output_records: usize,
+
+    /// Generating the ledger membership witnesses of the input records.
+    pub ledger_witness_duration: Duration,
+    /// Signing and randomizing the signatures of the transaction contents.
+    pub signature_duration: Duration,
+    /// Preparing the record encryption witnesses of the output records.
+    pub encryption_witness_duration: Duration,
+    pub inner_proof_duration: Duration,
+    pub inner_proof_verification_duration: Duration,
+    pub outer_proof_duration: Duration,
+    pub total_duration: Duration,
+
+    pub inner_proof_size: usize,
+    pub transaction_proof_size: usize,
+    pub signatures_size: usize,
+    pub encrypted_records_size: usize,
+    pub transaction_size: usize,
+}
+
+impl fmt::Display for ExecutionReceipt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "inputs {}+{}d outputs {}+{}d",
+            self.num_real_input_records,
+            self.num_dummy_input_records,
+            self.num_real_output_records,
+            self.num_dummy_output_records,
+        )?;
+        write!(
+            f,
+            " | witness {:?} sign {:?} encrypt {:?} inner {:?} verify {:?} outer {:?} total {:?}",
+            self.ledger_witness_duration,
+            self.signature_duration,
+            self.encryption_witness_duration,
+            self.inner_proof_duration,
+            self.inner_proof_verification_duration,
+            self.outer_proof_duration,
+            self.total_duration,
+        )?;
+        write!(
+            f,
+            " | inner proof {}B tx proof {}B tx {}B | digest {}",
+            self.inner_proof_size, self.transaction_proof_size, self.transaction_size, self.ledger_digest,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn receipt() -> ExecutionReceipt {
+        ExecutionReceipt {
+            ledger_digest: "00ff".to_string(),
+            num_real_input_records: 1,
+            num_dummy_input_records: 1,
+            num_real_output_records: 2,
+            num_dummy_output_records: 0,
+            ledger_witness_duration: Duration::from_micros(12),
+            signature_duration: Duration::from_micros(340),
+            encryption_witness_duration: Duration::from_micros(56),
+            inner_proof_duration: Duration::from_millis(1200),
+            inner_proof_verification_duration: Duration::from_millis(7),
+            outer_proof_duration: Duration::from_millis(3400),
+            total_duration: Duration::from_millis(4700),
+            inner_proof_size: 193,
+            transaction_proof_size: 385,
+            signatures_size: 128,
+            encrypted_records_size: 1024,
+            transaction_size: 2048,
+        }
+    }
+
+    #[test]
+    fn test_execution_receipt_display() {
+        assert_eq!(
+            receipt().to_string(),
+            "inputs 1+1d outputs 2+0d \
+             | witness 12µs sign 340µs encrypt 56µs inner 1.2s verify 7ms outer 3.4s total 4.7s \
+             | inner proof 193B tx proof 385B tx 2048B | digest 00ff"
+        );
+    }
+
+    #[test]
+    fn test_execution_receipt_serde_json() {
+        let receipt = receipt();
+        let json = serde_json::to_string(&receipt).unwrap();
+        assert_eq!(receipt, serde_json::from_str(&json).unwrap());
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The canonical testnet1 genesis block, parsed once from the bytes embedded in
+//! `snarkvm-parameters`.
+
+use crate::{
+    block::{Block, BlockHeader},
+    testnet1::instantiated::Tx,
+    traits::TransactionScheme,
+};
+use snarkvm_parameters::{checksum, testnet1::GenesisBlock, Genesis};
+use snarkvm_utilities::bytes::FromBytes;
+
+use once_cell::sync::Lazy;
+
+/// The hash of the testnet1 genesis block header.
+pub const GENESIS_BLOCK_HEADER_HASH: &str = "d1cd52113e16e83cc19468533f4a721debf9dbd2e029774efda09c25947af319";
+
+static GENESIS_BLOCK: Lazy<Block<Tx>> = Lazy::new(|| {
+    let bytes = GenesisBlock::load_bytes();
+    assert_eq!(
+        checksum!(&bytes),
+        GenesisBlock::CHECKSUM,
+        "the embedded genesis block does not match its checksum"
+    );
+
+    Block::read(&bytes[..]).expect("failed to parse the embedded genesis block")
+});
+
+/// Returns the testnet1 genesis block.
+pub fn genesis_block() -> Block<Tx> {
+    GENESIS_BLOCK.clone()
+}
+
+/// Returns the header of the testnet1 genesis block.
+pub fn genesis_header() -> BlockHeader {
+    GENESIS_BLOCK.header.clone()
+}
+
+/// Returns the commitment of the first output record of the genesis transaction.
+pub fn genesis_record_commitment() -> <Tx as TransactionScheme>::Commitment {
+    GENESIS_BLOCK.transactions[0].new_commitments()[0].clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::{BlockHeaderHash, Network};
+
+    #[test]
+    fn test_genesis_block() {
+        let block = genesis_block();
+        block.verify_genesis().unwrap();
+        assert_eq!(block.header, genesis_header());
+        assert_eq!(block.header.previous_block_hash, BlockHeaderHash::zero());
+        assert_eq!(block.transactions.len(), 1);
+        assert_eq!(block.serialized_size() as u64, GenesisBlock::SIZE);
+        assert_eq!(
+            &genesis_record_commitment(),
+            &block.transactions[0].new_commitments()[0]
+        );
+    }
+
+    #[test]
+    fn test_genesis_block_header_hash() {
+        assert_eq!(genesis_header().get_hash().to_string(), GENESIS_BLOCK_HEADER_HASH);
+        assert_eq!(Network::Mainnet.genesis_hash(), Some(GENESIS_BLOCK_HEADER_HASH));
+        assert_eq!(Network::Testnet1.genesis_hash(), None);
+        assert_eq!(Network::Custom(7).genesis_hash(), None);
+    }
+
+    #[test]
+    fn test_genesis_transactions_belong_to_the_genesis_network() {
+        // A node on the network whose genesis block this is accepts the genesis transactions.
+        for transaction in genesis_block().transactions.iter() {
+            assert_eq!(transaction.network, Network::Mainnet);
+            transaction.fast_checks(Network::Mainnet).unwrap();
+        }
+    }
+}
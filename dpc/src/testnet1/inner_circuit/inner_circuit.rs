@@ -73,6 +73,10 @@ pub struct InnerCircuit<C: BaseDPCComponents> {
 }
 
 impl<C: BaseDPCComponents> InnerCircuit<C> {
+    /// Returns a circuit with placeholder values, for use in setup mode.
+    ///
+    /// The record encryption checks derive their allocations from concrete records,
+    /// so the witness is filled with default values rather than left unassigned.
     pub fn blank(system_parameters: &SystemParameters<C>, ledger_parameters: &Arc<C::MerkleParameters>) -> Self {
         let num_input_records = C::NUM_INPUT_RECORDS;
         let num_output_records = C::NUM_OUTPUT_RECORDS;
@@ -45,6 +45,7 @@ use snarkvm_utilities::{
 use crate::{
     account::AccountPrivateKey,
     testnet1::{
+        derived_randomizers::{DerivedRandomizers, DerivedRandomizersGadget},
         parameters::SystemParameters,
         record::Record,
         record_encryption::RecordEncryptionGadgetComponents,
@@ -348,6 +349,7 @@ where
     let mut old_serial_numbers_bytes_gadgets = Vec::with_capacity(old_records.len() * 32); // Serial numbers are 32 bytes
     let mut old_record_commitments_gadgets = Vec::with_capacity(old_records.len());
     let mut old_death_program_ids_gadgets = Vec::with_capacity(old_records.len());
+    let mut old_sk_prf_gadgets = Vec::with_capacity(old_records.len());
 
     for (i, (((record, witness), account_private_key), given_serial_number)) in old_records
         .iter()
@@ -384,7 +386,7 @@ where
 
             let given_commitment =
                 RecordCommitmentGadget::OutputGadget::alloc(&mut declare_cs.ns(|| "given_commitment"), || {
-                    Ok(record.commitment())
+                    Ok(record.commitment().into_inner())
                 })?;
             old_record_commitments_gadgets.push(given_commitment.clone());
 
@@ -547,6 +549,7 @@ where
 
             (sk_prf, pk_sig)
         };
+        old_sk_prf_gadgets.push(sk_prf.clone());
         // ********************************************************************
 
         // ********************************************************************
@@ -635,6 +638,7 @@ where
 
     let mut new_record_commitments_gadgets = Vec::with_capacity(new_records.len());
     let mut new_birth_program_ids_gadgets = Vec::with_capacity(new_records.len());
+    let mut new_encryption_randomness_gadgets = Vec::with_capacity(new_records.len());
 
     for (
         j,
@@ -683,7 +687,7 @@ where
 
             let given_record_commitment =
                 RecordCommitmentGadget::OutputGadget::alloc(&mut declare_cs.ns(|| "given_record_commitment"), || {
-                    Ok(record.commitment())
+                    Ok(record.commitment().into_inner())
                 })?;
             new_record_commitments_gadgets.push(given_record_commitment.clone());
 
@@ -1173,6 +1177,7 @@ where
                 &encryption_plaintext_gadget,
                 &encryption_blinding_exponents_gadget,
             )?;
+            new_encryption_randomness_gadgets.push(encryption_randomness_gadget);
 
             // *******************************************************************
             // Check that the encrypted record hash is correct
@@ -1257,12 +1262,11 @@ where
     // ********************************************************************
     // Check that the local data root is valid
     // ********************************************************************
+    let memo_gadget = UInt8::alloc_input_vec_le(cs.ns(|| "Allocate memorandum"), memo)?;
+    let network_id_gadget = UInt8::alloc_input_vec_le(cs.ns(|| "Allocate network id"), &[network_id])?;
     {
         let mut cs = cs.ns(|| "Check that local data root is valid.");
 
-        let memo = UInt8::alloc_input_vec_le(cs.ns(|| "Allocate memorandum"), memo)?;
-        let network_id = UInt8::alloc_input_vec_le(cs.ns(|| "Allocate network id"), &[network_id])?;
-
         let mut old_record_commitment_bytes = vec![];
         let mut input_bytes = vec![];
         for i in 0..C::NUM_INPUT_RECORDS {
@@ -1272,8 +1276,8 @@ where
             input_bytes.extend_from_slice(
                 &old_record_commitments_gadgets[i].to_bytes(&mut cs.ns(|| "old_record_commitment"))?,
             );
-            input_bytes.extend_from_slice(&memo);
-            input_bytes.extend_from_slice(&network_id);
+            input_bytes.extend_from_slice(&memo_gadget);
+            input_bytes.extend_from_slice(&network_id_gadget);
 
             let commitment_randomness = LocalDataCommitmentGadget::RandomnessGadget::alloc(
                 cs.ns(|| format!("Allocate old record local data commitment randomness {}", i)),
@@ -1301,8 +1305,8 @@ where
 
             input_bytes
                 .extend_from_slice(&new_record_commitments_gadgets[j].to_bytes(&mut cs.ns(|| "record_commitment"))?);
-            input_bytes.extend_from_slice(&memo);
-            input_bytes.extend_from_slice(&network_id);
+            input_bytes.extend_from_slice(&memo_gadget);
+            input_bytes.extend_from_slice(&network_id_gadget);
 
             let commitment_randomness = LocalDataCommitmentGadget::RandomnessGadget::alloc(
                 cs.ns(|| format!("Allocate new record local data commitment randomness {}", j)),
@@ -1359,6 +1363,53 @@ where
     }
     // *******************************************************************
 
+    // ********************************************************************
+    // Check that the encryption randomness is derived from the transaction kernel
+    // ********************************************************************
+    if C::BIND_ENCRYPTION_RANDOMNESS {
+        let mut cs = cs.ns(|| "Check that the encryption randomness is derived from the transaction kernel");
+
+        let mut kernel_bytes = old_serial_numbers_bytes_gadgets.clone();
+        for (j, commitment) in new_record_commitments_gadgets.iter().enumerate() {
+            kernel_bytes
+                .extend_from_slice(&commitment.to_bytes(&mut cs.ns(|| format!("new_record_commitment_{}", j)))?);
+        }
+        kernel_bytes.extend_from_slice(&memo_gadget);
+        kernel_bytes.extend_from_slice(&network_id_gadget);
+
+        // The randomness is bound to the secret of the first input account.
+        let derived_randomizers_gadget = DerivedRandomizersGadget::new(
+            cs.ns(|| "Absorb the transaction kernel"),
+            &old_sk_prf_gadgets[0],
+            &kernel_bytes,
+        )?;
+
+        // The attempts are witnesses, and are only computed when the prover assigns them.
+        let encryption_attempts = || {
+            let account_secret = to_bytes![old_account_private_keys[0].sk_prf]?;
+            let derived_randomizers = DerivedRandomizers::derive(
+                system_parameters,
+                &account_secret,
+                old_serial_numbers,
+                new_records,
+                memo,
+                network_id,
+            )
+            .map_err(|_| SynthesisError::AssignmentMissing)?;
+            Ok(derived_randomizers.encryption_attempts)
+        };
+
+        for (j, encryption_randomness_gadget) in new_encryption_randomness_gadgets.iter().enumerate() {
+            derived_randomizers_gadget.enforce_encryption_randomness(
+                cs.ns(|| format!("Check the encryption randomness of output record {}", j)),
+                j,
+                || encryption_attempts().map(|attempts| attempts[j]),
+                encryption_randomness_gadget,
+            )?;
+        }
+    }
+    // ********************************************************************
+
     // *******************************************************************
     // Check that the value balance is valid
     // *******************************************************************
@@ -1370,7 +1421,7 @@ where
         let mut candidate_value_balance = Int64::zero();
 
         for (i, old_record) in old_records.iter().enumerate() {
-            let value = old_record.value as i64;
+            let value = old_record.value().0;
             let record_value = Int64::alloc(cs.ns(|| format!("old record {} value", i)), || Ok(value))?;
 
             candidate_value_balance = candidate_value_balance
@@ -1379,7 +1430,7 @@ where
         }
 
         for (j, new_record) in new_records.iter().enumerate() {
-            let value = new_record.value as i64;
+            let value = new_record.value().0;
             let record_value = Int64::alloc(cs.ns(|| format!("new record {} value", j)), || Ok(value))?;
 
             candidate_value_balance = candidate_value_balance
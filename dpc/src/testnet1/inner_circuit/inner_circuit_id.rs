@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::TransactionError, testnet1::BaseDPCComponents};
+use snarkvm_algorithms::traits::CRH;
+use snarkvm_utilities::{
+    bytes::{FromBytes, ToBytes},
+    to_bytes,
+};
+
+use std::{
+    fmt,
+    io::{Read, Result as IoResult, Write},
+    str::FromStr,
+};
+
+/// The ID of the inner circuit, which is the hash of the inner SNARK verifying key.
+///
+/// The outer circuit recomputes this hash from the verifying key it is given,
+/// so every transaction commits to the inner circuit it was proven with.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "C: BaseDPCComponents"),
+    Copy(bound = "C: BaseDPCComponents"),
+    Default(bound = "C: BaseDPCComponents"),
+    PartialEq(bound = "C: BaseDPCComponents"),
+    Eq(bound = "C: BaseDPCComponents"),
+    Hash(bound = "C: BaseDPCComponents")
+)]
+pub struct InnerCircuitId<C: BaseDPCComponents>(<C::InnerCircuitIDCRH as CRH>::Output);
+
+impl<C: BaseDPCComponents> InnerCircuitId<C> {
+    /// Wraps the given inner circuit ID CRH output as an inner circuit ID.
+    pub fn new(inner_circuit_id: <C::InnerCircuitIDCRH as CRH>::Output) -> Self {
+        Self(inner_circuit_id)
+    }
+
+    /// Returns a reference to the underlying CRH output.
+    pub fn inner(&self) -> &<C::InnerCircuitIDCRH as CRH>::Output {
+        &self.0
+    }
+
+    /// Returns the underlying CRH output.
+    pub fn into_inner(self) -> <C::InnerCircuitIDCRH as CRH>::Output {
+        self.0
+    }
+
+    /// Returns the inner circuit ID as bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        to_bytes![self.0].expect("failed to serialize the inner circuit id")
+    }
+}
+
+impl<C: BaseDPCComponents> ToBytes for InnerCircuitId<C> {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write(writer)
+    }
+}
+
+impl<C: BaseDPCComponents> FromBytes for InnerCircuitId<C> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Self(FromBytes::read(reader)?))
+    }
+}
+
+impl<C: BaseDPCComponents> FromStr for InnerCircuitId<C> {
+    type Err = TransactionError;
+
+    fn from_str(inner_circuit_id: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode(inner_circuit_id)?;
+        let inner_circuit_id = Self::read(&bytes[..])?;
+
+        // Reject trailing bytes, so that every inner circuit ID has exactly one string encoding.
+        if inner_circuit_id.to_bytes().len() != bytes.len() {
+            return Err(TransactionError::InvalidInnerCircuitIdSize(bytes.len()));
+        }
+
+        Ok(inner_circuit_id)
+    }
+}
+
+impl<C: BaseDPCComponents> fmt::Display for InnerCircuitId<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", hex::encode(self.to_bytes()))
+    }
+}
+
+impl<C: BaseDPCComponents> fmt::Debug for InnerCircuitId<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "InnerCircuitId({})", self)
+    }
+}
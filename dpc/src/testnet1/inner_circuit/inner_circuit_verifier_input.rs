@@ -14,7 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::testnet1::{parameters::SystemParameters, AleoAmount, BaseDPCComponents};
+use crate::testnet1::{
+    input_layout::{InputLayout, PublicInputs},
+    parameters::SystemParameters,
+    AleoAmount,
+    BaseDPCComponents,
+};
 use snarkvm_algorithms::{
     merkle_tree::MerkleTreeDigest,
     traits::{CommitmentScheme, EncryptionScheme, MerkleParameters, SignatureScheme, CRH},
@@ -51,7 +56,7 @@ pub struct InnerCircuitVerifierInput<C: BaseDPCComponents> {
     pub network_id: u8,
 }
 
-impl<C: BaseDPCComponents> ToConstraintField<C::InnerField> for InnerCircuitVerifierInput<C>
+impl<C: BaseDPCComponents> InnerCircuitVerifierInput<C>
 where
     <C::AccountCommitment as CommitmentScheme>::Parameters: ToConstraintField<C::InnerField>,
     <C::AccountCommitment as CommitmentScheme>::Output: ToConstraintField<C::InnerField>,
@@ -78,79 +83,102 @@ where
     <<C::MerkleParameters as MerkleParameters>::H as CRH>::Parameters: ToConstraintField<C::InnerField>,
     MerkleTreeDigest<C::MerkleParameters>: ToConstraintField<C::InnerField>,
 {
-    fn to_field_elements(&self) -> Result<Vec<C::InnerField>, ConstraintFieldError> {
-        let mut v = Vec::new();
-
-        v.extend_from_slice(
-            &self
-                .system_parameters
-                .account_commitment
-                .parameters()
-                .to_field_elements()?,
-        );
-        v.extend_from_slice(
-            &<C::AccountEncryption as EncryptionScheme>::parameters(&self.system_parameters.account_encryption)
-                .to_field_elements()?,
-        );
-        v.extend_from_slice(
-            &self
-                .system_parameters
-                .account_signature
-                .parameters()
-                .to_field_elements()?,
-        );
-        v.extend_from_slice(
-            &self
-                .system_parameters
-                .record_commitment
-                .parameters()
-                .to_field_elements()?,
-        );
-        v.extend_from_slice(
-            &self
-                .system_parameters
-                .encrypted_record_crh
-                .parameters()
-                .to_field_elements()?,
-        );
-        v.extend_from_slice(
-            &self
-                .system_parameters
-                .program_verification_key_commitment
-                .parameters()
-                .to_field_elements()?,
-        );
-        v.extend_from_slice(&self.system_parameters.local_data_crh.parameters().to_field_elements()?);
-        v.extend_from_slice(
-            &self
-                .system_parameters
-                .serial_number_nonce
-                .parameters()
-                .to_field_elements()?,
-        );
-
-        v.extend_from_slice(&self.ledger_parameters.parameters().to_field_elements()?);
-        v.extend_from_slice(&self.ledger_digest.to_field_elements()?);
-
-        for sn in &self.old_serial_numbers {
-            v.extend_from_slice(&sn.to_field_elements()?);
+    /// Returns the layout of the public input of the inner circuit.
+    pub fn describe_inputs(&self) -> Result<InputLayout, ConstraintFieldError> {
+        Ok(self.public_inputs()?.into_layout())
+    }
+
+    /// Returns the public input of the inner circuit, in the order in which the inner circuit
+    /// allocates it.
+    pub(crate) fn public_inputs(&self) -> Result<PublicInputs<C::InnerField>, ConstraintFieldError> {
+        let parameters = &self.system_parameters;
+        let mut inputs = PublicInputs::new();
+
+        inputs.push(
+            "account_commitment_parameters",
+            parameters.account_commitment.parameters(),
+        )?;
+        inputs.push(
+            "account_encryption_parameters",
+            <C::AccountEncryption as EncryptionScheme>::parameters(&parameters.account_encryption),
+        )?;
+        inputs.push(
+            "account_signature_parameters",
+            parameters.account_signature.parameters(),
+        )?;
+        inputs.push(
+            "record_commitment_parameters",
+            parameters.record_commitment.parameters(),
+        )?;
+        inputs.push(
+            "encrypted_record_crh_parameters",
+            parameters.encrypted_record_crh.parameters(),
+        )?;
+        inputs.push(
+            "program_verification_key_commitment_parameters",
+            parameters.program_verification_key_commitment.parameters(),
+        )?;
+        inputs.push("local_data_crh_parameters", parameters.local_data_crh.parameters())?;
+        inputs.push(
+            "serial_number_nonce_parameters",
+            parameters.serial_number_nonce.parameters(),
+        )?;
+
+        inputs.push("ledger_parameters", self.ledger_parameters.parameters())?;
+        inputs.push("ledger_digest", &self.ledger_digest)?;
+
+        for (i, sn) in self.old_serial_numbers.iter().enumerate() {
+            inputs.push(format!("old_serial_number_{}", i), sn)?;
         }
 
-        for (cm, encrypted_record_hash) in self.new_commitments.iter().zip(&self.new_encrypted_record_hashes) {
-            v.extend_from_slice(&cm.to_field_elements()?);
-            v.extend_from_slice(&encrypted_record_hash.to_field_elements()?);
+        for (i, (cm, encrypted_record_hash)) in self
+            .new_commitments
+            .iter()
+            .zip(&self.new_encrypted_record_hashes)
+            .enumerate()
+        {
+            inputs.push(format!("new_commitment_{}", i), cm)?;
+            inputs.push(format!("new_encrypted_record_hash_{}", i), encrypted_record_hash)?;
         }
 
-        v.extend_from_slice(&self.program_commitment.to_field_elements()?);
-        v.extend_from_slice(&ToConstraintField::<C::InnerField>::to_field_elements(&self.memo)?);
-        v.extend_from_slice(&ToConstraintField::<C::InnerField>::to_field_elements(
-            &[self.network_id][..],
-        )?);
-        v.extend_from_slice(&self.local_data_root.to_field_elements()?);
-
-        v.extend_from_slice(&ToConstraintField::<C::InnerField>::to_field_elements(
-            &self.value_balance.0.to_le_bytes()[..],
-        )?);
-        Ok(v)
+        inputs.push("program_commitment", &self.program_commitment)?;
+        inputs.push_bytes("memo", &self.memo)?;
+        inputs.push_bytes("network_id", &[self.network_id])?;
+        inputs.push("local_data_root", &self.local_data_root)?;
+        inputs.push_bytes("value_balance", &self.value_balance.0.to_le_bytes())?;
+
+        Ok(inputs)
+    }
+}
+
+impl<C: BaseDPCComponents> ToConstraintField<C::InnerField> for InnerCircuitVerifierInput<C>
+where
+    <C::AccountCommitment as CommitmentScheme>::Parameters: ToConstraintField<C::InnerField>,
+    <C::AccountCommitment as CommitmentScheme>::Output: ToConstraintField<C::InnerField>,
+
+    <C::AccountEncryption as EncryptionScheme>::Parameters: ToConstraintField<C::InnerField>,
+
+    <C::AccountSignature as SignatureScheme>::Parameters: ToConstraintField<C::InnerField>,
+    <C::AccountSignature as SignatureScheme>::PublicKey: ToConstraintField<C::InnerField>,
+
+    <C::RecordCommitment as CommitmentScheme>::Parameters: ToConstraintField<C::InnerField>,
+    <C::RecordCommitment as CommitmentScheme>::Output: ToConstraintField<C::InnerField>,
+
+    <C::EncryptedRecordCRH as CRH>::Parameters: ToConstraintField<C::InnerField>,
+    <C::EncryptedRecordCRH as CRH>::Output: ToConstraintField<C::InnerField>,
+
+    <C::SerialNumberNonceCRH as CRH>::Parameters: ToConstraintField<C::InnerField>,
+
+    <C::ProgramVerificationKeyCommitment as CommitmentScheme>::Parameters: ToConstraintField<C::InnerField>,
+    <C::ProgramVerificationKeyCommitment as CommitmentScheme>::Output: ToConstraintField<C::InnerField>,
+
+    <C::LocalDataCRH as CRH>::Parameters: ToConstraintField<C::InnerField>,
+    <C::LocalDataCRH as CRH>::Output: ToConstraintField<C::InnerField>,
+
+    <<C::MerkleParameters as MerkleParameters>::H as CRH>::Parameters: ToConstraintField<C::InnerField>,
+    MerkleTreeDigest<C::MerkleParameters>: ToConstraintField<C::InnerField>,
+{
+    fn to_field_elements(&self) -> Result<Vec<C::InnerField>, ConstraintFieldError> {
+        Ok(self.public_inputs()?.into_field_elements())
     }
 }
@@ -20,5 +20,8 @@ pub use inner_circuit::*;
 pub mod inner_circuit_gadget;
 pub use inner_circuit_gadget::*;
 
+pub mod inner_circuit_id;
+pub use inner_circuit_id::*;
+
 pub mod inner_circuit_verifier_input;
 pub use inner_circuit_verifier_input::*;
@@ -0,0 +1,331 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{ConstraintFieldError, PrimeField, ToConstraintField};
+
+use std::fmt;
+
+/// How the value of an input segment is encoded into field elements.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    /// `len` bytes, packed little-endian into field elements, as many whole bytes per element
+    /// as fit in the capacity of the field.
+    PackedBytes { len: usize },
+    /// The native field elements of the value, such as the coordinates of a group element.
+    FieldElement,
+    /// A single bit, as a field element that is zero or one.
+    Boolean,
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Encoding::PackedBytes { len } => write!(f, "packed bytes ({})", len),
+            Encoding::FieldElement => write!(f, "field elements"),
+            Encoding::Boolean => write!(f, "boolean"),
+        }
+    }
+}
+
+/// A named run of consecutive public input field elements.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputSegment {
+    pub name: String,
+    pub num_field_elements: usize,
+    pub encoding: Encoding,
+}
+
+/// The ordered segments of the public input of a circuit.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InputLayout {
+    segments: Vec<InputSegment>,
+}
+
+impl InputLayout {
+    /// Returns the segments, in the order of the public input.
+    pub fn segments(&self) -> &[InputSegment] {
+        &self.segments
+    }
+
+    /// Returns the total number of public input field elements.
+    pub fn num_field_elements(&self) -> usize {
+        self.segments.iter().map(|segment| segment.num_field_elements).sum()
+    }
+
+    /// Returns the segment that contains the field element at the given index of the public
+    /// input, and the index of the field element within the segment.
+    pub fn segment_of(&self, index: usize) -> Option<(&InputSegment, usize)> {
+        let mut offset = 0;
+        for segment in &self.segments {
+            if index < offset + segment.num_field_elements {
+                return Some((segment, index - offset));
+            }
+            offset += segment.num_field_elements;
+        }
+        None
+    }
+}
+
+/// Prints the layout as a table of offsets, sizes, encodings, and names.
+impl fmt::Display for InputLayout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{:>6}  {:>6}  {:<20}  name", "offset", "count", "encoding")?;
+
+        let mut offset = 0;
+        for segment in &self.segments {
+            writeln!(
+                f,
+                "{:>6}  {:>6}  {:<20}  {}",
+                offset,
+                segment.num_field_elements,
+                segment.encoding.to_string(),
+                segment.name
+            )?;
+            offset += segment.num_field_elements;
+        }
+
+        write!(f, "{:>6}  {:>6}  total", "", offset)
+    }
+}
+
+/// The public input field elements of a circuit, built together with their layout, so that
+/// the layout always describes the field elements that are passed to the verifier.
+pub(crate) struct PublicInputs<F: PrimeField> {
+    field_elements: Vec<F>,
+    layout: InputLayout,
+}
+
+impl<F: PrimeField> PublicInputs<F> {
+    pub(crate) fn new() -> Self {
+        Self {
+            field_elements: vec![],
+            layout: InputLayout::default(),
+        }
+    }
+
+    /// Appends the native field elements of the given value.
+    pub(crate) fn push<T: ToConstraintField<F> + ?Sized>(
+        &mut self,
+        name: impl Into<String>,
+        value: &T,
+    ) -> Result<(), ConstraintFieldError> {
+        let field_elements = value.to_field_elements()?;
+        self.push_segment(name.into(), Encoding::FieldElement, field_elements);
+        Ok(())
+    }
+
+    /// Appends the given bytes, packed into field elements.
+    pub(crate) fn push_bytes(&mut self, name: impl Into<String>, bytes: &[u8]) -> Result<(), ConstraintFieldError> {
+        let field_elements = ToConstraintField::<F>::to_field_elements(bytes)?;
+        self.push_segment(name.into(), Encoding::PackedBytes { len: bytes.len() }, field_elements);
+        Ok(())
+    }
+
+    fn push_segment(&mut self, name: String, encoding: Encoding, field_elements: Vec<F>) {
+        self.layout.segments.push(InputSegment {
+            name,
+            num_field_elements: field_elements.len(),
+            encoding,
+        });
+        self.field_elements.extend(field_elements);
+    }
+
+    /// Returns each segment with its field elements.
+    pub(crate) fn segments(&self) -> impl Iterator<Item = (&InputSegment, &[F])> {
+        let mut offset = 0;
+        self.layout.segments.iter().map(move |segment| {
+            let field_elements = &self.field_elements[offset..offset + segment.num_field_elements];
+            offset += segment.num_field_elements;
+            (segment, field_elements)
+        })
+    }
+
+    pub(crate) fn into_field_elements(self) -> Vec<F> {
+        self.field_elements
+    }
+
+    pub(crate) fn into_layout(self) -> InputLayout {
+        self.layout
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testnet1::{
+            instantiated::*,
+            AleoAmount,
+            InnerCircuitVerifierInput,
+            NoopCircuit,
+            OuterCircuit,
+            OuterCircuitVerifierInput,
+            ProgramLocalData,
+            SystemParameters,
+        },
+        traits::DPCComponents,
+    };
+    use snarkvm_algorithms::traits::{CommitmentScheme, MerkleParameters, CRH, SNARK};
+    use snarkvm_parameters::{
+        testnet1::{InnerSNARKVKParameters, NoopProgramSNARKVKParameters},
+        LedgerMerkleTreeParameters,
+        Parameter,
+    };
+    use snarkvm_r1cs::{ConstraintCounter, ConstraintSynthesizer};
+    use snarkvm_utilities::bytes::FromBytes;
+
+    use std::sync::Arc;
+
+    fn ledger_parameters() -> Arc<CommitmentMerkleParameters> {
+        let crh_parameters =
+            <MerkleTreeCRH as CRH>::Parameters::read(&LedgerMerkleTreeParameters::load_bytes().unwrap()[..]).unwrap();
+        Arc::new(From::from(<CommitmentMerkleParameters as MerkleParameters>::H::from(
+            crh_parameters,
+        )))
+    }
+
+    /// Returns the verifier input of the blank inner circuit.
+    fn blank_inner_verifier_input(
+        system_parameters: &SystemParameters<Components>,
+        ledger_parameters: &Arc<CommitmentMerkleParameters>,
+    ) -> InnerCircuitVerifierInput<Components> {
+        InnerCircuitVerifierInput {
+            system_parameters: system_parameters.clone(),
+            ledger_parameters: ledger_parameters.clone(),
+            ledger_digest: Default::default(),
+            old_serial_numbers: vec![Default::default(); Components::NUM_INPUT_RECORDS],
+            new_commitments: vec![Default::default(); Components::NUM_OUTPUT_RECORDS],
+            new_encrypted_record_hashes: vec![Default::default(); Components::NUM_OUTPUT_RECORDS],
+            program_commitment: Default::default(),
+            local_data_root: Default::default(),
+            memo: [0u8; 32],
+            value_balance: AleoAmount::ZERO,
+            network_id: 0,
+        }
+    }
+
+    fn check_layout<F: PrimeField>(layout: &InputLayout, field_elements: &[F], num_allocated_inputs: usize) {
+        assert_eq!(layout.num_field_elements(), field_elements.len());
+        assert_eq!(layout.num_field_elements(), num_allocated_inputs);
+
+        let last = layout
+            .segments()
+            .iter()
+            .rev()
+            .find(|segment| segment.num_field_elements > 0);
+        assert_eq!(
+            layout.segment_of(num_allocated_inputs - 1).map(|(segment, _)| segment),
+            last
+        );
+        assert!(layout.segment_of(num_allocated_inputs).is_none());
+
+        let table = layout.to_string();
+        assert_eq!(table.lines().count(), layout.segments().len() + 2);
+        for segment in layout.segments() {
+            assert!(table.contains(&segment.name));
+        }
+    }
+
+    #[test]
+    fn test_inner_circuit_input_layout() {
+        let system_parameters = SystemParameters::<Components>::load().unwrap();
+        let ledger_parameters = ledger_parameters();
+
+        // The blank inner circuit does not synthesize, so the allocated inputs are checked
+        // through the outer circuit, which allocates one input per inner field element.
+        let verifier_input = blank_inner_verifier_input(&system_parameters, &ledger_parameters);
+        let field_elements = verifier_input.to_field_elements().unwrap();
+        let layout = verifier_input.describe_inputs().unwrap();
+        check_layout(&layout, &field_elements, field_elements.len());
+
+        let segment = |name: &str| layout.segments().iter().find(|segment| segment.name == name).unwrap();
+        assert_eq!(segment("memo").encoding, Encoding::PackedBytes { len: 32 });
+        assert_eq!(segment("network_id").encoding, Encoding::PackedBytes { len: 1 });
+        assert_eq!(segment("value_balance").encoding, Encoding::PackedBytes { len: 8 });
+        assert_eq!(segment("ledger_digest").encoding, Encoding::FieldElement);
+    }
+
+    #[test]
+    fn test_outer_circuit_input_layout() {
+        let system_parameters = SystemParameters::<Components>::load().unwrap();
+        let ledger_parameters = ledger_parameters();
+        let inner_snark_vk =
+            <InnerSNARK as SNARK>::VerifyingKey::read(&InnerSNARKVKParameters::load_bytes().unwrap()[..]).unwrap();
+
+        let mut cs = ConstraintCounter::default();
+        OuterCircuit::<Components>::blank(
+            system_parameters.clone(),
+            ledger_parameters.clone(),
+            inner_snark_vk,
+            NoopProgramSNARKVKParameters::load_bytes().unwrap(),
+        )
+        .generate_constraints(&mut cs)
+        .unwrap();
+
+        let inner_snark_verifier_input = blank_inner_verifier_input(&system_parameters, &ledger_parameters);
+        let num_inner_field_elements = inner_snark_verifier_input.to_field_elements().unwrap().len();
+        let verifier_input = OuterCircuitVerifierInput {
+            inner_snark_verifier_input,
+            inner_circuit_id: Default::default(),
+        };
+        let layout = verifier_input.describe_inputs().unwrap();
+        check_layout(
+            &layout,
+            &verifier_input.to_field_elements().unwrap(),
+            cs.num_public_variables,
+        );
+
+        // Every inner field element is a segment of its own.
+        let inner_segments = layout
+            .segments()
+            .iter()
+            .filter(|segment| segment.name.starts_with("inner_snark_input."))
+            .collect::<Vec<_>>();
+        assert_eq!(inner_segments.len(), num_inner_field_elements);
+        assert!(inner_segments.contains(&&InputSegment {
+            name: "inner_snark_input.network_id".into(),
+            num_field_elements: 1,
+            encoding: Encoding::PackedBytes { len: 32 },
+        }));
+    }
+
+    #[test]
+    fn test_program_input_layout() {
+        let system_parameters = SystemParameters::<Components>::load().unwrap();
+
+        let mut cs = ConstraintCounter::default();
+        NoopCircuit::<Components>::blank(&system_parameters)
+            .generate_constraints(&mut cs)
+            .unwrap();
+
+        let local_data = ProgramLocalData::<Components> {
+            local_data_commitment_parameters: system_parameters.local_data_commitment.parameters().clone(),
+            local_data_root: Default::default(),
+            position: 0,
+        };
+        let layout = local_data.describe_inputs().unwrap();
+        check_layout(
+            &layout,
+            &local_data.to_field_elements().unwrap(),
+            cs.num_public_variables,
+        );
+        assert_eq!(layout.segments()[0], InputSegment {
+            name: "position".into(),
+            num_field_elements: 1,
+            encoding: Encoding::PackedBytes { len: 1 },
+        });
+    }
+}
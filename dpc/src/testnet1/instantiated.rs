@@ -129,6 +129,30 @@ impl PedersenSize for TwoToOneWindow {
     const WINDOW_SIZE: usize = 32;
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LargeInnerSNARKVkHashWindow;
+
+impl PedersenSize for LargeInnerSNARKVkHashWindow {
+    const NUM_WINDOWS: usize = 512;
+    const WINDOW_SIZE: usize = 63;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LargeLocalDataCRHWindow;
+
+impl PedersenSize for LargeLocalDataCRHWindow {
+    const NUM_WINDOWS: usize = 32;
+    const WINDOW_SIZE: usize = 32;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LargeSnNonceWindow;
+
+impl PedersenSize for LargeSnNonceWindow {
+    const NUM_WINDOWS: usize = 40;
+    const WINDOW_SIZE: usize = 63;
+}
+
 define_merkle_tree_parameters!(CommitmentMerkleParameters, MerkleTreeCRH, 32);
 
 pub struct Components;
@@ -177,6 +201,60 @@ impl BaseDPCComponents for Components {
     type ProgramSNARKGadget = ProgramSNARKGadget;
 }
 
+pub const LARGE_NUM_INPUT_RECORDS: usize = 4;
+pub const LARGE_NUM_OUTPUT_RECORDS: usize = 4;
+
+/// The testnet1 components with 4 input records and 4 output records.
+///
+/// The local data CRH and the serial number nonce CRH hash values from all input records
+/// together, and the inner circuit id CRH hashes an inner SNARK verifying key with more
+/// public inputs, so these use larger windows than in `Components`.
+pub struct ComponentsLarge;
+
+impl DPCComponents for ComponentsLarge {
+    type AccountCommitment = AccountCommitment;
+    type AccountCommitmentGadget = AccountCommitmentGadget;
+    type AccountEncryption = AccountEncryption;
+    type AccountEncryptionGadget = AccountEncryptionGadget;
+    type AccountSignature = AccountSignature;
+    type AccountSignatureGadget = AccountSignatureGadget;
+    type EncryptedRecordCRH = EncryptedRecordCRH;
+    type EncryptedRecordCRHGadget = EncryptedRecordCRHGadget;
+    type InnerCircuitIDCRH = LargeInnerCircuitIDCRH;
+    type InnerCircuitIDCRHGadget = InnerCircuitIDCRHGadget;
+    type InnerField = InnerField;
+    type LocalDataCRH = LargeLocalDataCRH;
+    type LocalDataCRHGadget = LocalDataCRHGadget;
+    type LocalDataCommitment = LocalDataCommitment;
+    type LocalDataCommitmentGadget = LocalDataCommitmentGadget;
+    type OuterField = OuterField;
+    type PRF = PRF;
+    type PRFGadget = PRFGadget;
+    type ProgramVerificationKeyCRH = ProgramVerificationKeyCRH;
+    type ProgramVerificationKeyCRHGadget = ProgramVerificationKeyCRHGadget;
+    type ProgramVerificationKeyCommitment = ProgramVerificationKeyCommitment;
+    type ProgramVerificationKeyCommitmentGadget = ProgramVerificationKeyCommitmentGadget;
+    type RecordCommitment = RecordCommitment;
+    type RecordCommitmentGadget = RecordCommitmentGadget;
+    type SerialNumberNonceCRH = LargeSerialNumberNonce;
+    type SerialNumberNonceCRHGadget = SerialNumberNonceGadget;
+
+    const NUM_INPUT_RECORDS: usize = LARGE_NUM_INPUT_RECORDS;
+    const NUM_OUTPUT_RECORDS: usize = LARGE_NUM_OUTPUT_RECORDS;
+}
+
+impl BaseDPCComponents for ComponentsLarge {
+    type EncryptionGroup = EdwardsBls;
+    type EncryptionModelParameters = EdwardsParameters;
+    type InnerSNARK = LargeInnerSNARK;
+    type InnerSNARKGadget = InnerSNARKGadget;
+    type MerkleHashGadget = MerkleTreeCRHGadget;
+    type MerkleParameters = CommitmentMerkleParameters;
+    type NoopProgramSNARK = NoopProgramSNARK<Self>;
+    type OuterSNARK = LargeOuterSNARK;
+    type ProgramSNARKGadget = ProgramSNARKGadget;
+}
+
 // Native primitives
 
 pub type InnerPairing = Bls12_377;
@@ -209,6 +287,20 @@ pub type Tx = Transaction<Components>;
 pub type InstantiatedDPC = DPC<Components>;
 pub type LocalData = DPCLocalData<Components>;
 
+pub type LargeLocalDataCRH = BoweHopwoodPedersenCompressedCRH<EdwardsBls, LargeLocalDataCRHWindow>;
+pub type LargeInnerCircuitIDCRH = BoweHopwoodPedersenCompressedCRH<EdwardsSW, LargeInnerSNARKVkHashWindow>;
+pub type LargeSerialNumberNonce = BoweHopwoodPedersenCompressedCRH<EdwardsBls, LargeSnNonceWindow>;
+
+pub type LargeInnerSNARK =
+    Groth16<InnerPairing, InnerCircuit<ComponentsLarge>, InnerCircuitVerifierInput<ComponentsLarge>>;
+pub type LargeOuterSNARK =
+    Groth16<OuterPairing, OuterCircuit<ComponentsLarge>, OuterCircuitVerifierInput<ComponentsLarge>>;
+
+pub type LargeTx = Transaction<ComponentsLarge>;
+
+pub type LargeInstantiatedDPC = DPC<ComponentsLarge>;
+pub type LargeLocalData = DPCLocalData<ComponentsLarge>;
+
 // Gadgets
 
 pub type AccountCommitmentGadget = PedersenCompressedCommitmentGadget<EdwardsBls, InnerField, EdwardsBlsGadget>;
@@ -14,14 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "rng-recording")]
+use crate::errors::RecordedExecutionError;
 use crate::{
     account::{Account, AccountAddress, AccountPrivateKey},
-    block::Network,
-    errors::DPCError,
-    testnet1::{payload::Payload, AleoAmount},
+    block::{ConflictPolicy, Network, Transactions},
+    errors::{DPCError, TransactionError},
+    testnet1::payload::Payload,
     traits::{AccountScheme, DPCComponents, DPCScheme, LedgerScheme, RecordScheme, TransactionScheme},
 };
 use snarkvm_algorithms::{
+    cfg_iter,
     commitment_tree::CommitmentMerkleTree,
     merkle_tree::{MerklePath, MerkleTreeDigest},
     traits::{
@@ -38,11 +41,13 @@ use snarkvm_algorithms::{
 use snarkvm_curves::traits::{Group, MontgomeryModelParameters, ProjectiveCurve, TEModelParameters};
 use snarkvm_gadgets::{
     bits::Boolean,
-    traits::algorithms::{CRHGadget, SNARKVerifierGadget},
+    traits::algorithms::{CRHGadget, PreparedSNARKVerifierGadget, SNARKVerifierGadget},
 };
+#[cfg(feature = "rng-recording")]
+use snarkvm_utilities::rand::RecordingRng;
 use snarkvm_utilities::{
     bytes::{FromBytes, ToBytes},
-    has_duplicates,
+    capabilities::warn_if_sequential,
     rand::UniformRand,
     to_bytes,
     variable_length_integer::*,
@@ -50,15 +55,31 @@ use snarkvm_utilities::{
 
 use itertools::{izip, Itertools};
 use rand::Rng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use std::{
+    convert::TryFrom,
     io::{Read, Result as IoResult, Write},
     marker::PhantomData,
     sync::Arc,
+    time::Instant,
 };
 
+pub mod derived_randomizers;
+pub use derived_randomizers::*;
+
+pub mod execution_receipt;
+pub use execution_receipt::*;
+
+pub mod genesis;
+pub use genesis::*;
+
 pub mod inner_circuit;
 pub use inner_circuit::*;
 
+pub mod input_layout;
+pub use input_layout::*;
+
 pub mod outer_circuit;
 pub use outer_circuit::*;
 
@@ -76,12 +97,23 @@ pub use transaction::*;
 
 pub mod instantiated;
 
+#[cfg(any(test, feature = "vectors"))]
+pub mod vectors;
+
+#[cfg(test)]
+mod constraint_budget;
+
 ///////////////////////////////////////////////////////////////////////////////
 
 /// Trait that stores all information about the components of a Plain DPC
 /// scheme. Simplifies the interface of Plain DPC by wrapping all these into
 /// one.
 pub trait BaseDPCComponents: DPCComponents {
+    /// If `true`, the encryption randomness of each output record is derived from the
+    /// transaction kernel with `DerivedRandomizers`, and the inner circuit checks it.
+    /// Changing this changes the inner circuit, and so its SNARK parameters.
+    const BIND_ENCRYPTION_RANDOMNESS: bool = false;
+
     /// Ledger digest type.
     type MerkleParameters: LoadableMerkleParameters;
     type MerkleHashGadget: CRHGadget<<Self::MerkleParameters as MerkleParameters>::H, Self::InnerField>;
@@ -116,7 +148,11 @@ pub trait BaseDPCComponents: DPCComponents {
     >;
 
     /// SNARK Verifier gadget for the "dummy program" that does nothing with its input.
-    type ProgramSNARKGadget: SNARKVerifierGadget<Self::NoopProgramSNARK, Self::OuterField, Input = Vec<Boolean>>;
+    type ProgramSNARKGadget: PreparedSNARKVerifierGadget<
+        Self::NoopProgramSNARK,
+        Self::OuterField,
+        Input = Vec<Boolean>,
+    >;
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -185,81 +221,25 @@ impl<Components: BaseDPCComponents> TransactionKernel<Components> {
             network_id: self.network_id,
         }
     }
-}
-
-impl<Components: BaseDPCComponents> ToBytes for TransactionKernel<Components> {
-    #[inline]
-    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write old record components
-
-        for old_account_private_key in &self.old_account_private_keys {
-            let r_pk_counter = old_account_private_key.r_pk_counter;
-            let private_key_seed = old_account_private_key.seed;
-            r_pk_counter.write(&mut writer)?;
-            private_key_seed.write(&mut writer)?;
-        }
-
-        for old_record in &self.old_records {
-            old_record.write(&mut writer)?;
-        }
-
-        for old_serial_number in &self.old_serial_numbers {
-            old_serial_number.write(&mut writer)?;
-        }
-
-        for old_randomizer in &self.old_randomizers {
-            variable_length_integer(old_randomizer.len() as u64).write(&mut writer)?;
-            old_randomizer.write(&mut writer)?;
-        }
-
-        // Write new record components
-
-        for new_record in &self.new_records {
-            new_record.write(&mut writer)?;
-        }
-
-        for new_sn_nonce_randomness in &self.new_sn_nonce_randomness {
-            new_sn_nonce_randomness.write(&mut writer)?;
-        }
-
-        for new_commitment in &self.new_commitments {
-            new_commitment.write(&mut writer)?;
-        }
-
-        for new_records_encryption_randomness in &self.new_records_encryption_randomness {
-            new_records_encryption_randomness.write(&mut writer)?;
-        }
-
-        for new_encrypted_record in &self.new_encrypted_records {
-            new_encrypted_record.write(&mut writer)?;
-        }
-
-        for new_encrypted_record_hash in &self.new_encrypted_record_hashes {
-            new_encrypted_record_hash.write(&mut writer)?;
-        }
-
-        // Write transaction components
-
-        self.program_commitment.write(&mut writer)?;
-        self.program_randomness.write(&mut writer)?;
-
-        self.local_data_merkle_tree.write(&mut writer)?;
-
-        for local_data_commitment_randomizer in &self.local_data_commitment_randomizers {
-            local_data_commitment_randomizer.write(&mut writer)?;
-        }
 
-        self.value_balance.write(&mut writer)?;
-        self.memorandum.write(&mut writer)?;
-        self.network_id.write(&mut writer)
+    /// Derives the randomizers bound to this transaction kernel and the given account secret.
+    pub fn derive_randomizers(&self, account_secret: &[u8]) -> Result<DerivedRandomizers<Components>, DPCError> {
+        DerivedRandomizers::derive(
+            &self.system_parameters,
+            account_secret,
+            &self.old_serial_numbers,
+            &self.new_records,
+            self.memorandum.as_bytes(),
+            self.network_id,
+        )
     }
-}
-
-impl<Components: BaseDPCComponents> FromBytes for TransactionKernel<Components> {
-    #[inline]
-    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
-        let system_parameters = SystemParameters::<Components>::load().expect("Could not load system parameters");
 
+    /// Reads a transaction kernel that was produced with the given system parameters,
+    /// instead of the system parameters that `FromBytes::read` loads for `Components`.
+    pub fn read_with_parameters<R: Read>(
+        mut reader: R,
+        system_parameters: SystemParameters<Components>,
+    ) -> IoResult<Self> {
         // Read old record components
 
         let mut old_account_private_keys = vec![];
@@ -349,14 +329,16 @@ impl<Components: BaseDPCComponents> FromBytes for TransactionKernel<Components>
             FromBytes::read(&mut reader)?;
 
         let local_data_merkle_tree =
-            CommitmentMerkleTree::<Components::LocalDataCommitment, Components::LocalDataCRH>::from_bytes(
+            CommitmentMerkleTree::<Components::LocalDataCommitment, Components::LocalDataCRH>::from_bytes_with_subtrees(
                 &mut reader,
                 system_parameters.local_data_crh.clone(),
+                Components::NUM_INPUT_RECORDS,
+                Components::NUM_OUTPUT_RECORDS,
             )
             .expect("Could not load local data merkle tree");
 
         let mut local_data_commitment_randomizers = vec![];
-        for _ in 0..4 {
+        for _ in 0..(Components::NUM_INPUT_RECORDS + Components::NUM_OUTPUT_RECORDS) {
             let local_data_commitment_randomizer: <Components::LocalDataCommitment as CommitmentScheme>::Randomness =
                 FromBytes::read(&mut reader)?;
             local_data_commitment_randomizers.push(local_data_commitment_randomizer);
@@ -393,6 +375,82 @@ impl<Components: BaseDPCComponents> FromBytes for TransactionKernel<Components>
     }
 }
 
+impl<Components: BaseDPCComponents> ToBytes for TransactionKernel<Components> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write old record components
+
+        for old_account_private_key in &self.old_account_private_keys {
+            let r_pk_counter = old_account_private_key.r_pk_counter;
+            let private_key_seed = old_account_private_key.seed;
+            r_pk_counter.write(&mut writer)?;
+            private_key_seed.write(&mut writer)?;
+        }
+
+        for old_record in &self.old_records {
+            old_record.write_with_secrets(&mut writer)?;
+        }
+
+        for old_serial_number in &self.old_serial_numbers {
+            old_serial_number.write(&mut writer)?;
+        }
+
+        for old_randomizer in &self.old_randomizers {
+            variable_length_integer(old_randomizer.len() as u64).write(&mut writer)?;
+            old_randomizer.write(&mut writer)?;
+        }
+
+        // Write new record components
+
+        for new_record in &self.new_records {
+            new_record.write_with_secrets(&mut writer)?;
+        }
+
+        for new_sn_nonce_randomness in &self.new_sn_nonce_randomness {
+            new_sn_nonce_randomness.write(&mut writer)?;
+        }
+
+        for new_commitment in &self.new_commitments {
+            new_commitment.write(&mut writer)?;
+        }
+
+        for new_records_encryption_randomness in &self.new_records_encryption_randomness {
+            new_records_encryption_randomness.write(&mut writer)?;
+        }
+
+        for new_encrypted_record in &self.new_encrypted_records {
+            new_encrypted_record.write(&mut writer)?;
+        }
+
+        for new_encrypted_record_hash in &self.new_encrypted_record_hashes {
+            new_encrypted_record_hash.write(&mut writer)?;
+        }
+
+        // Write transaction components
+
+        self.program_commitment.write(&mut writer)?;
+        self.program_randomness.write(&mut writer)?;
+
+        self.local_data_merkle_tree.write(&mut writer)?;
+
+        for local_data_commitment_randomizer in &self.local_data_commitment_randomizers {
+            local_data_commitment_randomizer.write(&mut writer)?;
+        }
+
+        self.value_balance.write(&mut writer)?;
+        self.memorandum.write(&mut writer)?;
+        self.network_id.write(&mut writer)
+    }
+}
+
+impl<Components: BaseDPCComponents> FromBytes for TransactionKernel<Components> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        let system_parameters = SystemParameters::<Components>::load().expect("Could not load system parameters");
+        Self::read_with_parameters(reader, system_parameters)
+    }
+}
+
 /// Stores local data required to produce program proofs.
 pub struct LocalData<Components: BaseDPCComponents> {
     pub system_parameters: SystemParameters<Components>,
@@ -481,10 +539,7 @@ impl<Components: BaseDPCComponents> DPC<Components> {
     ) -> Result<NoopProgramSNARKParameters<Components>, DPCError> {
         let (pk, pvk) = Components::NoopProgramSNARK::setup(&NoopCircuit::blank(system_parameters), rng)?;
 
-        Ok(NoopProgramSNARKParameters {
-            proving_key: pk,
-            verification_key: pvk.into(),
-        })
+        Ok(NoopProgramSNARKParameters::new(pk, pvk.into()))
     }
 
     pub fn generate_sn(
@@ -509,365 +564,236 @@ impl<Components: BaseDPCComponents> DPC<Components> {
         Ok((sn, sig_and_pk_randomizer))
     }
 
+    /// Returns a new record with the given contents, and with fresh commitment randomness.
     #[allow(clippy::too_many_arguments)]
-    pub fn generate_record<R: Rng>(
+    pub fn generate_record_with_amount<R: Rng>(
         system_parameters: &SystemParameters<Components>,
         sn_nonce: <Components::SerialNumberNonceCRH as CRH>::Output,
         owner: AccountAddress<Components>,
         is_dummy: bool,
-        value: u64,
+        value: AleoAmount,
         payload: Payload,
         birth_program_id: Vec<u8>,
         death_program_id: Vec<u8>,
         rng: &mut R,
     ) -> Result<Record<Components>, DPCError> {
+        if value.is_negative() {
+            return Err(TransactionError::NegativeRecordValue(value.0).into());
+        }
+
         let record_time = start_timer!(|| "Generate record");
         // Sample new commitment randomness.
         let commitment_randomness = <Components::RecordCommitment as CommitmentScheme>::Randomness::rand(rng);
 
-        // Total = 32 + 1 + 8 + 32 + 48 + 48 + 32 = 201 bytes
-        let commitment_input = to_bytes![
-            owner,            // 256 bits = 32 bytes
-            is_dummy,         // 1 bit = 1 byte
-            value,            // 64 bits = 8 bytes
-            payload,          // 256 bits = 32 bytes
-            birth_program_id, // 384 bits = 48 bytes
-            death_program_id, // 384 bits = 48 bytes
-            sn_nonce          // 256 bits = 32 bytes
-        ]?;
-
-        let commitment = Components::RecordCommitment::commit(
+        let plaintext = RecordPlaintext::new(
+            owner,
+            is_dummy,
+            value,
+            payload,
+            birth_program_id,
+            death_program_id,
+            sn_nonce,
+        );
+        let record = Record::from_parts(
             &system_parameters.record_commitment,
-            &commitment_input,
-            &commitment_randomness,
+            plaintext,
+            RecordSecrets::new(commitment_randomness),
         )?;
+        end_timer!(record_time);
+        Ok(record)
+    }
 
-        let record = Record {
+    /// Returns a new record with a `u64` value, which must fit in an `AleoAmount`.
+    #[deprecated(note = "use `generate_record_with_amount` instead")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn generate_record<R: Rng>(
+        system_parameters: &SystemParameters<Components>,
+        sn_nonce: <Components::SerialNumberNonceCRH as CRH>::Output,
+        owner: AccountAddress<Components>,
+        is_dummy: bool,
+        value: u64,
+        payload: Payload,
+        birth_program_id: Vec<u8>,
+        death_program_id: Vec<u8>,
+        rng: &mut R,
+    ) -> Result<Record<Components>, DPCError> {
+        let value = AleoAmount::try_from(value).map_err(|_| TransactionError::RecordValueOutOfRange(value))?;
+        Self::generate_record_with_amount(
+            system_parameters,
+            sn_nonce,
             owner,
             is_dummy,
             value,
             payload,
             birth_program_id,
             death_program_id,
-            serial_number_nonce: sn_nonce,
-            commitment,
-            commitment_randomness,
-        };
-        end_timer!(record_time);
-        Ok(record)
+            rng,
+        )
     }
-}
 
-impl<Components: BaseDPCComponents, L: LedgerScheme> DPCScheme<L> for DPC<Components>
-where
-    L: LedgerScheme<
-        Commitment = <Components::RecordCommitment as CommitmentScheme>::Output,
-        MerkleParameters = Components::MerkleParameters,
-        MerklePath = MerklePath<Components::MerkleParameters>,
-        MerkleTreeDigest = MerkleTreeDigest<Components::MerkleParameters>,
-        SerialNumber = <Components::AccountSignature as SignatureScheme>::PublicKey,
-        Transaction = Transaction<Components>,
-    >,
-{
-    type Account = Account<Components>;
-    type LocalData = LocalData<Components>;
-    type NetworkParameters = PublicParameters<Components>;
-    type Payload = <Self::Record as RecordScheme>::Payload;
-    type PrivateProgramInput = PrivateProgramInput;
-    type Record = Record<Components>;
-    type SystemParameters = SystemParameters<Components>;
-    type Transaction = Transaction<Components>;
-    type TransactionKernel = TransactionKernel<Components>;
+    /// Checks that each input record is owned by the address derived from its account private key,
+    /// and that its commitment opens to its contents. These are the checks the inner circuit
+    /// enforces on every input record, performed natively so mismatches fail before any proving.
+    pub fn check_input_records(
+        system_parameters: &SystemParameters<Components>,
+        old_records: &[Record<Components>],
+        old_account_private_keys: &[AccountPrivateKey<Components>],
+    ) -> Result<(), DPCError> {
+        for (i, (record, private_key)) in old_records.iter().zip_eq(old_account_private_keys).enumerate() {
+            let address = AccountAddress::from_private_key(
+                &system_parameters.account_signature,
+                &system_parameters.account_commitment,
+                &system_parameters.account_encryption,
+                private_key,
+            )?;
+            if &address != record.owner() {
+                return Err(DPCError::InvalidRecordOwner(
+                    i,
+                    record.owner().to_string(),
+                    address.to_string(),
+                ));
+            }
 
-    fn setup<R: Rng>(
-        ledger_parameters: &Arc<Components::MerkleParameters>,
-        rng: &mut R,
-    ) -> anyhow::Result<Self::NetworkParameters> {
-        let setup_time = start_timer!(|| "BaseDPC::setup");
-        let system_parameters = Self::generate_system_parameters(rng)?;
+            if !record.verify_commitment(&system_parameters.record_commitment) {
+                return Err(DPCError::InvalidRecordCommitment(i));
+            }
+        }
 
-        let program_snark_setup_time = start_timer!(|| "Dummy program SNARK setup");
-        let noop_program_snark_parameters = Self::generate_noop_program_snark_parameters(&system_parameters, rng)?;
-        let program_snark_proof = Components::NoopProgramSNARK::prove(
-            &noop_program_snark_parameters.proving_key,
-            &NoopCircuit::blank(&system_parameters),
-            rng,
-        )?;
-        end_timer!(program_snark_setup_time);
+        Ok(())
+    }
 
-        let program_snark_vk_and_proof = PrivateProgramInput {
-            verification_key: to_bytes![noop_program_snark_parameters.verification_key]?,
-            proof: to_bytes![program_snark_proof]?,
-        };
+    /// Checks that the membership witness of each non-dummy input record is valid
+    /// with respect to the given ledger digest.
+    pub fn check_ledger_witnesses(
+        ledger_digest: &MerkleTreeDigest<Components::MerkleParameters>,
+        old_records: &[Record<Components>],
+        old_witnesses: &[MerklePath<Components::MerkleParameters>],
+    ) -> Result<(), DPCError> {
+        for (i, (record, witness)) in old_records.iter().zip_eq(old_witnesses).enumerate() {
+            if !record.is_dummy() && !witness.verify(ledger_digest, &record.commitment())? {
+                return Err(DPCError::InvalidLedgerWitness(i));
+            }
+        }
 
-        let snark_setup_time = start_timer!(|| "Execute inner SNARK setup");
-        let inner_circuit = InnerCircuit::blank(&system_parameters, ledger_parameters);
-        let inner_snark_parameters = Components::InnerSNARK::setup(&inner_circuit, rng)?;
-        end_timer!(snark_setup_time);
+        Ok(())
+    }
 
-        let snark_setup_time = start_timer!(|| "Execute outer SNARK setup");
-        let inner_snark_vk: <Components::InnerSNARK as SNARK>::VerifyingKey = inner_snark_parameters.1.clone().into();
-        let inner_snark_proof = Components::InnerSNARK::prove(&inner_snark_parameters.0, &inner_circuit, rng)?;
+    /// Checks that the death program of each input record and the birth program of each
+    /// output record match the verification key of the program execution provided for it.
+    pub fn check_program_ids(
+        system_parameters: &SystemParameters<Components>,
+        old_records: &[Record<Components>],
+        new_records: &[Record<Components>],
+        old_death_program_proofs: &[PrivateProgramInput],
+        new_birth_program_proofs: &[PrivateProgramInput],
+    ) -> Result<(), DPCError> {
+        let program_id = |input: &PrivateProgramInput| {
+            Self::compute_program_id(&system_parameters.program_verification_key_crh, &input.verification_key)
+        };
 
-        let outer_snark_parameters = Components::OuterSNARK::setup(
-            &OuterCircuit::blank(
-                system_parameters.clone(),
-                ledger_parameters.clone(),
-                inner_snark_vk,
-                inner_snark_proof,
-                program_snark_vk_and_proof,
-            ),
-            rng,
-        )?;
-        end_timer!(snark_setup_time);
-        end_timer!(setup_time);
+        for (i, (record, input)) in old_records.iter().zip_eq(old_death_program_proofs).enumerate() {
+            let death_program_id = program_id(input)?;
+            if death_program_id != record.death_program_id() {
+                return Err(DPCError::MismatchedDeathProgramId(
+                    i,
+                    hex::encode(record.death_program_id()),
+                    hex::encode(death_program_id),
+                ));
+            }
+        }
 
-        let inner_snark_parameters = (Some(inner_snark_parameters.0), inner_snark_parameters.1);
-        let outer_snark_parameters = (Some(outer_snark_parameters.0), outer_snark_parameters.1);
+        for (j, (record, input)) in new_records.iter().zip_eq(new_birth_program_proofs).enumerate() {
+            let birth_program_id = program_id(input)?;
+            if birth_program_id != record.birth_program_id() {
+                return Err(DPCError::MismatchedBirthProgramId(
+                    j,
+                    hex::encode(record.birth_program_id()),
+                    hex::encode(birth_program_id),
+                ));
+            }
+        }
 
-        Ok(PublicParameters {
-            system_parameters,
-            noop_program_snark_parameters,
-            inner_snark_parameters,
-            outer_snark_parameters,
+        Ok(())
+    }
+
+    /// Runs `execute`, which calls `execute_offline` or `execute_online` with the given RNG, while
+    /// recording up to `max_recorded_bytes` of the randomness it consumes. If the execution fails,
+    /// the recording is returned alongside the error, so that it can be replayed in a test.
+    #[cfg(feature = "rng-recording")]
+    pub fn execute_recorded<R: Rng, T, F>(
+        rng: &mut R,
+        max_recorded_bytes: usize,
+        execute: F,
+    ) -> Result<T, RecordedExecutionError>
+    where
+        F: FnOnce(&mut RecordingRng<&mut R>) -> anyhow::Result<T>,
+    {
+        let mut recording_rng = RecordingRng::new(rng, max_recorded_bytes);
+        execute(&mut recording_rng).map_err(|error| RecordedExecutionError {
+            error,
+            randomness: recording_rng.into_recording(),
         })
     }
 
-    fn create_account<R: Rng>(parameters: &Self::SystemParameters, rng: &mut R) -> anyhow::Result<Self::Account> {
-        let time = start_timer!(|| "BaseDPC::create_account");
-        let account = Account::new(
-            &parameters.account_signature,
-            &parameters.account_commitment,
-            &parameters.account_encryption,
-            rng,
-        )?;
-        end_timer!(time);
-
-        Ok(account)
+    /// Returns the ID of the inner circuit with the given verifying key, which is the hash of
+    /// the serialized verifying key under the inner circuit ID CRH.
+    pub fn compute_inner_circuit_id(
+        inner_circuit_id_crh: &Components::InnerCircuitIDCRH,
+        inner_snark_vk: &<Components::InnerSNARK as SNARK>::VerifyingKey,
+    ) -> Result<InnerCircuitId<Components>, DPCError> {
+        Ok(InnerCircuitId::new(Components::InnerCircuitIDCRH::hash(
+            inner_circuit_id_crh,
+            &to_bytes![inner_snark_vk]?,
+        )?))
     }
 
-    fn execute_offline<R: Rng>(
-        parameters: Self::SystemParameters,
-        old_records: Vec<Self::Record>,
-        old_account_private_keys: Vec<<Self::Account as AccountScheme>::AccountPrivateKey>,
-        new_record_owners: Vec<<Self::Account as AccountScheme>::AccountAddress>,
-        new_is_dummy_flags: &[bool],
-        new_values: &[u64],
-        new_payloads: Vec<Self::Payload>,
-        new_birth_program_ids: Vec<Vec<u8>>,
-        new_death_program_ids: Vec<Vec<u8>>,
-        memorandum: <Self::Transaction as TransactionScheme>::Memorandum,
-        network_id: u8,
-        rng: &mut R,
-    ) -> anyhow::Result<Self::TransactionKernel> {
-        assert_eq!(Components::NUM_INPUT_RECORDS, old_records.len());
-        assert_eq!(Components::NUM_INPUT_RECORDS, old_account_private_keys.len());
-
-        assert_eq!(Components::NUM_OUTPUT_RECORDS, new_record_owners.len());
-        assert_eq!(Components::NUM_OUTPUT_RECORDS, new_is_dummy_flags.len());
-        assert_eq!(Components::NUM_OUTPUT_RECORDS, new_payloads.len());
-        assert_eq!(Components::NUM_OUTPUT_RECORDS, new_birth_program_ids.len());
-        assert_eq!(Components::NUM_OUTPUT_RECORDS, new_death_program_ids.len());
-
-        let mut old_serial_numbers = Vec::with_capacity(Components::NUM_INPUT_RECORDS);
-        let mut old_randomizers = Vec::with_capacity(Components::NUM_INPUT_RECORDS);
-        let mut joint_serial_numbers = Vec::new();
-        let mut old_death_program_ids = Vec::with_capacity(old_records.len());
-
-        let mut value_balance = AleoAmount::ZERO;
-
-        // Compute the ledger membership witness and serial number from the old records.
-        for (i, record) in old_records.iter().enumerate() {
-            let input_record_time = start_timer!(|| format!("Process input record {}", i));
-
-            if !record.is_dummy() {
-                value_balance = value_balance.add(AleoAmount::from_bytes(record.value() as i64));
-            }
-
-            let (sn, randomizer) = Self::generate_sn(&parameters, record, &old_account_private_keys[i])?;
-            joint_serial_numbers.extend_from_slice(&to_bytes![sn]?);
-            old_serial_numbers.push(sn);
-            old_randomizers.push(randomizer);
-            old_death_program_ids.push(record.death_program_id().to_vec());
-
-            end_timer!(input_record_time);
-        }
-
-        let mut new_records = Vec::with_capacity(Components::NUM_OUTPUT_RECORDS);
-        let mut new_commitments = Vec::with_capacity(Components::NUM_OUTPUT_RECORDS);
-        let mut new_sn_nonce_randomness = Vec::with_capacity(Components::NUM_OUTPUT_RECORDS);
-
-        // Generate new records and commitments for them.
-        for (j, (new_record_owner, new_payload, new_death_program_id)) in
-            izip!(new_record_owners, new_payloads, new_death_program_ids).enumerate()
-        {
-            if j == Components::NUM_OUTPUT_RECORDS {
-                break;
-            }
-
-            let output_record_time = start_timer!(|| format!("Process output record {}", j));
-            let sn_nonce_time = start_timer!(|| "Generate serial number nonce");
-
-            // Sample randomness sn_randomness for the CRH input.
-            let sn_randomness: [u8; 32] = rng.gen();
-
-            let crh_input = to_bytes![j as u8, sn_randomness, joint_serial_numbers]?;
-            let sn_nonce = Components::SerialNumberNonceCRH::hash(&parameters.serial_number_nonce, &crh_input)?;
-
-            end_timer!(sn_nonce_time);
-
-            let record = Self::generate_record(
-                &parameters,
-                sn_nonce,
-                new_record_owner,
-                new_is_dummy_flags[j],
-                new_values[j],
-                new_payload,
-                new_birth_program_ids[j].clone(),
-                new_death_program_id,
-                rng,
-            )?;
-
-            if !record.is_dummy() {
-                value_balance = value_balance.sub(AleoAmount::from_bytes(record.value() as i64));
-            }
-
-            new_commitments.push(record.commitment().clone());
-            new_sn_nonce_randomness.push(sn_randomness);
-            new_records.push(record);
-
-            end_timer!(output_record_time);
-        }
-
-        // TODO (raychu86) Add index and program register inputs + outputs to local data commitment leaves
-        let local_data_merkle_tree_timer = start_timer!(|| "Compute local data merkle tree");
-
-        let mut local_data_commitment_randomizers = Vec::with_capacity(Components::NUM_INPUT_RECORDS);
-
-        let mut old_record_commitments = Vec::with_capacity(Components::NUM_INPUT_RECORDS);
-        for i in 0..Components::NUM_INPUT_RECORDS {
-            let record = &old_records[i];
-            let input_bytes = to_bytes![old_serial_numbers[i], record.commitment(), memorandum, network_id]?;
-
-            let commitment_randomness = <Components::LocalDataCommitment as CommitmentScheme>::Randomness::rand(rng);
-            let commitment = Components::LocalDataCommitment::commit(
-                &parameters.local_data_commitment,
-                &input_bytes,
-                &commitment_randomness,
-            )?;
-
-            old_record_commitments.push(commitment);
-            local_data_commitment_randomizers.push(commitment_randomness);
-        }
-
-        let mut new_record_commitments = Vec::with_capacity(Components::NUM_OUTPUT_RECORDS);
-        for record in new_records.iter().take(Components::NUM_OUTPUT_RECORDS) {
-            let input_bytes = to_bytes![record.commitment(), memorandum, network_id]?;
-
-            let commitment_randomness = <Components::LocalDataCommitment as CommitmentScheme>::Randomness::rand(rng);
-            let commitment = Components::LocalDataCommitment::commit(
-                &parameters.local_data_commitment,
-                &input_bytes,
-                &commitment_randomness,
-            )?;
-
-            new_record_commitments.push(commitment);
-            local_data_commitment_randomizers.push(commitment_randomness);
-        }
-
-        let leaves = [
-            old_record_commitments[0].clone(),
-            old_record_commitments[1].clone(),
-            new_record_commitments[0].clone(),
-            new_record_commitments[1].clone(),
-        ];
-        let local_data_merkle_tree = CommitmentMerkleTree::new(parameters.local_data_crh.clone(), &leaves)?;
-
-        end_timer!(local_data_merkle_tree_timer);
-
-        let program_comm_timer = start_timer!(|| "Compute program commitment");
-        let (program_commitment, program_randomness) = {
-            let mut input = Vec::new();
-            for id in old_death_program_ids {
-                input.extend_from_slice(&id);
-            }
-
-            for id in new_birth_program_ids {
-                input.extend_from_slice(&id);
-            }
-            let program_randomness =
-                <Components::ProgramVerificationKeyCommitment as CommitmentScheme>::Randomness::rand(rng);
-            let program_commitment = Components::ProgramVerificationKeyCommitment::commit(
-                &parameters.program_verification_key_commitment,
-                &input,
-                &program_randomness,
-            )?;
-            (program_commitment, program_randomness)
-        };
-        end_timer!(program_comm_timer);
-
-        // Encrypt the new records
-
-        let mut new_records_encryption_randomness = Vec::with_capacity(Components::NUM_OUTPUT_RECORDS);
-        let mut new_encrypted_records = Vec::with_capacity(Components::NUM_OUTPUT_RECORDS);
-
-        for record in &new_records {
-            let (record_encryption_randomness, encrypted_record) =
-                RecordEncryption::encrypt_record(&parameters, record, rng)?;
-
-            new_records_encryption_randomness.push(record_encryption_randomness);
-            new_encrypted_records.push(encrypted_record);
-        }
-
-        // Construct the ciphertext hashes
-
-        let mut new_encrypted_record_hashes = Vec::with_capacity(Components::NUM_OUTPUT_RECORDS);
-        for encrypted_record in &new_encrypted_records {
-            let encrypted_record_hash = RecordEncryption::encrypted_record_hash(&parameters, &encrypted_record)?;
-
-            new_encrypted_record_hashes.push(encrypted_record_hash);
-        }
-
-        let transaction_kernel = TransactionKernel {
-            system_parameters: parameters,
-
-            old_records,
-            old_account_private_keys,
-            old_serial_numbers,
-            old_randomizers,
-
-            new_records,
-            new_sn_nonce_randomness,
-            new_commitments,
-
-            new_records_encryption_randomness,
-            new_encrypted_records,
-            new_encrypted_record_hashes,
+    /// Returns the ID of the program with the given serialized verifying key, which is the hash
+    /// of the verifying key under the program verification key CRH.
+    pub fn compute_program_id(
+        program_verification_key_crh: &Components::ProgramVerificationKeyCRH,
+        program_vk_bytes: &[u8],
+    ) -> Result<Vec<u8>, DPCError> {
+        Ok(to_bytes![Components::ProgramVerificationKeyCRH::hash(
+            program_verification_key_crh,
+            program_vk_bytes,
+        )?]?)
+    }
 
-            program_commitment,
-            program_randomness,
-            local_data_merkle_tree,
-            local_data_commitment_randomizers,
+    /// Returns the inner circuit id, which is the hash of the inner SNARK verifying key.
+    pub fn inner_circuit_id(parameters: &PublicParameters<Components>) -> Result<InnerCircuitId<Components>, DPCError> {
+        let inner_snark_vk: <Components::InnerSNARK as SNARK>::VerifyingKey =
+            parameters.inner_snark_parameters.1.clone().into();
 
-            value_balance,
-            memorandum,
-            network_id,
-        };
-        Ok(transaction_kernel)
+        Self::compute_inner_circuit_id(&parameters.system_parameters.inner_circuit_id_crh, &inner_snark_vk)
     }
 
-    fn execute_online<R: Rng>(
-        parameters: &Self::NetworkParameters,
-        transaction_kernel: Self::TransactionKernel,
-        old_death_program_proofs: Vec<Self::PrivateProgramInput>,
-        new_birth_program_proofs: Vec<Self::PrivateProgramInput>,
+    /// Returns new records and a transaction based on the authorized consumption of old
+    /// records, along with the timing and size metrics of the execution.
+    pub fn execute_online_with_receipt<L, R: Rng>(
+        parameters: &PublicParameters<Components>,
+        transaction_kernel: TransactionKernel<Components>,
+        old_death_program_proofs: Vec<PrivateProgramInput>,
+        new_birth_program_proofs: Vec<PrivateProgramInput>,
         ledger: &L,
         rng: &mut R,
-    ) -> anyhow::Result<(Vec<Self::Record>, Self::Transaction)> {
+    ) -> anyhow::Result<(Vec<Record<Components>>, Transaction<Components>, ExecutionReceipt)>
+    where
+        L: LedgerScheme<
+            Commitment = RecordCommitment<Components>,
+            MerkleParameters = Components::MerkleParameters,
+            MerklePath = MerklePath<Components::MerkleParameters>,
+            MerkleTreeDigest = MerkleTreeDigest<Components::MerkleParameters>,
+            SerialNumber = SerialNumber<Components>,
+            Transaction = Transaction<Components>,
+        >,
+    {
         assert_eq!(Components::NUM_INPUT_RECORDS, old_death_program_proofs.len());
         assert_eq!(Components::NUM_OUTPUT_RECORDS, new_birth_program_proofs.len());
 
         let exec_time = start_timer!(|| "BaseDPC::execute_online");
+        let exec_start = Instant::now();
+
+        warn_if_sequential("DPC execution", crate::PARALLEL_ENABLED);
 
         let TransactionKernel {
             system_parameters,
@@ -894,6 +820,14 @@ where
             network_id,
         } = transaction_kernel;
 
+        Self::check_program_ids(
+            &system_parameters,
+            &old_records,
+            &new_records,
+            &old_death_program_proofs,
+            &new_birth_program_proofs,
+        )?;
+
         let local_data_root = local_data_merkle_tree.root();
 
         let old_death_program_attributes = old_death_program_proofs;
@@ -901,6 +835,7 @@ where
 
         // Construct the ledger witnesses
 
+        let ledger_witness_start = Instant::now();
         let ledger_digest = ledger.digest().expect("could not get digest");
 
         // Generate the ledger membership witnesses
@@ -916,9 +851,13 @@ where
             }
         }
 
+        Self::check_ledger_witnesses(&ledger_digest, &old_records, &old_witnesses)?;
+        let ledger_witness_duration = ledger_witness_start.elapsed();
+
         // Generate Schnorr signature on transaction data
         // TODO (raychu86) Remove ledger_digest from signature and move the schnorr signing into `execute_offline`
         let signature_time = start_timer!(|| "Sign and randomize transaction contents");
+        let signature_start = Instant::now();
 
         let signature_message = to_bytes![
             network_id,
@@ -955,8 +894,10 @@ where
         }
 
         end_timer!(signature_time);
+        let signature_duration = signature_start.elapsed();
 
         // Prepare record encryption components used in the inner SNARK
+        let encryption_witness_start = Instant::now();
 
         let mut new_records_encryption_gadget_components = Vec::with_capacity(Components::NUM_OUTPUT_RECORDS);
 
@@ -970,6 +911,12 @@ where
             new_records_encryption_gadget_components.push(record_encryption_gadget_components);
         }
 
+        let encryption_witness_duration = encryption_witness_start.elapsed();
+
+        let num_dummy_input_records = old_records.iter().filter(|record| record.is_dummy()).count();
+        let num_dummy_output_records = new_records.iter().filter(|record| record.is_dummy()).count();
+
+        let inner_proof_start = Instant::now();
         let inner_proof = {
             let circuit = InnerCircuit::new(
                 parameters.system_parameters.clone(),
@@ -989,7 +936,7 @@ where
                 program_randomness.clone(),
                 local_data_root.clone(),
                 local_data_commitment_randomizers,
-                memorandum,
+                *memorandum.as_bytes(),
                 value_balance,
                 network_id,
             );
@@ -1002,7 +949,10 @@ where
             Components::InnerSNARK::prove(&inner_snark_parameters, &circuit, rng)?
         };
 
+        let inner_proof_duration = inner_proof_start.elapsed();
+
         // Verify that the inner proof passes
+        let inner_proof_verification_start = Instant::now();
         {
             let input = InnerCircuitVerifierInput {
                 system_parameters: parameters.system_parameters.clone(),
@@ -1011,7 +961,7 @@ where
                 old_serial_numbers: old_serial_numbers.clone(),
                 new_commitments: new_commitments.clone(),
                 new_encrypted_record_hashes: new_encrypted_record_hashes.clone(),
-                memo: memorandum,
+                memo: *memorandum.as_bytes(),
                 program_commitment: program_commitment.clone(),
                 local_data_root: local_data_root.clone(),
                 value_balance,
@@ -1023,14 +973,16 @@ where
             assert!(Components::InnerSNARK::verify(verification_key, &input, &inner_proof)?);
         }
 
+        let inner_proof_verification_duration = inner_proof_verification_start.elapsed();
+        let inner_proof_size = to_bytes![inner_proof]?.len();
+
         let inner_snark_vk: <Components::InnerSNARK as SNARK>::VerifyingKey =
             parameters.inner_snark_parameters.1.clone().into();
 
-        let inner_circuit_id = <Components::InnerCircuitIDCRH as CRH>::hash(
-            &parameters.system_parameters.inner_circuit_id_crh,
-            &to_bytes![inner_snark_vk]?,
-        )?;
+        let inner_circuit_id =
+            Self::compute_inner_circuit_id(&parameters.system_parameters.inner_circuit_id_crh, &inner_snark_vk)?;
 
+        let outer_proof_start = Instant::now();
         let transaction_proof = {
             let circuit = OuterCircuit::new(
                 parameters.system_parameters.clone(),
@@ -1039,7 +991,7 @@ where
                 old_serial_numbers.clone(),
                 new_commitments.clone(),
                 new_encrypted_record_hashes,
-                memorandum,
+                *memorandum.as_bytes(),
                 value_balance,
                 network_id,
                 inner_snark_vk,
@@ -1049,7 +1001,7 @@ where
                 program_commitment.clone(),
                 program_randomness,
                 local_data_root.clone(),
-                inner_circuit_id.clone(),
+                inner_circuit_id.into_inner(),
             );
 
             let outer_snark_parameters = match &parameters.outer_snark_parameters.0 {
@@ -1060,9 +1012,16 @@ where
             Components::OuterSNARK::prove(&outer_snark_parameters, &circuit, rng)?
         };
 
-        let transaction = Self::Transaction::new(
-            old_serial_numbers,
-            new_commitments,
+        let outer_proof_duration = outer_proof_start.elapsed();
+
+        let ledger_digest_bytes = to_bytes![ledger_digest]?;
+        let transaction_proof_size = to_bytes![transaction_proof]?.len();
+        let signatures_size = to_bytes![signatures]?.len();
+        let encrypted_records_size = to_bytes![new_encrypted_records]?.len();
+
+        let transaction = Transaction::new(
+            old_serial_numbers.into_iter().map(SerialNumber::new).collect(),
+            new_commitments.into_iter().map(RecordCommitment::new).collect(),
             memorandum,
             ledger_digest,
             inner_circuit_id,
@@ -1075,34 +1034,64 @@ where
             new_encrypted_records,
         );
 
+        let receipt = ExecutionReceipt {
+            ledger_digest: hex::encode(ledger_digest_bytes),
+            num_real_input_records: Components::NUM_INPUT_RECORDS - num_dummy_input_records,
+            num_dummy_input_records,
+            num_real_output_records: Components::NUM_OUTPUT_RECORDS - num_dummy_output_records,
+            num_dummy_output_records,
+            ledger_witness_duration,
+            signature_duration,
+            encryption_witness_duration,
+            inner_proof_duration,
+            inner_proof_verification_duration,
+            outer_proof_duration,
+            total_duration: exec_start.elapsed(),
+            inner_proof_size,
+            transaction_proof_size,
+            signatures_size,
+            encrypted_records_size,
+            transaction_size: to_bytes![transaction]?.len(),
+        };
+
         end_timer!(exec_time);
 
-        Ok((new_records, transaction))
+        Ok((new_records, transaction, receipt))
     }
 
-    fn verify(
-        parameters: &Self::NetworkParameters,
-        transaction: &Self::Transaction,
+    /// Returns true iff the transaction is valid according to the ledger, given the
+    /// precomputed inner circuit id.
+    fn verify_with_inner_circuit_id<L>(
+        parameters: &PublicParameters<Components>,
+        transaction: &Transaction<Components>,
         ledger: &L,
-    ) -> anyhow::Result<bool> {
-        let verify_time = start_timer!(|| "BaseDPC::verify");
-
-        // Returns false if there are duplicate serial numbers in the transaction.
-        if has_duplicates(transaction.old_serial_numbers().iter()) {
-            eprintln!("Transaction contains duplicate serial numbers");
-            return Ok(false);
-        }
-
-        // Returns false if there are duplicate commitments numbers in the transaction.
-        if has_duplicates(transaction.new_commitments().iter()) {
-            eprintln!("Transaction contains duplicate commitments");
+        inner_circuit_id: &InnerCircuitId<Components>,
+    ) -> anyhow::Result<bool>
+    where
+        L: LedgerScheme<
+            Commitment = RecordCommitment<Components>,
+            MerkleParameters = Components::MerkleParameters,
+            MerkleTreeDigest = MerkleTreeDigest<Components::MerkleParameters>,
+            SerialNumber = SerialNumber<Components>,
+            Transaction = Transaction<Components>,
+        >,
+    {
+        // Returns false if the transaction fails the structural checks.
+        if let Err(error) = transaction.fast_checks(parameters.network) {
+            eprintln!("Transaction failed the structural checks: {}", error);
             return Ok(false);
         }
 
         let ledger_time = start_timer!(|| "Ledger checks");
 
-        // Returns false if the transaction memo previously existed in the ledger.
-        if ledger.contains_memo(transaction.memorandum()) {
+        // Returns false if the transaction memo previously existed in the ledger, and the
+        // conflict policy requires it to be unique.
+        if parameters
+            .conflict_policy
+            .memo_uniqueness
+            .requires_unique(&to_bytes![transaction.memorandum()]?)
+            && ledger.contains_memo(transaction.memorandum())
+        {
             eprintln!("Ledger already contains this transaction memo.");
             return Ok(false);
         }
@@ -1133,20 +1122,11 @@ where
 
         let signature_time = start_timer!(|| "Signature checks");
 
-        let signature_message = &to_bytes![
-            transaction.network_id(),
-            transaction.ledger_digest(),
-            transaction.old_serial_numbers(),
-            transaction.new_commitments(),
-            transaction.program_commitment(),
-            transaction.local_data_root(),
-            transaction.value_balance(),
-            transaction.memorandum()
-        ]?;
+        let signature_message = &transaction.kernel().signature_message()?;
 
         let account_signature = &parameters.system_parameters.account_signature;
         for (pk, sig) in transaction.old_serial_numbers().iter().zip(&transaction.signatures) {
-            if !Components::AccountSignature::verify(account_signature, pk, signature_message, sig)? {
+            if !Components::AccountSignature::verify(account_signature, pk.inner(), signature_message, sig)? {
                 eprintln!("Signature didn't verify.");
                 return Ok(false);
             }
@@ -1168,27 +1148,27 @@ where
             system_parameters: parameters.system_parameters.clone(),
             ledger_parameters: ledger.parameters().clone(),
             ledger_digest: transaction.ledger_digest().clone(),
-            old_serial_numbers: transaction.old_serial_numbers().to_vec(),
-            new_commitments: transaction.new_commitments().to_vec(),
+            old_serial_numbers: transaction
+                .old_serial_numbers()
+                .iter()
+                .map(|sn| sn.inner().clone())
+                .collect(),
+            new_commitments: transaction
+                .new_commitments()
+                .iter()
+                .map(|cm| cm.inner().clone())
+                .collect(),
             new_encrypted_record_hashes,
-            memo: *transaction.memorandum(),
+            memo: *transaction.memorandum().as_bytes(),
             program_commitment: transaction.program_commitment().clone(),
             local_data_root: transaction.local_data_root().clone(),
             value_balance: transaction.value_balance(),
             network_id: transaction.network_id(),
         };
 
-        let inner_snark_vk: <<Components as BaseDPCComponents>::InnerSNARK as SNARK>::VerifyingKey =
-            parameters.inner_snark_parameters.1.clone().into();
-
-        let inner_circuit_id =
-            Components::InnerCircuitIDCRH::hash(&parameters.system_parameters.inner_circuit_id_crh, &to_bytes![
-                inner_snark_vk
-            ]?)?;
-
         let outer_snark_input = OuterCircuitVerifierInput {
             inner_snark_verifier_input: inner_snark_input,
-            inner_circuit_id,
+            inner_circuit_id: inner_circuit_id.into_inner(),
         };
 
         if !Components::OuterSNARK::verify(
@@ -1200,23 +1180,386 @@ where
             return Ok(false);
         }
 
-        end_timer!(verify_time);
-
         Ok(true)
     }
+}
 
-    /// Returns true iff all the transactions in the block are valid according to the ledger.
-    fn verify_transactions(
-        parameters: &Self::NetworkParameters,
-        transactions: &[Self::Transaction],
-        ledger: &L,
-    ) -> anyhow::Result<bool> {
-        for transaction in transactions {
-            if !Self::verify(parameters, transaction, ledger)? {
-                return Ok(false);
-            }
-        }
+impl<Components: BaseDPCComponents, L: LedgerScheme + Sync> DPCScheme<L> for DPC<Components>
+where
+    L: LedgerScheme<
+        Commitment = RecordCommitment<Components>,
+        MerkleParameters = Components::MerkleParameters,
+        MerklePath = MerklePath<Components::MerkleParameters>,
+        MerkleTreeDigest = MerkleTreeDigest<Components::MerkleParameters>,
+        SerialNumber = SerialNumber<Components>,
+        Transaction = Transaction<Components>,
+    >,
+    PublicParameters<Components>: Sync,
+    Transaction<Components>: Sync,
+{
+    type Account = Account<Components>;
+    type LocalData = LocalData<Components>;
+    type NetworkParameters = PublicParameters<Components>;
+    type Payload = <Self::Record as RecordScheme>::Payload;
+    type PrivateProgramInput = PrivateProgramInput;
+    type Record = Record<Components>;
+    type SystemParameters = SystemParameters<Components>;
+    type Transaction = Transaction<Components>;
+    type TransactionKernel = TransactionKernel<Components>;
 
-        Ok(true)
+    fn setup<R: Rng>(
+        ledger_parameters: &Arc<Components::MerkleParameters>,
+        network: Network,
+        rng: &mut R,
+    ) -> anyhow::Result<Self::NetworkParameters> {
+        let setup_time = start_timer!(|| "BaseDPC::setup");
+        let system_parameters = Self::generate_system_parameters(rng)?;
+
+        let program_snark_setup_time = start_timer!(|| "Dummy program SNARK setup");
+        let noop_program_snark_parameters = Self::generate_noop_program_snark_parameters(&system_parameters, rng)?;
+        end_timer!(program_snark_setup_time);
+
+        let snark_setup_time = start_timer!(|| "Execute inner SNARK setup");
+        let inner_snark_parameters =
+            Components::InnerSNARK::setup(&InnerCircuit::blank(&system_parameters, ledger_parameters), rng)?;
+        end_timer!(snark_setup_time);
+
+        let snark_setup_time = start_timer!(|| "Execute outer SNARK setup");
+        let inner_snark_vk: <Components::InnerSNARK as SNARK>::VerifyingKey = inner_snark_parameters.1.clone().into();
+
+        let outer_snark_parameters = Components::OuterSNARK::setup(
+            &OuterCircuit::blank(
+                system_parameters.clone(),
+                ledger_parameters.clone(),
+                inner_snark_vk,
+                to_bytes![noop_program_snark_parameters.verification_key]?,
+            ),
+            rng,
+        )?;
+        end_timer!(snark_setup_time);
+        end_timer!(setup_time);
+
+        let inner_snark_parameters = (Some(inner_snark_parameters.0), inner_snark_parameters.1);
+        let outer_snark_parameters = (Some(outer_snark_parameters.0), outer_snark_parameters.1);
+
+        Ok(PublicParameters {
+            system_parameters,
+            noop_program_snark_parameters,
+            inner_snark_parameters,
+            outer_snark_parameters,
+            conflict_policy: ConflictPolicy::default(),
+            network,
+        })
+    }
+
+    fn create_account<R: Rng>(parameters: &Self::SystemParameters, rng: &mut R) -> anyhow::Result<Self::Account> {
+        let time = start_timer!(|| "BaseDPC::create_account");
+        let account = Account::new(
+            &parameters.account_signature,
+            &parameters.account_commitment,
+            &parameters.account_encryption,
+            rng,
+        )?;
+        end_timer!(time);
+
+        Ok(account)
+    }
+
+    fn execute_offline<R: Rng>(
+        parameters: Self::SystemParameters,
+        old_records: Vec<Self::Record>,
+        old_account_private_keys: Vec<<Self::Account as AccountScheme>::AccountPrivateKey>,
+        new_record_owners: Vec<<Self::Account as AccountScheme>::AccountAddress>,
+        new_is_dummy_flags: &[bool],
+        new_values: &[u64],
+        new_payloads: Vec<Self::Payload>,
+        new_birth_program_ids: Vec<Vec<u8>>,
+        new_death_program_ids: Vec<Vec<u8>>,
+        memorandum: <Self::Transaction as TransactionScheme>::Memorandum,
+        network_id: u8,
+        rng: &mut R,
+    ) -> anyhow::Result<Self::TransactionKernel> {
+        assert_eq!(Components::NUM_INPUT_RECORDS, old_records.len());
+        assert_eq!(Components::NUM_INPUT_RECORDS, old_account_private_keys.len());
+
+        assert_eq!(Components::NUM_OUTPUT_RECORDS, new_record_owners.len());
+        assert_eq!(Components::NUM_OUTPUT_RECORDS, new_is_dummy_flags.len());
+        assert_eq!(Components::NUM_OUTPUT_RECORDS, new_payloads.len());
+        assert_eq!(Components::NUM_OUTPUT_RECORDS, new_birth_program_ids.len());
+        assert_eq!(Components::NUM_OUTPUT_RECORDS, new_death_program_ids.len());
+
+        Self::check_input_records(&parameters, &old_records, &old_account_private_keys)?;
+
+        let mut old_serial_numbers = Vec::with_capacity(Components::NUM_INPUT_RECORDS);
+        let mut old_randomizers = Vec::with_capacity(Components::NUM_INPUT_RECORDS);
+        let mut joint_serial_numbers = Vec::new();
+        let mut old_death_program_ids = Vec::with_capacity(old_records.len());
+
+        let mut value_balance = AleoAmount::ZERO;
+
+        // Compute the ledger membership witness and serial number from the old records.
+        for (i, record) in old_records.iter().enumerate() {
+            let input_record_time = start_timer!(|| format!("Process input record {}", i));
+
+            if !record.is_dummy() {
+                value_balance = value_balance
+                    .checked_add(record.value())
+                    .ok_or(TransactionError::ValueBalanceOverflow)?;
+            }
+
+            let (sn, randomizer) = Self::generate_sn(&parameters, record, &old_account_private_keys[i])?;
+            joint_serial_numbers.extend_from_slice(&to_bytes![sn]?);
+            old_serial_numbers.push(sn);
+            old_randomizers.push(randomizer);
+            old_death_program_ids.push(record.death_program_id().to_vec());
+
+            end_timer!(input_record_time);
+        }
+
+        let mut new_records = Vec::with_capacity(Components::NUM_OUTPUT_RECORDS);
+        let mut new_commitments = Vec::with_capacity(Components::NUM_OUTPUT_RECORDS);
+        let mut new_sn_nonce_randomness = Vec::with_capacity(Components::NUM_OUTPUT_RECORDS);
+
+        // Generate new records and commitments for them.
+        for (j, (new_record_owner, new_payload, new_death_program_id)) in
+            izip!(new_record_owners, new_payloads, new_death_program_ids).enumerate()
+        {
+            if j == Components::NUM_OUTPUT_RECORDS {
+                break;
+            }
+
+            let output_record_time = start_timer!(|| format!("Process output record {}", j));
+            let sn_nonce_time = start_timer!(|| "Generate serial number nonce");
+
+            // Sample randomness sn_randomness for the CRH input.
+            let sn_randomness: [u8; 32] = rng.gen();
+
+            let crh_input = to_bytes![j as u8, sn_randomness, joint_serial_numbers]?;
+            let sn_nonce = Components::SerialNumberNonceCRH::hash(&parameters.serial_number_nonce, &crh_input)?;
+
+            end_timer!(sn_nonce_time);
+
+            let value = AleoAmount::try_from(new_values[j])
+                .map_err(|_| TransactionError::RecordValueOutOfRange(new_values[j]))?;
+            let record = Self::generate_record_with_amount(
+                &parameters,
+                sn_nonce,
+                new_record_owner,
+                new_is_dummy_flags[j],
+                value,
+                new_payload,
+                new_birth_program_ids[j].clone(),
+                new_death_program_id,
+                rng,
+            )?;
+
+            if !record.is_dummy() {
+                value_balance = value_balance
+                    .checked_sub(record.value())
+                    .ok_or(TransactionError::ValueBalanceOverflow)?;
+            }
+
+            new_commitments.push(record.commitment().into_inner());
+            new_sn_nonce_randomness.push(sn_randomness);
+            new_records.push(record);
+
+            end_timer!(output_record_time);
+        }
+
+        // TODO (raychu86) Add index and program register inputs + outputs to local data commitment leaves
+        let local_data_merkle_tree_timer = start_timer!(|| "Compute local data merkle tree");
+
+        let mut local_data_commitment_randomizers = Vec::with_capacity(Components::NUM_INPUT_RECORDS);
+
+        let mut old_record_commitments = Vec::with_capacity(Components::NUM_INPUT_RECORDS);
+        for i in 0..Components::NUM_INPUT_RECORDS {
+            let record = &old_records[i];
+            let input_bytes = to_bytes![old_serial_numbers[i], record.commitment(), memorandum, network_id]?;
+
+            let commitment_randomness = <Components::LocalDataCommitment as CommitmentScheme>::Randomness::rand(rng);
+            let commitment = Components::LocalDataCommitment::commit(
+                &parameters.local_data_commitment,
+                &input_bytes,
+                &commitment_randomness,
+            )?;
+
+            old_record_commitments.push(commitment);
+            local_data_commitment_randomizers.push(commitment_randomness);
+        }
+
+        let mut new_record_commitments = Vec::with_capacity(Components::NUM_OUTPUT_RECORDS);
+        for record in new_records.iter().take(Components::NUM_OUTPUT_RECORDS) {
+            let input_bytes = to_bytes![record.commitment(), memorandum, network_id]?;
+
+            let commitment_randomness = <Components::LocalDataCommitment as CommitmentScheme>::Randomness::rand(rng);
+            let commitment = Components::LocalDataCommitment::commit(
+                &parameters.local_data_commitment,
+                &input_bytes,
+                &commitment_randomness,
+            )?;
+
+            new_record_commitments.push(commitment);
+            local_data_commitment_randomizers.push(commitment_randomness);
+        }
+
+        let local_data_merkle_tree = CommitmentMerkleTree::from_subtrees(
+            parameters.local_data_crh.clone(),
+            &old_record_commitments,
+            &new_record_commitments,
+        )?;
+
+        end_timer!(local_data_merkle_tree_timer);
+
+        let program_comm_timer = start_timer!(|| "Compute program commitment");
+        let (program_commitment, program_randomness) = {
+            let mut input = Vec::new();
+            for id in old_death_program_ids {
+                input.extend_from_slice(&id);
+            }
+
+            for id in new_birth_program_ids {
+                input.extend_from_slice(&id);
+            }
+            let program_randomness =
+                <Components::ProgramVerificationKeyCommitment as CommitmentScheme>::Randomness::rand(rng);
+            let program_commitment = Components::ProgramVerificationKeyCommitment::commit(
+                &parameters.program_verification_key_commitment,
+                &input,
+                &program_randomness,
+            )?;
+            (program_commitment, program_randomness)
+        };
+        end_timer!(program_comm_timer);
+
+        // Encrypt the new records
+
+        // The randomness is bound to the secret of the first input account.
+        let derived_randomizers = if Components::BIND_ENCRYPTION_RANDOMNESS {
+            Some(DerivedRandomizers::derive(
+                &parameters,
+                &to_bytes![old_account_private_keys[0].sk_prf]?,
+                &old_serial_numbers,
+                &new_records,
+                memorandum.as_bytes(),
+                network_id,
+            )?)
+        } else {
+            None
+        };
+
+        let mut new_records_encryption_randomness = Vec::with_capacity(Components::NUM_OUTPUT_RECORDS);
+        let mut new_encrypted_records = Vec::with_capacity(Components::NUM_OUTPUT_RECORDS);
+
+        for (j, record) in new_records.iter().enumerate() {
+            let (record_encryption_randomness, encrypted_record) = match &derived_randomizers {
+                Some(derived_randomizers) => {
+                    let randomness = derived_randomizers.encryption_randomness[j].clone();
+                    let encrypted_record =
+                        RecordEncryption::encrypt_record_with_randomness(&parameters, record, &randomness)?;
+                    (randomness, encrypted_record)
+                }
+                None => RecordEncryption::encrypt_record(&parameters, record, rng)?,
+            };
+
+            new_records_encryption_randomness.push(record_encryption_randomness);
+            new_encrypted_records.push(encrypted_record);
+        }
+
+        // Construct the ciphertext hashes
+
+        let mut new_encrypted_record_hashes = Vec::with_capacity(Components::NUM_OUTPUT_RECORDS);
+        for encrypted_record in &new_encrypted_records {
+            let encrypted_record_hash = RecordEncryption::encrypted_record_hash(&parameters, &encrypted_record)?;
+
+            new_encrypted_record_hashes.push(encrypted_record_hash);
+        }
+
+        let transaction_kernel = TransactionKernel {
+            system_parameters: parameters,
+
+            old_records,
+            old_account_private_keys,
+            old_serial_numbers,
+            old_randomizers,
+
+            new_records,
+            new_sn_nonce_randomness,
+            new_commitments,
+
+            new_records_encryption_randomness,
+            new_encrypted_records,
+            new_encrypted_record_hashes,
+
+            program_commitment,
+            program_randomness,
+            local_data_merkle_tree,
+            local_data_commitment_randomizers,
+
+            value_balance,
+            memorandum,
+            network_id,
+        };
+        Ok(transaction_kernel)
+    }
+
+    fn execute_online<R: Rng>(
+        parameters: &Self::NetworkParameters,
+        transaction_kernel: Self::TransactionKernel,
+        old_death_program_proofs: Vec<Self::PrivateProgramInput>,
+        new_birth_program_proofs: Vec<Self::PrivateProgramInput>,
+        ledger: &L,
+        rng: &mut R,
+    ) -> anyhow::Result<(Vec<Self::Record>, Self::Transaction)> {
+        let (new_records, transaction, _) = Self::execute_online_with_receipt(
+            parameters,
+            transaction_kernel,
+            old_death_program_proofs,
+            new_birth_program_proofs,
+            ledger,
+            rng,
+        )?;
+        Ok((new_records, transaction))
+    }
+
+    fn verify(
+        parameters: &Self::NetworkParameters,
+        transaction: &Self::Transaction,
+        ledger: &L,
+    ) -> anyhow::Result<bool> {
+        let verify_time = start_timer!(|| "BaseDPC::verify");
+
+        let inner_circuit_id = Self::inner_circuit_id(parameters)?;
+        let is_valid = Self::verify_with_inner_circuit_id(parameters, transaction, ledger, &inner_circuit_id)?;
+
+        end_timer!(verify_time);
+
+        Ok(is_valid)
+    }
+
+    /// Returns true iff all the transactions in the block are valid according to the ledger,
+    /// and no two of them conflict with each other under the conflict policy of the network.
+    fn verify_transactions(
+        parameters: &Self::NetworkParameters,
+        transactions: &[Self::Transaction],
+        ledger: &L,
+    ) -> anyhow::Result<bool> {
+        let verify_time = start_timer!(|| "BaseDPC::verify_transactions");
+
+        // Returns false if any two transactions in the block conflict with each other.
+        if Transactions::contains_conflicts(transactions, parameters.conflict_policy)? {
+            eprintln!("Block contains conflicting transactions.");
+            return Ok(false);
+        }
+
+        // The inner circuit id is the same for every transaction, so it is derived once per block.
+        let inner_circuit_id = Self::inner_circuit_id(parameters)?;
+
+        let results = cfg_iter!(transactions)
+            .map(|transaction| Self::verify_with_inner_circuit_id(parameters, transaction, ledger, &inner_circuit_id))
+            .collect::<anyhow::Result<Vec<bool>>>()?;
+
+        end_timer!(verify_time);
+
+        Ok(results.into_iter().all(|is_valid| is_valid))
     }
 }
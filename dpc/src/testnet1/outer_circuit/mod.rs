@@ -22,3 +22,9 @@ pub use outer_circuit_gadget::*;
 
 pub mod outer_circuit_verifier_input;
 pub use outer_circuit_verifier_input::*;
+
+pub mod program_proof_verification_gadget;
+pub use program_proof_verification_gadget::*;
+
+#[cfg(test)]
+mod tests;
@@ -47,7 +47,7 @@ pub struct OuterCircuit<C: BaseDPCComponents> {
 
     // Inner snark verifier private inputs
     inner_snark_vk: <C::InnerSNARK as SNARK>::VerifyingKey,
-    inner_snark_proof: <C::InnerSNARK as SNARK>::Proof,
+    inner_snark_proof: Option<<C::InnerSNARK as SNARK>::Proof>,
 
     old_private_program_inputs: Vec<PrivateProgramInput>,
     new_private_program_inputs: Vec<PrivateProgramInput>,
@@ -60,12 +60,15 @@ pub struct OuterCircuit<C: BaseDPCComponents> {
 }
 
 impl<C: BaseDPCComponents> OuterCircuit<C> {
+    /// Returns a circuit without a witness, for use in setup mode.
+    ///
+    /// Only the verification keys are required, as they determine the structure of the
+    /// verifier gadgets. The inner SNARK proof and the program proofs are left unassigned.
     pub fn blank(
         system_parameters: SystemParameters<C>,
         ledger_parameters: Arc<C::MerkleParameters>,
         inner_snark_vk: <C::InnerSNARK as SNARK>::VerifyingKey,
-        inner_snark_proof: <C::InnerSNARK as SNARK>::Proof,
-        program_snark_vk_and_proof: PrivateProgramInput,
+        program_snark_vk_bytes: Vec<u8>,
     ) -> Self {
         let num_input_records = C::NUM_INPUT_RECORDS;
         let num_output_records = C::NUM_OUTPUT_RECORDS;
@@ -79,6 +82,10 @@ impl<C: BaseDPCComponents> OuterCircuit<C> {
         let value_balance = AleoAmount::ZERO;
         let network_id = 0;
 
+        let program_snark_vk_and_proof = PrivateProgramInput {
+            verification_key: program_snark_vk_bytes,
+            proof: vec![],
+        };
         let old_private_program_inputs = vec![program_snark_vk_and_proof.clone(); num_input_records];
         let new_private_program_inputs = vec![program_snark_vk_and_proof; num_output_records];
 
@@ -99,7 +106,7 @@ impl<C: BaseDPCComponents> OuterCircuit<C> {
             value_balance,
             network_id,
             inner_snark_vk,
-            inner_snark_proof,
+            inner_snark_proof: None,
             old_private_program_inputs,
             new_private_program_inputs,
             program_commitment,
@@ -161,7 +168,7 @@ impl<C: BaseDPCComponents> OuterCircuit<C> {
             value_balance,
             network_id,
             inner_snark_vk,
-            inner_snark_proof,
+            inner_snark_proof: Some(inner_snark_proof),
             old_private_program_inputs,
             new_private_program_inputs,
             program_commitment,
@@ -212,7 +219,7 @@ where
             self.value_balance,
             self.network_id,
             &self.inner_snark_vk,
-            &self.inner_snark_proof,
+            self.inner_snark_proof.as_ref(),
             &self.old_private_program_inputs,
             &self.new_private_program_inputs,
             &self.program_commitment,
@@ -16,7 +16,13 @@
 
 use itertools::Itertools;
 
-use crate::testnet1::{parameters::SystemParameters, program::PrivateProgramInput, AleoAmount, BaseDPCComponents};
+use crate::testnet1::{
+    outer_circuit::ProgramProofVerificationGadget,
+    parameters::SystemParameters,
+    program::PrivateProgramInput,
+    AleoAmount,
+    BaseDPCComponents,
+};
 use snarkvm_algorithms::{
     merkle_tree::MerkleTreeDigest,
     traits::{CommitmentScheme, EncryptionScheme, MerkleParameters, SignatureScheme, CRH, SNARK},
@@ -32,7 +38,7 @@ use snarkvm_gadgets::{
         integers::integer::Integer,
     },
 };
-use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+use snarkvm_r1cs::{errors::SynthesisError, Assignment, ConstraintSystem};
 use snarkvm_utilities::{bytes::ToBytes, to_bytes};
 
 fn field_element_to_bytes<C: BaseDPCComponents, CS: ConstraintSystem<C::OuterField>>(
@@ -75,7 +81,7 @@ pub fn execute_outer_proof_gadget<C: BaseDPCComponents, CS: ConstraintSystem<C::
 
     // Inner snark verifier private inputs (verification key and proof)
     inner_snark_vk: &<C::InnerSNARK as SNARK>::VerifyingKey,
-    inner_snark_proof: &<C::InnerSNARK as SNARK>::Proof,
+    inner_snark_proof: Option<&<C::InnerSNARK as SNARK>::Proof>,
 
     // Old record death program verification keys and proofs
     old_death_program_verification_inputs: &[PrivateProgramInput],
@@ -315,7 +321,7 @@ where
 
     let inner_snark_proof = <C::InnerSNARKGadget as SNARKVerifierGadget<_, _>>::ProofGadget::alloc(
         &mut cs.ns(|| "Allocate inner snark proof"),
-        || Ok(inner_snark_proof),
+        || inner_snark_proof.get(),
     )?;
 
     C::InnerSNARKGadget::check_verify(
@@ -353,8 +359,9 @@ where
     // ************************************************************************
     // ************************************************************************
 
-    let mut old_death_program_ids = Vec::with_capacity(C::NUM_INPUT_RECORDS);
-    let mut new_birth_program_ids = Vec::with_capacity(C::NUM_OUTPUT_RECORDS);
+    let mut program_verification_gadget =
+        ProgramProofVerificationGadget::<C>::new(program_vk_crh_parameters, program_vk_commitment_parameters);
+
     for (i, input) in old_death_program_verification_inputs
         .iter()
         .enumerate()
@@ -373,26 +380,12 @@ where
                 || Ok(&input.verification_key),
             )?;
 
-        let death_program_vk_bytes = death_program_vk.to_bytes(&mut cs.ns(|| "Convert death pred vk to bytes"))?;
-
-        let claimed_death_program_id = C::ProgramVerificationKeyCRHGadget::check_evaluation_gadget(
-            &mut cs.ns(|| "Compute death program vk hash"),
-            &program_vk_crh_parameters,
-            death_program_vk_bytes,
-        )?;
-
-        let claimed_death_program_id_bytes =
-            claimed_death_program_id.to_bytes(&mut cs.ns(|| "Convert death_pred vk hash to bytes"))?;
-
-        old_death_program_ids.push(claimed_death_program_id_bytes);
-
-        let position = UInt8::constant(i as u8).to_bits_le();
-
-        C::ProgramSNARKGadget::check_verify(
-            &mut cs.ns(|| "Check that proof is satisfied"),
+        program_verification_gadget.verify_program(
+            &mut cs.ns(|| "Verify death program"),
             &death_program_vk,
-            ([position].iter()).chain(program_input_bits.iter()).cloned(),
             &death_program_proof,
+            i as u8,
+            &program_input_bits,
         )?;
     }
 
@@ -414,26 +407,12 @@ where
                 || Ok(&input.verification_key),
             )?;
 
-        let birth_program_vk_bytes = birth_program_vk.to_bytes(&mut cs.ns(|| "Convert birth pred vk to bytes"))?;
-
-        let claimed_birth_program_id = C::ProgramVerificationKeyCRHGadget::check_evaluation_gadget(
-            &mut cs.ns(|| "Compute birth program vk hash"),
-            &program_vk_crh_parameters,
-            birth_program_vk_bytes,
-        )?;
-
-        let claimed_birth_program_id_bytes =
-            claimed_birth_program_id.to_bytes(&mut cs.ns(|| "Convert birth_pred vk hash to bytes"))?;
-
-        new_birth_program_ids.push(claimed_birth_program_id_bytes);
-
-        let position = UInt8::constant((C::NUM_INPUT_RECORDS + j) as u8).to_bits_le();
-
-        C::ProgramSNARKGadget::check_verify(
-            &mut cs.ns(|| "Check that proof is satisfied"),
+        program_verification_gadget.verify_program(
+            &mut cs.ns(|| "Verify birth program"),
             &birth_program_vk,
-            ([position].iter()).chain(program_input_bits.iter()).cloned(),
             &birth_program_proof,
+            (C::NUM_INPUT_RECORDS + j) as u8,
+            &program_input_bits,
         )?;
     }
     // ********************************************************************
@@ -444,15 +423,6 @@ where
     {
         let commitment_cs = &mut cs.ns(|| "Check that program commitment is well-formed");
 
-        let mut input = Vec::new();
-        for id in old_death_program_ids.iter().take(C::NUM_INPUT_RECORDS) {
-            input.extend_from_slice(&id);
-        }
-
-        for id in new_birth_program_ids.iter().take(C::NUM_OUTPUT_RECORDS) {
-            input.extend_from_slice(&id);
-        }
-
         let given_commitment_randomness =
             <C::ProgramVerificationKeyCommitmentGadget as CommitmentGadget<_, C::OuterField>>::RandomnessGadget::alloc(
                 &mut commitment_cs.ns(|| "Commitment randomness"),
@@ -464,17 +434,10 @@ where
             || Ok(program_commitment),
         )?;
 
-        let candidate_commitment =
-            <C::ProgramVerificationKeyCommitmentGadget as CommitmentGadget<_, C::OuterField>>::check_commitment_gadget(
-                &mut commitment_cs.ns(|| "Compute commitment"),
-                &program_vk_commitment_parameters,
-                &input,
-                &given_commitment_randomness,
-            )?;
-
-        candidate_commitment.enforce_equal(
-            &mut commitment_cs.ns(|| "Check that declared and computed commitments are equal"),
+        program_verification_gadget.enforce_program_commitment(
+            commitment_cs,
             &given_commitment,
+            &given_commitment_randomness,
         )?;
     }
 
@@ -14,7 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::testnet1::{inner_circuit_verifier_input::InnerCircuitVerifierInput, BaseDPCComponents};
+use crate::testnet1::{
+    inner_circuit_verifier_input::InnerCircuitVerifierInput,
+    input_layout::{InputLayout, PublicInputs},
+    BaseDPCComponents,
+};
 use snarkvm_algorithms::{
     merkle_tree::MerkleTreeDigest,
     traits::{CommitmentScheme, EncryptionScheme, MerkleParameters, SignatureScheme, CRH},
@@ -29,7 +33,7 @@ pub struct OuterCircuitVerifierInput<C: BaseDPCComponents> {
     pub inner_circuit_id: <C::InnerCircuitIDCRH as CRH>::Output,
 }
 
-impl<C: BaseDPCComponents> ToConstraintField<C::OuterField> for OuterCircuitVerifierInput<C>
+impl<C: BaseDPCComponents> OuterCircuitVerifierInput<C>
 where
     <C::ProgramVerificationKeyCommitment as CommitmentScheme>::Parameters: ToConstraintField<C::OuterField>,
     <C::ProgramVerificationKeyCommitment as CommitmentScheme>::Output: ToConstraintField<C::OuterField>,
@@ -63,46 +67,87 @@ where
     <<C::MerkleParameters as MerkleParameters>::H as CRH>::Parameters: ToConstraintField<C::InnerField>,
     MerkleTreeDigest<C::MerkleParameters>: ToConstraintField<C::InnerField>,
 {
-    fn to_field_elements(&self) -> Result<Vec<C::OuterField>, ConstraintFieldError> {
-        let mut v = Vec::new();
-
-        v.extend_from_slice(
-            &self
-                .inner_snark_verifier_input
-                .system_parameters
-                .program_verification_key_commitment
-                .parameters()
-                .to_field_elements()?,
-        );
-        v.extend_from_slice(
-            &self
-                .inner_snark_verifier_input
-                .system_parameters
-                .program_verification_key_crh
-                .parameters()
-                .to_field_elements()?,
-        );
-        v.extend_from_slice(
-            &self
-                .inner_snark_verifier_input
-                .system_parameters
-                .inner_circuit_id_crh
-                .parameters()
-                .to_field_elements()?,
-        );
-        // Convert inner snark verifier inputs into `OuterField` field elements
+    /// Returns the layout of the public input of the outer circuit.
+    pub fn describe_inputs(&self) -> Result<InputLayout, ConstraintFieldError> {
+        Ok(self.public_inputs()?.into_layout())
+    }
 
-        let inner_snark_field_elements = &self.inner_snark_verifier_input.to_field_elements()?;
+    /// Returns the public input of the outer circuit, in the order in which the outer circuit
+    /// allocates it.
+    pub(crate) fn public_inputs(&self) -> Result<PublicInputs<C::OuterField>, ConstraintFieldError> {
+        let parameters = &self.inner_snark_verifier_input.system_parameters;
+        let mut inputs = PublicInputs::new();
+
+        inputs.push(
+            "program_verification_key_commitment_parameters",
+            parameters.program_verification_key_commitment.parameters(),
+        )?;
+        inputs.push(
+            "program_verification_key_crh_parameters",
+            parameters.program_verification_key_crh.parameters(),
+        )?;
+        inputs.push(
+            "inner_circuit_id_crh_parameters",
+            parameters.inner_circuit_id_crh.parameters(),
+        )?;
 
-        for inner_snark_fe in inner_snark_field_elements {
-            let inner_snark_fe_bytes = to_bytes![inner_snark_fe]?;
-            v.extend_from_slice(&ToConstraintField::<C::OuterField>::to_field_elements(
-                inner_snark_fe_bytes.as_slice(),
-            )?);
+        // Convert inner snark verifier inputs into `OuterField` field elements
+        let inner_snark_inputs = self.inner_snark_verifier_input.public_inputs()?;
+        for (segment, inner_snark_field_elements) in inner_snark_inputs.segments() {
+            for (i, inner_snark_fe) in inner_snark_field_elements.iter().enumerate() {
+                let name = match inner_snark_field_elements.len() {
+                    1 => format!("inner_snark_input.{}", segment.name),
+                    _ => format!("inner_snark_input.{}[{}]", segment.name, i),
+                };
+                inputs.push_bytes(name, &to_bytes![inner_snark_fe]?)?;
+            }
         }
 
-        v.extend_from_slice(&self.inner_snark_verifier_input.program_commitment.to_field_elements()?);
-        v.extend_from_slice(&self.inner_circuit_id.to_field_elements()?);
-        Ok(v)
+        inputs.push(
+            "program_commitment",
+            &self.inner_snark_verifier_input.program_commitment,
+        )?;
+        inputs.push("inner_circuit_id", &self.inner_circuit_id)?;
+
+        Ok(inputs)
+    }
+}
+
+impl<C: BaseDPCComponents> ToConstraintField<C::OuterField> for OuterCircuitVerifierInput<C>
+where
+    <C::ProgramVerificationKeyCommitment as CommitmentScheme>::Parameters: ToConstraintField<C::OuterField>,
+    <C::ProgramVerificationKeyCommitment as CommitmentScheme>::Output: ToConstraintField<C::OuterField>,
+    <C::ProgramVerificationKeyCRH as CRH>::Parameters: ToConstraintField<C::OuterField>,
+
+    <C::InnerCircuitIDCRH as CRH>::Parameters: ToConstraintField<C::OuterField>,
+    <C::InnerCircuitIDCRH as CRH>::Output: ToConstraintField<C::OuterField>,
+
+    <C::AccountCommitment as CommitmentScheme>::Parameters: ToConstraintField<C::InnerField>,
+    <C::AccountCommitment as CommitmentScheme>::Output: ToConstraintField<C::InnerField>,
+
+    <C::AccountEncryption as EncryptionScheme>::Parameters: ToConstraintField<C::InnerField>,
+
+    <C::AccountSignature as SignatureScheme>::Parameters: ToConstraintField<C::InnerField>,
+    <C::AccountSignature as SignatureScheme>::PublicKey: ToConstraintField<C::InnerField>,
+
+    <C::RecordCommitment as CommitmentScheme>::Parameters: ToConstraintField<C::InnerField>,
+    <C::RecordCommitment as CommitmentScheme>::Output: ToConstraintField<C::InnerField>,
+
+    <C::EncryptedRecordCRH as CRH>::Parameters: ToConstraintField<C::InnerField>,
+    <C::EncryptedRecordCRH as CRH>::Output: ToConstraintField<C::InnerField>,
+
+    <C::SerialNumberNonceCRH as CRH>::Parameters: ToConstraintField<C::InnerField>,
+
+    <C::ProgramVerificationKeyCommitment as CommitmentScheme>::Parameters: ToConstraintField<C::InnerField>,
+    <C::ProgramVerificationKeyCommitment as CommitmentScheme>::Output: ToConstraintField<C::InnerField>,
+
+    <C::LocalDataCRH as CRH>::Parameters: ToConstraintField<C::InnerField>,
+    <C::LocalDataCRH as CRH>::Output: ToConstraintField<C::InnerField>,
+
+    <<C::MerkleParameters as MerkleParameters>::H as CRH>::Parameters: ToConstraintField<C::InnerField>,
+    MerkleTreeDigest<C::MerkleParameters>: ToConstraintField<C::InnerField>,
+{
+    fn to_field_elements(&self) -> Result<Vec<C::OuterField>, ConstraintFieldError> {
+        Ok(self.public_inputs()?.into_field_elements())
     }
 }
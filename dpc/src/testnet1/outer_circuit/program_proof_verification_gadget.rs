@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{testnet1::BaseDPCComponents, traits::DPCComponents};
+use snarkvm_gadgets::{
+    bits::{Boolean, ToBytesGadget},
+    integers::uint::UInt8,
+    traits::{
+        algorithms::{CRHGadget, CommitmentGadget, PreparedSNARKVerifierGadget, SNARKVerifierGadget},
+        eq::EqGadget,
+        integers::integer::Integer,
+    },
+};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+type ProgramVerificationKeyGadget<C> = <<C as BaseDPCComponents>::ProgramSNARKGadget as SNARKVerifierGadget<
+    <C as BaseDPCComponents>::NoopProgramSNARK,
+    <C as DPCComponents>::OuterField,
+>>::VerificationKeyGadget;
+
+type ProgramProofGadget<C> = <<C as BaseDPCComponents>::ProgramSNARKGadget as SNARKVerifierGadget<
+    <C as BaseDPCComponents>::NoopProgramSNARK,
+    <C as DPCComponents>::OuterField,
+>>::ProofGadget;
+
+type ProgramVerificationKeyCRHParametersGadget<C> =
+    <<C as DPCComponents>::ProgramVerificationKeyCRHGadget as CRHGadget<
+        <C as DPCComponents>::ProgramVerificationKeyCRH,
+        <C as DPCComponents>::OuterField,
+    >>::ParametersGadget;
+
+type ProgramCommitmentGadget<C> = <C as DPCComponents>::ProgramVerificationKeyCommitmentGadget;
+
+type ProgramCommitmentParametersGadget<C> = <ProgramCommitmentGadget<C> as CommitmentGadget<
+    <C as DPCComponents>::ProgramVerificationKeyCommitment,
+    <C as DPCComponents>::OuterField,
+>>::ParametersGadget;
+
+type ProgramCommitmentOutputGadget<C> = <ProgramCommitmentGadget<C> as CommitmentGadget<
+    <C as DPCComponents>::ProgramVerificationKeyCommitment,
+    <C as DPCComponents>::OuterField,
+>>::OutputGadget;
+
+type ProgramCommitmentRandomnessGadget<C> = <ProgramCommitmentGadget<C> as CommitmentGadget<
+    <C as DPCComponents>::ProgramVerificationKeyCommitment,
+    <C as DPCComponents>::OuterField,
+>>::RandomnessGadget;
+
+/// Verifies the program proofs of a transaction, and that the program ids, which are the hashes
+/// of the program verification keys, open the program commitment of the transaction in order.
+pub struct ProgramProofVerificationGadget<C: BaseDPCComponents> {
+    program_vk_crh_parameters: ProgramVerificationKeyCRHParametersGadget<C>,
+    program_vk_commitment_parameters: ProgramCommitmentParametersGadget<C>,
+    program_ids: Vec<Vec<UInt8>>,
+}
+
+impl<C: BaseDPCComponents> ProgramProofVerificationGadget<C> {
+    pub fn new(
+        program_vk_crh_parameters: ProgramVerificationKeyCRHParametersGadget<C>,
+        program_vk_commitment_parameters: ProgramCommitmentParametersGadget<C>,
+    ) -> Self {
+        Self {
+            program_vk_crh_parameters,
+            program_vk_commitment_parameters,
+            program_ids: Vec::with_capacity(C::NUM_INPUT_RECORDS + C::NUM_OUTPUT_RECORDS),
+        }
+    }
+
+    /// Verifies the proof of the program for the record at the given position, whose public
+    /// inputs are the position followed by `program_input_bits`, and records the program id.
+    pub fn verify_program<CS: ConstraintSystem<C::OuterField>>(
+        &mut self,
+        mut cs: CS,
+        verification_key: &ProgramVerificationKeyGadget<C>,
+        proof: &ProgramProofGadget<C>,
+        position: u8,
+        program_input_bits: &[Vec<Boolean>],
+    ) -> Result<(), SynthesisError> {
+        let verification_key_bytes = verification_key.to_bytes(&mut cs.ns(|| "Convert program vk to bytes"))?;
+
+        let program_id = C::ProgramVerificationKeyCRHGadget::check_evaluation_gadget(
+            &mut cs.ns(|| "Compute program vk hash"),
+            &self.program_vk_crh_parameters,
+            verification_key_bytes,
+        )?;
+
+        let program_id_bytes = program_id.to_bytes(&mut cs.ns(|| "Convert program vk hash to bytes"))?;
+        self.program_ids.push(program_id_bytes);
+
+        let position = UInt8::constant(position).to_bits_le();
+
+        let prepared_verification_key =
+            C::ProgramSNARKGadget::prepare_verification_key(&mut cs.ns(|| "Prepare program vk"), verification_key)?;
+
+        C::ProgramSNARKGadget::check_verify_with_prepared(
+            &mut cs.ns(|| "Check that proof is satisfied"),
+            &prepared_verification_key,
+            ([position].iter()).chain(program_input_bits.iter()).cloned(),
+            proof,
+        )
+    }
+
+    /// Returns the ids of the verified programs, in the order they were verified.
+    pub fn program_ids(&self) -> &[Vec<UInt8>] {
+        &self.program_ids
+    }
+
+    /// Enforces that the ids of the verified programs, in the order they were verified,
+    /// open the given program commitment under the given randomness.
+    pub fn enforce_program_commitment<CS: ConstraintSystem<C::OuterField>>(
+        &self,
+        mut cs: CS,
+        program_commitment: &ProgramCommitmentOutputGadget<C>,
+        program_randomness: &ProgramCommitmentRandomnessGadget<C>,
+    ) -> Result<(), SynthesisError> {
+        let input = self.program_ids.concat();
+
+        let candidate_commitment =
+            <C::ProgramVerificationKeyCommitmentGadget as CommitmentGadget<_, C::OuterField>>::check_commitment_gadget(
+                &mut cs.ns(|| "Compute commitment"),
+                &self.program_vk_commitment_parameters,
+                &input,
+                program_randomness,
+            )?;
+
+        candidate_commitment.enforce_equal(
+            &mut cs.ns(|| "Check that declared and computed commitments are equal"),
+            program_commitment,
+        )
+    }
+}
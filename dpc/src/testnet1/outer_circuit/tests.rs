@@ -0,0 +1,382 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::testnet1::{instantiated::*, NoopCircuit, OuterCircuit, ProgramProofVerificationGadget, SystemParameters};
+use snarkvm_algorithms::{
+    snark::gm17::GM17,
+    traits::{CommitmentScheme, MerkleParameters, CRH, SNARK},
+};
+use snarkvm_fields::{Field, ToConstraintField};
+use snarkvm_gadgets::{
+    integers::uint::UInt8,
+    traits::{
+        algorithms::{CRHGadget, CommitmentGadget, SNARKVerifierGadget},
+        alloc::{AllocBytesGadget, AllocGadget},
+        integers::integer::Integer,
+    },
+};
+use snarkvm_parameters::{
+    testnet1::{InnerSNARKVKParameters, NoopProgramSNARKVKParameters},
+    LedgerMerkleTreeParameters,
+    Parameter,
+};
+use snarkvm_r1cs::{
+    errors::SynthesisError,
+    ConstraintCounter,
+    ConstraintSynthesizer,
+    ConstraintSystem,
+    TestConstraintSystem,
+};
+use snarkvm_utilities::{
+    bytes::{FromBytes, ToBytes},
+    rand::UniformRand,
+    to_bytes,
+};
+
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use std::sync::Arc;
+
+/// A program that knows a `2^num_squarings`-th root of its input.
+#[derive(Clone)]
+struct RootProgram {
+    num_squarings: usize,
+    position: u8,
+    root: InnerField,
+}
+
+impl ConstraintSynthesizer<InnerField> for RootProgram {
+    fn generate_constraints<CS: ConstraintSystem<InnerField>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        cs.alloc_input(|| "position", || Ok(InnerField::from(self.position as u64)))?;
+
+        let mut value = self.root;
+        let mut variable = cs.alloc(|| "root", || Ok(value))?;
+        for i in 0..self.num_squarings {
+            let square = value.square();
+            let square_variable = match i + 1 == self.num_squarings {
+                true => cs.alloc_input(|| "input", || Ok(square))?,
+                false => cs.alloc(|| format!("square {}", i), || Ok(square))?,
+            };
+            cs.enforce(
+                || format!("enforce square {}", i),
+                |lc| lc + variable,
+                |lc| lc + variable,
+                |lc| lc + square_variable,
+            );
+            value = square;
+            variable = square_variable;
+        }
+        Ok(())
+    }
+}
+
+type RootProgramSNARK = GM17<InnerPairing, RootProgram, Vec<InnerField>>;
+type ProgramVerificationKeyGadget =
+    <ProgramSNARKGadget as SNARKVerifierGadget<NoopProgramSNARK<Components>, OuterField>>::VerificationKeyGadget;
+type ProgramProofGadget =
+    <ProgramSNARKGadget as SNARKVerifierGadget<NoopProgramSNARK<Components>, OuterField>>::ProofGadget;
+
+/// The verification key, proof, position, and id of a program.
+struct ProgramProof {
+    verification_key: Vec<u8>,
+    proof: Vec<u8>,
+    position: u8,
+    id: Vec<u8>,
+}
+
+/// Proves two root programs with 1 and 2 squarings at positions 0 and 1, on a shared input.
+fn prove_root_programs<R: Rng>(
+    system_parameters: &SystemParameters<Components>,
+    rng: &mut R,
+) -> (Vec<ProgramProof>, InnerField) {
+    let root = InnerField::rand(rng);
+    let input = root.square().square();
+
+    let programs = [
+        RootProgram {
+            num_squarings: 1,
+            position: 0,
+            root: root.square(),
+        },
+        RootProgram {
+            num_squarings: 2,
+            position: 1,
+            root,
+        },
+    ];
+
+    let proofs = programs
+        .iter()
+        .map(|program| {
+            let (proving_key, prepared_verification_key) = RootProgramSNARK::setup(program, rng).unwrap();
+            let proof = RootProgramSNARK::prove(&proving_key, program, rng).unwrap();
+
+            let program_input = vec![InnerField::from(program.position as u64), input];
+            assert!(RootProgramSNARK::verify(&prepared_verification_key, &program_input, &proof).unwrap());
+
+            let verification_key = to_bytes![proving_key.vk].unwrap();
+            let id = to_bytes![
+                ProgramVerificationKeyCRH::hash(&system_parameters.program_verification_key_crh, &verification_key)
+                    .unwrap()
+            ]
+            .unwrap();
+
+            ProgramProof {
+                verification_key,
+                proof: to_bytes![proof].unwrap(),
+                position: program.position,
+                id,
+            }
+        })
+        .collect();
+
+    (proofs, input)
+}
+
+/// Verifies the given programs on the given inputs, and checks their ids against a commitment
+/// to the given ids, returning the constraint system.
+fn verify_programs(
+    system_parameters: &SystemParameters<Components>,
+    programs: &[ProgramProof],
+    inputs: &[InnerField],
+    committed_ids: &[&[u8]],
+) -> TestConstraintSystem<OuterField> {
+    let mut cs = TestConstraintSystem::<OuterField>::new();
+
+    let program_vk_crh_parameters =
+        <ProgramVerificationKeyCRHGadget as CRHGadget<_, OuterField>>::ParametersGadget::alloc_input(
+            &mut cs.ns(|| "Declare program vk CRH parameters"),
+            || Ok(system_parameters.program_verification_key_crh.parameters()),
+        )
+        .unwrap();
+    let program_vk_commitment_parameters =
+        <ProgramVerificationKeyCommitmentGadget as CommitmentGadget<_, OuterField>>::ParametersGadget::alloc_input(
+            &mut cs.ns(|| "Declare program vk commitment parameters"),
+            || Ok(system_parameters.program_verification_key_commitment.parameters()),
+        )
+        .unwrap();
+    let mut gadget =
+        ProgramProofVerificationGadget::<Components>::new(program_vk_crh_parameters, program_vk_commitment_parameters);
+
+    let program_input_bits = inputs
+        .iter()
+        .enumerate()
+        .map(|(i, input)| {
+            UInt8::alloc_input_vec_le(
+                &mut cs.ns(|| format!("Allocate input {}", i)),
+                &to_bytes![input].unwrap(),
+            )
+            .unwrap()
+            .iter()
+            .flat_map(|byte| byte.to_bits_le())
+            .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    for (i, program) in programs.iter().enumerate() {
+        let cs = &mut cs.ns(|| format!("Check program {}", i));
+        let verification_key =
+            ProgramVerificationKeyGadget::alloc_bytes(&mut cs.ns(|| "Allocate verification key"), || {
+                Ok(&program.verification_key)
+            })
+            .unwrap();
+        let proof = ProgramProofGadget::alloc_bytes(&mut cs.ns(|| "Allocate proof"), || Ok(&program.proof)).unwrap();
+
+        gadget
+            .verify_program(
+                &mut cs.ns(|| "Verify program"),
+                &verification_key,
+                &proof,
+                program.position,
+                &program_input_bits,
+            )
+            .unwrap();
+    }
+
+    let ids = gadget
+        .program_ids()
+        .iter()
+        .map(|id| id.iter().map(|byte| byte.value.unwrap()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ids,
+        programs.iter().map(|program| program.id.clone()).collect::<Vec<_>>()
+    );
+
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let randomness = rng.gen();
+    let commitment = ProgramVerificationKeyCommitment::commit(
+        &system_parameters.program_verification_key_commitment,
+        &committed_ids.concat(),
+        &randomness,
+    )
+    .unwrap();
+
+    let randomness =
+        <ProgramVerificationKeyCommitmentGadget as CommitmentGadget<_, OuterField>>::RandomnessGadget::alloc(
+            &mut cs.ns(|| "Commitment randomness"),
+            || Ok(&randomness),
+        )
+        .unwrap();
+    let commitment =
+        <ProgramVerificationKeyCommitmentGadget as CommitmentGadget<_, OuterField>>::OutputGadget::alloc_input(
+            &mut cs.ns(|| "Commitment output"),
+            || Ok(&commitment),
+        )
+        .unwrap();
+    gadget
+        .enforce_program_commitment(&mut cs.ns(|| "Check program commitment"), &commitment, &randomness)
+        .unwrap();
+
+    cs
+}
+
+#[test]
+fn test_program_proof_verification_gadget() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let system_parameters = SystemParameters::<Components>::load().unwrap();
+    let (programs, input) = prove_root_programs(&system_parameters, rng);
+
+    let cs = verify_programs(&system_parameters, &programs, &[input], &[
+        &programs[0].id,
+        &programs[1].id,
+    ]);
+    assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
+}
+
+#[test]
+fn test_program_proof_verification_gadget_rejects_wrong_position() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let system_parameters = SystemParameters::<Components>::load().unwrap();
+    let (mut programs, input) = prove_root_programs(&system_parameters, rng);
+    programs[1].position = 2;
+
+    let cs = verify_programs(&system_parameters, &programs, &[input], &[
+        &programs[0].id,
+        &programs[1].id,
+    ]);
+    assert!(!cs.is_satisfied());
+}
+
+#[test]
+fn test_program_proof_verification_gadget_rejects_wrong_input() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let system_parameters = SystemParameters::<Components>::load().unwrap();
+    let (programs, input) = prove_root_programs(&system_parameters, rng);
+
+    let cs = verify_programs(&system_parameters, &programs, &[input.double()], &[
+        &programs[0].id,
+        &programs[1].id,
+    ]);
+    assert!(!cs.is_satisfied());
+}
+
+#[test]
+fn test_program_proof_verification_gadget_rejects_reordered_commitment() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let system_parameters = SystemParameters::<Components>::load().unwrap();
+    let (programs, input) = prove_root_programs(&system_parameters, rng);
+
+    let cs = verify_programs(&system_parameters, &programs, &[input], &[
+        &programs[1].id,
+        &programs[0].id,
+    ]);
+    let unsatisfied = cs.which_is_unsatisfied().unwrap();
+    assert!(
+        unsatisfied.starts_with("Check program commitment/Check that declared and computed commitments are equal"),
+        "{}",
+        unsatisfied
+    );
+}
+
+#[test]
+fn test_program_proof_verification_gadget_rejects_swapped_noop_proofs() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let system_parameters = SystemParameters::<Components>::load().unwrap();
+    let noop_program_snark_parameters =
+        InstantiatedDPC::generate_noop_program_snark_parameters(&system_parameters, rng).unwrap();
+    let local_data_root = LocalDataCRH::hash(&system_parameters.local_data_crh, &rng.gen::<[u8; 32]>()).unwrap();
+
+    // Every noop program proof shares one verification key, so only the position public input
+    // distinguishes the proof for one record from the proof for another.
+    let verification_key = to_bytes![noop_program_snark_parameters.verification_key].unwrap();
+    let id = to_bytes![
+        ProgramVerificationKeyCRH::hash(&system_parameters.program_verification_key_crh, &verification_key).unwrap()
+    ]
+    .unwrap();
+    let mut programs = (0..2)
+        .map(|position| {
+            let proof = NoopProgramSNARK::<Components>::prove(
+                &noop_program_snark_parameters.proving_key,
+                &NoopCircuit::new(&system_parameters, &local_data_root, position),
+                rng,
+            )
+            .unwrap();
+
+            ProgramProof {
+                verification_key: verification_key.clone(),
+                proof: to_bytes![proof].unwrap(),
+                position,
+                id: id.clone(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // The outer circuit passes the remaining program public inputs, after the position.
+    let mut inputs = system_parameters
+        .local_data_commitment
+        .parameters()
+        .to_field_elements()
+        .unwrap();
+    inputs.extend(local_data_root.to_field_elements().unwrap());
+
+    let cs = verify_programs(&system_parameters, &programs, &inputs, &[&id, &id]);
+    assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
+
+    // Swapping the proofs between the two positions is rejected.
+    let (first, second) = programs.split_at_mut(1);
+    std::mem::swap(&mut first[0].proof, &mut second[0].proof);
+
+    let cs = verify_programs(&system_parameters, &programs, &inputs, &[&id, &id]);
+    assert!(!cs.is_satisfied());
+}
+
+#[test]
+fn test_outer_circuit_constraint_count() {
+    let system_parameters = SystemParameters::<Components>::load().unwrap();
+    let crh_parameters =
+        <MerkleTreeCRH as CRH>::Parameters::read(&LedgerMerkleTreeParameters::load_bytes().unwrap()[..]).unwrap();
+    let ledger_parameters = Arc::new(From::from(<CommitmentMerkleParameters as MerkleParameters>::H::from(
+        crh_parameters,
+    )));
+    let inner_snark_vk =
+        <InnerSNARK as SNARK>::VerifyingKey::read(&InnerSNARKVKParameters::load_bytes().unwrap()[..]).unwrap();
+    let program_snark_vk_bytes = NoopProgramSNARKVKParameters::load_bytes().unwrap();
+
+    let mut cs = ConstraintCounter::default();
+    OuterCircuit::<Components>::blank(
+        system_parameters,
+        ledger_parameters,
+        inner_snark_vk,
+        program_snark_vk_bytes,
+    )
+    .generate_constraints(&mut cs)
+    .unwrap();
+
+    assert_eq!(cs.num_constraints, 519976);
+    assert_eq!(cs.num_public_variables, 18);
+    assert_eq!(cs.num_private_variables, 520387);
+}
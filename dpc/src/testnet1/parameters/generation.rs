@@ -0,0 +1,411 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Generates the public parameters of the DPC, so that they can be regenerated from code
+//! rather than only through the setup programs in `snarkvm-parameters`.
+
+use crate::{
+    block::ConflictPolicy,
+    errors::DPCError,
+    testnet1::{
+        inner_circuit::InnerCircuit,
+        outer_circuit::OuterCircuit,
+        parameters::{NoopProgramSNARKParameters, PublicParameters, SystemParameters},
+        program::NoopCircuit,
+        BaseDPCComponents,
+    },
+    Network,
+};
+use snarkvm_algorithms::{
+    crh::sha256::sha256,
+    traits::{CommitmentScheme, EncryptionScheme, MerkleParameters, SignatureScheme, CRH, SNARK},
+};
+use snarkvm_parameters::{prelude::*, testnet1::*};
+use snarkvm_utilities::{
+    bytes::{FromBytes, ToBytes},
+    to_bytes,
+};
+
+use rand::Rng;
+use std::{fs, path::Path, sync::Arc};
+
+/// The name of the file listing the checksum of every parameter file written by
+/// [`GeneratedParameters::write_to_dir`], in the format of `sha256sum`.
+pub const MANIFEST_FILENAME: &str = "manifest.sha256";
+
+/// The number of parameter files written for the system parameters, including the ledger Merkle tree parameters.
+const NUM_SYSTEM_PARAMETER_FILES: usize = 11;
+
+/// Selects which of the public parameters are regenerated by [`generate_all`].
+/// Every parameter that is not selected is loaded from `snarkvm-parameters` instead.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GenerationConfig {
+    pub account_commitment: bool,
+    pub account_encryption: bool,
+    pub account_signature: bool,
+    pub encrypted_record_crh: bool,
+    pub inner_circuit_id_crh: bool,
+    pub local_data_commitment: bool,
+    pub local_data_crh: bool,
+    pub program_vk_crh: bool,
+    pub record_commitment: bool,
+    pub serial_number_nonce_crh: bool,
+    pub ledger_merkle_tree: bool,
+    pub noop_program_snark: bool,
+    pub inner_snark: bool,
+    pub outer_snark: bool,
+}
+
+impl GenerationConfig {
+    /// Regenerates all of the public parameters.
+    pub fn all() -> Self {
+        Self {
+            noop_program_snark: true,
+            inner_snark: true,
+            outer_snark: true,
+            ..Self::system_parameters()
+        }
+    }
+
+    /// Regenerates the system parameters and the ledger Merkle tree parameters,
+    /// and loads the SNARK parameters.
+    pub fn system_parameters() -> Self {
+        Self {
+            account_commitment: true,
+            account_encryption: true,
+            account_signature: true,
+            encrypted_record_crh: true,
+            inner_circuit_id_crh: true,
+            local_data_commitment: true,
+            local_data_crh: true,
+            program_vk_crh: true,
+            record_commitment: true,
+            serial_number_nonce_crh: true,
+            ledger_merkle_tree: true,
+            ..Self::default()
+        }
+    }
+}
+
+/// The phases of [`generate_all`], in the order in which they run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    SystemParameters,
+    NoopProgramSNARK,
+    InnerSNARK,
+    OuterSNARK,
+}
+
+/// A parameter file produced by [`generate_all`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct GeneratedFile {
+    name: &'static str,
+    bytes: Vec<u8>,
+    /// If `true`, the name of the parameter file includes the beginning of its checksum.
+    versioned: bool,
+}
+
+impl GeneratedFile {
+    fn new(name: &'static str, bytes: Vec<u8>) -> Self {
+        Self {
+            name,
+            bytes,
+            versioned: false,
+        }
+    }
+
+    fn versioned(name: &'static str, bytes: Vec<u8>) -> Self {
+        Self {
+            name,
+            bytes,
+            versioned: true,
+        }
+    }
+
+    fn filename(&self, checksum: &str) -> String {
+        match (self.versioned, checksum.get(0..7)) {
+            (true, Some(sum)) => format!("{}-{}.params", self.name, sum),
+            _ => format!("{}.params", self.name),
+        }
+    }
+}
+
+/// The public parameters produced by [`generate_all`].
+#[derive(Derivative)]
+#[derivative(Clone(bound = "C: BaseDPCComponents"))]
+pub struct GeneratedParameters<C: BaseDPCComponents> {
+    /// The selection of parameters that were regenerated.
+    pub config: GenerationConfig,
+    /// The public parameters, of which the SNARK proving keys are only set if they were regenerated.
+    pub public_parameters: PublicParameters<C>,
+    pub ledger_parameters: Arc<C::MerkleParameters>,
+    files: Vec<GeneratedFile>,
+}
+
+impl<C: BaseDPCComponents> GeneratedParameters<C> {
+    /// Returns the name and the bytes of each parameter file that was regenerated.
+    pub fn files(&self) -> impl Iterator<Item = (&str, &[u8])> {
+        self.files.iter().map(|file| (file.name, &file.bytes[..]))
+    }
+
+    /// Writes each regenerated parameter file to the given directory, along with its checksum,
+    /// and a manifest of the checksums of all of the written parameter files.
+    pub fn write_to_dir(&self, path: &Path) -> Result<(), DPCError> {
+        fs::create_dir_all(path)?;
+
+        let mut manifest = String::new();
+        for file in &self.files {
+            let checksum = hex::encode(sha256(&file.bytes));
+            let filename = file.filename(&checksum);
+
+            fs::write(path.join(&filename), &file.bytes)?;
+            fs::write(path.join(format!("{}.checksum", file.name)), &checksum)?;
+            manifest.push_str(&format!("{}  {}\n", checksum, filename));
+        }
+        fs::write(path.join(MANIFEST_FILENAME), manifest)?;
+
+        Ok(())
+    }
+}
+
+/// Regenerates the public parameters selected by `config`, and loads the remaining ones.
+/// The returned public parameters verify the transactions of `network`.
+///
+/// `progress` is called with the fraction of each phase that is done, starting at `0.0`
+/// and ending at `1.0`, and several times during each SNARK setup that is regenerated.
+pub fn generate_all<C: BaseDPCComponents, R: Rng>(
+    config: GenerationConfig,
+    network: Network,
+    rng: &mut R,
+    progress: impl Fn(Phase, f32) + Sync,
+) -> Result<GeneratedParameters<C>, DPCError> {
+    let mut files = vec![];
+    let mut num_system_parameters_done = 0;
+
+    // Sets up the scheme if it is selected, and loads its parameters otherwise.
+    macro_rules! setup_or_load {
+        ($selected: ident, $scheme: ty, $trait: ident, $parameter: ty, $setup: expr) => {{
+            let scheme: $scheme = match config.$selected {
+                true => {
+                    let scheme: $scheme = $setup;
+                    let parameters = <$scheme as $trait>::parameters(&scheme);
+                    files.push(GeneratedFile::new(stringify!($selected), to_bytes![parameters]?));
+                    scheme
+                }
+                false => {
+                    let parameters: <$scheme as $trait>::Parameters =
+                        FromBytes::read(<$parameter>::load_bytes()?.as_slice())?;
+                    From::from(parameters)
+                }
+            };
+            num_system_parameters_done += 1;
+            progress(
+                Phase::SystemParameters,
+                num_system_parameters_done as f32 / NUM_SYSTEM_PARAMETER_FILES as f32,
+            );
+            scheme
+        }};
+    }
+
+    progress(Phase::SystemParameters, 0.0);
+    let system_parameters = {
+        let account_commitment = setup_or_load!(
+            account_commitment,
+            C::AccountCommitment,
+            CommitmentScheme,
+            AccountCommitmentParameters,
+            C::AccountCommitment::setup(rng)
+        );
+        let account_encryption = setup_or_load!(
+            account_encryption,
+            C::AccountEncryption,
+            EncryptionScheme,
+            AccountEncryptionParameters,
+            <C::AccountEncryption as EncryptionScheme>::setup(rng)
+        );
+        let account_signature = setup_or_load!(
+            account_signature,
+            C::AccountSignature,
+            SignatureScheme,
+            AccountSignatureParameters,
+            C::AccountSignature::setup(rng)?
+        );
+        let encrypted_record_crh = setup_or_load!(
+            encrypted_record_crh,
+            C::EncryptedRecordCRH,
+            CRH,
+            EncryptedRecordCRHParameters,
+            C::EncryptedRecordCRH::setup(rng)
+        );
+        let inner_circuit_id_crh = setup_or_load!(
+            inner_circuit_id_crh,
+            C::InnerCircuitIDCRH,
+            CRH,
+            InnerCircuitIDCRH,
+            C::InnerCircuitIDCRH::setup(rng)
+        );
+        let local_data_commitment = setup_or_load!(
+            local_data_commitment,
+            C::LocalDataCommitment,
+            CommitmentScheme,
+            LocalDataCommitmentParameters,
+            C::LocalDataCommitment::setup(rng)
+        );
+        let local_data_crh = setup_or_load!(
+            local_data_crh,
+            C::LocalDataCRH,
+            CRH,
+            LocalDataCRHParameters,
+            C::LocalDataCRH::setup(rng)
+        );
+        let program_verification_key_crh = setup_or_load!(
+            program_vk_crh,
+            C::ProgramVerificationKeyCRH,
+            CRH,
+            ProgramVKCRHParameters,
+            C::ProgramVerificationKeyCRH::setup(rng)
+        );
+        let record_commitment = setup_or_load!(
+            record_commitment,
+            C::RecordCommitment,
+            CommitmentScheme,
+            RecordCommitmentParameters,
+            C::RecordCommitment::setup(rng)
+        );
+        let serial_number_nonce = setup_or_load!(
+            serial_number_nonce_crh,
+            C::SerialNumberNonceCRH,
+            CRH,
+            SerialNumberNonceCRHParameters,
+            C::SerialNumberNonceCRH::setup(rng)
+        );
+        // The program verification key commitment has no parameter file, as in `SystemParameters::load`.
+        let program_verification_key_commitment: C::ProgramVerificationKeyCommitment =
+            From::from(FromBytes::read(&[][..])?);
+
+        SystemParameters {
+            account_commitment,
+            account_encryption,
+            account_signature,
+            encrypted_record_crh,
+            inner_circuit_id_crh,
+            local_data_crh,
+            local_data_commitment,
+            program_verification_key_commitment,
+            program_verification_key_crh,
+            record_commitment,
+            serial_number_nonce,
+        }
+    };
+
+    let ledger_parameters: Arc<C::MerkleParameters> = Arc::new(match config.ledger_merkle_tree {
+        true => {
+            let ledger_parameters = C::MerkleParameters::setup(rng);
+            files.push(GeneratedFile::new("ledger_merkle_tree", to_bytes![
+                ledger_parameters.parameters()
+            ]?));
+            ledger_parameters
+        }
+        false => {
+            let hash_parameters: <C::MerkleParameters as MerkleParameters>::H =
+                From::from(FromBytes::read(LedgerMerkleTreeParameters::load_bytes()?.as_slice())?);
+            From::from(hash_parameters)
+        }
+    });
+    progress(Phase::SystemParameters, 1.0);
+
+    progress(Phase::NoopProgramSNARK, 0.0);
+    let noop_program_snark_parameters = match config.noop_program_snark {
+        true => {
+            let (proving_key, prepared_verifying_key) =
+                C::NoopProgramSNARK::setup_with_progress(&NoopCircuit::blank(&system_parameters), rng, &|fraction| {
+                    progress(Phase::NoopProgramSNARK, fraction)
+                })?;
+            let parameters = NoopProgramSNARKParameters::<C>::new(proving_key, prepared_verifying_key.into());
+            files.push(GeneratedFile::new("noop_program_snark_pk", to_bytes![
+                parameters.proving_key
+            ]?));
+            files.push(GeneratedFile::new("noop_program_snark_vk", to_bytes![
+                parameters.verification_key
+            ]?));
+            parameters
+        }
+        false => NoopProgramSNARKParameters::<C>::load()?,
+    };
+    progress(Phase::NoopProgramSNARK, 1.0);
+
+    progress(Phase::InnerSNARK, 0.0);
+    let inner_snark_parameters = match config.inner_snark {
+        true => {
+            let (proving_key, prepared_verifying_key) = C::InnerSNARK::setup_with_progress(
+                &InnerCircuit::blank(&system_parameters, &ledger_parameters),
+                rng,
+                &|fraction| progress(Phase::InnerSNARK, fraction),
+            )?;
+            let verifying_key: <C::InnerSNARK as SNARK>::VerifyingKey = prepared_verifying_key.clone().into();
+            files.push(GeneratedFile::versioned("inner_snark_pk", to_bytes![proving_key]?));
+            files.push(GeneratedFile::new("inner_snark_vk", to_bytes![verifying_key]?));
+            (Some(proving_key), prepared_verifying_key)
+        }
+        false => {
+            let verifying_key: <C::InnerSNARK as SNARK>::VerifyingKey =
+                FromBytes::read(InnerSNARKVKParameters::load_bytes()?.as_slice())?;
+            (None, verifying_key.into())
+        }
+    };
+    progress(Phase::InnerSNARK, 1.0);
+
+    progress(Phase::OuterSNARK, 0.0);
+    let outer_snark_parameters = match config.outer_snark {
+        true => {
+            let inner_snark_vk: <C::InnerSNARK as SNARK>::VerifyingKey = inner_snark_parameters.1.clone().into();
+            let (proving_key, prepared_verifying_key) = C::OuterSNARK::setup_with_progress(
+                &OuterCircuit::blank(
+                    system_parameters.clone(),
+                    ledger_parameters.clone(),
+                    inner_snark_vk,
+                    to_bytes![noop_program_snark_parameters.verification_key]?,
+                ),
+                rng,
+                &|fraction| progress(Phase::OuterSNARK, fraction),
+            )?;
+            let verifying_key: <C::OuterSNARK as SNARK>::VerifyingKey = prepared_verifying_key.clone().into();
+            files.push(GeneratedFile::versioned("outer_snark_pk", to_bytes![proving_key]?));
+            files.push(GeneratedFile::new("outer_snark_vk", to_bytes![verifying_key]?));
+            (Some(proving_key), prepared_verifying_key)
+        }
+        false => {
+            let verifying_key: <C::OuterSNARK as SNARK>::VerifyingKey =
+                FromBytes::read(OuterSNARKVKParameters::load_bytes()?.as_slice())?;
+            (None, verifying_key.into())
+        }
+    };
+    progress(Phase::OuterSNARK, 1.0);
+
+    Ok(GeneratedParameters {
+        config,
+        public_parameters: PublicParameters {
+            system_parameters,
+            noop_program_snark_parameters,
+            inner_snark_parameters,
+            outer_snark_parameters,
+            conflict_policy: ConflictPolicy::default(),
+            network,
+        },
+        ledger_parameters,
+        files,
+    })
+}
@@ -14,12 +14,22 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::testnet1::BaseDPCComponents;
-use snarkvm_algorithms::traits::{EncryptionScheme, SNARK};
+pub mod generation;
+pub use generation::*;
+
+#[cfg(test)]
+mod tests;
+
+use crate::{block::ConflictPolicy, errors::DPCError, testnet1::BaseDPCComponents, Network};
+use snarkvm_algorithms::{
+    errors::SNARKError,
+    traits::{CommitmentScheme, EncryptionScheme, CRH, SNARK},
+};
 use snarkvm_parameters::{prelude::*, testnet1::*};
 use snarkvm_utilities::bytes::FromBytes;
 
-use std::io::Result as IoResult;
+use once_cell::sync::OnceCell;
+use std::io::{Error, ErrorKind, Result as IoResult};
 
 #[derive(Derivative)]
 #[derivative(Clone(bound = "C: BaseDPCComponents"))]
@@ -80,6 +90,29 @@ impl<C: BaseDPCComponents> SystemParameters<C> {
             serial_number_nonce,
         })
     }
+
+    /// Checks that the parameters of the commitment schemes and the hash functions are well-formed.
+    pub fn validate(&self) -> Result<(), DPCError> {
+        self.account_commitment.validate()?;
+        self.local_data_commitment.validate()?;
+        self.program_verification_key_commitment.validate()?;
+        self.record_commitment.validate()?;
+        self.encrypted_record_crh.validate()?;
+        self.inner_circuit_id_crh.validate()?;
+        self.local_data_crh.validate()?;
+        self.program_verification_key_crh.validate()?;
+        self.serial_number_nonce.validate()?;
+        Ok(())
+    }
+
+    /// Loads the system parameters, and checks that they are well-formed with `validate`.
+    pub fn load_checked() -> IoResult<Self> {
+        let system_parameters = Self::load()?;
+        system_parameters
+            .validate()
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+        Ok(system_parameters)
+    }
 }
 
 #[derive(Derivative)]
@@ -87,9 +120,27 @@ impl<C: BaseDPCComponents> SystemParameters<C> {
 pub struct NoopProgramSNARKParameters<C: BaseDPCComponents> {
     pub proving_key: <C::NoopProgramSNARK as SNARK>::ProvingKey,
     pub verification_key: <C::NoopProgramSNARK as SNARK>::VerifyingKey,
+    prepared_proving_key: OnceCell<<C::NoopProgramSNARK as SNARK>::PreparedProvingKey>,
 }
 
 impl<C: BaseDPCComponents> NoopProgramSNARKParameters<C> {
+    pub fn new(
+        proving_key: <C::NoopProgramSNARK as SNARK>::ProvingKey,
+        verification_key: <C::NoopProgramSNARK as SNARK>::VerifyingKey,
+    ) -> Self {
+        Self {
+            proving_key,
+            verification_key,
+            prepared_proving_key: OnceCell::new(),
+        }
+    }
+
+    /// Returns the prepared noop program proving key, preparing it on first use.
+    pub fn prepared_proving_key(&self) -> Result<&<C::NoopProgramSNARK as SNARK>::PreparedProvingKey, SNARKError> {
+        self.prepared_proving_key
+            .get_or_try_init(|| C::NoopProgramSNARK::prepare_proving_key(self.proving_key.clone()))
+    }
+
     // TODO (howardwu): Why are we not preparing the VK here?
     pub fn load() -> IoResult<Self> {
         let proving_key: <C::NoopProgramSNARK as SNARK>::ProvingKey =
@@ -97,10 +148,7 @@ impl<C: BaseDPCComponents> NoopProgramSNARKParameters<C> {
         let verification_key =
             <C::NoopProgramSNARK as SNARK>::VerifyingKey::read(NoopProgramSNARKVKParameters::load_bytes()?.as_slice())?;
 
-        Ok(Self {
-            proving_key,
-            verification_key,
-        })
+        Ok(Self::new(proving_key, verification_key))
     }
 }
 
@@ -117,6 +165,11 @@ pub struct PublicParameters<C: BaseDPCComponents> {
         Option<<C::OuterSNARK as SNARK>::ProvingKey>,
         <C::OuterSNARK as SNARK>::PreparedVerifyingKey,
     ),
+    /// The policy for transactions that conflict with each other, or with the ledger.
+    /// Every node on the network must use the same policy, as it is part of consensus.
+    pub conflict_policy: ConflictPolicy,
+    /// The network whose transactions are accepted by `DPC::verify`.
+    pub network: Network,
 }
 
 impl<C: BaseDPCComponents> PublicParameters<C> {
@@ -182,8 +235,16 @@ impl<C: BaseDPCComponents> PublicParameters<C> {
         &self.system_parameters.serial_number_nonce
     }
 
-    pub fn load(verify_only: bool) -> IoResult<Self> {
-        let system_parameters = SystemParameters::<C>::load()?;
+    pub fn conflict_policy(&self) -> ConflictPolicy {
+        self.conflict_policy
+    }
+
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    pub fn load(verify_only: bool, network: Network) -> IoResult<Self> {
+        let system_parameters = SystemParameters::<C>::load_checked()?;
         let noop_program_snark_parameters = NoopProgramSNARKParameters::<C>::load()?;
 
         let inner_snark_parameters = {
@@ -219,11 +280,13 @@ impl<C: BaseDPCComponents> PublicParameters<C> {
             noop_program_snark_parameters,
             inner_snark_parameters,
             outer_snark_parameters,
+            conflict_policy: ConflictPolicy::default(),
+            network,
         })
     }
 
-    pub fn load_vk_direct() -> IoResult<Self> {
-        let system_parameters = SystemParameters::<C>::load()?;
+    pub fn load_vk_direct(network: Network) -> IoResult<Self> {
+        let system_parameters = SystemParameters::<C>::load_checked()?;
         let noop_program_snark_parameters = NoopProgramSNARKParameters::<C>::load()?;
 
         let inner_snark_parameters = {
@@ -245,6 +308,8 @@ impl<C: BaseDPCComponents> PublicParameters<C> {
             noop_program_snark_parameters,
             inner_snark_parameters,
             outer_snark_parameters,
+            conflict_policy: ConflictPolicy::default(),
+            network,
         })
     }
 }
@@ -0,0 +1,196 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    block::ConflictPolicy,
+    errors::DPCError,
+    testnet1::{
+        instantiated::Components,
+        parameters::{generate_all, GenerationConfig, Phase, SystemParameters, MANIFEST_FILENAME},
+        BaseDPCComponents,
+    },
+    Network,
+};
+use snarkvm_algorithms::{
+    crh::sha256::sha256,
+    errors::{CRHError, CommitmentError},
+    traits::{CommitmentScheme, SNARK},
+};
+use snarkvm_curves::{edwards_bls12::EdwardsProjective, Group};
+use snarkvm_fields::Zero;
+use snarkvm_parameters::{prelude::*, testnet1::*};
+use snarkvm_utilities::{bytes::ToBytes, to_bytes};
+
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use std::{env, fs, sync::Mutex};
+
+#[test]
+fn test_generate_nothing_loads_parameters() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let generated =
+        generate_all::<Components, _>(GenerationConfig::default(), Network::Testnet1, rng, |_, _| {}).unwrap();
+    assert_eq!(generated.files().count(), 0);
+
+    let public_parameters = generated.public_parameters;
+    assert!(public_parameters.inner_snark_parameters.0.is_none());
+    assert!(public_parameters.outer_snark_parameters.0.is_none());
+    assert_eq!(public_parameters.conflict_policy, ConflictPolicy::default());
+    assert_eq!(public_parameters.network, Network::Testnet1);
+    assert_eq!(
+        to_bytes![public_parameters.system_parameters.account_commitment.parameters()].unwrap(),
+        AccountCommitmentParameters::load_bytes().unwrap()
+    );
+    assert_eq!(
+        to_bytes![public_parameters.noop_program_snark_parameters.verification_key].unwrap(),
+        NoopProgramSNARKVKParameters::load_bytes().unwrap()
+    );
+}
+
+#[test]
+fn test_generate_system_and_noop_program_snark_parameters() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let config = GenerationConfig {
+        noop_program_snark: true,
+        ..GenerationConfig::system_parameters()
+    };
+    let reported = Mutex::new(vec![]);
+    let generated = generate_all::<Components, _>(config, Network::Mainnet, rng, |phase, fraction| {
+        reported.lock().unwrap().push((phase, fraction))
+    })
+    .unwrap();
+
+    // Each phase reports its start and its end, in order, and the noop program SNARK setup
+    // reports its progress in between.
+    let reported = reported.into_inner().unwrap();
+    assert!(reported.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+    assert!(reported.iter().all(|(_, fraction)| (0.0..=1.0).contains(fraction)));
+    for phase in &[
+        Phase::SystemParameters,
+        Phase::NoopProgramSNARK,
+        Phase::InnerSNARK,
+        Phase::OuterSNARK,
+    ] {
+        let fractions = reported
+            .iter()
+            .filter(|(reported_phase, _)| reported_phase == phase)
+            .map(|(_, fraction)| *fraction)
+            .collect::<Vec<_>>();
+        assert_eq!(fractions.first(), Some(&0.0));
+        assert_eq!(fractions.last(), Some(&1.0));
+    }
+    let num_noop_program_snark_reports = reported
+        .iter()
+        .filter(|(phase, _)| *phase == Phase::NoopProgramSNARK)
+        .count();
+    assert!(num_noop_program_snark_reports > 3);
+
+    // The regenerated parameters differ from the loaded ones, and only the SNARK proving keys
+    // that were regenerated are set.
+    let public_parameters = &generated.public_parameters;
+    assert_ne!(
+        to_bytes![public_parameters.system_parameters.account_commitment.parameters()].unwrap(),
+        AccountCommitmentParameters::load_bytes().unwrap()
+    );
+    assert!(public_parameters.inner_snark_parameters.0.is_none());
+    assert!(public_parameters.outer_snark_parameters.0.is_none());
+    public_parameters.system_parameters.validate().unwrap();
+
+    // Each regenerated parameter file is written along with its checksum and listed in the manifest.
+    let directory = env::temp_dir().join(format!("snarkvm_parameters_{:016x}", rng.gen::<u64>()));
+    generated.write_to_dir(&directory).unwrap();
+
+    let manifest = fs::read_to_string(directory.join(MANIFEST_FILENAME)).unwrap();
+    assert_eq!(manifest.lines().count(), generated.files().count());
+    assert_eq!(generated.files().count(), 13);
+
+    for ((name, bytes), line) in generated.files().zip(manifest.lines()) {
+        let checksum = hex::encode(sha256(bytes));
+        let filename = format!("{}.params", name);
+
+        assert_eq!(fs::read(directory.join(&filename)).unwrap(), bytes);
+        assert_eq!(
+            fs::read_to_string(directory.join(format!("{}.checksum", name))).unwrap(),
+            checksum
+        );
+        assert_eq!(line, format!("{}  {}", checksum, filename));
+    }
+
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn test_generate_inner_and_outer_snark_parameters() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let config = GenerationConfig {
+        inner_snark: true,
+        outer_snark: true,
+        ..GenerationConfig::default()
+    };
+    let reported = Mutex::new(vec![]);
+    let generated = generate_all::<Components, _>(config, Network::Mainnet, rng, |phase, fraction| {
+        reported.lock().unwrap().push((phase, fraction))
+    })
+    .unwrap();
+
+    // Both SNARK setups report their progress in between their start and their end.
+    let reported = reported.into_inner().unwrap();
+    for phase in &[Phase::InnerSNARK, Phase::OuterSNARK] {
+        assert!(reported.iter().filter(|(reported_phase, _)| reported_phase == phase).count() > 3);
+    }
+
+    // The inner and outer SNARK keys are regenerated, and the outer circuit is set up
+    // with the regenerated inner SNARK verifying key.
+    let public_parameters = &generated.public_parameters;
+    let inner_snark_vk: <<Components as BaseDPCComponents>::InnerSNARK as SNARK>::VerifyingKey =
+        public_parameters.inner_snark_parameters.1.clone().into();
+    let outer_snark_vk: <<Components as BaseDPCComponents>::OuterSNARK as SNARK>::VerifyingKey =
+        public_parameters.outer_snark_parameters.1.clone().into();
+    assert_eq!(public_parameters.inner_snark_parameters.0.as_ref().unwrap().vk, inner_snark_vk);
+    assert_eq!(public_parameters.outer_snark_parameters.0.as_ref().unwrap().vk, outer_snark_vk);
+    assert_ne!(to_bytes![inner_snark_vk].unwrap(), InnerSNARKVKParameters::load_bytes().unwrap());
+    assert_ne!(to_bytes![outer_snark_vk].unwrap(), OuterSNARKVKParameters::load_bytes().unwrap());
+
+    assert_eq!(generated.files().map(|(name, _)| name).collect::<Vec<_>>(), vec![
+        "inner_snark_pk",
+        "inner_snark_vk",
+        "outer_snark_pk",
+        "outer_snark_vk"
+    ]);
+}
+
+#[test]
+fn test_system_parameters_validate() {
+    let system_parameters = SystemParameters::<Components>::load_checked().unwrap();
+
+    let mut corrupted = system_parameters.clone();
+    corrupted.record_commitment.parameters.random_base[0] = EdwardsProjective::zero();
+    assert!(matches!(
+        corrupted.validate(),
+        Err(DPCError::CommitmentError(CommitmentError::InvalidRandomBase(0)))
+    ));
+
+    let mut corrupted = system_parameters;
+    let bases = &mut corrupted.serial_number_nonce.parameters.bases;
+    bases[1][2] = bases[1][2].double();
+    assert!(matches!(
+        corrupted.validate(),
+        Err(DPCError::CRHError(CRHError::NotADoublingChain(1, 2)))
+    ));
+}
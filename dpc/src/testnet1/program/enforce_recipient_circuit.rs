@@ -0,0 +1,291 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    testnet1::{parameters::SystemParameters, BaseDPCComponents, LocalData, Record},
+    traits::RecordScheme,
+    AccountAddress,
+    AccountAddressGadget,
+};
+use snarkvm_algorithms::traits::{CommitmentScheme, SignatureScheme, CRH};
+use snarkvm_gadgets::{
+    bits::{Boolean, ToBytesGadget},
+    integers::uint::UInt8,
+    traits::{
+        algorithms::{CRHGadget, CommitmentGadget, SignaturePublicKeyRandomizationGadget},
+        alloc::AllocGadget,
+        eq::{ConditionalEqGadget, EqGadget},
+        integers::Integer,
+    },
+};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
+use snarkvm_utilities::{to_bytes, ToBytes};
+
+/// Example program that only accepts a record owned by a fixed recipient.
+///
+/// The circuit exposes the same public inputs as the `NoopCircuit`, so it is proven and verified
+/// with `ProgramLocalData` as its verifier input. It opens the record commitment and the local data
+/// commitment of the record at `position`, checks that the local data commitments lead to the local
+/// data root, and constrains the owner of the record to equal the constant `recipient` address.
+pub struct EnforceRecipientCircuit<C: BaseDPCComponents> {
+    /// System parameters
+    pub system_parameters: SystemParameters<C>,
+
+    /// Commitment to the program input.
+    pub local_data_root: <C::LocalDataCRH as CRH>::Output,
+
+    /// Record position
+    pub position: u8,
+
+    /// The address that must own the record.
+    pub recipient: AccountAddress<C>,
+
+    /// The record at `position`.
+    pub record: Record<C>,
+
+    /// The serial number of the record, if it is an input record.
+    pub serial_number: <C::AccountSignature as SignatureScheme>::PublicKey,
+
+    /// The memorandum of the transaction.
+    pub memorandum: [u8; 32],
+
+    /// The network id of the transaction.
+    pub network_id: u8,
+
+    /// The randomness of the local data commitment of the record.
+    pub local_data_commitment_randomness: <C::LocalDataCommitment as CommitmentScheme>::Randomness,
+
+    /// The local data commitments of the input records, followed by those of the output records.
+    pub local_data_commitments: Vec<<C::LocalDataCommitment as CommitmentScheme>::Output>,
+}
+
+impl<C: BaseDPCComponents> EnforceRecipientCircuit<C> {
+    pub fn blank(system_parameters: &SystemParameters<C>, recipient: &AccountAddress<C>) -> Self {
+        let num_records = C::NUM_INPUT_RECORDS + C::NUM_OUTPUT_RECORDS;
+
+        Self {
+            system_parameters: system_parameters.clone(),
+            local_data_root: <C::LocalDataCRH as CRH>::Output::default(),
+            position: 0u8,
+            recipient: recipient.clone(),
+            record: Record::default(),
+            serial_number: <C::AccountSignature as SignatureScheme>::PublicKey::default(),
+            memorandum: [0u8; 32],
+            network_id: 0u8,
+            local_data_commitment_randomness: <C::LocalDataCommitment as CommitmentScheme>::Randomness::default(),
+            local_data_commitments: vec![<C::LocalDataCommitment as CommitmentScheme>::Output::default(); num_records],
+        }
+    }
+
+    pub fn new(local_data: &LocalData<C>, position: u8, recipient: &AccountAddress<C>) -> Self {
+        let index = position as usize;
+        let num_input_records = local_data.old_records.len();
+        let (record, serial_number) = if index < num_input_records {
+            (&local_data.old_records[index], local_data.old_serial_numbers[index].clone())
+        } else {
+            (
+                &local_data.new_records[index - num_input_records],
+                <C::AccountSignature as SignatureScheme>::PublicKey::default(),
+            )
+        };
+
+        Self {
+            system_parameters: local_data.system_parameters.clone(),
+            local_data_root: local_data.local_data_merkle_tree.root(),
+            position,
+            recipient: recipient.clone(),
+            record: record.clone(),
+            serial_number,
+            memorandum: *local_data.memorandum.as_bytes(),
+            network_id: local_data.network_id,
+            local_data_commitment_randomness: local_data.local_data_commitment_randomizers[index].clone(),
+            local_data_commitments: local_data.local_data_merkle_tree.leaves(),
+        }
+    }
+}
+
+impl<C: BaseDPCComponents> ConstraintSynthesizer<C::InnerField> for EnforceRecipientCircuit<C> {
+    fn generate_constraints<CS: ConstraintSystem<C::InnerField>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let system_parameters = &self.system_parameters;
+        let num_records = C::NUM_INPUT_RECORDS + C::NUM_OUTPUT_RECORDS;
+        if self.local_data_commitments.len() != num_records {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let position = UInt8::alloc_input_vec_le(cs.ns(|| "Alloc position"), &[self.position])?;
+
+        let local_data_commitment_parameters =
+            <C::LocalDataCommitmentGadget as CommitmentGadget<_, _>>::ParametersGadget::alloc_input(
+                &mut cs.ns(|| "Declare local data commitment parameters"),
+                || Ok(system_parameters.local_data_commitment.parameters().clone()),
+            )?;
+
+        let local_data_root = <C::LocalDataCRHGadget as CRHGadget<_, _>>::OutputGadget::alloc_input(
+            cs.ns(|| "Allocate local data root"),
+            || Ok(&self.local_data_root),
+        )?;
+
+        // *******************************************************************
+        // Open the record commitment.
+        // *******************************************************************
+
+        let record_commitment_parameters =
+            <C::RecordCommitmentGadget as CommitmentGadget<_, _>>::ParametersGadget::alloc_constant(
+                &mut cs.ns(|| "Declare record commitment parameters"),
+                || Ok(system_parameters.record_commitment.parameters().clone()),
+            )?;
+
+        // The record commitment input starts with the owner, followed by the remaining record fields.
+        let record_owner =
+            AccountAddressGadget::<C>::alloc(cs.ns(|| "Allocate record owner"), || Ok(self.record.owner()))?;
+        let owner_size = to_bytes![self.record.owner()]?.len();
+        let commitment_input = self.record.plaintext().to_commitment_input()?;
+
+        let mut commitment_input_gadget = record_owner.to_bytes(cs.ns(|| "Convert record owner to bytes"))?;
+        commitment_input_gadget.extend(UInt8::alloc_vec(
+            cs.ns(|| "Allocate record commitment input"),
+            &commitment_input[owner_size..],
+        )?);
+
+        let record_commitment_randomness =
+            <C::RecordCommitmentGadget as CommitmentGadget<_, _>>::RandomnessGadget::alloc(
+                cs.ns(|| "Allocate record commitment randomness"),
+                || Ok(self.record.commitment_randomness()),
+            )?;
+
+        let record_commitment = C::RecordCommitmentGadget::check_commitment_gadget(
+            cs.ns(|| "Compute record commitment"),
+            &record_commitment_parameters,
+            &commitment_input_gadget,
+            &record_commitment_randomness,
+        )?;
+
+        // *******************************************************************
+        // Open the local data commitment of the record, for both an input and an output record.
+        // *******************************************************************
+
+        let serial_number =
+            <C::AccountSignatureGadget as SignaturePublicKeyRandomizationGadget<_, _>>::PublicKeyGadget::alloc(
+                cs.ns(|| "Allocate serial number"),
+                || Ok(&self.serial_number),
+            )?;
+        let memorandum = UInt8::alloc_vec(cs.ns(|| "Allocate memorandum"), &self.memorandum)?;
+        let network_id = UInt8::alloc_vec(cs.ns(|| "Allocate network id"), &[self.network_id])?;
+
+        let mut output_local_data_input = record_commitment.to_bytes(cs.ns(|| "Convert record commitment to bytes"))?;
+        output_local_data_input.extend_from_slice(&memorandum);
+        output_local_data_input.extend_from_slice(&network_id);
+
+        let mut input_local_data_input = serial_number.to_bytes(cs.ns(|| "Convert serial number to bytes"))?;
+        input_local_data_input.extend_from_slice(&output_local_data_input);
+
+        let local_data_commitment_randomness =
+            <C::LocalDataCommitmentGadget as CommitmentGadget<_, _>>::RandomnessGadget::alloc(
+                cs.ns(|| "Allocate local data commitment randomness"),
+                || Ok(&self.local_data_commitment_randomness),
+            )?;
+
+        let input_local_data_commitment = C::LocalDataCommitmentGadget::check_commitment_gadget(
+            cs.ns(|| "Compute input record local data commitment"),
+            &local_data_commitment_parameters,
+            &input_local_data_input,
+            &local_data_commitment_randomness,
+        )?;
+
+        let output_local_data_commitment = C::LocalDataCommitmentGadget::check_commitment_gadget(
+            cs.ns(|| "Compute output record local data commitment"),
+            &local_data_commitment_parameters,
+            &output_local_data_input,
+            &local_data_commitment_randomness,
+        )?;
+
+        // *******************************************************************
+        // Check that the local data commitments lead to the local data root, and that
+        // the local data commitment at `position` is the one of the record.
+        // *******************************************************************
+
+        let position_bits = position[0].to_bits_le();
+        let mut local_data_commitment_bytes = Vec::with_capacity(num_records);
+        let mut is_valid_position = Boolean::constant(false);
+        for (i, local_data_commitment) in self.local_data_commitments.iter().enumerate() {
+            let mut cs = cs.ns(|| format!("Check local data commitment {}", i));
+
+            let local_data_commitment = <C::LocalDataCommitmentGadget as CommitmentGadget<_, _>>::OutputGadget::alloc(
+                cs.ns(|| "Allocate local data commitment"),
+                || Ok(local_data_commitment),
+            )?;
+
+            let position_matches: Vec<_> = position_bits
+                .iter()
+                .enumerate()
+                .map(|(j, bit)| if (i >> j) & 1 == 1 { *bit } else { bit.not() })
+                .collect();
+            let is_position = Boolean::kary_and(cs.ns(|| "Check position"), &position_matches)?;
+
+            let record_local_data_commitment = match i < C::NUM_INPUT_RECORDS {
+                true => &input_local_data_commitment,
+                false => &output_local_data_commitment,
+            };
+            local_data_commitment.conditional_enforce_equal(
+                cs.ns(|| "Enforce the local data commitment of the record"),
+                record_local_data_commitment,
+                &is_position,
+            )?;
+
+            is_valid_position = Boolean::or(cs.ns(|| "Accumulate position"), &is_valid_position, &is_position)?;
+            local_data_commitment_bytes.push(local_data_commitment.to_bytes(cs.ns(|| "Convert to bytes"))?);
+        }
+        is_valid_position.enforce_equal(cs.ns(|| "Enforce position is valid"), &Boolean::constant(true))?;
+
+        let local_data_crh_parameters = <C::LocalDataCRHGadget as CRHGadget<_, _>>::ParametersGadget::alloc_constant(
+            &mut cs.ns(|| "Declare local data CRH parameters"),
+            || Ok(system_parameters.local_data_crh.parameters().clone()),
+        )?;
+
+        let output_records_bytes = local_data_commitment_bytes.split_off(C::NUM_INPUT_RECORDS);
+        let input_records_hash = C::LocalDataCRHGadget::check_evaluation_gadget(
+            cs.ns(|| "Compute input records hash"),
+            &local_data_crh_parameters,
+            local_data_commitment_bytes.concat(),
+        )?;
+        let output_records_hash = C::LocalDataCRHGadget::check_evaluation_gadget(
+            cs.ns(|| "Compute output records hash"),
+            &local_data_crh_parameters,
+            output_records_bytes.concat(),
+        )?;
+
+        let mut inner_hash_bytes = input_records_hash.to_bytes(cs.ns(|| "Convert input records hash to bytes"))?;
+        inner_hash_bytes
+            .extend_from_slice(&output_records_hash.to_bytes(cs.ns(|| "Convert output records hash to bytes"))?);
+
+        let candidate_local_data_root = C::LocalDataCRHGadget::check_evaluation_gadget(
+            cs.ns(|| "Compute local data root"),
+            &local_data_crh_parameters,
+            inner_hash_bytes,
+        )?;
+
+        candidate_local_data_root.enforce_equal(cs.ns(|| "Check that local data root is valid"), &local_data_root)?;
+
+        // *******************************************************************
+        // Check that the record is owned by the recipient.
+        // *******************************************************************
+
+        let recipient =
+            AccountAddressGadget::<C>::alloc_constant(cs.ns(|| "Allocate recipient"), || Ok(&self.recipient))?;
+
+        record_owner.enforce_equal(cs.ns(|| "Enforce record owner is the recipient"), &recipient)
+    }
+}
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod enforce_recipient_circuit;
+pub use enforce_recipient_circuit::*;
+
 pub mod noop_program;
 pub use noop_program::*;
 
@@ -22,3 +25,12 @@ pub use noop_program_circuit::*;
 
 pub mod program;
 pub use program::*;
+
+pub mod time_lock_program;
+pub use time_lock_program::*;
+
+pub mod time_lock_program_circuit;
+pub use time_lock_program_circuit::*;
+
+#[cfg(test)]
+mod tests;
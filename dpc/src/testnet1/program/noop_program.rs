@@ -19,7 +19,10 @@ use crate::{
     testnet1::{BaseDPCComponents, LocalData, NoopCircuit, PrivateProgramInput, ProgramLocalData},
     traits::{ProgramScheme, RecordScheme},
 };
-use snarkvm_algorithms::traits::{CommitmentScheme, SNARK};
+use snarkvm_algorithms::{
+    errors::SNARKError,
+    traits::{CommitmentScheme, SNARK},
+};
 use snarkvm_utilities::{to_bytes, ToBytes};
 
 use rand::Rng;
@@ -49,24 +52,35 @@ impl<C: BaseDPCComponents, S: SNARK> NoopProgram<C, S> {
     }
 }
 
-impl<C: BaseDPCComponents, S: SNARK> ProgramScheme for NoopProgram<C, S>
+impl<C: BaseDPCComponents, S: SNARK> NoopProgram<C, S>
 where
     S: SNARK<AllocatedCircuit = NoopCircuit<C>, VerifierInput = ProgramLocalData<C>>,
 {
-    type LocalData = LocalData<C>;
-    type PrivateWitness = PrivateProgramInput;
-    type ProvingKey = S::ProvingKey;
-    type PublicInput = ();
-    type VerifyingKey = S::VerifyingKey;
-
-    fn execute<R: Rng>(
+    /// Executes the noop program with a prepared proving key, which amortizes
+    /// the proving key preprocessing across executions.
+    pub fn execute_with_prepared_key<R: Rng>(
         &self,
-        proving_key: &Self::ProvingKey,
-        verifying_key: &Self::VerifyingKey,
-        local_data: &Self::LocalData,
+        prepared_proving_key: &S::PreparedProvingKey,
+        verifying_key: &S::VerifyingKey,
+        local_data: &LocalData<C>,
         position: u8,
         rng: &mut R,
-    ) -> Result<Self::PrivateWitness, DPCError> {
+    ) -> Result<PrivateProgramInput, DPCError> {
+        self.execute_with(verifying_key, local_data, position, |circuit| {
+            S::prove_with_prepared_key(prepared_proving_key, circuit, rng)
+        })
+    }
+
+    fn execute_with<P>(
+        &self,
+        verifying_key: &S::VerifyingKey,
+        local_data: &LocalData<C>,
+        position: u8,
+        prove: P,
+    ) -> Result<PrivateProgramInput, DPCError>
+    where
+        P: FnOnce(&NoopCircuit<C>) -> Result<S::Proof, SNARKError>,
+    {
         let num_records = local_data.old_records.len() + local_data.new_records.len();
         assert!((position as usize) < num_records);
 
@@ -86,7 +100,7 @@ where
 
         let circuit = NoopCircuit::<C>::new(&local_data.system_parameters, &local_data_root, position);
 
-        let proof = S::prove(proving_key, &circuit, rng)?;
+        let proof = prove(&circuit)?;
 
         {
             let program_snark_pvk: <S as SNARK>::PreparedVerifyingKey = verifying_key.clone().into();
@@ -103,11 +117,35 @@ where
             assert!(S::verify(&program_snark_pvk, &program_pub_input, &proof)?);
         }
 
-        Ok(Self::PrivateWitness {
+        Ok(PrivateProgramInput {
             verification_key: to_bytes![verifying_key]?,
             proof: to_bytes![proof]?,
         })
     }
+}
+
+impl<C: BaseDPCComponents, S: SNARK> ProgramScheme for NoopProgram<C, S>
+where
+    S: SNARK<AllocatedCircuit = NoopCircuit<C>, VerifierInput = ProgramLocalData<C>>,
+{
+    type LocalData = LocalData<C>;
+    type PrivateWitness = PrivateProgramInput;
+    type ProvingKey = S::ProvingKey;
+    type PublicInput = ();
+    type VerifyingKey = S::VerifyingKey;
+
+    fn execute<R: Rng>(
+        &self,
+        proving_key: &Self::ProvingKey,
+        verifying_key: &Self::VerifyingKey,
+        local_data: &Self::LocalData,
+        position: u8,
+        rng: &mut R,
+    ) -> Result<Self::PrivateWitness, DPCError> {
+        self.execute_with(verifying_key, local_data, position, |circuit| {
+            S::prove(proving_key, circuit, rng)
+        })
+    }
 
     fn evaluate(&self, _p: &Self::PublicInput, _w: &Self::PrivateWitness) -> bool {
         unimplemented!()
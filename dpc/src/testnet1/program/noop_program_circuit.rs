@@ -38,12 +38,11 @@ pub struct NoopCircuit<C: BaseDPCComponents> {
 }
 
 impl<C: BaseDPCComponents> NoopCircuit<C> {
+    /// Returns a circuit without a witness, for use in setup mode.
     pub fn blank(system_parameters: &SystemParameters<C>) -> Self {
-        let local_data_root = <C::LocalDataCRH as CRH>::Output::default();
-
         Self {
             system_parameters: Some(system_parameters.clone()),
-            local_data_root: Some(local_data_root),
+            local_data_root: None,
             position: 0u8,
         }
     }
@@ -66,7 +65,7 @@ impl<C: BaseDPCComponents> ConstraintSynthesizer<C::InnerField> for NoopCircuit<
         execute_noop_gadget(
             cs,
             self.system_parameters.get_ref()?,
-            self.local_data_root.get_ref()?,
+            self.local_data_root.as_ref(),
             self.position,
         )
     }
@@ -75,7 +74,7 @@ impl<C: BaseDPCComponents> ConstraintSynthesizer<C::InnerField> for NoopCircuit<
 fn execute_noop_gadget<C: BaseDPCComponents, CS: ConstraintSystem<C::InnerField>>(
     cs: &mut CS,
     system_parameters: &SystemParameters<C>,
-    local_data_root: &<C::LocalDataCRH as CRH>::Output,
+    local_data_root: Option<&<C::LocalDataCRH as CRH>::Output>,
     position: u8,
 ) -> Result<(), SynthesisError> {
     let _position = UInt8::alloc_input_vec_le(cs.ns(|| "Alloc position"), &[position])?;
@@ -88,7 +87,7 @@ fn execute_noop_gadget<C: BaseDPCComponents, CS: ConstraintSystem<C::InnerField>
 
     let _local_data_root_gadget = <C::LocalDataCRHGadget as CRHGadget<_, _>>::OutputGadget::alloc_input(
         cs.ns(|| "Allocate local data root"),
-        || Ok(local_data_root),
+        || local_data_root.get(),
     )?;
 
     Ok(())
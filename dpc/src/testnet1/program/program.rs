@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::testnet1::BaseDPCComponents;
+use crate::testnet1::{
+    input_layout::{InputLayout, PublicInputs},
+    BaseDPCComponents,
+};
 use snarkvm_algorithms::traits::{CommitmentScheme, CRH};
 use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
 
@@ -41,6 +44,29 @@ pub struct ProgramLocalData<C: BaseDPCComponents> {
     pub position: u8,
 }
 
+impl<C: BaseDPCComponents> ProgramLocalData<C>
+where
+    <C::LocalDataCommitment as CommitmentScheme>::Parameters: ToConstraintField<C::InnerField>,
+    <C::LocalDataCRH as CRH>::Output: ToConstraintField<C::InnerField>,
+{
+    /// Returns the layout of the public input of a program circuit.
+    pub fn describe_inputs(&self) -> Result<InputLayout, ConstraintFieldError> {
+        Ok(self.public_inputs()?.into_layout())
+    }
+
+    /// Returns the public input of a program circuit, in the order in which programs allocate it.
+    fn public_inputs(&self) -> Result<PublicInputs<C::InnerField>, ConstraintFieldError> {
+        let mut inputs = PublicInputs::new();
+        inputs.push_bytes("position", &[self.position])?;
+        inputs.push(
+            "local_data_commitment_parameters",
+            &self.local_data_commitment_parameters,
+        )?;
+        inputs.push("local_data_root", &self.local_data_root)?;
+        Ok(inputs)
+    }
+}
+
 /// Convert each component to bytes and pack into field elements.
 impl<C: BaseDPCComponents> ToConstraintField<C::InnerField> for ProgramLocalData<C>
 where
@@ -48,10 +74,6 @@ where
     <C::LocalDataCRH as CRH>::Output: ToConstraintField<C::InnerField>,
 {
     fn to_field_elements(&self) -> Result<Vec<C::InnerField>, ConstraintFieldError> {
-        let mut v = ToConstraintField::<C::InnerField>::to_field_elements(&[self.position][..])?;
-
-        v.extend_from_slice(&self.local_data_commitment_parameters.to_field_elements()?);
-        v.extend_from_slice(&self.local_data_root.to_field_elements()?);
-        Ok(v)
+        Ok(self.public_inputs()?.into_field_elements())
     }
 }
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::testnet1::{instantiated::*, payload::Payload, NoopCircuit, ProgramLocalData, TimeLockProgram};
+use snarkvm_algorithms::traits::{CommitmentScheme, CRH, SNARK};
+use snarkvm_r1cs::{ConstraintCounter, ConstraintSynthesizer, TestConstraintSystem};
+
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn test_noop_circuit_blank_matches_witnessed() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+    let local_data_root = LocalDataCRH::hash(&system_parameters.local_data_crh, &rng.gen::<[u8; 32]>()).unwrap();
+
+    // Synthesize the circuit with a witness.
+    let mut witnessed_cs = TestConstraintSystem::<InnerField>::new();
+    NoopCircuit::<Components>::new(&system_parameters, &local_data_root, 1)
+        .generate_constraints(&mut witnessed_cs)
+        .unwrap();
+    assert!(witnessed_cs.is_satisfied());
+
+    // Synthesize the circuit in setup mode, without a witness.
+    let mut blank_cs = ConstraintCounter::default();
+    NoopCircuit::<Components>::blank(&system_parameters)
+        .generate_constraints(&mut blank_cs)
+        .unwrap();
+
+    assert_eq!(witnessed_cs.num_constraints(), blank_cs.num_constraints);
+}
+
+#[test]
+fn test_noop_program_snark_parameters_verify_witnessed_proof() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+    let noop_program_snark_parameters =
+        InstantiatedDPC::generate_noop_program_snark_parameters(&system_parameters, &mut rng).unwrap();
+
+    let local_data_root = LocalDataCRH::hash(&system_parameters.local_data_crh, &rng.gen::<[u8; 32]>()).unwrap();
+    let position = 1;
+
+    let proof = NoopProgramSNARK::<Components>::prove(
+        &noop_program_snark_parameters.proving_key,
+        &NoopCircuit::new(&system_parameters, &local_data_root, position),
+        &mut rng,
+    )
+    .unwrap();
+
+    let program_local_data = ProgramLocalData::<Components> {
+        local_data_commitment_parameters: system_parameters.local_data_commitment.parameters().clone(),
+        local_data_root,
+        position,
+    };
+    let verifying_key = noop_program_snark_parameters.verification_key.clone().into();
+    assert!(NoopProgramSNARK::<Components>::verify(&verifying_key, &program_local_data, &proof).unwrap());
+
+    // The proof does not verify against a different position.
+    let program_local_data = ProgramLocalData::<Components> {
+        position: position + 1,
+        ..program_local_data
+    };
+    assert!(!NoopProgramSNARK::<Components>::verify(&verifying_key, &program_local_data, &proof).unwrap());
+}
+
+#[test]
+fn test_time_lock_program_heights() {
+    type Program = TimeLockProgram<Components, NoopProgramSNARK<Components>>;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for lock_height in [0, 1, 0x0102_0304, u32::MAX].iter() {
+        let payload = Program::lock_payload(*lock_height);
+        assert_eq!(Program::lock_height(&payload), *lock_height);
+        assert_eq!(&payload.to_bytes()[..4], &lock_height.to_le_bytes());
+        assert_eq!(&payload.to_bytes()[4..], &[0u8; 28]);
+    }
+    assert_eq!(Program::lock_height(&Payload::default()), 0);
+
+    // Memoranda at the same height share their height, but not their remaining bytes.
+    let memorandum = Program::memorandum(10, &mut rng);
+    let other_memorandum = Program::memorandum(10, &mut rng);
+    assert_eq!(Program::memorandum_height(&memorandum), 10);
+    assert_eq!(Program::memorandum_height(&other_memorandum), 10);
+    assert_ne!(memorandum, other_memorandum);
+
+    // A memorandum may not claim a height above the current height of the ledger.
+    assert!(Program::is_valid_memorandum(&memorandum, 10));
+    assert!(Program::is_valid_memorandum(&memorandum, 11));
+    assert!(!Program::is_valid_memorandum(&memorandum, 9));
+}
@@ -0,0 +1,188 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::DPCError,
+    testnet1::{
+        parameters::SystemParameters,
+        payload::Payload,
+        BaseDPCComponents,
+        LocalData,
+        PrivateProgramInput,
+        ProgramLocalData,
+        RecordBuilder,
+        TimeLockCircuit,
+        DPC,
+    },
+    traits::{ProgramScheme, RecordScheme},
+    Memo,
+};
+use snarkvm_algorithms::traits::{CommitmentScheme, SNARK};
+use snarkvm_utilities::{to_bytes, ToBytes};
+
+use rand::Rng;
+use std::{convert::TryInto, marker::PhantomData};
+
+/// Reference program for time-locked records, which can only be spent from a given block height on.
+///
+/// A time-locked record has this program as its death program, and stores its lock height in the first
+/// four bytes of its payload, in little-endian order (see `TimeLockProgram::lock_payload`). The program
+/// only accepts a transaction spending the record if the block height in the first four bytes of the
+/// transaction memorandum is at least the lock height (see `TimeLockProgram::memorandum`).
+///
+/// # Trust model
+///
+/// The program proof binds the record and the memorandum to the transaction through the local data root,
+/// but the block height in the memorandum is chosen by the spender, as program proofs have no other view
+/// of the ledger. A ledger that accepts time-locked records must therefore also reject transactions whose
+/// memorandum height is above its current block height, with `TimeLockProgram::is_valid_memorandum`.
+/// A memorandum height below the current block height is harmless, as it only delays the unlocking.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "C: BaseDPCComponents, S: SNARK"),
+    Debug(bound = "C: BaseDPCComponents, S: SNARK"),
+    PartialEq(bound = "C: BaseDPCComponents, S: SNARK"),
+    Eq(bound = "C: BaseDPCComponents, S: SNARK")
+)]
+pub struct TimeLockProgram<C: BaseDPCComponents, S: SNARK> {
+    identity: Vec<u8>,
+    _components: PhantomData<C>,
+    _snark: PhantomData<S>,
+}
+
+impl<C: BaseDPCComponents, S: SNARK> TimeLockProgram<C, S> {
+    pub fn new(identity: Vec<u8>) -> Self {
+        Self {
+            identity,
+            _components: PhantomData,
+            _snark: PhantomData,
+        }
+    }
+
+    /// Returns a record builder for a record that is locked until `lock_height`, with this program
+    /// as its death program. The other fields of the record are left to the caller.
+    pub fn record_builder(&self, lock_height: u32) -> RecordBuilder<C> {
+        RecordBuilder::new()
+            .payload(Self::lock_payload(lock_height))
+            .death_program_id(self.identity.clone())
+    }
+
+    /// Returns the payload of a record that is locked until `lock_height`.
+    pub fn lock_payload(lock_height: u32) -> Payload {
+        let mut payload = [0u8; 32];
+        payload[..4].copy_from_slice(&lock_height.to_le_bytes());
+        Payload::from_bytes(&payload)
+    }
+
+    /// Returns the lock height stored in the given record payload.
+    pub fn lock_height(payload: &Payload) -> u32 {
+        u32::from_le_bytes(payload.to_bytes()[..4].try_into().unwrap())
+    }
+
+    /// Returns a memorandum for a transaction at `current_height`, whose remaining bytes are random,
+    /// so that transactions at the same height have distinct memoranda.
+    pub fn memorandum<R: Rng>(current_height: u32, rng: &mut R) -> Memo {
+        let mut memorandum: [u8; 32] = rng.gen();
+        memorandum[..4].copy_from_slice(&current_height.to_le_bytes());
+        Memo::new(memorandum)
+    }
+
+    /// Returns the block height stored in the given transaction memorandum.
+    pub fn memorandum_height(memorandum: &Memo) -> u32 {
+        u32::from_le_bytes(memorandum.as_bytes()[..4].try_into().unwrap())
+    }
+
+    /// Returns `true` if the block height in the given memorandum does not exceed the current
+    /// block height of the ledger.
+    pub fn is_valid_memorandum(memorandum: &Memo, ledger_height: u32) -> bool {
+        Self::memorandum_height(memorandum) <= ledger_height
+    }
+}
+
+impl<C: BaseDPCComponents, S: SNARK> TimeLockProgram<C, S>
+where
+    S: SNARK<Circuit = TimeLockCircuit<C>, AllocatedCircuit = TimeLockCircuit<C>, VerifierInput = ProgramLocalData<C>>,
+{
+    /// Generates the program SNARK parameters for the given system parameters, and returns
+    /// the program, whose identity is derived from its verifying key.
+    pub fn setup<R: Rng>(
+        system_parameters: &SystemParameters<C>,
+        rng: &mut R,
+    ) -> Result<(Self, S::ProvingKey, S::VerifyingKey), DPCError> {
+        let (proving_key, prepared_verifying_key) = S::setup(&TimeLockCircuit::blank(system_parameters), rng)?;
+        let verifying_key: S::VerifyingKey = prepared_verifying_key.into();
+
+        let identity = DPC::<C>::compute_program_id(&system_parameters.program_verification_key_crh, &to_bytes![
+            verifying_key
+        ]?)?;
+
+        Ok((Self::new(identity), proving_key, verifying_key))
+    }
+}
+
+impl<C: BaseDPCComponents, S: SNARK> ProgramScheme for TimeLockProgram<C, S>
+where
+    S: SNARK<AllocatedCircuit = TimeLockCircuit<C>, VerifierInput = ProgramLocalData<C>>,
+{
+    type LocalData = LocalData<C>;
+    type PrivateWitness = PrivateProgramInput;
+    type ProvingKey = S::ProvingKey;
+    type PublicInput = ();
+    type VerifyingKey = S::VerifyingKey;
+
+    /// Executes the program on the input record at `position`, and returns an error
+    /// if the program proof does not verify, as is the case for a locked record.
+    fn execute<R: Rng>(
+        &self,
+        proving_key: &Self::ProvingKey,
+        verifying_key: &Self::VerifyingKey,
+        local_data: &Self::LocalData,
+        position: u8,
+        rng: &mut R,
+    ) -> Result<Self::PrivateWitness, DPCError> {
+        assert!((position as usize) < C::NUM_INPUT_RECORDS);
+        assert_eq!(
+            self.identity,
+            local_data.old_records[position as usize].death_program_id()
+        );
+
+        let circuit = TimeLockCircuit::<C>::new(local_data, position);
+        let proof = S::prove(proving_key, &circuit, rng)?;
+
+        let program_snark_pvk: S::PreparedVerifyingKey = verifying_key.clone().into();
+        let program_pub_input = ProgramLocalData {
+            local_data_commitment_parameters: local_data.system_parameters.local_data_commitment.parameters().clone(),
+            local_data_root: local_data.local_data_merkle_tree.root(),
+            position,
+        };
+        if !S::verify(&program_snark_pvk, &program_pub_input, &proof)? {
+            return Err(DPCError::InvalidProgramProof(position));
+        }
+
+        Ok(PrivateProgramInput {
+            verification_key: to_bytes![verifying_key]?,
+            proof: to_bytes![proof]?,
+        })
+    }
+
+    fn evaluate(&self, _p: &Self::PublicInput, _w: &Self::PrivateWitness) -> bool {
+        unimplemented!()
+    }
+
+    fn into_compact_repr(&self) -> Vec<u8> {
+        self.identity.clone()
+    }
+}
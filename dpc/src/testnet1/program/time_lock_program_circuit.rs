@@ -0,0 +1,295 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    testnet1::{parameters::SystemParameters, BaseDPCComponents, LocalData, Record, RecordPlaintext},
+    traits::RecordScheme,
+};
+use snarkvm_algorithms::traits::{CommitmentScheme, SignatureScheme, CRH};
+use snarkvm_gadgets::{
+    bits::{Boolean, ToBytesGadget},
+    integers::uint::{UInt32, UInt8},
+    traits::{
+        algorithms::{CRHGadget, CommitmentGadget, SignaturePublicKeyRandomizationGadget},
+        alloc::AllocGadget,
+        bits::ComparatorGadget,
+        eq::EqGadget,
+        integers::Integer,
+        select::CondSelectGadget,
+    },
+};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
+use snarkvm_utilities::{to_bytes, ToBytes};
+
+/// Reference program that only allows an input record to be spent once the block height
+/// reaches the lock height stored in its payload.
+///
+/// The circuit exposes the same public inputs as the `NoopCircuit`, and reads the current block
+/// height from the memorandum of the transaction. It opens the record commitment and the local
+/// data commitment of the record at `position`, and checks that they lead to the local data root,
+/// so that both heights are bound to the transaction. See `TimeLockProgram` for the trust model.
+///
+/// The circuit opens the local data root from the sibling of the record, so it requires
+/// components with two input records.
+pub struct TimeLockCircuit<C: BaseDPCComponents> {
+    /// System parameters
+    pub system_parameters: SystemParameters<C>,
+
+    /// Commitment to the program input.
+    pub local_data_root: <C::LocalDataCRH as CRH>::Output,
+
+    /// Record position
+    pub position: u8,
+
+    /// The input record at `position`.
+    pub record: Record<C>,
+
+    /// The serial number of the input record.
+    pub serial_number: <C::AccountSignature as SignatureScheme>::PublicKey,
+
+    /// The memorandum of the transaction, which starts with the current block height.
+    pub memorandum: [u8; 32],
+
+    /// The network id of the transaction.
+    pub network_id: u8,
+
+    /// The randomness of the local data commitment of the input record.
+    pub local_data_commitment_randomness: <C::LocalDataCommitment as CommitmentScheme>::Randomness,
+
+    /// The local data commitment of the other input record.
+    pub sibling_local_data_commitment: <C::LocalDataCommitment as CommitmentScheme>::Output,
+
+    /// The inner hash of the local data commitments of the output records.
+    pub output_records_hash: <C::LocalDataCRH as CRH>::Output,
+}
+
+impl<C: BaseDPCComponents> TimeLockCircuit<C> {
+    /// Returns a circuit with default values, for use in setup mode.
+    pub fn blank(system_parameters: &SystemParameters<C>) -> Self {
+        Self {
+            system_parameters: system_parameters.clone(),
+            local_data_root: <C::LocalDataCRH as CRH>::Output::default(),
+            position: 0u8,
+            record: Record::default(),
+            serial_number: <C::AccountSignature as SignatureScheme>::PublicKey::default(),
+            memorandum: [0u8; 32],
+            network_id: 0u8,
+            local_data_commitment_randomness: <C::LocalDataCommitment as CommitmentScheme>::Randomness::default(),
+            sibling_local_data_commitment: <C::LocalDataCommitment as CommitmentScheme>::Output::default(),
+            output_records_hash: <C::LocalDataCRH as CRH>::Output::default(),
+        }
+    }
+
+    /// Returns the circuit for the input record at `position`, which must be less than `C::NUM_INPUT_RECORDS`.
+    pub fn new(local_data: &LocalData<C>, position: u8) -> Self {
+        assert_eq!(C::NUM_INPUT_RECORDS, 2);
+        assert!((position as usize) < C::NUM_INPUT_RECORDS);
+        let index = position as usize;
+
+        let leaves = local_data.local_data_merkle_tree.leaves();
+        let (_, output_records_hash) = local_data.local_data_merkle_tree.inner_hashes();
+
+        Self {
+            system_parameters: local_data.system_parameters.clone(),
+            local_data_root: local_data.local_data_merkle_tree.root(),
+            position,
+            record: local_data.old_records[index].clone(),
+            serial_number: local_data.old_serial_numbers[index].clone(),
+            memorandum: *local_data.memorandum.as_bytes(),
+            network_id: local_data.network_id,
+            local_data_commitment_randomness: local_data.local_data_commitment_randomizers[index].clone(),
+            sibling_local_data_commitment: leaves[index ^ 1].clone(),
+            output_records_hash,
+        }
+    }
+}
+
+impl<C: BaseDPCComponents> ConstraintSynthesizer<C::InnerField> for TimeLockCircuit<C> {
+    fn generate_constraints<CS: ConstraintSystem<C::InnerField>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let system_parameters = &self.system_parameters;
+
+        let position = UInt8::alloc_input_vec_le(cs.ns(|| "Alloc position"), &[self.position])?;
+
+        let local_data_commitment_parameters =
+            <C::LocalDataCommitmentGadget as CommitmentGadget<_, _>>::ParametersGadget::alloc_input(
+                &mut cs.ns(|| "Declare local data commitment parameters"),
+                || Ok(system_parameters.local_data_commitment.parameters().clone()),
+            )?;
+
+        let local_data_root = <C::LocalDataCRHGadget as CRHGadget<_, _>>::OutputGadget::alloc_input(
+            cs.ns(|| "Allocate local data root"),
+            || Ok(&self.local_data_root),
+        )?;
+
+        // The record is an input record, so its position is either 0 or 1.
+        let position_bits = position[0].to_bits_le();
+        for (i, bit) in position_bits.iter().enumerate().skip(1) {
+            bit.enforce_equal(
+                cs.ns(|| format!("Enforce position bit {} is zero", i)),
+                &Boolean::constant(false),
+            )?;
+        }
+        let is_second_input_record = position_bits[0];
+
+        // *******************************************************************
+        // Open the record commitment.
+        // *******************************************************************
+
+        let record_commitment_parameters =
+            <C::RecordCommitmentGadget as CommitmentGadget<_, _>>::ParametersGadget::alloc(
+                &mut cs.ns(|| "Declare record commitment parameters"),
+                || Ok(system_parameters.record_commitment.parameters().clone()),
+            )?;
+
+        let commitment_input = UInt8::alloc_vec(
+            cs.ns(|| "Allocate record commitment input"),
+            &self.record.plaintext().to_commitment_input()?,
+        )?;
+
+        let record_commitment_randomness =
+            <C::RecordCommitmentGadget as CommitmentGadget<_, _>>::RandomnessGadget::alloc(
+                cs.ns(|| "Allocate record commitment randomness"),
+                || Ok(self.record.commitment_randomness()),
+            )?;
+
+        let record_commitment = C::RecordCommitmentGadget::check_commitment_gadget(
+            cs.ns(|| "Compute record commitment"),
+            &record_commitment_parameters,
+            &commitment_input,
+            &record_commitment_randomness,
+        )?;
+
+        // *******************************************************************
+        // Open the local data commitment of the record, and check that it leads to the local data root.
+        // *******************************************************************
+
+        let serial_number =
+            <C::AccountSignatureGadget as SignaturePublicKeyRandomizationGadget<_, _>>::PublicKeyGadget::alloc(
+                cs.ns(|| "Allocate serial number"),
+                || Ok(&self.serial_number),
+            )?;
+        let memorandum = UInt8::alloc_vec(cs.ns(|| "Allocate memorandum"), &self.memorandum)?;
+        let network_id = UInt8::alloc_vec(cs.ns(|| "Allocate network id"), &[self.network_id])?;
+
+        let mut local_data_input = serial_number.to_bytes(cs.ns(|| "Convert serial number to bytes"))?;
+        local_data_input
+            .extend_from_slice(&record_commitment.to_bytes(cs.ns(|| "Convert record commitment to bytes"))?);
+        local_data_input.extend_from_slice(&memorandum);
+        local_data_input.extend_from_slice(&network_id);
+
+        let local_data_commitment_randomness =
+            <C::LocalDataCommitmentGadget as CommitmentGadget<_, _>>::RandomnessGadget::alloc(
+                cs.ns(|| "Allocate local data commitment randomness"),
+                || Ok(&self.local_data_commitment_randomness),
+            )?;
+
+        let local_data_commitment = C::LocalDataCommitmentGadget::check_commitment_gadget(
+            cs.ns(|| "Compute local data commitment"),
+            &local_data_commitment_parameters,
+            &local_data_input,
+            &local_data_commitment_randomness,
+        )?;
+
+        let sibling_local_data_commitment =
+            <C::LocalDataCommitmentGadget as CommitmentGadget<_, _>>::OutputGadget::alloc(
+                cs.ns(|| "Allocate sibling local data commitment"),
+                || Ok(&self.sibling_local_data_commitment),
+            )?;
+
+        let local_data_commitment_bytes =
+            local_data_commitment.to_bytes(cs.ns(|| "Convert local data commitment to bytes"))?;
+        let sibling_local_data_commitment_bytes =
+            sibling_local_data_commitment.to_bytes(cs.ns(|| "Convert sibling local data commitment to bytes"))?;
+
+        // The local data commitment of the record is the right leaf if and only if it is the second input record.
+        let mut left_leaf_bytes = Vec::with_capacity(local_data_commitment_bytes.len());
+        let mut right_leaf_bytes = Vec::with_capacity(local_data_commitment_bytes.len());
+        for (i, (leaf_byte, sibling_byte)) in local_data_commitment_bytes
+            .iter()
+            .zip(&sibling_local_data_commitment_bytes)
+            .enumerate()
+        {
+            left_leaf_bytes.push(UInt8::conditionally_select(
+                cs.ns(|| format!("Select left leaf byte {}", i)),
+                &is_second_input_record,
+                sibling_byte,
+                leaf_byte,
+            )?);
+            right_leaf_bytes.push(UInt8::conditionally_select(
+                cs.ns(|| format!("Select right leaf byte {}", i)),
+                &is_second_input_record,
+                leaf_byte,
+                sibling_byte,
+            )?);
+        }
+        left_leaf_bytes.extend(right_leaf_bytes);
+
+        let local_data_crh_parameters = <C::LocalDataCRHGadget as CRHGadget<_, _>>::ParametersGadget::alloc(
+            &mut cs.ns(|| "Declare local data CRH parameters"),
+            || Ok(system_parameters.local_data_crh.parameters().clone()),
+        )?;
+
+        let input_records_hash = C::LocalDataCRHGadget::check_evaluation_gadget(
+            cs.ns(|| "Compute input records hash"),
+            &local_data_crh_parameters,
+            left_leaf_bytes,
+        )?;
+
+        let output_records_hash = <C::LocalDataCRHGadget as CRHGadget<_, _>>::OutputGadget::alloc(
+            cs.ns(|| "Allocate output records hash"),
+            || Ok(&self.output_records_hash),
+        )?;
+
+        let mut inner_hash_bytes = input_records_hash.to_bytes(cs.ns(|| "Convert input records hash to bytes"))?;
+        inner_hash_bytes
+            .extend_from_slice(&output_records_hash.to_bytes(cs.ns(|| "Convert output records hash to bytes"))?);
+
+        let candidate_local_data_root = C::LocalDataCRHGadget::check_evaluation_gadget(
+            cs.ns(|| "Compute local data root"),
+            &local_data_crh_parameters,
+            inner_hash_bytes,
+        )?;
+
+        candidate_local_data_root.enforce_equal(cs.ns(|| "Check that local data root is valid"), &local_data_root)?;
+
+        // *******************************************************************
+        // Check that the current block height has reached the lock height.
+        // *******************************************************************
+
+        let payload_offset = payload_offset::<C>()?;
+        let lock_height = height_from_bytes(&commitment_input[payload_offset..payload_offset + 4]);
+        let current_height = height_from_bytes(&memorandum[..4]);
+
+        let is_unlocked =
+            current_height.greater_than_or_equal(cs.ns(|| "Compare current height and lock height"), &lock_height)?;
+        is_unlocked.enforce_equal(
+            cs.ns(|| "Enforce that the current height has reached the lock height"),
+            &Boolean::constant(true),
+        )
+    }
+}
+
+/// Returns the offset of the payload in the record commitment input.
+fn payload_offset<C: BaseDPCComponents>() -> Result<usize, SynthesisError> {
+    let plaintext = RecordPlaintext::<C>::default();
+    Ok(to_bytes![plaintext.owner(), plaintext.is_dummy(), plaintext.value()]?.len())
+}
+
+/// Returns the block height encoded in the little-endian `bytes`.
+fn height_from_bytes(bytes: &[UInt8]) -> UInt32 {
+    let bits: Vec<_> = bytes.iter().flat_map(|byte| byte.to_bits_le()).collect();
+    UInt32::from_bits_le(&bits)
+}
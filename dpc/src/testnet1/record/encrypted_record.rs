@@ -14,7 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{testnet1::BaseDPCComponents, traits::DPCComponents};
+use crate::{
+    account::AccountViewKey,
+    errors::DPCError,
+    testnet1::{
+        parameters::SystemParameters,
+        record::{Record, RecordCommitment, RecordEncryption},
+        BaseDPCComponents,
+    },
+    traits::{DPCComponents, RecordScheme},
+};
 use snarkvm_algorithms::traits::EncryptionScheme;
 use snarkvm_curves::traits::{AffineCurve, ProjectiveCurve};
 use snarkvm_utilities::{bits_to_bytes, bytes_to_bits, to_bytes, variable_length_integer::*, FromBytes, ToBytes};
@@ -34,6 +43,36 @@ pub struct EncryptedRecord<C: BaseDPCComponents> {
     pub final_fq_high_selector: bool,
 }
 
+impl<C: BaseDPCComponents> EncryptedRecord<C> {
+    /// Decrypts the record with the given account view key, and returns it if it opens the given
+    /// record commitment, such as the output commitment of the transaction that holds the record.
+    pub fn decrypt(
+        &self,
+        system_parameters: &SystemParameters<C>,
+        account_view_key: &AccountViewKey<C>,
+        commitment: &RecordCommitment<C>,
+    ) -> Result<Record<C>, DPCError> {
+        let record = RecordEncryption::decrypt_record(system_parameters, account_view_key, self)?;
+        if record.commitment() != *commitment {
+            return Err(DPCError::MismatchedRecordCommitment(
+                record.commitment().to_string(),
+                commitment.to_string(),
+            ));
+        }
+
+        Ok(record)
+    }
+
+    /// Returns `true` if the record decrypts to a valid record encoding with the given account
+    /// view key. This is cheaper than `decrypt`, as it neither derives the owner address nor
+    /// computes the record commitment, so it suits scanning many encrypted records for the ones
+    /// of an account. It may return `true` for a record of another account, although rarely, so
+    /// the records it accepts must still be decrypted with `decrypt`.
+    pub fn is_owner(&self, system_parameters: &SystemParameters<C>, account_view_key: &AccountViewKey<C>) -> bool {
+        RecordEncryption::decrypt_record_components(system_parameters, account_view_key, self).is_ok()
+    }
+}
+
 impl<C: BaseDPCComponents> ToBytes for EncryptedRecord<C> {
     #[inline]
     fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
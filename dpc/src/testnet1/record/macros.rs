@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_utilities::bytes::ToBytes;
+
+use subtle::{Choice, ConstantTimeEq};
+
+/// The size of the stack buffers that wrapper values are serialized into for comparison.
+const MAX_WRAPPER_SIZE: usize = 1024;
+
+/// Serializes the given value into the given buffer, returning the number of bytes written.
+fn write_to_buffer<T: ToBytes>(value: &T, buffer: &mut [u8; MAX_WRAPPER_SIZE]) -> std::io::Result<usize> {
+    let mut writer = &mut buffer[..];
+    value.write(&mut writer)?;
+    Ok(MAX_WRAPPER_SIZE - writer.len())
+}
+
+/// Compares the serializations of the given values in constant time, without allocating.
+///
+/// A value that fails to serialize, or whose serialization exceeds `MAX_WRAPPER_SIZE` bytes,
+/// compares unequal to every value.
+pub(crate) fn ct_eq_serialized<T: ToBytes>(a: &T, b: &T) -> Choice {
+    let mut a_bytes = [0u8; MAX_WRAPPER_SIZE];
+    let mut b_bytes = [0u8; MAX_WRAPPER_SIZE];
+
+    match (write_to_buffer(a, &mut a_bytes), write_to_buffer(b, &mut b_bytes)) {
+        (Ok(a_size), Ok(b_size)) => (a_size as u64).ct_eq(&(b_size as u64)) & a_bytes[..].ct_eq(&b_bytes[..]),
+        _ => Choice::from(0),
+    }
+}
+
+/// Implements the constructors, accessors, comparisons, serialization, and formatting of a record
+/// wrapper type, which must be declared as a tuple struct over a private `$inner` value.
+///
+/// Wrappers are compared in constant time with `ct_eq_serialized`, so comparisons never panic.
+macro_rules! impl_record_wrapper {
+    ($wrapper: ident, $inner: ty, $name: expr) => {
+        impl<C: BaseDPCComponents> $wrapper<C> {
+            /// Wraps the given value.
+            pub fn new(inner: $inner) -> Self {
+                Self(inner)
+            }
+
+            /// Returns a reference to the underlying value.
+            pub fn inner(&self) -> &$inner {
+                &self.0
+            }
+
+            /// Returns the underlying value.
+            pub fn into_inner(self) -> $inner {
+                self.0
+            }
+
+            /// Returns the underlying value as bytes.
+            pub fn to_bytes(&self) -> Vec<u8> {
+                let mut bytes = Vec::new();
+                snarkvm_utilities::bytes::ToBytes::write(&self.0, &mut bytes)
+                    .expect(concat!("failed to serialize the ", $name));
+                bytes
+            }
+        }
+
+        impl<C: BaseDPCComponents> subtle::ConstantTimeEq for $wrapper<C> {
+            fn ct_eq(&self, other: &Self) -> subtle::Choice {
+                crate::testnet1::record::macros::ct_eq_serialized(&self.0, &other.0)
+            }
+        }
+
+        impl<C: BaseDPCComponents> PartialEq for $wrapper<C> {
+            fn eq(&self, other: &Self) -> bool {
+                subtle::ConstantTimeEq::ct_eq(self, other).into()
+            }
+        }
+
+        impl<C: BaseDPCComponents> Eq for $wrapper<C> {}
+
+        impl<C: BaseDPCComponents> std::hash::Hash for $wrapper<C> {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                std::hash::Hash::hash(&self.0, state);
+            }
+        }
+
+        impl<C: BaseDPCComponents> snarkvm_utilities::bytes::ToBytes for $wrapper<C> {
+            #[inline]
+            fn write<W: std::io::Write>(&self, writer: W) -> std::io::Result<()> {
+                snarkvm_utilities::bytes::ToBytes::write(&self.0, writer)
+            }
+        }
+
+        impl<C: BaseDPCComponents> snarkvm_utilities::bytes::FromBytes for $wrapper<C> {
+            #[inline]
+            fn read<R: std::io::Read>(reader: R) -> std::io::Result<Self> {
+                Ok(Self(snarkvm_utilities::bytes::FromBytes::read(reader)?))
+            }
+        }
+
+        impl<C: BaseDPCComponents> std::fmt::Display for $wrapper<C> {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{}", hex::encode(self.to_bytes()))
+            }
+        }
+
+        impl<C: BaseDPCComponents> std::fmt::Debug for $wrapper<C> {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{}({})", stringify!($wrapper), self)
+            }
+        }
+    };
+}
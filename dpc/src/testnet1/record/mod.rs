@@ -14,19 +14,40 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[macro_use]
+mod macros;
+
 pub mod encrypted_record;
 pub use encrypted_record::*;
 
 pub mod record;
 pub use record::*;
 
+pub mod record_builder;
+pub use record_builder::*;
+
+pub mod record_commitment;
+pub use record_commitment::*;
+
 pub mod record_encoding;
 pub use record_encoding::*;
 
 pub mod record_encryption;
 pub use record_encryption::*;
 
+pub mod record_format;
+pub use record_format::*;
+
+pub mod record_plaintext;
+pub use record_plaintext::*;
+
+pub mod record_secrets;
+pub use record_secrets::*;
+
 pub mod payload;
 
+pub mod serial_number;
+pub use serial_number::*;
+
 #[cfg(test)]
 mod tests;
@@ -16,27 +16,37 @@
 
 use crate::{
     account::AccountAddress,
-    errors::RecordError,
-    testnet1::{payload::Payload, BaseDPCComponents},
+    bytes_serde,
+    errors::{DPCError, RecordError},
+    testnet1::{
+        payload::Payload,
+        record_plaintext::{default_program_id, read_record_value},
+        AleoAmount,
+        BaseDPCComponents,
+        RecordCommitment,
+        RecordFormat,
+        RecordPlaintext,
+        RecordSecrets,
+        SerialNumber,
+        RECORD_FORMAT_SENTINEL,
+    },
     traits::RecordScheme,
 };
-use snarkvm_algorithms::traits::{CommitmentScheme, SignatureScheme, CRH};
+use snarkvm_algorithms::traits::{CommitmentScheme, CRH};
 use snarkvm_utilities::{
-    bytes::{FromBytes, ToBytes},
-    to_bytes,
+    bytes::{to_bytes_len, FromBytes, ToBytes},
+    error,
     variable_length_integer::*,
 };
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt,
-    io::{Read, Result as IoResult, Write},
+    io::{self, Read, Result as IoResult, Write},
     str::FromStr,
 };
 
-fn default_program_id<C: CRH>() -> Vec<u8> {
-    to_bytes![C::Output::default()].unwrap()
-}
-
+/// A record, composed of its plaintext, its commitment, and the secrets used to open the commitment.
 #[derive(Derivative)]
 #[derivative(
     Default(bound = "C: BaseDPCComponents"),
@@ -46,57 +56,223 @@ fn default_program_id<C: CRH>() -> Vec<u8> {
     Eq(bound = "C: BaseDPCComponents")
 )]
 pub struct Record<C: BaseDPCComponents> {
-    pub(crate) owner: AccountAddress<C>,
-    pub(crate) is_dummy: bool,
-    // TODO (raychu86) use AleoAmount which will guard the value range
-    pub(crate) value: u64,
-    pub(crate) payload: Payload,
-
-    #[derivative(Default(value = "default_program_id::<C::ProgramVerificationKeyCRH>()"))]
-    pub(crate) birth_program_id: Vec<u8>,
-    #[derivative(Default(value = "default_program_id::<C::ProgramVerificationKeyCRH>()"))]
-    pub(crate) death_program_id: Vec<u8>,
-
-    pub(crate) serial_number_nonce: <C::SerialNumberNonceCRH as CRH>::Output,
-    pub(crate) commitment: <C::RecordCommitment as CommitmentScheme>::Output,
-    pub(crate) commitment_randomness: <C::RecordCommitment as CommitmentScheme>::Randomness,
+    pub(crate) plaintext: RecordPlaintext<C>,
+    pub(crate) commitment: RecordCommitment<C>,
+    pub(crate) secrets: RecordSecrets<C>,
+}
+
+impl<C: BaseDPCComponents> Record<C> {
+    /// Returns the record with the given plaintext, committed to with the given secrets.
+    pub fn from_parts(
+        record_commitment_parameters: &C::RecordCommitment,
+        plaintext: RecordPlaintext<C>,
+        secrets: RecordSecrets<C>,
+    ) -> Result<Self, DPCError> {
+        let commitment = C::RecordCommitment::commit(
+            record_commitment_parameters,
+            &plaintext.to_commitment_input()?,
+            &secrets.commitment_randomness,
+        )?;
+
+        Ok(Self {
+            plaintext,
+            commitment: RecordCommitment::new(commitment),
+            secrets,
+        })
+    }
+
+    /// Returns `true` if the record commitment opens to the record contents with the commitment randomness.
+    pub fn verify_commitment(&self, record_commitment_parameters: &C::RecordCommitment) -> bool {
+        let commitment = self.plaintext.to_commitment_input().ok().and_then(|input| {
+            C::RecordCommitment::commit(
+                record_commitment_parameters,
+                &input,
+                &self.secrets.commitment_randomness,
+            )
+            .ok()
+        });
+        commitment.map(RecordCommitment::new).as_ref() == Some(&self.commitment)
+    }
+
+    /// Splits the record into its plaintext and its secrets.
+    /// The commitment can be recomputed from both with `Record::from_parts`.
+    pub fn split(self) -> (RecordPlaintext<C>, RecordSecrets<C>) {
+        (self.plaintext, self.secrets)
+    }
+
+    /// Returns the record plaintext.
+    pub fn plaintext(&self) -> &RecordPlaintext<C> {
+        &self.plaintext
+    }
+
+    /// Returns the record secrets.
+    pub fn secrets(&self) -> &RecordSecrets<C> {
+        &self.secrets
+    }
+
+    /// Returns the number of bytes written by `Record::write_with_secrets`, without serializing
+    /// the record.
+    pub fn serialized_size(&self) -> usize {
+        self.plaintext.serialized_size()
+            + to_bytes_len(&self.commitment).unwrap()
+            + to_bytes_len(&self.secrets).unwrap()
+    }
+
+    /// Writes the full record, including the commitment randomness.
+    pub fn write_with_secrets<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.plaintext.write(&mut writer)?;
+        self.commitment.write(&mut writer)?;
+        self.secrets.write(&mut writer)
+    }
+
+    /// Returns the full record as bytes, including the commitment randomness.
+    pub fn to_bytes_with_secrets(&self) -> IoResult<Vec<u8>> {
+        let mut bytes = vec![];
+        self.write_with_secrets(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Writes the full record in the given format.
+    pub fn write_with_format<W: Write>(&self, format: RecordFormat, writer: W) -> IoResult<()> {
+        match format {
+            RecordFormat::V0 => self.write_v0(writer),
+            RecordFormat::V1 => self.write_v1(writer),
+        }
+    }
+
+    /// Writes the full record in format 0, exactly as `Record::write_with_secrets`.
+    pub fn write_v0<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_with_secrets(writer)
+    }
+
+    /// Writes the full record in format 1. Fails if a program id is not as wide as
+    /// the output of the program verification key CRH.
+    pub fn write_v1<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        let program_id_size = default_program_id::<C::ProgramVerificationKeyCRH>().len();
+        if self.plaintext.birth_program_id.len() != program_id_size
+            || self.plaintext.death_program_id.len() != program_id_size
+        {
+            return Err(error("record program id does not have the size of format 1"));
+        }
+
+        RECORD_FORMAT_SENTINEL.write(&mut writer)?;
+        RecordFormat::V1.number().write(&mut writer)?;
+
+        self.plaintext.owner.write(&mut writer)?;
+        self.plaintext.is_dummy.write(&mut writer)?;
+        self.plaintext.value.write(&mut writer)?;
+        self.plaintext.payload.write(&mut writer)?;
+        self.plaintext.birth_program_id.write(&mut writer)?;
+        self.plaintext.death_program_id.write(&mut writer)?;
+        self.plaintext.serial_number_nonce.write(&mut writer)?;
+        self.commitment.write(&mut writer)?;
+        self.secrets.write(&mut writer)?;
+
+        // There are no extension entries yet.
+        variable_length_integer(0).write(&mut writer)
+    }
+
+    /// Reads a full record in any format, and returns it with its format.
+    pub fn read_with_format<R: Read>(mut reader: R) -> IoResult<(Self, RecordFormat)> {
+        let mut prefix = [0u8; RECORD_FORMAT_SENTINEL.len()];
+        reader.read_exact(&mut prefix)?;
+        if prefix != RECORD_FORMAT_SENTINEL {
+            // The prefix is the start of the owner address of a format 0 record.
+            return Ok((Self::read_v0(Read::chain(&prefix[..], reader))?, RecordFormat::V0));
+        }
+
+        let number: u8 = FromBytes::read(&mut reader)?;
+        match number {
+            1 => Ok((Self::read_v1(reader)?, RecordFormat::V1)),
+            _ => Err(error("unknown record format")),
+        }
+    }
+
+    /// Reads a full record in format 0.
+    fn read_v0<R: Read>(mut reader: R) -> IoResult<Self> {
+        let plaintext: RecordPlaintext<C> = FromBytes::read(&mut reader)?;
+        let commitment: RecordCommitment<C> = FromBytes::read(&mut reader)?;
+        let secrets: RecordSecrets<C> = FromBytes::read(&mut reader)?;
+
+        Ok(Self {
+            plaintext,
+            commitment,
+            secrets,
+        })
+    }
+
+    /// Reads a full record in format 1, after its sentinel and format number.
+    fn read_v1<R: Read>(mut reader: R) -> IoResult<Self> {
+        let program_id_size = default_program_id::<C::ProgramVerificationKeyCRH>().len();
+        let read_program_id = |reader: &mut R| -> IoResult<Vec<u8>> {
+            let mut program_id = vec![0u8; program_id_size];
+            reader.read_exact(&mut program_id)?;
+            Ok(program_id)
+        };
+
+        let plaintext = RecordPlaintext {
+            owner: FromBytes::read(&mut reader)?,
+            is_dummy: FromBytes::read(&mut reader)?,
+            value: read_record_value(&mut reader)?,
+            payload: FromBytes::read(&mut reader)?,
+            birth_program_id: read_program_id(&mut reader)?,
+            death_program_id: read_program_id(&mut reader)?,
+            serial_number_nonce: FromBytes::read(&mut reader)?,
+        };
+        let commitment: RecordCommitment<C> = FromBytes::read(&mut reader)?;
+        let secrets: RecordSecrets<C> = FromBytes::read(&mut reader)?;
+
+        // Skip the extension entries, none of which are known yet.
+        let num_entries = read_variable_length_integer(&mut reader)?;
+        for _ in 0..num_entries {
+            let _tag = read_variable_length_integer(&mut reader)?;
+            let size = read_variable_length_integer(&mut reader)?;
+            io::copy(&mut (&mut reader).take(size as u64), &mut io::sink())?;
+        }
+
+        Ok(Self {
+            plaintext,
+            commitment,
+            secrets,
+        })
+    }
 }
 
 impl<C: BaseDPCComponents> RecordScheme for Record<C> {
-    type Commitment = <C::RecordCommitment as CommitmentScheme>::Output;
+    type Commitment = RecordCommitment<C>;
     type CommitmentRandomness = <C::RecordCommitment as CommitmentScheme>::Randomness;
     type Owner = AccountAddress<C>;
     type Payload = Payload;
-    type SerialNumber = <C::AccountSignature as SignatureScheme>::PublicKey;
+    type SerialNumber = SerialNumber<C>;
     type SerialNumberNonce = <C::SerialNumberNonceCRH as CRH>::Output;
-    type Value = u64;
+    type Value = AleoAmount;
 
     fn owner(&self) -> &Self::Owner {
-        &self.owner
+        self.plaintext.owner()
     }
 
     fn is_dummy(&self) -> bool {
-        self.is_dummy
+        self.plaintext.is_dummy()
     }
 
     fn value(&self) -> Self::Value {
-        self.value
+        self.plaintext.value()
     }
 
     fn payload(&self) -> &Self::Payload {
-        &self.payload
+        self.plaintext.payload()
     }
 
     fn birth_program_id(&self) -> &[u8] {
-        &self.birth_program_id
+        self.plaintext.birth_program_id()
     }
 
     fn death_program_id(&self) -> &[u8] {
-        &self.death_program_id
+        self.plaintext.death_program_id()
     }
 
     fn serial_number_nonce(&self) -> &Self::SerialNumberNonce {
-        &self.serial_number_nonce
+        self.plaintext.serial_number_nonce()
     }
 
     fn commitment(&self) -> Self::Commitment {
@@ -104,71 +280,38 @@ impl<C: BaseDPCComponents> RecordScheme for Record<C> {
     }
 
     fn commitment_randomness(&self) -> Self::CommitmentRandomness {
-        self.commitment_randomness.clone()
+        self.secrets.commitment_randomness().clone()
     }
 }
 
+/// Records are serialized in full, including the commitment randomness, exactly as in
+/// `Record::write_with_secrets`. This is the encoding that stored records and transaction
+/// kernels have always used. Serialize the `RecordPlaintext` to leave out the randomness.
 impl<C: BaseDPCComponents> ToBytes for Record<C> {
     #[inline]
-    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        self.owner.write(&mut writer)?;
-        self.is_dummy.write(&mut writer)?;
-        self.value.write(&mut writer)?;
-        self.payload.write(&mut writer)?;
-
-        variable_length_integer(self.birth_program_id.len() as u64).write(&mut writer)?;
-        self.birth_program_id.write(&mut writer)?;
-
-        variable_length_integer(self.death_program_id.len() as u64).write(&mut writer)?;
-        self.death_program_id.write(&mut writer)?;
-
-        self.serial_number_nonce.write(&mut writer)?;
-        self.commitment.write(&mut writer)?;
-        self.commitment_randomness.write(&mut writer)
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_with_secrets(writer)
     }
 }
 
+/// Records are read in any format, detected by `RECORD_FORMAT_SENTINEL`.
 impl<C: BaseDPCComponents> FromBytes for Record<C> {
     #[inline]
-    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
-        let owner: AccountAddress<C> = FromBytes::read(&mut reader)?;
-        let is_dummy: bool = FromBytes::read(&mut reader)?;
-        let value: u64 = FromBytes::read(&mut reader)?;
-        let payload: Payload = FromBytes::read(&mut reader)?;
-
-        let birth_program_id_size: usize = read_variable_length_integer(&mut reader)?;
-
-        let mut birth_program_id = Vec::with_capacity(birth_program_id_size);
-        for _ in 0..birth_program_id_size {
-            let byte: u8 = FromBytes::read(&mut reader)?;
-            birth_program_id.push(byte);
-        }
-
-        let death_program_id_size: usize = read_variable_length_integer(&mut reader)?;
-
-        let mut death_program_id = Vec::with_capacity(death_program_id_size);
-        for _ in 0..death_program_id_size {
-            let byte: u8 = FromBytes::read(&mut reader)?;
-            death_program_id.push(byte);
-        }
-
-        let serial_number_nonce: <C::SerialNumberNonceCRH as CRH>::Output = FromBytes::read(&mut reader)?;
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Self::read_with_format(reader)?.0)
+    }
+}
 
-        let commitment: <C::RecordCommitment as CommitmentScheme>::Output = FromBytes::read(&mut reader)?;
-        let commitment_randomness: <C::RecordCommitment as CommitmentScheme>::Randomness =
-            FromBytes::read(&mut reader)?;
+/// Records are serialized as in `Display`, or as raw bytes for binary formats.
+impl<C: BaseDPCComponents> Serialize for Record<C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        bytes_serde::serialize(self, serializer)
+    }
+}
 
-        Ok(Self {
-            owner,
-            is_dummy,
-            value,
-            payload,
-            birth_program_id,
-            death_program_id,
-            serial_number_nonce,
-            commitment,
-            commitment_randomness,
-        })
+impl<'de, C: BaseDPCComponents> Deserialize<'de> for Record<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        bytes_serde::deserialize(deserializer, "record")
     }
 }
 
@@ -187,7 +330,7 @@ impl<C: BaseDPCComponents> fmt::Display for Record<C> {
         write!(
             f,
             "{}",
-            hex::encode(to_bytes![self].expect("serialization to bytes failed"))
+            hex::encode(self.to_bytes_with_secrets().expect("serialization to bytes failed"))
         )
     }
 }
@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::AccountAddress,
+    errors::RecordError,
+    testnet1::{payload::Payload, AleoAmount, BaseDPCComponents, Record, RecordPlaintext, RecordSecrets},
+};
+use snarkvm_algorithms::traits::{CommitmentScheme, CRH};
+use snarkvm_utilities::rand::UniformRand;
+
+use rand::Rng;
+use std::convert::TryFrom;
+
+/// A builder for records, which commits to the record contents once they are all set.
+///
+/// Setting a field after `calculate_commitment` discards the commitment, so a built
+/// record always opens its commitment.
+#[derive(Derivative)]
+#[derivative(
+    Default(bound = "C: BaseDPCComponents"),
+    Debug(bound = "C: BaseDPCComponents"),
+    Clone(bound = "C: BaseDPCComponents")
+)]
+pub struct RecordBuilder<C: BaseDPCComponents> {
+    owner: Option<AccountAddress<C>>,
+    is_dummy: bool,
+    /// The record value, or a `u64` value that does not fit in an `AleoAmount`.
+    value: Option<Result<AleoAmount, u64>>,
+    payload: Option<Payload>,
+    birth_program_id: Option<Vec<u8>>,
+    death_program_id: Option<Vec<u8>>,
+    serial_number_nonce: Option<<C::SerialNumberNonceCRH as CRH>::Output>,
+    record: Option<Record<C>>,
+}
+
+impl<C: BaseDPCComponents> RecordBuilder<C> {
+    /// Returns a builder with no fields set, for a record that is not dummy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the record owner.
+    pub fn owner(mut self, owner: AccountAddress<C>) -> Self {
+        self.owner = Some(owner);
+        self.record = None;
+        self
+    }
+
+    /// Sets whether or not the record is dummy.
+    pub fn is_dummy(mut self, is_dummy: bool) -> Self {
+        self.is_dummy = is_dummy;
+        self.record = None;
+        self
+    }
+
+    /// Sets the record value.
+    pub fn amount(mut self, value: AleoAmount) -> Self {
+        self.value = Some(Ok(value));
+        self.record = None;
+        self
+    }
+
+    /// Sets the record value from a `u64`, which must fit in an `AleoAmount`.
+    #[deprecated(note = "use `amount` instead")]
+    pub fn value(mut self, value: u64) -> Self {
+        self.value = Some(AleoAmount::try_from(value).map_err(|_| value));
+        self.record = None;
+        self
+    }
+
+    /// Sets the record payload.
+    pub fn payload(mut self, payload: Payload) -> Self {
+        self.payload = Some(payload);
+        self.record = None;
+        self
+    }
+
+    /// Sets the birth program id of the record.
+    pub fn birth_program_id(mut self, birth_program_id: Vec<u8>) -> Self {
+        self.birth_program_id = Some(birth_program_id);
+        self.record = None;
+        self
+    }
+
+    /// Sets the death program id of the record.
+    pub fn death_program_id(mut self, death_program_id: Vec<u8>) -> Self {
+        self.death_program_id = Some(death_program_id);
+        self.record = None;
+        self
+    }
+
+    /// Sets the randomness used for the serial number.
+    pub fn serial_number_nonce(mut self, serial_number_nonce: <C::SerialNumberNonceCRH as CRH>::Output) -> Self {
+        self.serial_number_nonce = Some(serial_number_nonce);
+        self.record = None;
+        self
+    }
+
+    /// Samples the commitment randomness, and commits to the record contents with it.
+    pub fn calculate_commitment<R: Rng>(
+        mut self,
+        record_commitment_parameters: &C::RecordCommitment,
+        rng: &mut R,
+    ) -> Result<Self, RecordError> {
+        let commitment_randomness = <C::RecordCommitment as CommitmentScheme>::Randomness::rand(rng);
+        self.record = Some(Record::from_parts(
+            record_commitment_parameters,
+            self.plaintext()?,
+            RecordSecrets::new(commitment_randomness),
+        )?);
+        Ok(self)
+    }
+
+    /// Returns the record, if all of its fields are set and its commitment is calculated.
+    pub fn build(self) -> Result<Record<C>, RecordError> {
+        self.plaintext()?;
+        self.record.ok_or(RecordError::InvalidCommitment)
+    }
+
+    /// Returns the record plaintext, if all of its fields are set, its value is not negative,
+    /// and a dummy record has no value.
+    fn plaintext(&self) -> Result<RecordPlaintext<C>, RecordError> {
+        fn require<T: Clone>(field: &Option<T>, name: &str) -> Result<T, RecordError> {
+            field.clone().ok_or_else(|| RecordError::MissingField(name.into()))
+        }
+
+        let value = match require(&self.value, "value")? {
+            Ok(value) if value.is_negative() => return Err(RecordError::NegativeValue(value.0)),
+            Ok(value) => value,
+            Err(value) => return Err(RecordError::ValueOutOfRange(value)),
+        };
+        if self.is_dummy && value != AleoAmount::ZERO {
+            return Err(RecordError::DummyMustBeZero(value.0));
+        }
+
+        Ok(RecordPlaintext::new(
+            require(&self.owner, "owner")?,
+            self.is_dummy,
+            value,
+            require(&self.payload, "payload")?,
+            require(&self.birth_program_id, "birth_program_id")?,
+            require(&self.death_program_id, "death_program_id")?,
+            require(&self.serial_number_nonce, "serial_number_nonce")?,
+        ))
+    }
+}
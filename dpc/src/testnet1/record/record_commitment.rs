@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::testnet1::BaseDPCComponents;
+use snarkvm_algorithms::traits::CommitmentScheme;
+
+/// The commitment to a record, published when the record is created.
+///
+/// Record commitments and serial numbers are distinct types, so one cannot be
+/// passed where the other is expected, even when their underlying types coincide.
+///
+/// ```
+/// use snarkvm_dpc::testnet1::{instantiated::Components, RecordCommitment};
+///
+/// fn insert(_commitment: &RecordCommitment<Components>) {}
+///
+/// let commitment = RecordCommitment::<Components>::default();
+/// insert(&commitment);
+/// ```
+///
+/// ```compile_fail,E0308
+/// use snarkvm_dpc::testnet1::{instantiated::Components, RecordCommitment, SerialNumber};
+///
+/// fn insert(_commitment: &RecordCommitment<Components>) {}
+///
+/// let serial_number = SerialNumber::<Components>::default();
+/// insert(&serial_number);
+/// ```
+#[derive(Derivative)]
+#[derivative(Clone(bound = "C: BaseDPCComponents"), Default(bound = "C: BaseDPCComponents"))]
+pub struct RecordCommitment<C: BaseDPCComponents>(<C::RecordCommitment as CommitmentScheme>::Output);
+
+impl_record_wrapper!(RecordCommitment, <C::RecordCommitment as CommitmentScheme>::Output, "record commitment");
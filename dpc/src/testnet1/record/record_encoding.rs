@@ -16,7 +16,7 @@
 
 use crate::{
     errors::DPCError,
-    testnet1::{payload::Payload, record::Record, BaseDPCComponents},
+    testnet1::{payload::Payload, record::Record, record_plaintext::read_record_value, AleoAmount, BaseDPCComponents},
     traits::{DPCComponents, RecordEncodingScheme, RecordScheme},
 };
 use snarkvm_algorithms::{
@@ -55,7 +55,7 @@ pub fn decode_from_group<P: MontgomeryModelParameters + TEModelParameters, G: Gr
 }
 
 pub struct DecodedRecord<C: BaseDPCComponents> {
-    pub value: u64,
+    pub value: AleoAmount,
     pub payload: Payload,
     pub birth_program_id: Vec<u8>,
     pub death_program_id: Vec<u8>,
@@ -273,12 +273,22 @@ impl<C: BaseDPCComponents, P: MontgomeryModelParameters + TEModelParameters, G:
     /// Decode and return the record components
     fn decode(encoded_record: Vec<Self::Group>, final_sign_high: bool) -> Result<Self::DecodedRecord, DPCError> {
         let remainder_size = Self::OUTER_FIELD_BITSIZE - Self::DATA_ELEMENT_BITSIZE;
+        let value_size = std::mem::size_of_val(&<Self::Record as RecordScheme>::Value::default()) * 8;
+
+        // The record has five fixed elements and a final element, and the final element holds a
+        // leading 1 bit, an fq_high bit for every other element, and the value.
+        if encoded_record.len() < 6 {
+            return Err(DPCError::InvalidRecordEncoding);
+        }
 
         // Extract the fq_bits
         let final_element = &encoded_record[encoded_record.len() - 1];
         let final_element_bytes =
             decode_from_group::<Self::Parameters, Self::Group>(final_element.into_affine(), final_sign_high)?;
         let final_element_bits = bytes_to_bits(&final_element_bytes).collect::<Vec<_>>();
+        if final_element_bits.len() < encoded_record.len() + value_size || !final_element_bits[0] {
+            return Err(DPCError::InvalidRecordEncoding);
+        }
 
         let fq_high_bits = &final_element_bits[1..encoded_record.len()];
 
@@ -339,9 +349,8 @@ impl<C: BaseDPCComponents, P: MontgomeryModelParameters + TEModelParameters, G:
         // Deserialize the value
 
         let value_start = encoded_record.len();
-        let value_end = value_start + (std::mem::size_of_val(&<Self::Record as RecordScheme>::Value::default()) * 8);
-        let value: <Self::Record as RecordScheme>::Value =
-            FromBytes::read(&bits_to_bytes(&final_element_bits[value_start..value_end])[..])?;
+        let value_end = value_start + value_size;
+        let value = read_record_value(&bits_to_bytes(&final_element_bits[value_start..value_end])[..])?;
 
         // Deserialize payload
 
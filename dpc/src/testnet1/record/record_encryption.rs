@@ -20,14 +20,15 @@ use crate::{
     testnet1::{
         parameters::SystemParameters,
         payload::Payload,
-        record::{encrypted_record::*, record_encoding::*, Record},
+        record::{encrypted_record::*, record_encoding::*, Record, RecordPlaintext, RecordSecrets},
+        AleoAmount,
         BaseDPCComponents,
     },
     traits::{DPCComponents, RecordEncodingScheme, RecordScheme},
 };
 use snarkvm_algorithms::{
     encoding::Elligator2,
-    traits::{CommitmentScheme, EncryptionScheme, CRH},
+    traits::{EncryptionScheme, CRH},
 };
 use snarkvm_curves::traits::{AffineCurve, ModelParameters, ProjectiveCurve};
 use snarkvm_fields::One;
@@ -66,7 +67,8 @@ impl<C: BaseDPCComponents> Default for RecordEncryptionGadgetComponents<C> {
         let base_field_default = <C::EncryptionModelParameters as ModelParameters>::BaseField::default();
 
         let record_field_elements = vec![base_field_one; record_encoding_length];
-        let record_group_encoding = vec![(base_field_default, base_field_default); record_encoding_length];
+        // The coordinates of the identity of the encryption group, which is a valid group encoding.
+        let record_group_encoding = vec![(base_field_default, base_field_one); record_encoding_length];
 
         let ciphertext_selectors = vec![false; record_encoding_length + 1];
         let fq_high_selectors = vec![false; record_encoding_length];
@@ -101,6 +103,20 @@ impl<C: BaseDPCComponents> RecordEncryption<C> {
         ),
         DPCError,
     > {
+        let encryption_randomness = system_parameters
+            .account_encryption
+            .generate_randomness(record.owner().into_repr(), rng)?;
+        let encrypted_record = Self::encrypt_record_with_randomness(system_parameters, record, &encryption_randomness)?;
+
+        Ok((encryption_randomness, encrypted_record))
+    }
+
+    /// Encrypt the given record with the given encryption randomness
+    pub fn encrypt_record_with_randomness(
+        system_parameters: &SystemParameters<C>,
+        record: &Record<C>,
+        encryption_randomness: &<<C as DPCComponents>::AccountEncryption as EncryptionScheme>::Randomness,
+    ) -> Result<EncryptedRecord<C>, DPCError> {
         // Serialize the record into group elements and fq_high bits
         let (serialized_record, final_fq_high_selector) =
             RecordEncoding::<C, C::EncryptionModelParameters, C::EncryptionGroup>::encode(record)?;
@@ -115,23 +131,17 @@ impl<C: BaseDPCComponents> RecordEncryption<C> {
         }
 
         // Encrypt the record plaintext
-        let record_public_key = record.owner().into_repr();
-        let encryption_randomness = system_parameters
-            .account_encryption
-            .generate_randomness(record_public_key, rng)?;
         let encrypted_record = C::AccountEncryption::encrypt(
             &system_parameters.account_encryption,
-            record_public_key,
-            &encryption_randomness,
+            record.owner().into_repr(),
+            encryption_randomness,
             &record_plaintexts,
         )?;
 
-        let encrypted_record = EncryptedRecord {
+        Ok(EncryptedRecord {
             encrypted_record,
             final_fq_high_selector,
-        };
-
-        Ok((encryption_randomness, encrypted_record))
+        })
     }
 
     /// Decrypt and reconstruct the encrypted record
@@ -140,27 +150,6 @@ impl<C: BaseDPCComponents> RecordEncryption<C> {
         account_view_key: &AccountViewKey<C>,
         encrypted_record: &EncryptedRecord<C>,
     ) -> Result<Record<C>, DPCError> {
-        // Decrypt the encrypted record
-        let plaintext_elements = C::AccountEncryption::decrypt(
-            &system_parameters.account_encryption,
-            &account_view_key.decryption_key,
-            &encrypted_record.encrypted_record,
-        )?;
-
-        let mut plaintext = Vec::with_capacity(plaintext_elements.len());
-        for element in plaintext_elements {
-            let plaintext_element = <C as BaseDPCComponents>::EncryptionGroup::read(&to_bytes![element]?[..])?;
-
-            plaintext.push(plaintext_element);
-        }
-
-        // Deserialize the plaintext record into record components
-        let record_components = RecordEncoding::<
-            C,
-            <C as BaseDPCComponents>::EncryptionModelParameters,
-            <C as BaseDPCComponents>::EncryptionGroup,
-        >::decode(plaintext, encrypted_record.final_fq_high_selector)?;
-
         let DecodedRecord {
             serial_number_nonce,
             commitment_randomness,
@@ -168,7 +157,7 @@ impl<C: BaseDPCComponents> RecordEncryption<C> {
             death_program_id,
             payload,
             value,
-        } = record_components;
+        } = Self::decrypt_record_components(system_parameters, account_view_key, encrypted_record)?;
 
         // Construct the record account address
 
@@ -179,40 +168,62 @@ impl<C: BaseDPCComponents> RecordEncryption<C> {
         // TODO (raychu86) Establish `is_dummy` flag properly by checking that the value is 0 and the programs are equivalent to a global dummy
         let dummy_program = birth_program_id.clone();
 
-        let is_dummy = (value == 0)
+        let is_dummy = (value == AleoAmount::ZERO)
             && (payload == Payload::default())
             && (death_program_id == dummy_program)
             && (birth_program_id == dummy_program);
 
         // Calculate record commitment
 
-        let commitment_input = to_bytes![
+        let plaintext = RecordPlaintext::new(
             owner,
             is_dummy,
             value,
             payload,
             birth_program_id,
             death_program_id,
-            serial_number_nonce
-        ]?;
+            serial_number_nonce,
+        );
 
-        let commitment = C::RecordCommitment::commit(
+        Record::from_parts(
             &system_parameters.record_commitment,
-            &commitment_input,
-            &commitment_randomness,
+            plaintext,
+            RecordSecrets::new(commitment_randomness),
+        )
+    }
+
+    /// Decrypts the encrypted record, and decodes the record components other than the owner.
+    /// Fails if the decrypted plaintext is not a valid record encoding, which is the case for
+    /// most encrypted records that are decrypted with the wrong account view key.
+    pub(crate) fn decrypt_record_components(
+        system_parameters: &SystemParameters<C>,
+        account_view_key: &AccountViewKey<C>,
+        encrypted_record: &EncryptedRecord<C>,
+    ) -> Result<DecodedRecord<C>, DPCError> {
+        if encrypted_record.encrypted_record.is_empty() {
+            return Err(DPCError::InvalidRecordEncoding);
+        }
+
+        // Decrypt the encrypted record
+        let plaintext_elements = C::AccountEncryption::decrypt(
+            &system_parameters.account_encryption,
+            &account_view_key.decryption_key,
+            &encrypted_record.encrypted_record,
         )?;
 
-        Ok(Record {
-            owner,
-            is_dummy,
-            value,
-            payload,
-            birth_program_id,
-            death_program_id,
-            serial_number_nonce,
-            commitment_randomness,
-            commitment,
-        })
+        let mut plaintext = Vec::with_capacity(plaintext_elements.len());
+        for element in plaintext_elements {
+            let plaintext_element = <C as BaseDPCComponents>::EncryptionGroup::read(&to_bytes![element]?[..])?;
+
+            plaintext.push(plaintext_element);
+        }
+
+        // Deserialize the plaintext record into record components
+        RecordEncoding::<
+            C,
+            <C as BaseDPCComponents>::EncryptionModelParameters,
+            <C as BaseDPCComponents>::EncryptionGroup,
+        >::decode(plaintext, encrypted_record.final_fq_high_selector)
     }
 
     /// Returns the encrypted record hash
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::RecordError,
+    testnet1::{BaseDPCComponents, Record},
+};
+
+/// The prefix of records in format 1 and later, followed by the format number.
+///
+/// Format 0 records start with the owner address, written as the x-coordinate of the account
+/// encryption key. The x-coordinate is a canonical element of a field of at most 255 bits, so the
+/// last byte of its little-endian encoding has its top bit clear. Every byte of the prefix has its
+/// top bit set, so a format 0 record never starts with it.
+pub const RECORD_FORMAT_SENTINEL: [u8; 32] = [0xff; 32];
+
+/// The serialization formats of records.
+///
+/// Both formats encode the same record, and the record commitment is computed over
+/// `RecordPlaintext::to_commitment_input`, which does not depend on the format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecordFormat {
+    /// The original format, with each program id prefixed by its variable-length size.
+    /// Records are written in this format by `ToBytes`.
+    V0,
+    /// `RECORD_FORMAT_SENTINEL` and the format number, then the record with program ids at the
+    /// fixed width of the program verification key CRH output, then a section of extension entries.
+    ///
+    /// The extension section is the variable-length number of entries, followed by each entry as its
+    /// variable-length tag, its variable-length size, and its bytes. Readers skip unknown entries.
+    V1,
+}
+
+impl RecordFormat {
+    /// Returns the format number. Format 1 and later write it after `RECORD_FORMAT_SENTINEL`.
+    pub fn number(&self) -> u8 {
+        match self {
+            Self::V0 => 0,
+            Self::V1 => 1,
+        }
+    }
+}
+
+/// Rewrites the given record bytes in format 1, without changing the record commitment.
+/// Records already in format 1 are returned unchanged.
+pub fn migrate_record_v0_to_v1<C: BaseDPCComponents>(record_bytes: &[u8]) -> Result<Vec<u8>, RecordError> {
+    let (record, format) = Record::<C>::read_with_format(record_bytes)?;
+    if format == RecordFormat::V1 {
+        return Ok(record_bytes.to_vec());
+    }
+
+    let mut bytes = vec![];
+    record.write_v1(&mut bytes)?;
+    Ok(bytes)
+}
@@ -0,0 +1,215 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+use crate::{
+    account::AccountAddress,
+    testnet1::{payload::Payload, AleoAmount, BaseDPCComponents},
+};
+use snarkvm_algorithms::traits::CRH;
+use snarkvm_utilities::{
+    bytes::{to_bytes_len, FromBytes, ToBytes},
+    error,
+    to_bytes,
+    variable_length_integer::*,
+};
+
+use std::io::{Read, Result as IoResult, Write};
+
+pub(crate) fn default_program_id<C: CRH>() -> Vec<u8> {
+    to_bytes![C::Output::default()].unwrap()
+}
+
+/// Reads a record value, which is never negative.
+pub(crate) fn read_record_value<R: Read>(reader: R) -> IoResult<AleoAmount> {
+    let value: AleoAmount = FromBytes::read(reader)?;
+    match value.is_negative() {
+        true => Err(error("negative record value")),
+        false => Ok(value),
+    }
+}
+
+/// The contents of a record that are visible to its owner, without the commitment randomness.
+///
+/// Code that only handles record plaintexts cannot reach the commitment randomness.
+///
+/// ```compile_fail
+/// use snarkvm_dpc::testnet1::{instantiated::Components, RecordPlaintext};
+///
+/// fn leak(plaintext: &RecordPlaintext<Components>) {
+///     let _randomness = plaintext.commitment_randomness();
+/// }
+/// ```
+#[derive(Derivative)]
+#[derivative(
+    Default(bound = "C: BaseDPCComponents"),
+    Debug(bound = "C: BaseDPCComponents"),
+    Clone(bound = "C: BaseDPCComponents"),
+    PartialEq(bound = "C: BaseDPCComponents"),
+    Eq(bound = "C: BaseDPCComponents")
+)]
+pub struct RecordPlaintext<C: BaseDPCComponents> {
+    pub(crate) owner: AccountAddress<C>,
+    pub(crate) is_dummy: bool,
+    pub(crate) value: AleoAmount,
+    pub(crate) payload: Payload,
+
+    #[derivative(Default(value = "default_program_id::<C::ProgramVerificationKeyCRH>()"))]
+    pub(crate) birth_program_id: Vec<u8>,
+    #[derivative(Default(value = "default_program_id::<C::ProgramVerificationKeyCRH>()"))]
+    pub(crate) death_program_id: Vec<u8>,
+
+    pub(crate) serial_number_nonce: <C::SerialNumberNonceCRH as CRH>::Output,
+}
+
+impl<C: BaseDPCComponents> RecordPlaintext<C> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        owner: AccountAddress<C>,
+        is_dummy: bool,
+        value: AleoAmount,
+        payload: Payload,
+        birth_program_id: Vec<u8>,
+        death_program_id: Vec<u8>,
+        serial_number_nonce: <C::SerialNumberNonceCRH as CRH>::Output,
+    ) -> Self {
+        Self {
+            owner,
+            is_dummy,
+            value,
+            payload,
+            birth_program_id,
+            death_program_id,
+            serial_number_nonce,
+        }
+    }
+
+    /// Returns the record owner.
+    pub fn owner(&self) -> &AccountAddress<C> {
+        &self.owner
+    }
+
+    /// Returns whether or not the record is dummy.
+    pub fn is_dummy(&self) -> bool {
+        self.is_dummy
+    }
+
+    /// Returns the record value.
+    pub fn value(&self) -> AleoAmount {
+        self.value
+    }
+
+    /// Returns the record payload.
+    pub fn payload(&self) -> &Payload {
+        &self.payload
+    }
+
+    /// Returns the birth program id of this record.
+    pub fn birth_program_id(&self) -> &[u8] {
+        &self.birth_program_id
+    }
+
+    /// Returns the death program id of this record.
+    pub fn death_program_id(&self) -> &[u8] {
+        &self.death_program_id
+    }
+
+    /// Returns the randomness used for the serial number.
+    pub fn serial_number_nonce(&self) -> &<C::SerialNumberNonceCRH as CRH>::Output {
+        &self.serial_number_nonce
+    }
+
+    /// Returns the number of bytes in the serialized record plaintext, without serializing it.
+    pub fn serialized_size(&self) -> usize {
+        to_bytes_len(&self.owner).unwrap()
+            + 1 // is_dummy
+            + 8 // value
+            + self.payload.size()
+            + variable_length_integer_size(self.birth_program_id.len() as u64)
+            + self.birth_program_id.len()
+            + variable_length_integer_size(self.death_program_id.len() as u64)
+            + self.death_program_id.len()
+            + to_bytes_len(&self.serial_number_nonce).unwrap()
+    }
+
+    /// Returns the bytes that are committed to in the record commitment.
+    pub fn to_commitment_input(&self) -> IoResult<Vec<u8>> {
+        // Total = 32 + 1 + 8 + 32 + 48 + 48 + 32 = 201 bytes
+        to_bytes![
+            self.owner,               // 256 bits = 32 bytes
+            self.is_dummy,            // 1 bit = 1 byte
+            self.value,               // 64 bits = 8 bytes
+            self.payload,             // 256 bits = 32 bytes
+            self.birth_program_id,    // 384 bits = 48 bytes
+            self.death_program_id,    // 384 bits = 48 bytes
+            self.serial_number_nonce  // 256 bits = 32 bytes
+        ]
+    }
+}
+
+impl<C: BaseDPCComponents> ToBytes for RecordPlaintext<C> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.owner.write(&mut writer)?;
+        self.is_dummy.write(&mut writer)?;
+        self.value.write(&mut writer)?;
+        self.payload.write(&mut writer)?;
+
+        variable_length_integer(self.birth_program_id.len() as u64).write(&mut writer)?;
+        self.birth_program_id.write(&mut writer)?;
+
+        variable_length_integer(self.death_program_id.len() as u64).write(&mut writer)?;
+        self.death_program_id.write(&mut writer)?;
+
+        self.serial_number_nonce.write(&mut writer)
+    }
+}
+
+impl<C: BaseDPCComponents> FromBytes for RecordPlaintext<C> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let owner: AccountAddress<C> = FromBytes::read(&mut reader)?;
+        let is_dummy: bool = FromBytes::read(&mut reader)?;
+        let value = read_record_value(&mut reader)?;
+        let payload: Payload = FromBytes::read(&mut reader)?;
+
+        let birth_program_id_size: usize = read_variable_length_integer(&mut reader)?;
+
+        let mut birth_program_id = Vec::with_capacity(birth_program_id_size);
+        for _ in 0..birth_program_id_size {
+            let byte: u8 = FromBytes::read(&mut reader)?;
+            birth_program_id.push(byte);
+        }
+
+        let death_program_id_size: usize = read_variable_length_integer(&mut reader)?;
+
+        let mut death_program_id = Vec::with_capacity(death_program_id_size);
+        for _ in 0..death_program_id_size {
+            let byte: u8 = FromBytes::read(&mut reader)?;
+            death_program_id.push(byte);
+        }
+
+        let serial_number_nonce: <C::SerialNumberNonceCRH as CRH>::Output = FromBytes::read(&mut reader)?;
+
+        Ok(Self {
+            owner,
+            is_dummy,
+            value,
+            payload,
+            birth_program_id,
+            death_program_id,
+            serial_number_nonce,
+        })
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+use crate::testnet1::BaseDPCComponents;
+use snarkvm_algorithms::traits::CommitmentScheme;
+use snarkvm_utilities::bytes::{FromBytes, ToBytes};
+
+use std::io::{Read, Result as IoResult, Write};
+
+/// The secret data of a record, which must only be shared with the record owner.
+#[derive(Derivative)]
+#[derivative(
+    Default(bound = "C: BaseDPCComponents"),
+    Debug(bound = "C: BaseDPCComponents"),
+    Clone(bound = "C: BaseDPCComponents"),
+    PartialEq(bound = "C: BaseDPCComponents"),
+    Eq(bound = "C: BaseDPCComponents")
+)]
+pub struct RecordSecrets<C: BaseDPCComponents> {
+    pub(crate) commitment_randomness: <C::RecordCommitment as CommitmentScheme>::Randomness,
+}
+
+impl<C: BaseDPCComponents> RecordSecrets<C> {
+    pub fn new(commitment_randomness: <C::RecordCommitment as CommitmentScheme>::Randomness) -> Self {
+        Self { commitment_randomness }
+    }
+
+    /// Returns the randomness used for the record commitment.
+    pub fn commitment_randomness(&self) -> &<C::RecordCommitment as CommitmentScheme>::Randomness {
+        &self.commitment_randomness
+    }
+}
+
+impl<C: BaseDPCComponents> ToBytes for RecordSecrets<C> {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.commitment_randomness.write(writer)
+    }
+}
+
+impl<C: BaseDPCComponents> FromBytes for RecordSecrets<C> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Self {
+            commitment_randomness: FromBytes::read(reader)?,
+        })
+    }
+}
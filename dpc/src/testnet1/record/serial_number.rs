@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::testnet1::BaseDPCComponents;
+use snarkvm_algorithms::traits::SignatureScheme;
+
+/// The serial number of a record, revealed when the record is spent.
+///
+/// Serial numbers and record commitments are distinct types, so one cannot be
+/// passed where the other is expected, even when their underlying types coincide.
+///
+/// ```
+/// use snarkvm_dpc::testnet1::{instantiated::Components, SerialNumber};
+///
+/// fn spend(_serial_number: &SerialNumber<Components>) {}
+///
+/// let serial_number = SerialNumber::<Components>::default();
+/// spend(&serial_number);
+/// ```
+///
+/// ```compile_fail,E0308
+/// use snarkvm_dpc::testnet1::{instantiated::Components, RecordCommitment, SerialNumber};
+///
+/// fn spend(_serial_number: &SerialNumber<Components>) {}
+///
+/// let commitment = RecordCommitment::<Components>::default();
+/// spend(&commitment);
+/// ```
+#[derive(Derivative)]
+#[derivative(Clone(bound = "C: BaseDPCComponents"), Default(bound = "C: BaseDPCComponents"))]
+pub struct SerialNumber<C: BaseDPCComponents>(<C::AccountSignature as SignatureScheme>::PublicKey);
+
+impl_record_wrapper!(SerialNumber, <C::AccountSignature as SignatureScheme>::PublicKey, "serial number");
@@ -14,18 +14,37 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use super::{record_encoding::*, record_encryption::*};
+use super::{
+    macros::ct_eq_serialized,
+    record_encoding::*,
+    record_encryption::*,
+    record_format::*,
+    Record,
+    RecordBuilder,
+    RecordCommitment as RecordCommitmentWrapper,
+    RecordPlaintext,
+    SerialNumber,
+};
 use crate::{
-    account::{Account, AccountViewKey},
-    testnet1::{instantiated::*, payload::Payload, DPC},
-    traits::{AccountScheme, RecordEncodingScheme},
+    account::{Account, AccountAddress, AccountViewKey},
+    errors::{DPCError, RecordError, TransactionError},
+    testnet1::{instantiated::*, payload::Payload, AleoAmount, DPC},
+    traits::{AccountScheme, RecordEncodingScheme, RecordScheme},
+};
+use snarkvm_algorithms::traits::{CommitmentScheme, SignatureScheme, CRH};
+use snarkvm_curves::edwards_bls12::{EdwardsParameters, EdwardsProjective as EdwardsBls, Fq as EdwardsFq};
+use snarkvm_fields::PrimeField;
+use snarkvm_parameters::{testnet1::NoopProgramSNARKVKParameters, Parameter};
+use snarkvm_utilities::{
+    bytes::{FromBytes, ToBytes},
+    rand::UniformRand,
+    to_bytes,
+    variable_length_integer::variable_length_integer,
 };
-use snarkvm_algorithms::traits::CRH;
-use snarkvm_curves::edwards_bls12::{EdwardsParameters, EdwardsProjective as EdwardsBls};
-use snarkvm_utilities::{bytes::ToBytes, to_bytes};
 
 use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
+use subtle::ConstantTimeEq;
 
 pub(crate) const ITERATIONS: usize = 5;
 
@@ -59,10 +78,10 @@ fn test_record_encoding() {
             .unwrap();
 
             let sn_nonce_input: [u8; 32] = rng.gen();
-            let value = rng.gen();
+            let value = AleoAmount(rng.gen_range(0..=i64::MAX));
             let payload: [u8; 32] = rng.gen();
 
-            let given_record = DPC::generate_record(
+            let given_record = DPC::generate_record_with_amount(
                 &system_parameters,
                 SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &sn_nonce_input).unwrap(),
                 dummy_account.address,
@@ -83,15 +102,24 @@ fn test_record_encoding() {
             )
             .unwrap();
 
-            assert_eq!(given_record.serial_number_nonce, record_components.serial_number_nonce);
             assert_eq!(
-                given_record.commitment_randomness,
+                *given_record.plaintext().serial_number_nonce(),
+                record_components.serial_number_nonce
+            );
+            assert_eq!(
+                *given_record.secrets().commitment_randomness(),
                 record_components.commitment_randomness
             );
-            assert_eq!(given_record.birth_program_id, record_components.birth_program_id);
-            assert_eq!(given_record.death_program_id, record_components.death_program_id);
-            assert_eq!(given_record.value, record_components.value);
-            assert_eq!(given_record.payload, record_components.payload);
+            assert_eq!(
+                given_record.plaintext().birth_program_id(),
+                record_components.birth_program_id
+            );
+            assert_eq!(
+                given_record.plaintext().death_program_id(),
+                record_components.death_program_id
+            );
+            assert_eq!(given_record.plaintext().value(), record_components.value);
+            assert_eq!(*given_record.plaintext().payload(), record_components.payload);
         }
     }
 }
@@ -126,10 +154,10 @@ fn test_record_encryption() {
             .unwrap();
 
             let sn_nonce_input: [u8; 32] = rng.gen();
-            let value = rng.gen();
+            let value = AleoAmount(rng.gen_range(0..=i64::MAX));
             let payload: [u8; 32] = rng.gen();
 
-            let given_record = DPC::generate_record(
+            let given_record = DPC::generate_record_with_amount(
                 &system_parameters,
                 SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &sn_nonce_input).unwrap(),
                 dummy_account.address,
@@ -160,3 +188,561 @@ fn test_record_encryption() {
         }
     }
 }
+
+#[test]
+fn test_encrypted_record_decrypt() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+    let program_id = InstantiatedDPC::compute_program_id(
+        &system_parameters.program_verification_key_crh,
+        &NoopProgramSNARKVKParameters::load_bytes().unwrap(),
+    )
+    .unwrap();
+
+    let new_view_key = |rng: &mut XorShiftRng| {
+        let account = Account::new(
+            &system_parameters.account_signature,
+            &system_parameters.account_commitment,
+            &system_parameters.account_encryption,
+            rng,
+        )
+        .unwrap();
+        let view_key = AccountViewKey::from_private_key(
+            &system_parameters.account_signature,
+            &system_parameters.account_commitment,
+            &account.private_key,
+        )
+        .unwrap();
+        (account.address, view_key)
+    };
+
+    let mut num_false_positives = 0;
+    for _ in 0..ITERATIONS {
+        let (owner, view_key) = new_view_key(&mut rng);
+        let (_, other_view_key) = new_view_key(&mut rng);
+
+        let sn_nonce_input: [u8; 32] = rng.gen();
+        let payload: [u8; 32] = rng.gen();
+        let record = DPC::generate_record_with_amount(
+            &system_parameters,
+            SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &sn_nonce_input).unwrap(),
+            owner,
+            false,
+            AleoAmount(rng.gen_range(0..=i64::MAX)),
+            Payload::from_bytes(&payload),
+            program_id.clone(),
+            program_id.clone(),
+            &mut rng,
+        )
+        .unwrap();
+        let (_, encrypted_record) = RecordEncryption::encrypt_record(&system_parameters, &record, &mut rng).unwrap();
+
+        // The owner recovers every field of the record.
+        assert!(encrypted_record.is_owner(&system_parameters, &view_key));
+        let decrypted_record = encrypted_record
+            .decrypt(&system_parameters, &view_key, &record.commitment())
+            .unwrap();
+        assert_eq!(decrypted_record, record);
+
+        // Another account cannot decrypt the record, and is rarely taken for its owner.
+        assert!(
+            encrypted_record
+                .decrypt(&system_parameters, &other_view_key, &record.commitment())
+                .is_err()
+        );
+        num_false_positives += encrypted_record.is_owner(&system_parameters, &other_view_key) as usize;
+        for _ in 0..10 {
+            let (_, other_view_key) = new_view_key(&mut rng);
+            num_false_positives += encrypted_record.is_owner(&system_parameters, &other_view_key) as usize;
+        }
+
+        // The record must open the expected commitment.
+        let other_commitment = RecordCommitmentWrapper::default();
+        assert!(matches!(
+            encrypted_record.decrypt(&system_parameters, &view_key, &other_commitment),
+            Err(DPCError::MismatchedRecordCommitment(..))
+        ));
+
+        // A tampered ciphertext does not decrypt.
+        let mut tampered = encrypted_record.clone();
+        tampered.final_fq_high_selector = !tampered.final_fq_high_selector;
+        assert!(
+            tampered
+                .decrypt(&system_parameters, &view_key, &record.commitment())
+                .is_err()
+        );
+        for i in 1..encrypted_record.encrypted_record.len() - 1 {
+            let mut tampered = encrypted_record.clone();
+            tampered.encrypted_record.swap(i, i + 1);
+            assert!(
+                tampered
+                    .decrypt(&system_parameters, &view_key, &record.commitment())
+                    .is_err()
+            );
+        }
+        let mut tampered = encrypted_record.clone();
+        tampered.encrypted_record.truncate(3);
+        assert!(matches!(
+            tampered.decrypt(&system_parameters, &view_key, &record.commitment()),
+            Err(DPCError::InvalidRecordEncoding)
+        ));
+        tampered.encrypted_record.clear();
+        assert!(!tampered.is_owner(&system_parameters, &view_key));
+    }
+    assert_eq!(num_false_positives, 0);
+}
+
+#[test]
+fn test_serial_number_and_record_commitment_wrappers() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+
+    for _ in 0..ITERATIONS {
+        // Serial numbers serialize exactly like the underlying randomized public key.
+        let private_key = system_parameters
+            .account_signature
+            .generate_private_key(&mut rng)
+            .unwrap();
+        let public_key = system_parameters
+            .account_signature
+            .generate_public_key(&private_key)
+            .unwrap();
+        let serial_number = SerialNumber::<Components>::new(public_key);
+
+        let serial_number_bytes = to_bytes![serial_number].unwrap();
+        assert_eq!(serial_number_bytes, to_bytes![public_key].unwrap());
+        assert_eq!(serial_number.to_string(), hex::encode(&serial_number_bytes));
+
+        let recovered_serial_number = SerialNumber::<Components>::read(&serial_number_bytes[..]).unwrap();
+        assert_eq!(serial_number, recovered_serial_number);
+        assert!(bool::from(serial_number.ct_eq(&recovered_serial_number)));
+        assert_eq!(recovered_serial_number.into_inner(), public_key);
+
+        // Record commitments serialize exactly like the underlying commitment output.
+        let input: [u8; 32] = rng.gen();
+        let randomness = UniformRand::rand(&mut rng);
+        let output = system_parameters.record_commitment.commit(&input, &randomness).unwrap();
+        let commitment = RecordCommitmentWrapper::<Components>::new(output);
+
+        let commitment_bytes = to_bytes![commitment].unwrap();
+        assert_eq!(commitment_bytes, to_bytes![output].unwrap());
+        assert_eq!(commitment.to_string(), hex::encode(&commitment_bytes));
+
+        let recovered_commitment = RecordCommitmentWrapper::<Components>::read(&commitment_bytes[..]).unwrap();
+        assert_eq!(commitment, recovered_commitment);
+        assert!(bool::from(commitment.ct_eq(&recovered_commitment)));
+        assert_eq!(recovered_commitment.into_inner(), output);
+
+        // Distinct values compare unequal.
+        let other_input: [u8; 32] = rng.gen();
+        let other_output = system_parameters
+            .record_commitment
+            .commit(&other_input, &randomness)
+            .unwrap();
+        let other_commitment = RecordCommitmentWrapper::<Components>::new(other_output);
+        assert_ne!(commitment, other_commitment);
+        assert!(!bool::from(commitment.ct_eq(&other_commitment)));
+    }
+}
+
+#[test]
+fn test_wrapper_comparison_without_allocation() {
+    // Values that differ only in their serialized length compare unequal.
+    assert!(bool::from(ct_eq_serialized(&vec![0u8; 32], &vec![0u8; 32])));
+    assert!(!bool::from(ct_eq_serialized(&vec![0u8; 31], &vec![0u8; 32])));
+
+    // Values that do not fit in the comparison buffers compare unequal instead of panicking.
+    assert!(!bool::from(ct_eq_serialized(&vec![0u8; 2048], &vec![0u8; 2048])));
+}
+
+#[test]
+fn test_record_split_and_serialization() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+
+    for _ in 0..ITERATIONS {
+        let account = Account::new(
+            &system_parameters.account_signature,
+            &system_parameters.account_commitment,
+            &system_parameters.account_encryption,
+            &mut rng,
+        )
+        .unwrap();
+
+        let sn_nonce_input: [u8; 32] = rng.gen();
+        let payload: [u8; 32] = rng.gen();
+        let program_id: Vec<u8> = (0..48).map(|_| rng.gen()).collect();
+
+        let record = DPC::generate_record_with_amount(
+            &system_parameters,
+            SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &sn_nonce_input).unwrap(),
+            account.address,
+            false,
+            AleoAmount(rng.gen_range(0..=i64::MAX)),
+            Payload::from_bytes(&payload),
+            program_id.clone(),
+            program_id.clone(),
+            &mut rng,
+        )
+        .unwrap();
+
+        // The record serializes exactly as it did before it was split into parts.
+        let plaintext = record.plaintext();
+        let expected_bytes = to_bytes![
+            plaintext.owner(),
+            plaintext.is_dummy(),
+            plaintext.value(),
+            plaintext.payload(),
+            variable_length_integer(program_id.len() as u64),
+            plaintext.birth_program_id(),
+            variable_length_integer(program_id.len() as u64),
+            plaintext.death_program_id(),
+            plaintext.serial_number_nonce(),
+            record.commitment(),
+            record.secrets().commitment_randomness()
+        ]
+        .unwrap();
+
+        let record_bytes = to_bytes![record].unwrap();
+        assert_eq!(record_bytes, expected_bytes);
+        assert_eq!(record.to_bytes_with_secrets().unwrap(), expected_bytes);
+        assert_eq!(record.to_string(), hex::encode(&expected_bytes));
+        assert_eq!(Record::<Components>::read(&expected_bytes[..]).unwrap(), record);
+
+        // The plaintext serialization is a prefix of the record that leaves out the commitment and its randomness.
+        let plaintext_bytes = to_bytes![plaintext].unwrap();
+        assert_eq!(plaintext_bytes[..], record_bytes[..plaintext_bytes.len()]);
+        assert_eq!(
+            &RecordPlaintext::<Components>::read(&plaintext_bytes[..]).unwrap(),
+            plaintext
+        );
+
+        // Recombining the parts recomputes the same commitment.
+        let (plaintext, secrets) = record.clone().split();
+        let recombined = Record::from_parts(&system_parameters.record_commitment, plaintext, secrets).unwrap();
+        assert_eq!(recombined, record);
+    }
+}
+
+#[test]
+fn test_record_serialized_size() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+
+    let account = Account::new(
+        &system_parameters.account_signature,
+        &system_parameters.account_commitment,
+        &system_parameters.account_encryption,
+        &mut rng,
+    )
+    .unwrap();
+
+    let sn_nonce_input: [u8; 32] = rng.gen();
+    let payload: [u8; 32] = rng.gen();
+    let program_id: Vec<u8> = (0..48).map(|_| rng.gen()).collect();
+
+    let record = DPC::generate_record_with_amount(
+        &system_parameters,
+        SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &sn_nonce_input).unwrap(),
+        account.address,
+        false,
+        AleoAmount(rng.gen_range(0..=i64::MAX)),
+        Payload::from_bytes(&payload),
+        program_id.clone(),
+        program_id,
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(record.serialized_size(), to_bytes![record].unwrap().len());
+
+    // The size accounts for the width of the program id lengths, up to the largest program ids.
+    for birth_program_id_size in &[0, 1, 252, 253, 65_535, 65_536] {
+        for death_program_id_size in &[0, 48, 70_000] {
+            let mut record = record.clone();
+            record.plaintext.birth_program_id = (0..*birth_program_id_size).map(|_| rng.gen()).collect();
+            record.plaintext.death_program_id = (0..*death_program_id_size).map(|_| rng.gen()).collect();
+
+            let plaintext = record.plaintext();
+            assert_eq!(plaintext.serialized_size(), to_bytes![plaintext].unwrap().len());
+            assert_eq!(record.serialized_size(), to_bytes![record].unwrap().len());
+        }
+    }
+}
+
+#[test]
+fn test_record_builder() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+
+    let account = Account::new(
+        &system_parameters.account_signature,
+        &system_parameters.account_commitment,
+        &system_parameters.account_encryption,
+        &mut rng,
+    )
+    .unwrap();
+
+    let sn_nonce_input: [u8; 32] = rng.gen();
+    let sn_nonce = SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &sn_nonce_input).unwrap();
+    let value = AleoAmount(rng.gen_range(0..=i64::MAX));
+    let payload = Payload::from_bytes(&rng.gen::<[u8; 32]>());
+    let program_id: Vec<u8> = (0..48).map(|_| rng.gen()).collect();
+
+    let builder = RecordBuilder::<Components>::new()
+        .owner(account.address.clone())
+        .amount(value)
+        .payload(payload.clone())
+        .birth_program_id(program_id.clone())
+        .death_program_id(program_id.clone())
+        .serial_number_nonce(sn_nonce.clone());
+
+    // The builder commits to the record exactly as the DPC does.
+    let expected_record = DPC::generate_record_with_amount(
+        &system_parameters,
+        sn_nonce,
+        account.address,
+        false,
+        value,
+        payload,
+        program_id.clone(),
+        program_id,
+        &mut rng.clone(),
+    )
+    .unwrap();
+    let record = builder
+        .clone()
+        .calculate_commitment(&system_parameters.record_commitment, &mut rng)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(record, expected_record);
+    assert!(record.verify_commitment(&system_parameters.record_commitment));
+
+    // A record is not built before its commitment is calculated, or with a missing field.
+    assert!(matches!(builder.clone().build(), Err(RecordError::InvalidCommitment)));
+    assert!(matches!(
+        RecordBuilder::<Components>::new()
+            .amount(AleoAmount::ZERO)
+            .calculate_commitment(&system_parameters.record_commitment, &mut rng),
+        Err(RecordError::MissingField(field)) if field == "owner"
+    ));
+
+    // Setting a field after calculating the commitment discards it.
+    let stale = builder
+        .clone()
+        .calculate_commitment(&system_parameters.record_commitment, &mut rng)
+        .unwrap()
+        .amount(AleoAmount::ZERO);
+    assert!(matches!(stale.build(), Err(RecordError::InvalidCommitment)));
+
+    // A dummy record must have no value.
+    let dummy = builder.clone().is_dummy(true);
+    assert!(matches!(
+        dummy
+            .clone()
+            .calculate_commitment(&system_parameters.record_commitment, &mut rng),
+        Err(RecordError::DummyMustBeZero(v)) if v == value.0
+    ));
+    let dummy_record = dummy
+        .amount(AleoAmount::ZERO)
+        .calculate_commitment(&system_parameters.record_commitment, &mut rng)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert!(dummy_record.is_dummy());
+    assert!(dummy_record.verify_commitment(&system_parameters.record_commitment));
+
+    // A record value must be a non-negative amount.
+    assert!(matches!(
+        builder
+            .clone()
+            .is_dummy(false)
+            .amount(AleoAmount(-1))
+            .calculate_commitment(&system_parameters.record_commitment, &mut rng),
+        Err(RecordError::NegativeValue(-1))
+    ));
+    #[allow(deprecated)]
+    let out_of_range = builder.clone().is_dummy(false).value(u64::MAX);
+    assert!(matches!(
+        out_of_range.calculate_commitment(&system_parameters.record_commitment, &mut rng),
+        Err(RecordError::ValueOutOfRange(u64::MAX))
+    ));
+    assert!(matches!(
+        DPC::generate_record_with_amount(
+            &system_parameters,
+            Default::default(),
+            Default::default(),
+            false,
+            AleoAmount(-1),
+            Default::default(),
+            vec![0u8; 48],
+            vec![0u8; 48],
+            &mut rng,
+        ),
+        Err(DPCError::TransactionError(TransactionError::NegativeRecordValue(-1)))
+    ));
+
+    // A record whose payload is tampered with after serialization no longer opens its commitment.
+    let mut record_bytes = to_bytes![record].unwrap();
+    let payload_offset = to_bytes![record.owner(), record.is_dummy(), record.value()]
+        .unwrap()
+        .len();
+    record_bytes[payload_offset] ^= 1;
+    let tampered = Record::<Components>::read(&record_bytes[..]).unwrap();
+    assert_ne!(tampered.payload(), record.payload());
+    assert!(!tampered.verify_commitment(&system_parameters.record_commitment));
+}
+
+#[test]
+fn test_record_formats() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+
+    // Format 0 records start with the owner address, a canonical element of a field of at most 255 bits,
+    // so they never start with the sentinel, whose last byte has its top bit set.
+    assert!(EdwardsFq::size_in_bits() <= 255);
+    assert_eq!(RECORD_FORMAT_SENTINEL.len(), 32);
+    assert!(RECORD_FORMAT_SENTINEL[31] & 0x80 != 0);
+    assert!(AccountAddress::<Components>::read(&RECORD_FORMAT_SENTINEL[..]).is_err());
+
+    for _ in 0..ITERATIONS {
+        let account = Account::new(
+            &system_parameters.account_signature,
+            &system_parameters.account_commitment,
+            &system_parameters.account_encryption,
+            &mut rng,
+        )
+        .unwrap();
+
+        let sn_nonce_input: [u8; 32] = rng.gen();
+        let payload: [u8; 32] = rng.gen();
+        let program_id: Vec<u8> = (0..48).map(|_| rng.gen()).collect();
+
+        let record = DPC::generate_record_with_amount(
+            &system_parameters,
+            SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &sn_nonce_input).unwrap(),
+            account.address,
+            false,
+            AleoAmount(rng.gen_range(0..=i64::MAX)),
+            Payload::from_bytes(&payload),
+            program_id.clone(),
+            program_id,
+            &mut rng,
+        )
+        .unwrap();
+
+        let v0_bytes = to_bytes![record].unwrap();
+        let mut v1_bytes = vec![];
+        record.write_v1(&mut v1_bytes).unwrap();
+        assert_eq!(v1_bytes[..32], RECORD_FORMAT_SENTINEL);
+        assert_eq!(v1_bytes[32], RecordFormat::V1.number());
+        assert_eq!(v0_bytes[31] & 0x80, 0);
+
+        // Both formats round trip, and are detected when reading.
+        for (bytes, format) in [(&v0_bytes, RecordFormat::V0), (&v1_bytes, RecordFormat::V1)].iter() {
+            let mut written = vec![];
+            record.write_with_format(*format, &mut written).unwrap();
+            assert_eq!(&&written, bytes);
+
+            assert_eq!(
+                Record::<Components>::read_with_format(&bytes[..]).unwrap(),
+                (record.clone(), *format)
+            );
+            assert_eq!(Record::<Components>::read(&bytes[..]).unwrap(), record);
+        }
+
+        // Migrating a record keeps its commitment, which opens in either format.
+        let migrated_bytes = migrate_record_v0_to_v1::<Components>(&v0_bytes).unwrap();
+        assert_eq!(migrated_bytes, v1_bytes);
+        assert_eq!(migrate_record_v0_to_v1::<Components>(&v1_bytes).unwrap(), v1_bytes);
+
+        let migrated = Record::<Components>::read(&migrated_bytes[..]).unwrap();
+        assert_eq!(migrated.commitment(), record.commitment());
+        assert_eq!(
+            migrated.plaintext().to_commitment_input().unwrap(),
+            record.plaintext().to_commitment_input().unwrap()
+        );
+        assert!(migrated.verify_commitment(&system_parameters.record_commitment));
+
+        // Extension entries that are not known yet are skipped.
+        let mut extended_bytes = v1_bytes[..v1_bytes.len() - 1].to_vec();
+        extended_bytes.extend_from_slice(&[2, 7, 3, 1, 2, 3, 8, 0]);
+        assert_eq!(Record::<Components>::read(&extended_bytes[..]).unwrap(), record);
+
+        // Unknown formats are rejected.
+        let mut unknown_bytes = v1_bytes.clone();
+        unknown_bytes[32] = 2;
+        assert!(Record::<Components>::read(&unknown_bytes[..]).is_err());
+    }
+
+    // Format 1 only holds program ids of the size of the program verification key CRH output.
+    let record = DPC::generate_record_with_amount(
+        &system_parameters,
+        Default::default(),
+        Default::default(),
+        true,
+        AleoAmount::ZERO,
+        Default::default(),
+        vec![0u8; 47],
+        vec![0u8; 48],
+        &mut rng,
+    )
+    .unwrap();
+    assert!(record.write_v1(&mut vec![]).is_err());
+    let v0_bytes = to_bytes![record].unwrap();
+    assert!(migrate_record_v0_to_v1::<Components>(&v0_bytes).is_err());
+}
+
+#[test]
+fn test_record_serde() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = InstantiatedDPC::generate_system_parameters(&mut rng).unwrap();
+    let account = Account::new(
+        &system_parameters.account_signature,
+        &system_parameters.account_commitment,
+        &system_parameters.account_encryption,
+        &mut rng,
+    )
+    .unwrap();
+
+    let sn_nonce_input: [u8; 32] = rng.gen();
+    let payload: [u8; 32] = rng.gen();
+    let program_id: Vec<u8> = (0..48).map(|_| rng.gen()).collect();
+
+    let record = DPC::generate_record_with_amount(
+        &system_parameters,
+        SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &sn_nonce_input).unwrap(),
+        account.address,
+        false,
+        AleoAmount(rng.gen_range(0..=i64::MAX)),
+        Payload::from_bytes(&payload),
+        program_id.clone(),
+        program_id,
+        &mut rng,
+    )
+    .unwrap();
+
+    // Human-readable formats use the same hex string as `Display`.
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(json, format!("\"{}\"", record));
+    assert_eq!(serde_json::from_str::<Record<Components>>(&json).unwrap(), record);
+
+    // Binary formats use the raw bytes.
+    let bytes = bincode::serialize(&record).unwrap();
+    assert_eq!(bytes[8..], to_bytes![record].unwrap()[..]);
+    assert_eq!(bincode::deserialize::<Record<Components>>(&bytes).unwrap(), record);
+
+    // Malformed encodings are rejected.
+    let hex = record.to_string();
+    assert!(serde_json::from_str::<Record<Components>>("\"not hex\"").is_err());
+    assert!(serde_json::from_str::<Record<Components>>(&format!("\"{}\"", &hex[1..])).is_err());
+    assert!(serde_json::from_str::<Record<Components>>(&format!("\"{}\"", &hex[..hex.len() - 2])).is_err());
+    assert!(serde_json::from_str::<Record<Components>>(&format!("\"{}00\"", hex)).is_err());
+}
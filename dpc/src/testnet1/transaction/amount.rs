@@ -17,12 +17,14 @@
 use snarkvm_utilities::bytes::{FromBytes, ToBytes};
 
 use std::{
+    convert::TryFrom,
     fmt,
     io::{Read, Result as IoResult, Write},
+    num::TryFromIntError,
 };
 
 /// Represents the amount of ALEOs in UNITS
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct AleoAmount(pub i64);
 
 pub enum Denomination {
@@ -58,6 +60,10 @@ impl fmt::Display for Denomination {
 impl AleoAmount {
     /// Number of AB (base unit) per ALEO
     pub const COIN: i64 = 1_000_000;
+    /// Number of AB (base unit) per AG
+    pub const GATE: i64 = 1_000;
+    /// The maximum number of AB that can be in circulation.
+    pub const MAX_SUPPLY: AleoAmount = AleoAmount(1_000_000_000 * Self::COIN);
     /// Exactly one ALEO.
     pub const ONE_ALEO: AleoAmount = AleoAmount(Self::COIN);
     /// Exactly one byte.
@@ -84,18 +90,40 @@ impl AleoAmount {
         Self::from_bytes(bytes)
     }
 
+    /// Create an `AleoAmount` given a number of gates, returning `None` on overflow
+    pub fn checked_from_gates(gate_value: i64) -> Option<Self> {
+        gate_value.checked_mul(Self::GATE).map(Self::from_bytes)
+    }
+
+    /// Create an `AleoAmount` given a number of ALEOs, returning `None` on overflow
+    pub fn checked_from_aleo(aleo_value: i64) -> Option<Self> {
+        aleo_value.checked_mul(Self::COIN).map(Self::from_bytes)
+    }
+
     /// Add the values of two `AleoAmount`s
+    #[deprecated(note = "the sum may overflow, use `checked_add` instead")]
     #[allow(clippy::should_implement_trait)]
     pub fn add(self, b: Self) -> Self {
         Self::from_bytes(self.0 + b.0)
     }
 
     /// Subtract the value of two `AleoAmounts`
+    #[deprecated(note = "the difference may overflow, use `checked_sub` instead")]
     #[allow(clippy::should_implement_trait)]
     pub fn sub(self, b: AleoAmount) -> Self {
         Self::from_bytes(self.0 - b.0)
     }
 
+    /// Add the values of two `AleoAmount`s, returning `None` on overflow
+    pub fn checked_add(self, b: Self) -> Option<Self> {
+        self.0.checked_add(b.0).map(Self::from_bytes)
+    }
+
+    /// Subtract the value of two `AleoAmount`s, returning `None` on overflow
+    pub fn checked_sub(self, b: Self) -> Option<Self> {
+        self.0.checked_sub(b.0).map(Self::from_bytes)
+    }
+
     /// Returns `true` the amount is positive and `false` if the amount is zero or
     /// negative.
     pub const fn is_positive(self) -> bool {
@@ -107,6 +135,29 @@ impl AleoAmount {
     pub const fn is_negative(self) -> bool {
         self.0.is_negative()
     }
+
+    /// Returns `true` if the magnitude of the amount does not exceed the maximum supply.
+    pub const fn is_within_supply(self) -> bool {
+        self.0 >= -Self::MAX_SUPPLY.0 && self.0 <= Self::MAX_SUPPLY.0
+    }
+}
+
+/// Converts a `u64` value, such as a record value from before records held an `AleoAmount`.
+impl TryFrom<u64> for AleoAmount {
+    type Error = TryFromIntError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Ok(Self(i64::try_from(value)?))
+    }
+}
+
+/// Converts a non-negative amount into a `u64` value.
+impl TryFrom<AleoAmount> for u64 {
+    type Error = TryFromIntError;
+
+    fn try_from(amount: AleoAmount) -> Result<Self, Self::Error> {
+        u64::try_from(amount.0)
+    }
 }
 
 impl ToBytes for AleoAmount {
@@ -149,12 +200,54 @@ mod tests {
         assert_eq!(expected_amount, amount)
     }
 
+    #[test]
+    fn test_checked_arithmetic_boundaries() {
+        let max = AleoAmount(i64::MAX);
+        let min = AleoAmount(i64::MIN);
+        let one = AleoAmount::ONE_BYTE;
+
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(max.checked_sub(AleoAmount(-1)), None);
+        assert_eq!(min.checked_sub(one), None);
+        assert_eq!(min.checked_add(AleoAmount(-1)), None);
+        assert_eq!(AleoAmount::ZERO.checked_sub(min), None);
+
+        assert_eq!(max.checked_sub(one), Some(AleoAmount(i64::MAX - 1)));
+        assert_eq!(max.checked_add(min), Some(AleoAmount(-1)));
+        assert_eq!(AleoAmount::ZERO.checked_sub(max), Some(AleoAmount(-i64::MAX)));
+        assert_eq!(AleoAmount(-i64::MAX).checked_sub(one), Some(min));
+
+        assert_eq!(
+            AleoAmount::checked_from_gates(i64::MAX / AleoAmount::GATE),
+            Some(AleoAmount(i64::MAX / AleoAmount::GATE * AleoAmount::GATE))
+        );
+        assert_eq!(AleoAmount::checked_from_gates(i64::MAX / AleoAmount::GATE + 1), None);
+        assert_eq!(AleoAmount::checked_from_aleo(i64::MAX / AleoAmount::COIN + 1), None);
+        assert_eq!(
+            AleoAmount::checked_from_aleo(-2),
+            Some(AleoAmount(-2 * AleoAmount::COIN))
+        );
+    }
+
+    #[test]
+    fn test_u64_conversions() {
+        assert_eq!(AleoAmount::try_from(0u64).unwrap(), AleoAmount::ZERO);
+        assert_eq!(AleoAmount::try_from(i64::MAX as u64).unwrap(), AleoAmount(i64::MAX));
+        assert!(AleoAmount::try_from(i64::MAX as u64 + 1).is_err());
+        assert!(AleoAmount::try_from(u64::MAX).is_err());
+
+        assert_eq!(u64::try_from(AleoAmount(i64::MAX)).unwrap(), i64::MAX as u64);
+        assert_eq!(u64::try_from(AleoAmount::ZERO).unwrap(), 0);
+        assert!(u64::try_from(AleoAmount(-1)).is_err());
+        assert!(u64::try_from(AleoAmount(i64::MIN)).is_err());
+    }
+
     fn test_addition(a: &i64, b: &i64, result: &i64) {
         let a = AleoAmount::from_bytes(*a);
         let b = AleoAmount::from_bytes(*b);
         let result = AleoAmount::from_bytes(*result);
 
-        assert_eq!(result, a.add(b));
+        assert_eq!(Some(result), a.checked_add(b));
     }
 
     fn test_subtraction(a: &i64, b: &i64, result: &i64) {
@@ -162,7 +255,7 @@ mod tests {
         let b = AleoAmount::from_bytes(*b);
         let result = AleoAmount::from_bytes(*result);
 
-        assert_eq!(result, a.sub(b));
+        assert_eq!(Some(result), a.checked_sub(b));
     }
 
     pub(crate) struct AmountDenominationTestCase {
@@ -19,3 +19,6 @@ pub use amount::*;
 
 pub mod transaction;
 pub use transaction::*;
+
+#[cfg(test)]
+mod tests;
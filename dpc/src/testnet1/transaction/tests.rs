@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::TransactionError,
+    testnet1::{instantiated::*, AleoAmount},
+    traits::TransactionScheme,
+    Network,
+};
+use snarkvm_algorithms::snark::groth16::Proof;
+use snarkvm_curves::edwards_bls12::Fq as EdwardsFq;
+use snarkvm_fields::{FieldParameters, PrimeField};
+use snarkvm_parameters::{testnet1::Transaction1, Genesis};
+use snarkvm_utilities::{
+    biginteger::BigInteger,
+    bytes::{FromBytes, ToBytes},
+    to_bytes,
+};
+
+use std::{
+    io::ErrorKind,
+    time::{Duration, Instant},
+};
+
+type OuterProof = Proof<OuterPairing>;
+
+fn genesis_transaction() -> Tx {
+    Tx::read(&Transaction1::load_bytes()[..]).unwrap()
+}
+
+#[test]
+fn test_fast_checks_accepts_genesis_transaction() {
+    let transaction = genesis_transaction();
+    transaction.fast_checks(Network::Mainnet).unwrap();
+}
+
+#[test]
+fn test_fast_checks_is_fast() {
+    let transaction = genesis_transaction();
+
+    // The checks involve no pairings and no hashing, so they take far less than a proof verification.
+    let iterations = 100;
+    let start = Instant::now();
+    for _ in 0..iterations {
+        transaction.fast_checks(Network::Mainnet).unwrap();
+    }
+    assert!(start.elapsed() / iterations < Duration::from_millis(5));
+}
+
+#[test]
+fn test_fast_checks_rejects_network_mismatch() {
+    let transaction = genesis_transaction();
+    assert!(matches!(
+        transaction.fast_checks(Network::Testnet1),
+        Err(TransactionError::InvalidNetworkId(0, 1))
+    ));
+}
+
+#[test]
+fn test_fast_checks_rejects_wrong_counts() {
+    let mut transaction = genesis_transaction();
+    transaction.old_serial_numbers.pop();
+    assert!(matches!(
+        transaction.fast_checks(Network::Mainnet),
+        Err(TransactionError::InvalidNumberOfSerialNumbers(..))
+    ));
+
+    let mut transaction = genesis_transaction();
+    transaction.new_commitments.pop();
+    assert!(matches!(
+        transaction.fast_checks(Network::Mainnet),
+        Err(TransactionError::InvalidNumberOfCommitments(..))
+    ));
+
+    let mut transaction = genesis_transaction();
+    transaction.signatures.pop();
+    assert!(matches!(
+        transaction.fast_checks(Network::Mainnet),
+        Err(TransactionError::InvalidNumberOfSignatures(..))
+    ));
+
+    let mut transaction = genesis_transaction();
+    transaction.encrypted_records.pop();
+    assert!(matches!(
+        transaction.fast_checks(Network::Mainnet),
+        Err(TransactionError::InvalidNumberOfEncryptedRecords(..))
+    ));
+}
+
+#[test]
+fn test_fast_checks_rejects_duplicates() {
+    let mut transaction = genesis_transaction();
+    transaction.old_serial_numbers[1] = transaction.old_serial_numbers[0].clone();
+    assert!(matches!(
+        transaction.fast_checks(Network::Mainnet),
+        Err(TransactionError::DuplicateSerialNumbers)
+    ));
+
+    let mut transaction = genesis_transaction();
+    transaction.new_commitments[1] = transaction.new_commitments[0].clone();
+    assert!(matches!(
+        transaction.fast_checks(Network::Mainnet),
+        Err(TransactionError::DuplicateCommitments)
+    ));
+}
+
+#[test]
+fn test_fast_checks_rejects_value_balance_beyond_supply() {
+    let mut transaction = genesis_transaction();
+    transaction.value_balance = AleoAmount(AleoAmount::MAX_SUPPLY.0 + 1);
+    assert!(matches!(
+        transaction.fast_checks(Network::Mainnet),
+        Err(TransactionError::InvalidValueBalance(..))
+    ));
+
+    transaction.value_balance = AleoAmount(-AleoAmount::MAX_SUPPLY.0 - 1);
+    assert!(matches!(
+        transaction.fast_checks(Network::Mainnet),
+        Err(TransactionError::InvalidValueBalance(..))
+    ));
+
+    transaction.value_balance = AleoAmount(-AleoAmount::MAX_SUPPLY.0);
+    transaction.fast_checks(Network::Mainnet).unwrap();
+}
+
+#[test]
+fn test_fast_checks_rejects_default_ledger_digest() {
+    let mut transaction = genesis_transaction();
+    transaction.ledger_digest = Default::default();
+    assert!(matches!(
+        transaction.fast_checks(Network::Mainnet),
+        Err(TransactionError::InvalidLedgerDigest(..))
+    ));
+}
+
+#[test]
+fn test_fast_checks_accepts_both_proof_encodings() {
+    // The genesis proof is uncompressed, while the prover emits compressed proofs.
+    let mut transaction = genesis_transaction();
+    let mut compressed = vec![];
+    transaction.transaction_proof.write_compressed(&mut compressed).unwrap();
+    assert!(to_bytes![transaction.transaction_proof].unwrap().len() > compressed.len());
+    transaction.fast_checks(Network::Mainnet).unwrap();
+
+    transaction.transaction_proof = OuterProof::read_compressed(&compressed[..]).unwrap();
+    transaction.fast_checks(Network::Mainnet).unwrap();
+}
+
+#[test]
+fn test_read_rejects_non_canonical_commitment() {
+    let bytes = Transaction1::load_bytes();
+    let transaction = Tx::read(&bytes[..]).unwrap();
+
+    // Encode the first commitment as the same integer plus the modulus.
+    let commitment: EdwardsFq = *transaction.new_commitments[0].inner();
+    let canonical = to_bytes![commitment].unwrap();
+    let mut non_canonical = commitment.into_repr();
+    assert!(!non_canonical.add_nocarry(&<EdwardsFq as PrimeField>::Parameters::MODULUS));
+    let non_canonical = to_bytes![non_canonical].unwrap();
+    assert_eq!(EdwardsFq::from_bytes_unchecked_reduce(&non_canonical), commitment);
+
+    // Craft a near-duplicate of the transaction that differs only in that encoding.
+    let offset = bytes
+        .windows(canonical.len())
+        .position(|window| window == &canonical[..])
+        .unwrap();
+    let mut near_duplicate = bytes.clone();
+    near_duplicate[offset..offset + canonical.len()].copy_from_slice(&non_canonical);
+    assert_ne!(near_duplicate, bytes);
+
+    // The near-duplicate is rejected, so the transaction has a single id.
+    assert_eq!(
+        Tx::read(&near_duplicate[..]).unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+    assert_eq!(
+        Tx::read(&bytes[..]).unwrap().transaction_id().unwrap(),
+        transaction.transaction_id().unwrap()
+    );
+}
@@ -16,8 +16,16 @@
 
 use crate::{
     errors::TransactionError,
-    testnet1::{record::encrypted_record::*, AleoAmount, BaseDPCComponents},
+    testnet1::{
+        record::encrypted_record::*,
+        AleoAmount,
+        BaseDPCComponents,
+        InnerCircuitId,
+        RecordCommitment,
+        SerialNumber,
+    },
     traits::TransactionScheme,
+    Memo,
     Network,
 };
 use snarkvm_algorithms::{
@@ -25,7 +33,8 @@ use snarkvm_algorithms::{
     traits::{CommitmentScheme, SignatureScheme, CRH, SNARK},
 };
 use snarkvm_utilities::{
-    bytes::{FromBytes, ToBytes},
+    bytes::{to_bytes_len, FromBytes, ToBytes},
+    has_duplicates,
     serialize::{CanonicalDeserialize, CanonicalSerialize},
     to_bytes,
 };
@@ -36,6 +45,34 @@ use std::{
     io::{Read, Result as IoResult, Write},
 };
 
+/// The public view of a transaction kernel, consisting of the fields bound by the transaction signatures.
+pub struct TransactionKernelView<'a, C: BaseDPCComponents> {
+    pub network_id: u8,
+    pub ledger_digest: &'a MerkleTreeDigest<C::MerkleParameters>,
+    pub old_serial_numbers: &'a [SerialNumber<C>],
+    pub new_commitments: &'a [RecordCommitment<C>],
+    pub program_commitment: &'a <C::ProgramVerificationKeyCommitment as CommitmentScheme>::Output,
+    pub local_data_root: &'a <C::LocalDataCRH as CRH>::Output,
+    pub value_balance: AleoAmount,
+    pub memorandum: &'a Memo,
+}
+
+impl<C: BaseDPCComponents> TransactionKernelView<'_, C> {
+    /// Returns the message signed by the randomized signatures of the transaction.
+    pub fn signature_message(&self) -> Result<Vec<u8>, TransactionError> {
+        Ok(to_bytes![
+            self.network_id,
+            self.ledger_digest,
+            self.old_serial_numbers,
+            self.new_commitments,
+            self.program_commitment,
+            self.local_data_root,
+            self.value_balance,
+            self.memorandum
+        ]?)
+    }
+}
+
 #[derive(Derivative)]
 #[derivative(
     Clone(bound = "C: BaseDPCComponents"),
@@ -51,10 +88,10 @@ pub struct Transaction<C: BaseDPCComponents> {
     pub ledger_digest: MerkleTreeDigest<C::MerkleParameters>,
 
     /// The serial numbers of the records being spend
-    pub old_serial_numbers: Vec<<C::AccountSignature as SignatureScheme>::PublicKey>,
+    pub old_serial_numbers: Vec<SerialNumber<C>>,
 
     /// The commitment of the new records
-    pub new_commitments: Vec<<C::RecordCommitment as CommitmentScheme>::Output>,
+    pub new_commitments: Vec<RecordCommitment<C>>,
 
     #[derivative(PartialEq = "ignore")]
     /// The commitment to the old record death and new record birth programs
@@ -80,11 +117,12 @@ pub struct Transaction<C: BaseDPCComponents> {
     /// Zero-knowledge proof attesting to the valididty of the transaction
     pub transaction_proof: <C::OuterSNARK as SNARK>::Proof,
 
-    /// Public data associated with the transaction that must be unique among all transactions
-    pub memorandum: [u8; 32],
+    /// Public data associated with the transaction, which must be unique among all transactions
+    /// unless it is empty
+    pub memorandum: Memo,
 
     /// The ID of the inner SNARK being used
-    pub inner_circuit_id: <C::InnerCircuitIDCRH as CRH>::Output,
+    pub inner_circuit_id: InnerCircuitId<C>,
 }
 
 impl<C: BaseDPCComponents> Transaction<C> {
@@ -94,7 +132,7 @@ impl<C: BaseDPCComponents> Transaction<C> {
         new_commitments: Vec<<Self as TransactionScheme>::Commitment>,
         memorandum: <Self as TransactionScheme>::Memorandum,
         ledger_digest: MerkleTreeDigest<C::MerkleParameters>,
-        inner_circuit_id: <C::InnerCircuitIDCRH as CRH>::Output,
+        inner_circuit_id: InnerCircuitId<C>,
         transaction_proof: <C::OuterSNARK as SNARK>::Proof,
         program_commitment: <C::ProgramVerificationKeyCommitment as CommitmentScheme>::Output,
         local_data_root: <C::LocalDataCRH as CRH>::Output,
@@ -118,17 +156,94 @@ impl<C: BaseDPCComponents> Transaction<C> {
             encrypted_records,
         }
     }
+
+    /// Returns the public view of the transaction kernel.
+    pub fn kernel(&self) -> TransactionKernelView<'_, C> {
+        TransactionKernelView {
+            network_id: self.network.id(),
+            ledger_digest: &self.ledger_digest,
+            old_serial_numbers: &self.old_serial_numbers,
+            new_commitments: &self.new_commitments,
+            program_commitment: &self.program_commitment,
+            local_data_root: &self.local_data_root,
+            value_balance: self.value_balance,
+            memorandum: &self.memorandum,
+        }
+    }
+
+    /// Performs the structural checks on the transaction that do not require
+    /// the ledger, signature verification, or proof verification.
+    ///
+    /// These checks are cheap, and reject malformed transactions before they reach `DPC::verify`.
+    pub fn fast_checks(&self, network: Network) -> Result<(), TransactionError> {
+        if self.network != network {
+            return Err(TransactionError::InvalidNetworkId(self.network.id(), network.id()));
+        }
+
+        if self.old_serial_numbers.len() != C::NUM_INPUT_RECORDS {
+            return Err(TransactionError::InvalidNumberOfSerialNumbers(
+                self.old_serial_numbers.len(),
+                C::NUM_INPUT_RECORDS,
+            ));
+        }
+
+        if self.new_commitments.len() != C::NUM_OUTPUT_RECORDS {
+            return Err(TransactionError::InvalidNumberOfCommitments(
+                self.new_commitments.len(),
+                C::NUM_OUTPUT_RECORDS,
+            ));
+        }
+
+        if self.signatures.len() != C::NUM_INPUT_RECORDS {
+            return Err(TransactionError::InvalidNumberOfSignatures(
+                self.signatures.len(),
+                C::NUM_INPUT_RECORDS,
+            ));
+        }
+
+        if self.encrypted_records.len() != C::NUM_OUTPUT_RECORDS {
+            return Err(TransactionError::InvalidNumberOfEncryptedRecords(
+                self.encrypted_records.len(),
+                C::NUM_OUTPUT_RECORDS,
+            ));
+        }
+
+        if has_duplicates(self.old_serial_numbers.iter()) {
+            return Err(TransactionError::DuplicateSerialNumbers);
+        }
+
+        if has_duplicates(self.new_commitments.iter()) {
+            return Err(TransactionError::DuplicateCommitments);
+        }
+
+        if !self.value_balance.is_within_supply() {
+            return Err(TransactionError::InvalidValueBalance(self.value_balance.0));
+        }
+
+        if self.ledger_digest == MerkleTreeDigest::<C::MerkleParameters>::default() {
+            return Err(TransactionError::InvalidLedgerDigest(self.ledger_digest.to_string()));
+        }
+
+        // The proof must have the length of a proof encoding. The curve points are not decoded
+        // here, as that is left to the proof verification.
+        let proof_size = to_bytes_len(&self.transaction_proof)?;
+        if !C::OuterSNARK::is_valid_proof_size(proof_size) {
+            return Err(TransactionError::InvalidProofSize(proof_size));
+        }
+
+        Ok(())
+    }
 }
 
 impl<C: BaseDPCComponents> TransactionScheme for Transaction<C> {
-    type Commitment = <C::RecordCommitment as CommitmentScheme>::Output;
+    type Commitment = RecordCommitment<C>;
     type Digest = MerkleTreeDigest<C::MerkleParameters>;
     type EncryptedRecord = EncryptedRecord<C>;
-    type InnerCircuitID = <C::InnerCircuitIDCRH as CRH>::Output;
+    type InnerCircuitID = InnerCircuitId<C>;
     type LocalDataRoot = <C::LocalDataCRH as CRH>::Output;
-    type Memorandum = [u8; 32];
+    type Memorandum = Memo;
     type ProgramCommitment = <C::ProgramVerificationKeyCommitment as CommitmentScheme>::Output;
-    type SerialNumber = <C::AccountSignature as SignatureScheme>::PublicKey;
+    type SerialNumber = SerialNumber<C>;
     type ValueBalance = AleoAmount;
 
     /// Transaction id = Hash of (serial numbers || commitments || memo)
@@ -143,7 +258,7 @@ impl<C: BaseDPCComponents> TransactionScheme for Transaction<C> {
             pre_image_bytes.extend(&to_bytes![cm]?);
         }
 
-        pre_image_bytes.extend(self.memorandum());
+        pre_image_bytes.extend(self.memorandum().as_bytes());
 
         let mut h = b2s::new();
         h.update(&pre_image_bytes);
@@ -194,8 +309,7 @@ impl<C: BaseDPCComponents> TransactionScheme for Transaction<C> {
     }
 
     fn size(&self) -> usize {
-        let transaction_bytes = to_bytes![self].unwrap();
-        transaction_bytes.len()
+        to_bytes_len(self).unwrap()
     }
 }
 
@@ -203,7 +317,7 @@ impl<C: BaseDPCComponents> ToBytes for Transaction<C> {
     #[inline]
     fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
         for old_serial_number in &self.old_serial_numbers {
-            CanonicalSerialize::serialize(old_serial_number, &mut writer).unwrap();
+            CanonicalSerialize::serialize(old_serial_number.inner(), &mut writer).unwrap();
         }
 
         for new_commitment in &self.new_commitments {
@@ -243,21 +357,21 @@ impl<C: BaseDPCComponents> FromBytes for Transaction<C> {
             let old_serial_number: <C::AccountSignature as SignatureScheme>::PublicKey =
                 CanonicalDeserialize::deserialize(&mut reader).unwrap();
 
-            old_serial_numbers.push(old_serial_number);
+            old_serial_numbers.push(SerialNumber::new(old_serial_number));
         }
 
         // Read the new commitments
         let num_new_commitments = C::NUM_OUTPUT_RECORDS;
         let mut new_commitments = Vec::with_capacity(num_new_commitments);
         for _ in 0..num_new_commitments {
-            let new_commitment: <C::RecordCommitment as CommitmentScheme>::Output = FromBytes::read(&mut reader)?;
+            let new_commitment: RecordCommitment<C> = FromBytes::read(&mut reader)?;
             new_commitments.push(new_commitment);
         }
 
-        let memorandum: [u8; 32] = FromBytes::read(&mut reader)?;
+        let memorandum: Memo = FromBytes::read(&mut reader)?;
 
         let ledger_digest: MerkleTreeDigest<C::MerkleParameters> = FromBytes::read(&mut reader)?;
-        let inner_circuit_id: <C::InnerCircuitIDCRH as CRH>::Output = FromBytes::read(&mut reader)?;
+        let inner_circuit_id: InnerCircuitId<C> = FromBytes::read(&mut reader)?;
         let transaction_proof: <C::OuterSNARK as SNARK>::Proof = FromBytes::read(&mut reader)?;
         let program_commitment: <C::ProgramVerificationKeyCommitment as CommitmentScheme>::Output =
             FromBytes::read(&mut reader)?;
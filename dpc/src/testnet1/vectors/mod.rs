@@ -0,0 +1,486 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Test vectors pinning the byte-level encodings of testnet1.
+//!
+//! Each kind of vector is stored as a JSON file in `dpc/tests/vectors/`, and is regenerated with
+//! `cargo run --bin generate-vectors --features vectors`. Every random value is sampled from an
+//! RNG seeded with the seed recorded in the file, so regenerating the files is deterministic.
+
+use crate::{
+    account::{Account, AccountViewKey},
+    block::{merkle_root, pedersen_merkle_root, Block},
+    testnet1::{instantiated::*, payload::Payload, record::RecordEncryption, AleoAmount, SystemParameters},
+    traits::{AccountScheme, RecordScheme, TransactionScheme},
+};
+use snarkvm_algorithms::traits::{CommitmentScheme, CRH, PRF as PRFScheme};
+use snarkvm_parameters::{
+    testnet1::{GenesisBlock, NoopProgramSNARKVKParameters, Transaction1},
+    Genesis,
+    LedgerMerkleTreeParameters,
+    Parameter,
+};
+use snarkvm_utilities::{
+    bytes::{FromBytes, ToBytes},
+    rand::UniformRand,
+    to_bytes,
+};
+
+use anyhow::{anyhow, Result};
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt,
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The version of the test vector file format.
+/// Bump it whenever the set of vectors or their fields change.
+pub const TEST_VECTORS_VERSION: u32 = 1;
+
+/// The number of records generated for the record vectors.
+const NUM_RECORDS: usize = 2;
+
+/// A named set of values. Byte strings are hex-encoded, and account keys and
+/// addresses use their canonical string encoding.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVector {
+    pub name: String,
+    pub values: BTreeMap<String, String>,
+}
+
+impl TestVector {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            values: BTreeMap::new(),
+        }
+    }
+
+    fn insert(&mut self, key: &str, value: String) {
+        self.values.insert(key.to_string(), value);
+    }
+
+    fn insert_bytes<T: ToBytes>(&mut self, key: &str, value: &T) -> Result<()> {
+        self.insert(key, hex::encode(to_bytes![value]?));
+        Ok(())
+    }
+}
+
+/// A file of test vectors of one kind.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVectorFile {
+    pub version: u32,
+    pub kind: String,
+    /// The seed of the RNG that sampled every random value in the vectors.
+    pub seed: u64,
+    pub vectors: Vec<TestVector>,
+}
+
+/// The kinds of test vectors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TestVectorKind {
+    /// The genesis transaction, its id, and each of its fields.
+    Transactions,
+    /// The genesis block, its header, and the header hash and Merkle roots.
+    Blocks,
+    /// Records with their owner keys, commitments, serial numbers, and encryptions.
+    Records,
+    /// The system parameter hashes and commitments, and Blake2s, evaluated on fixed inputs.
+    Hashes,
+}
+
+impl TestVectorKind {
+    pub const ALL: [Self; 4] = [Self::Transactions, Self::Blocks, Self::Records, Self::Hashes];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Transactions => "transactions",
+            Self::Blocks => "blocks",
+            Self::Records => "records",
+            Self::Hashes => "hashes",
+        }
+    }
+
+    /// Returns the name of the file storing the vectors of this kind.
+    pub fn file_name(&self) -> String {
+        format!("{}.json", self.name())
+    }
+
+    fn seed(&self) -> u64 {
+        match self {
+            Self::Transactions => 0,
+            Self::Blocks => 0,
+            Self::Records => 1231275789,
+            Self::Hashes => 1231275790,
+        }
+    }
+
+    /// Generates the vectors of this kind with the current code.
+    pub fn generate(&self) -> Result<TestVectorFile> {
+        let mut rng = XorShiftRng::seed_from_u64(self.seed());
+        let vectors = match self {
+            Self::Transactions => transaction_vectors()?,
+            Self::Blocks => block_vectors()?,
+            Self::Records => record_vectors(&mut rng)?,
+            Self::Hashes => hash_vectors(&mut rng)?,
+        };
+
+        Ok(TestVectorFile {
+            version: TEST_VECTORS_VERSION,
+            kind: self.name().to_string(),
+            seed: self.seed(),
+            vectors,
+        })
+    }
+}
+
+impl fmt::Display for TestVectorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Returns the directory storing the published test vectors.
+pub fn default_directory() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("vectors")
+}
+
+/// Loads the vectors of the given kind from the given directory.
+pub fn load(directory: &Path, kind: TestVectorKind) -> Result<TestVectorFile> {
+    let path = directory.join(kind.file_name());
+    let contents = fs::read_to_string(&path).map_err(|error| anyhow!("failed to read {:?}: {}", path, error))?;
+    let file: TestVectorFile =
+        serde_json::from_str(&contents).map_err(|error| anyhow!("failed to parse {:?}: {}", path, error))?;
+
+    if file.kind != kind.name() {
+        return Err(anyhow!("{:?} stores {} vectors, expected {}", path, file.kind, kind));
+    }
+    Ok(file)
+}
+
+/// Writes the given vectors to their file in the given directory.
+pub fn write(directory: &Path, kind: TestVectorKind, file: &TestVectorFile) -> Result<()> {
+    fs::create_dir_all(directory)?;
+    let mut contents = serde_json::to_string_pretty(file)?;
+    contents.push('\n');
+    fs::write(directory.join(kind.file_name()), contents)?;
+    Ok(())
+}
+
+/// Returns a summary of every difference between the `expected` and `candidate` vectors,
+/// or an empty vector if they are identical.
+pub fn diff(expected: &TestVectorFile, candidate: &TestVectorFile) -> Vec<String> {
+    let mut differences = vec![];
+
+    if expected.version != candidate.version {
+        differences.push(format!(
+            "version: expected {}, found {}",
+            expected.version, candidate.version
+        ));
+    }
+    if expected.kind != candidate.kind {
+        differences.push(format!("kind: expected {}, found {}", expected.kind, candidate.kind));
+    }
+    if expected.seed != candidate.seed {
+        differences.push(format!("seed: expected {}, found {}", expected.seed, candidate.seed));
+    }
+
+    let expected_vectors: BTreeMap<_, _> = expected.vectors.iter().map(|v| (&v.name, &v.values)).collect();
+    let candidate_vectors: BTreeMap<_, _> = candidate.vectors.iter().map(|v| (&v.name, &v.values)).collect();
+
+    for (name, expected_values) in &expected_vectors {
+        let candidate_values = match candidate_vectors.get(name) {
+            Some(candidate_values) => candidate_values,
+            None => {
+                differences.push(format!("{}: missing", name));
+                continue;
+            }
+        };
+
+        for (key, expected_value) in expected_values.iter() {
+            match candidate_values.get(key) {
+                Some(candidate_value) if candidate_value == expected_value => {}
+                Some(candidate_value) => {
+                    differences.push(format!(
+                        "{}.{}: {}",
+                        name,
+                        key,
+                        describe_difference(expected_value, candidate_value)
+                    ));
+                }
+                None => differences.push(format!("{}.{}: missing", name, key)),
+            }
+        }
+        for key in candidate_values
+            .keys()
+            .filter(|key| !expected_values.contains_key(*key))
+        {
+            differences.push(format!("{}.{}: unexpected", name, key));
+        }
+    }
+    for name in candidate_vectors
+        .keys()
+        .filter(|name| !expected_vectors.contains_key(*name))
+    {
+        differences.push(format!("{}: unexpected", name));
+    }
+
+    differences
+}
+
+/// Describes where two differing values first diverge.
+fn describe_difference(expected: &str, candidate: &str) -> String {
+    let offset = expected
+        .chars()
+        .zip(candidate.chars())
+        .position(|(a, b)| a != b)
+        .unwrap_or_else(|| expected.len().min(candidate.len()));
+    let excerpt = |value: &str| value.chars().skip(offset).take(16).collect::<String>();
+
+    format!(
+        "differs at character {} (expected length {}, found {}): expected {:?}.., found {:?}..",
+        offset,
+        expected.len(),
+        candidate.len(),
+        excerpt(expected),
+        excerpt(candidate)
+    )
+}
+
+fn genesis_transaction() -> Result<Tx> {
+    Ok(Tx::read(&Transaction1::load_bytes()[..])?)
+}
+
+fn transaction_vectors() -> Result<Vec<TestVector>> {
+    let transaction = genesis_transaction()?;
+
+    let mut vector = TestVector::new("genesis_transaction");
+    vector.insert_bytes("transaction", &transaction)?;
+    vector.insert("transaction_id", hex::encode(transaction.transaction_id()?));
+    vector.insert(
+        "signature_message",
+        hex::encode(transaction.kernel().signature_message()?),
+    );
+    vector.insert("network_id", transaction.network_id().to_string());
+    vector.insert_bytes("ledger_digest", &transaction.ledger_digest)?;
+    vector.insert_bytes("inner_circuit_id", &transaction.inner_circuit_id)?;
+    vector.insert_bytes("program_commitment", &transaction.program_commitment)?;
+    vector.insert_bytes("local_data_root", &transaction.local_data_root)?;
+    vector.insert("value_balance", transaction.value_balance.0.to_string());
+    vector.insert("memorandum", hex::encode(transaction.memorandum));
+    vector.insert_bytes("transaction_proof", &transaction.transaction_proof)?;
+    for (i, serial_number) in transaction.old_serial_numbers.iter().enumerate() {
+        vector.insert_bytes(&format!("old_serial_number_{}", i), serial_number)?;
+    }
+    for (i, commitment) in transaction.new_commitments.iter().enumerate() {
+        vector.insert_bytes(&format!("new_commitment_{}", i), commitment)?;
+    }
+    for (i, signature) in transaction.signatures.iter().enumerate() {
+        vector.insert_bytes(&format!("signature_{}", i), signature)?;
+    }
+    for (i, encrypted_record) in transaction.encrypted_records.iter().enumerate() {
+        vector.insert_bytes(&format!("encrypted_record_{}", i), encrypted_record)?;
+    }
+
+    Ok(vec![vector])
+}
+
+fn block_vectors() -> Result<Vec<TestVector>> {
+    let block = Block::<Tx>::read(&GenesisBlock::load_bytes()[..])?;
+    let header = &block.header;
+    let transaction_ids = block
+        .transactions
+        .iter()
+        .map(|transaction| transaction.transaction_id())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut vector = TestVector::new("genesis_block");
+    vector.insert("block", hex::encode(block.serialize()?));
+    vector.insert("header", hex::encode(&header.serialize()[..]));
+    vector.insert("header_hash", header.get_hash().to_string());
+    vector.insert("previous_block_hash", header.previous_block_hash.to_string());
+    vector.insert("merkle_root_hash", header.merkle_root_hash.to_string());
+    vector.insert(
+        "pedersen_merkle_root_hash",
+        header.pedersen_merkle_root_hash.to_string(),
+    );
+    vector.insert("computed_merkle_root_hash", hex::encode(merkle_root(&transaction_ids)));
+    vector.insert(
+        "computed_pedersen_merkle_root_hash",
+        pedersen_merkle_root(&transaction_ids).to_string(),
+    );
+    vector.insert("time", header.time.to_string());
+    vector.insert("difficulty_target", header.difficulty_target.to_string());
+    vector.insert("difficulty_hash", header.to_difficulty_hash().to_string());
+    vector.insert("nonce", header.nonce.to_string());
+    for (i, transaction_id) in transaction_ids.iter().enumerate() {
+        vector.insert(&format!("transaction_id_{}", i), hex::encode(transaction_id));
+    }
+
+    Ok(vec![vector])
+}
+
+fn record_vectors<R: Rng>(rng: &mut R) -> Result<Vec<TestVector>> {
+    let system_parameters = SystemParameters::<Components>::load()?;
+    let noop_program_id = InstantiatedDPC::compute_program_id(
+        &system_parameters.program_verification_key_crh,
+        &NoopProgramSNARKVKParameters::load_bytes()?,
+    )?;
+
+    let mut vectors = Vec::with_capacity(NUM_RECORDS);
+    for i in 0..NUM_RECORDS {
+        let account = Account::new(
+            &system_parameters.account_signature,
+            &system_parameters.account_commitment,
+            &system_parameters.account_encryption,
+            rng,
+        )?;
+        let view_key = AccountViewKey::from_private_key(
+            &system_parameters.account_signature,
+            &system_parameters.account_commitment,
+            &account.private_key,
+        )?;
+
+        // The first record is a dummy record, which decrypts as such only if its value is zero.
+        let is_dummy = i == 0;
+        let sn_nonce_input: [u8; 32] = rng.gen();
+        let (value, payload) = match is_dummy {
+            true => (AleoAmount::ZERO, [0u8; 32]),
+            false => (AleoAmount(rng.gen::<u32>() as i64), rng.gen()),
+        };
+        let record = InstantiatedDPC::generate_record_with_amount(
+            &system_parameters,
+            SerialNumberNonce::hash(&system_parameters.serial_number_nonce, &sn_nonce_input)?,
+            account.address.clone(),
+            is_dummy,
+            value,
+            Payload::from_bytes(&payload),
+            noop_program_id.clone(),
+            noop_program_id.clone(),
+            rng,
+        )?;
+        let (serial_number, _) = InstantiatedDPC::generate_sn(&system_parameters, &record, &account.private_key)?;
+        let (_, encrypted_record) = RecordEncryption::encrypt_record(&system_parameters, &record, rng)?;
+        let encrypted_record_hash = RecordEncryption::encrypted_record_hash(&system_parameters, &encrypted_record)?;
+
+        let mut vector = TestVector::new(&format!("record_{}", i));
+        vector.insert("private_key", account.private_key.to_string());
+        vector.insert("view_key", view_key.to_string());
+        vector.insert("address", account.address.to_string());
+        vector.insert_bytes("record", &record)?;
+        vector.insert_bytes("commitment", &record.commitment())?;
+        vector.insert_bytes("serial_number", &serial_number)?;
+        vector.insert_bytes("encrypted_record", &encrypted_record)?;
+        vector.insert_bytes("encrypted_record_hash", &encrypted_record_hash)?;
+        vectors.push(vector);
+    }
+
+    Ok(vectors)
+}
+
+fn hash_vectors<R: Rng>(rng: &mut R) -> Result<Vec<TestVector>> {
+    let system_parameters = SystemParameters::<Components>::load()?;
+    let ledger_merkle_tree_crh: MerkleTreeCRH = From::from(<MerkleTreeCRH as CRH>::Parameters::read(
+        &LedgerMerkleTreeParameters::load_bytes()?[..],
+    )?);
+
+    let input: Vec<u8> = (0u8..32).collect();
+    let mut vectors = vec![];
+
+    fn crh_vector<H: CRH>(name: &str, crh: &H, input: &[u8]) -> Result<TestVector> {
+        let mut vector = TestVector::new(name);
+        vector.insert("input", hex::encode(input));
+        vector.insert_bytes("output", &crh.hash(input)?)?;
+        Ok(vector)
+    }
+    vectors.push(crh_vector(
+        "encrypted_record_crh",
+        &system_parameters.encrypted_record_crh,
+        &input,
+    )?);
+    vectors.push(crh_vector(
+        "inner_circuit_id_crh",
+        &system_parameters.inner_circuit_id_crh,
+        &input,
+    )?);
+    vectors.push(crh_vector("ledger_merkle_tree_crh", &ledger_merkle_tree_crh, &input)?);
+    vectors.push(crh_vector("local_data_crh", &system_parameters.local_data_crh, &input)?);
+    vectors.push(crh_vector(
+        "program_verification_key_crh",
+        &system_parameters.program_verification_key_crh,
+        &input,
+    )?);
+    vectors.push(crh_vector(
+        "serial_number_nonce_crh",
+        &system_parameters.serial_number_nonce,
+        &input,
+    )?);
+
+    fn commitment_vector<C: CommitmentScheme, R: Rng>(
+        name: &str,
+        commitment: &C,
+        input: &[u8],
+        rng: &mut R,
+    ) -> Result<TestVector> {
+        let randomness = C::Randomness::rand(rng);
+        let mut vector = TestVector::new(name);
+        vector.insert("input", hex::encode(input));
+        vector.insert_bytes("randomness", &randomness)?;
+        vector.insert_bytes("output", &commitment.commit(input, &randomness)?)?;
+        Ok(vector)
+    }
+    vectors.push(commitment_vector(
+        "account_commitment",
+        &system_parameters.account_commitment,
+        &input,
+        rng,
+    )?);
+    vectors.push(commitment_vector(
+        "local_data_commitment",
+        &system_parameters.local_data_commitment,
+        &input,
+        rng,
+    )?);
+    vectors.push(commitment_vector(
+        "program_verification_key_commitment",
+        &system_parameters.program_verification_key_commitment,
+        &input,
+        rng,
+    )?);
+    vectors.push(commitment_vector(
+        "record_commitment",
+        &system_parameters.record_commitment,
+        &input,
+        rng,
+    )?);
+
+    let seed: [u8; 32] = rng.gen();
+    let prf_input: [u8; 32] = rng.gen();
+    let mut vector = TestVector::new("blake2s_prf");
+    vector.insert("seed", hex::encode(seed));
+    vector.insert("input", hex::encode(prf_input));
+    vector.insert("output", hex::encode(PRF::evaluate(&seed, &prf_input)?));
+    vectors.push(vector);
+
+    Ok(vectors)
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::account::{AccountAddress, AccountPrivateKey};
+
+use std::str::FromStr;
+
+fn assert_reproduces(kind: TestVectorKind) {
+    let expected = load(&default_directory(), kind).unwrap();
+    let candidate = kind.generate().unwrap();
+
+    let differences = diff(&expected, &candidate);
+    assert!(
+        differences.is_empty(),
+        "The current code does not reproduce {} of the {} test vectors:\n  {}\n\
+         If the change is intended, regenerate them with `cargo run --bin generate-vectors --features vectors`.",
+        differences.len(),
+        kind,
+        differences.join("\n  ")
+    );
+}
+
+#[test]
+fn test_transaction_vectors() {
+    assert_reproduces(TestVectorKind::Transactions);
+}
+
+#[test]
+fn test_block_vectors() {
+    assert_reproduces(TestVectorKind::Blocks);
+}
+
+#[test]
+fn test_record_vectors() {
+    assert_reproduces(TestVectorKind::Records);
+}
+
+#[test]
+fn test_hash_vectors() {
+    assert_reproduces(TestVectorKind::Hashes);
+}
+
+#[test]
+fn test_record_vectors_decrypt() {
+    let system_parameters = SystemParameters::<Components>::load().unwrap();
+    let records = load(&default_directory(), TestVectorKind::Records).unwrap();
+
+    for vector in &records.vectors {
+        let value = |key: &str| &vector.values[key];
+        let bytes = |key: &str| hex::decode(value(key)).unwrap();
+
+        // The published keys are consistent with each other.
+        let private_key = AccountPrivateKey::<Components>::from_str(value("private_key")).unwrap();
+        let view_key = AccountViewKey::<Components>::from_str(value("view_key")).unwrap();
+        let address = AccountAddress::<Components>::from_private_key(
+            &system_parameters.account_signature,
+            &system_parameters.account_commitment,
+            &system_parameters.account_encryption,
+            &private_key,
+        )
+        .unwrap();
+        assert_eq!(value("address"), &address.to_string());
+
+        // The published ciphertext decrypts to the published record.
+        let encrypted_record = FromBytes::read(&bytes("encrypted_record")[..]).unwrap();
+        let record = RecordEncryption::decrypt_record(&system_parameters, &view_key, &encrypted_record).unwrap();
+        assert_eq!(bytes("record"), to_bytes![record].unwrap());
+    }
+}
+
+#[test]
+fn test_diff_reports_every_change() {
+    let expected = TestVectorKind::Blocks.generate().unwrap();
+    assert!(diff(&expected, &expected).is_empty());
+
+    let mut candidate = expected.clone();
+    candidate.version += 1;
+    let header_hash = candidate.vectors[0].values.get_mut("header_hash").unwrap();
+    header_hash.replace_range(4..5, if &header_hash[4..5] == "0" { "1" } else { "0" });
+    candidate.vectors[0].values.remove("nonce");
+    candidate.vectors[0].insert("extra", "00".to_string());
+    candidate.vectors.push(TestVector::new("extra_block"));
+
+    let differences = diff(&expected, &candidate);
+    assert_eq!(5, differences.len(), "{:?}", differences);
+    assert!(differences[0].starts_with("version"));
+    assert!(differences[1].starts_with("genesis_block.header_hash: differs at character 4"));
+    assert_eq!("genesis_block.nonce: missing", differences[2]);
+    assert_eq!("genesis_block.extra: unexpected", differences[3]);
+    assert_eq!("extra_block: unexpected", differences[4]);
+}
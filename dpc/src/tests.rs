@@ -15,24 +15,32 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    testnet1::{instantiated::Components, parameters::PublicParameters, BaseDPCComponents},
+    testnet1::{
+        instantiated::{Components, InstantiatedDPC},
+        parameters::SystemParameters,
+        BaseDPCComponents,
+        InnerCircuitId,
+    },
     traits::DPCComponents,
 };
 use snarkvm_algorithms::traits::{CRH, SNARK};
+use snarkvm_parameters::{
+    testnet1::{InnerSNARKVKParameters, NoopProgramSNARKVKParameters},
+    Parameter,
+};
 use snarkvm_utilities::{to_bytes, ToBytes};
 
-fn testnet1_inner_circuit_id() -> anyhow::Result<Vec<u8>> {
-    let parameters = PublicParameters::<Components>::load(false)?;
-
-    let inner_snark_vk: <<Components as BaseDPCComponents>::InnerSNARK as SNARK>::VerifyingKey =
-        parameters.inner_snark_parameters.1.clone().into();
+fn testnet1_inner_circuit_id() -> anyhow::Result<InnerCircuitId<Components>> {
+    let system_parameters = SystemParameters::<Components>::load()?;
 
-    let inner_circuit_id = <<Components as DPCComponents>::InnerCircuitIDCRH as CRH>::hash(
-        &parameters.system_parameters.inner_circuit_id_crh,
-        &to_bytes![inner_snark_vk]?,
+    let inner_snark_vk = <<Components as BaseDPCComponents>::InnerSNARK as SNARK>::VerifyingKey::read(
+        InnerSNARKVKParameters::load_bytes()?.as_slice(),
     )?;
 
-    Ok(to_bytes![inner_circuit_id]?)
+    Ok(InstantiatedDPC::compute_inner_circuit_id(
+        &system_parameters.inner_circuit_id_crh,
+        &inner_snark_vk,
+    )?)
 }
 
 #[test]
@@ -42,5 +50,67 @@ fn test_inner_circuit_sanity_check() {
         141, 28, 29, 2, 131, 75, 18, 78, 248, 57, 118, 61, 81, 53, 11, 91, 196, 233, 80, 186, 167, 144, 163, 0,
     ];
     let candidate_testnet1_inner_circuit_id = testnet1_inner_circuit_id().unwrap();
-    assert_eq!(expected_testnet1_inner_circuit_id, candidate_testnet1_inner_circuit_id);
+    assert_eq!(
+        expected_testnet1_inner_circuit_id,
+        candidate_testnet1_inner_circuit_id.to_bytes()
+    );
+}
+
+#[test]
+fn test_inner_circuit_id_string_round_trip() {
+    let inner_circuit_id = testnet1_inner_circuit_id().unwrap();
+
+    let inner_circuit_id_string = inner_circuit_id.to_string();
+    assert_eq!(hex::encode(inner_circuit_id.to_bytes()), inner_circuit_id_string);
+    assert_eq!(inner_circuit_id, inner_circuit_id_string.parse().unwrap());
+
+    // Truncated, extended, and malformed encodings are rejected.
+    assert!(
+        inner_circuit_id_string[2..]
+            .parse::<InnerCircuitId<Components>>()
+            .is_err()
+    );
+    assert!(
+        format!("{}00", inner_circuit_id_string)
+            .parse::<InnerCircuitId<Components>>()
+            .is_err()
+    );
+    assert!("zz".parse::<InnerCircuitId<Components>>().is_err());
+}
+
+#[test]
+fn test_noop_program_id_sanity_check() {
+    let system_parameters = SystemParameters::<Components>::load().unwrap();
+
+    let noop_program_id = InstantiatedDPC::compute_program_id(
+        &system_parameters.program_verification_key_crh,
+        &NoopProgramSNARKVKParameters::load_bytes().unwrap(),
+    )
+    .unwrap();
+
+    let expected_noop_program_id = vec![
+        78, 122, 227, 239, 149, 119, 135, 125, 220, 239, 143, 140, 93, 155, 94, 59, 245, 68, 199, 140, 80, 197, 18, 19,
+        133, 127, 53, 195, 60, 53, 2, 223, 18, 240, 251, 114, 160, 215, 197, 108, 205, 49, 168, 125, 173, 169, 43, 0,
+    ];
+    assert_eq!(expected_noop_program_id, noop_program_id);
+}
+
+#[test]
+fn test_inner_circuit_id_crh_sanity_check() {
+    // The inner circuit id CRH determines every inner circuit id, so its output on a fixed input is pinned.
+    let system_parameters = SystemParameters::<Components>::load().unwrap();
+    let input: Vec<u8> = (0..64).collect();
+
+    let output = <<Components as DPCComponents>::InnerCircuitIDCRH as CRH>::hash(
+        &system_parameters.inner_circuit_id_crh,
+        &input,
+    )
+    .unwrap();
+
+    let expected_output = vec![
+        145, 129, 43, 97, 76, 157, 235, 103, 235, 215, 144, 102, 255, 236, 210, 223, 216, 59, 148, 206, 84, 239, 219,
+        130, 182, 113, 119, 142, 59, 193, 194, 78, 128, 136, 96, 60, 71, 169, 182, 139, 237, 84, 27, 200, 151, 140,
+        133, 1,
+    ];
+    assert_eq!(expected_output, to_bytes![output].unwrap());
 }
@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::traits::{AccountScheme, LedgerScheme, RecordScheme, TransactionScheme};
+use crate::{
+    block::Network,
+    traits::{AccountScheme, LedgerScheme, RecordScheme, TransactionScheme},
+};
 
 use rand::Rng;
 use std::sync::Arc;
@@ -30,9 +33,10 @@ pub trait DPCScheme<L: LedgerScheme> {
     type Transaction: TransactionScheme<SerialNumber = <Self::Record as RecordScheme>::SerialNumber>;
     type TransactionKernel;
 
-    /// Returns public parameters for the DPC.
+    /// Returns public parameters for the DPC on the given network.
     fn setup<R: Rng>(
         ledger_parameters: &Arc<L::MerkleParameters>,
+        network: Network,
         rng: &mut R,
     ) -> anyhow::Result<Self::NetworkParameters>;
 
@@ -74,7 +78,8 @@ pub trait DPCScheme<L: LedgerScheme> {
         ledger: &L,
     ) -> anyhow::Result<bool>;
 
-    /// Returns true iff all the transactions in the block are valid according to the ledger.
+    /// Returns true iff all the transactions in the block are valid according to the ledger,
+    /// and no two of them conflict with each other.
     fn verify_transactions(
         parameters: &Self::NetworkParameters,
         block: &[Self::Transaction],
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_algorithms::traits::{CommitmentScheme, EncryptionScheme, SignatureScheme, CRH, PRF};
-use snarkvm_fields::PrimeField;
+use snarkvm_fields::{PoseidonMDSField, PrimeField};
 use snarkvm_gadgets::traits::algorithms::{
     CRHGadget,
     CommitmentGadget,
@@ -25,10 +25,12 @@ use snarkvm_gadgets::traits::algorithms::{
 };
 
 pub trait DPCComponents: 'static + Sized {
+    /// The number of input and output records of a transaction. `Self::LocalDataCRH` hashes
+    /// the local data commitments of all input records, and of all output records, at once.
     const NUM_INPUT_RECORDS: usize;
     const NUM_OUTPUT_RECORDS: usize;
 
-    type InnerField: PrimeField;
+    type InnerField: PrimeField + PoseidonMDSField;
     type OuterField: PrimeField;
 
     /// Encryption scheme for account records.
@@ -25,6 +25,7 @@ pub trait LedgerScheme: Sized {
     type MerkleParameters;
     type MerklePath;
     type MerkleTreeDigest;
+    type NonMembershipProof;
     type SerialNumber;
     type Transaction: TransactionScheme;
 
@@ -68,4 +69,25 @@ pub trait LedgerScheme: Sized {
         cm: &Self::Commitment,
         witness: &Self::MerklePath,
     ) -> bool;
+
+    /// Returns the digest of the serial numbers in the ledger
+    /// as of the given block height, if the block exists.
+    fn sn_digest(&self, block_height: u32) -> Option<Self::MerkleTreeDigest>;
+
+    /// Returns a proof that the given serial number did not exist
+    /// in the ledger as of the given block height.
+    fn prove_non_membership(
+        &self,
+        sn: &Self::SerialNumber,
+        block_height: u32,
+    ) -> anyhow::Result<Self::NonMembershipProof>;
+
+    /// Returns true if the given proof is a valid witness that the given
+    /// serial number does not exist for the given serial number digest.
+    fn verify_non_membership(
+        parameters: &Arc<Self::MerkleParameters>,
+        digest: &Self::MerkleTreeDigest,
+        sn: &Self::SerialNumber,
+        witness: &Self::NonMembershipProof,
+    ) -> bool;
 }
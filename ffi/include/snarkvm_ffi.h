@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+// C declarations for snarkvm-ffi. See `src/lib.rs` for the ownership rules of each
+// function.
+
+#ifndef SNARKVM_FFI_H
+#define SNARKVM_FFI_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// The length of an account seed in bytes.
+#define SNARKVM_SEED_LENGTH 32
+
+// The minimum size of the buffer receiving an account address, including the NUL terminator.
+#define SNARKVM_ADDRESS_BUFFER_LENGTH 64
+
+// The status code returned by every fallible function. The values are stable.
+typedef enum {
+    SNARKVM_OK = 0,
+    SNARKVM_NULL_POINTER = 1,
+    SNARKVM_INVALID_LENGTH = 2,
+    SNARKVM_INVALID_UTF8 = 3,
+    SNARKVM_INVALID_SEED = 4,
+    SNARKVM_INVALID_VIEW_KEY = 5,
+    SNARKVM_INVALID_CIPHERTEXT = 6,
+    SNARKVM_DECRYPTION_FAILED = 7,
+    SNARKVM_PARAMETERS_UNAVAILABLE = 8,
+    SNARKVM_SERIALIZATION_FAILED = 9,
+    SNARKVM_PANIC = 10,
+} snarkvm_status_t;
+
+// Derives the account address for a 32-byte seed into a caller-allocated buffer of
+// `out_address_len` bytes, which must be at least SNARKVM_ADDRESS_BUFFER_LENGTH.
+snarkvm_status_t snarkvm_account_from_seed(
+    const uint8_t *seed,
+    size_t seed_len,
+    char *out_address,
+    size_t out_address_len);
+
+// Decrypts a serialized encrypted record with a view key. On success, `*out_json` holds a
+// NUL-terminated JSON string that must be released with `snarkvm_string_free`.
+snarkvm_status_t snarkvm_record_decrypt(
+    const char *view_key,
+    const uint8_t *ciphertext,
+    size_t ciphertext_len,
+    char **out_json);
+
+// Returns true if the NUL-terminated string is a well-formed account address.
+bool snarkvm_address_validate(const char *address);
+
+// Releases a string returned by this library. Passing NULL is a no-op.
+void snarkvm_string_free(char *string);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // SNARKVM_FFI_H
@@ -0,0 +1,295 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A minimal C ABI for wallets that only need address derivation and record decryption.
+//!
+//! The interface is deliberately struct-free: every function takes and returns primitive types,
+//! byte pointers, and NUL-terminated strings, and reports failures through [`SnarkVMStatus`].
+//! The matching C declarations live in `include/snarkvm_ffi.h`.
+//!
+//! Ownership rules:
+//! - Input pointers are borrowed for the duration of the call and never retained.
+//! - Address strings are written into a caller-allocated buffer of at least
+//!   [`SNARKVM_ADDRESS_BUFFER_LENGTH`] bytes.
+//! - Record JSON strings are allocated by the library and must be released with
+//!   [`snarkvm_string_free`], and with nothing else.
+//!
+//! No function unwinds across the boundary; a panic is reported as [`SnarkVMStatus::Panic`].
+
+use snarkvm_dpc::{
+    account::{AccountAddress, AccountPrivateKey, AccountViewKey},
+    testnet1::{
+        instantiated::Components,
+        parameters::SystemParameters,
+        record::{EncryptedRecord, Record, RecordEncryption},
+    },
+    traits::RecordScheme,
+};
+use snarkvm_utilities::{to_bytes, FromBytes, ToBytes};
+
+use once_cell::sync::OnceCell;
+use serde::Serialize;
+use std::{
+    convert::TryFrom,
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr,
+    str::FromStr,
+};
+
+/// The length of an account seed in bytes.
+pub const SNARKVM_SEED_LENGTH: usize = 32;
+
+/// The minimum size of the buffer receiving an account address, including the NUL terminator.
+pub const SNARKVM_ADDRESS_BUFFER_LENGTH: usize = 64;
+
+/// The status code returned by every fallible FFI function.
+///
+/// The discriminants are part of the ABI and must never be renumbered.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnarkVMStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// A length argument does not match the expected size, or an output buffer is too small.
+    InvalidLength = 2,
+    /// A string argument is not valid UTF-8.
+    InvalidUtf8 = 3,
+    /// The seed does not derive a valid account private key.
+    InvalidSeed = 4,
+    /// The view key string is malformed.
+    InvalidViewKey = 5,
+    /// The ciphertext bytes do not deserialize to an encrypted record.
+    InvalidCiphertext = 6,
+    /// The encrypted record could not be decrypted with the given view key.
+    DecryptionFailed = 7,
+    /// The system parameters could not be loaded.
+    ParametersUnavailable = 8,
+    /// The decrypted record could not be serialized.
+    SerializationFailed = 9,
+    /// The library panicked; the panic was caught at the boundary.
+    Panic = 10,
+}
+
+/// The JSON representation of a decrypted record. Byte fields are hex-encoded.
+#[derive(Serialize)]
+struct RecordJson {
+    owner: String,
+    is_dummy: bool,
+    value: u64,
+    payload: String,
+    birth_program_id: String,
+    death_program_id: String,
+    serial_number_nonce: String,
+    commitment: String,
+    commitment_randomness: String,
+}
+
+impl RecordJson {
+    fn new(record: &Record<Components>) -> Result<Self, SnarkVMStatus> {
+        let hex_bytes =
+            |bytes: std::io::Result<Vec<u8>>| bytes.map(hex::encode).map_err(|_| SnarkVMStatus::SerializationFailed);
+
+        Ok(Self {
+            owner: record.owner().to_string(),
+            is_dummy: record.is_dummy(),
+            value: u64::try_from(record.value()).map_err(|_| SnarkVMStatus::SerializationFailed)?,
+            payload: hex_bytes(to_bytes![record.payload()])?,
+            birth_program_id: hex::encode(record.birth_program_id()),
+            death_program_id: hex::encode(record.death_program_id()),
+            serial_number_nonce: hex_bytes(to_bytes![record.serial_number_nonce()])?,
+            commitment: hex_bytes(to_bytes![record.commitment()])?,
+            commitment_randomness: hex_bytes(to_bytes![record.commitment_randomness()])?,
+        })
+    }
+}
+
+/// Returns the system parameters, loading them on first use.
+fn system_parameters() -> Result<&'static SystemParameters<Components>, SnarkVMStatus> {
+    static SYSTEM_PARAMETERS: OnceCell<SystemParameters<Components>> = OnceCell::new();
+
+    SYSTEM_PARAMETERS
+        .get_or_try_init(SystemParameters::load)
+        .map_err(|_| SnarkVMStatus::ParametersUnavailable)
+}
+
+/// Runs the given closure, converting a panic into `SnarkVMStatus::Panic`.
+fn guard<F: FnOnce() -> Result<(), SnarkVMStatus>>(f: F) -> SnarkVMStatus {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => SnarkVMStatus::Ok,
+        Ok(Err(status)) => status,
+        Err(_) => SnarkVMStatus::Panic,
+    }
+}
+
+/// Borrows a NUL-terminated C string as a `&str`.
+///
+/// The pointer must be null or point to a NUL-terminated string that outlives the call.
+unsafe fn borrow_str<'a>(string: *const c_char) -> Result<&'a str, SnarkVMStatus> {
+    if string.is_null() {
+        return Err(SnarkVMStatus::NullPointer);
+    }
+    CStr::from_ptr(string).to_str().map_err(|_| SnarkVMStatus::InvalidUtf8)
+}
+
+/// Borrows `len` bytes starting at `bytes`.
+///
+/// The pointer must be null or valid for reads of `len` bytes for the duration of the call.
+unsafe fn borrow_bytes<'a>(bytes: *const u8, len: usize) -> Result<&'a [u8], SnarkVMStatus> {
+    if bytes.is_null() {
+        return Err(SnarkVMStatus::NullPointer);
+    }
+    Ok(std::slice::from_raw_parts(bytes, len))
+}
+
+/// Derives the account address for the given 32-byte seed.
+fn address_from_seed(seed: &[u8]) -> Result<String, SnarkVMStatus> {
+    if seed.len() != SNARKVM_SEED_LENGTH {
+        return Err(SnarkVMStatus::InvalidLength);
+    }
+    let mut seed_bytes = [0u8; SNARKVM_SEED_LENGTH];
+    seed_bytes.copy_from_slice(seed);
+
+    let parameters = system_parameters()?;
+    let private_key = AccountPrivateKey::<Components>::from_seed(
+        &parameters.account_signature,
+        &parameters.account_commitment,
+        &seed_bytes,
+    )
+    .map_err(|_| SnarkVMStatus::InvalidSeed)?;
+    let address = AccountAddress::from_private_key(
+        &parameters.account_signature,
+        &parameters.account_commitment,
+        &parameters.account_encryption,
+        &private_key,
+    )
+    .map_err(|_| SnarkVMStatus::InvalidSeed)?;
+
+    Ok(address.to_string())
+}
+
+/// Decrypts the serialized encrypted record with the given view key, returning the record as JSON.
+fn decrypt_record_to_json(view_key: &str, ciphertext: &[u8]) -> Result<String, SnarkVMStatus> {
+    let view_key = AccountViewKey::<Components>::from_str(view_key).map_err(|_| SnarkVMStatus::InvalidViewKey)?;
+    let encrypted_record =
+        EncryptedRecord::<Components>::read(ciphertext).map_err(|_| SnarkVMStatus::InvalidCiphertext)?;
+
+    let parameters = system_parameters()?;
+    let record = RecordEncryption::decrypt_record(parameters, &view_key, &encrypted_record)
+        .map_err(|_| SnarkVMStatus::DecryptionFailed)?;
+
+    serde_json::to_string(&RecordJson::new(&record)?).map_err(|_| SnarkVMStatus::SerializationFailed)
+}
+
+/// Derives the account address for a 32-byte seed and writes it, NUL-terminated, to `out_address`.
+///
+/// `out_address` must point to a caller-allocated buffer of `out_address_len` bytes, where
+/// `out_address_len` is at least `SNARKVM_ADDRESS_BUFFER_LENGTH`. The buffer is left untouched
+/// unless the call returns `SnarkVMStatus::Ok`.
+///
+/// # Safety
+///
+/// `seed` must be valid for reads of `seed_len` bytes, and `out_address` must be valid for
+/// writes of `out_address_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_account_from_seed(
+    seed: *const u8,
+    seed_len: usize,
+    out_address: *mut c_char,
+    out_address_len: usize,
+) -> SnarkVMStatus {
+    guard(|| {
+        let seed = borrow_bytes(seed, seed_len)?;
+        if out_address.is_null() {
+            return Err(SnarkVMStatus::NullPointer);
+        }
+
+        let address = address_from_seed(seed)?;
+        if address.len() >= out_address_len {
+            return Err(SnarkVMStatus::InvalidLength);
+        }
+
+        ptr::copy_nonoverlapping(address.as_ptr(), out_address.cast::<u8>(), address.len());
+        *out_address.add(address.len()) = 0;
+        Ok(())
+    })
+}
+
+/// Decrypts a serialized encrypted record with a view key string and stores the record as a
+/// NUL-terminated JSON string in `*out_json`.
+///
+/// On success, `*out_json` is owned by the caller and must be released with `snarkvm_string_free`.
+/// On failure, `*out_json` is set to null.
+///
+/// # Safety
+///
+/// `view_key` must be a NUL-terminated string, `ciphertext` must be valid for reads of
+/// `ciphertext_len` bytes, and `out_json` must be valid for writes of one pointer.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_record_decrypt(
+    view_key: *const c_char,
+    ciphertext: *const u8,
+    ciphertext_len: usize,
+    out_json: *mut *mut c_char,
+) -> SnarkVMStatus {
+    if out_json.is_null() {
+        return SnarkVMStatus::NullPointer;
+    }
+    *out_json = ptr::null_mut();
+
+    guard(|| {
+        let view_key = borrow_str(view_key)?;
+        let ciphertext = borrow_bytes(ciphertext, ciphertext_len)?;
+
+        let json = decrypt_record_to_json(view_key, ciphertext)?;
+        let json = CString::new(json).map_err(|_| SnarkVMStatus::SerializationFailed)?;
+
+        *out_json = json.into_raw();
+        Ok(())
+    })
+}
+
+/// Returns `true` if the NUL-terminated string is a well-formed account address.
+///
+/// # Safety
+///
+/// `address` must be null or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_address_validate(address: *const c_char) -> bool {
+    catch_unwind(|| match borrow_str(address) {
+        Ok(address) => AccountAddress::<Components>::from_str(address).is_ok(),
+        Err(_) => false,
+    })
+    .unwrap_or(false)
+}
+
+/// Releases a string returned by this library. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `string` must be null or a pointer returned by this library that has not yet been freed.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use serde_json::Value;
+
+const RECORD_VECTORS: &str = include_str!("../../dpc/tests/vectors/records.json");
+
+const SEED: [u8; SNARKVM_SEED_LENGTH] = [0x50u8; SNARKVM_SEED_LENGTH];
+
+/// A seed for which no `r_pk` counter yields a valid account private key.
+const INVALID_SEED: [u8; SNARKVM_SEED_LENGTH] = [0x07u8; SNARKVM_SEED_LENGTH];
+
+/// Returns the view key, encrypted record bytes, and vector values of the non-dummy test record.
+fn record_vector() -> (CString, Vec<u8>, Value) {
+    let vectors: Value = serde_json::from_str(RECORD_VECTORS).unwrap();
+    let values = vectors["vectors"][1]["values"].clone();
+
+    let view_key = CString::new(values["view_key"].as_str().unwrap()).unwrap();
+    let ciphertext = hex::decode(values["encrypted_record"].as_str().unwrap()).unwrap();
+
+    (view_key, ciphertext, values)
+}
+
+fn account_from_seed(seed: &[u8]) -> (SnarkVMStatus, String) {
+    let mut buffer: [c_char; SNARKVM_ADDRESS_BUFFER_LENGTH] = [0; SNARKVM_ADDRESS_BUFFER_LENGTH];
+    let status = unsafe { snarkvm_account_from_seed(seed.as_ptr(), seed.len(), buffer.as_mut_ptr(), buffer.len()) };
+    let address = unsafe { CStr::from_ptr(buffer.as_ptr()) }.to_str().unwrap().to_string();
+
+    (status, address)
+}
+
+fn decrypt(view_key: &CStr, ciphertext: &[u8]) -> (SnarkVMStatus, Option<String>) {
+    let mut json = ptr::null_mut();
+    let status = unsafe { snarkvm_record_decrypt(view_key.as_ptr(), ciphertext.as_ptr(), ciphertext.len(), &mut json) };
+
+    if json.is_null() {
+        return (status, None);
+    }
+    let output = unsafe { CStr::from_ptr(json) }.to_str().unwrap().to_string();
+    unsafe { snarkvm_string_free(json) };
+
+    (status, Some(output))
+}
+
+#[test]
+fn test_account_from_seed() {
+    let parameters = SystemParameters::<Components>::load().unwrap();
+    let private_key =
+        AccountPrivateKey::from_seed(&parameters.account_signature, &parameters.account_commitment, &SEED).unwrap();
+    let expected = AccountAddress::<Components>::from_private_key(
+        &parameters.account_signature,
+        &parameters.account_commitment,
+        &parameters.account_encryption,
+        &private_key,
+    )
+    .unwrap();
+
+    let (status, address) = account_from_seed(&SEED);
+    assert_eq!(status, SnarkVMStatus::Ok);
+    assert_eq!(address, expected.to_string());
+
+    let address = CString::new(address).unwrap();
+    assert!(unsafe { snarkvm_address_validate(address.as_ptr()) });
+}
+
+#[test]
+fn test_account_from_seed_rejects_invalid_arguments() {
+    let mut buffer: [c_char; SNARKVM_ADDRESS_BUFFER_LENGTH] = [0; SNARKVM_ADDRESS_BUFFER_LENGTH];
+
+    let (status, address) = account_from_seed(&SEED[..31]);
+    assert_eq!(status, SnarkVMStatus::InvalidLength);
+    assert!(address.is_empty());
+
+    let (status, address) = account_from_seed(&INVALID_SEED);
+    assert_eq!(status, SnarkVMStatus::InvalidSeed);
+    assert!(address.is_empty());
+
+    let status =
+        unsafe { snarkvm_account_from_seed(ptr::null(), SNARKVM_SEED_LENGTH, buffer.as_mut_ptr(), buffer.len()) };
+    assert_eq!(status, SnarkVMStatus::NullPointer);
+
+    let status = unsafe { snarkvm_account_from_seed(SEED.as_ptr(), SEED.len(), ptr::null_mut(), buffer.len()) };
+    assert_eq!(status, SnarkVMStatus::NullPointer);
+
+    // The buffer must leave room for the NUL terminator.
+    let status = unsafe { snarkvm_account_from_seed(SEED.as_ptr(), SEED.len(), buffer.as_mut_ptr(), buffer.len() - 1) };
+    assert_eq!(status, SnarkVMStatus::InvalidLength);
+    assert!(buffer.iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn test_record_decrypt() {
+    let (view_key, ciphertext, values) = record_vector();
+
+    let (status, json) = decrypt(&view_key, &ciphertext);
+    assert_eq!(status, SnarkVMStatus::Ok);
+
+    let record: Value = serde_json::from_str(&json.unwrap()).unwrap();
+    assert_eq!(record["owner"], values["address"]);
+    assert_eq!(record["commitment"], values["commitment"]);
+    assert_eq!(record["is_dummy"], Value::Bool(false));
+}
+
+#[test]
+fn test_record_decrypt_rejects_invalid_arguments() {
+    let (view_key, ciphertext, _) = record_vector();
+
+    let invalid_view_key = CString::new("AViewKey1invalid").unwrap();
+    assert_eq!(
+        decrypt(&invalid_view_key, &ciphertext),
+        (SnarkVMStatus::InvalidViewKey, None)
+    );
+    assert_eq!(
+        decrypt(&view_key, &ciphertext[..ciphertext.len() / 2]),
+        (SnarkVMStatus::InvalidCiphertext, None)
+    );
+
+    let mut json = ptr::null_mut();
+    let status = unsafe { snarkvm_record_decrypt(ptr::null(), ciphertext.as_ptr(), ciphertext.len(), &mut json) };
+    assert_eq!(status, SnarkVMStatus::NullPointer);
+    assert!(json.is_null());
+
+    let status = unsafe {
+        snarkvm_record_decrypt(
+            view_key.as_ptr(),
+            ciphertext.as_ptr(),
+            ciphertext.len(),
+            ptr::null_mut(),
+        )
+    };
+    assert_eq!(status, SnarkVMStatus::NullPointer);
+
+    // Freeing null is a no-op.
+    unsafe { snarkvm_string_free(ptr::null_mut()) };
+}
+
+#[test]
+fn test_address_validate() {
+    let (_, _, values) = record_vector();
+    let address = values["address"].as_str().unwrap();
+
+    let valid = CString::new(address).unwrap();
+    assert!(unsafe { snarkvm_address_validate(valid.as_ptr()) });
+
+    let truncated = CString::new(&address[..62]).unwrap();
+    assert!(!unsafe { snarkvm_address_validate(truncated.as_ptr()) });
+
+    let non_utf8 = CString::new(vec![0xffu8; 63]).unwrap();
+    assert!(!unsafe { snarkvm_address_validate(non_utf8.as_ptr()) });
+
+    assert!(!unsafe { snarkvm_address_validate(ptr::null()) });
+}
+
+#[test]
+fn test_address_validate_does_not_unwind() {
+    // A multi-byte character straddling the prefix boundary panics inside the address parser.
+    let address = format!("ale\u{e9}{}", "q".repeat(58));
+    assert_eq!(address.len(), 63);
+
+    let address = CString::new(address).unwrap();
+    assert!(!unsafe { snarkvm_address_validate(address.as_ptr()) });
+}
@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Builds the shared library of this crate, compiles `tests/ffi/ffi_test.c` against it and
+//! `include/snarkvm_ffi.h`, then runs the program, so that a change to the C ABI fails the build.
+
+use snarkvm_dpc::{
+    testnet1::{instantiated::Components, parameters::SystemParameters},
+    AccountAddress,
+    AccountPrivateKey,
+};
+
+use serde_json::Value;
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Returns the target directory holding the library artifacts, i.e. the parent of this test's `deps` directory.
+fn target_directory() -> PathBuf {
+    let executable = env::current_exe().unwrap();
+    executable.parent().unwrap().parent().unwrap().to_path_buf()
+}
+
+/// Builds the shared library, which `cargo test` does not produce on its own.
+fn build_shared_library(manifest_directory: &Path, target_directory: &Path) {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+
+    let mut command = Command::new(cargo);
+    command
+        .args(["build", "--lib", "--manifest-path"])
+        .arg(manifest_directory.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(target_directory.parent().unwrap());
+    if target_directory.ends_with("release") {
+        command.arg("--release");
+    }
+
+    let status = command.status().expect("failed to run cargo");
+    assert!(status.success(), "failed to build the shared library");
+}
+
+fn compile(manifest_directory: &Path, target_directory: &Path) -> PathBuf {
+    let output = target_directory.join("snarkvm_ffi_test");
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+
+    let mut command = Command::new(compiler);
+    command
+        .arg("-std=c11")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(manifest_directory.join("include"))
+        .arg(manifest_directory.join("tests/ffi/ffi_test.c"))
+        .arg("-L")
+        .arg(target_directory)
+        .arg(format!("-Wl,-rpath,{}", target_directory.display()))
+        .arg("-lsnarkvm_ffi")
+        .arg("-o")
+        .arg(&output);
+
+    let status = command.status().expect("failed to run the C compiler");
+    assert!(status.success(), "failed to compile the FFI test program");
+
+    output
+}
+
+#[test]
+fn test_c_abi() {
+    let manifest_directory = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let target_directory = target_directory();
+    build_shared_library(&manifest_directory, &target_directory);
+    let program = compile(&manifest_directory, &target_directory);
+
+    let vectors: Value =
+        serde_json::from_str(include_str!("../../dpc/tests/vectors/records.json")).expect("failed to parse the record vectors");
+    let values = &vectors["vectors"][1]["values"];
+    let owner = values["address"].as_str().unwrap();
+
+    let output = Command::new(&program)
+        .arg(values["view_key"].as_str().unwrap())
+        .arg(values["encrypted_record"].as_str().unwrap())
+        .arg(owner)
+        .output()
+        .expect("failed to run the FFI test program");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success(), "the FFI test program failed:\n{}", stderr);
+
+    // The address derived through the C ABI matches the one derived in Rust.
+    let parameters = SystemParameters::<Components>::load().unwrap();
+    let private_key = AccountPrivateKey::from_seed(
+        &parameters.account_signature,
+        &parameters.account_commitment,
+        &[0x50u8; 32],
+    )
+    .unwrap();
+    let expected_address = AccountAddress::<Components>::from_private_key(
+        &parameters.account_signature,
+        &parameters.account_commitment,
+        &parameters.account_encryption,
+        &private_key,
+    )
+    .unwrap();
+    assert!(stdout.contains(&format!("address={}\n", expected_address)));
+
+    // The decrypted record matches the record vector.
+    let record = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("record="))
+        .expect("missing decrypted record");
+    let record: Value = serde_json::from_str(record).unwrap();
+    assert_eq!(record["owner"], values["address"]);
+    assert_eq!(record["commitment"], values["commitment"]);
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+// Exercises the snarkvm-ffi C ABI through the public header only.
+//
+// Usage: ffi_test <view_key> <encrypted_record_hex> <record_owner_address>
+//
+// Prints the address derived from a seed of 32 bytes of 0x50 and the decrypted record JSON,
+// and exits non-zero on the first failed check.
+
+#include "snarkvm_ffi.h"
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+_Static_assert(sizeof(snarkvm_status_t) == sizeof(int32_t), "snarkvm_status_t must be 32 bits");
+
+#define CHECK(condition) \
+    do { \
+        if (!(condition)) { \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #condition); \
+            return 1; \
+        } \
+    } while (0)
+
+static int hex_decode(const char *hex, uint8_t **out, size_t *out_len) {
+    size_t hex_len = strlen(hex);
+    if (hex_len % 2 != 0) {
+        return 0;
+    }
+
+    *out_len = hex_len / 2;
+    *out = malloc(*out_len);
+    if (*out == NULL) {
+        return 0;
+    }
+
+    for (size_t i = 0; i < *out_len; i++) {
+        unsigned int byte;
+        if (sscanf(hex + 2 * i, "%2x", &byte) != 1) {
+            free(*out);
+            return 0;
+        }
+        (*out)[i] = (uint8_t)byte;
+    }
+    return 1;
+}
+
+int main(int argc, char **argv) {
+    CHECK(argc == 4);
+    const char *view_key = argv[1];
+    const char *owner = argv[3];
+
+    // Address derivation.
+    uint8_t seed[SNARKVM_SEED_LENGTH];
+    memset(seed, 0x50, sizeof(seed));
+
+    char address[SNARKVM_ADDRESS_BUFFER_LENGTH];
+    CHECK(snarkvm_account_from_seed(seed, sizeof(seed), address, sizeof(address)) == SNARKVM_OK);
+    CHECK(snarkvm_address_validate(address));
+    printf("address=%s\n", address);
+
+    CHECK(snarkvm_account_from_seed(seed, sizeof(seed) - 1, address, sizeof(address)) == SNARKVM_INVALID_LENGTH);
+    CHECK(snarkvm_account_from_seed(seed, sizeof(seed), address, sizeof(address) - 1) == SNARKVM_INVALID_LENGTH);
+    uint8_t invalid_seed[SNARKVM_SEED_LENGTH];
+    memset(invalid_seed, 0x07, sizeof(invalid_seed));
+    CHECK(snarkvm_account_from_seed(invalid_seed, sizeof(invalid_seed), address, sizeof(address)) == SNARKVM_INVALID_SEED);
+    CHECK(snarkvm_account_from_seed(NULL, sizeof(seed), address, sizeof(address)) == SNARKVM_NULL_POINTER);
+    CHECK(snarkvm_account_from_seed(seed, sizeof(seed), NULL, sizeof(address)) == SNARKVM_NULL_POINTER);
+
+    // Address validation.
+    CHECK(snarkvm_address_validate(owner));
+    CHECK(!snarkvm_address_validate("aleo1invalid"));
+    CHECK(!snarkvm_address_validate(NULL));
+
+    // Record decryption.
+    uint8_t *ciphertext;
+    size_t ciphertext_len;
+    CHECK(hex_decode(argv[2], &ciphertext, &ciphertext_len));
+
+    char *json = NULL;
+    CHECK(snarkvm_record_decrypt(view_key, ciphertext, ciphertext_len, &json) == SNARKVM_OK);
+    CHECK(json != NULL);
+    CHECK(strstr(json, owner) != NULL);
+    printf("record=%s\n", json);
+    snarkvm_string_free(json);
+
+    json = (char *)1;
+    CHECK(snarkvm_record_decrypt("AViewKey1invalid", ciphertext, ciphertext_len, &json) == SNARKVM_INVALID_VIEW_KEY);
+    CHECK(json == NULL);
+    CHECK(snarkvm_record_decrypt(view_key, ciphertext, ciphertext_len / 2, &json) == SNARKVM_INVALID_CIPHERTEXT);
+    CHECK(snarkvm_record_decrypt(view_key, NULL, ciphertext_len, &json) == SNARKVM_NULL_POINTER);
+    CHECK(snarkvm_record_decrypt(view_key, ciphertext, ciphertext_len, NULL) == SNARKVM_NULL_POINTER);
+    snarkvm_string_free(NULL);
+
+    free(ciphertext);
+    return 0;
+}
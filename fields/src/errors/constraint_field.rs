@@ -15,16 +15,14 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum ConstraintFieldError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] std::io::Error),
+
     #[error("{}", _0)]
     Message(String),
 }
-
-impl From<std::io::Error> for ConstraintFieldError {
-    fn from(error: std::io::Error) -> Self {
-        ConstraintFieldError::Crate("std::io", format!("{:?}", error))
-    }
-}
@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum FieldError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
@@ -25,6 +26,9 @@ pub enum FieldError {
     #[error("Attempting to parse an invalid string into a field element")]
     InvalidString,
 
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] std::io::Error),
+
     #[error("{}", _0)]
     Message(String),
 
@@ -35,14 +39,11 @@ pub enum FieldError {
     ParsingNonDigitCharacter,
 }
 
-impl From<std::io::Error> for FieldError {
-    fn from(error: std::io::Error) -> Self {
-        FieldError::Crate("std::io", format!("{:?}", error))
-    }
-}
-
 impl From<FieldError> for std::io::Error {
     fn from(error: FieldError) -> Self {
-        std::io::Error::new(std::io::ErrorKind::Other, format!("{}", error))
+        match error {
+            FieldError::InvalidFieldElement => std::io::Error::new(std::io::ErrorKind::InvalidData, error),
+            _ => std::io::Error::new(std::io::ErrorKind::Other, error),
+        }
     }
 }
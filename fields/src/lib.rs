@@ -121,3 +121,9 @@ pub fn batch_inversion<F: Field>(v: &mut [F]) {
         tmp = new_tmp;
     }
 }
+
+/// Registers `snarkvm-fields` and the crates it depends on.
+pub fn register_capabilities(capabilities: &mut snarkvm_utilities::Capabilities) {
+    capabilities.register(snarkvm_utilities::CrateCapabilities::new("snarkvm-fields", &[]));
+    snarkvm_utilities::capabilities::register_capabilities(capabilities);
+}
@@ -334,8 +334,11 @@ macro_rules! impl_field_from_random_bytes_with_flags {
 #[macro_export]
 macro_rules! impl_additive_ops_from_ref {
     ($type: ident, $params: ident) => {
+        $crate::impl_additive_ops_from_ref!($type<P>, P: $params);
+    };
+    ($type: ty, $($generic: ident: $bound: path),+) => {
         #[allow(unused_qualifications)]
-        impl<P: $params> core::ops::Add<Self> for $type<P> {
+        impl<$($generic: $bound),+> core::ops::Add<Self> for $type {
             type Output = Self;
 
             #[inline]
@@ -347,7 +350,7 @@ macro_rules! impl_additive_ops_from_ref {
         }
 
         #[allow(unused_qualifications)]
-        impl<P: $params> core::ops::Sub<Self> for $type<P> {
+        impl<$($generic: $bound),+> core::ops::Sub<Self> for $type {
             type Output = Self;
 
             #[inline]
@@ -359,7 +362,7 @@ macro_rules! impl_additive_ops_from_ref {
         }
 
         #[allow(unused_qualifications)]
-        impl<P: $params> core::ops::Add<&&Self> for $type<P> {
+        impl<$($generic: $bound),+> core::ops::Add<&&Self> for $type {
             type Output = Self;
 
             #[inline]
@@ -371,7 +374,7 @@ macro_rules! impl_additive_ops_from_ref {
         }
 
         #[allow(unused_qualifications)]
-        impl<P: $params> core::ops::Sub<&&Self> for $type<P> {
+        impl<$($generic: $bound),+> core::ops::Sub<&&Self> for $type {
             type Output = Self;
 
             #[inline]
@@ -383,7 +386,7 @@ macro_rules! impl_additive_ops_from_ref {
         }
 
         #[allow(unused_qualifications)]
-        impl<'a, P: $params> core::ops::Add<&'a mut Self> for $type<P> {
+        impl<'a, $($generic: $bound),+> core::ops::Add<&'a mut Self> for $type {
             type Output = Self;
 
             #[inline]
@@ -395,7 +398,7 @@ macro_rules! impl_additive_ops_from_ref {
         }
 
         #[allow(unused_qualifications)]
-        impl<'a, P: $params> core::ops::Sub<&'a mut Self> for $type<P> {
+        impl<'a, $($generic: $bound),+> core::ops::Sub<&'a mut Self> for $type {
             type Output = Self;
 
             #[inline]
@@ -407,56 +410,114 @@ macro_rules! impl_additive_ops_from_ref {
         }
 
         #[allow(unused_qualifications)]
-        impl<P: $params> core::ops::AddAssign<Self> for $type<P> {
+        impl<'a, $($generic: $bound),+> core::ops::Add<$type> for &'a $type {
+            type Output = $type;
+
+            #[inline]
+            fn add(self, other: $type) -> $type {
+                let mut result = *self;
+                result.add_assign(&other);
+                result
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, 'b, $($generic: $bound),+> core::ops::Add<&'b $type> for &'a $type {
+            type Output = $type;
+
+            #[inline]
+            fn add(self, other: &'b $type) -> $type {
+                let mut result = *self;
+                result.add_assign(other);
+                result
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, $($generic: $bound),+> core::ops::Sub<$type> for &'a $type {
+            type Output = $type;
+
+            #[inline]
+            fn sub(self, other: $type) -> $type {
+                let mut result = *self;
+                result.sub_assign(&other);
+                result
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, 'b, $($generic: $bound),+> core::ops::Sub<&'b $type> for &'a $type {
+            type Output = $type;
+
+            #[inline]
+            fn sub(self, other: &'b $type) -> $type {
+                let mut result = *self;
+                result.sub_assign(other);
+                result
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, $($generic: $bound),+> core::ops::Neg for &'a $type {
+            type Output = $type;
+
+            #[inline]
+            fn neg(self) -> $type {
+                -*self
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<$($generic: $bound),+> core::ops::AddAssign<Self> for $type {
             fn add_assign(&mut self, other: Self) {
                 self.add_assign(&other)
             }
         }
 
         #[allow(unused_qualifications)]
-        impl<P: $params> core::ops::SubAssign<Self> for $type<P> {
+        impl<$($generic: $bound),+> core::ops::SubAssign<Self> for $type {
             fn sub_assign(&mut self, other: Self) {
                 self.sub_assign(&other)
             }
         }
 
         #[allow(unused_qualifications)]
-        impl<P: $params> core::ops::AddAssign<&&Self> for $type<P> {
+        impl<$($generic: $bound),+> core::ops::AddAssign<&&Self> for $type {
             fn add_assign(&mut self, other: &&Self) {
                 self.add_assign(*other)
             }
         }
 
         #[allow(unused_qualifications)]
-        impl<P: $params> core::ops::SubAssign<&&Self> for $type<P> {
+        impl<$($generic: $bound),+> core::ops::SubAssign<&&Self> for $type {
             fn sub_assign(&mut self, other: &&Self) {
                 self.sub_assign(*other)
             }
         }
 
         #[allow(unused_qualifications)]
-        impl<'a, P: $params> core::ops::AddAssign<&'a mut Self> for $type<P> {
+        impl<'a, $($generic: $bound),+> core::ops::AddAssign<&'a mut Self> for $type {
             fn add_assign(&mut self, other: &'a mut Self) {
                 self.add_assign(&*other)
             }
         }
 
         #[allow(unused_qualifications)]
-        impl<'a, P: $params> core::ops::SubAssign<&'a mut Self> for $type<P> {
+        impl<'a, $($generic: $bound),+> core::ops::SubAssign<&'a mut Self> for $type {
             fn sub_assign(&mut self, other: &'a mut Self) {
                 self.sub_assign(&*other)
             }
         }
 
         #[allow(unused_qualifications)]
-        impl<P: $params> core::iter::Sum<Self> for $type<P> {
+        impl<$($generic: $bound),+> core::iter::Sum<Self> for $type {
             fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
                 iter.fold(Self::zero(), core::ops::Add::add)
             }
         }
 
         #[allow(unused_qualifications)]
-        impl<'a, P: $params> core::iter::Sum<&'a Self> for $type<P> {
+        impl<'a, $($generic: $bound),+> core::iter::Sum<&'a Self> for $type {
             fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
                 iter.fold(Self::zero(), core::ops::Add::add)
             }
@@ -464,7 +525,7 @@ macro_rules! impl_additive_ops_from_ref {
     };
 }
 
-// Implements AddAssign on Self by deferring to an implementation on &Self
+// Implements MulAssign on Self by deferring to an implementation on &Self
 #[macro_export]
 macro_rules! impl_multiplicative_ops_from_ref {
     ($type: ident, $params: ident) => {
@@ -540,6 +601,54 @@ macro_rules! impl_multiplicative_ops_from_ref {
             }
         }
 
+        #[allow(unused_qualifications)]
+        impl<'a, P: $params> core::ops::Mul<$type<P>> for &'a $type<P> {
+            type Output = $type<P>;
+
+            #[inline]
+            fn mul(self, other: $type<P>) -> $type<P> {
+                let mut result = *self;
+                result.mul_assign(&other);
+                result
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, 'b, P: $params> core::ops::Mul<&'b $type<P>> for &'a $type<P> {
+            type Output = $type<P>;
+
+            #[inline]
+            fn mul(self, other: &'b $type<P>) -> $type<P> {
+                let mut result = *self;
+                result.mul_assign(other);
+                result
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, P: $params> core::ops::Div<$type<P>> for &'a $type<P> {
+            type Output = $type<P>;
+
+            #[inline]
+            fn div(self, other: $type<P>) -> $type<P> {
+                let mut result = *self;
+                result.div_assign(&other);
+                result
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, 'b, P: $params> core::ops::Div<&'b $type<P>> for &'a $type<P> {
+            type Output = $type<P>;
+
+            #[inline]
+            fn div(self, other: &'b $type<P>) -> $type<P> {
+                let mut result = *self;
+                result.div_assign(other);
+                result
+            }
+        }
+
         #[allow(unused_qualifications)]
         impl<P: $params> core::ops::MulAssign<Self> for $type<P> {
             fn mul_assign(&mut self, other: Self) {
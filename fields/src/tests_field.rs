@@ -14,10 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{traits::FftParameters, FftField, Field, LegendreSymbol, PrimeField, SquareRootField};
+use crate::{traits::FftParameters, FftField, Field, FieldParameters, LegendreSymbol, PrimeField, SquareRootField};
 use snarkvm_utilities::{
-    io::Cursor,
+    biginteger::BigInteger,
+    bytes::ToBytes,
+    io::{Cursor, ErrorKind},
     serialize::{CanonicalDeserialize, CanonicalSerialize, Flags, SWFlags},
+    to_bytes,
 };
 
 use rand::{Rng, SeedableRng};
@@ -334,6 +337,49 @@ pub fn field_test<F: Field>(a: F, b: F) {
     random_field_tests::<F>();
 }
 
+/// Checks that the arithmetic operators of a concrete field type accept every combination
+/// of owned and borrowed operands, and that all combinations agree with one another.
+#[macro_export]
+macro_rules! field_ref_ops_test {
+    ($a:expr, $b:expr) => {{
+        let a = $a;
+        let b = $b;
+
+        $crate::field_ref_ops_test!(@binary a, b, +, +=);
+        $crate::field_ref_ops_test!(@binary a, b, -, -=);
+        $crate::field_ref_ops_test!(@binary a, b, *, *=);
+        $crate::field_ref_ops_test!(@binary a, b, /, /=);
+
+        assert_eq!(-a, -&a);
+    }};
+    (@binary $a:ident, $b:ident, $op:tt, $op_assign:tt) => {{
+        let expected = $a $op $b;
+        let mut b_mut = $b;
+
+        assert_eq!(expected, $a $op &$b);
+        assert_eq!(expected, $a $op &&$b);
+        assert_eq!(expected, $a $op &mut b_mut);
+        assert_eq!(expected, &$a $op $b);
+        assert_eq!(expected, &$a $op &$b);
+
+        let mut result = $a;
+        result $op_assign $b;
+        assert_eq!(expected, result);
+
+        let mut result = $a;
+        result $op_assign &$b;
+        assert_eq!(expected, result);
+
+        let mut result = $a;
+        result $op_assign &&$b;
+        assert_eq!(expected, result);
+
+        let mut result = $a;
+        result $op_assign &mut b_mut;
+        assert_eq!(expected, result);
+    }};
+}
+
 pub fn fft_field_test<F: PrimeField + FftField>() {
     let modulus_minus_one_div_two = F::from_repr(F::modulus_minus_one_div_two()).unwrap();
     assert!(!modulus_minus_one_div_two.is_zero());
@@ -374,6 +420,33 @@ pub fn primefield_test<F: PrimeField>() {
     assert_eq!(F::from_str(&two.to_string()).ok().unwrap(), two);
 
     fft_field_test::<F>();
+    canonical_encoding_test::<F>();
+}
+
+/// Checks that `FromBytes` accepts exactly the encodings of integers less than the modulus.
+pub fn canonical_encoding_test<F: PrimeField>() {
+    let modulus = <F as PrimeField>::Parameters::MODULUS;
+    let mut modulus_plus_one = modulus;
+    modulus_plus_one.add_nocarry(&F::BigInteger::from(1));
+    let mut modulus_minus_one = modulus;
+    modulus_minus_one.sub_noborrow(&F::BigInteger::from(1));
+
+    // The modulus minus one is the largest canonical encoding.
+    let bytes = to_bytes![modulus_minus_one].unwrap();
+    assert_eq!(F::read(&bytes[..]).unwrap(), -F::one());
+    assert_eq!(to_bytes![-F::one()].unwrap(), bytes);
+
+    // The modulus and the modulus plus one encode zero and one, but are rejected.
+    for (integer, element) in [(modulus, F::zero()), (modulus_plus_one, F::one())].iter() {
+        let bytes = to_bytes![integer].unwrap();
+        assert_eq!(F::read(&bytes[..]).unwrap_err().kind(), ErrorKind::InvalidData);
+        assert!(<F as CanonicalDeserialize>::deserialize(&mut &bytes[..]).is_err());
+        assert_eq!(F::from_bytes_unchecked_reduce(&bytes), *element);
+    }
+
+    // The largest integer of the encoding size is rejected too.
+    let bytes = vec![u8::MAX; to_bytes![modulus].unwrap().len()];
+    assert_eq!(F::read(&bytes[..]).unwrap_err().kind(), ErrorKind::InvalidData);
 }
 
 pub fn sqrt_field_test<F: SquareRootField>(elem: F) {
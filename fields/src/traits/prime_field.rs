@@ -37,6 +37,20 @@ pub trait PrimeField: FftField<FftParameters = <Self as PrimeField>::Parameters>
     /// Returns the underlying raw representation of the prime field element.
     fn into_repr_raw(&self) -> Self::BigInteger;
 
+    /// Returns the little-endian integer of `bytes`, reduced modulo the characteristic.
+    ///
+    /// `FromBytes` rejects encodings of integers that are not less than the characteristic, so
+    /// every element has a single encoding. This accepts any bytes instead, and distinct byte
+    /// strings may map to the same element, so it must only be used to derive field elements
+    /// from bytes such as hash outputs, never to deserialize them.
+    fn from_bytes_unchecked_reduce(bytes: &[u8]) -> Self {
+        let base = Self::from(256u64);
+        bytes
+            .iter()
+            .rev()
+            .fold(Self::zero(), |result, byte| result * base + Self::from(*byte))
+    }
+
     /// Returns the field size in bits.
     fn size_in_bits() -> usize {
         Self::Parameters::MODULUS_BITS as usize
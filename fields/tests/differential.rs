@@ -0,0 +1,530 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Differential tests of `Fp256` and `Fp384` arithmetic against a `num-bigint` oracle.
+//!
+//! The algebraic identities in `tests_field` hold even when a carry is dropped on both sides
+//! of an equation, so these tests instead compare every result with the same operation
+//! computed on arbitrary-precision integers reduced modulo the field characteristic.
+//!
+//! The operands are drawn from random elements, from a corpus of values on carry boundaries
+//! (in both the canonical and the Montgomery domain), and from all pairs of corpus values.
+//! Set `SNARKVM_EXTENDED_FIELD_TESTS` to run many more random iterations.
+
+use snarkvm_fields::{FftParameters, FieldParameters, Fp256, Fp256Parameters, Fp384, Fp384Parameters, PrimeField};
+use snarkvm_utilities::{
+    biginteger::{BigInteger256, BigInteger384},
+    bytes::ToBytes,
+    rand::UniformRand,
+    to_bytes,
+};
+
+use num_bigint::BigUint;
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use std::env;
+
+/// The environment variable that enables the extended iteration counts.
+const EXTENDED_TESTS_VARIABLE: &str = "SNARKVM_EXTENDED_FIELD_TESTS";
+
+/// The number of random iterations in the default test suite.
+const DEFAULT_ITERATIONS: usize = 1_000;
+
+/// The number of random iterations in the extended mode.
+const EXTENDED_ITERATIONS: usize = 200_000;
+
+/// The BLS12-377 scalar field, a 253-bit field on four limbs.
+struct Fr256Parameters;
+
+type Fr256 = Fp256<Fr256Parameters>;
+
+impl Fp256Parameters for Fr256Parameters {}
+
+impl FftParameters for Fr256Parameters {
+    type BigInteger = BigInteger256;
+
+    const TWO_ADICITY: u32 = 47;
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger256 = BigInteger256([
+        0x3c3d3ca739381fb2,
+        0x9a14cda3ec99772b,
+        0xd7aacc7c59724826,
+        0xd1ba211c5cc349c,
+    ]);
+}
+
+impl FieldParameters for Fr256Parameters {
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    const GENERATOR: BigInteger256 = BigInteger256([
+        2984901390528151251u64,
+        10561528701063790279u64,
+        5476750214495080041u64,
+        898978044469942640u64,
+    ]);
+    const INV: u64 = 725501752471715839u64;
+    const MODULUS: BigInteger256 = BigInteger256([
+        725501752471715841u64,
+        6461107452199829505u64,
+        6968279316240510977u64,
+        1345280370688173398u64,
+    ]);
+    const MODULUS_BITS: u32 = 253;
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger256 = BigInteger256([
+        0x8508c00000000000,
+        0xacd53b7f68000000,
+        0x305a268f2e1bd800,
+        0x955b2af4d1652ab,
+    ]);
+    const R: BigInteger256 = BigInteger256([
+        9015221291577245683u64,
+        8239323489949974514u64,
+        1646089257421115374u64,
+        958099254763297437u64,
+    ]);
+    const R2: BigInteger256 = BigInteger256([
+        2726216793283724667u64,
+        14712177743343147295u64,
+        12091039717619697043u64,
+        81024008013859129u64,
+    ]);
+    const REPR_SHAVE_BITS: u32 = 3;
+    const T: BigInteger256 = BigInteger256([0xedfda00000021423, 0x9a3cb86f6002b354, 0xcabd34594aacc168, 0x2556]);
+    const T_MINUS_ONE_DIV_TWO: BigInteger256 =
+        BigInteger256([0x76fed00000010a11, 0x4d1e5c37b00159aa, 0x655e9a2ca55660b4, 0x12ab]);
+}
+
+/// The BLS12-377 base field, a 377-bit field on six limbs.
+struct Fq384Parameters;
+
+type Fq384 = Fp384<Fq384Parameters>;
+
+impl Fp384Parameters for Fq384Parameters {}
+
+impl FftParameters for Fq384Parameters {
+    type BigInteger = BigInteger384;
+
+    const TWO_ADICITY: u32 = 46u32;
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger384 = BigInteger384([
+        2022196864061697551u64,
+        17419102863309525423u64,
+        8564289679875062096u64,
+        17152078065055548215u64,
+        17966377291017729567u64,
+        68610905582439508u64,
+    ]);
+}
+
+impl FieldParameters for Fq384Parameters {
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    const GENERATOR: BigInteger384 = BigInteger384([
+        0xfc0b8000000002fa,
+        0x97d39cf6e000018b,
+        0x2072420fbfa05044,
+        0xcbbcbd50d97c3802,
+        0xbaf1ec35813f9eb,
+        0x9974a2c0945ad2,
+    ]);
+    const INV: u64 = 9586122913090633727u64;
+    const MODULUS: BigInteger384 = BigInteger384([
+        0x8508c00000000001,
+        0x170b5d4430000000,
+        0x1ef3622fba094800,
+        0x1a22d9f300f5138f,
+        0xc63b05c06ca1493b,
+        0x1ae3a4617c510ea,
+    ]);
+    const MODULUS_BITS: u32 = 377;
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger384 = BigInteger384([
+        0x4284600000000000,
+        0xb85aea218000000,
+        0x8f79b117dd04a400,
+        0x8d116cf9807a89c7,
+        0x631d82e03650a49d,
+        0xd71d230be28875,
+    ]);
+    const R: BigInteger384 = BigInteger384([
+        202099033278250856u64,
+        5854854902718660529u64,
+        11492539364873682930u64,
+        8885205928937022213u64,
+        5545221690922665192u64,
+        39800542322357402u64,
+    ]);
+    const R2: BigInteger384 = BigInteger384([
+        0xb786686c9400cd22,
+        0x329fcaab00431b1,
+        0x22a5f11162d6b46d,
+        0xbfdf7d03827dc3ac,
+        0x837e92f041790bf9,
+        0x6dfccb1e914b88,
+    ]);
+    const REPR_SHAVE_BITS: u32 = 7;
+    const T: BigInteger384 = BigInteger384([
+        0x7510c00000021423,
+        0x88bee82520005c2d,
+        0x67cc03d44e3c7bcd,
+        0x1701b28524ec688b,
+        0xe9185f1443ab18ec,
+        0x6b8,
+    ]);
+    const T_MINUS_ONE_DIV_TWO: BigInteger384 = BigInteger384([
+        0xba88600000010a11,
+        0xc45f741290002e16,
+        0xb3e601ea271e3de6,
+        0xb80d94292763445,
+        0x748c2f8a21d58c76,
+        0x35c,
+    ]);
+}
+
+/// Returns the number of random iterations to run, which is larger in the extended mode.
+fn iterations() -> usize {
+    match env::var_os(EXTENDED_TESTS_VARIABLE) {
+        Some(_) => EXTENDED_ITERATIONS,
+        None => DEFAULT_ITERATIONS,
+    }
+}
+
+/// Returns the integer represented by the little-endian limbs.
+fn limbs_to_biguint(limbs: &[u64]) -> BigUint {
+    let bytes: Vec<u8> = limbs.iter().flat_map(|limb| limb.to_le_bytes().to_vec()).collect();
+    BigUint::from_bytes_le(&bytes)
+}
+
+/// A modular-arithmetic oracle for the prime field `F`.
+struct Oracle<F: PrimeField> {
+    modulus: BigUint,
+    /// The number of bits in the limbs of `F::BigInteger`.
+    repr_bits: usize,
+    /// The Montgomery constant `2^repr_bits mod p`.
+    r: BigUint,
+    /// The inverse of `r` modulo `p`.
+    r_inverse: BigUint,
+    _field: std::marker::PhantomData<F>,
+}
+
+impl<F: PrimeField> Oracle<F> {
+    fn new() -> Self {
+        let modulus = limbs_to_biguint(F::Parameters::MODULUS.as_ref());
+        let repr_bits = 64 * F::Parameters::MODULUS.as_ref().len();
+        let r = (BigUint::from(1u32) << repr_bits) % &modulus;
+        let r_inverse = r.modpow(&(&modulus - 2u32), &modulus);
+
+        Self {
+            modulus,
+            repr_bits,
+            r,
+            r_inverse,
+            _field: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns `F::BigInteger` holding `value`, which must fit in the limbs.
+    fn repr(&self, value: &BigUint) -> F::BigInteger {
+        assert!(value.bits() as usize <= self.repr_bits);
+
+        let mut bytes = value.to_bytes_le();
+        bytes.resize(self.repr_bits / 8, 0);
+
+        let mut repr = F::BigInteger::default();
+        for (limb, chunk) in repr.as_mut().iter_mut().zip(bytes.chunks(8)) {
+            let mut limb_bytes = [0u8; 8];
+            limb_bytes.copy_from_slice(chunk);
+            *limb = u64::from_le_bytes(limb_bytes);
+        }
+        repr
+    }
+
+    /// Returns the field element with the canonical value `value`, which must be reduced.
+    fn element(&self, value: &BigUint) -> F {
+        F::from_repr(self.repr(value)).expect("the value is reduced")
+    }
+
+    /// Returns the canonical value of the field element.
+    fn value(&self, element: &F) -> BigUint {
+        limbs_to_biguint(element.into_repr().as_ref())
+    }
+
+    /// Returns `value mod p`.
+    fn reduce(&self, value: &BigUint) -> BigUint {
+        value % &self.modulus
+    }
+
+    /// Returns `a - b mod p` for reduced `a` and `b`.
+    fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        self.reduce(&(a + &self.modulus - b))
+    }
+
+    /// Returns the inverse of a reduced non-zero `a`, by Fermat's little theorem.
+    fn inverse(&self, a: &BigUint) -> BigUint {
+        a.modpow(&(&self.modulus - 2u32), &self.modulus)
+    }
+
+    /// Returns the little-endian encoding of a reduced value, padded to the width of the limbs.
+    fn to_bytes(&self, value: &BigUint) -> Vec<u8> {
+        let mut bytes = value.to_bytes_le();
+        bytes.resize(self.repr_bits / 8, 0);
+        bytes
+    }
+
+    /// Returns the corpus of reduced values on carry boundaries.
+    ///
+    /// For every canonical value `v` of interest, the corpus also holds `v * R^-1 mod p`,
+    /// the element whose Montgomery representation is `v`, because the limb arithmetic
+    /// operates on that representation.
+    fn carry_edge_corpus(&self) -> Vec<BigUint> {
+        let p = &self.modulus;
+        let one = BigUint::from(1u32);
+        let num_limbs = self.repr_bits / 64;
+
+        let mut values = vec![
+            BigUint::from(0u32),
+            one.clone(),
+            BigUint::from(2u32),
+            p - 1u32,
+            p - 2u32,
+            (p - 1u32) >> 1,
+            (p + 1u32) >> 1,
+            self.r.clone(),
+            self.reduce(&(&self.r * &self.r)),
+            self.r_inverse.clone(),
+            p - &self.r,
+            // The largest value below the modulus whose bit length is `MODULUS_BITS - 1`.
+            (&one << (p.bits() - 1)) - 1u32,
+            &one << (p.bits() - 1),
+        ];
+
+        for k in 0..=num_limbs {
+            let power = &one << (64 * k);
+            // 2^(64k) - 1 is a run of `k` all-ones limbs.
+            if k > 0 {
+                values.push(&power - 1u32);
+            }
+            values.push(power.clone());
+            values.push(&power + 1u32);
+        }
+
+        for k in 0..num_limbs {
+            // A single all-ones limb, and all limbs set to all-ones except limb `k`.
+            let limb = BigUint::from(u64::MAX) << (64 * k);
+            values.push(limb.clone());
+            values.push(((&one << self.repr_bits) - 1u32) - limb);
+
+            // The modulus with limb `k` replaced by all-ones, and by zero.
+            let mut limbs = F::Parameters::MODULUS.as_ref().to_vec();
+            limbs[k] = u64::MAX;
+            values.push(limbs_to_biguint(&limbs));
+            limbs[k] = 0;
+            values.push(limbs_to_biguint(&limbs));
+        }
+
+        let mut corpus = Vec::with_capacity(2 * values.len());
+        for value in values {
+            let value = self.reduce(&value);
+            corpus.push(self.reduce(&(&value * &self.r_inverse)));
+            corpus.push(value);
+        }
+        corpus.sort();
+        corpus.dedup();
+        corpus
+    }
+
+    /// Returns a reduced value whose limbs are each drawn from zero, all-ones, or uniform bits,
+    /// which exercises long carry chains far more often than uniform sampling.
+    fn sparse_limb_value<R: Rng>(&self, rng: &mut R) -> BigUint {
+        let limbs: Vec<u64> = (0..self.repr_bits / 64)
+            .map(|_| match rng.gen_range(0..3) {
+                0 => 0,
+                1 => u64::MAX,
+                _ => rng.gen(),
+            })
+            .collect();
+        self.reduce(&limbs_to_biguint(&limbs))
+    }
+
+    /// Checks the constants of the field parameters against the modulus.
+    fn check_parameters(&self) {
+        let r = limbs_to_biguint(F::Parameters::R.as_ref());
+        let r2 = limbs_to_biguint(F::Parameters::R2.as_ref());
+        assert_eq!(r, self.r, "R is not 2^{} mod p", self.repr_bits);
+        assert_eq!(r2, self.reduce(&(&self.r * &self.r)), "R2 is not R^2 mod p");
+
+        // INV is -p^-1 mod 2^64, so p * INV + 1 vanishes modulo 2^64.
+        let word = BigUint::from(1u32) << 64;
+        let inv = BigUint::from(F::Parameters::INV);
+        assert_eq!(
+            (&self.modulus * inv + 1u32) % word,
+            BigUint::from(0u32),
+            "INV is not -p^-1 mod 2^64"
+        );
+
+        assert_eq!(self.modulus.bits() as u32, F::Parameters::MODULUS_BITS);
+        assert_eq!(
+            limbs_to_biguint(F::Parameters::MODULUS_MINUS_ONE_DIV_TWO.as_ref()),
+            (&self.modulus - 1u32) >> 1
+        );
+    }
+
+    /// Checks the unary operations on `a` against the oracle.
+    fn check_unary(&self, a: &BigUint) {
+        let element = self.element(a);
+
+        assert_eq!(&self.value(&element), a, "into_repr({})", a);
+        assert_eq!(to_bytes![element].unwrap(), self.to_bytes(a), "to_bytes({})", a);
+        assert_eq!(self.value(&-element), self.sub(&BigUint::from(0u32), a), "-{}", a);
+        assert_eq!(self.value(&element.double()), self.reduce(&(a << 1)), "2 * {}", a);
+        assert_eq!(self.value(&element.square()), self.reduce(&(a * a)), "{}^2", a);
+
+        match element.inverse() {
+            Some(inverse) => assert_eq!(self.value(&inverse), self.inverse(a), "{}^-1", a),
+            None => assert_eq!(a, &BigUint::from(0u32), "{} has no inverse", a),
+        }
+    }
+
+    /// Checks the binary operations on `a` and `b` against the oracle.
+    fn check_binary(&self, a: &BigUint, b: &BigUint) {
+        let (x, y) = (self.element(a), self.element(b));
+
+        assert_eq!(self.value(&(x + y)), self.reduce(&(a + b)), "{} + {}", a, b);
+        assert_eq!(self.value(&(x - y)), self.sub(a, b), "{} - {}", a, b);
+        assert_eq!(self.value(&(x * y)), self.reduce(&(a * b)), "{} * {}", a, b);
+    }
+
+    /// Checks exponentiation of `a` by the exponent `e`, given as an integer of at most `repr_bits` bits.
+    fn check_pow(&self, a: &BigUint, e: &BigUint) {
+        let element = self.element(a);
+        let exponent = self.repr(e);
+
+        assert_eq!(
+            self.value(&element.pow(exponent)),
+            a.modpow(e, &self.modulus),
+            "{}^{}",
+            a,
+            e
+        );
+    }
+
+    /// Checks that `from_repr` accepts exactly the reduced values.
+    fn check_from_repr(&self, value: &BigUint) {
+        let repr = self.repr(value);
+        match F::from_repr(repr) {
+            Some(element) => {
+                assert!(value < &self.modulus, "from_repr accepted the unreduced {}", value);
+                assert_eq!(&self.value(&element), value);
+                assert_eq!(element.into_repr(), repr);
+            }
+            None => assert!(value >= &self.modulus, "from_repr rejected the reduced {}", value),
+        }
+    }
+}
+
+/// Runs every differential check on the field `F`.
+fn differential_test<F: PrimeField>(seed: u64) {
+    let oracle = Oracle::<F>::new();
+    oracle.check_parameters();
+
+    let rng = &mut XorShiftRng::seed_from_u64(seed);
+    let corpus = oracle.carry_edge_corpus();
+
+    // (a) Random operands, half uniform and half with sparse limbs.
+    for i in 0..iterations() {
+        let (a, b) = match i % 2 {
+            0 => (oracle.value(&F::rand(rng)), oracle.value(&F::rand(rng))),
+            _ => (oracle.sparse_limb_value(rng), oracle.sparse_limb_value(rng)),
+        };
+        oracle.check_unary(&a);
+        oracle.check_binary(&a, &b);
+
+        if i % 16 == 0 {
+            let exponent = limbs_to_biguint(F::BigInteger::rand(rng).as_ref());
+            oracle.check_pow(&a, &exponent);
+        }
+    }
+
+    // (b) The carry-edge corpus.
+    for a in &corpus {
+        oracle.check_unary(a);
+        oracle.check_pow(a, &(&oracle.modulus - 1u32));
+        oracle.check_pow(a, &(&oracle.modulus - 2u32));
+    }
+
+    // (c) Pairwise operations over the corpus, which is also used as a set of exponents.
+    for a in &corpus {
+        for b in &corpus {
+            oracle.check_binary(a, b);
+        }
+    }
+    for a in corpus.iter().step_by(3) {
+        for e in &corpus {
+            oracle.check_pow(a, e);
+        }
+    }
+}
+
+/// Checks the canonical encoding and `from_repr` on both sides of the modulus.
+fn repr_round_trip_test<F: PrimeField>(seed: u64) {
+    let oracle = Oracle::<F>::new();
+    let rng = &mut XorShiftRng::seed_from_u64(seed);
+    let p = &oracle.modulus;
+    let max_repr = (BigUint::from(1u32) << oracle.repr_bits) - 1u32;
+
+    let mut values = oracle.carry_edge_corpus();
+    values.extend(vec![p.clone(), p + 1u32, (p << 1) - 1u32, max_repr.clone()]);
+    for _ in 0..iterations() {
+        values.push(limbs_to_biguint(F::BigInteger::rand(rng).as_ref()) & &max_repr);
+    }
+
+    for value in &values {
+        oracle.check_from_repr(value);
+    }
+}
+
+#[test]
+fn fp256_matches_oracle() {
+    differential_test::<Fr256>(1231275789);
+}
+
+#[test]
+fn fp384_matches_oracle() {
+    differential_test::<Fq384>(1231275789);
+}
+
+#[test]
+fn fp256_repr_matches_oracle() {
+    repr_round_trip_test::<Fr256>(1231275789);
+}
+
+#[test]
+fn fp384_repr_matches_oracle() {
+    repr_round_trip_test::<Fq384>(1231275789);
+}
+
+#[test]
+fn carry_edge_corpus_is_reduced_and_covers_the_boundaries() {
+    let oracle = Oracle::<Fq384>::new();
+    let corpus = oracle.carry_edge_corpus();
+
+    assert!(corpus.iter().all(|value| value < &oracle.modulus));
+    for value in &[
+        BigUint::from(0u32),
+        BigUint::from(1u32),
+        &oracle.modulus - 1u32,
+        &oracle.modulus - 2u32,
+        oracle.r.clone(),
+        BigUint::from(u64::MAX),
+    ] {
+        assert!(corpus.contains(value), "the corpus is missing {}", value);
+    }
+}
@@ -14,10 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::{
-    borrow::{Borrow, Cow},
-    marker::PhantomData,
-};
+use std::{borrow::Borrow, marker::PhantomData};
 
 use snarkvm_algorithms::{
     commitment::{PedersenCommitment, PedersenCommitmentParameters, PedersenCompressedCommitment},
@@ -29,6 +26,7 @@ use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
 use snarkvm_utilities::{bytes::ToBytes, to_bytes};
 
 use crate::{
+    algorithms::crh::pedersen::pad_input_and_bitify,
     integers::uint::UInt8,
     traits::{
         algorithms::CommitmentGadget,
@@ -132,21 +130,10 @@ impl<F: PrimeField, G: Group, GG: GroupGadget<G, F>, S: PedersenSize> Commitment
         input: &[UInt8],
         randomness: &Self::RandomnessGadget,
     ) -> Result<Self::OutputGadget, SynthesisError> {
-        assert!((input.len() * 8) <= (S::WINDOW_SIZE * S::NUM_WINDOWS));
-
-        let mut padded_input = Cow::Borrowed(input);
-        // Pad if input length is less than `S::WINDOW_SIZE * S::NUM_WINDOWS`.
-        if (input.len() * 8) < S::WINDOW_SIZE * S::NUM_WINDOWS {
-            padded_input
-                .to_mut()
-                .resize((S::WINDOW_SIZE * S::NUM_WINDOWS) / 8, UInt8::constant(0u8))
-        }
-
-        assert_eq!(padded_input.len() * 8, S::WINDOW_SIZE * S::NUM_WINDOWS);
         assert_eq!(parameters.parameters.bases.len(), S::NUM_WINDOWS);
 
         // Allocate new variable for commitment output.
-        let input_in_bits: Vec<_> = padded_input.iter().flat_map(|byte| byte.to_bits_le()).collect();
+        let input_in_bits = pad_input_and_bitify::<S>(input)?;
         let input_in_bits = input_in_bits.chunks(S::WINDOW_SIZE);
         let mut result = GG::multi_scalar_multiplication(cs.ns(|| "msm"), &parameters.parameters.bases, input_in_bits)?;
 
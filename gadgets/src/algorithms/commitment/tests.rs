@@ -128,3 +128,54 @@ fn pedersen_commitment_gadget_test() {
     assert_eq!(native_output.y, output_gadget.y.get_value().unwrap());
     assert!(cs.is_satisfied());
 }
+
+#[test]
+fn pedersen_commitment_gadget_non_byte_aligned_window_test() {
+    // 35 bits of capacity, which is not a multiple of 8.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub(super) struct UnalignedSize;
+
+    impl PedersenSize for UnalignedSize {
+        const NUM_WINDOWS: usize = 5;
+        const WINDOW_SIZE: usize = 7;
+    }
+
+    type TestCommitment = PedersenCommitment<EdwardsProjective, UnalignedSize>;
+    type TestCommitmentGadget = PedersenCommitmentGadget<EdwardsProjective, Fq, EdwardsBlsGadget>;
+
+    let rng = &mut thread_rng();
+    let commitment = TestCommitment::setup(rng);
+
+    for length in 0..=4 {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let input: Vec<u8> = (0..length).map(|_| rng.gen()).collect();
+        let randomness = Fr::rand(rng);
+        let native_output = commitment.commit(&input, &randomness).unwrap().into_affine();
+
+        let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), &input).unwrap();
+        let randomness_gadget =
+            <TestCommitmentGadget as CommitmentGadget<TestCommitment, Fq>>::RandomnessGadget::alloc(
+                &mut cs.ns(|| "randomness_gadget"),
+                || Ok(&randomness),
+            )
+            .unwrap();
+        let parameters_gadget =
+            <TestCommitmentGadget as CommitmentGadget<TestCommitment, Fq>>::ParametersGadget::alloc(
+                &mut cs.ns(|| "parameters_gadget"),
+                || Ok(&commitment.parameters),
+            )
+            .unwrap();
+        let output_gadget = <TestCommitmentGadget as CommitmentGadget<TestCommitment, Fq>>::check_commitment_gadget(
+            &mut cs.ns(|| "commitment_gadget"),
+            &parameters_gadget,
+            &input_bytes,
+            &randomness_gadget,
+        )
+        .unwrap();
+
+        assert_eq!(native_output.x, output_gadget.x.get_value().unwrap());
+        assert_eq!(native_output.y, output_gadget.y.get_value().unwrap());
+        assert!(cs.is_satisfied());
+    }
+}
@@ -15,13 +15,12 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    algorithms::crh::pedersen::PedersenCRHParametersGadget,
+    algorithms::crh::pedersen::{pad_input_and_bitify, PedersenCRHParametersGadget},
     bits::Boolean,
     integers::uint::UInt8,
     traits::{
         algorithms::CRHGadget,
         curves::{CompressedGroupGadget, GroupGadget},
-        integers::integer::Integer,
     },
 };
 use snarkvm_algorithms::crh::{
@@ -54,16 +53,8 @@ impl<F: Field, G: Group, GG: GroupGadget<G, F>, S: PedersenSize> CRHGadget<BoweH
         parameters: &Self::ParametersGadget,
         input: Vec<UInt8>,
     ) -> Result<Self::OutputGadget, SynthesisError> {
-        // Pad the input bytes
-        let mut padded_input_bytes = input;
-        padded_input_bytes.resize(S::WINDOW_SIZE * S::NUM_WINDOWS / 8, UInt8::constant(0u8));
-        assert_eq!(padded_input_bytes.len() * 8, S::WINDOW_SIZE * S::NUM_WINDOWS);
-
-        // Pad the input bits if it is not the current length.
-        let mut input_in_bits: Vec<_> = padded_input_bytes
-            .into_iter()
-            .flat_map(|byte| byte.to_bits_le())
-            .collect();
+        // Pad the input bits to a whole number of chunks.
+        let mut input_in_bits = pad_input_and_bitify::<S>(&input)?;
         if (input_in_bits.len()) % BOWE_HOPWOOD_CHUNK_SIZE != 0 {
             let current_length = input_in_bits.len();
             let target_length = current_length + BOWE_HOPWOOD_CHUNK_SIZE - current_length % BOWE_HOPWOOD_CHUNK_SIZE;
@@ -20,5 +20,14 @@ pub use bowe_hopwood_pedersen::*;
 pub mod pedersen;
 pub use pedersen::*;
 
+pub mod poseidon;
+pub use poseidon::*;
+
+pub mod poseidon_sponge;
+pub use poseidon_sponge::*;
+
+pub mod sha256;
+pub use sha256::*;
+
 #[cfg(test)]
 pub mod tests;
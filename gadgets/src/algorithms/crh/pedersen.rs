@@ -14,9 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::{borrow::Borrow, marker::PhantomData};
+use std::{
+    borrow::Borrow,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
 
-use snarkvm_algorithms::crh::{PedersenCRH, PedersenCRHParameters, PedersenCompressedCRH, PedersenSize};
+use snarkvm_algorithms::{
+    crh::{variable_length_prefix, PedersenCRH, PedersenCRHParameters, PedersenCompressedCRH, PedersenSize},
+    traits::CRHParameters,
+};
 use snarkvm_curves::traits::{Group, ProjectiveCurve};
 use snarkvm_fields::{Field, PrimeField};
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
@@ -32,16 +39,56 @@ use crate::{
     },
 };
 
-#[derive(Clone, PartialEq, Eq)]
+/// The parameters gadget of the Pedersen CRHs. As the bases are constants of the circuit,
+/// parameters gadgets are equal and hash alike if and only if their native parameters have the same digest.
+#[derive(Clone)]
 pub struct PedersenCRHParametersGadget<G: Group, S: PedersenSize, F: Field, GG: GroupGadget<G, F>> {
     pub(crate) parameters: PedersenCRHParameters<G, S>,
+    digest: [u8; 32],
     _group: PhantomData<GG>,
     _engine: PhantomData<F>,
 }
 
+impl<G: Group, S: PedersenSize, F: Field, GG: GroupGadget<G, F>> PedersenCRHParametersGadget<G, S, F, GG> {
+    fn new(parameters: PedersenCRHParameters<G, S>) -> Self {
+        Self {
+            digest: parameters.digest(),
+            parameters,
+            _group: PhantomData,
+            _engine: PhantomData,
+        }
+    }
+}
+
+impl<G: Group, S: PedersenSize, F: Field, GG: GroupGadget<G, F>> PartialEq for PedersenCRHParametersGadget<G, S, F, GG> {
+    fn eq(&self, other: &Self) -> bool {
+        self.digest == other.digest
+    }
+}
+
+impl<G: Group, S: PedersenSize, F: Field, GG: GroupGadget<G, F>> Eq for PedersenCRHParametersGadget<G, S, F, GG> {}
+
+impl<G: Group, S: PedersenSize, F: Field, GG: GroupGadget<G, F>> Hash for PedersenCRHParametersGadget<G, S, F, GG> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.digest.hash(state);
+    }
+}
+
 impl<G: Group, S: PedersenSize, F: Field, GG: GroupGadget<G, F>> AllocGadget<PedersenCRHParameters<G, S>, F>
     for PedersenCRHParametersGadget<G, S, F, GG>
 {
+    /// The bases are always kept as constants, so this is equivalent to `alloc`.
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<PedersenCRHParameters<G, S>>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self::new(value_gen()?.borrow().clone()))
+    }
+
     fn alloc<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<PedersenCRHParameters<G, S>>,
@@ -50,11 +97,7 @@ impl<G: Group, S: PedersenSize, F: Field, GG: GroupGadget<G, F>> AllocGadget<Ped
         _cs: CS,
         value_gen: Fn,
     ) -> Result<Self, SynthesisError> {
-        Ok(PedersenCRHParametersGadget {
-            parameters: value_gen()?.borrow().clone(),
-            _group: PhantomData,
-            _engine: PhantomData,
-        })
+        Ok(Self::new(value_gen()?.borrow().clone()))
     }
 
     fn alloc_input<
@@ -65,11 +108,7 @@ impl<G: Group, S: PedersenSize, F: Field, GG: GroupGadget<G, F>> AllocGadget<Ped
         _cs: CS,
         value_gen: Fn,
     ) -> Result<Self, SynthesisError> {
-        Ok(PedersenCRHParametersGadget {
-            parameters: value_gen()?.borrow().clone(),
-            _group: PhantomData,
-            _engine: PhantomData,
-        })
+        Ok(Self::new(value_gen()?.borrow().clone()))
     }
 }
 
@@ -93,17 +132,54 @@ impl<F: Field, G: Group, GG: GroupGadget<G, F>, S: PedersenSize> CRHGadget<Peder
     ) -> Result<Self::OutputGadget, SynthesisError> {
         assert_eq!(parameters.parameters.bases.len(), S::NUM_WINDOWS);
         // Pad the input if it is not the correct length.
-        let input_in_bits = pad_input_and_bitify::<S>(input);
+        let input_in_bits = pad_input_and_bitify::<S>(&input)?;
+
+        GG::multi_scalar_multiplication(cs, &parameters.parameters.bases, input_in_bits.chunks(S::WINDOW_SIZE))
+    }
+}
+
+impl<F: Field, G: Group, GG: GroupGadget<G, F>> PedersenCRHGadget<G, F, GG> {
+    /// Evaluates `PedersenCRH::hash_variable_length` on the given input. As the length of the input
+    /// is fixed by the circuit, its bit-length prefix is a constant. An input that does not fit in the
+    /// windows after its prefix is rejected.
+    pub fn check_evaluation_gadget_variable_length<S: PedersenSize, CS: ConstraintSystem<F>>(
+        cs: CS,
+        parameters: &PedersenCRHParametersGadget<G, S, F, GG>,
+        input: Vec<UInt8>,
+    ) -> Result<GG, SynthesisError> {
+        assert_eq!(parameters.parameters.bases.len(), S::NUM_WINDOWS);
+        let input_in_bits = pad_variable_length_input_and_bitify::<S>(&input)?;
 
         GG::multi_scalar_multiplication(cs, &parameters.parameters.bases, input_in_bits.chunks(S::WINDOW_SIZE))
     }
 }
 
-fn pad_input_and_bitify<S: PedersenSize>(input: Vec<UInt8>) -> Vec<Boolean> {
-    let mut padded_input = input;
-    padded_input.resize(S::WINDOW_SIZE * S::NUM_WINDOWS / 8, UInt8::constant(0u8));
-    assert_eq!(padded_input.len() * 8, S::WINDOW_SIZE * S::NUM_WINDOWS);
-    padded_input.into_iter().flat_map(|byte| byte.to_bits_le()).collect()
+/// Returns the little-endian bits of `input`, padded with constant `false` bits to exactly
+/// `S::WINDOW_SIZE * S::NUM_WINDOWS` bits, which need not be a multiple of 8.
+/// As in `PedersenCRH::hash`, an input that does not fit in the windows is rejected.
+pub(crate) fn pad_input_and_bitify<S: PedersenSize>(input: &[UInt8]) -> Result<Vec<Boolean>, SynthesisError> {
+    let input_size_bits = S::WINDOW_SIZE * S::NUM_WINDOWS;
+    if input.len() * 8 > input_size_bits {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+
+    let mut input_in_bits: Vec<_> = input.iter().flat_map(|byte| byte.to_bits_le()).collect();
+    input_in_bits.resize(input_size_bits, Boolean::constant(false));
+    Ok(input_in_bits)
+}
+
+/// Returns the constant bit-length prefix of `input` followed by its little-endian bits, padded
+/// with constant `false` bits to exactly `S::WINDOW_SIZE * S::NUM_WINDOWS` bits.
+fn pad_variable_length_input_and_bitify<S: PedersenSize>(input: &[UInt8]) -> Result<Vec<Boolean>, SynthesisError> {
+    let prefix = variable_length_prefix::<S>(input.len()).map_err(|error| SynthesisError::IoError(error.into()))?;
+
+    let mut input_in_bits: Vec<_> = prefix
+        .into_iter()
+        .map(Boolean::constant)
+        .chain(input.iter().flat_map(|byte| byte.to_bits_le()))
+        .collect();
+    input_in_bits.resize(S::WINDOW_SIZE * S::NUM_WINDOWS, Boolean::constant(false));
+    Ok(input_in_bits)
 }
 
 impl<F: PrimeField, G: Group, GG: GroupGadget<G, F>, S: PedersenSize> MaskedCRHGadget<PedersenCRH<G, S>, F>
@@ -135,7 +211,7 @@ impl<F: PrimeField, G: Group, GG: GroupGadget<G, F>, S: PedersenSize> MaskedCRHG
         let mask_hash = Self::check_evaluation_gadget(cs.ns(|| "evaluate mask"), parameters, mask.clone())?;
 
         // H_2(p) = sum of h_i^{1-2*p_i} for all i.
-        let mask_input_in_bits = pad_input_and_bitify::<S>(mask.clone());
+        let mask_input_in_bits = pad_input_and_bitify::<S>(&mask)?;
         let mask_symmetric_hash = GG::symmetric_multi_scalar_multiplication(
             cs.ns(|| "evaluate mask with mask bases"),
             &mask_parameters.parameters.bases,
@@ -144,8 +220,8 @@ impl<F: PrimeField, G: Group, GG: GroupGadget<G, F>, S: PedersenSize> MaskedCRHG
 
         assert_eq!(parameters.parameters.bases.len(), S::NUM_WINDOWS);
         // Pad the input if it is not the correct length.
-        let input_in_bits = pad_input_and_bitify::<S>(input);
-        let mask_in_bits = pad_input_and_bitify::<S>(mask);
+        let input_in_bits = pad_input_and_bitify::<S>(&input)?;
+        let mask_in_bits = pad_input_and_bitify::<S>(&mask)?;
 
         let masked_output = GG::masked_multi_scalar_multiplication(
             cs.ns(|| "multiscalar multiplication"),
@@ -183,6 +259,18 @@ impl<F: Field, G: Group + ProjectiveCurve, GG: CompressedGroupGadget<G, F>, S: P
     }
 }
 
+impl<F: Field, G: Group + ProjectiveCurve, GG: CompressedGroupGadget<G, F>> PedersenCompressedCRHGadget<G, F, GG> {
+    /// Evaluates `PedersenCompressedCRH::hash_variable_length` on the given input.
+    pub fn check_evaluation_gadget_variable_length<S: PedersenSize, CS: ConstraintSystem<F>>(
+        cs: CS,
+        parameters: &PedersenCRHParametersGadget<G, S, F, GG>,
+        input: Vec<UInt8>,
+    ) -> Result<GG::BaseFieldGadget, SynthesisError> {
+        let output = PedersenCRHGadget::<G, F, GG>::check_evaluation_gadget_variable_length(cs, parameters, input)?;
+        Ok(output.to_x_coordinate())
+    }
+}
+
 impl<F: PrimeField, G: Group + ProjectiveCurve, GG: CompressedGroupGadget<G, F>, S: PedersenSize>
     MaskedCRHGadget<PedersenCompressedCRH<G, S>, F> for PedersenCompressedCRHGadget<G, F, GG>
 {
@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{
+    borrow::Borrow,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    sync::Arc,
+};
+
+use snarkvm_algorithms::{
+    crh::{PoseidonCRH, PoseidonParameters},
+    traits::CRHParameters,
+};
+use snarkvm_fields::{PoseidonMDSField, PrimeField};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    algorithms::crh::PoseidonSpongeGadget,
+    fields::FpGadget,
+    integers::uint::UInt8,
+    traits::{
+        algorithms::{AlgebraicSpongeVar, CRHGadget},
+        alloc::AllocGadget,
+        fields::ToConstraintFieldGadget,
+    },
+};
+
+/// The parameters gadget of the Poseidon CRH. Parameters gadgets are equal and hash alike
+/// if and only if their native parameters have the same digest.
+#[derive(Clone)]
+pub struct PoseidonCRHParametersGadget<F: PrimeField + PoseidonMDSField, const RATE: usize> {
+    pub(crate) parameters: Arc<PoseidonParameters<F, RATE>>,
+    digest: [u8; 32],
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> PoseidonCRHParametersGadget<F, RATE> {
+    fn new(parameters: PoseidonParameters<F, RATE>) -> Self {
+        Self {
+            digest: parameters.digest(),
+            parameters: Arc::new(parameters),
+        }
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> PartialEq for PoseidonCRHParametersGadget<F, RATE> {
+    fn eq(&self, other: &Self) -> bool {
+        self.digest == other.digest
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> Eq for PoseidonCRHParametersGadget<F, RATE> {}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> Hash for PoseidonCRHParametersGadget<F, RATE> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.digest.hash(state);
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> AllocGadget<PoseidonParameters<F, RATE>, F>
+    for PoseidonCRHParametersGadget<F, RATE>
+{
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<PoseidonParameters<F, RATE>>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self::new(value_gen()?.borrow().clone()))
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<PoseidonParameters<F, RATE>>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self::new(value_gen()?.borrow().clone()))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonCRHGadget<F: PrimeField + PoseidonMDSField, const RATE: usize> {
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> PoseidonCRHGadget<F, RATE> {
+    /// Returns the hash of the given field elements, as the native `PoseidonCRH::hash_field_elements`.
+    pub fn check_evaluation_gadget_on_field_elements<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        parameters: &PoseidonCRHParametersGadget<F, RATE>,
+        input: &[FpGadget<F>],
+    ) -> Result<FpGadget<F>, SynthesisError> {
+        let mut sponge = PoseidonSpongeGadget::with_parameters(cs.ns(|| "sponge"), parameters.parameters.clone())?;
+        sponge.absorb(cs.ns(|| "absorb"), input)?;
+        let mut output = sponge.squeeze(cs.ns(|| "squeeze"), 1)?;
+        Ok(output.remove(0))
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> CRHGadget<PoseidonCRH<F, RATE>, F>
+    for PoseidonCRHGadget<F, RATE>
+{
+    type OutputGadget = FpGadget<F>;
+    type ParametersGadget = PoseidonCRHParametersGadget<F, RATE>;
+
+    fn check_evaluation_gadget<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        parameters: &Self::ParametersGadget,
+        input: Vec<UInt8>,
+    ) -> Result<Self::OutputGadget, SynthesisError> {
+        // Pack the bytes into field elements, as the native hash does.
+        let input = input.to_constraint_field(cs.ns(|| "pack input"))?;
+        Self::check_evaluation_gadget_on_field_elements(cs.ns(|| "hash"), parameters, &input)
+    }
+}
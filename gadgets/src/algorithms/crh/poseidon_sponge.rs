@@ -0,0 +1,279 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//
+// Acknowledgements
+//
+// This implementation of Poseidon is entirely from Fractal's implementation
+// ([COS20]: https://eprint.iacr.org/2019/1076) with small syntax changes.
+//
+
+use crate::{
+    fields::FpGadget,
+    traits::{algorithms::AlgebraicSpongeVar, alloc::AllocGadget, fields::FieldGadget},
+};
+use snarkvm_algorithms::{
+    crh::{PoseidonParameters, PoseidonSponge, PoseidonSpongeState},
+    traits::{AlgebraicSponge, TranscriptCost},
+};
+use snarkvm_fields::{PoseidonMDSField, PrimeField};
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
+
+use std::sync::Arc;
+
+#[derive(Clone)]
+/// the gadget for Poseidon sponge
+pub struct PoseidonSpongeGadget<F: PrimeField + PoseidonMDSField, const RATE: usize> {
+    /// the parameters of the permutation
+    pub(crate) parameters: Arc<PoseidonParameters<F, RATE>>,
+    /// the sponge's state
+    pub(crate) state: Vec<FpGadget<F>>,
+    /// the mode
+    mode: PoseidonSpongeState,
+    /// the cost of the transcript so far
+    cost: TranscriptCost,
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> PoseidonSpongeGadget<F, RATE> {
+    /// Creates a sponge with the given parameters.
+    pub fn with_parameters<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        parameters: Arc<PoseidonParameters<F, RATE>>,
+    ) -> Result<Self, SynthesisError> {
+        let zero = FpGadget::<F>::zero(cs.ns(|| "zero"))?;
+        Ok(Self {
+            parameters,
+            state: vec![zero; PoseidonParameters::<F, RATE>::WIDTH],
+            mode: PoseidonSpongeState::Absorbing { next_absorb_index: 0 },
+            cost: TranscriptCost::default(),
+        })
+    }
+
+    /// Returns the number of permutations invoked so far.
+    pub fn permutation_count(&self) -> usize {
+        self.cost.permutations
+    }
+
+    fn apply_s_box<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        state: &mut [FpGadget<F>],
+        is_full_round: bool,
+    ) -> Result<(), SynthesisError> {
+        let alpha = [self.parameters.alpha];
+
+        // Full rounds apply the S Box (x^alpha) to every element of state
+        if is_full_round {
+            for (i, state_item) in state.iter_mut().enumerate() {
+                *state_item = state_item.pow_by_constant(cs.ns(|| format!("pow_by_constant_{}", i)), alpha)?;
+            }
+        }
+        // Partial rounds apply the S Box (x^alpha) to just the final element of state
+        else {
+            state[state.len() - 1] = state[state.len() - 1].pow_by_constant(cs.ns(|| "pow_by_constant"), alpha)?;
+        }
+
+        Ok(())
+    }
+
+    fn apply_ark<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        state: &mut [FpGadget<F>],
+        round_number: usize,
+    ) -> Result<(), SynthesisError> {
+        for (i, state_elem) in state.iter_mut().enumerate() {
+            *state_elem =
+                state_elem.add_constant(cs.ns(|| format!("add_{}", i)), &self.parameters.ark[round_number][i])?;
+        }
+        Ok(())
+    }
+
+    fn apply_mds<CS: ConstraintSystem<F>>(&self, mut cs: CS, state: &mut [FpGadget<F>]) -> Result<(), SynthesisError> {
+        let mut new_state = Vec::new();
+        let zero = FpGadget::<F>::zero(cs.ns(|| "zero"))?;
+        for i in 0..state.len() {
+            let mut cur = zero.clone();
+            for (j, state_elem) in state.iter().enumerate() {
+                let term = state_elem.mul_by_constant(
+                    cs.ns(|| format!("state_elem_times_mds_{}_{}", i, j)),
+                    &self.parameters.mds[i][j],
+                )?;
+                cur = cur.add(cs.ns(|| format!("cur_add_term_{}_{}", i, j)), &term)?;
+            }
+            new_state.push(cur);
+        }
+        state.clone_from_slice(&new_state[..state.len()]);
+        Ok(())
+    }
+
+    fn permute<CS: ConstraintSystem<F>>(&mut self, mut cs: CS) -> Result<(), SynthesisError> {
+        let full_rounds = self.parameters.full_rounds;
+        let partial_rounds = self.parameters.partial_rounds;
+        let full_rounds_over_2 = full_rounds / 2;
+        let mut state = self.state.clone();
+        for i in 0..full_rounds_over_2 {
+            self.apply_ark(cs.ns(|| format!("first_apply_ark_{}", i)), &mut state, i as usize)?;
+            self.apply_s_box(cs.ns(|| format!("first_apply_s_box_{}", i)), &mut state, true)?;
+            self.apply_mds(cs.ns(|| format!("first_apply_mds_{}", i)), &mut state)?;
+        }
+        for i in full_rounds_over_2..(full_rounds_over_2 + partial_rounds) {
+            self.apply_ark(cs.ns(|| format!("second_apply_ark_{}", i)), &mut state, i as usize)?;
+            self.apply_s_box(cs.ns(|| format!("second_apply_s_box_{}", i)), &mut state, false)?;
+            self.apply_mds(cs.ns(|| format!("second_apply_mds_{}", i)), &mut state)?;
+        }
+
+        for i in (full_rounds_over_2 + partial_rounds)..(partial_rounds + full_rounds) {
+            self.apply_ark(cs.ns(|| format!("third_apply_ark_{}", i)), &mut state, i as usize)?;
+            self.apply_s_box(cs.ns(|| format!("third_apply_s_box_{}", i)), &mut state, true)?;
+            self.apply_mds(cs.ns(|| format!("third_apply_mds_{}", i)), &mut state)?;
+        }
+
+        self.state = state;
+        self.cost.permutations += 1;
+        Ok(())
+    }
+
+    fn absorb_internal<CS: ConstraintSystem<F>>(
+        &mut self,
+        mut cs: CS,
+        rate_start_index: usize,
+        elements: &[FpGadget<F>],
+    ) -> Result<(), SynthesisError> {
+        // if we can finish in this call
+        if rate_start_index + elements.len() <= RATE {
+            for (i, element) in elements.iter().enumerate() {
+                self.state[i + rate_start_index].add_in_place(cs.ns(|| format!("first_add_element_{}", i)), element)?;
+            }
+            self.mode = PoseidonSpongeState::Absorbing {
+                next_absorb_index: rate_start_index + elements.len(),
+            };
+
+            return Ok(());
+        }
+        // otherwise absorb (rate - rate_start_index) elements
+        let num_elements_absorbed = RATE - rate_start_index;
+        for (i, element) in elements.iter().enumerate().take(num_elements_absorbed) {
+            self.state[i + rate_start_index].add_in_place(cs.ns(|| format!("second_add_element_{}", i)), element)?;
+        }
+        self.permute(cs.ns(|| "permute"))?;
+        // Tail recurse, with the input elements being truncated by num elements absorbed
+        self.absorb_internal(cs.ns(|| "absorb_internal"), 0, &elements[num_elements_absorbed..])
+    }
+
+    // Squeeze |output| many elements. This does not end in a squeeze
+    fn squeeze_internal<CS: ConstraintSystem<F>>(
+        &mut self,
+        mut cs: CS,
+        rate_start_index: usize,
+        output: &mut [FpGadget<F>],
+    ) -> Result<(), SynthesisError> {
+        // if we can finish in this call
+        if rate_start_index + output.len() <= RATE {
+            output.clone_from_slice(&self.state[rate_start_index..(output.len() + rate_start_index)]);
+            self.mode = PoseidonSpongeState::Squeezing {
+                next_squeeze_index: rate_start_index + output.len(),
+            };
+            return Ok(());
+        }
+        // otherwise squeeze (rate - rate_start_index) elements
+        let num_elements_squeezed = RATE - rate_start_index;
+        output[..num_elements_squeezed]
+            .clone_from_slice(&self.state[rate_start_index..(num_elements_squeezed + rate_start_index)]);
+
+        // Unless we are done with squeezing in this call, permute.
+        if output.len() != RATE {
+            self.permute(cs.ns(|| "permute"))?;
+        }
+        // Tail recurse, with the correct change to indices in output happening due to changing the slice
+        self.squeeze_internal(cs.ns(|| "squeeze_internal"), 0, &mut output[num_elements_squeezed..])
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> AlgebraicSpongeVar<F, PoseidonSponge<F, RATE>>
+    for PoseidonSpongeGadget<F, RATE>
+{
+    fn new<CS: ConstraintSystem<F>>(cs: CS) -> Self {
+        Self::with_parameters(cs, Arc::new(PoseidonParameters::default())).unwrap()
+    }
+
+    fn constant<CS: ConstraintSystem<F>>(mut cs: CS, pfs: &PoseidonSponge<F, RATE>) -> Self {
+        let mut state_gadgets = Vec::new();
+
+        for (i, state_elem) in pfs.state.iter().enumerate() {
+            state_gadgets.push(
+                FpGadget::<F>::alloc_constant(cs.ns(|| format!("alloc_elems_{}", i)), || Ok(*state_elem)).unwrap(),
+            );
+        }
+
+        Self {
+            parameters: pfs.parameters.clone(),
+            state: state_gadgets,
+            mode: pfs.mode.clone(),
+            cost: pfs.transcript_cost(),
+        }
+    }
+
+    fn absorb<CS: ConstraintSystem<F>>(&mut self, mut cs: CS, elems: &[FpGadget<F>]) -> Result<(), SynthesisError> {
+        if elems.is_empty() {
+            return Ok(());
+        }
+        self.cost.absorbed_elements += elems.len();
+
+        match self.mode {
+            PoseidonSpongeState::Absorbing { next_absorb_index } => {
+                let mut absorb_index = next_absorb_index;
+                if absorb_index == RATE {
+                    self.permute(cs.ns(|| "permute"))?;
+                    absorb_index = 0;
+                }
+                self.absorb_internal(cs.ns(|| "absorb_internal"), absorb_index, elems)?;
+            }
+            PoseidonSpongeState::Squeezing { next_squeeze_index: _ } => {
+                self.permute(cs.ns(|| "permute"))?;
+                self.absorb_internal(cs.ns(|| "absorb_internal"), 0, elems)?;
+            }
+        };
+
+        Ok(())
+    }
+
+    fn squeeze<CS: ConstraintSystem<F>>(&mut self, mut cs: CS, num: usize) -> Result<Vec<FpGadget<F>>, SynthesisError> {
+        let zero = FpGadget::zero(cs.ns(|| "zero"))?;
+        let mut squeezed_elems = vec![zero; num];
+        self.cost.squeezed_elements += num;
+        match self.mode {
+            PoseidonSpongeState::Absorbing { next_absorb_index: _ } => {
+                self.permute(cs.ns(|| "permute"))?;
+                self.squeeze_internal(cs.ns(|| "squeeze_internal"), 0, &mut squeezed_elems)?;
+            }
+            PoseidonSpongeState::Squeezing { next_squeeze_index } => {
+                let mut squeeze_index = next_squeeze_index;
+                if squeeze_index == RATE {
+                    self.permute(cs.ns(|| "permute"))?;
+                    squeeze_index = 0;
+                }
+                self.squeeze_internal(cs.ns(|| "squeeze_internal"), squeeze_index, &mut squeezed_elems)?;
+            }
+        };
+
+        Ok(squeezed_elems)
+    }
+
+    fn transcript_cost(&self) -> TranscriptCost {
+        self.cost
+    }
+}
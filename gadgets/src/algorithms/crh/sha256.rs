@@ -0,0 +1,367 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::borrow::Borrow;
+
+use snarkvm_algorithms::crh::{Sha256CRH, Sha256Output, Sha256Parameters};
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    bits::{Boolean, ToBytesGadget},
+    integers::uint::{UInt, UInt32, UInt8},
+    traits::{
+        algorithms::CRHGadget,
+        alloc::AllocGadget,
+        bits::Xor,
+        eq::{ConditionalEqGadget, EqGadget},
+        integers::integer::Integer,
+        select::CondSelectGadget,
+    },
+};
+
+/// The number of constraints of a SHA-256 compression function over an allocated block and state.
+///
+/// This bounds the cost of each 64-byte block of the padded input. Blocks that consist of constants,
+/// such as trailing padding, and the first block, which starts from the constant initial hash value, cost less.
+pub const SHA256_CONSTRAINTS_PER_BLOCK: usize = 36720;
+
+/// The initial hash value, from FIPS 180-4, Section 5.3.3.
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// The round constants, from FIPS 180-4, Section 4.2.2.
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98,
+    0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
+    0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8,
+    0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
+    0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+    0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
+    0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+    0xc67178f2,
+];
+
+/// Returns `x >> by`, filling the vacated high bits with zeros.
+fn shr(x: &UInt32, by: usize) -> UInt32 {
+    let bits = x
+        .to_bits_le()
+        .into_iter()
+        .skip(by)
+        .chain((0..by).map(|_| Boolean::constant(false)))
+        .collect::<Vec<_>>();
+
+    UInt32::new(bits, x.value.map(|v| v >> by))
+}
+
+/// Returns `rotr(x, r0) ^ rotr(x, r1) ^ rotr(x, r2)`, or `rotr(x, r0) ^ rotr(x, r1) ^ shr(x, r2)`
+/// if `shift_last` is set, as used by the Σ and σ functions.
+fn triple_xor<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    x: &UInt32,
+    r0: usize,
+    r1: usize,
+    r2: usize,
+    shift_last: bool,
+) -> Result<UInt32, SynthesisError> {
+    let last = if shift_last { shr(x, r2) } else { x.rotr(r2) };
+    x.rotr(r0)
+        .xor(cs.ns(|| "first xor"), &x.rotr(r1))?
+        .xor(cs.ns(|| "second xor"), &last)
+}
+
+/// Returns `Ch(e, f, g) = (e & f) ^ (!e & g)`, computed bitwise as `g ^ (e & (f ^ g))`.
+fn ch<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    e: &UInt32,
+    f: &UInt32,
+    g: &UInt32,
+) -> Result<UInt32, SynthesisError> {
+    let bits = e
+        .to_bits_le()
+        .iter()
+        .zip(f.to_bits_le().iter().zip(g.to_bits_le().iter()))
+        .enumerate()
+        .map(|(i, (e, (f, g)))| {
+            let mut cs = cs.ns(|| format!("ch bit {}", i));
+            let f_xor_g = f.xor(cs.ns(|| "f xor g"), g)?;
+            let selected = Boolean::and(cs.ns(|| "e and (f xor g)"), e, &f_xor_g)?;
+            g.xor(cs.ns(|| "g xor selected"), &selected)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(UInt32::from_bits_le(&bits))
+}
+
+/// Returns `Maj(a, b, c) = (a & b) ^ (a & c) ^ (b & c)`, computed bitwise as `(a & b) ^ (c & (a ^ b))`.
+fn maj<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    a: &UInt32,
+    b: &UInt32,
+    c: &UInt32,
+) -> Result<UInt32, SynthesisError> {
+    let bits = a
+        .to_bits_le()
+        .iter()
+        .zip(b.to_bits_le().iter().zip(c.to_bits_le().iter()))
+        .enumerate()
+        .map(|(i, (a, (b, c)))| {
+            let mut cs = cs.ns(|| format!("maj bit {}", i));
+            let a_and_b = Boolean::and(cs.ns(|| "a and b"), a, b)?;
+            let a_xor_b = a.xor(cs.ns(|| "a xor b"), b)?;
+            let c_and_a_xor_b = Boolean::and(cs.ns(|| "c and (a xor b)"), c, &a_xor_b)?;
+            a_and_b.xor(cs.ns(|| "majority"), &c_and_a_xor_b)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(UInt32::from_bits_le(&bits))
+}
+
+/// Applies the SHA-256 compression function to the state `h` with the message block `block`,
+/// following FIPS 180-4, Section 6.2.2.
+#[allow(clippy::many_single_char_names)]
+fn sha256_compression<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    h: &mut [UInt32],
+    block: &[UInt32],
+) -> Result<(), SynthesisError> {
+    assert_eq!(h.len(), 8);
+    assert_eq!(block.len(), 16);
+
+    // Prepare the message schedule.
+    let mut w = block.to_vec();
+    for t in 16..64 {
+        let mut cs = cs.ns(|| format!("message schedule {}", t));
+
+        let s0 = triple_xor(cs.ns(|| "sigma 0"), &w[t - 15], 7, 18, 3, true)?;
+        let s1 = triple_xor(cs.ns(|| "sigma 1"), &w[t - 2], 17, 19, 10, true)?;
+        let word = UInt32::addmany(cs.ns(|| "add"), &[w[t - 16].clone(), s0, w[t - 7].clone(), s1])?;
+        w.push(word);
+    }
+
+    let mut a = h[0].clone();
+    let mut b = h[1].clone();
+    let mut c = h[2].clone();
+    let mut d = h[3].clone();
+    let mut e = h[4].clone();
+    let mut f = h[5].clone();
+    let mut g = h[6].clone();
+    let mut hh = h[7].clone();
+
+    for t in 0..64 {
+        let mut cs = cs.ns(|| format!("round {}", t));
+
+        let s1 = triple_xor(cs.ns(|| "big sigma 1"), &e, 6, 11, 25, false)?;
+        let ch = ch(cs.ns(|| "ch"), &e, &f, &g)?;
+        let temp1 = UInt32::addmany(cs.ns(|| "temp 1"), &[
+            hh,
+            s1,
+            ch,
+            UInt32::constant(ROUND_CONSTANTS[t]),
+            w[t].clone(),
+        ])?;
+
+        let s0 = triple_xor(cs.ns(|| "big sigma 0"), &a, 2, 13, 22, false)?;
+        let maj = maj(cs.ns(|| "maj"), &a, &b, &c)?;
+
+        hh = g;
+        g = f;
+        f = e;
+        e = UInt32::addmany(cs.ns(|| "new e"), &[d, temp1.clone()])?;
+        d = c;
+        c = b;
+        b = a;
+        a = UInt32::addmany(cs.ns(|| "new a"), &[temp1, s0, maj])?;
+    }
+
+    for (i, value) in [a, b, c, d, e, f, g, hh].iter().enumerate() {
+        h[i] = UInt32::addmany(cs.ns(|| format!("update state {}", i)), &[h[i].clone(), value.clone()])?;
+    }
+
+    Ok(())
+}
+
+/// Returns the big-endian word of the given 4 bytes.
+fn word_from_bytes_be(bytes: &[UInt8]) -> UInt32 {
+    let bits = bytes
+        .iter()
+        .rev()
+        .flat_map(|byte| byte.to_bits_le())
+        .collect::<Vec<_>>();
+    UInt32::from_bits_le(&bits)
+}
+
+/// Returns the SHA-256 digest of the given bytes.
+///
+/// The input is padded as in FIPS 180-4, Section 5.1.1, so its length must be known at synthesis time.
+/// Each 64-byte block of the padded input costs one compression function.
+pub fn sha256_gadget<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    input: &[UInt8],
+) -> Result<Vec<UInt8>, SynthesisError> {
+    // Append the bit '1', the zero padding, and the message length in bits as a 64-bit big-endian integer.
+    let length_in_bits = (input.len() as u64) * 8;
+    let mut padded = input.to_vec();
+    padded.push(UInt8::constant(0x80));
+    while padded.len() % 64 != 56 {
+        padded.push(UInt8::constant(0));
+    }
+    padded.extend(length_in_bits.to_be_bytes().iter().map(|byte| UInt8::constant(*byte)));
+
+    let mut h = IV.iter().map(|word| UInt32::constant(*word)).collect::<Vec<_>>();
+
+    for (i, block) in padded.chunks(64).enumerate() {
+        let block = block.chunks(4).map(word_from_bytes_be).collect::<Vec<_>>();
+        sha256_compression(cs.ns(|| format!("block {}", i)), &mut h, &block)?;
+    }
+
+    // Serialize the state as big-endian words.
+    Ok(h.iter()
+        .flat_map(|word| {
+            let bits = word.to_bits_le();
+            (0..4)
+                .rev()
+                .map(move |i| UInt8::from_bits_le(&bits[i * 8..(i + 1) * 8]))
+        })
+        .collect())
+}
+
+/// The parameters gadget of the SHA-256 CRH, which has no parameters.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Sha256ParametersGadget;
+
+impl<F: PrimeField> AllocGadget<Sha256Parameters, F> for Sha256ParametersGadget {
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<Sha256Parameters>, CS: ConstraintSystem<F>>(
+        _cs: CS,
+        _value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self)
+    }
+
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<Sha256Parameters>, CS: ConstraintSystem<F>>(
+        _cs: CS,
+        _value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Sha256OutputGadget(pub Vec<UInt8>);
+
+impl PartialEq for Sha256OutputGadget {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Sha256OutputGadget {}
+
+impl<F: PrimeField> EqGadget<F> for Sha256OutputGadget {}
+
+impl<F: PrimeField> ConditionalEqGadget<F> for Sha256OutputGadget {
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        for (i, (a, b)) in self.0.iter().zip(other.0.iter()).enumerate() {
+            a.conditional_enforce_equal(&mut cs.ns(|| format!("sha256_equal_{}", i)), b, condition)?;
+        }
+        Ok(())
+    }
+
+    fn cost() -> usize {
+        32 * <UInt8 as ConditionalEqGadget<F>>::cost()
+    }
+}
+
+impl<F: PrimeField> CondSelectGadget<F> for Sha256OutputGadget {
+    fn conditionally_select<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        cond: &Boolean,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, SynthesisError> {
+        let bytes = first
+            .0
+            .iter()
+            .zip(second.0.iter())
+            .enumerate()
+            .map(|(i, (a, b))| UInt8::conditionally_select(cs.ns(|| format!("select_byte_{}", i)), cond, a, b))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self(bytes))
+    }
+
+    fn cost() -> usize {
+        32 * <UInt8 as CondSelectGadget<F>>::cost()
+    }
+}
+
+impl<F: PrimeField> ToBytesGadget<F> for Sha256OutputGadget {
+    #[inline]
+    fn to_bytes<CS: ConstraintSystem<F>>(&self, _cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        Ok(self.0.clone())
+    }
+
+    #[inline]
+    fn to_bytes_strict<CS: ConstraintSystem<F>>(&self, cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.to_bytes(cs)
+    }
+}
+
+impl<F: PrimeField> AllocGadget<Sha256Output, F> for Sha256OutputGadget {
+    #[inline]
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<Sha256Output>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = value_gen().map(|value| value.borrow().0).unwrap_or([0u8; 32]);
+        Ok(Sha256OutputGadget(UInt8::alloc_vec(cs, &value)?))
+    }
+
+    #[inline]
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<Sha256Output>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = value_gen().map(|value| value.borrow().0).unwrap_or([0u8; 32]);
+        Ok(Sha256OutputGadget(UInt8::alloc_input_vec_le(cs, &value)?))
+    }
+}
+
+/// The SHA-256 CRH gadget.
+///
+/// Each 64-byte block of the padded input costs at most `SHA256_CONSTRAINTS_PER_BLOCK` constraints,
+/// and an input of `n` bytes is padded to `(n + 9) / 64` blocks, rounded up.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Sha256Gadget;
+
+impl<F: PrimeField> CRHGadget<Sha256CRH, F> for Sha256Gadget {
+    type OutputGadget = Sha256OutputGadget;
+    type ParametersGadget = Sha256ParametersGadget;
+
+    fn check_evaluation_gadget<CS: ConstraintSystem<F>>(
+        cs: CS,
+        _parameters: &Self::ParametersGadget,
+        input: Vec<UInt8>,
+    ) -> Result<Self::OutputGadget, SynthesisError> {
+        Ok(Sha256OutputGadget(sha256_gadget(cs, &input)?))
+    }
+}
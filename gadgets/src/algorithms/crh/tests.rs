@@ -17,7 +17,17 @@
 use rand::{thread_rng, Rng};
 
 use snarkvm_algorithms::{
-    crh::{BoweHopwoodPedersenCRH, BoweHopwoodPedersenCompressedCRH, PedersenCRH, PedersenCompressedCRH, PedersenSize},
+    crh::{
+        extend_mask,
+        BoweHopwoodPedersenCRH,
+        BoweHopwoodPedersenCompressedCRH,
+        PedersenCRH,
+        PedersenCompressedCRH,
+        PedersenSize,
+        PoseidonCRH,
+        Sha256CRH,
+    },
+    errors::CRHError,
     traits::{CRHParameters, CRH},
 };
 use snarkvm_curves::{
@@ -25,7 +35,7 @@ use snarkvm_curves::{
     edwards_bls12::{EdwardsAffine, EdwardsProjective},
 };
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+use snarkvm_r1cs::{ConstraintSystem, SynthesisError, TestConstraintSystem};
 
 use crate::{
     algorithms::crh::{
@@ -33,8 +43,13 @@ use crate::{
         BoweHopwoodPedersenCompressedCRHGadget,
         PedersenCRHGadget,
         PedersenCompressedCRHGadget,
+        PoseidonCRHGadget,
+        PoseidonCRHParametersGadget,
+        Sha256Gadget,
+        SHA256_CONSTRAINTS_PER_BLOCK,
     },
     curves::edwards_bls12::EdwardsBlsGadget,
+    fields::FpGadget,
     integers::uint::UInt8,
     traits::{
         algorithms::{CRHGadget, MaskedCRHGadget},
@@ -59,9 +74,20 @@ impl PedersenSize for BoweHopwoodSize {
     const WINDOW_SIZE: usize = 48;
 }
 
+// 35 bits of capacity, which is neither a multiple of 8 nor of the Bowe-Hopwood chunk size.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(super) struct UnalignedSize;
+
+impl PedersenSize for UnalignedSize {
+    const NUM_WINDOWS: usize = 5;
+    const WINDOW_SIZE: usize = 7;
+}
+
 const PEDERSEN_HASH_CONSTRAINTS: usize = 5632;
 const PEDERSEN_HASH_CONSTRAINTS_ON_AFFINE: usize = 6656;
 const BOWE_HOPWOOD_HASH_CONSTRAINTS: usize = 3974;
+const POSEIDON_HASH_CONSTRAINTS: usize = 2361;
+const SHA256_HASH_CONSTRAINTS: usize = 103818;
 
 fn generate_input<F: Field, CS: ConstraintSystem<F>, R: Rng>(
     mut cs: CS,
@@ -169,8 +195,152 @@ fn masked_crh_gadget_test<F: PrimeField, H: CRH, CG: MaskedCRHGadget<H, F>>() {
     assert!(cs.is_satisfied());
 }
 
+/// Checks the masked gadget against the native masked evaluation `evaluate_masked` on identical inputs.
+fn masked_crh_gadget_matches_native_test<F: PrimeField, H: CRH, CG: MaskedCRHGadget<H, F>>(
+    evaluate_masked: impl Fn(&H, &[u8], &H::Parameters, &[u8]) -> Result<H::Output, CRHError>,
+) {
+    let rng = &mut thread_rng();
+    let crh = H::setup(rng);
+    let mask_parameters = H::Parameters::setup(rng);
+
+    let mut cs = TestConstraintSystem::<F>::new();
+
+    let input: Vec<u8> = (0..128).map(|_| rng.gen()).collect();
+    let mask: Vec<u8> = (0..64).map(|_| rng.gen()).collect();
+    let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), &input).unwrap();
+    let mask_bytes = UInt8::alloc_vec(cs.ns(|| "mask"), &mask).unwrap();
+
+    // The mask is extended identically.
+    let extended_mask = CG::extend_mask(cs.ns(|| "extend_mask"), &mask_bytes).unwrap();
+    let extended_mask = extended_mask.iter().map(|byte| byte.value.unwrap()).collect::<Vec<_>>();
+    assert_eq!(extended_mask, extend_mask(&mask));
+
+    let parameters_gadget =
+        <CG as CRHGadget<_, _>>::ParametersGadget::alloc(&mut cs.ns(|| "gadget_parameters"), || Ok(crh.parameters()))
+            .unwrap();
+    let mask_parameters_gadget =
+        <CG as CRHGadget<_, _>>::ParametersGadget::alloc(&mut cs.ns(|| "gadget_mask_parameters"), || {
+            Ok(&mask_parameters)
+        })
+        .unwrap();
+
+    let masked_output_gadget = <CG as MaskedCRHGadget<_, _>>::check_evaluation_gadget_masked(
+        &mut cs.ns(|| "masked_gadget_evaluation"),
+        &parameters_gadget,
+        input_bytes.clone(),
+        &mask_parameters_gadget,
+        mask_bytes,
+    )
+    .unwrap();
+
+    let native_result = evaluate_masked(&crh, &input, &mask_parameters, &mask).unwrap();
+    assert_eq!(native_result, crh.hash(&input).unwrap());
+
+    let native_result_gadget =
+        <CG as CRHGadget<_, _>>::OutputGadget::alloc(&mut cs.ns(|| "native_result"), || Ok(&native_result)).unwrap();
+    masked_output_gadget
+        .enforce_equal(
+            &mut cs.ns(|| "Check that computed crh matches native masked crh"),
+            &native_result_gadget,
+        )
+        .unwrap();
+
+    assert!(cs.is_satisfied());
+
+    // A mask that is not half the length of the input is rejected by both.
+    let short_mask_bytes = UInt8::alloc_vec(cs.ns(|| "short_mask"), &mask[1..]).unwrap();
+    assert!(matches!(
+        <CG as MaskedCRHGadget<_, _>>::check_evaluation_gadget_masked(
+            &mut cs.ns(|| "short_mask_gadget_evaluation"),
+            &parameters_gadget,
+            input_bytes,
+            &mask_parameters_gadget,
+            short_mask_bytes,
+        ),
+        Err(SynthesisError::Unsatisfiable)
+    ));
+    assert!(matches!(
+        evaluate_masked(&crh, &input, &mask_parameters, &mask[1..]),
+        Err(CRHError::IncorrectMaskLength(63, 128))
+    ));
+}
+
+/// Checks the gadget against the native hash for every input length up to the 4 bytes that
+/// fit in `UnalignedSize`.
+fn non_byte_aligned_crh_gadget_test<H: CRH, CG: CRHGadget<H, Fr>>() {
+    let rng = &mut thread_rng();
+    let crh = H::setup(rng);
+
+    for length in 0..=4 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let input: Vec<u8> = (0..length).map(|_| rng.gen()).collect();
+        let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), &input).unwrap();
+        let native_result = crh.hash(&input).unwrap();
+
+        let parameters_gadget =
+            <CG as CRHGadget<_, _>>::ParametersGadget::alloc(&mut cs.ns(|| "gadget_parameters"), || {
+                Ok(crh.parameters())
+            })
+            .unwrap();
+        let output_gadget = <CG as CRHGadget<_, _>>::check_evaluation_gadget(
+            &mut cs.ns(|| "gadget_evaluation"),
+            &parameters_gadget,
+            input_bytes,
+        )
+        .unwrap();
+
+        let native_result_gadget =
+            <CG as CRHGadget<_, _>>::OutputGadget::alloc(&mut cs.ns(|| "native_result"), || Ok(&native_result))
+                .unwrap();
+        output_gadget
+            .enforce_equal(&mut cs.ns(|| "enforce_equal"), &native_result_gadget)
+            .unwrap();
+
+        assert!(cs.is_satisfied());
+    }
+}
+
+/// Checks that the gadget rejects an input longer than the window capacity of `num_bytes`,
+/// as `CRH::hash` does.
+fn over_long_input_crh_gadget_test<H: CRH, CG: CRHGadget<H, Fr>>(num_bytes: usize) {
+    let rng = &mut thread_rng();
+    let mut cs = TestConstraintSystem::<Fr>::new();
+
+    let input: Vec<u8> = (0..num_bytes + 1).map(|_| rng.gen()).collect();
+    let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), &input).unwrap();
+
+    let crh = H::setup(rng);
+    assert!(crh.hash(&input).is_err());
+
+    let parameters_gadget =
+        <CG as CRHGadget<_, _>>::ParametersGadget::alloc(&mut cs.ns(|| "gadget_parameters"), || Ok(crh.parameters()))
+            .unwrap();
+    assert!(
+        <CG as CRHGadget<_, _>>::check_evaluation_gadget(
+            &mut cs.ns(|| "gadget_evaluation"),
+            &parameters_gadget,
+            input_bytes,
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn over_long_input_gadget_test() {
+    over_long_input_crh_gadget_test::<
+        PedersenCRH<EdwardsProjective, Size>,
+        PedersenCRHGadget<EdwardsProjective, Fr, EdwardsBlsGadget>,
+    >(Size::WINDOW_SIZE * Size::NUM_WINDOWS / 8);
+    over_long_input_crh_gadget_test::<
+        BoweHopwoodPedersenCRH<EdwardsProjective, BoweHopwoodSize>,
+        BoweHopwoodPedersenCRHGadget<EdwardsProjective, Fr, EdwardsBlsGadget>,
+    >(BoweHopwoodSize::WINDOW_SIZE * BoweHopwoodSize::NUM_WINDOWS / 8);
+}
+
 mod pedersen_crh_gadget_on_projective {
     use super::*;
+    use crate::traits::curves::GroupGadget;
 
     type TestCRH = PedersenCRH<EdwardsProjective, Size>;
     type TestCRHGadget = PedersenCRHGadget<EdwardsProjective, Fr, EdwardsBlsGadget>;
@@ -184,6 +354,127 @@ mod pedersen_crh_gadget_on_projective {
     fn masked_gadget_test() {
         masked_crh_gadget_test::<Fr, TestCRH, TestCRHGadget>()
     }
+
+    #[test]
+    fn masked_gadget_matches_native_test() {
+        masked_crh_gadget_matches_native_test::<Fr, TestCRH, TestCRHGadget>(TestCRH::evaluate_masked)
+    }
+
+    #[test]
+    fn variable_length_gadget_test() {
+        let rng = &mut thread_rng();
+        let crh = TestCRH::setup(rng);
+
+        // The 1024 bits of capacity hold an 11-bit length prefix and up to 126 bytes.
+        for length in &[0, 1, 31, 126] {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let input: Vec<u8> = (0..*length).map(|_| rng.gen()).collect();
+            let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), &input).unwrap();
+            let native_result = crh.hash_variable_length(&input).unwrap();
+
+            let parameters_gadget =
+                <TestCRHGadget as CRHGadget<TestCRH, _>>::ParametersGadget::alloc(&mut cs.ns(|| "parameters"), || {
+                    Ok(crh.parameters())
+                })
+                .unwrap();
+            let output_gadget = TestCRHGadget::check_evaluation_gadget_variable_length(
+                &mut cs.ns(|| "evaluation"),
+                &parameters_gadget,
+                input_bytes,
+            )
+            .unwrap();
+
+            let native_result_gadget =
+                <EdwardsBlsGadget as AllocGadget<EdwardsProjective, Fr>>::alloc(&mut cs.ns(|| "native_result"), || {
+                    Ok(&native_result)
+                })
+                .unwrap();
+            output_gadget
+                .enforce_equal(&mut cs.ns(|| "enforce_equal"), &native_result_gadget)
+                .unwrap();
+
+            assert!(cs.is_satisfied());
+        }
+
+        // An input that does not fit after its prefix is rejected.
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), &[0u8; 127]).unwrap();
+        let parameters_gadget =
+            <TestCRHGadget as CRHGadget<TestCRH, _>>::ParametersGadget::alloc(&mut cs.ns(|| "parameters"), || {
+                Ok(crh.parameters())
+            })
+            .unwrap();
+        assert!(
+            TestCRHGadget::check_evaluation_gadget_variable_length(
+                &mut cs.ns(|| "evaluation"),
+                &parameters_gadget,
+                input_bytes,
+            )
+            .is_err()
+        );
+    }
+    /// Checks the hash of the input against a fixed digest, allocating both the parameters
+    /// and the digest either as constants or as witnesses. Returns the computed digest and
+    /// the number of constraints and variables of the circuit.
+    fn fixed_digest_circuit(crh: &TestCRH, input: &[u8], constant: bool) -> (EdwardsProjective, usize, usize) {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), input).unwrap();
+        let digest = crh.hash(input).unwrap();
+
+        let parameters_gadget = if constant {
+            <TestCRHGadget as CRHGadget<TestCRH, _>>::ParametersGadget::alloc_constant(
+                &mut cs.ns(|| "parameters"),
+                || Ok(crh.parameters()),
+            )
+            .unwrap()
+        } else {
+            <TestCRHGadget as CRHGadget<TestCRH, _>>::ParametersGadget::alloc(&mut cs.ns(|| "parameters"), || {
+                Ok(crh.parameters())
+            })
+            .unwrap()
+        };
+        let output_gadget = <TestCRHGadget as CRHGadget<TestCRH, _>>::check_evaluation_gadget(
+            &mut cs.ns(|| "evaluation"),
+            &parameters_gadget,
+            input_bytes,
+        )
+        .unwrap();
+
+        let digest_gadget = if constant {
+            <EdwardsBlsGadget as AllocGadget<EdwardsProjective, Fr>>::alloc_constant(&mut cs.ns(|| "digest"), || {
+                Ok(&digest)
+            })
+            .unwrap()
+        } else {
+            <EdwardsBlsGadget as AllocGadget<EdwardsProjective, Fr>>::alloc(&mut cs.ns(|| "digest"), || Ok(&digest))
+                .unwrap()
+        };
+        output_gadget
+            .enforce_equal(&mut cs.ns(|| "enforce_equal"), &digest_gadget)
+            .unwrap();
+        assert!(cs.is_satisfied());
+
+        let output = <EdwardsBlsGadget as GroupGadget<EdwardsProjective, Fr>>::get_value(&output_gadget).unwrap();
+        (output, cs.num_constraints(), cs.num_private_variables())
+    }
+
+    #[test]
+    fn constant_parameters_gadget_test() {
+        let rng = &mut thread_rng();
+        let crh = TestCRH::setup(rng);
+        let input: Vec<u8> = (0..64).map(|_| rng.gen()).collect();
+
+        let (output, num_constraints, num_variables) = fixed_digest_circuit(&crh, &input, false);
+        let (constant_output, num_constant_constraints, num_constant_variables) =
+            fixed_digest_circuit(&crh, &input, true);
+
+        assert_eq!(output, constant_output);
+        assert_eq!(output, crh.hash(&input).unwrap());
+        assert!(num_constant_constraints < num_constraints);
+        assert!(num_constant_variables < num_variables);
+    }
 }
 
 mod pedersen_crh_gadget_on_affine {
@@ -213,6 +504,51 @@ mod pedersen_compressed_crh_gadget_on_projective {
     fn masked_gadget_test() {
         masked_crh_gadget_test::<Fr, TestCRH, TestCRHGadget>()
     }
+
+    #[test]
+    fn masked_gadget_matches_native_test() {
+        masked_crh_gadget_matches_native_test::<Fr, TestCRH, TestCRHGadget>(TestCRH::evaluate_masked)
+    }
+
+    #[test]
+    fn variable_length_gadget_test() {
+        let rng = &mut thread_rng();
+        let crh = TestCRH::setup(rng);
+
+        for length in &[0, 1, 126] {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let input: Vec<u8> = (0..*length).map(|_| rng.gen()).collect();
+            let mut extended_input = input.clone();
+            extended_input.push(0u8);
+            let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), &input).unwrap();
+            let native_result = crh.hash_variable_length(&input).unwrap();
+
+            // Appending a zero byte changes the hash, while the input still fits.
+            if *length < 126 {
+                assert_ne!(native_result, crh.hash_variable_length(&extended_input).unwrap());
+            }
+
+            let parameters_gadget =
+                <TestCRHGadget as CRHGadget<TestCRH, _>>::ParametersGadget::alloc(&mut cs.ns(|| "parameters"), || {
+                    Ok(crh.parameters())
+                })
+                .unwrap();
+            let output_gadget = TestCRHGadget::check_evaluation_gadget_variable_length(
+                &mut cs.ns(|| "evaluation"),
+                &parameters_gadget,
+                input_bytes,
+            )
+            .unwrap();
+
+            let native_result_gadget = FpGadget::alloc(&mut cs.ns(|| "native_result"), || Ok(&native_result)).unwrap();
+            output_gadget
+                .enforce_equal(&mut cs.ns(|| "enforce_equal"), &native_result_gadget)
+                .unwrap();
+
+            assert!(cs.is_satisfied());
+        }
+    }
 }
 
 // Note: Bowe-Hopwood CRH Gadget currently does not support affine curves or masked crh
@@ -240,3 +576,444 @@ mod bowe_hopwood_pedersen_compressed_crh_gadget_on_projective {
         primitive_crh_gadget_test::<Fr, TestCRH, TestCRHGadget>(BOWE_HOPWOOD_HASH_CONSTRAINTS)
     }
 }
+
+mod poseidon_crh_gadget {
+    use super::*;
+
+    use crate::traits::fields::FieldGadget;
+    use snarkvm_utilities::rand::UniformRand;
+
+    type TestCRH = PoseidonCRH<Fr, 2>;
+    type TestCRHGadget = PoseidonCRHGadget<Fr, 2>;
+
+    #[test]
+    fn primitive_gadget_test() {
+        primitive_crh_gadget_test::<Fr, TestCRH, TestCRHGadget>(POSEIDON_HASH_CONSTRAINTS)
+    }
+
+    /// Returns the number of constraints used to hash the given input in-circuit.
+    fn hash_constraints<H: CRH, CG: CRHGadget<H, Fr>>(input: &[u8]) -> usize {
+        let rng = &mut thread_rng();
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), input).unwrap();
+        let num_input_constraints = cs.num_constraints();
+
+        let crh = H::setup(rng);
+        let parameters_gadget =
+            <CG as CRHGadget<_, _>>::ParametersGadget::alloc(&mut cs.ns(|| "gadget_parameters"), || {
+                Ok(crh.parameters())
+            })
+            .unwrap();
+        <CG as CRHGadget<_, _>>::check_evaluation_gadget(
+            &mut cs.ns(|| "gadget_evaluation"),
+            &parameters_gadget,
+            input_bytes,
+        )
+        .unwrap();
+
+        assert!(cs.is_satisfied());
+        cs.num_constraints() - num_input_constraints
+    }
+
+    #[test]
+    fn cost_versus_bowe_hopwood_test() {
+        let input = [1u8; 128];
+
+        let bowe_hopwood_constraints = hash_constraints::<
+            BoweHopwoodPedersenCompressedCRH<EdwardsProjective, BoweHopwoodSize>,
+            BoweHopwoodPedersenCompressedCRHGadget<EdwardsProjective, Fr, EdwardsBlsGadget>,
+        >(&input);
+        let poseidon_constraints = hash_constraints::<TestCRH, TestCRHGadget>(&input);
+
+        assert_eq!(bowe_hopwood_constraints, 2438);
+        assert_eq!(poseidon_constraints, 825);
+    }
+
+    #[test]
+    fn random_input_gadget_test() {
+        let rng = &mut thread_rng();
+        let crh = TestCRH::setup(rng);
+
+        for i in 0..20 {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let length: usize = rng.gen_range(0..200);
+            let input: Vec<u8> = (0..length).map(|_| rng.gen()).collect();
+            let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), &input).unwrap();
+            let native_result = crh.hash(&input).unwrap();
+
+            let parameters_gadget =
+                <TestCRHGadget as CRHGadget<_, _>>::ParametersGadget::alloc(&mut cs.ns(|| "gadget_parameters"), || {
+                    Ok(crh.parameters())
+                })
+                .unwrap();
+            let output_gadget = <TestCRHGadget as CRHGadget<_, _>>::check_evaluation_gadget(
+                &mut cs.ns(|| "gadget_evaluation"),
+                &parameters_gadget,
+                input_bytes,
+            )
+            .unwrap();
+            assert_eq!(output_gadget.get_value(), Some(native_result), "iteration {}", i);
+
+            let native_result_gadget =
+                <TestCRHGadget as CRHGadget<TestCRH, Fr>>::OutputGadget::alloc(&mut cs.ns(|| "native_result"), || {
+                    Ok(&native_result)
+                })
+                .unwrap();
+            output_gadget
+                .enforce_equal(&mut cs.ns(|| "enforce_equal"), &native_result_gadget)
+                .unwrap();
+
+            assert!(cs.is_satisfied());
+        }
+    }
+
+    #[test]
+    fn field_elements_gadget_test() {
+        let rng = &mut thread_rng();
+        let crh = TestCRH::setup(rng);
+
+        for i in 0..10 {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let input: Vec<Fr> = (0..i).map(|_| Fr::rand(rng)).collect();
+            let input_gadgets = input
+                .iter()
+                .enumerate()
+                .map(|(j, element)| FpGadget::alloc(cs.ns(|| format!("input_{}", j)), || Ok(element)).unwrap())
+                .collect::<Vec<_>>();
+            let native_result = crh.hash_field_elements(&input);
+
+            let parameters_gadget =
+                PoseidonCRHParametersGadget::alloc(&mut cs.ns(|| "gadget_parameters"), || Ok(crh.parameters()))
+                    .unwrap();
+            let output_gadget = TestCRHGadget::check_evaluation_gadget_on_field_elements(
+                &mut cs.ns(|| "gadget_evaluation"),
+                &parameters_gadget,
+                &input_gadgets,
+            )
+            .unwrap();
+
+            let native_result_gadget = FpGadget::alloc(&mut cs.ns(|| "native_result"), || Ok(native_result)).unwrap();
+            output_gadget
+                .enforce_equal(&mut cs.ns(|| "enforce_equal"), &native_result_gadget)
+                .unwrap();
+
+            assert!(cs.is_satisfied());
+        }
+    }
+}
+
+mod sha256_crh_gadget {
+    use super::*;
+
+    use sha2::{Digest, Sha256};
+
+    /// Hashes the given input in-circuit, checks it against the `sha2` crate,
+    /// and returns the number of constraints used by the hash.
+    fn check_sha256(input: &[u8]) -> usize {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), input).unwrap();
+        let num_input_constraints = cs.num_constraints();
+
+        let output_gadget = <Sha256Gadget as CRHGadget<Sha256CRH, Fr>>::check_evaluation_gadget(
+            cs.ns(|| "sha256"),
+            &Default::default(),
+            input_bytes,
+        )
+        .unwrap();
+        let num_hash_constraints = cs.num_constraints() - num_input_constraints;
+
+        let output = output_gadget
+            .0
+            .iter()
+            .map(|byte| byte.value.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(&output[..], &Sha256::digest(input)[..]);
+
+        let native_result = Sha256CRH::default().hash(input).unwrap();
+        let native_result_gadget =
+            <Sha256Gadget as CRHGadget<Sha256CRH, Fr>>::OutputGadget::alloc(cs.ns(|| "native_result"), || {
+                Ok(native_result)
+            })
+            .unwrap();
+        output_gadget
+            .enforce_equal(cs.ns(|| "enforce_equal"), &native_result_gadget)
+            .unwrap();
+
+        assert!(cs.is_satisfied());
+        num_hash_constraints
+    }
+
+    #[test]
+    fn primitive_gadget_test() {
+        primitive_crh_gadget_test::<Fr, Sha256CRH, Sha256Gadget>(SHA256_HASH_CONSTRAINTS)
+    }
+
+    #[test]
+    fn single_block_test() {
+        // The empty string hashes a block of constants, which requires no constraints.
+        assert_eq!(check_sha256(b""), 0);
+        assert_eq!(check_sha256(b"abc"), 34569);
+        // The largest input that fits in a single block.
+        assert_eq!(check_sha256(&[0xab; 55]), 35791);
+    }
+
+    #[test]
+    fn multi_block_test() {
+        let rng = &mut thread_rng();
+
+        // A 64-byte input requires a second block for the padding.
+        assert_eq!(check_sha256(&[0xcd; 64]), 65562);
+
+        for (length, num_blocks) in &[(56, 2), (119, 2), (120, 3), (200, 4)] {
+            let input = (0..*length).map(|_| rng.gen()).collect::<Vec<u8>>();
+            assert!(check_sha256(&input) <= num_blocks * SHA256_CONSTRAINTS_PER_BLOCK);
+        }
+
+        // Each additional block of allocated input costs exactly one compression function.
+        let one_block = check_sha256(&[0xef; 64]);
+        let two_blocks = check_sha256(&[0xef; 128]);
+        assert_eq!(two_blocks - one_block, SHA256_CONSTRAINTS_PER_BLOCK);
+    }
+}
+
+mod non_byte_aligned_window {
+    use super::*;
+
+    #[test]
+    fn pedersen_gadget_test() {
+        non_byte_aligned_crh_gadget_test::<
+            PedersenCRH<EdwardsProjective, UnalignedSize>,
+            PedersenCRHGadget<EdwardsProjective, Fr, EdwardsBlsGadget>,
+        >()
+    }
+
+    #[test]
+    fn bowe_hopwood_gadget_test() {
+        non_byte_aligned_crh_gadget_test::<
+            BoweHopwoodPedersenCRH<EdwardsProjective, UnalignedSize>,
+            BoweHopwoodPedersenCRHGadget<EdwardsProjective, Fr, EdwardsBlsGadget>,
+        >()
+    }
+}
+
+mod poseidon_sponge {
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use snarkvm_algorithms::{
+        crh::PoseidonSponge,
+        traits::{AlgebraicSponge, TranscriptOp},
+    };
+    use snarkvm_curves::bls12_377::Fq;
+    use snarkvm_utilities::rand::UniformRand;
+
+    use crate::{algorithms::crh::PoseidonSpongeGadget, fields::FpGadget, traits::algorithms::AlgebraicSpongeVar};
+
+    use super::*;
+
+    type Sponge = PoseidonSponge<Fq, 2>;
+    type SpongeGadget = PoseidonSpongeGadget<Fq, 2>;
+
+    const MAX_ELEMENTS: usize = 100;
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_poseidon_sponge_constant() {
+        let mut rng = XorShiftRng::seed_from_u64(123456789u64);
+
+        for i in 0..ITERATIONS {
+            let mut cs = TestConstraintSystem::<Fq>::new();
+
+            // Create a new algebraic sponge.
+            let mut sponge = Sponge::new();
+
+            // Generate random elements to absorb.
+            let num_elements: usize = rng.gen_range(0..MAX_ELEMENTS);
+            let elements: Vec<_> = (0..num_elements).map(|_| Fq::rand(&mut rng)).collect();
+
+            // Absorb the random elements.
+            sponge.absorb(&elements);
+
+            // Alloc the sponge gadget from a given sponge.
+            let mut sponge_gadget =
+                SpongeGadget::constant(cs.ns(|| format!("poseidon_sponge_constant_{}", i)), &sponge);
+
+            // Squeeze the elements from the sponge and sponge gadget.
+            let sponge_squeeze = sponge.squeeze(num_elements);
+            let sponge_gadget_squeeze = sponge_gadget
+                .squeeze(cs.ns(|| format!("squeeze_{}", i)), num_elements)
+                .unwrap();
+
+            // Check that the squeeze results are equivalent.
+            for (j, (gadget, element)) in sponge_gadget_squeeze.iter().zip(sponge_squeeze).enumerate() {
+                // Allocate the field gadget from the base element.
+                let alloc_element =
+                    FpGadget::alloc(cs.ns(|| format!("alloc_field_{}_{}", i, j)), || Ok(element)).unwrap();
+
+                // Check that the elements are equivalent.
+                gadget
+                    .enforce_equal(cs.ns(|| format!("enforce_equal_element_{}_{}", i, j)), &alloc_element)
+                    .unwrap();
+            }
+
+            assert!(cs.is_satisfied());
+        }
+    }
+
+    #[test]
+    fn test_poseidon_sponge_squeeze() {
+        let mut rng = XorShiftRng::seed_from_u64(123456789u64);
+
+        for i in 0..ITERATIONS {
+            let mut cs = TestConstraintSystem::<Fq>::new();
+
+            // Create a new algebraic sponge.
+            let mut sponge = Sponge::new();
+
+            // Alloc the sponge gadget from a given sponge.
+            let mut sponge_gadget = SpongeGadget::new(cs.ns(|| format!("new_poseidon_sponge_{}", i)));
+
+            // Generate random elements to absorb.
+            let num_elements: usize = rng.gen_range(0..MAX_ELEMENTS);
+            let elements: Vec<_> = (0..num_elements).map(|_| Fq::rand(&mut rng)).collect();
+
+            let mut element_gadgets = vec![];
+            for (j, element) in elements.iter().enumerate() {
+                // Allocate the field gadget from the base element.
+                let alloc_element =
+                    FpGadget::alloc(cs.ns(|| format!("native_alloc_field_{}_{}", i, j)), || Ok(element)).unwrap();
+
+                element_gadgets.push(alloc_element);
+            }
+
+            // Absorb the random elements.
+            sponge.absorb(&elements);
+            sponge_gadget
+                .absorb(cs.ns(|| format!("absorb_{}", i)), &element_gadgets)
+                .unwrap();
+
+            // Squeeze the elements from the sponge and sponge gadget.
+            let sponge_squeeze = sponge.squeeze(num_elements);
+            let sponge_gadget_squeeze = sponge_gadget
+                .squeeze(cs.ns(|| format!("squeeze_{}", i)), num_elements)
+                .unwrap();
+
+            // Check that the squeeze results are equivalent.
+            for (j, (gadget, element)) in sponge_gadget_squeeze.iter().zip(sponge_squeeze).enumerate() {
+                // Allocate the field gadget from the base element.
+                let alloc_element =
+                    FpGadget::alloc(cs.ns(|| format!("squeeze_alloc_field_{}_{}", i, j)), || Ok(element)).unwrap();
+
+                // Check that the elements are equivalent.
+                gadget
+                    .enforce_equal(cs.ns(|| format!("enforce_equal_element_{}_{}", i, j)), &alloc_element)
+                    .unwrap();
+            }
+
+            assert!(cs.is_satisfied());
+        }
+    }
+
+    #[test]
+    fn test_poseidon_sponge_transcript_cost() {
+        let mut rng = XorShiftRng::seed_from_u64(123456789u64);
+
+        for i in 0..ITERATIONS {
+            let mut cs = TestConstraintSystem::<Fq>::new();
+
+            let schedule: Vec<TranscriptOp> = (0..rng.gen_range(0..10))
+                .map(|_| match rng.gen() {
+                    true => TranscriptOp::Absorb(rng.gen_range(0..8)),
+                    false => TranscriptOp::Squeeze(rng.gen_range(0..8)),
+                })
+                .collect();
+
+            // Run a prefix of the schedule natively, and instantiate the sponge gadget from it.
+            let split = rng.gen_range(0..=schedule.len());
+            let mut sponge = Sponge::new();
+            for op in schedule[..split].iter() {
+                match *op {
+                    TranscriptOp::Absorb(num) => {
+                        sponge.absorb(&(0..num).map(|_| Fq::rand(&mut rng)).collect::<Vec<_>>())
+                    }
+                    TranscriptOp::Squeeze(num) => {
+                        sponge.squeeze(num);
+                    }
+                }
+            }
+            let mut sponge_gadget =
+                SpongeGadget::constant(cs.ns(|| format!("poseidon_sponge_constant_{}", i)), &sponge);
+
+            // Run the rest of the schedule on both sponges.
+            for (j, op) in schedule[split..].iter().enumerate() {
+                let mut cs = cs.ns(|| format!("op_{}_{}", i, j));
+                match *op {
+                    TranscriptOp::Absorb(num) => {
+                        let elements: Vec<_> = (0..num).map(|_| Fq::rand(&mut rng)).collect();
+                        let element_gadgets: Vec<_> = elements
+                            .iter()
+                            .enumerate()
+                            .map(|(k, element)| {
+                                FpGadget::alloc(cs.ns(|| format!("alloc_field_{}", k)), || Ok(element)).unwrap()
+                            })
+                            .collect();
+                        sponge.absorb(&elements);
+                        sponge_gadget.absorb(cs.ns(|| "absorb"), &element_gadgets).unwrap();
+                    }
+                    TranscriptOp::Squeeze(num) => {
+                        sponge.squeeze(num);
+                        sponge_gadget.squeeze(cs.ns(|| "squeeze"), num).unwrap();
+                    }
+                }
+            }
+
+            // The native and gadget sponges invoke the same number of permutations, as estimated.
+            assert_eq!(sponge.transcript_cost(), sponge_gadget.transcript_cost());
+            assert_eq!(sponge.permutation_count(), sponge_gadget.permutation_count());
+            assert_eq!(sponge.transcript_cost(), Sponge::estimate_cost(&schedule));
+        }
+    }
+}
+
+mod parameters_identity {
+    use super::*;
+    use snarkvm_algorithms::crh::PedersenCRHParameters;
+    use snarkvm_curves::traits::Group;
+    use std::collections::HashSet;
+
+    type TestCRH = PedersenCRH<EdwardsProjective, Size>;
+    type TestCRHGadget = PedersenCRHGadget<EdwardsProjective, Fr, EdwardsBlsGadget>;
+    type TestParameters = PedersenCRHParameters<EdwardsProjective, Size>;
+    type TestParametersGadget = <TestCRHGadget as CRHGadget<TestCRH, Fr>>::ParametersGadget;
+
+    #[test]
+    fn parameters_gadgets_are_identified_by_digest() {
+        let rng = &mut thread_rng();
+        let parameters = TestParameters::setup(rng);
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let gadget = TestParametersGadget::alloc(cs.ns(|| "parameters"), || Ok(&parameters)).unwrap();
+        let same_gadget = TestParametersGadget::alloc(cs.ns(|| "same_parameters"), || Ok(parameters.clone())).unwrap();
+        assert!(gadget == same_gadget);
+
+        // Changing a single base changes the identity of the parameters.
+        let mut bases = parameters.bases.clone();
+        bases[0][0].double_in_place();
+        let other_parameters = TestParameters::from(bases);
+        assert_ne!(parameters.digest(), other_parameters.digest());
+
+        let other_gadget = TestParametersGadget::alloc(cs.ns(|| "other_parameters"), || Ok(other_parameters)).unwrap();
+        assert!(gadget != other_gadget);
+        assert_eq!(
+            vec![&gadget, &same_gadget, &other_gadget]
+                .into_iter()
+                .collect::<HashSet<_>>()
+                .len(),
+            2
+        );
+    }
+}
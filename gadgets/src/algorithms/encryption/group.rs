@@ -19,7 +19,12 @@ use std::{borrow::Borrow, marker::PhantomData};
 use digest::Digest;
 use itertools::Itertools;
 
-use snarkvm_algorithms::encryption::{GroupEncryption, GroupEncryptionParameters, GroupEncryptionPublicKey};
+use snarkvm_algorithms::encryption::{
+    GroupEncryption,
+    GroupEncryptionMultiRecipientCiphertext,
+    GroupEncryptionParameters,
+    GroupEncryptionPublicKey,
+};
 use snarkvm_curves::traits::{Group, ProjectiveCurve};
 use snarkvm_fields::{Field, PrimeField};
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
@@ -78,6 +83,17 @@ impl<G: Group + ProjectiveCurve, F: Field> AllocGadget<GroupEncryptionParameters
 pub struct GroupEncryptionPrivateKeyGadget<G: Group>(pub Vec<UInt8>, PhantomData<G>);
 
 impl<G: Group, F: PrimeField> AllocGadget<G::ScalarField, F> for GroupEncryptionPrivateKeyGadget<G> {
+    fn alloc_constant<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<G::ScalarField>, CS: ConstraintSystem<F>>(
+        _cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let private_key = to_bytes![value_gen()?.borrow()].unwrap();
+        Ok(GroupEncryptionPrivateKeyGadget(
+            UInt8::constant_vec(&private_key),
+            PhantomData,
+        ))
+    }
+
     fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<G::ScalarField>, CS: ConstraintSystem<F>>(
         cs: CS,
         value_gen: Fn,
@@ -139,6 +155,16 @@ impl<G: Group, F: PrimeField> AllocGadget<G::ScalarField, F> for GroupEncryption
     }
 }
 
+impl<G: Group, F: PrimeField> ToBytesGadget<F> for GroupEncryptionRandomnessGadget<G> {
+    fn to_bytes<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.0.to_bytes(&mut cs.ns(|| "to_bytes"))
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.0.to_bytes_strict(&mut cs.ns(|| "to_bytes_strict"))
+    }
+}
+
 /// Group encryption blinding exponents gadget
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GroupEncryptionBlindingExponentsGadget<G: Group>(pub Vec<Vec<UInt8>>, PhantomData<G>);
@@ -194,6 +220,24 @@ pub struct GroupEncryptionPublicKeyGadget<G: Group, F: Field, GG: GroupGadget<G,
 impl<G: Group + ProjectiveCurve, F: Field, GG: GroupGadget<G, F>> AllocGadget<GroupEncryptionPublicKey<G>, F>
     for GroupEncryptionPublicKeyGadget<G, F, GG>
 {
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<GroupEncryptionPublicKey<G>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        f: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let public_key = f()?.borrow().0;
+        let zero = GG::zero(cs.ns(|| "zero"))?;
+
+        Ok(Self {
+            public_key: zero.add_constant(cs.ns(|| "add_constant"), &public_key)?,
+            _engine: PhantomData,
+            _group: PhantomData,
+        })
+    }
+
     fn alloc<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<GroupEncryptionPublicKey<G>>,
@@ -474,6 +518,145 @@ impl<G: Group + ProjectiveCurve, F: Field, GG: CompressedGroupGadget<G, F>> EqGa
 {
 }
 
+/// Group encryption multi-recipient ciphertext gadget
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupEncryptionMultiRecipientCiphertextGadget<
+    G: Group + ProjectiveCurve,
+    F: Field,
+    GG: CompressedGroupGadget<G, F>,
+> {
+    ephemeral_key: GG,
+    key_confirmation: GG,
+    encapsulations: Vec<GG>,
+    payload: Vec<GG>,
+    _group: PhantomData<*const G>,
+    _engine: PhantomData<*const F>,
+}
+
+impl<G: Group + ProjectiveCurve, F: Field, GG: CompressedGroupGadget<G, F>>
+    AllocGadget<GroupEncryptionMultiRecipientCiphertext<G>, F> for GroupEncryptionMultiRecipientCiphertextGadget<G, F, GG>
+{
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<GroupEncryptionMultiRecipientCiphertext<G>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        f: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = f().map(|pp| pp.borrow().clone())?;
+
+        let ephemeral_key = GG::alloc(cs.ns(|| "Ephemeral key"), || Ok(value.ephemeral_key))?;
+        let key_confirmation = GG::alloc(cs.ns(|| "Key confirmation"), || Ok(value.key_confirmation))?;
+
+        let mut encapsulations = Vec::with_capacity(value.encapsulations.len());
+        for (i, d_j) in value.encapsulations.into_iter().enumerate() {
+            encapsulations.push(GG::alloc(cs.ns(|| format!("Encapsulation Iteration {}", i)), || Ok(d_j))?);
+        }
+
+        let mut payload = Vec::with_capacity(value.payload.len());
+        for (i, c_i) in value.payload.into_iter().enumerate() {
+            payload.push(GG::alloc(cs.ns(|| format!("Payload Iteration {}", i)), || Ok(c_i))?);
+        }
+
+        Ok(Self {
+            ephemeral_key,
+            key_confirmation,
+            encapsulations,
+            payload,
+            _engine: PhantomData,
+            _group: PhantomData,
+        })
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<GroupEncryptionMultiRecipientCiphertext<G>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        f: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let value = f().map(|pp| pp.borrow().clone())?;
+
+        let ephemeral_key = GG::alloc_input(cs.ns(|| "Ephemeral key"), || Ok(value.ephemeral_key))?;
+        let key_confirmation = GG::alloc_input(cs.ns(|| "Key confirmation"), || Ok(value.key_confirmation))?;
+
+        let mut encapsulations = Vec::with_capacity(value.encapsulations.len());
+        for (i, d_j) in value.encapsulations.into_iter().enumerate() {
+            encapsulations.push(GG::alloc_input(cs.ns(|| format!("Encapsulation Iteration {}", i)), || Ok(d_j))?);
+        }
+
+        let mut payload = Vec::with_capacity(value.payload.len());
+        for (i, c_i) in value.payload.into_iter().enumerate() {
+            payload.push(GG::alloc_input(cs.ns(|| format!("Payload Iteration {}", i)), || Ok(c_i))?);
+        }
+
+        Ok(Self {
+            ephemeral_key,
+            key_confirmation,
+            encapsulations,
+            payload,
+            _engine: PhantomData,
+            _group: PhantomData,
+        })
+    }
+}
+
+impl<G: Group + ProjectiveCurve, F: Field, GG: CompressedGroupGadget<G, F>> ConditionalEqGadget<F>
+    for GroupEncryptionMultiRecipientCiphertextGadget<G, F, GG>
+{
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        if self.encapsulations.len() != other.encapsulations.len() || self.payload.len() != other.payload.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        self.ephemeral_key.conditional_enforce_equal(
+            &mut cs.ns(|| "conditional_enforce_equal ephemeral key"),
+            &other.ephemeral_key,
+            condition,
+        )?;
+        self.key_confirmation.conditional_enforce_equal(
+            &mut cs.ns(|| "conditional_enforce_equal key confirmation"),
+            &other.key_confirmation,
+            condition,
+        )?;
+
+        for (i, (d_j, other_d_j)) in self.encapsulations.iter().zip(&other.encapsulations).enumerate() {
+            d_j.conditional_enforce_equal(
+                &mut cs.ns(|| format!("conditional_enforce_equal encapsulation {}", i)),
+                other_d_j,
+                condition,
+            )?;
+        }
+
+        for (i, (c_i, other_c_i)) in self.payload.iter().zip(&other.payload).enumerate() {
+            c_i.conditional_enforce_equal(
+                &mut cs.ns(|| format!("conditional_enforce_equal payload {}", i)),
+                other_c_i,
+                condition,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn cost() -> usize {
+        unimplemented!()
+    }
+}
+
+impl<G: Group + ProjectiveCurve, F: Field, GG: CompressedGroupGadget<G, F>> EqGadget<F>
+    for GroupEncryptionMultiRecipientCiphertextGadget<G, F, GG>
+{
+}
+
 /// Group encryption gadget
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GroupEncryptionGadget<G: Group + ProjectiveCurve, F: PrimeField, GG: CompressedGroupGadget<G, F>> {
@@ -600,3 +783,110 @@ impl<
         })
     }
 }
+
+impl<G: Group + ProjectiveCurve, F: PrimeField, GG: CompressedGroupGadget<G, F>> GroupEncryptionGadget<G, F, GG> {
+    /// Enforces the multi-recipient encryption of the plaintext to each of the public keys.
+    ///
+    /// The payload is constrained once, and each recipient adds one variable-base scalar
+    /// multiplication for its encapsulation.
+    pub fn check_multi_recipient_encryption_gadget<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        parameters: &GroupEncryptionParametersGadget<G>,                // g
+        ephemeral_randomness: &GroupEncryptionRandomnessGadget<G>,      // r
+        payload_key_randomness: &GroupEncryptionRandomnessGadget<G>,    // k
+        public_keys: &[GroupEncryptionPublicKeyGadget<G, F, GG>],       // pk_j
+        input: &GroupEncryptionPlaintextGadget<G, F, GG>,               // m
+        blinding_exponents: &GroupEncryptionBlindingExponentsGadget<G>, // 1 [/] (z [+] i)
+    ) -> Result<GroupEncryptionMultiRecipientCiphertextGadget<G, F, GG>, SynthesisError> {
+        let zero = GG::zero(&mut cs.ns(|| "zero"))?;
+
+        let ephemeral_bits: Vec<_> = ephemeral_randomness.0.iter().flat_map(|byte| byte.to_bits_le()).collect();
+        let payload_key_bits: Vec<_> = payload_key_randomness.0.iter().flat_map(|byte| byte.to_bits_le()).collect();
+
+        // c_0 <- r * g
+        let mut ephemeral_key = zero.clone();
+        ephemeral_key.scalar_multiplication(
+            cs.ns(|| "c_0"),
+            ephemeral_bits.iter().zip_eq(&parameters.parameters.generator_powers),
+        )?;
+
+        // K <- k * g
+        let mut payload_key = zero.clone();
+        payload_key.scalar_multiplication(
+            cs.ns(|| "payload_key"),
+            payload_key_bits.iter().zip_eq(&parameters.parameters.generator_powers),
+        )?;
+
+        // d_j <- K + r * pk_j
+        let mut encapsulations = Vec::with_capacity(public_keys.len());
+        for (j, public_key) in public_keys.iter().enumerate() {
+            let d_j = public_key.public_key.mul_bits(
+                cs.ns(|| format!("d_{}", j)),
+                &payload_key,
+                ephemeral_bits.iter().copied(),
+            )?;
+            encapsulations.push(d_j);
+        }
+
+        let z = payload_key.to_x_coordinate();
+        let z_bytes = z.to_bytes(&mut cs.ns(|| "z_to_bytes"))?;
+        let z_bits: Vec<_> = z_bytes.into_iter().flat_map(|byte| byte.to_bits_le()).collect();
+
+        let mut key_confirmation = None;
+        let mut payload = Vec::with_capacity(input.plaintext.len());
+
+        for (i, blinding_exponent) in blinding_exponents.0.iter().enumerate() {
+            let cs = &mut cs.ns(|| format!("h_{}", i));
+
+            let blinding_exponent_bits = blinding_exponent.iter().flat_map(|byte| byte.to_bits_le());
+
+            let h = payload_key.mul_bits(cs.ns(|| "h"), &zero, blinding_exponent_bits)?;
+
+            // z * h
+            let h_z = h.mul_bits(cs.ns(|| "z * h"), &zero, z_bits.iter().copied())?;
+
+            // (z [+] i) * h
+            let expected_payload_key = if i == 0 {
+                h_z
+            } else {
+                let mut h_i = h.clone();
+                for bit in (0..64 - (i as u64).leading_zeros() - 1).rev() {
+                    h_i.double_in_place(cs.ns(|| format!("Double {}", bit)))?;
+                    if (i >> bit) & 1 == 1 {
+                        h_i = h_i.add(cs.ns(|| format!("Add {}", bit)), &h)?;
+                    }
+                }
+
+                h_z.add(cs.ns(|| "expected payload key"), &h_i)?
+            };
+
+            expected_payload_key.enforce_equal(
+                &mut cs.ns(|| "Check that declared and computed payload keys are equal"),
+                &payload_key,
+            )?;
+
+            match i {
+                // t <- 1 [/] z * K
+                0 => key_confirmation = Some(h),
+                // c_i <- m_i + 1 [/] (z [+] i) * K
+                _ => {
+                    let m_i = input.plaintext.get(i - 1).ok_or(SynthesisError::Unsatisfiable)?;
+                    payload.push(h.add(cs.ns(|| "construct c_i"), m_i)?);
+                }
+            }
+        }
+
+        if payload.len() != input.plaintext.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        Ok(GroupEncryptionMultiRecipientCiphertextGadget {
+            ephemeral_key,
+            key_confirmation: key_confirmation.ok_or(SynthesisError::Unsatisfiable)?,
+            encapsulations,
+            payload,
+            _engine: PhantomData,
+            _group: PhantomData,
+        })
+    }
+}
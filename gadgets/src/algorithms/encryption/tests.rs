@@ -182,3 +182,89 @@ fn test_group_encryption_gadget() {
     }
     assert!(cs.is_satisfied());
 }
+
+#[test]
+fn test_group_encryption_multi_recipient_gadget() {
+    let mut cs = TestConstraintSystem::<Fr>::new();
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let encryption_scheme = TestEncryptionScheme::setup(rng);
+
+    let public_keys: Vec<_> = (0..3)
+        .map(|_| {
+            let private_key = encryption_scheme.generate_private_key(rng);
+            encryption_scheme.generate_public_key(&private_key).unwrap()
+        })
+        .collect();
+
+    let randomness = encryption_scheme.generate_multi_recipient_randomness(rng).unwrap();
+    let message = generate_input(10, rng);
+    let blinding_exponents = encryption_scheme
+        .generate_multi_recipient_blinding_exponents(&randomness, message.len())
+        .unwrap();
+    let ciphertext = encryption_scheme
+        .encrypt_multi_recipient(&public_keys, &randomness, &message)
+        .unwrap();
+
+    // Alloc parameters, public keys, plaintext, randomness, and blinding exponents
+    let parameters_gadget =
+        GroupEncryptionParametersGadget::alloc(&mut cs.ns(|| "parameters_gadget"), || Ok(&encryption_scheme.parameters))
+            .unwrap();
+    let public_key_gadgets: Vec<GroupEncryptionPublicKeyGadget<_, _, EdwardsBlsGadget>> = public_keys
+        .iter()
+        .enumerate()
+        .map(|(i, public_key)| {
+            GroupEncryptionPublicKeyGadget::alloc(&mut cs.ns(|| format!("public_key_gadget_{}", i)), || Ok(public_key))
+                .unwrap()
+        })
+        .collect();
+    let plaintext_gadget =
+        GroupEncryptionPlaintextGadget::alloc(&mut cs.ns(|| "plaintext_gadget"), || Ok(&message)).unwrap();
+    let ephemeral_randomness_gadget = GroupEncryptionRandomnessGadget::alloc(
+        &mut cs.ns(|| "ephemeral_randomness_gadget"),
+        || Ok(&randomness.ephemeral),
+    )
+    .unwrap();
+    let payload_key_randomness_gadget = GroupEncryptionRandomnessGadget::alloc(
+        &mut cs.ns(|| "payload_key_randomness_gadget"),
+        || Ok(&randomness.payload_key),
+    )
+    .unwrap();
+    let blinding_exponents_gadget =
+        GroupEncryptionBlindingExponentsGadget::alloc(&mut cs.ns(|| "blinding_exponents_gadget"), || {
+            Ok(&blinding_exponents)
+        })
+        .unwrap();
+
+    // Expected ciphertext gadget
+    let expected_ciphertext_gadget =
+        GroupEncryptionMultiRecipientCiphertextGadget::alloc(&mut cs.ns(|| "ciphertext_gadget"), || Ok(&ciphertext))
+            .unwrap();
+
+    println!("number of constraints for inputs: {}", cs.num_constraints());
+
+    let ciphertext_gadget = TestEncryptionSchemeGadget::check_multi_recipient_encryption_gadget(
+        &mut cs.ns(|| "ciphertext_gadget_evaluation"),
+        &parameters_gadget,
+        &ephemeral_randomness_gadget,
+        &payload_key_randomness_gadget,
+        &public_key_gadgets,
+        &plaintext_gadget,
+        &blinding_exponents_gadget,
+    )
+    .unwrap();
+
+    expected_ciphertext_gadget
+        .enforce_equal(
+            cs.ns(|| "Check that declared and computed ciphertexts are equal"),
+            &ciphertext_gadget,
+        )
+        .unwrap();
+
+    println!("number of constraints total: {}", cs.num_constraints());
+
+    if !cs.is_satisfied() {
+        println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+    }
+    assert!(cs.is_satisfied());
+}
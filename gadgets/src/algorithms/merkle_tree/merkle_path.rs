@@ -52,12 +52,36 @@ impl<P: MerkleParameters, HG: CRHGadget<P::H, F>, F: Field> MerklePathGadget<P,
 
     pub fn conditionally_check_membership<CS: ConstraintSystem<F>>(
         &self,
-        mut cs: CS,
+        cs: CS,
         parameters: &HG::ParametersGadget,
         root: &HG::OutputGadget,
         leaf: impl ToBytesGadget<F>,
         should_enforce: &Boolean,
     ) -> Result<(), SynthesisError> {
+        self.conditionally_check_membership_with_position(cs, parameters, root, leaf, should_enforce)?;
+        Ok(())
+    }
+
+    /// Checks the membership of the given leaf, and returns the position of the leaf in the tree
+    /// as little-endian bits, where a set bit denotes a right child.
+    pub fn check_membership_with_position<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        parameters: &HG::ParametersGadget,
+        root: &HG::OutputGadget,
+        leaf: impl ToBytesGadget<F>,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
+        self.conditionally_check_membership_with_position(cs, parameters, root, leaf, &Boolean::Constant(true))
+    }
+
+    pub fn conditionally_check_membership_with_position<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        parameters: &HG::ParametersGadget,
+        root: &HG::OutputGadget,
+        leaf: impl ToBytesGadget<F>,
+        should_enforce: &Boolean,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
         assert_eq!(self.path.len(), P::DEPTH);
         // Check that the hash of the given leaf matches the leaf hash in the membership
         // proof.
@@ -78,12 +102,15 @@ impl<P: MerkleParameters, HG: CRHGadget<P::H, F>, F: Field> MerklePathGadget<P,
 
         // Check levels between leaf level and root.
         let mut previous_hash = leaf_hash;
+        let mut position = Vec::with_capacity(P::DEPTH);
         for (i, &(ref left_hash, ref right_hash)) in self.path.iter().enumerate() {
             // Check if the previous_hash matches the correct current hash.
-            let previous_is_left = AllocatedBit::alloc(&mut cs.ns(|| format!("previous_is_left_{}", i)), || {
-                Ok(&previous_hash == left_hash)
-            })?
-            .into();
+            let previous_is_left: Boolean =
+                AllocatedBit::alloc(&mut cs.ns(|| format!("previous_is_left_{}", i)), || {
+                    Ok(&previous_hash == left_hash)
+                })?
+                .into();
+            position.push(previous_is_left.not());
 
             HG::OutputGadget::conditional_enforce_equal_or(
                 &mut cs.ns(|| format!("check_equals_which_{}", i)),
@@ -102,7 +129,9 @@ impl<P: MerkleParameters, HG: CRHGadget<P::H, F>, F: Field> MerklePathGadget<P,
             )?;
         }
 
-        root.conditional_enforce_equal(&mut cs.ns(|| "root_is_last"), &previous_hash, should_enforce)
+        root.conditional_enforce_equal(&mut cs.ns(|| "root_is_last"), &previous_hash, should_enforce)?;
+
+        Ok(position)
     }
 }
 
@@ -123,6 +152,9 @@ where
     let mut bytes = left_bytes;
     bytes.extend_from_slice(&right_bytes);
 
+    // As in `MerkleParameters::hash_inner_node`, only the bytes that fit in the CRH input are hashed.
+    bytes.truncate(H::INPUT_SIZE_BITS / 8);
+
     HG::check_evaluation_gadget(cs, parameters, bytes)
 }
 
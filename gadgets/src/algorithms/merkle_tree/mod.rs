@@ -20,5 +20,8 @@ pub use masked_tree::*;
 pub mod merkle_path;
 pub use merkle_path::*;
 
+pub mod non_membership;
+pub use non_membership::*;
+
 #[cfg(test)]
 pub mod tests;
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::borrow::Borrow;
+
+use snarkvm_algorithms::{merkle_tree::NonMembershipProof, traits::MerkleParameters};
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem, LinearCombination};
+
+use crate::{
+    algorithms::merkle_tree::MerklePathGadget,
+    bits::Boolean,
+    integers::uint::UInt64,
+    traits::{algorithms::CRHGadget, alloc::AllocGadget, bits::EvaluateLtGadget, eq::EqGadget},
+};
+
+/// Verifies a non-membership proof of a `SortedMerkleTree`, by checking the membership
+/// of two adjacent leaves that are respectively smaller and larger than the value.
+pub struct NonMembershipGadget<P: MerkleParameters, HG: CRHGadget<P::H, F>, F: PrimeField> {
+    left_leaf: UInt64,
+    left_path: MerklePathGadget<P, HG, F>,
+    right_leaf: UInt64,
+    right_path: MerklePathGadget<P, HG, F>,
+}
+
+impl<P: MerkleParameters, HG: CRHGadget<P::H, F>, F: PrimeField> NonMembershipGadget<P, HG, F> {
+    pub fn check_non_membership<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        parameters: &HG::ParametersGadget,
+        root: &HG::OutputGadget,
+        value: &UInt64,
+    ) -> Result<(), SynthesisError> {
+        let left_position = self.left_path.check_membership_with_position(
+            cs.ns(|| "check_left_membership"),
+            parameters,
+            root,
+            &self.left_leaf,
+        )?;
+        let right_position = self.right_path.check_membership_with_position(
+            cs.ns(|| "check_right_membership"),
+            parameters,
+            root,
+            &self.right_leaf,
+        )?;
+
+        // Check that the right leaf immediately follows the left leaf.
+        let mut position_difference = LinearCombination::zero();
+        let mut coeff = F::one();
+        for (left_bit, right_bit) in left_position.iter().zip(right_position.iter()) {
+            position_difference = position_difference + right_bit.lc(CS::one(), coeff) - left_bit.lc(CS::one(), coeff);
+            coeff.double_in_place();
+        }
+        cs.enforce(
+            || "check_adjacent_positions",
+            |_| position_difference,
+            |lc| lc + CS::one(),
+            |lc| lc + CS::one(),
+        );
+
+        // Check that the value lies strictly between the two leaves.
+        let left_is_smaller = self.left_leaf.less_than(cs.ns(|| "left_leaf_less_than_value"), value)?;
+        left_is_smaller.enforce_equal(cs.ns(|| "check_left_leaf_is_smaller"), &Boolean::constant(true))?;
+
+        let right_is_larger = value.less_than(cs.ns(|| "value_less_than_right_leaf"), &self.right_leaf)?;
+        right_is_larger.enforce_equal(cs.ns(|| "check_right_leaf_is_larger"), &Boolean::constant(true))?;
+
+        Ok(())
+    }
+}
+
+impl<P, HGadget, F> AllocGadget<NonMembershipProof<P>, F> for NonMembershipGadget<P, HGadget, F>
+where
+    P: MerkleParameters,
+    HGadget: CRHGadget<P::H, F>,
+    F: PrimeField,
+{
+    fn alloc<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<NonMembershipProof<P>>,
+    {
+        let proof = value_gen()?;
+        let proof = proof.borrow();
+
+        Ok(NonMembershipGadget {
+            left_leaf: UInt64::alloc(cs.ns(|| "left_leaf"), || Ok(proof.left_leaf))?,
+            left_path: MerklePathGadget::alloc(cs.ns(|| "left_path"), || Ok(&proof.left_path))?,
+            right_leaf: UInt64::alloc(cs.ns(|| "right_leaf"), || Ok(proof.right_leaf))?,
+            right_path: MerklePathGadget::alloc(cs.ns(|| "right_path"), || Ok(&proof.right_path))?,
+        })
+    }
+
+    fn alloc_input<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<NonMembershipProof<P>>,
+    {
+        let proof = value_gen()?;
+        let proof = proof.borrow();
+
+        Ok(NonMembershipGadget {
+            left_leaf: UInt64::alloc_input(cs.ns(|| "left_leaf"), || Ok(proof.left_leaf))?,
+            left_path: MerklePathGadget::alloc_input(cs.ns(|| "left_path"), || Ok(&proof.left_path))?,
+            right_leaf: UInt64::alloc_input(cs.ns(|| "right_leaf"), || Ok(proof.right_leaf))?,
+            right_path: MerklePathGadget::alloc_input(cs.ns(|| "right_path"), || Ok(&proof.right_path))?,
+        })
+    }
+}
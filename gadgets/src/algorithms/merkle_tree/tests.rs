@@ -18,10 +18,13 @@ use std::sync::Arc;
 
 use blake2::{digest::Digest, Blake2s};
 
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
 use snarkvm_algorithms::{
     crh::{BoweHopwoodPedersenCompressedCRH, PedersenCRH, PedersenCompressedCRH, PedersenSize},
     define_masked_merkle_tree_parameters,
-    merkle_tree::MerkleTree,
+    merkle_tree::{MerkleTree, NonMembershipProof, SortedMerkleTree},
     traits::{MaskedMerkleParameters, MerkleParameters, CRH},
 };
 use snarkvm_curves::{
@@ -38,7 +41,7 @@ use crate::{
         merkle_tree::*,
     },
     curves::edwards_bls12::EdwardsBlsGadget,
-    integers::uint::UInt8,
+    integers::uint::{UInt64, UInt8},
     traits::{
         algorithms::{CRHGadget, MaskedCRHGadget},
         alloc::AllocGadget,
@@ -198,6 +201,117 @@ fn generate_masked_merkle_tree<P: MaskedMerkleParameters, F: PrimeField, HG: Mas
     assert!(cs.is_satisfied());
 }
 
+fn check_non_membership<P: MerkleParameters, F: PrimeField, HG: CRHGadget<P::H, F>>(
+    parameters: &P,
+    root: &<P::H as CRH>::Output,
+    proof: &NonMembershipProof<P>,
+    value: u64,
+) -> bool {
+    let mut cs = TestConstraintSystem::<F>::new();
+
+    let crh_parameters = <HG as CRHGadget<_, _>>::ParametersGadget::alloc(&mut cs.ns(|| "new_parameters"), || {
+        Ok(parameters.parameters())
+    })
+    .unwrap();
+    let root = <HG as CRHGadget<_, _>>::OutputGadget::alloc(&mut cs.ns(|| "root"), || Ok(root)).unwrap();
+    let value = UInt64::alloc(cs.ns(|| "value"), || Ok(value)).unwrap();
+    let proof = NonMembershipGadget::<_, HG, _>::alloc(&mut cs.ns(|| "proof"), || Ok(proof)).unwrap();
+
+    proof
+        .check_non_membership(cs.ns(|| "check_non_membership"), &crh_parameters, &root, &value)
+        .unwrap();
+
+    if !cs.is_satisfied() {
+        println!("Unsatisfied constraint: {}", cs.which_is_unsatisfied().unwrap());
+    }
+    cs.is_satisfied()
+}
+
+fn generate_non_membership_proofs<P: MerkleParameters + Send + Sync, F: PrimeField, HG: CRHGadget<P::H, F>>() {
+    let parameters = P::default();
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // Test an empty tree, a full tree, and a tree with a random number of values.
+    let max_num_values = (1 << P::DEPTH) - 2;
+    for &num_values in &[0, max_num_values, rng.gen_range(1..max_num_values)] {
+        // Keep 1 and u64::MAX - 1 free, to test values outside the range of the leaves.
+        let values = (0..num_values)
+            .map(|_| rng.gen_range(2..u64::MAX - 1))
+            .collect::<Vec<_>>();
+
+        let tree = SortedMerkleTree::new(Arc::new(parameters.clone()), &values).unwrap();
+        let root = tree.root();
+
+        for &value in &[1, u64::MAX - 1, rng.gen_range(1..u64::MAX)] {
+            let proof = tree.prove_non_membership(value).unwrap();
+            assert!(proof.verify(&root, value).unwrap());
+            assert!(check_non_membership::<P, F, HG>(&parameters, &root, &proof, value));
+        }
+    }
+}
+
+fn generate_adversarial_non_membership_proofs<
+    P: MerkleParameters + Send + Sync,
+    F: PrimeField,
+    HG: CRHGadget<P::H, F>,
+>() {
+    let parameters = P::default();
+
+    let tree = SortedMerkleTree::new(Arc::new(parameters.clone()), &[10, 20, 30, 40, 50]).unwrap();
+    let root = tree.root();
+
+    // Builds a proof from any two leaves of the tree.
+    let proof = |left_leaf: u64, right_leaf: u64| NonMembershipProof {
+        left_leaf,
+        left_path: tree.prove_membership(left_leaf).unwrap(),
+        right_leaf,
+        right_path: tree.prove_membership(right_leaf).unwrap(),
+    };
+
+    assert!(check_non_membership::<P, F, HG>(&parameters, &root, &proof(20, 30), 25));
+
+    // Non-adjacent leaves are rejected, even when they surround a non-member.
+    assert!(!check_non_membership::<P, F, HG>(
+        &parameters,
+        &root,
+        &proof(10, 30),
+        20
+    ));
+    assert!(!check_non_membership::<P, F, HG>(
+        &parameters,
+        &root,
+        &proof(10, 30),
+        25
+    ));
+
+    // Leaves in the wrong order are rejected.
+    assert!(!check_non_membership::<P, F, HG>(
+        &parameters,
+        &root,
+        &proof(u64::MAX, u64::MIN),
+        25
+    ));
+
+    // The value must lie strictly between the leaves.
+    assert!(!check_non_membership::<P, F, HG>(
+        &parameters,
+        &root,
+        &proof(20, 30),
+        30
+    ));
+    assert!(!check_non_membership::<P, F, HG>(
+        &parameters,
+        &root,
+        &proof(20, 30),
+        35
+    ));
+
+    // The paths must belong to the given leaves.
+    let mut bad_proof = proof(20, 30);
+    bad_proof.left_leaf = 21;
+    assert!(!check_non_membership::<P, F, HG>(&parameters, &root, &bad_proof, 25));
+}
+
 mod merkle_tree_pedersen_crh_on_affine {
     use super::*;
 
@@ -277,6 +391,16 @@ mod merkle_tree_compressed_pedersen_crh_on_projective {
         }
         generate_masked_merkle_tree::<EdwardsMerkleParameters, Fr, HG>(&leaves, true);
     }
+
+    #[test]
+    fn non_membership_test() {
+        generate_non_membership_proofs::<EdwardsMerkleParameters, Fr, HG>();
+    }
+
+    #[test]
+    fn adversarial_non_membership_test() {
+        generate_adversarial_non_membership_proofs::<EdwardsMerkleParameters, Fr, HG>();
+    }
 }
 
 mod merkle_tree_bowe_hopwood_pedersen_compressed_crh_on_projective {
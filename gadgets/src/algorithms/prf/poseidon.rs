@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{borrow::Borrow, marker::PhantomData};
+
+use snarkvm_algorithms::{crh::PoseidonParameters, prf::PoseidonPRF, traits::PRF};
+use snarkvm_fields::{FieldParameters, PoseidonMDSField, PrimeField};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem, ConstraintVariable, LinearCombination};
+use snarkvm_utilities::{to_bytes, ToBytes};
+
+use crate::{
+    algorithms::crh::{PoseidonCRHGadget, PoseidonCRHParametersGadget},
+    bits::{Boolean, ToBytesGadget},
+    fields::{AllocatedFp, FpGadget},
+    integers::uint::UInt8,
+    traits::{
+        algorithms::PRFGadget,
+        alloc::AllocGadget,
+        eq::{ConditionalEqGadget, EqGadget},
+    },
+};
+
+/// Packs the bytes into field elements in the same way as the native
+/// `bytes_to_field_elements_mod_order`, that is, each chunk of the size of a
+/// serialized field element is read as a little-endian integer modulo the
+/// order of the field. The packing is a linear combination of the underlying
+/// bits, so no constraints are added.
+fn bytes_to_field_elements_mod_order<F: PrimeField, CS: ConstraintSystem<F>>(bytes: &[UInt8]) -> Vec<FpGadget<F>> {
+    let chunk_size = (<F as PrimeField>::Parameters::MODULUS_BITS as usize + 63) / 64 * 8;
+
+    let mut res = Vec::new();
+    for chunk in bytes.chunks(chunk_size) {
+        let mut lc = LinearCombination::zero();
+        let mut value = Some(F::zero());
+        let mut is_constant = true;
+        let mut coeff = F::one();
+
+        for bit in chunk.iter().flat_map(|byte| byte.bits.iter()) {
+            lc = lc + bit.lc(CS::one(), coeff);
+            value = match (value, bit.get_value()) {
+                (Some(value), Some(true)) => Some(value + coeff),
+                (Some(value), Some(false)) => Some(value),
+                _ => None,
+            };
+            is_constant &= matches!(bit, Boolean::Constant(_));
+            coeff.double_in_place();
+        }
+
+        res.push(match value {
+            Some(value) if is_constant => FpGadget::Constant(value),
+            _ => FpGadget::Variable(AllocatedFp {
+                value,
+                variable: ConstraintVariable::LC(lc),
+            }),
+        });
+    }
+
+    res
+}
+
+pub struct PoseidonPRFGadget<F: PrimeField + PoseidonMDSField, const RATE: usize>(PhantomData<F>);
+
+/// The output of the Poseidon PRF gadget. Its bytes are enforced to be the canonical
+/// encoding of the field element, so that the output has a unique byte representation.
+#[derive(Clone, Debug)]
+pub struct PoseidonPRFOutputGadget<F: PrimeField>(pub FpGadget<F>);
+
+impl<F: PrimeField> PartialEq for PoseidonPRFOutputGadget<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<F: PrimeField> Eq for PoseidonPRFOutputGadget<F> {}
+
+impl<F: PrimeField> EqGadget<F> for PoseidonPRFOutputGadget<F> {}
+
+impl<F: PrimeField> ConditionalEqGadget<F> for PoseidonPRFOutputGadget<F> {
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.0.conditional_enforce_equal(cs, &other.0, condition)
+    }
+
+    fn cost() -> usize {
+        <FpGadget<F> as ConditionalEqGadget<F>>::cost()
+    }
+}
+
+impl<F: PrimeField> ToBytesGadget<F> for PoseidonPRFOutputGadget<F> {
+    #[inline]
+    fn to_bytes<CS: ConstraintSystem<F>>(&self, cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.0.to_bytes_strict(cs)
+    }
+
+    #[inline]
+    fn to_bytes_strict<CS: ConstraintSystem<F>>(&self, cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.0.to_bytes_strict(cs)
+    }
+}
+
+impl<F: PrimeField> AllocGadget<F, F> for PoseidonPRFOutputGadget<F> {
+    #[inline]
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<F>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self(FpGadget::alloc(cs, value_gen)?))
+    }
+
+    #[inline]
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<F>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self(FpGadget::alloc_input(cs, value_gen)?))
+    }
+}
+
+impl<F: PrimeField + PoseidonMDSField, const RATE: usize> PRFGadget<PoseidonPRF<F, RATE>, F>
+    for PoseidonPRFGadget<F, RATE>
+{
+    type OutputGadget = PoseidonPRFOutputGadget<F>;
+
+    fn new_seed<CS: ConstraintSystem<F>>(mut cs: CS, seed: &<PoseidonPRF<F, RATE> as PRF>::Seed) -> Vec<UInt8> {
+        UInt8::alloc_vec(&mut cs.ns(|| "alloc_seed"), &to_bytes![seed].unwrap()).unwrap()
+    }
+
+    fn check_evaluation_gadget<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        seed: &[UInt8],
+        input: &[UInt8],
+    ) -> Result<Self::OutputGadget, SynthesisError> {
+        let mut elements = bytes_to_field_elements_mod_order::<F, CS>(seed);
+        elements.extend(bytes_to_field_elements_mod_order::<F, CS>(input));
+
+        let parameters =
+            PoseidonCRHParametersGadget::alloc(
+                cs.ns(|| "parameters"),
+                || Ok(PoseidonParameters::<F, RATE>::default()),
+            )?;
+        let output =
+            PoseidonCRHGadget::check_evaluation_gadget_on_field_elements(cs.ns(|| "hash"), &parameters, &elements)?;
+
+        Ok(PoseidonPRFOutputGadget(output))
+    }
+}
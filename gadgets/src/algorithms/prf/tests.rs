@@ -19,13 +19,20 @@ use digest::{Update, VariableOutput};
 use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 
-use snarkvm_algorithms::{prf::blake2s::Blake2s as B2SPRF, traits::PRF};
+use snarkvm_algorithms::{
+    prf::{blake2s::Blake2s as B2SPRF, PoseidonPRF, PoseidonPRFSeed},
+    traits::PRF,
+};
 use snarkvm_curves::bls12_377::Fr;
 use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+use snarkvm_utilities::{rand::UniformRand, to_bytes, ToBytes};
 
 use crate::{
     algorithms::prf::*,
-    bits::boolean::{AllocatedBit, Boolean},
+    bits::{
+        boolean::{AllocatedBit, Boolean},
+        ToBytesGadget,
+    },
     integers::uint::UInt8,
     traits::{algorithms::PRFGadget, alloc::AllocGadget, eq::EqGadget, integers::integer::Integer},
 };
@@ -157,3 +164,108 @@ fn test_blake2s() {
         }
     }
 }
+
+type PoseidonPRFGadgetRate2 = PoseidonPRFGadget<Fr, 2>;
+
+#[test]
+fn test_poseidon_prf() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for i in 0..10 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let seed = PoseidonPRFSeed(Fr::rand(&mut rng));
+        let input = Fr::rand(&mut rng);
+
+        let seed_gadget = PoseidonPRFGadgetRate2::new_seed(&mut cs.ns(|| "declare_seed"), &seed);
+        let input_gadget = UInt8::alloc_vec(&mut cs.ns(|| "declare_input"), &to_bytes![input].unwrap()).unwrap();
+        let out = PoseidonPRF::<Fr, 2>::evaluate(&seed, &input).unwrap();
+        let actual_out_gadget =
+            <PoseidonPRFGadgetRate2 as PRFGadget<_, Fr>>::OutputGadget::alloc(&mut cs.ns(|| "declare_output"), || {
+                Ok(out)
+            })
+            .unwrap();
+
+        let output_gadget = PoseidonPRFGadgetRate2::check_evaluation_gadget(
+            &mut cs.ns(|| "eval_poseidon"),
+            &seed_gadget,
+            &input_gadget,
+        )
+        .unwrap();
+        output_gadget.enforce_equal(&mut cs, &actual_out_gadget).unwrap();
+
+        // The output bytes match the native output bytes.
+        let output_bytes = output_gadget.to_bytes(&mut cs.ns(|| "output_to_bytes")).unwrap();
+        let expected_bytes = to_bytes![out].unwrap();
+        assert_eq!(
+            output_bytes.iter().map(|byte| byte.value.unwrap()).collect::<Vec<_>>(),
+            expected_bytes,
+            "iteration {}",
+            i
+        );
+
+        if !cs.is_satisfied() {
+            println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+        }
+        assert!(cs.is_satisfied());
+    }
+}
+
+#[test]
+fn test_poseidon_prf_wrong_output() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut cs = TestConstraintSystem::<Fr>::new();
+
+    let seed = PoseidonPRFSeed(Fr::rand(&mut rng));
+    let input = Fr::rand(&mut rng);
+
+    let seed_gadget = PoseidonPRFGadgetRate2::new_seed(&mut cs.ns(|| "declare_seed"), &seed);
+    let input_gadget = UInt8::alloc_vec(&mut cs.ns(|| "declare_input"), &to_bytes![input].unwrap()).unwrap();
+    let wrong_out = PoseidonPRF::<Fr, 2>::evaluate(&seed, &(input + Fr::from(1u64))).unwrap();
+    let wrong_out_gadget =
+        <PoseidonPRFGadgetRate2 as PRFGadget<_, Fr>>::OutputGadget::alloc(&mut cs.ns(|| "declare_output"), || {
+            Ok(wrong_out)
+        })
+        .unwrap();
+
+    let output_gadget =
+        PoseidonPRFGadgetRate2::check_evaluation_gadget(&mut cs.ns(|| "eval_poseidon"), &seed_gadget, &input_gadget)
+            .unwrap();
+    output_gadget.enforce_equal(&mut cs, &wrong_out_gadget).unwrap();
+
+    assert!(!cs.is_satisfied());
+}
+
+#[test]
+fn test_poseidon_prf_constraints() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let mut seed = [0u8; 32];
+    rng.fill(&mut seed);
+    let mut input = [0u8; 32];
+    rng.fill(&mut input);
+
+    let mut blake2s_cs = TestConstraintSystem::<Fr>::new();
+    let seed_gadget = Blake2sGadget::new_seed(&mut blake2s_cs.ns(|| "declare_seed"), &seed);
+    let input_gadget = UInt8::alloc_vec(&mut blake2s_cs.ns(|| "declare_input"), &input).unwrap();
+    let allocation_constraints = blake2s_cs.num_constraints();
+    let output =
+        Blake2sGadget::check_evaluation_gadget(&mut blake2s_cs.ns(|| "eval"), &seed_gadget, &input_gadget).unwrap();
+    output.to_bytes(&mut blake2s_cs.ns(|| "to_bytes")).unwrap();
+    let blake2s_constraints = blake2s_cs.num_constraints() - allocation_constraints;
+
+    let mut poseidon_cs = TestConstraintSystem::<Fr>::new();
+    let seed_gadget = UInt8::alloc_vec(&mut poseidon_cs.ns(|| "declare_seed"), &seed).unwrap();
+    let input_gadget = UInt8::alloc_vec(&mut poseidon_cs.ns(|| "declare_input"), &input).unwrap();
+    assert_eq!(poseidon_cs.num_constraints(), allocation_constraints);
+    let output =
+        PoseidonPRFGadgetRate2::check_evaluation_gadget(&mut poseidon_cs.ns(|| "eval"), &seed_gadget, &input_gadget)
+            .unwrap();
+    output.to_bytes(&mut poseidon_cs.ns(|| "to_bytes")).unwrap();
+    let poseidon_constraints = poseidon_cs.num_constraints() - allocation_constraints;
+
+    assert!(blake2s_cs.is_satisfied());
+    assert!(poseidon_cs.is_satisfied());
+    assert_eq!(blake2s_constraints, 21280);
+    assert_eq!(poseidon_constraints, 953);
+}
@@ -15,7 +15,8 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use blake2::Blake2s;
-use rand::{thread_rng, Rng};
+use rand::{thread_rng, Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
 
 use snarkvm_algorithms::{signature::SchnorrSignature, traits::SignatureScheme};
 use snarkvm_curves::{bls12_377::Fr, edwards_bls12::EdwardsAffine, traits::Group};
@@ -24,6 +25,7 @@ use snarkvm_utilities::{bytes::ToBytes, rand::UniformRand, to_bytes};
 
 use crate::{
     algorithms::signature::{SchnorrParametersGadget, SchnorrPublicKeyGadget, SchnorrPublicKeyRandomizationGadget},
+    bits::ToBytesGadget,
     curves::edwards_bls12::EdwardsBlsGadget,
     integers::uint::UInt8,
     traits::{algorithms::SignaturePublicKeyRandomizationGadget, alloc::AllocGadget, eq::EqGadget},
@@ -112,3 +114,79 @@ fn test_schnorr_signature_randomize_public_key_gadget() {
     }
     assert!(cs.is_satisfied());
 }
+
+#[test]
+fn test_schnorr_signature_randomize_public_key_gadget_matches_native() {
+    type Schnorr = SchnorrSignature<EdwardsAffine, Blake2s>;
+
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let schnorr_signature = Schnorr::setup::<_>(rng).unwrap();
+
+    for i in 0..5 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let mut message = [0u8; 32];
+        rng.fill(&mut message);
+
+        let private_key = schnorr_signature.generate_private_key(rng).unwrap();
+        let public_key = schnorr_signature.generate_public_key(&private_key).unwrap();
+        let signature = schnorr_signature.sign(&private_key, &message, rng).unwrap();
+
+        // Native Schnorr randomization
+
+        let random_scalar = to_bytes!(<EdwardsAffine as Group>::ScalarField::rand(rng)).unwrap();
+        let randomized_public_key = schnorr_signature
+            .randomize_public_key(&public_key, &random_scalar)
+            .unwrap();
+        let randomized_signature = schnorr_signature
+            .randomize_signature(&signature, &random_scalar)
+            .unwrap();
+        assert!(
+            schnorr_signature
+                .verify(&randomized_public_key, &message, &randomized_signature)
+                .unwrap()
+        );
+
+        // Circuit Schnorr randomization
+
+        let parameters_gadget = SchnorrParametersGadget::<EdwardsAffine, Fr, Blake2s>::alloc(
+            &mut cs.ns(|| "parameters"),
+            || Ok(schnorr_signature.parameters()),
+        )
+        .unwrap();
+        let public_key_gadget = SchnorrPublicKeyGadget::<EdwardsAffine, Fr, EdwardsBlsGadget>::alloc(
+            &mut cs.ns(|| "public_key"),
+            || Ok(&public_key),
+        )
+        .unwrap();
+        let randomizer = UInt8::alloc_vec(&mut cs.ns(|| "randomizer"), &random_scalar).unwrap();
+
+        let randomized_public_key_gadget = <SchnorrPublicKeyRandomizationGadget<
+            EdwardsAffine,
+            Fr,
+            EdwardsBlsGadget,
+        > as SignaturePublicKeyRandomizationGadget<Schnorr, Fr>>::check_randomization_gadget(
+            &mut cs.ns(|| "randomized_public_key"),
+            &parameters_gadget,
+            &public_key_gadget,
+            &randomizer,
+        )
+        .unwrap();
+
+        let randomized_public_key_bytes = randomized_public_key_gadget
+            .to_bytes(&mut cs.ns(|| "randomized_public_key_to_bytes"))
+            .unwrap()
+            .iter()
+            .map(|byte| byte.value.unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            to_bytes![randomized_public_key].unwrap(),
+            randomized_public_key_bytes,
+            "iteration {}",
+            i
+        );
+        assert!(cs.is_satisfied());
+    }
+}
@@ -16,24 +16,29 @@
 
 use std::{borrow::Borrow, marker::PhantomData};
 
-use snarkvm_algorithms::snark::gm17::{Proof, VerifyingKey, GM17};
+use snarkvm_algorithms::snark::gm17::{PreparedVerifyingKey, Proof, VerifyingKey, GM17};
 use snarkvm_curves::traits::{AffineCurve, PairingEngine};
-use snarkvm_fields::{Field, ToConstraintField};
+use snarkvm_fields::{Field, PoseidonMDSField, PrimeField, ToConstraintField};
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
 use snarkvm_utilities::bytes::FromBytes;
 
 use crate::{
-    bits::{Boolean, ToBitsBEGadget, ToBytesGadget},
+    algorithms::{crh::PoseidonSpongeGadget, snark::accumulate_constant_query},
+    bits::{Boolean, ToBitsBEGadget, ToBitsLEGadget, ToBytesGadget},
+    fields::FpGadget,
     integers::uint::UInt8,
     traits::{
-        algorithms::SNARKVerifierGadget,
+        algorithms::{AlgebraicSpongeVar, PreparedSNARKVerifierGadget, SNARKVerifierGadget},
         alloc::{AllocBytesGadget, AllocGadget},
         curves::{GroupGadget, PairingGadget},
-        eq::EqGadget,
-        fields::FieldGadget,
+        eq::{ConditionalEqGadget, EqGadget},
+        fields::{FieldGadget, ToConstraintFieldGadget},
     },
 };
 
+/// The number of bits of the challenge that merges the two verification equations in `check_verify_merged`.
+pub const GM17_MERGE_CHALLENGE_BITS: usize = 128;
+
 #[derive(Derivative)]
 #[derivative(Clone(bound = "P::G1Gadget: Clone, P::G2Gadget: Clone"))]
 pub struct GM17ProofGadget<PairingE: PairingEngine, F: Field, P: PairingGadget<PairingE, F>> {
@@ -111,30 +116,134 @@ impl<
     type VerificationKeyGadget = GM17VerifyingKeyGadget<Pairing, F, P>;
 
     fn check_verify<CS: ConstraintSystem<F>, I: Iterator<Item = Self::Input>>(
-        mut cs: CS,
+        cs: CS,
         vk: &Self::VerificationKeyGadget,
-        mut public_inputs: I,
+        public_inputs: I,
         proof: &Self::ProofGadget,
+    ) -> Result<(), SynthesisError> {
+        Self::check_verify_conditional(cs, vk, public_inputs, proof, &Boolean::constant(true))
+    }
+}
+
+impl<
+    Pairing: PairingEngine,
+    F: Field,
+    P: PairingGadget<Pairing, F>,
+    C: ConstraintSynthesizer<Pairing::Fr>,
+    V: ToConstraintField<Pairing::Fr>,
+> PreparedSNARKVerifierGadget<GM17<Pairing, C, V>, F> for GM17VerifierGadget<Pairing, F, P>
+{
+    type PreparedVerificationKeyGadget = GM17PreparedVerifyingKeyGadget<Pairing, F, P>;
+
+    fn prepare_verification_key<CS: ConstraintSystem<F>>(
+        cs: CS,
+        vk: &Self::VerificationKeyGadget,
+    ) -> Result<Self::PreparedVerificationKeyGadget, SynthesisError> {
+        vk.prepare(cs)
+    }
+
+    fn check_verify_with_prepared<CS: ConstraintSystem<F>, I: Iterator<Item = Self::Input>>(
+        mut cs: CS,
+        pvk: &Self::PreparedVerificationKeyGadget,
+        public_inputs: I,
+        proof: &Self::ProofGadget,
+    ) -> Result<(), SynthesisError> {
+        let g_psi = Self::process_inputs(cs.ns(|| "Process input"), pvk, public_inputs)?;
+        Self::check_verify_prepared(cs, pvk, g_psi, proof, &Boolean::constant(true))
+    }
+}
+
+impl<Pairing: PairingEngine, F: Field, P: PairingGadget<Pairing, F>> GM17VerifierGadget<Pairing, F, P> {
+    /// Verifies the proof like `check_verify`, but only enforces the verification equations
+    /// if `condition` is true. This allows a circuit to skip the verification of a proof,
+    /// e.g. for a dummy record, in which case any proof is accepted.
+    pub fn check_verify_conditional<CS: ConstraintSystem<F>, I: Iterator<Item = Vec<Boolean>>>(
+        mut cs: CS,
+        vk: &GM17VerifyingKeyGadget<Pairing, F, P>,
+        public_inputs: I,
+        proof: &GM17ProofGadget<Pairing, F, P>,
+        condition: &Boolean,
     ) -> Result<(), SynthesisError> {
         let pvk = vk.prepare(&mut cs.ns(|| "Prepare vk"))?;
-        // e(A*G^{alpha}, B*H^{beta}) = e(G^{alpha}, H^{beta}) * e(G^{psi}, H^{gamma}) *
-        // e(C, H) where psi = \sum_{i=0}^l input_i pvk.query[i]
-
-        let g_psi = {
-            let mut cs = cs.ns(|| "Process input");
-            let mut g_psi = pvk.query[0].clone();
-            let mut input_len = 1;
-            for (i, (input, b)) in public_inputs.by_ref().zip(pvk.query.iter().skip(1)).enumerate() {
-                let input_bits = input.to_bits_be(cs.ns(|| format!("Input {}", i)))?;
-                g_psi = b.mul_bits(cs.ns(|| format!("Mul {}", i)), &g_psi, input_bits.into_iter())?;
-                input_len += 1;
-            }
-            // Check that the input and the query in the verification are of the
-            // same length.
-            assert!(input_len == pvk.query.len() && public_inputs.next().is_none());
-            g_psi
-        };
+        let g_psi = Self::process_inputs(cs.ns(|| "Process input"), &pvk, public_inputs)?;
+        Self::check_verify_prepared(cs, &pvk, g_psi, proof, condition)
+    }
+
+    /// Returns a `Boolean` that is true if and only if the proof is valid, without enforcing it.
+    pub fn verify_to_boolean<CS: ConstraintSystem<F>, I: Iterator<Item = Vec<Boolean>>>(
+        mut cs: CS,
+        vk: &GM17VerifyingKeyGadget<Pairing, F, P>,
+        public_inputs: I,
+        proof: &GM17ProofGadget<Pairing, F, P>,
+    ) -> Result<Boolean, SynthesisError> {
+        let pvk = vk.prepare(&mut cs.ns(|| "Prepare vk"))?;
+        let g_psi = Self::process_inputs(cs.ns(|| "Process input"), &pvk, public_inputs)?;
+        let (test1, test2) = Self::verification_equations(cs.ns(|| "Verification equations"), &pvk, g_psi, proof)?;
+
+        let one = P::GTGadget::one(cs.ns(|| "GT One"))?;
+        let test1_is_valid = test1.is_eq(cs.ns(|| "Test 1"), &one)?;
+        let test2_is_valid = test2.is_eq(cs.ns(|| "Test 2"), &one)?;
+        Boolean::and(cs.ns(|| "Test 1 and Test 2"), &test1_is_valid, &test2_is_valid)
+    }
+
+    /// Verifies the proof like `check_verify`, for a verifying key whose query is known when the
+    /// circuit is built. The public inputs are multiplied with the constant `query`, using
+    /// `constant_base_scalar_mul_le`, instead of with `vk.query`, which is ignored.
+    pub fn check_verify_with_constant_query<CS: ConstraintSystem<F>, I: Iterator<Item = Vec<Boolean>>>(
+        mut cs: CS,
+        vk: &GM17VerifyingKeyGadget<Pairing, F, P>,
+        query: &[Pairing::G1Affine],
+        public_inputs: I,
+        proof: &GM17ProofGadget<Pairing, F, P>,
+    ) -> Result<(), SynthesisError> {
+        let pvk = vk.prepare(&mut cs.ns(|| "Prepare vk"))?;
+        let g_psi = accumulate_constant_query::<Pairing, F, P, _, _>(cs.ns(|| "Process input"), query, public_inputs)?;
+        Self::check_verify_prepared(cs, &pvk, g_psi, proof, &Boolean::constant(true))
+    }
+
+    /// Returns `G^{psi}`, where `psi = \sum_{i=0}^l input_i pvk.query[i]`.
+    fn process_inputs<CS: ConstraintSystem<F>, I: Iterator<Item = Vec<Boolean>>>(
+        mut cs: CS,
+        pvk: &GM17PreparedVerifyingKeyGadget<Pairing, F, P>,
+        mut public_inputs: I,
+    ) -> Result<P::G1Gadget, SynthesisError> {
+        let mut g_psi = pvk.query[0].clone();
+        let mut input_len = 1;
+        for (i, (input, b)) in public_inputs.by_ref().zip(pvk.query.iter().skip(1)).enumerate() {
+            let input_bits = input.to_bits_be(cs.ns(|| format!("Input {}", i)))?;
+            g_psi = b.mul_bits(cs.ns(|| format!("Mul {}", i)), &g_psi, input_bits.into_iter())?;
+            input_len += 1;
+        }
+        // Check that the input and the query in the verification are of the
+        // same length.
+        assert!(input_len == pvk.query.len() && public_inputs.next().is_none());
+        Ok(g_psi)
+    }
+
+    fn check_verify_prepared<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        pvk: &GM17PreparedVerifyingKeyGadget<Pairing, F, P>,
+        g_psi: P::G1Gadget,
+        proof: &GM17ProofGadget<Pairing, F, P>,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        let (test1, test2) = Self::verification_equations(&mut cs, pvk, g_psi, proof)?;
 
+        let one = P::GTGadget::one(cs.ns(|| "GT One"))?;
+        test1.conditional_enforce_equal(cs.ns(|| "Test 1"), &one, condition)?;
+        test2.conditional_enforce_equal(cs.ns(|| "Test 2"), &one, condition)?;
+        Ok(())
+    }
+
+    /// Returns the left-hand sides of the two verification equations, which both equal one
+    /// in the target group if and only if the proof is valid.
+    fn verification_equations<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        pvk: &GM17PreparedVerifyingKeyGadget<Pairing, F, P>,
+        g_psi: P::G1Gadget,
+        proof: &GM17ProofGadget<Pairing, F, P>,
+    ) -> Result<(P::GTGadget, P::GTGadget), SynthesisError> {
+        // e(A*G^{alpha}, B*H^{beta}) = e(G^{alpha}, H^{beta}) * e(G^{psi}, H^{gamma}) * e(C, H)
         let mut test1_a_g_alpha = proof.a.add(cs.ns(|| "A * G^{alpha}"), &pvk.g_alpha)?;
         let test1_b_h_beta = proof.b.add(cs.ns(|| "B * H^{beta}"), &pvk.h_beta)?;
 
@@ -169,22 +278,163 @@ impl<
             let proof_b = proof.b.negate(cs.ns(|| "Negate b"))?;
             let b_prep = P::prepare_g2(cs.ns(|| "Sixth prep"), proof_b)?;
             P::miller_loop(cs.ns(|| "Miller loop 4"), &[a_prep, pvk.g_gamma_pc.clone()], &[
-                pvk.h_gamma_pc,
+                pvk.h_gamma_pc.clone(),
                 b_prep,
             ])?
         };
         let test2 = P::final_exponentiation(cs.ns(|| "Final Exp 2"), &test2_exp)?;
 
+        Ok((test1, test2))
+    }
+}
+
+impl<Pairing: PairingEngine, F: PrimeField + PoseidonMDSField, P: PairingGadget<Pairing, F>>
+    GM17VerifierGadget<Pairing, F, P>
+where
+    P::G1Gadget: ToConstraintFieldGadget<F>,
+    P::G2Gadget: ToConstraintFieldGadget<F>,
+{
+    /// Verifies the proof like `check_verify`, but with one Miller loop and one final exponentiation.
+    ///
+    /// The second verification equation is raised to an odd power `m` and multiplied into the first,
+    /// where `m` is derived from a Poseidon sponge that absorbs the verifying key, the processed
+    /// public inputs, and the proof. If either equation fails, the product is one for at most one `m`.
+    pub fn check_verify_merged<CS: ConstraintSystem<F>, I: Iterator<Item = Vec<Boolean>>>(
+        mut cs: CS,
+        vk: &GM17VerifyingKeyGadget<Pairing, F, P>,
+        public_inputs: I,
+        proof: &GM17ProofGadget<Pairing, F, P>,
+    ) -> Result<(), SynthesisError> {
+        let pvk = vk.prepare(&mut cs.ns(|| "Prepare vk"))?;
+        let g_psi = Self::process_inputs(cs.ns(|| "Process input"), &pvk, public_inputs)?;
+        let challenge = Self::merge_challenge(cs.ns(|| "Merge challenge"), vk, &g_psi, proof)?;
+
+        // Compute A^m and G^{gamma*m} for m = 2 * challenge + 1, which is odd so that
+        // the incomplete additions in `mul_bits` never add a point to itself.
+        let mut a_m = proof.a.clone();
+        a_m.double_in_place(cs.ns(|| "Double A"))?;
+        let a_m = a_m.mul_bits(cs.ns(|| "A^m"), &proof.a, challenge.iter().cloned())?;
+        let mut g_gamma_m = vk.g_gamma_g1.clone();
+        g_gamma_m.double_in_place(cs.ns(|| "Double G^{gamma}"))?;
+        let g_gamma_m = g_gamma_m.mul_bits(cs.ns(|| "G^{gamma*m}"), &vk.g_gamma_g1, challenge.into_iter())?;
+
+        // e(A*G^{alpha}, B*H^{beta})^{-1} * e(G^{psi}, H^{gamma}) * e(C, H) * e(G^{alpha}, H^{beta}) = 1,
+        // times (e(A, H^{gamma}) * e(G^{gamma}, B^{-1}))^m = 1, where both pairings with H^{gamma} share one.
+        let test_exp = {
+            let a_g_alpha = proof.a.add(cs.ns(|| "A * G^{alpha}"), &pvk.g_alpha)?;
+            let a_g_alpha = a_g_alpha.negate(cs.ns(|| "Negate A * G^{alpha}"))?;
+            let b_h_beta = proof.b.add(cs.ns(|| "B * H^{beta}"), &pvk.h_beta)?;
+            let b_neg = proof.b.negate(cs.ns(|| "Negate B"))?;
+
+            let a_g_alpha_prep = P::prepare_g1(cs.ns(|| "Prepare A * G^{alpha}"), a_g_alpha)?;
+            let b_h_beta_prep = P::prepare_g2(cs.ns(|| "Prepare B * H^{beta}"), b_h_beta)?;
+            let g_psi_a_m = g_psi.add(cs.ns(|| "G^{psi} * A^m"), &a_m)?;
+            let g_psi_a_m_prep = P::prepare_g1(cs.ns(|| "Prepare G^{psi} * A^m"), g_psi_a_m)?;
+            let c_prep = P::prepare_g1(cs.ns(|| "Prepare C"), proof.c.clone())?;
+            let g_gamma_m_prep = P::prepare_g1(cs.ns(|| "Prepare G^{gamma*m}"), g_gamma_m)?;
+            let b_neg_prep = P::prepare_g2(cs.ns(|| "Prepare B^{-1}"), b_neg)?;
+
+            P::miller_loop(
+                cs.ns(|| "Miller loop"),
+                &[
+                    a_g_alpha_prep,
+                    g_psi_a_m_prep,
+                    c_prep,
+                    pvk.g_alpha_pc.clone(),
+                    g_gamma_m_prep,
+                ],
+                &[
+                    b_h_beta_prep,
+                    pvk.h_gamma_pc.clone(),
+                    pvk.h_pc.clone(),
+                    pvk.h_beta_pc.clone(),
+                    b_neg_prep,
+                ],
+            )?
+        };
+        let test = P::final_exponentiation(cs.ns(|| "Final Exp"), &test_exp)?;
+
         let one = P::GTGadget::one(cs.ns(|| "GT One"))?;
-        test1.enforce_equal(cs.ns(|| "Test 1"), &one)?;
-        test2.enforce_equal(cs.ns(|| "Test 2"), &one)?;
+        test.enforce_equal(cs.ns(|| "Test"), &one)?;
         Ok(())
     }
+
+    /// Returns the `GM17_MERGE_CHALLENGE_BITS` little-endian bits of the challenge that merges
+    /// the verification equations, squeezed after absorbing every point that appears in them.
+    fn merge_challenge<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        vk: &GM17VerifyingKeyGadget<Pairing, F, P>,
+        g_psi: &P::G1Gadget,
+        proof: &GM17ProofGadget<Pairing, F, P>,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
+        let mut elements = Vec::new();
+        for (i, g1) in [&vk.g_alpha_g1, &vk.g_gamma_g1, g_psi, &proof.a, &proof.c]
+            .iter()
+            .enumerate()
+        {
+            elements.extend(g1.to_constraint_field(cs.ns(|| format!("G1 element {}", i)))?);
+        }
+        for (i, g2) in [&vk.h_g2, &vk.h_beta_g2, &vk.h_gamma_g2, &proof.b].iter().enumerate() {
+            elements.extend(g2.to_constraint_field(cs.ns(|| format!("G2 element {}", i)))?);
+        }
+
+        let mut sponge = PoseidonSpongeGadget::<F, 4>::new(cs.ns(|| "Sponge"));
+        sponge.absorb(cs.ns(|| "Absorb"), &elements)?;
+        let challenge = sponge.squeeze(cs.ns(|| "Squeeze"), 1)?;
+
+        let mut challenge_bits = challenge[0].to_bits_le(cs.ns(|| "Challenge to bits"))?;
+        challenge_bits.truncate(GM17_MERGE_CHALLENGE_BITS);
+        Ok(challenge_bits)
+    }
 }
 
 impl<Pairing: PairingEngine, F: Field, P: PairingGadget<Pairing, F>> AllocGadget<VerifyingKey<Pairing>, F>
     for GM17VerifyingKeyGadget<Pairing, F, P>
 {
+    #[inline]
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<VerifyingKey<Pairing>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        value_gen().and_then(|vk| {
+            let VerifyingKey {
+                h_g2,
+                g_alpha_g1,
+                h_beta_g2,
+                g_gamma_g1,
+                h_gamma_g2,
+                query,
+            } = vk.borrow().clone();
+            let h_g2 = P::G2Gadget::alloc_constant(cs.ns(|| "h_g2"), || Ok(h_g2.into_projective()))?;
+            let g_alpha_g1 = P::G1Gadget::alloc_constant(cs.ns(|| "g_alpha"), || Ok(g_alpha_g1.into_projective()))?;
+            let h_beta_g2 = P::G2Gadget::alloc_constant(cs.ns(|| "h_beta"), || Ok(h_beta_g2.into_projective()))?;
+            let g_gamma_g1 = P::G1Gadget::alloc_constant(cs.ns(|| "g_gamma_g1"), || Ok(g_gamma_g1.into_projective()))?;
+            let h_gamma_g2 = P::G2Gadget::alloc_constant(cs.ns(|| "h_gamma_g2"), || Ok(h_gamma_g2.into_projective()))?;
+
+            let query = query
+                .into_iter()
+                .enumerate()
+                .map(|(i, query_i)| {
+                    P::G1Gadget::alloc_constant(cs.ns(|| format!("query_{}", i)), || Ok(query_i.into_projective()))
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .collect::<Result<_, _>>()?;
+            Ok(Self {
+                h_g2,
+                g_alpha_g1,
+                h_beta_g2,
+                g_gamma_g1,
+                h_gamma_g2,
+                query,
+            })
+        })
+    }
+
     #[inline]
     fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<VerifyingKey<Pairing>>, CS: ConstraintSystem<F>>(
         mut cs: CS,
@@ -270,6 +520,55 @@ impl<Pairing: PairingEngine, F: Field, P: PairingGadget<Pairing, F>> AllocGadget
     }
 }
 
+impl<Pairing: PairingEngine, F: Field, P: PairingGadget<Pairing, F>> AllocGadget<PreparedVerifyingKey<Pairing>, F>
+    for GM17PreparedVerifyingKeyGadget<Pairing, F, P>
+{
+    #[inline]
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<PreparedVerifyingKey<Pairing>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let vk = GM17VerifyingKeyGadget::<Pairing, F, P>::alloc_constant(cs.ns(|| "Allocate vk"), || {
+            value_gen().map(|pvk| pvk.borrow().vk.clone())
+        })?;
+        vk.prepare(cs.ns(|| "Prepare vk"))
+    }
+
+    #[inline]
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<PreparedVerifyingKey<Pairing>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let vk = GM17VerifyingKeyGadget::<Pairing, F, P>::alloc(cs.ns(|| "Allocate vk"), || {
+            value_gen().map(|pvk| pvk.borrow().vk.clone())
+        })?;
+        vk.prepare(cs.ns(|| "Prepare vk"))
+    }
+
+    #[inline]
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<PreparedVerifyingKey<Pairing>>,
+        CS: ConstraintSystem<F>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let vk = GM17VerifyingKeyGadget::<Pairing, F, P>::alloc_input(cs.ns(|| "Allocate vk"), || {
+            value_gen().map(|pvk| pvk.borrow().vk.clone())
+        })?;
+        vk.prepare(cs.ns(|| "Prepare vk"))
+    }
+}
+
 impl<Pairing: PairingEngine, F: Field, P: PairingGadget<Pairing, F>> AllocBytesGadget<Vec<u8>, F>
     for GM17VerifyingKeyGadget<Pairing, F, P>
 {
@@ -308,13 +607,28 @@ impl<Pairing: PairingEngine, F: Field, P: PairingGadget<Pairing, F>> AllocGadget
         mut cs: CS,
         value_gen: Fn,
     ) -> Result<Self, SynthesisError> {
-        value_gen().and_then(|proof| {
-            let Proof { a, b, c, .. } = proof.borrow().clone();
-            let a = P::G1Gadget::alloc_checked(cs.ns(|| "a"), || Ok(a.into_projective()))?;
-            let b = P::G2Gadget::alloc_checked(cs.ns(|| "b"), || Ok(b.into_projective()))?;
-            let c = P::G1Gadget::alloc_checked(cs.ns(|| "c"), || Ok(c.into_projective()))?;
-            Ok(Self { a, b, c })
-        })
+        // Missing assignments are deferred to the individual points, so that
+        // the proof can be allocated in setup mode without a witness.
+        let (a, b, c) = match value_gen() {
+            Ok(proof) => {
+                let Proof { a, b, c, .. } = proof.borrow();
+                (
+                    Ok(a.into_projective()),
+                    Ok(b.into_projective()),
+                    Ok(c.into_projective()),
+                )
+            }
+            Err(error) => (
+                Err(error),
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        let a = P::G1Gadget::alloc_checked(cs.ns(|| "a"), || a)?;
+        let b = P::G2Gadget::alloc_checked(cs.ns(|| "b"), || b)?;
+        let c = P::G1Gadget::alloc_checked(cs.ns(|| "c"), || c)?;
+        Ok(Self { a, b, c })
     }
 
     #[inline]
@@ -343,11 +657,12 @@ impl<Pairing: PairingEngine, F: Field, P: PairingGadget<Pairing, F>> AllocBytesG
         FN: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<Vec<u8>>,
     {
-        value_gen().and_then(|proof_bytes| {
-            let proof: Proof<Pairing> = FromBytes::read(&proof_bytes.borrow().clone()[..])?;
+        let proof = value_gen().and_then(|proof_bytes| {
+            let proof: Proof<Pairing> = FromBytes::read(&proof_bytes.borrow()[..])?;
+            Ok(proof)
+        });
 
-            Self::alloc(cs.ns(|| "alloc_bytes"), || Ok(proof))
-        })
+        Self::alloc(cs.ns(|| "alloc_bytes"), || proof)
     }
 
     #[inline]
@@ -390,3 +705,43 @@ impl<Pairing: PairingEngine, F: Field, P: PairingGadget<Pairing, F>> ToBytesGadg
         self.to_bytes(cs)
     }
 }
+
+impl<Pairing: PairingEngine, F: PrimeField, P: PairingGadget<Pairing, F>> ToConstraintFieldGadget<F>
+    for GM17VerifyingKeyGadget<Pairing, F, P>
+where
+    P::G1Gadget: ToConstraintFieldGadget<F>,
+    P::G2Gadget: ToConstraintFieldGadget<F>,
+{
+    fn to_constraint_field<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<FpGadget<F>>, SynthesisError> {
+        let mut res = Vec::new();
+        res.extend_from_slice(&self.h_g2.to_constraint_field(cs.ns(|| "h_g2_to_constraint_field"))?);
+        res.extend_from_slice(
+            &self
+                .g_alpha_g1
+                .to_constraint_field(cs.ns(|| "g_alpha_g1_to_constraint_field"))?,
+        );
+        res.extend_from_slice(
+            &self
+                .h_beta_g2
+                .to_constraint_field(cs.ns(|| "h_beta_g2_to_constraint_field"))?,
+        );
+        res.extend_from_slice(
+            &self
+                .g_gamma_g1
+                .to_constraint_field(cs.ns(|| "g_gamma_g1_to_constraint_field"))?,
+        );
+        res.extend_from_slice(
+            &self
+                .h_gamma_g2
+                .to_constraint_field(cs.ns(|| "h_gamma_g2_to_constraint_field"))?,
+        );
+
+        // The length of the query is fixed when the verification key is allocated.
+        res.push(FpGadget::Constant(F::from(self.query.len() as u64)));
+        for (i, q) in self.query.iter().enumerate() {
+            res.extend_from_slice(&q.to_constraint_field(cs.ns(|| format!("query_{}_to_constraint_field", i)))?);
+        }
+
+        Ok(res)
+    }
+}
@@ -23,6 +23,7 @@ use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSyst
 use snarkvm_utilities::bytes::FromBytes;
 
 use crate::{
+    algorithms::snark::accumulate_constant_query,
     bits::{Boolean, ToBitsBEGadget, ToBytesGadget},
     integers::uint::UInt8,
     traits::{
@@ -155,6 +156,54 @@ where
             g_ic
         };
 
+        Self::check_verify_prepared(cs, gamma_g2_neg_pc, delta_g2_neg_pc, alpha_g1_beta_g2, g_ic, proof)
+    }
+}
+
+impl<PairingE, ConstraintF, P> Groth16VerifierGadget<PairingE, ConstraintF, P>
+where
+    PairingE: PairingEngine,
+    ConstraintF: Field,
+    P: PairingGadget<PairingE, ConstraintF>,
+{
+    /// Verifies the proof like `check_verify`, for a verifying key whose `gamma_abc_g1` is known
+    /// when the circuit is built. The public inputs are multiplied with the constant `gamma_abc_g1`,
+    /// using `constant_base_scalar_mul_le`, instead of with `vk.gamma_abc_g1`, which is ignored.
+    pub fn check_verify_with_constant_query<CS, I>(
+        mut cs: CS,
+        vk: &VerifyingKeyGadget<PairingE, ConstraintF, P>,
+        gamma_abc_g1: &[PairingE::G1Affine],
+        public_inputs: I,
+        proof: &ProofGadget<PairingE, ConstraintF, P>,
+    ) -> Result<(), SynthesisError>
+    where
+        CS: ConstraintSystem<ConstraintF>,
+        I: Iterator<Item = Vec<Boolean>>,
+    {
+        let PreparedVerifyingKeyGadget {
+            alpha_g1_beta_g2,
+            gamma_g2_neg_pc,
+            delta_g2_neg_pc,
+            ..
+        } = vk.prepare(&mut cs.ns(|| "Prepare vk"))?;
+
+        let g_ic = accumulate_constant_query::<PairingE, ConstraintF, P, _, _>(
+            cs.ns(|| "Process input"),
+            gamma_abc_g1,
+            public_inputs,
+        )?;
+
+        Self::check_verify_prepared(cs, gamma_g2_neg_pc, delta_g2_neg_pc, alpha_g1_beta_g2, g_ic, proof)
+    }
+
+    fn check_verify_prepared<CS: ConstraintSystem<ConstraintF>>(
+        mut cs: CS,
+        gamma_g2_neg_pc: P::G2PreparedGadget,
+        delta_g2_neg_pc: P::G2PreparedGadget,
+        alpha_g1_beta_g2: P::GTGadget,
+        g_ic: P::G1Gadget,
+        proof: &ProofGadget<PairingE, ConstraintF, P>,
+    ) -> Result<(), SynthesisError> {
         let test_exp = {
             let proof_a_prep = P::prepare_g1(cs.ns(|| "Prepare proof a"), proof.a.clone())?;
             let proof_b_prep = P::prepare_g2(cs.ns(|| "Prepare proof b"), proof.b.clone())?;
@@ -309,13 +358,28 @@ where
         FN: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<Proof<PairingE>>,
     {
-        value_gen().and_then(|proof| {
-            let Proof { a, b, c, .. } = proof.borrow();
-            let a = P::G1Gadget::alloc_checked(cs.ns(|| "a"), || Ok(a.into_projective()))?;
-            let b = P::G2Gadget::alloc_checked(cs.ns(|| "b"), || Ok(b.into_projective()))?;
-            let c = P::G1Gadget::alloc_checked(cs.ns(|| "c"), || Ok(c.into_projective()))?;
-            Ok(Self { a, b, c })
-        })
+        // Missing assignments are deferred to the individual points, so that
+        // the proof can be allocated in setup mode without a witness.
+        let (a, b, c) = match value_gen() {
+            Ok(proof) => {
+                let Proof { a, b, c, .. } = proof.borrow();
+                (
+                    Ok(a.into_projective()),
+                    Ok(b.into_projective()),
+                    Ok(c.into_projective()),
+                )
+            }
+            Err(error) => (
+                Err(error),
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        let a = P::G1Gadget::alloc_checked(cs.ns(|| "a"), || a)?;
+        let b = P::G2Gadget::alloc_checked(cs.ns(|| "b"), || b)?;
+        let c = P::G1Gadget::alloc_checked(cs.ns(|| "c"), || c)?;
+        Ok(Self { a, b, c })
     }
 
     #[inline]
@@ -348,11 +412,12 @@ where
         FN: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<Vec<u8>>,
     {
-        value_gen().and_then(|proof_bytes| {
+        let proof = value_gen().and_then(|proof_bytes| {
             let proof: Proof<PairingE> = FromBytes::read(&proof_bytes.borrow()[..])?;
+            Ok(proof)
+        });
 
-            Self::alloc(cs.ns(|| "alloc_bytes"), || Ok(proof))
-        })
+        Self::alloc(cs.ns(|| "alloc_bytes"), || proof)
     }
 
     #[inline]
@@ -421,8 +486,8 @@ mod test {
 
     use snarkvm_algorithms::snark::groth16::*;
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
-    use snarkvm_fields::PrimeField;
-    use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, TestConstraintSystem};
+    use snarkvm_fields::{One, PrimeField, Zero};
+    use snarkvm_r1cs::{Assignment, ConstraintCounter, ConstraintSynthesizer, ConstraintSystem, TestConstraintSystem};
     use snarkvm_utilities::{test_rng, to_bytes, BitIteratorBE, ToBytes};
 
     use crate::{bits::Boolean, curves::bls12_377::PairingGadget as Bls12_377PairingGadget};
@@ -476,6 +541,33 @@ mod test {
         }
     }
 
+    fn synthesize_verifier<CS: ConstraintSystem<Fq>>(
+        cs: &mut CS,
+        vk: &VerifyingKey<Bls12_377>,
+        inputs: &[Fr],
+        proof: Option<&Proof<Bls12_377>>,
+    ) {
+        let mut input_gadgets = Vec::new();
+        for (i, input) in inputs.iter().enumerate() {
+            let mut input_bits = BitIteratorBE::new(input.into_repr()).collect::<Vec<_>>();
+            // Input must be in little-endian, but BitIterator outputs in big-endian.
+            input_bits.reverse();
+
+            let input_bits = Vec::<Boolean>::alloc_input(cs.ns(|| format!("Input {}", i)), || Ok(input_bits)).unwrap();
+            input_gadgets.push(input_bits);
+        }
+
+        let vk_gadget = TestVkGadget::alloc_input(cs.ns(|| "Vk"), || Ok(vk)).unwrap();
+        let proof_gadget = TestProofGadget::alloc(cs.ns(|| "Proof"), || proof.get()).unwrap();
+        <TestVerifierGadget as SNARKVerifierGadget<TestProofSystem, Fq>>::check_verify(
+            cs.ns(|| "Verify"),
+            &vk_gadget,
+            input_gadgets.iter().cloned(),
+            &proof_gadget,
+        )
+        .unwrap();
+    }
+
     #[test]
     fn groth16_verifier_test() {
         let num_inputs = 100;
@@ -716,4 +808,133 @@ mod test {
             assert_eq!(verifier_gadget_constraints, VERIFIER_GADGET_CONSTRAINTS);
         }
     }
+
+    #[test]
+    fn groth16_verifier_setup_mode_test() {
+        let num_inputs = 10;
+        let num_constraints = num_inputs;
+        let rng = &mut test_rng();
+        let inputs: Vec<Fr> = (0..num_inputs).map(|_| rng.gen()).collect();
+
+        let params = generate_random_parameters(
+            &Bench::<Fr> {
+                inputs: vec![None; num_inputs],
+                num_constraints,
+            },
+            rng,
+        )
+        .unwrap();
+        let proof = create_random_proof(
+            &Bench {
+                inputs: inputs.iter().cloned().map(Some).collect(),
+                num_constraints,
+            },
+            &params,
+            rng,
+        )
+        .unwrap();
+
+        // Synthesize the verifier with the proof as a witness.
+        let mut witnessed_cs = TestConstraintSystem::<Fq>::new();
+        synthesize_verifier(&mut witnessed_cs, &params.vk, &inputs, Some(&proof));
+        assert!(witnessed_cs.is_satisfied());
+
+        // Synthesize the verifier in setup mode, without the proof.
+        let mut blank_cs = ConstraintCounter::default();
+        synthesize_verifier(&mut blank_cs, &params.vk, &inputs, None);
+
+        assert_eq!(witnessed_cs.num_constraints(), blank_cs.num_constraints);
+    }
+
+    #[test]
+    fn groth16_verifier_constant_query_test() {
+        let num_inputs = 10;
+        let num_constraints = num_inputs;
+        let rng = &mut test_rng();
+        let mut inputs: Vec<Fr> = (0..num_inputs).map(|_| rng.gen()).collect();
+        // Zero inputs must not produce an identity element in the query accumulation.
+        inputs[1] = Fr::zero();
+
+        let params = generate_random_parameters(
+            &Bench::<Fr> {
+                inputs: vec![None; num_inputs],
+                num_constraints,
+            },
+            rng,
+        )
+        .unwrap();
+        let proof = create_random_proof(
+            &Bench {
+                inputs: inputs.iter().cloned().map(Some).collect(),
+                num_constraints,
+            },
+            &params,
+            rng,
+        )
+        .unwrap();
+
+        let verify = |inputs: &[Fr], constant_query: bool| {
+            let mut cs = TestConstraintSystem::<Fq>::new();
+
+            let mut input_gadgets = Vec::new();
+            for (i, input) in inputs.iter().enumerate() {
+                let mut input_bits = BitIteratorBE::new(input.into_repr()).collect::<Vec<_>>();
+                // Input must be in little-endian, but BitIterator outputs in big-endian.
+                input_bits.reverse();
+
+                let input_bits =
+                    Vec::<Boolean>::alloc_input(cs.ns(|| format!("Input {}", i)), || Ok(input_bits)).unwrap();
+                input_gadgets.push(input_bits);
+            }
+
+            let vk_gadget = TestVkGadget::alloc_input(cs.ns(|| "Vk"), || Ok(&params.vk)).unwrap();
+            let proof_gadget = TestProofGadget::alloc(cs.ns(|| "Proof"), || Ok(proof.clone())).unwrap();
+
+            let num_constraints = cs.num_constraints();
+            if constant_query {
+                TestVerifierGadget::check_verify_with_constant_query(
+                    cs.ns(|| "Verify"),
+                    &vk_gadget,
+                    &params.vk.gamma_abc_g1,
+                    input_gadgets.iter().cloned(),
+                    &proof_gadget,
+                )
+                .unwrap();
+            } else {
+                <TestVerifierGadget as SNARKVerifierGadget<TestProofSystem, Fq>>::check_verify(
+                    cs.ns(|| "Verify"),
+                    &vk_gadget,
+                    input_gadgets.iter().cloned(),
+                    &proof_gadget,
+                )
+                .unwrap();
+            }
+            (cs.is_satisfied(), cs.num_constraints() - num_constraints)
+        };
+
+        let (is_satisfied, verifier_gadget_constraints) = verify(&inputs, false);
+        assert!(is_satisfied);
+        let (is_satisfied, constant_query_verifier_gadget_constraints) = verify(&inputs, true);
+        assert!(is_satisfied);
+
+        println!("verifier_gadget_constraints : {:?}", verifier_gadget_constraints);
+        println!(
+            "constant_query_verifier_gadget_constraints : {:?}",
+            constant_query_verifier_gadget_constraints
+        );
+
+        const VERIFIER_GADGET_CONSTRAINTS: usize = 63090;
+        const CONSTANT_QUERY_VERIFIER_GADGET_CONSTRAINTS: usize = 42850;
+
+        assert_eq!(verifier_gadget_constraints, VERIFIER_GADGET_CONSTRAINTS);
+        assert_eq!(
+            constant_query_verifier_gadget_constraints,
+            CONSTANT_QUERY_VERIFIER_GADGET_CONSTRAINTS
+        );
+
+        // The proof does not verify for different inputs.
+        inputs[0] += Fr::one();
+        let (is_satisfied, _) = verify(&inputs, true);
+        assert!(!is_satisfied);
+    }
 }
@@ -22,3 +22,61 @@ pub use groth16::*;
 
 #[cfg(test)]
 pub mod tests;
+
+use snarkvm_curves::traits::{AffineCurve, Group, PairingEngine};
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    bits::{Boolean, ToBitsBEGadget},
+    traits::curves::{GroupGadget, PairingGadget},
+};
+
+/// Returns `query[0] + \sum_{i=1}^l input_i query[i]` for a constant `query`, where each public
+/// input is given as *little-endian* bits.
+///
+/// Every input is extended with a constant most significant `true` bit, so that no product is
+/// the identity element, which the incomplete addition of short Weierstrass gadgets cannot add.
+/// The extra multiples are removed, together with adding `query[0]`, by one constant addition.
+pub(crate) fn accumulate_constant_query<Pairing, F, P, CS, I>(
+    mut cs: CS,
+    query: &[Pairing::G1Affine],
+    mut public_inputs: I,
+) -> Result<P::G1Gadget, SynthesisError>
+where
+    Pairing: PairingEngine,
+    F: Field,
+    P: PairingGadget<Pairing, F>,
+    CS: ConstraintSystem<F>,
+    I: Iterator<Item = Vec<Boolean>>,
+{
+    let mut correction = query[0].into_projective();
+    let mut result: Option<P::G1Gadget> = None;
+    let mut input_len = 1;
+    for (i, (input, base)) in public_inputs.by_ref().zip(query.iter().skip(1)).enumerate() {
+        let mut input_bits = input.to_bits_be(cs.ns(|| format!("Input {}", i)))?;
+
+        let base = base.into_projective();
+        let mut high_bit_base = base;
+        for _ in 0..input_bits.len() {
+            high_bit_base.double_in_place();
+        }
+        correction -= &high_bit_base;
+        input_bits.push(Boolean::Constant(true));
+
+        let product = P::G1Gadget::constant_base_scalar_mul_le(cs.ns(|| format!("Mul {}", i)), &base, &input_bits)?;
+        result = Some(match result {
+            None => product,
+            Some(result) => result.add(cs.ns(|| format!("Add {}", i)), &product)?,
+        });
+        input_len += 1;
+    }
+    // Check that the input and the query in the verification are of the
+    // same length.
+    assert!(input_len == query.len() && public_inputs.next().is_none());
+
+    match result {
+        Some(result) => result.add_constant(cs.ns(|| "Add correction"), &correction),
+        None => P::G1Gadget::constant_base_scalar_mul_le(cs.ns(|| "query[0]"), &correction, &[Boolean::Constant(true)]),
+    }
+}
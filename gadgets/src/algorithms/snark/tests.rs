@@ -16,10 +16,25 @@
 
 use rand::{thread_rng, Rng};
 
-use snarkvm_algorithms::snark::gm17::{create_random_proof, generate_random_parameters, GM17};
-use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
-use snarkvm_fields::{Field, PrimeField};
-use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem, TestConstraintSystem};
+use snarkvm_algorithms::snark::gm17::{
+    create_random_proof,
+    generate_random_parameters,
+    prepare_verifying_key,
+    Proof,
+    GM17,
+};
+use snarkvm_curves::{
+    bls12_377::{Bls12_377, Fq, Fr, G1Affine, G2Affine},
+    traits::{AffineCurve, ProjectiveCurve},
+};
+use snarkvm_fields::{Field, One, PrimeField, ToConstraintField, Zero};
+use snarkvm_r1cs::{
+    errors::SynthesisError,
+    ConstraintCounter,
+    ConstraintSynthesizer,
+    ConstraintSystem,
+    TestConstraintSystem,
+};
 use snarkvm_utilities::{bititerator::BitIteratorBE, to_bytes, ToBytes};
 
 use crate::{
@@ -27,8 +42,9 @@ use crate::{
     bits::Boolean,
     curves::bls12_377::PairingGadget as Bls12_377PairingGadget,
     traits::{
-        algorithms::snark::SNARKVerifierGadget,
+        algorithms::snark::{PreparedSNARKVerifierGadget, SNARKVerifierGadget},
         alloc::{AllocBytesGadget, AllocGadget},
+        fields::{FieldGadget, ToConstraintFieldGadget},
     },
 };
 
@@ -36,6 +52,7 @@ type TestProofSystem = GM17<Bls12_377, Bench<Fr>, Fr>;
 type TestVerifierGadget = GM17VerifierGadget<Bls12_377, Fq, Bls12_377PairingGadget>;
 type TestProofGadget = GM17ProofGadget<Bls12_377, Fq, Bls12_377PairingGadget>;
 type TestVkGadget = GM17VerifyingKeyGadget<Bls12_377, Fq, Bls12_377PairingGadget>;
+type TestPreparedVkGadget = GM17PreparedVerifyingKeyGadget<Bls12_377, Fq, Bls12_377PairingGadget>;
 
 struct Bench<F: Field> {
     inputs: Vec<Option<F>>,
@@ -324,3 +341,562 @@ fn gm17_verifier_num_constraints_test() {
         assert_eq!(verifier_gadget_constraints, VERIFIER_GADGET_CONSTRAINTS);
     }
 }
+
+fn synthesize_verifier_from_bytes<CS: ConstraintSystem<Fq>>(
+    cs: &mut CS,
+    vk_bytes: &[u8],
+    inputs: &[Fr],
+    proof_bytes: Option<Vec<u8>>,
+) {
+    let mut input_gadgets = Vec::new();
+    for (i, input) in inputs.iter().enumerate() {
+        let mut input_bits = BitIteratorBE::new(input.into_repr()).collect::<Vec<_>>();
+        // Input must be in little-endian, but BitIterator outputs in big-endian.
+        input_bits.reverse();
+
+        let input_bits = Vec::<Boolean>::alloc_input(cs.ns(|| format!("Input {}", i)), || Ok(input_bits)).unwrap();
+        input_gadgets.push(input_bits);
+    }
+
+    let vk_gadget = TestVkGadget::alloc_bytes(cs.ns(|| "Vk"), || Ok(vk_bytes.to_vec())).unwrap();
+    let proof_gadget = TestProofGadget::alloc_bytes(cs.ns(|| "Proof"), || {
+        proof_bytes.ok_or(SynthesisError::AssignmentMissing)
+    })
+    .unwrap();
+    <TestVerifierGadget as SNARKVerifierGadget<TestProofSystem, Fq>>::check_verify(
+        cs.ns(|| "Verify"),
+        &vk_gadget,
+        input_gadgets.iter().cloned(),
+        &proof_gadget,
+    )
+    .unwrap();
+}
+
+#[test]
+fn gm17_verifier_setup_mode_test() {
+    let num_inputs = 10;
+    let num_constraints = num_inputs;
+    let rng = &mut thread_rng();
+    let inputs: Vec<Fr> = (0..num_inputs).map(|_| rng.gen()).collect();
+
+    let params = generate_random_parameters::<Bls12_377, _, _>(
+        &Bench::<Fr> {
+            inputs: vec![None; num_inputs],
+            num_constraints,
+        },
+        rng,
+    )
+    .unwrap();
+    let proof = create_random_proof(
+        &Bench {
+            inputs: inputs.iter().cloned().map(Some).collect(),
+            num_constraints,
+        },
+        &params,
+        rng,
+    )
+    .unwrap();
+
+    let vk_bytes = to_bytes![params.vk].unwrap();
+    let proof_bytes = to_bytes![proof].unwrap();
+
+    // Synthesize the verifier with the proof as a witness.
+    let mut witnessed_cs = TestConstraintSystem::<Fq>::new();
+    synthesize_verifier_from_bytes(&mut witnessed_cs, &vk_bytes, &inputs, Some(proof_bytes));
+    assert!(witnessed_cs.is_satisfied());
+
+    // Synthesize the verifier in setup mode, without the proof.
+    let mut blank_cs = ConstraintCounter::default();
+    synthesize_verifier_from_bytes(&mut blank_cs, &vk_bytes, &inputs, None);
+
+    assert_eq!(witnessed_cs.num_constraints(), blank_cs.num_constraints);
+}
+
+#[test]
+fn gm17_verifier_constant_query_test() {
+    let num_inputs = 10;
+    let num_constraints = num_inputs;
+    let rng = &mut thread_rng();
+    let mut inputs: Vec<Fr> = (0..num_inputs).map(|_| rng.gen()).collect();
+    // Zero inputs must not produce an identity element in the query accumulation.
+    inputs[1] = Fr::zero();
+
+    let params = generate_random_parameters::<Bls12_377, _, _>(
+        &Bench::<Fr> {
+            inputs: vec![None; num_inputs],
+            num_constraints,
+        },
+        rng,
+    )
+    .unwrap();
+    let proof = create_random_proof(
+        &Bench {
+            inputs: inputs.iter().cloned().map(Some).collect(),
+            num_constraints,
+        },
+        &params,
+        rng,
+    )
+    .unwrap();
+
+    let verify = |inputs: &[Fr], constant_query: bool| {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let mut input_gadgets = Vec::new();
+        for (i, input) in inputs.iter().enumerate() {
+            let mut input_bits = BitIteratorBE::new(input.into_repr()).collect::<Vec<_>>();
+            // Input must be in little-endian, but BitIterator outputs in big-endian.
+            input_bits.reverse();
+
+            let input_bits = Vec::<Boolean>::alloc_input(cs.ns(|| format!("Input {}", i)), || Ok(input_bits)).unwrap();
+            input_gadgets.push(input_bits);
+        }
+
+        let vk_gadget = TestVkGadget::alloc_input(cs.ns(|| "Vk"), || Ok(&params.vk)).unwrap();
+        let proof_gadget = TestProofGadget::alloc(cs.ns(|| "Proof"), || Ok(proof.clone())).unwrap();
+
+        let num_constraints = cs.num_constraints();
+        if constant_query {
+            TestVerifierGadget::check_verify_with_constant_query(
+                cs.ns(|| "Verify"),
+                &vk_gadget,
+                &params.vk.query,
+                input_gadgets.iter().cloned(),
+                &proof_gadget,
+            )
+            .unwrap();
+        } else {
+            <TestVerifierGadget as SNARKVerifierGadget<TestProofSystem, Fq>>::check_verify(
+                cs.ns(|| "Verify"),
+                &vk_gadget,
+                input_gadgets.iter().cloned(),
+                &proof_gadget,
+            )
+            .unwrap();
+        }
+        (cs.is_satisfied(), cs.num_constraints() - num_constraints)
+    };
+
+    let (is_satisfied, verifier_gadget_constraints) = verify(&inputs, false);
+    assert!(is_satisfied);
+    let (is_satisfied, constant_query_verifier_gadget_constraints) = verify(&inputs, true);
+    assert!(is_satisfied);
+
+    println!("verifier_gadget_constraints : {:?}", verifier_gadget_constraints);
+    println!(
+        "constant_query_verifier_gadget_constraints : {:?}",
+        constant_query_verifier_gadget_constraints
+    );
+
+    const VERIFIER_GADGET_CONSTRAINTS: usize = 69802;
+    const CONSTANT_QUERY_VERIFIER_GADGET_CONSTRAINTS: usize = 49562;
+
+    assert_eq!(verifier_gadget_constraints, VERIFIER_GADGET_CONSTRAINTS);
+    assert_eq!(
+        constant_query_verifier_gadget_constraints,
+        CONSTANT_QUERY_VERIFIER_GADGET_CONSTRAINTS
+    );
+
+    // The proof does not verify for different inputs.
+    inputs[0] += Fr::one();
+    let (is_satisfied, _) = verify(&inputs, true);
+    assert!(!is_satisfied);
+}
+
+#[test]
+fn gm17_verifier_conditional_test() {
+    let num_inputs = 10;
+    let num_constraints = num_inputs;
+    let rng = &mut thread_rng();
+    let inputs: Vec<Fr> = (0..num_inputs).map(|_| rng.gen()).collect();
+
+    let params = generate_random_parameters::<Bls12_377, _, _>(
+        &Bench::<Fr> {
+            inputs: vec![None; num_inputs],
+            num_constraints,
+        },
+        rng,
+    )
+    .unwrap();
+    let proof = create_random_proof(
+        &Bench {
+            inputs: inputs.iter().cloned().map(Some).collect(),
+            num_constraints,
+        },
+        &params,
+        rng,
+    )
+    .unwrap();
+
+    // The proof does not verify for different inputs.
+    let mut invalid_inputs = inputs.clone();
+    invalid_inputs[0] += Fr::one();
+
+    // Returns whether the constraints are satisfied, and the value of the `Boolean` if `condition` is `None`.
+    let verify = |inputs: &[Fr], condition: Option<bool>| {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let mut input_gadgets = Vec::new();
+        for (i, input) in inputs.iter().enumerate() {
+            let mut input_bits = BitIteratorBE::new(input.into_repr()).collect::<Vec<_>>();
+            // Input must be in little-endian, but BitIterator outputs in big-endian.
+            input_bits.reverse();
+
+            let input_bits = Vec::<Boolean>::alloc_input(cs.ns(|| format!("Input {}", i)), || Ok(input_bits)).unwrap();
+            input_gadgets.push(input_bits);
+        }
+
+        let vk_gadget = TestVkGadget::alloc_input(cs.ns(|| "Vk"), || Ok(&params.vk)).unwrap();
+        let proof_gadget = TestProofGadget::alloc(cs.ns(|| "Proof"), || Ok(proof.clone())).unwrap();
+
+        let is_valid = match condition {
+            Some(condition) => {
+                let condition = Boolean::alloc(cs.ns(|| "Condition"), || Ok(condition)).unwrap();
+                TestVerifierGadget::check_verify_conditional(
+                    cs.ns(|| "Verify"),
+                    &vk_gadget,
+                    input_gadgets.iter().cloned(),
+                    &proof_gadget,
+                    &condition,
+                )
+                .unwrap();
+                None
+            }
+            None => {
+                let is_valid = TestVerifierGadget::verify_to_boolean(
+                    cs.ns(|| "Verify"),
+                    &vk_gadget,
+                    input_gadgets.iter().cloned(),
+                    &proof_gadget,
+                )
+                .unwrap();
+                is_valid.get_value()
+            }
+        };
+        (cs.is_satisfied(), is_valid)
+    };
+
+    // A valid proof is accepted whether or not it is enforced.
+    assert_eq!(verify(&inputs, Some(true)), (true, None));
+    assert_eq!(verify(&inputs, Some(false)), (true, None));
+
+    // An invalid proof is only accepted if it is not enforced.
+    assert_eq!(verify(&invalid_inputs, Some(true)), (false, None));
+    assert_eq!(verify(&invalid_inputs, Some(false)), (true, None));
+
+    // The `Boolean` reflects the validity of the proof, without enforcing it.
+    assert_eq!(verify(&inputs, None), (true, Some(true)));
+    assert_eq!(verify(&invalid_inputs, None), (true, Some(false)));
+}
+
+#[test]
+fn gm17_verifier_merged_test() {
+    let num_inputs = 10;
+    let num_constraints = num_inputs;
+    let rng = &mut thread_rng();
+    let inputs: Vec<Fr> = (0..num_inputs).map(|_| rng.gen()).collect();
+
+    let params = generate_random_parameters::<Bls12_377, _, _>(
+        &Bench::<Fr> {
+            inputs: vec![None; num_inputs],
+            num_constraints,
+        },
+        rng,
+    )
+    .unwrap();
+    let proof = create_random_proof(
+        &Bench {
+            inputs: inputs.iter().cloned().map(Some).collect(),
+            num_constraints,
+        },
+        &params,
+        rng,
+    )
+    .unwrap();
+
+    // Returns whether the constraints are satisfied, and the number of constraints of the verifier.
+    let verify = |inputs: &[Fr], proof: &Proof<Bls12_377>, merged: bool| {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let mut input_gadgets = Vec::new();
+        for (i, input) in inputs.iter().enumerate() {
+            let mut input_bits = BitIteratorBE::new(input.into_repr()).collect::<Vec<_>>();
+            // Input must be in little-endian, but BitIterator outputs in big-endian.
+            input_bits.reverse();
+
+            let input_bits = Vec::<Boolean>::alloc_input(cs.ns(|| format!("Input {}", i)), || Ok(input_bits)).unwrap();
+            input_gadgets.push(input_bits);
+        }
+
+        let vk_gadget = TestVkGadget::alloc_input(cs.ns(|| "Vk"), || Ok(&params.vk)).unwrap();
+        let proof_gadget = TestProofGadget::alloc(cs.ns(|| "Proof"), || Ok(proof.clone())).unwrap();
+
+        let num_constraints = cs.num_constraints();
+        if merged {
+            TestVerifierGadget::check_verify_merged(
+                cs.ns(|| "Verify"),
+                &vk_gadget,
+                input_gadgets.iter().cloned(),
+                &proof_gadget,
+            )
+            .unwrap();
+        } else {
+            <TestVerifierGadget as SNARKVerifierGadget<TestProofSystem, Fq>>::check_verify(
+                cs.ns(|| "Verify"),
+                &vk_gadget,
+                input_gadgets.iter().cloned(),
+                &proof_gadget,
+            )
+            .unwrap();
+        }
+        (cs.is_satisfied(), cs.num_constraints() - num_constraints)
+    };
+
+    let (is_satisfied, verifier_gadget_constraints) = verify(&inputs, &proof, false);
+    assert!(is_satisfied);
+    let (is_satisfied, merged_verifier_gadget_constraints) = verify(&inputs, &proof, true);
+    assert!(is_satisfied);
+
+    println!("verifier_gadget_constraints : {:?}", verifier_gadget_constraints);
+    println!(
+        "merged_verifier_gadget_constraints : {:?}",
+        merged_verifier_gadget_constraints
+    );
+
+    const MERGED_VERIFIER_GADGET_SAVED_CONSTRAINTS: usize = 5037;
+    assert_eq!(
+        verifier_gadget_constraints - merged_verifier_gadget_constraints,
+        MERGED_VERIFIER_GADGET_SAVED_CONSTRAINTS
+    );
+
+    // Corrupting any of A, B, C, or an input makes the merged verifier unsatisfiable.
+    let g1 = G1Affine::prime_subgroup_generator().into_projective();
+    let g2 = G2Affine::prime_subgroup_generator().into_projective();
+
+    let mut corrupted_proof = proof.clone();
+    corrupted_proof.a = (corrupted_proof.a.into_projective() + g1).into_affine();
+    assert!(!verify(&inputs, &corrupted_proof, true).0);
+
+    let mut corrupted_proof = proof.clone();
+    corrupted_proof.b = (corrupted_proof.b.into_projective() + g2).into_affine();
+    assert!(!verify(&inputs, &corrupted_proof, true).0);
+
+    let mut corrupted_proof = proof.clone();
+    corrupted_proof.c = (corrupted_proof.c.into_projective() + g1).into_affine();
+    assert!(!verify(&inputs, &corrupted_proof, true).0);
+
+    let mut corrupted_inputs = inputs.clone();
+    corrupted_inputs[0] += Fr::one();
+    assert!(!verify(&corrupted_inputs, &proof, true).0);
+}
+
+#[test]
+fn gm17_verifier_prepared_test() {
+    let num_inputs = 10;
+    let num_constraints = num_inputs;
+    let rng = &mut thread_rng();
+
+    let params = generate_random_parameters::<Bls12_377, _, _>(
+        &Bench::<Fr> {
+            inputs: vec![None; num_inputs],
+            num_constraints,
+        },
+        rng,
+    )
+    .unwrap();
+
+    let mut instances = Vec::with_capacity(2);
+    for _ in 0..2 {
+        let inputs: Vec<Fr> = (0..num_inputs).map(|_| rng.gen()).collect();
+        let proof = create_random_proof(
+            &Bench {
+                inputs: inputs.iter().cloned().map(Some).collect(),
+                num_constraints,
+            },
+            &params,
+            rng,
+        )
+        .unwrap();
+        instances.push((inputs, proof));
+    }
+
+    // Allocates the inputs and proof of every instance.
+    let alloc_instances = |cs: &mut TestConstraintSystem<Fq>, instances: &[(Vec<Fr>, Proof<Bls12_377>)]| {
+        let mut gadgets = Vec::with_capacity(instances.len());
+        for (i, (inputs, proof)) in instances.iter().enumerate() {
+            let mut input_gadgets = Vec::new();
+            for (j, input) in inputs.iter().enumerate() {
+                let mut input_bits = BitIteratorBE::new(input.into_repr()).collect::<Vec<_>>();
+                // Input must be in little-endian, but BitIterator outputs in big-endian.
+                input_bits.reverse();
+
+                let input_bits =
+                    Vec::<Boolean>::alloc_input(cs.ns(|| format!("Input {} {}", i, j)), || Ok(input_bits)).unwrap();
+                input_gadgets.push(input_bits);
+            }
+
+            let proof_gadget = TestProofGadget::alloc(cs.ns(|| format!("Proof {}", i)), || Ok(proof.clone())).unwrap();
+            gadgets.push((input_gadgets, proof_gadget));
+        }
+        gadgets
+    };
+
+    // Verify both proofs with independent `check_verify` calls, which prepare the verifying key twice.
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let gadgets = alloc_instances(&mut cs, &instances);
+    let vk_gadget = TestVkGadget::alloc_input(cs.ns(|| "Vk"), || Ok(&params.vk)).unwrap();
+    let num_allocation_constraints = cs.num_constraints();
+    for (i, (input_gadgets, proof_gadget)) in gadgets.iter().enumerate() {
+        <TestVerifierGadget as SNARKVerifierGadget<TestProofSystem, Fq>>::check_verify(
+            cs.ns(|| format!("Verify {}", i)),
+            &vk_gadget,
+            input_gadgets.iter().cloned(),
+            proof_gadget,
+        )
+        .unwrap();
+    }
+    assert!(cs.is_satisfied());
+    let verifier_gadget_constraints = cs.num_constraints() - num_allocation_constraints;
+
+    // Verify both proofs under one prepared verifying key.
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let gadgets = alloc_instances(&mut cs, &instances);
+    let vk_gadget = TestVkGadget::alloc_input(cs.ns(|| "Vk"), || Ok(&params.vk)).unwrap();
+    let num_allocation_constraints = cs.num_constraints();
+    let pvk_gadget =
+        <TestVerifierGadget as PreparedSNARKVerifierGadget<TestProofSystem, Fq>>::prepare_verification_key(
+            cs.ns(|| "Prepare vk"),
+            &vk_gadget,
+        )
+        .unwrap();
+    for (i, (input_gadgets, proof_gadget)) in gadgets.iter().enumerate() {
+        <TestVerifierGadget as PreparedSNARKVerifierGadget<TestProofSystem, Fq>>::check_verify_with_prepared(
+            cs.ns(|| format!("Verify {}", i)),
+            &pvk_gadget,
+            input_gadgets.iter().cloned(),
+            proof_gadget,
+        )
+        .unwrap();
+    }
+    assert!(cs.is_satisfied());
+    let prepared_verifier_gadget_constraints = cs.num_constraints() - num_allocation_constraints;
+
+    println!("verifier_gadget_constraints : {:?}", verifier_gadget_constraints);
+    println!(
+        "prepared_verifier_gadget_constraints : {:?}",
+        prepared_verifier_gadget_constraints
+    );
+    assert!(prepared_verifier_gadget_constraints < verifier_gadget_constraints);
+
+    // A prepared verifying key can also be allocated directly.
+    let pvk = prepare_verifying_key(params.vk.clone());
+    let verify_with_allocated_pvk = |instance: (Vec<Fr>, Proof<Bls12_377>)| {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let gadgets = alloc_instances(&mut cs, &[instance]);
+        let pvk_gadget = TestPreparedVkGadget::alloc_input(cs.ns(|| "Prepared vk"), || Ok(&pvk)).unwrap();
+        let (input_gadgets, proof_gadget) = &gadgets[0];
+        <TestVerifierGadget as PreparedSNARKVerifierGadget<TestProofSystem, Fq>>::check_verify_with_prepared(
+            cs.ns(|| "Verify"),
+            &pvk_gadget,
+            input_gadgets.iter().cloned(),
+            proof_gadget,
+        )
+        .unwrap();
+        cs.is_satisfied()
+    };
+    assert!(verify_with_allocated_pvk(instances[0].clone()));
+    assert!(!verify_with_allocated_pvk((
+        instances[1].0.clone(),
+        instances[0].1.clone()
+    )));
+}
+
+#[test]
+fn gm17_verifier_constant_vk_test() {
+    let num_inputs = 5;
+    let num_constraints = num_inputs;
+    let rng = &mut thread_rng();
+    let inputs: Vec<Fr> = (0..num_inputs).map(|_| rng.gen()).collect();
+    let params = generate_random_parameters(
+        &Bench::<Fr> {
+            inputs: vec![None; num_inputs],
+            num_constraints,
+        },
+        rng,
+    )
+    .unwrap();
+    let proof = create_random_proof(
+        &Bench {
+            inputs: inputs.iter().copied().map(Some).collect(),
+            num_constraints,
+        },
+        &params,
+        rng,
+    )
+    .unwrap();
+
+    // Returns the number of constraints and variables of the verifier.
+    let verify = |constant_vk: bool| {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let mut input_gadgets = Vec::new();
+        for (i, input) in inputs.iter().enumerate() {
+            let mut input_bits = BitIteratorBE::new(input.into_repr()).collect::<Vec<_>>();
+            // Input must be in little-endian, but BitIterator outputs in big-endian.
+            input_bits.reverse();
+
+            let input_bits = Vec::<Boolean>::alloc_input(cs.ns(|| format!("Input {}", i)), || Ok(input_bits)).unwrap();
+            input_gadgets.push(input_bits);
+        }
+
+        let vk_gadget = if constant_vk {
+            TestVkGadget::alloc_constant(cs.ns(|| "Vk"), || Ok(&params.vk)).unwrap()
+        } else {
+            TestVkGadget::alloc(cs.ns(|| "Vk"), || Ok(&params.vk)).unwrap()
+        };
+        let proof_gadget = TestProofGadget::alloc(cs.ns(|| "Proof"), || Ok(proof.clone())).unwrap();
+        <TestVerifierGadget as SNARKVerifierGadget<TestProofSystem, Fq>>::check_verify(
+            cs.ns(|| "Verify"),
+            &vk_gadget,
+            input_gadgets.iter().cloned(),
+            &proof_gadget,
+        )
+        .unwrap();
+        assert!(cs.is_satisfied());
+
+        (cs.num_constraints(), cs.num_private_variables())
+    };
+
+    let (num_constraints, num_variables) = verify(false);
+    let (num_constant_constraints, num_constant_variables) = verify(true);
+    assert!(num_constant_constraints < num_constraints);
+    assert!(num_constant_variables < num_variables);
+}
+
+#[test]
+fn gm17_verifying_key_to_constraint_field_test() {
+    let num_inputs = 5;
+    let rng = &mut thread_rng();
+    let params = generate_random_parameters::<Bls12_377, _, _>(
+        &Bench::<Fr> {
+            inputs: vec![None; num_inputs],
+            num_constraints: num_inputs,
+        },
+        rng,
+    )
+    .unwrap();
+
+    let expected = params.vk.to_field_elements().unwrap();
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+    let vk_gadget = TestVkGadget::alloc(cs.ns(|| "Vk"), || Ok(&params.vk)).unwrap();
+    let candidate = vk_gadget
+        .to_constraint_field(cs.ns(|| "Vk to constraint field"))
+        .unwrap();
+    assert!(cs.is_satisfied());
+
+    assert_eq!(candidate.len(), expected.len());
+    for (i, (candidate, expected)) in candidate.iter().zip(&expected).enumerate() {
+        assert_eq!(candidate.get_value().unwrap(), *expected, "element {} differs", i);
+    }
+}
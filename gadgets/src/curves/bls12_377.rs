@@ -45,7 +45,7 @@ mod test {
             alloc::AllocGadget,
             curves::GroupGadget,
             eq::EqGadget,
-            fields::FieldGadget,
+            fields::{FieldGadget, ToConstraintFieldGadget},
             select::CondSelectGadget,
         },
     };
@@ -54,12 +54,13 @@ mod test {
         traits::ProjectiveCurve,
         Group,
     };
-    use snarkvm_fields::PrimeField;
+    use snarkvm_fields::{PrimeField, ToConstraintField, Zero};
     use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
     use snarkvm_utilities::{bititerator::BitIteratorBE, rand::UniformRand};
 
     use core::ops::Mul;
     use rand::{
+        Rng,
         SeedableRng,
         {self},
     };
@@ -176,6 +177,110 @@ mod test {
         assert!(cs.is_satisfied());
     }
 
+    fn native_scalar_mul_le(base: &G1, bits: &[bool]) -> G1 {
+        let mut result = G1::zero();
+        let mut base_power = *base;
+        for bit in bits {
+            if *bit {
+                result += &base_power;
+            }
+            base_power.double_in_place();
+        }
+        result
+    }
+
+    #[test]
+    fn bls12_g1_constant_base_scalar_mul_test() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let max_num_bits = Fr::size_in_bits() - 3;
+        for num_bits in [1, 2, 3, 8, 63, 128, max_num_bits - 1, max_num_bits].iter() {
+            let mut cs = TestConstraintSystem::<Fq>::new();
+
+            let base = G1::rand(&mut rng);
+            let offset = G1::rand(&mut rng);
+            let bits: Vec<bool> = (0..*num_bits).map(|_| rng.gen()).collect();
+            let input = Vec::<Boolean>::alloc(cs.ns(|| "Input"), || Ok(bits.clone())).unwrap();
+
+            let result = G1Gadget::constant_base_scalar_mul_le(cs.ns(|| "constant base"), &base, &input).unwrap();
+            assert_eq!(result.get_value().unwrap(), native_scalar_mul_le(&base, &bits));
+
+            // Check the equivalence with `mul_bits`, which requires a non-zero starting point.
+            let base_gadget = G1Gadget::alloc(cs.ns(|| "base"), || Ok(base)).unwrap();
+            let offset_gadget = G1Gadget::alloc(cs.ns(|| "offset"), || Ok(offset)).unwrap();
+            let expected = base_gadget
+                .mul_bits(cs.ns(|| "mul_bits"), &offset_gadget, input.iter().copied())
+                .unwrap();
+            assert_eq!(result.get_value().unwrap(), expected.get_value().unwrap() - offset);
+
+            assert!(cs.is_satisfied());
+        }
+    }
+
+    #[test]
+    fn bls12_g1_constant_base_scalar_mul_edge_cases_test() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let base = G1::rand(&mut rng);
+
+        for num_bits in [1, 2, 7, 8].iter() {
+            for bit in [false, true].iter() {
+                let mut cs = TestConstraintSystem::<Fq>::new();
+
+                let bits = vec![*bit; *num_bits];
+                let input = Vec::<Boolean>::alloc(cs.ns(|| "Input"), || Ok(bits.clone())).unwrap();
+                let result = G1Gadget::constant_base_scalar_mul_le(cs.ns(|| "constant base"), &base, &input).unwrap();
+                assert_eq!(result.get_value().unwrap(), native_scalar_mul_le(&base, &bits));
+                assert_eq!(result.infinity.get_value().unwrap(), !*bit);
+
+                assert!(cs.is_satisfied());
+            }
+        }
+
+        // The identity element as base.
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let bits: Vec<bool> = (0..128).map(|_| rng.gen()).collect();
+        let input = Vec::<Boolean>::alloc(cs.ns(|| "Input"), || Ok(bits)).unwrap();
+        let num_constraints = cs.num_constraints();
+        let result = G1Gadget::constant_base_scalar_mul_le(cs.ns(|| "constant base"), &G1::zero(), &input).unwrap();
+        assert!(result.get_value().unwrap().is_zero());
+        assert_eq!(cs.num_constraints(), num_constraints);
+        assert!(cs.is_satisfied());
+
+        // Scalars which wrap around the group order are reduced.
+        let bits = vec![true; Fr::size_in_bits() + 3];
+        let input = Vec::<Boolean>::alloc(cs.ns(|| "Long input"), || Ok(bits.clone())).unwrap();
+        let result = G1Gadget::constant_base_scalar_mul_le(cs.ns(|| "long scalar"), &base, &input).unwrap();
+        assert_eq!(result.get_value().unwrap(), native_scalar_mul_le(&base, &bits));
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn bls12_g1_constant_base_scalar_mul_constraint_costs() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let base = G1::rand(&mut rng);
+        let offset = G1::rand(&mut rng);
+        let bits: Vec<bool> = (0..128).map(|_| rng.gen()).collect();
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let input = Vec::<Boolean>::alloc(cs.ns(|| "Input"), || Ok(bits.clone())).unwrap();
+        let base_gadget = G1Gadget::alloc(cs.ns(|| "base"), || Ok(base)).unwrap();
+        let offset_gadget = G1Gadget::alloc(cs.ns(|| "offset"), || Ok(offset)).unwrap();
+        let num_constraints = cs.num_constraints();
+        let _ = base_gadget
+            .mul_bits(cs.ns(|| "mul_bits"), &offset_gadget, input.iter().copied())
+            .unwrap();
+        let mul_bits_cost = cs.num_constraints() - num_constraints;
+
+        let num_constraints = cs.num_constraints();
+        let _ = G1Gadget::constant_base_scalar_mul_le(cs.ns(|| "constant base"), &base, &input).unwrap();
+        let constant_base_cost = cs.num_constraints() - num_constraints;
+
+        assert!(cs.is_satisfied());
+        assert_eq!(mul_bits_cost, 1408);
+        assert_eq!(constant_base_cost, 398);
+    }
+
     #[test]
     fn bls12_g2_gadget_test() {
         let mut cs = TestConstraintSystem::<Fq>::new();
@@ -215,4 +320,57 @@ mod test {
 
         assert!(cs.is_satisfied());
     }
+
+    #[test]
+    fn bls12_g1_to_constraint_field_test() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        for (i, point) in [G1::rand(&mut rng), G1::rand(&mut rng), G1::zero()].iter().enumerate() {
+            let gadget = G1Gadget::alloc(&mut cs.ns(|| format!("alloc_{}", i)), || Ok(*point)).unwrap();
+            let field_gadgets = gadget
+                .to_constraint_field(cs.ns(|| format!("to_constraint_field_{}", i)))
+                .unwrap();
+
+            let expected: Vec<Fq> = point.into_affine().to_field_elements().unwrap();
+            let candidate: Vec<Fq> = field_gadgets.iter().map(|fe| fe.get_value().unwrap()).collect();
+            assert_eq!(expected, candidate);
+        }
+
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn bls12_g2_to_constraint_field_test() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        for (i, point) in [G2::rand(&mut rng), G2::rand(&mut rng)].iter().enumerate() {
+            let gadget = G2Gadget::alloc(&mut cs.ns(|| format!("alloc_{}", i)), || Ok(*point)).unwrap();
+            let field_gadgets = gadget
+                .to_constraint_field(cs.ns(|| format!("to_constraint_field_{}", i)))
+                .unwrap();
+
+            let expected: Vec<Fq> = point.into_affine().to_field_elements().unwrap();
+            let candidate: Vec<Fq> = field_gadgets.iter().map(|fe| fe.get_value().unwrap()).collect();
+            assert_eq!(expected, candidate);
+        }
+
+        assert!(cs.is_satisfied());
+
+        // The on-curve check in `alloc` does not special-case the point at infinity,
+        // so the identity is converted in a separate constraint system.
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let gadget = G2Gadget::alloc(&mut cs.ns(|| "alloc_zero"), || Ok(G2::zero())).unwrap();
+        let field_gadgets = gadget
+            .to_constraint_field(cs.ns(|| "to_constraint_field_zero"))
+            .unwrap();
+
+        let expected: Vec<Fq> = G2::zero().into_affine().to_field_elements().unwrap();
+        let candidate: Vec<Fq> = field_gadgets.iter().map(|fe| fe.get_value().unwrap()).collect();
+        assert_eq!(expected, candidate);
+    }
 }
@@ -24,7 +24,13 @@ pub type EdwardsSWGadget = AffineGadget<EdwardsParameters, Fq, FqGadget>;
 #[cfg(test)]
 mod test {
     use super::EdwardsSWGadget;
-    use crate::curves::templates::twisted_edwards::test::{edwards_constraint_costs, edwards_test};
+    use crate::curves::templates::twisted_edwards::test::{
+        edwards_constant_base_scalar_mul_costs,
+        edwards_constant_base_scalar_mul_test,
+        edwards_constraint_costs,
+        edwards_test,
+        edwards_to_constraint_field_test,
+    };
     use snarkvm_curves::edwards_sw6::{EdwardsParameters, Fq};
     use snarkvm_r1cs::TestConstraintSystem;
 
@@ -41,4 +47,28 @@ mod test {
         edwards_test::<_, EdwardsParameters, EdwardsSWGadget, _>(&mut cs);
         assert!(cs.is_satisfied());
     }
+
+    #[test]
+    fn edwards_constant_base_scalar_mul_gadget_test() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        edwards_constant_base_scalar_mul_test::<_, EdwardsParameters, EdwardsSWGadget, _>(&mut cs);
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn edwards_constant_base_scalar_mul_constraint_costs() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let (mul_bits_cost, constant_base_cost) =
+            edwards_constant_base_scalar_mul_costs::<_, EdwardsParameters, EdwardsSWGadget, _>(&mut cs, 128);
+        assert!(cs.is_satisfied());
+        assert_eq!(mul_bits_cost, 1664);
+        assert_eq!(constant_base_cost, 512);
+    }
+
+    #[test]
+    fn edwards_to_constraint_field_gadget_test() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        edwards_to_constraint_field_test::<_, EdwardsParameters, EdwardsSWGadget, _>(&mut cs);
+        assert!(cs.is_satisfied());
+    }
 }
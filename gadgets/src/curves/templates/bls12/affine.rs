@@ -21,7 +21,7 @@ use snarkvm_curves::{
         GroupAffine as SWAffine,
         GroupProjective as SWProjective,
     },
-    traits::{AffineCurve, ProjectiveCurve, SWModelParameters},
+    traits::{AffineCurve, Group, ProjectiveCurve, SWModelParameters},
 };
 use snarkvm_fields::{Field, One, PrimeField, Zero};
 use snarkvm_r1cs::{errors::SynthesisError, Assignment, ConstraintSystem};
@@ -84,6 +84,14 @@ impl<P: SWModelParameters, F: Field, FG: FieldGadget<P::BaseField, F>> AffineGad
 
         Ok(Self::new(x, y, infinity))
     }
+
+    /// Returns the given point as a constant, without allocating any variables.
+    pub fn constant<CS: ConstraintSystem<F>>(mut cs: CS, point: &SWProjective<P>) -> Result<Self, SynthesisError> {
+        let point = point.into_affine();
+        let x = FG::zero(cs.ns(|| "zero x"))?.add_constant(cs.ns(|| "x"), &point.x)?;
+        let y = FG::zero(cs.ns(|| "zero y"))?.add_constant(cs.ns(|| "y"), &point.y)?;
+        Ok(Self::new(x, y, Boolean::Constant(point.infinity)))
+    }
 }
 
 impl<P, F, FG> PartialEq for AffineGadget<P, F, FG>
@@ -322,6 +330,89 @@ where
         ))
     }
 
+    /// Splits the scalar into 2-bit windows, and maps each window `d` to the signed digit
+    /// `2d - 3` in `{-3, -1, 1, 3}` of `base / 2`, which is looked up from a constant table.
+    /// As the digits are odd, neither the table entries nor the partial sums are the identity
+    /// element, and the incomplete addition never fails. The offset introduced by the digits is
+    /// removed with one final constant addition, which special-cases the all-zero and all-one
+    /// scalars, whose offset sums are exceptional.
+    ///
+    /// For scalars of at most `ScalarField::size_in_bits() - 3` bits, the partial sums do not wrap
+    /// around the group order, and no addition is exceptional. For longer scalars, a negligible
+    /// fraction of them makes the constraints unsatisfiable, but the result is never incorrect.
+    fn constant_base_scalar_mul_le<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        base: &SWProjective<P>,
+        bits: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        if base.is_zero() || bits.is_empty() {
+            return Self::zero(cs.ns(|| "zero"));
+        }
+
+        let two_inv = P::ScalarField::one().double().inverse().get()?;
+        let mut window_base = *base * two_inv;
+        // The sum of the largest digit of every window, i.e. `(4^num_windows - 1) * base / 2`.
+        let mut offset = SWProjective::<P>::zero();
+
+        let mut result: Option<Self> = None;
+        for (i, window) in bits.chunks(2).enumerate() {
+            let mut cs = cs.ns(|| format!("Window {}", i));
+
+            let window_bits = [window[0], window.get(1).copied().unwrap_or(Boolean::Constant(false))];
+            let table = [
+                -window_base.double() - window_base,
+                -window_base,
+                window_base,
+                window_base.double() + window_base,
+            ];
+            offset += &table[3];
+
+            let table = table.iter().map(|point| point.into_affine()).collect::<Vec<_>>();
+            let x_s = table.iter().map(|point| point.x).collect::<Vec<_>>();
+            let y_s = table.iter().map(|point| point.y).collect::<Vec<_>>();
+            let x = FG::two_bit_lookup(cs.ns(|| "Lookup x"), &window_bits, &x_s)?;
+            let y = FG::two_bit_lookup(cs.ns(|| "Lookup y"), &window_bits, &y_s)?;
+            let digit = Self::new(x, y, Boolean::Constant(false));
+
+            result = Some(match result {
+                None => digit,
+                Some(result) => result.add(cs.ns(|| "Add"), &digit)?,
+            });
+
+            window_base.double_in_place();
+            window_base.double_in_place();
+        }
+        let result = result.get()?;
+
+        // The sum of the digits is `scalar * base - offset`, which shares its x-coordinate with
+        // `offset` only for the all-zero scalar, where it is `-offset`, and for the all-one scalar
+        // of an even length, where it is `offset`. Neither sum can be added to `offset`.
+        let offset_gadget = Self::constant(cs.ns(|| "Offset"), &offset)?;
+        let is_exceptional = result.x.is_eq(cs.ns(|| "Check exceptional x"), &offset_gadget.x)?;
+        let is_offset = result.y.is_eq(cs.ns(|| "Check exceptional y"), &offset_gadget.y)?;
+        let is_all_ones = Boolean::and(cs.ns(|| "Check all ones"), &is_exceptional, &is_offset)?;
+        let is_zero = Boolean::and(cs.ns(|| "Check zero"), &is_exceptional, &is_offset.not())?;
+
+        let base_gadget = Self::constant(cs.ns(|| "Base"), base)?;
+        let result = Self::conditionally_select(
+            cs.ns(|| "Select non-exceptional sum"),
+            &is_exceptional,
+            &base_gadget,
+            &result,
+        )?
+        .add_constant(cs.ns(|| "Remove offset"), &offset)?;
+
+        let doubled_offset = Self::constant(cs.ns(|| "Doubled offset"), &offset.double())?;
+        let result = Self::conditionally_select(
+            cs.ns(|| "Select all-one result"),
+            &is_all_ones,
+            &doubled_offset,
+            &result,
+        )?;
+        let zero = Self::zero(cs.ns(|| "Identity"))?;
+        Self::conditionally_select(cs.ns(|| "Select zero result"), &is_zero, &zero, &result)
+    }
+
     fn cost_of_add() -> usize {
         3 * FG::cost_of_mul() + FG::cost_of_inv()
     }
@@ -418,6 +509,22 @@ where
 impl<P: SWModelParameters, F: PrimeField, FG: FieldGadget<P::BaseField, F>> AllocGadget<SWProjective<P>, F>
     for AffineGadget<P, F, FG>
 {
+    #[inline]
+    fn alloc_constant<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<SWProjective<P>>,
+    {
+        // The point is known, so the on-curve check is skipped.
+        let ge = value_gen()?.borrow().into_affine();
+
+        let x = FG::alloc_constant(&mut cs.ns(|| "x"), || Ok(ge.x))?;
+        let y = FG::alloc_constant(&mut cs.ns(|| "y"), || Ok(ge.y))?;
+        let infinity = Boolean::constant(ge.infinity);
+
+        Ok(Self::new(x, y, infinity))
+    }
+
     #[inline]
     fn alloc<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
     where
@@ -20,18 +20,19 @@ use snarkvm_curves::{
     templates::twisted_edwards_extended::GroupAffine as TEAffine,
     traits::{MontgomeryModelParameters, TEModelParameters},
 };
-use snarkvm_fields::Field;
+use snarkvm_fields::{Field, PrimeField};
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem, Namespace};
 use snarkvm_utilities::bititerator::BitIteratorBE;
 
 use crate::{
     bits::{Boolean, ToBitsBEGadget, ToBytesGadget},
+    fields::FpGadget,
     integers::uint::UInt8,
     traits::{
         alloc::AllocGadget,
         curves::{CompressedGroupGadget, GroupGadget},
         eq::{ConditionalEqGadget, EqGadget, NEqGadget},
-        fields::FieldGadget,
+        fields::{FieldGadget, ToConstraintFieldGadget},
         select::CondSelectGadget,
     },
 };
@@ -244,7 +245,7 @@ impl<P: TEModelParameters, F: Field, FG: FieldGadget<P::BaseField, F>> Eq for Af
 mod affine_impl {
     use std::ops::Neg;
 
-    use snarkvm_curves::traits::AffineCurve;
+    use snarkvm_curves::{templates::twisted_edwards_extended::GroupProjective as TEProjective, traits::AffineCurve};
     use snarkvm_fields::{Field, One, PrimeField};
     use snarkvm_r1cs::Assignment;
 
@@ -441,6 +442,15 @@ mod affine_impl {
             Ok(Self::new(self.x.negate(cs.ns(|| "negate x"))?, self.y.clone()))
         }
 
+        /// Uses the two-bit lookups of the projective scalar multiplication.
+        fn constant_base_scalar_mul_le<CS: ConstraintSystem<F>>(
+            cs: CS,
+            base: &TEAffine<P>,
+            bits: &[Boolean],
+        ) -> Result<Self, SynthesisError> {
+            <Self as GroupGadget<TEProjective<P>, F>>::constant_base_scalar_mul_le(cs, &base.into_projective(), bits)
+        }
+
         fn cost_of_add() -> usize {
             4 + 2 * FG::cost_of_mul()
         }
@@ -455,6 +465,22 @@ mod affine_impl {
     where
         Self: GroupGadget<TEAffine<P>, F>,
     {
+        fn alloc_constant<
+            Fn: FnOnce() -> Result<T, SynthesisError>,
+            T: Borrow<TEAffine<P>>,
+            CS: ConstraintSystem<F>,
+        >(
+            mut cs: CS,
+            value_gen: Fn,
+        ) -> Result<Self, SynthesisError> {
+            // The point is known, so the on-curve check is skipped.
+            let ge = *value_gen()?.borrow();
+
+            let x = FG::alloc_constant(&mut cs.ns(|| "x"), || Ok(ge.x))?;
+            let y = FG::alloc_constant(&mut cs.ns(|| "y"), || Ok(ge.y))?;
+            Ok(Self::new(x, y))
+        }
+
         fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<TEAffine<P>>, CS: ConstraintSystem<F>>(
             mut cs: CS,
             value_gen: Fn,
@@ -1089,6 +1115,19 @@ mod projective_impl {
     where
         Self: GroupGadget<TEProjective<P>, F>,
     {
+        fn alloc_constant<FN, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: FN) -> Result<Self, SynthesisError>
+        where
+            FN: FnOnce() -> Result<T, SynthesisError>,
+            T: Borrow<TEProjective<P>>,
+        {
+            // The point is known, so the on-curve check is skipped.
+            let ge = value_gen()?.borrow().into_affine();
+
+            let x = FG::alloc_constant(&mut cs.ns(|| "x"), || Ok(ge.x))?;
+            let y = FG::alloc_constant(&mut cs.ns(|| "y"), || Ok(ge.y))?;
+            Ok(Self::new(x, y))
+        }
+
         fn alloc<FN, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: FN) -> Result<Self, SynthesisError>
         where
             FN: FnOnce() -> Result<T, SynthesisError>,
@@ -1333,3 +1372,19 @@ impl<P: TEModelParameters, F: Field, FG: FieldGadget<P::BaseField, F>> ToBytesGa
         Ok(x_bytes)
     }
 }
+
+impl<P, F, FG> ToConstraintFieldGadget<F> for AffineGadget<P, F, FG>
+where
+    P: TEModelParameters,
+    F: PrimeField,
+    FG: FieldGadget<P::BaseField, F> + ToConstraintFieldGadget<F>,
+{
+    fn to_constraint_field<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<FpGadget<F>>, SynthesisError> {
+        let mut res = Vec::<FpGadget<F>>::new();
+
+        res.extend_from_slice(&self.x.to_constraint_field(cs.ns(|| "x_to_constraint_field"))?);
+        res.extend_from_slice(&self.y.to_constraint_field(cs.ns(|| "y_to_constraint_field"))?);
+
+        Ok(res)
+    }
+}
@@ -17,18 +17,23 @@
 use crate::{
     bits::boolean::{AllocatedBit, Boolean},
     curves::tests_group::group_test,
-    traits::{alloc::AllocGadget, curves::GroupGadget, select::CondSelectGadget},
+    traits::{
+        alloc::AllocGadget,
+        curves::GroupGadget,
+        fields::{FieldGadget, ToConstraintFieldGadget},
+        select::CondSelectGadget,
+    },
 };
 use snarkvm_curves::{
     templates::twisted_edwards_extended::GroupAffine as TEAffine,
     traits::{Group, TEModelParameters},
 };
-use snarkvm_fields::{Field, PrimeField};
+use snarkvm_fields::{Field, PrimeField, ToConstraintField, Zero};
 use snarkvm_r1cs::ConstraintSystem;
 use snarkvm_utilities::{bititerator::BitIteratorBE, rand::UniformRand};
 
 use core::ops::Mul;
-use rand::thread_rng;
+use rand::{thread_rng, Rng};
 
 pub(crate) fn edwards_test<F, P, GG, CS>(cs: &mut CS)
 where
@@ -61,6 +66,99 @@ where
     assert_eq!(native_result, gadget_value);
 }
 
+pub(crate) fn edwards_constant_base_scalar_mul_test<F, P, GG, CS>(cs: &mut CS)
+where
+    F: Field,
+    P: TEModelParameters,
+    GG: GroupGadget<TEAffine<P>, F, Value = TEAffine<P>>,
+    CS: ConstraintSystem<F>,
+{
+    let mut rng = thread_rng();
+
+    for (i, num_bits) in [1, 2, 3, 64, 251].iter().enumerate() {
+        let mut cs = cs.ns(|| format!("{} bits", num_bits));
+
+        let base: TEAffine<P> = UniformRand::rand(&mut rng);
+        let bits: Vec<bool> = (0..*num_bits).map(|_| rng.gen()).collect();
+        let input = Vec::<Boolean>::alloc(cs.ns(|| "Input"), || Ok(bits.clone())).unwrap();
+
+        let result = GG::constant_base_scalar_mul_le(cs.ns(|| "constant base"), &base, &input).unwrap();
+
+        let base_gadget = GG::alloc(cs.ns(|| "base"), || Ok(base)).unwrap();
+        let zero = GG::zero(cs.ns(|| "zero")).unwrap();
+        let expected = base_gadget
+            .mul_bits(cs.ns(|| "mul_bits"), &zero, input.iter().copied())
+            .unwrap();
+        assert_eq!(result.get_value().unwrap(), expected.get_value().unwrap(), "{}", i);
+    }
+
+    // The all-zero scalar, and the identity element as base.
+    let base: TEAffine<P> = UniformRand::rand(&mut rng);
+    let input = vec![Boolean::constant(false); 8];
+    let result = GG::constant_base_scalar_mul_le(cs.ns(|| "zero scalar"), &base, &input).unwrap();
+    assert!(result.get_value().unwrap().is_zero());
+
+    let input = Vec::<Boolean>::alloc(cs.ns(|| "Input"), || Ok(vec![true; 8])).unwrap();
+    let result = GG::constant_base_scalar_mul_le(cs.ns(|| "zero base"), &TEAffine::zero(), &input).unwrap();
+    assert!(result.get_value().unwrap().is_zero());
+}
+
+/// Returns the number of constraints of `mul_bits` and `constant_base_scalar_mul_le`
+/// for a scalar of the given length.
+pub(crate) fn edwards_constant_base_scalar_mul_costs<F, P, GG, CS>(cs: &mut CS, num_bits: usize) -> (usize, usize)
+where
+    F: Field,
+    P: TEModelParameters,
+    GG: GroupGadget<TEAffine<P>, F, Value = TEAffine<P>>,
+    CS: ConstraintSystem<F>,
+{
+    let base: TEAffine<P> = rand::random();
+    let input = Vec::<Boolean>::alloc(cs.ns(|| "Input"), || Ok(vec![true; num_bits])).unwrap();
+    let base_gadget = GG::alloc(cs.ns(|| "base"), || Ok(base)).unwrap();
+    let zero = GG::zero(cs.ns(|| "zero")).unwrap();
+
+    let num_constraints = cs.num_constraints();
+    let _ = base_gadget
+        .mul_bits(cs.ns(|| "mul_bits"), &zero, input.iter().copied())
+        .unwrap();
+    let mul_bits_cost = cs.num_constraints() - num_constraints;
+
+    let num_constraints = cs.num_constraints();
+    let _ = GG::constant_base_scalar_mul_le(cs.ns(|| "constant base"), &base, &input).unwrap();
+    let constant_base_cost = cs.num_constraints() - num_constraints;
+
+    (mul_bits_cost, constant_base_cost)
+}
+
+pub(crate) fn edwards_to_constraint_field_test<F, P, GG, CS>(cs: &mut CS)
+where
+    F: PrimeField,
+    P: TEModelParameters,
+    P::BaseField: ToConstraintField<F>,
+    GG: GroupGadget<TEAffine<P>, F, Value = TEAffine<P>> + ToConstraintFieldGadget<F>,
+    CS: ConstraintSystem<F>,
+{
+    let mut rng = thread_rng();
+
+    for (i, point) in [
+        TEAffine::<P>::rand(&mut rng),
+        TEAffine::<P>::rand(&mut rng),
+        TEAffine::<P>::zero(),
+    ]
+    .iter()
+    .enumerate()
+    {
+        let gadget = GG::alloc(&mut cs.ns(|| format!("alloc_{}", i)), || Ok(*point)).unwrap();
+        let field_gadgets = gadget
+            .to_constraint_field(cs.ns(|| format!("to_constraint_field_{}", i)))
+            .unwrap();
+
+        let expected: Vec<F> = point.to_field_elements().unwrap();
+        let candidate: Vec<F> = field_gadgets.iter().map(|fe| fe.get_value().unwrap()).collect();
+        assert_eq!(expected, candidate);
+    }
+}
+
 pub(crate) fn edwards_constraint_costs<F, P, GG, CS>(cs: &mut CS)
 where
     F: Field,
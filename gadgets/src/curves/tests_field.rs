@@ -16,6 +16,7 @@
 
 use rand::{
     thread_rng,
+    Rng,
     SeedableRng,
     {self},
 };
@@ -168,6 +169,29 @@ fn field_test<NativeF: Field, F: Field, FG: FieldGadget<NativeF, F>, CS: Constra
         a.pow(cs.ns(|| "test_pow"), &bits).unwrap().get_value().unwrap()
     );
 
+    // a^e for constant and variable exponents, including zero and one
+    let mut rng = thread_rng();
+    let exponents = [0u64, 1, 5, rng.gen(), rng.gen()];
+    for (i, e) in exponents.iter().enumerate() {
+        let expected = a_native.pow([*e]);
+        let by_constant = a
+            .pow_by_constant(cs.ns(|| format!("pow_by_constant_{}", i)), [*e])
+            .unwrap();
+        assert_eq!(expected, by_constant.get_value().unwrap());
+
+        let exponent_bits = (0..64)
+            .map(|j| Boolean::alloc(cs.ns(|| format!("exponent_{}_bit_{}", i, j)), || Ok((*e >> j) & 1 == 1)).unwrap())
+            .collect::<Vec<_>>();
+        let by_boolean = a
+            .pow_by_boolean_exponent(cs.ns(|| format!("pow_by_boolean_exponent_{}", i)), &exponent_bits)
+            .unwrap();
+        assert_eq!(expected, by_boolean.get_value().unwrap());
+    }
+    let by_empty = a
+        .pow_by_boolean_exponent(cs.ns(|| "pow_by_empty_exponent"), &[])
+        .unwrap();
+    assert_eq!(one_native, by_empty.get_value().unwrap());
+
     // a * a * a = a^3
     let mut constants = [NativeF::zero(); 4];
     for c in &mut constants {
@@ -273,3 +297,21 @@ fn edwards_field_gadgets_test() {
     }
     assert!(cs.is_satisfied());
 }
+
+#[test]
+fn pow_by_constant_x5_constraints_test() {
+    use crate::curves::bls12_377::FqGadget;
+    use snarkvm_curves::bls12_377::Fq;
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let a_native = Fq::rand(&mut rng);
+    let a = FqGadget::alloc(&mut cs.ns(|| "generate_a"), || Ok(a_native)).unwrap();
+
+    let num_constraints = cs.num_constraints();
+    let a_5 = a.pow_by_constant(cs.ns(|| "a^5"), [5]).unwrap();
+    assert_eq!(cs.num_constraints() - num_constraints, 3);
+    assert_eq!(a_5.get_value().unwrap(), a_native.pow([5]));
+    assert!(cs.is_satisfied());
+}
@@ -15,9 +15,14 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    bits::Boolean,
     errors::SignedIntegerError,
     integers::int::*,
-    traits::integers::{Add, Neg, Sub},
+    traits::{
+        bits::{RippleCarryAdder, Xor},
+        eq::EqGadget,
+        integers::{Integer, Sub},
+    },
 };
 use snarkvm_fields::PrimeField;
 use snarkvm_r1cs::ConstraintSystem;
@@ -28,11 +33,53 @@ macro_rules! sub_int_impl {
             type ErrorType = SignedIntegerError;
 
             fn sub<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, Self::ErrorType> {
-                // Negate other
-                let other_neg = other.neg(cs.ns(|| format!("negate")))?;
+                // check for subtraction overflow here
+                if let (Some(a), Some(b)) = (self.value, other.value) {
+                    if a.checked_sub(b).is_none() {
+                        return Err(SignedIntegerError::Overflow);
+                    }
+                }
 
-                // self + negated other
-                self.add(cs.ns(|| format!("add_complement")), &other_neg)
+                let (result, overflow) = self.overflowing_sub(cs.ns(|| "overflowing_sub"), other)?;
+
+                // enforce no overflow in the circuit, for when the values are not known
+                overflow.enforce_equal(cs.ns(|| "enforce no overflow"), &Boolean::constant(false))?;
+
+                Ok(result)
+            }
+
+            fn overflowing_sub<CS: ConstraintSystem<F>>(
+                &self,
+                mut cs: CS,
+                other: &Self,
+            ) -> Result<(Self, Boolean), Self::ErrorType> {
+                // a - b = a + !b + 1 in two's complement
+                let other_complement: Vec<Boolean> = other.bits.iter().map(|bit| bit.not()).collect();
+                let mut bits = self.bits.add_bits_with_carry(
+                    cs.ns(|| "bits"),
+                    &other_complement,
+                    &Boolean::constant(true),
+                )?;
+
+                // the carry is not part of the wrapped result
+                let _carry = bits.pop();
+
+                // overflow occurs when the operands have different signs,
+                // and the sign of the result differs from the sign of `self`
+                let self_sign = self.bits[<$gadget as Integer>::SIZE - 1];
+                let other_sign = other.bits[<$gadget as Integer>::SIZE - 1];
+                let result_sign = bits[<$gadget as Integer>::SIZE - 1];
+
+                let operand_signs_differ = self_sign.xor(cs.ns(|| "operand signs differ"), &other_sign)?;
+                let result_sign_differs = self_sign.xor(cs.ns(|| "result sign differs"), &result_sign)?;
+                let overflow = Boolean::and(cs.ns(|| "overflow"), &operand_signs_differ, &result_sign_differs)?;
+
+                let value = match (self.value, other.value) {
+                    (Some(a), Some(b)) => Some(a.wrapping_sub(b)),
+                    _ => None,
+                };
+
+                Ok((Self { bits, value }, overflow))
             }
         }
     )*)
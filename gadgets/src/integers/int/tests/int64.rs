@@ -25,7 +25,12 @@ use snarkvm_r1cs::{ConstraintSystem, Fr, TestConstraintSystem};
 use crate::{
     bits::Boolean,
     integers::int::*,
-    traits::{alloc::AllocGadget, eq::EqGadget, integers::*},
+    traits::{
+        alloc::AllocGadget,
+        bits::{ComparatorGadget, EvaluateLtGadget},
+        eq::EqGadget,
+        integers::*,
+    },
 };
 
 fn check_all_constant_bits(expected: i64, actual: Int64) {
@@ -169,10 +174,6 @@ fn test_int64_sub_constants() {
         let a: i64 = rng.gen();
         let b: i64 = rng.gen();
 
-        if b.checked_neg().is_none() {
-            // negate with overflows will fail: -128
-            continue;
-        }
         let expected = match a.checked_sub(b) {
             // subtract with overflow will fail: -0
             Some(valid) => valid,
@@ -200,10 +201,6 @@ fn test_int64_sub() {
         let a: i64 = rng.gen();
         let b: i64 = rng.gen();
 
-        if b.checked_neg().is_none() {
-            // negate with overflows will fail: -9223372036854775808
-            continue;
-        }
         let expected = match a.checked_sub(b) {
             // subtract with overflow will fail: -0
             Some(valid) => valid,
@@ -223,12 +220,12 @@ fn test_int64_sub() {
 
         // Flip a bit_gadget and see if the subtraction constraint still works
         if cs
-            .get("subtraction/add_complement/result bit_gadget 0/boolean")
+            .get("subtraction/overflowing_sub/bits/rpc 0/a XOR b/xor result")
             .is_zero()
         {
-            cs.set("subtraction/add_complement/result bit_gadget 0/boolean", Fr::one());
+            cs.set("subtraction/overflowing_sub/bits/rpc 0/a XOR b/xor result", Fr::one());
         } else {
-            cs.set("subtraction/add_complement/result bit_gadget 0/boolean", Fr::zero());
+            cs.set("subtraction/overflowing_sub/bits/rpc 0/a XOR b/xor result", Fr::zero());
         }
 
         assert!(!cs.is_satisfied());
@@ -437,3 +434,120 @@ fn test_int64_pow() {
         assert!(!cs.is_satisfied());
     }
 }
+
+#[test]
+fn test_int64_overflowing_sub() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let edge_values = [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX - 1, i64::MAX];
+    let mut pairs = Vec::new();
+    for a in edge_values.iter() {
+        for b in edge_values.iter() {
+            pairs.push((*a, *b));
+        }
+    }
+    pairs.extend((0..1000).map(|_| (rng.gen(), rng.gen())));
+
+    for (a, b) in pairs {
+        {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let a_bit = Int64::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+            let b_bit = Int64::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+            let (r, overflow) = a_bit
+                .overflowing_sub(cs.ns(|| "overflowing subtraction"), &b_bit)
+                .unwrap();
+            let (expected, expected_overflow) = a.overflowing_sub(b);
+
+            assert!(cs.is_satisfied());
+            assert_eq!(r.value, Some(expected));
+            assert_eq!(overflow.get_value(), Some(expected_overflow));
+            check_all_allocated_bits(expected, r);
+
+            // the checked subtraction fails exactly when the subtraction overflows
+            let checked = a_bit.sub(cs.ns(|| "subtraction"), &b_bit);
+            assert_eq!(checked.is_err(), expected_overflow);
+        }
+    }
+}
+
+#[test]
+fn test_int64_overflowing_sub_constants() {
+    for (a, b) in [
+        (i64::MIN, 1),
+        (i64::MAX, -1),
+        (-1, i64::MIN),
+        (0, i64::MIN),
+        (i64::MIN, i64::MIN),
+    ]
+    .iter()
+    {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let (r, overflow) = Int64::constant(*a)
+            .overflowing_sub(cs.ns(|| "overflowing subtraction"), &Int64::constant(*b))
+            .unwrap();
+        let (expected, expected_overflow) = a.overflowing_sub(*b);
+
+        assert_eq!(r.value, Some(expected));
+        assert_eq!(overflow, Boolean::constant(expected_overflow));
+        check_all_constant_bits(expected, r);
+    }
+}
+
+#[test]
+fn test_int64_sub_overflow_is_enforced() {
+    let mut cs = TestConstraintSystem::<Fr>::new();
+
+    let a_bit = Int64::alloc(cs.ns(|| "a_bit"), || Ok(i64::MIN)).unwrap();
+    let b_bit = Int64::alloc(cs.ns(|| "b_bit"), || Ok(0)).unwrap();
+
+    a_bit.sub(cs.ns(|| "subtraction"), &b_bit).unwrap();
+    assert!(cs.is_satisfied());
+
+    // Changing the subtrahend to 1 makes the subtraction overflow, which the circuit rejects.
+    for i in 0..<Int64 as Integer>::SIZE {
+        let expected = if i == 0 { Fr::one() } else { Fr::zero() };
+        cs.set(&format!("b_bit/allocated bit_gadget {}/boolean", i), expected);
+    }
+    assert!(!cs.is_satisfied());
+}
+
+#[test]
+fn test_int64_comparison() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let edge_values = [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX - 1, i64::MAX];
+    let mut pairs = Vec::new();
+    for a in edge_values.iter() {
+        for b in edge_values.iter() {
+            pairs.push((*a, *b));
+        }
+    }
+    pairs.extend((0..1000).map(|_| (rng.gen(), rng.gen())));
+
+    for (a, b) in pairs {
+        {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let a_bit = Int64::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+            let b_bit = Int64::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+            let lt = a_bit.less_than(cs.ns(|| "less than"), &b_bit).unwrap();
+            let le = a_bit
+                .less_than_or_equal(cs.ns(|| "less than or equal"), &b_bit)
+                .unwrap();
+            let gt = a_bit.greater_than(cs.ns(|| "greater than"), &b_bit).unwrap();
+            let ge = a_bit
+                .greater_than_or_equal(cs.ns(|| "greater than or equal"), &b_bit)
+                .unwrap();
+
+            assert!(cs.is_satisfied());
+            assert_eq!(lt.get_value(), Some(a < b));
+            assert_eq!(le.get_value(), Some(a <= b));
+            assert_eq!(gt.get_value(), Some(a > b));
+            assert_eq!(ge.get_value(), Some(a >= b));
+        }
+    }
+}
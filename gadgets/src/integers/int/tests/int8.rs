@@ -25,7 +25,11 @@ use snarkvm_r1cs::{ConstraintSystem, Fr, TestConstraintSystem};
 use crate::{
     bits::Boolean,
     integers::int::*,
-    traits::{alloc::AllocGadget, integers::*},
+    traits::{
+        alloc::AllocGadget,
+        bits::{ComparatorGadget, EvaluateLtGadget},
+        integers::*,
+    },
 };
 
 fn check_all_constant_bits(expected: i8, actual: Int8) {
@@ -162,10 +166,6 @@ fn test_int8_sub_constants() {
         let a: i8 = rng.gen();
         let b: i8 = rng.gen();
 
-        if b.checked_neg().is_none() {
-            // negate with overflows will fail: -128
-            continue;
-        }
         let expected = match a.checked_sub(b) {
             // subtract with overflow will fail: -0
             Some(valid) => valid,
@@ -193,10 +193,6 @@ fn test_int8_sub() {
         let a: i8 = rng.gen();
         let b: i8 = rng.gen();
 
-        if b.checked_neg().is_none() {
-            // negate with overflows will fail: -128
-            continue;
-        }
         let expected = match a.checked_sub(b) {
             // subtract with overflow will fail: -0
             Some(valid) => valid,
@@ -216,12 +212,12 @@ fn test_int8_sub() {
 
         // Flip a bit_gadget and see if the subtraction constraint still works
         if cs
-            .get("subtraction/add_complement/result bit_gadget 0/boolean")
+            .get("subtraction/overflowing_sub/bits/rpc 0/a XOR b/xor result")
             .is_zero()
         {
-            cs.set("subtraction/add_complement/result bit_gadget 0/boolean", Fr::one());
+            cs.set("subtraction/overflowing_sub/bits/rpc 0/a XOR b/xor result", Fr::one());
         } else {
-            cs.set("subtraction/add_complement/result bit_gadget 0/boolean", Fr::zero());
+            cs.set("subtraction/overflowing_sub/bits/rpc 0/a XOR b/xor result", Fr::zero());
         }
 
         assert!(!cs.is_satisfied());
@@ -424,3 +420,98 @@ fn test_int8_pow() {
         assert!(!cs.is_satisfied());
     }
 }
+
+#[test]
+fn test_int8_overflowing_sub() {
+    for a in i8::MIN..=i8::MAX {
+        for b in i8::MIN..=i8::MAX {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let a_bit = Int8::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+            let b_bit = Int8::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+            let (r, overflow) = a_bit
+                .overflowing_sub(cs.ns(|| "overflowing subtraction"), &b_bit)
+                .unwrap();
+            let (expected, expected_overflow) = a.overflowing_sub(b);
+
+            assert!(cs.is_satisfied());
+            assert_eq!(r.value, Some(expected));
+            assert_eq!(overflow.get_value(), Some(expected_overflow));
+            check_all_allocated_bits(expected, r);
+
+            // the checked subtraction fails exactly when the subtraction overflows
+            let checked = a_bit.sub(cs.ns(|| "subtraction"), &b_bit);
+            assert_eq!(checked.is_err(), expected_overflow);
+        }
+    }
+}
+
+#[test]
+fn test_int8_overflowing_sub_constants() {
+    for (a, b) in [
+        (i8::MIN, 1),
+        (i8::MAX, -1),
+        (-1, i8::MIN),
+        (0, i8::MIN),
+        (i8::MIN, i8::MIN),
+    ]
+    .iter()
+    {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let (r, overflow) = Int8::constant(*a)
+            .overflowing_sub(cs.ns(|| "overflowing subtraction"), &Int8::constant(*b))
+            .unwrap();
+        let (expected, expected_overflow) = a.overflowing_sub(*b);
+
+        assert_eq!(r.value, Some(expected));
+        assert_eq!(overflow, Boolean::constant(expected_overflow));
+        check_all_constant_bits(expected, r);
+    }
+}
+
+#[test]
+fn test_int8_sub_overflow_is_enforced() {
+    let mut cs = TestConstraintSystem::<Fr>::new();
+
+    let a_bit = Int8::alloc(cs.ns(|| "a_bit"), || Ok(i8::MIN)).unwrap();
+    let b_bit = Int8::alloc(cs.ns(|| "b_bit"), || Ok(0)).unwrap();
+
+    a_bit.sub(cs.ns(|| "subtraction"), &b_bit).unwrap();
+    assert!(cs.is_satisfied());
+
+    // Changing the subtrahend to 1 makes the subtraction overflow, which the circuit rejects.
+    for i in 0..<Int8 as Integer>::SIZE {
+        let expected = if i == 0 { Fr::one() } else { Fr::zero() };
+        cs.set(&format!("b_bit/allocated bit_gadget {}/boolean", i), expected);
+    }
+    assert!(!cs.is_satisfied());
+}
+
+#[test]
+fn test_int8_comparison() {
+    for a in i8::MIN..=i8::MAX {
+        for b in i8::MIN..=i8::MAX {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let a_bit = Int8::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+            let b_bit = Int8::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+            let lt = a_bit.less_than(cs.ns(|| "less than"), &b_bit).unwrap();
+            let le = a_bit
+                .less_than_or_equal(cs.ns(|| "less than or equal"), &b_bit)
+                .unwrap();
+            let gt = a_bit.greater_than(cs.ns(|| "greater than"), &b_bit).unwrap();
+            let ge = a_bit
+                .greater_than_or_equal(cs.ns(|| "greater than or equal"), &b_bit)
+                .unwrap();
+
+            assert!(cs.is_satisfied());
+            assert_eq!(lt.get_value(), Some(a < b));
+            assert_eq!(le.get_value(), Some(a <= b));
+            assert_eq!(gt.get_value(), Some(a > b));
+            assert_eq!(ge.get_value(), Some(a >= b));
+        }
+    }
+}
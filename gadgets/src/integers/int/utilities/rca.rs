@@ -21,8 +21,13 @@ use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
 macro_rules! rca_impl {
     ($($gadget: ident)*) => ($(
         impl<F: Field + PrimeField> RippleCarryAdder<F> for $gadget {
-            fn add_bits<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Vec<Boolean>, SynthesisError> {
-                self.bits.add_bits(cs, &other.bits)
+            fn add_bits_with_carry<CS: ConstraintSystem<F>>(
+                &self,
+                cs: CS,
+                other: &Self,
+                carry: &Boolean,
+            ) -> Result<Vec<Boolean>, SynthesisError> {
+                self.bits.add_bits_with_carry(cs, &other.bits, carry)
             }
         }
     )*)
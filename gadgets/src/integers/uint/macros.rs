@@ -28,8 +28,8 @@ macro_rules! to_bytes_int_impl {
                     val.write(bytes.as_mut()).unwrap();
                     bytes
                 }) {
-                    Some(chunks) => [Some(chunks[0]), Some(chunks[1]), Some(chunks[2]), Some(chunks[3])],
-                    None => [None, None, None, None],
+                    Some(chunks) => chunks.iter().map(|byte| Some(*byte)).collect::<Vec<_>>(),
+                    None => vec![None; BYTES_SIZE],
                 };
                 let bits = self.to_bits_le();
                 let mut bytes = Vec::with_capacity(bits.len() / 8);
@@ -23,7 +23,7 @@ use snarkvm_fields::{One, Zero};
 use snarkvm_r1cs::{ConstraintSystem, Fr, TestConstraintSystem};
 
 use crate::{
-    bits::Boolean,
+    bits::{Boolean, ToBytesGadget},
     integers::uint::{Sub, UInt, UInt64},
     traits::{alloc::AllocGadget, bits::Xor, integers::*},
 };
@@ -88,6 +88,46 @@ fn test_uint64_from_bits() {
     }
 }
 
+#[test]
+fn test_uint64_to_bytes() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..1000 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a: u64 = rng.gen();
+        let a_bit = UInt64::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+
+        let bytes = a_bit.to_bytes(cs.ns(|| "to_bytes")).unwrap();
+        let values = bytes.iter().map(|byte| byte.value.unwrap()).collect::<Vec<_>>();
+
+        assert_eq!(values, a.to_le_bytes());
+        assert!(cs.is_satisfied());
+    }
+}
+
+#[test]
+fn test_uint64_to_bits_be_from_bits_be() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..1000 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a: u64 = rng.gen();
+        let a_bit = UInt64::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+
+        let bits_be = a_bit.to_bits_be();
+        let values = bits_be.iter().map(|bit| bit.get_value().unwrap()).collect::<Vec<_>>();
+        let expected = (0..64).rev().map(|i| (a >> i) & 1 == 1).collect::<Vec<_>>();
+        assert_eq!(values, expected);
+
+        let recovered = UInt64::from_bits_be(&bits_be);
+        assert_eq!(recovered.value, Some(a));
+        check_all_allocated_bits(a, recovered);
+        assert!(cs.is_satisfied());
+    }
+}
+
 #[test]
 fn test_uint64_rotr() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
@@ -17,13 +17,19 @@
 use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 
-use snarkvm_fields::{One, Zero};
+use snarkvm_fields::{One, ToConstraintField, Zero};
 use snarkvm_r1cs::{ConstraintSystem, Fr, TestConstraintSystem};
 
 use crate::{
     bits::Boolean,
+    fields::FpGadget,
     integers::uint::{Sub, UInt, UInt8},
-    traits::{alloc::AllocGadget, bits::Xor, integers::*},
+    traits::{
+        alloc::AllocGadget,
+        bits::Xor,
+        fields::{FieldGadget, ToConstraintFieldGadget},
+        integers::*,
+    },
 };
 
 fn check_all_constant_bits(mut expected: u8, actual: UInt8) {
@@ -67,6 +73,41 @@ fn test_uint8_from_bits_to_bits() {
     }
 }
 
+#[test]
+fn test_uint8_to_bits_be_from_bits_be() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..100 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let byte_val: u8 = rng.gen();
+        let byte = UInt8::alloc(cs.ns(|| "alloc value"), || Ok(byte_val)).unwrap();
+
+        // The big-endian bits start from the most significant bit.
+        let bits_be = byte.to_bits_be();
+        for (i, bit) in bits_be.iter().enumerate() {
+            assert_eq!(bit.get_value().unwrap(), (byte_val >> (7 - i)) & 1 == 1);
+        }
+
+        let mut bits_le = byte.to_bits_le();
+        bits_le.reverse();
+        assert_eq!(bits_be, bits_le);
+
+        // Reading the bits back in the same order recovers the byte.
+        let recovered = UInt8::from_bits_be(&bits_be);
+        assert_eq!(recovered.value, Some(byte_val));
+        check_all_allocated_bits(byte_val, recovered);
+
+        // Reading them in the other order reverses the bits of the byte.
+        assert_eq!(UInt8::from_bits_le(&bits_be).value, Some(byte_val.reverse_bits()));
+    }
+
+    let constant = UInt8::constant(0b01110001);
+    let recovered = UInt8::from_bits_be(&constant.to_bits_be());
+    assert_eq!(recovered.value, Some(0b01110001));
+    check_all_constant_bits(0b01110001, recovered);
+}
+
 #[test]
 fn test_uint8_alloc_input_vec() {
     let mut cs = TestConstraintSystem::<Fr>::new();
@@ -80,6 +121,40 @@ fn test_uint8_alloc_input_vec() {
     }
 }
 
+#[test]
+fn test_uint8_to_constraint_field() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for num_bytes in [0, 1, 30, 31, 32, 100].iter() {
+        let byte_vals = (0..*num_bytes).map(|_| rng.gen()).collect::<Vec<u8>>();
+        let expected: Vec<Fr> = ToConstraintField::<Fr>::to_field_elements(&byte_vals[..]).unwrap();
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let bytes = UInt8::alloc_vec(cs.ns(|| "alloc value"), &byte_vals).unwrap();
+        let num_constraints = cs.num_constraints();
+
+        let field_gadgets = bytes.to_constraint_field(cs.ns(|| "to_constraint_field")).unwrap();
+        let candidate = field_gadgets
+            .iter()
+            .map(|fe| fe.get_value().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(expected, candidate);
+        assert_eq!(num_constraints, cs.num_constraints());
+        assert!(cs.is_satisfied());
+
+        let constants = UInt8::constant_vec(&byte_vals);
+        let field_gadgets = constants
+            .to_constraint_field(cs.ns(|| "constant_to_constraint_field"))
+            .unwrap();
+        for (fe, expected) in field_gadgets.iter().zip(expected.iter()) {
+            match fe {
+                FpGadget::Constant(value) => assert_eq!(value, expected),
+                FpGadget::Variable(_) => panic!(),
+            }
+        }
+    }
+}
+
 #[test]
 fn test_uint8_from_bits() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
@@ -17,7 +17,7 @@
 use std::fmt::Debug;
 
 use snarkvm_fields::{Field, FieldParameters, PrimeField, ToConstraintField};
-use snarkvm_r1cs::{errors::SynthesisError, Assignment, ConstraintSystem, LinearCombination};
+use snarkvm_r1cs::{errors::SynthesisError, Assignment, ConstraintSystem, ConstraintVariable, LinearCombination};
 use snarkvm_utilities::bytes::ToBytes;
 
 use crate::{
@@ -26,10 +26,11 @@ use crate::{
         ToBitsBEGadget,
         ToBytesGadget,
     },
-    fields::FpGadget,
+    fields::{AllocatedFp, FpGadget},
     traits::{
         alloc::AllocGadget,
         eq::{ConditionalEqGadget, EqGadget},
+        fields::ToConstraintFieldGadget,
         integers::Integer,
         select::CondSelectGadget,
     },
@@ -115,3 +116,47 @@ impl UInt8 {
             .collect())
     }
 }
+
+impl<F: PrimeField> ToConstraintFieldGadget<F> for [UInt8] {
+    /// Packs the bytes into as few field elements as possible, matching the layout of
+    /// the native `ToConstraintField` implementation for `[u8]`. The packing is a
+    /// linear combination of the underlying bits, so no constraints are added.
+    fn to_constraint_field<CS: ConstraintSystem<F>>(&self, _cs: CS) -> Result<Vec<FpGadget<F>>, SynthesisError> {
+        let max_size = (F::Parameters::CAPACITY / 8) as usize;
+
+        let mut res = Vec::new();
+        for chunk in self.chunks(max_size) {
+            let mut lc = LinearCombination::zero();
+            let mut value = Some(F::zero());
+            let mut is_constant = true;
+            let mut coeff = F::one();
+
+            for bit in chunk.iter().flat_map(|byte| byte.bits.iter()) {
+                lc = lc + bit.lc(CS::one(), coeff);
+                value = match (value, bit.get_value()) {
+                    (Some(value), Some(true)) => Some(value + coeff),
+                    (Some(value), Some(false)) => Some(value),
+                    _ => None,
+                };
+                is_constant &= matches!(bit, Boolean::Constant(_));
+                coeff.double_in_place();
+            }
+
+            res.push(match value {
+                Some(value) if is_constant => FpGadget::Constant(value),
+                _ => FpGadget::Variable(AllocatedFp {
+                    value,
+                    variable: ConstraintVariable::LC(lc),
+                }),
+            });
+        }
+
+        Ok(res)
+    }
+}
+
+impl<F: PrimeField> ToConstraintFieldGadget<F> for Vec<UInt8> {
+    fn to_constraint_field<CS: ConstraintSystem<F>>(&self, cs: CS) -> Result<Vec<FpGadget<F>>, SynthesisError> {
+        self.as_slice().to_constraint_field(cs)
+    }
+}
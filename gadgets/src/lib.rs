@@ -70,6 +70,12 @@ pub mod nonnative;
 pub mod traits;
 pub use traits::*;
 
+/// Registers `snarkvm-gadgets` and the crates it depends on.
+pub fn register_capabilities(capabilities: &mut snarkvm_utilities::Capabilities) {
+    capabilities.register(snarkvm_utilities::CrateCapabilities::new("snarkvm-gadgets", &[]));
+    snarkvm_algorithms::register_capabilities(capabilities);
+}
+
 pub mod prelude {
     pub use crate::{bits::*, errors::*, traits::*};
 }
@@ -36,7 +36,7 @@ use crate::{
 };
 
 /// The allocated form of `NonNativeFieldMulResultVar` (introduced below)
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct AllocatedNonNativeFieldMulResultVar<TargetField: PrimeField, BaseField: PrimeField> {
     /// Limbs of the intermediate representations
     pub limbs: Vec<FpGadget<BaseField>>,
@@ -131,6 +131,9 @@ impl<TargetField: PrimeField, BaseField: PrimeField> AllocatedNonNativeFieldMulR
         };
 
         // Step 2: Compute surfeit
+        if !Self::is_reducible(self.prod_of_num_of_additions, self.get_optimization_type()) {
+            return Err(SynthesisError::Unsatisfiable);
+        }
         let surfeit = overhead!(self.prod_of_num_of_additions + BaseField::one()) + 1 + 1;
 
         // Step 3: Allocate k
@@ -248,9 +251,21 @@ impl<TargetField: PrimeField, BaseField: PrimeField> AllocatedNonNativeFieldMulR
     }
 
     /// Add unreduced elements.
+    ///
+    /// If the sum would have too many additions to be reduced, both elements are reduced first.
     pub fn add<CS: ConstraintSystem<BaseField>>(&self, cs: &mut CS, other: &Self) -> Result<Self, SynthesisError> {
         assert_eq!(self.get_optimization_type(), other.get_optimization_type());
 
+        let prod_of_num_of_additions = self.prod_of_num_of_additions + other.prod_of_num_of_additions;
+        if !Self::is_reducible(prod_of_num_of_additions, self.get_optimization_type()) {
+            let self_reduced = self.reduce(&mut cs.ns(|| "reduce_self"))?;
+            let self_reduced = Self::from_allocated_nonnative_field_gadget(cs, &self_reduced)?;
+            let other_reduced = other.reduce(&mut cs.ns(|| "reduce_other"))?;
+            let other_reduced = Self::from_allocated_nonnative_field_gadget(cs, &other_reduced)?;
+
+            return self_reduced.add(&mut cs.ns(|| "add_reduced"), &other_reduced);
+        }
+
         let mut new_limbs = Vec::new();
 
         for (i, (l1, l2)) in self.limbs.iter().zip(other.limbs.iter()).enumerate() {
@@ -260,7 +275,7 @@ impl<TargetField: PrimeField, BaseField: PrimeField> AllocatedNonNativeFieldMulR
 
         Ok(Self {
             limbs: new_limbs,
-            prod_of_num_of_additions: self.prod_of_num_of_additions + other.prod_of_num_of_additions,
+            prod_of_num_of_additions,
             target_phantom: PhantomData,
         })
     }
@@ -271,6 +286,15 @@ impl<TargetField: PrimeField, BaseField: PrimeField> AllocatedNonNativeFieldMulR
         cs: &mut CS,
         other: &TargetField,
     ) -> Result<Self, SynthesisError> {
+        if !Self::is_reducible(
+            self.prod_of_num_of_additions + BaseField::one(),
+            self.get_optimization_type(),
+        ) {
+            let self_reduced = self.reduce(&mut cs.ns(|| "reduce_self"))?;
+            return Self::from_allocated_nonnative_field_gadget(cs, &self_reduced)?
+                .add_constant(&mut cs.ns(|| "add_constant_reduced"), other);
+        }
+
         let mut other_limbs = AllocatedNonNativeFieldVar::<TargetField, BaseField>::get_limbs_representations(
             other,
             self.get_optimization_type(),
@@ -296,6 +320,19 @@ impl<TargetField: PrimeField, BaseField: PrimeField> AllocatedNonNativeFieldMulR
         })
     }
 
+    /// Returns whether an element with the given cumulative number of additions can be reduced,
+    /// that is, whether its limbs still leave room in `BaseField` for the surfeit of `reduce`.
+    fn is_reducible(prod_of_num_of_additions: BaseField, optimization_type: OptimizationType) -> bool {
+        let params = get_params(
+            TargetField::size_in_bits(),
+            BaseField::size_in_bits(),
+            optimization_type,
+        );
+        let surfeit = overhead!(prod_of_num_of_additions + BaseField::one()) + 1 + 1;
+
+        2 * params.bits_per_limb + surfeit + 4 <= BaseField::size_in_bits()
+    }
+
     pub(crate) fn get_optimization_type(&self) -> OptimizationType {
         // TODO (raychu86): Implement optimization goal for constraint system.
 
@@ -556,7 +556,8 @@ impl<TargetField: PrimeField, BaseField: PrimeField> AllocatedNonNativeFieldVar<
         Ok(())
     }
 
-    pub(crate) fn get_optimization_type(&self) -> OptimizationType {
+    /// Returns the optimization type that the limbs of this variable are represented in.
+    pub fn get_optimization_type(&self) -> OptimizationType {
         // TODO (raychu86): Implement optimization goal.
 
         // match self.cs().optimization_goal() {
@@ -74,6 +74,9 @@ impl<TargetField: PrimeField, BaseField: PrimeField> NonNativeFieldMulResultVar<
     pub fn add<CS: ConstraintSystem<BaseField>>(&self, cs: &mut CS, other: &Self) -> Result<Self, SynthesisError> {
         match (self, other) {
             (Self::Constant(c1), Self::Constant(c2)) => Ok(Self::Constant(*c1 + c2)),
+            (Self::Constant(c), Self::Variable(v)) | (Self::Variable(v), Self::Constant(c)) if c.is_zero() => {
+                Ok(Self::Variable(v.clone()))
+            }
             (Self::Constant(c), Self::Variable(v)) | (Self::Variable(v), Self::Constant(c)) => {
                 Ok(Self::Variable(v.add_constant(cs, &c)?))
             }
@@ -14,12 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::traits::AlgebraicSponge;
+use crate::fields::FpGadget;
+use snarkvm_algorithms::traits::{AlgebraicSponge, TranscriptCost};
 use snarkvm_fields::PrimeField;
-use snarkvm_gadgets::fields::FpGadget;
 use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
 
-/// Trait for an algebraic sponge such as Poseidon.
 pub trait AlgebraicSpongeVar<BaseField: PrimeField, PS: AlgebraicSponge<BaseField>>: Clone {
     /// Create the new sponge.
     fn new<CS: ConstraintSystem<BaseField>>(cs: CS) -> Self;
@@ -40,4 +39,8 @@ pub trait AlgebraicSpongeVar<BaseField: PrimeField, PS: AlgebraicSponge<BaseFiel
         cs: CS,
         num: usize,
     ) -> Result<Vec<FpGadget<BaseField>>, SynthesisError>;
+
+    /// Returns the cost of the transcript run on this sponge so far, including
+    /// the transcript of the plaintext sponge it was instantiated from.
+    fn transcript_cost(&self) -> TranscriptCost;
 }
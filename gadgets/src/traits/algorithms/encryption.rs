@@ -40,7 +40,7 @@ pub trait EncryptionGadget<E: EncryptionScheme, F: Field> {
         + Debug;
     type CiphertextGadget: AllocGadget<Vec<E::Text>, F> + ToBytesGadget<F> + EqGadget<F> + Clone + Sized + Debug;
     type PlaintextGadget: AllocGadget<Vec<E::Text>, F> + EqGadget<F> + Clone + Sized + Debug;
-    type RandomnessGadget: AllocGadget<E::Randomness, F> + Clone + Sized + Debug;
+    type RandomnessGadget: AllocGadget<E::Randomness, F> + ToBytesGadget<F> + Clone + Sized + Debug;
     type BlindingExponentGadget: AllocGadget<Vec<E::BlindingExponent>, F> + Clone + Sized + Debug;
 
     fn check_public_key_gadget<CS: ConstraintSystem<F>>(
@@ -38,6 +38,24 @@ pub trait SNARKVerifierGadget<N: SNARK, F: Field> {
     ) -> Result<(), SynthesisError>;
 }
 
+/// A SNARK verifier gadget whose verifying key can be prepared once, and then reused to verify
+/// several proofs without repeating the constraints that prepare it.
+pub trait PreparedSNARKVerifierGadget<N: SNARK, F: Field>: SNARKVerifierGadget<N, F> {
+    type PreparedVerificationKeyGadget: AllocGadget<N::PreparedVerifyingKey, F> + Clone;
+
+    fn prepare_verification_key<CS: ConstraintSystem<F>>(
+        cs: CS,
+        verification_key: &Self::VerificationKeyGadget,
+    ) -> Result<Self::PreparedVerificationKeyGadget, SynthesisError>;
+
+    fn check_verify_with_prepared<CS: ConstraintSystem<F>, I: Iterator<Item = Self::Input>>(
+        cs: CS,
+        prepared_verification_key: &Self::PreparedVerificationKeyGadget,
+        input: I,
+        proof: &Self::ProofGadget,
+    ) -> Result<(), SynthesisError>;
+}
+
 // TODO (raychu86): Unify with the `SNARK` trait. Currently the `SNARKGadget` is only used for `marlin`.
 
 /// This implements constraints for SNARK verifiers.
@@ -49,11 +49,14 @@ where
 }
 
 pub trait AllocGadget<V: ?Sized, F: Field>: Sized {
+    /// Allocates a constant of the circuit, which is folded into linear combinations
+    /// instead of being assigned to a new variable. Gadgets without a constant
+    /// representation fall back to `alloc`.
     fn alloc_constant<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<V>, CS: ConstraintSystem<F>>(
-        _cs: CS,
-        _f: Fn,
+        cs: CS,
+        f: Fn,
     ) -> Result<Self, SynthesisError> {
-        unimplemented!()
+        Self::alloc(cs, f)
     }
 
     fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<V>, CS: ConstraintSystem<F>>(
@@ -82,6 +85,21 @@ pub trait AllocGadget<V: ?Sized, F: Field>: Sized {
 }
 
 impl<I, F: Field, A: AllocGadget<I, F>> AllocGadget<[I], F> for Vec<A> {
+    fn alloc_constant<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<[I]>, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        f: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let f = f()?;
+        let mut vec = Vec::with_capacity(f.borrow().len());
+        for (i, value) in f.borrow().iter().enumerate() {
+            vec.push(A::alloc_constant(
+                &mut cs.ns(|| format!("alloc_constant_{}", i)),
+                || Ok(value),
+            )?);
+        }
+        Ok(vec)
+    }
+
     fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<[I]>, CS: ConstraintSystem<F>>(
         mut cs: CS,
         f: Fn,
@@ -23,14 +23,29 @@ pub trait RippleCarryAdder<F: Field, Rhs = Self>
 where
     Self: std::marker::Sized,
 {
-    fn add_bits<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Vec<Boolean>, SynthesisError>;
+    fn add_bits<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Vec<Boolean>, SynthesisError> {
+        self.add_bits_with_carry(cs, other, &Boolean::constant(false))
+    }
+
+    /// Returns the bitwise sum of `self`, `other` and the `carry` bit, with the final carry bit appended.
+    fn add_bits_with_carry<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        other: &Self,
+        carry: &Boolean,
+    ) -> Result<Vec<Boolean>, SynthesisError>;
 }
 
 // Generic impl
 impl<F: Field> RippleCarryAdder<F> for Vec<Boolean> {
-    fn add_bits<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Vec<Boolean>, SynthesisError> {
+    fn add_bits_with_carry<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        carry: &Boolean,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
         let mut result = Vec::with_capacity(self.len() + 1);
-        let mut carry = Boolean::constant(false);
+        let mut carry = *carry;
         for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
             let (sum, next) = Boolean::add(cs.ns(|| format!("rpc {}", i)), a, b, &carry)?;
 
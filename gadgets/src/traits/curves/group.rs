@@ -96,6 +96,28 @@ pub trait GroupGadget<G: Group, F: Field>:
         Ok(result)
     }
 
+    /// Computes `scalar * base` for a constant `base`, where `scalar` is given as
+    /// *little-endian* bits. Unlike `mul_bits`, the result may be the identity element.
+    ///
+    /// The default implementation adds the constant powers of `base` with
+    /// `scalar_multiplication`, starting from the identity element.
+    fn constant_base_scalar_mul_le<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        base: &G,
+        bits: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        let mut base_powers = Vec::with_capacity(bits.len());
+        let mut base_power = *base;
+        for _ in bits {
+            base_powers.push(base_power);
+            base_power.double_in_place();
+        }
+
+        let mut result = Self::zero(cs.ns(|| "zero"))?;
+        result.scalar_multiplication(cs.ns(|| "scalar multiplication"), bits.iter().zip_eq(&base_powers))?;
+        Ok(result)
+    }
+
     fn scalar_multiplication<'a, CS, I, B>(
         &mut self,
         mut cs: CS,
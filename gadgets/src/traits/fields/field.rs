@@ -183,15 +183,53 @@ pub trait FieldGadget<NativeF: Field, F: Field>:
         Ok(res)
     }
 
+    /// Accepts as input a list of bits which, when interpreted in little-endian
+    /// form, are a scalar, such as the output of `Integer::to_bits_le`.
+    ///
+    /// Costs one square, one multiplication and one conditional select per bit,
+    /// except for the least significant bit which only requires a select.
+    fn pow_by_boolean_exponent<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        exponent_bits: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        let one = Self::one(cs.ns(|| "one"))?;
+        let (first, rest) = match exponent_bits.split_first() {
+            Some(split) => split,
+            None => return Ok(one),
+        };
+
+        let mut res = Self::conditionally_select(cs.ns(|| "conditional_select_0"), first, self, &one)?;
+        let mut base_power = self.clone();
+        for (i, bit) in rest.iter().enumerate() {
+            let i = i + 1;
+            base_power.square_in_place(cs.ns(|| format!("square_in_place_{}", i)))?;
+            let tmp = res.mul(cs.ns(|| format!("mul_{}", i)), &base_power)?;
+            res = Self::conditionally_select(cs.ns(|| format!("conditional_select_{}", i)), bit, &tmp, &res)?;
+        }
+        Ok(res)
+    }
+
     /// Computes `self^S`, where S is interpreted as an little-endian
     /// u64-decomposition of an integer.
+    ///
+    /// Leading zeros are skipped and the most significant bit is absorbed by
+    /// starting from `self`, so an exponent with `n` significant bits and `k`
+    /// set bits costs `n - 1` squarings and `k - 1` multiplications,
+    /// e.g. three multiplications for the `x^5` S-box.
     fn pow_by_constant<CS: ConstraintSystem<F>, S: AsRef<[u64]>>(
         &self,
         mut cs: CS,
         exp: S,
     ) -> Result<Self, SynthesisError> {
-        let mut res = Self::one(cs.ns(|| "one"))?;
-        for (index, i) in BitIteratorBE::new_without_leading_zeros(exp).enumerate() {
+        let mut bits = BitIteratorBE::new_without_leading_zeros(exp);
+        // The most significant bit is always set, so the result starts at `self`.
+        if bits.next().is_none() {
+            return Self::one(cs.ns(|| "one"));
+        }
+
+        let mut res = self.clone();
+        for (index, i) in bits.enumerate() {
             res.square_in_place(cs.ns(|| format!("square_in_place_{}", index)))?;
             if i {
                 res.mul_in_place(cs.ns(|| format!("mul_in_place_{}", index)), self)?;
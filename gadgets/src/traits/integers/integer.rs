@@ -40,9 +40,27 @@ pub trait Integer: Debug + Clone {
         first.is_constant() && second.is_constant()
     }
 
+    /// Returns the bits of this integer, from the least significant bit to the most significant bit.
     fn to_bits_le(&self) -> Vec<Boolean>;
 
+    /// Returns the integer whose bits, from the least significant bit to the most significant bit,
+    /// are `bits`. Panics if `bits` is not `Self::SIZE` bits long.
     fn from_bits_le(bits: &[Boolean]) -> Self;
 
+    /// Returns the bits of this integer, from the most significant bit to the least significant bit.
+    fn to_bits_be(&self) -> Vec<Boolean> {
+        let mut bits = self.to_bits_le();
+        bits.reverse();
+        bits
+    }
+
+    /// Returns the integer whose bits, from the most significant bit to the least significant bit,
+    /// are `bits`. Panics if `bits` is not `Self::SIZE` bits long.
+    fn from_bits_be(bits: &[Boolean]) -> Self {
+        let mut bits_le = bits.to_vec();
+        bits_le.reverse();
+        Self::from_bits_le(&bits_le)
+    }
+
     fn get_value(&self) -> Option<String>;
 }
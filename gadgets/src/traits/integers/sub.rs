@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::bits::Boolean;
 use snarkvm_fields::Field;
 use snarkvm_r1cs::ConstraintSystem;
 
@@ -25,4 +26,12 @@ where
     type ErrorType;
 
     fn sub<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Self, Self::ErrorType>;
+
+    /// Returns the wrapped difference `self` - `other`, and a boolean that is true
+    /// if and only if the subtraction overflowed.
+    fn overflowing_sub<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        other: &Self,
+    ) -> Result<(Self, Boolean), Self::ErrorType>;
 }
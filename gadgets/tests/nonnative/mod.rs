@@ -16,5 +16,6 @@
 
 pub mod arithmetic_tests;
 pub mod from_test;
+pub mod mul_without_reduce_test;
 pub mod to_bytes_test;
 pub mod to_constraint_field_test;
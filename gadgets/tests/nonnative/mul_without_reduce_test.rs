@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_gadgets::{
+    nonnative::{params::get_params, NonNativeFieldMulResultVar, NonNativeFieldVar},
+    traits::{alloc::AllocGadget, fields::FieldGadget},
+};
+use snarkvm_r1cs::{ConstraintCounter, ConstraintSystem, TestConstraintSystem};
+use snarkvm_utilities::rand::UniformRand;
+
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+type F = snarkvm_curves::bls12_377::Fr;
+type CF = snarkvm_curves::bls12_377::Fq;
+
+/// Computes the dot product of `a` and `b` with one reduction per multiplication.
+fn dot_product<TargetField: PrimeField, BaseField: PrimeField, CS: ConstraintSystem<BaseField>>(
+    mut cs: CS,
+    a: &[NonNativeFieldVar<TargetField, BaseField>],
+    b: &[NonNativeFieldVar<TargetField, BaseField>],
+) -> NonNativeFieldVar<TargetField, BaseField> {
+    let mut result = NonNativeFieldVar::<TargetField, BaseField>::zero(cs.ns(|| "zero")).unwrap();
+    for (i, (a_i, b_i)) in a.iter().zip(b).enumerate() {
+        let product = a_i.mul(cs.ns(|| format!("mul_{}", i)), b_i).unwrap();
+        result = result.add(cs.ns(|| format!("add_{}", i)), &product).unwrap();
+    }
+    result
+}
+
+/// Computes the dot product of `a` and `b` with a single reduction at the end.
+fn dot_product_without_reduce<TargetField: PrimeField, BaseField: PrimeField, CS: ConstraintSystem<BaseField>>(
+    mut cs: CS,
+    a: &[NonNativeFieldVar<TargetField, BaseField>],
+    b: &[NonNativeFieldVar<TargetField, BaseField>],
+) -> NonNativeFieldVar<TargetField, BaseField> {
+    let mut result = NonNativeFieldMulResultVar::<TargetField, BaseField>::zero();
+    for (i, (a_i, b_i)) in a.iter().zip(b).enumerate() {
+        let product = a_i
+            .mul_without_reduce(cs.ns(|| format!("mul_without_reduce_{}", i)), b_i)
+            .unwrap();
+        result = result.add(&mut cs.ns(|| format!("add_{}", i)), &product).unwrap();
+    }
+    result.reduce(&mut cs.ns(|| "reduce")).unwrap()
+}
+
+fn alloc_vec<CS: ConstraintSystem<CF>>(mut cs: CS, values: &[F]) -> Vec<NonNativeFieldVar<F, CF>> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| NonNativeFieldVar::alloc(cs.ns(|| format!("alloc_{}", i)), || Ok(value)).unwrap())
+        .collect()
+}
+
+fn dot_product_test(length: usize) {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let a_native: Vec<F> = (0..length).map(|_| F::rand(&mut rng)).collect();
+    let b_native: Vec<F> = (0..length).map(|_| F::rand(&mut rng)).collect();
+    let expected = a_native
+        .iter()
+        .zip(&b_native)
+        .fold(F::zero(), |sum, (a_i, b_i)| sum + *a_i * b_i);
+
+    // Check that both paths compute the dot product, and are satisfied.
+    let mut cs = TestConstraintSystem::<CF>::new();
+    let a = alloc_vec(cs.ns(|| "a"), &a_native);
+    let b = alloc_vec(cs.ns(|| "b"), &b_native);
+
+    let reduced = dot_product(cs.ns(|| "dot_product"), &a, &b);
+    let unreduced = dot_product_without_reduce(cs.ns(|| "dot_product_without_reduce"), &a, &b);
+
+    assert_eq!(expected, reduced.value().unwrap());
+    assert_eq!(expected, unreduced.value().unwrap());
+    assert!(cs.is_satisfied());
+
+    // Check that the single reduction uses fewer constraints.
+    let count = |without_reduce: bool| {
+        let mut cs = ConstraintCounter::default();
+        let a = alloc_vec(cs.ns(|| "a"), &a_native);
+        let b = alloc_vec(cs.ns(|| "b"), &b_native);
+        let num_constraints = cs.num_constraints;
+
+        match without_reduce {
+            true => dot_product_without_reduce(cs.ns(|| "dot_product_without_reduce"), &a, &b),
+            false => dot_product(cs.ns(|| "dot_product"), &a, &b),
+        };
+        cs.num_constraints - num_constraints
+    };
+
+    let reduced_constraints = count(false);
+    let unreduced_constraints = count(true);
+    println!(
+        "dot product of length {}: {} constraints with a reduction per product, {} with one reduction",
+        length, reduced_constraints, unreduced_constraints
+    );
+    match length {
+        1 => assert_eq!(unreduced_constraints, reduced_constraints),
+        _ => assert!(unreduced_constraints < reduced_constraints),
+    }
+}
+
+#[test]
+fn dot_product_without_reduce_length_1() {
+    dot_product_test(1);
+}
+
+#[test]
+fn dot_product_without_reduce_length_3() {
+    dot_product_test(3);
+}
+
+#[test]
+fn dot_product_without_reduce_length_20() {
+    dot_product_test(20);
+}
+
+#[test]
+fn add_without_reduce_reduces_before_overflow() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut cs = TestConstraintSystem::<CF>::new();
+
+    let a_native = F::rand(&mut rng);
+    let b_native = F::rand(&mut rng);
+    let a = NonNativeFieldVar::<F, CF>::alloc(cs.ns(|| "alloc_a"), || Ok(a_native)).unwrap();
+    let b = NonNativeFieldVar::<F, CF>::alloc(cs.ns(|| "alloc_b"), || Ok(b_native)).unwrap();
+
+    let optimization_type = match &a {
+        NonNativeFieldVar::Var(a) => a.get_optimization_type(),
+        NonNativeFieldVar::Constant(_) => unreachable!(),
+    };
+    let params = get_params(F::size_in_bits(), CF::size_in_bits(), optimization_type);
+
+    // The largest number of additions that `reduce` supports is 2^k - 1.
+    let k = CF::size_in_bits() - 4 - 2 * params.bits_per_limb - 3;
+    let max_num_of_additions = CF::from(2u64).pow(&[k as u64]) - CF::one();
+
+    // Overstating the number of additions is sound, so use it to bring both products to the limit.
+    let inflate = |product: NonNativeFieldMulResultVar<F, CF>| match product {
+        NonNativeFieldMulResultVar::Variable(mut product) => {
+            product.prod_of_num_of_additions = max_num_of_additions;
+            product
+        }
+        NonNativeFieldMulResultVar::Constant(_) => unreachable!(),
+    };
+    let a_times_b = inflate(a.mul_without_reduce(cs.ns(|| "a_times_b"), &b).unwrap());
+    let b_times_a = inflate(b.mul_without_reduce(cs.ns(|| "b_times_a"), &a).unwrap());
+
+    // The sum exceeds the limit, so both products are reduced before they are added.
+    let sum = a_times_b.add(&mut cs.ns(|| "sum"), &b_times_a).unwrap();
+    assert!(sum.prod_of_num_of_additions != a_times_b.prod_of_num_of_additions + b_times_a.prod_of_num_of_additions);
+
+    let sum = sum.reduce(&mut cs.ns(|| "reduce")).unwrap();
+    assert_eq!(a_native * b_native + b_native * a_native, sum.value().unwrap());
+    assert!(cs.is_satisfied());
+}
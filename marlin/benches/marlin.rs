@@ -167,6 +167,39 @@ fn snark_prove(c: &mut Criterion) {
     });
 }
 
+fn snark_prove_with_context(c: &mut Criterion) {
+    let num_constraints = 100;
+    let num_variables = 100;
+    let rng = &mut thread_rng();
+
+    let x = Fr::rand(rng);
+    let y = Fr::rand(rng);
+
+    let universal_srs = MarlinInst::universal_setup(1000, 1000, 1000, rng).unwrap();
+
+    let circuit = Benchmark::<Fr> {
+        a: Some(x),
+        b: Some(y),
+        num_constraints,
+        num_variables,
+    };
+
+    let (circuit_proving_key, _) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+    let mut group = c.benchmark_group("snark_prove_with_context");
+
+    group.bench_function("prove", |b| {
+        b.iter(|| MarlinInst::prove(&circuit_proving_key, &circuit, rng).unwrap())
+    });
+
+    let proving_context = MarlinInst::proving_context(&circuit_proving_key).unwrap();
+    group.bench_function("prove_with_context", |b| {
+        b.iter(|| MarlinInst::prove_with_context(&proving_context, &circuit, rng).unwrap())
+    });
+
+    group.finish();
+}
+
 fn snark_verify(c: &mut Criterion) {
     let num_constraints = 1000;
     let num_variables = 25;
@@ -287,7 +320,8 @@ fn snark_verify_gadget(c: &mut Criterion) {
 criterion_group! {
     name = marlin_snark;
     config = Criterion::default().sample_size(10);
-    targets = snark_universal_setup, snark_circuit_setup, snark_prove, snark_verify, snark_verify_gadget
+    targets = snark_universal_setup, snark_circuit_setup, snark_prove, snark_prove_with_context, snark_verify,
+        snark_verify_gadget
 }
 
 criterion_main!(marlin_snark);
@@ -143,7 +143,6 @@ impl<F: PrimeField> AHPForR1CS<F> {
     ) -> Result<Vec<LinearCombination<F>>, AHPError> {
         let domain_h = state.domain_h;
         let domain_k = state.domain_k;
-        let k_size = domain_k.size_as_field_element;
 
         let public_input = ProverConstraintSystem::format_public_input(public_input);
         if !Self::formatted_public_input_is_admissible(&public_input) {
@@ -152,13 +151,19 @@ impl<F: PrimeField> AHPForR1CS<F> {
         let x_domain = EvaluationDomain::new(public_input.len()).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
 
         let first_round_msg = state.first_round_message.unwrap();
-        let alpha = first_round_msg.alpha;
-        let eta_a = first_round_msg.eta_a;
-        let eta_b = first_round_msg.eta_b;
-        let eta_c = first_round_msg.eta_c;
-
-        let beta = state.second_round_message.unwrap().beta;
-        let gamma = state.gamma.unwrap();
+        let challenges = verifier::VerifierChallenges {
+            alpha: first_round_msg.alpha,
+            eta_a: first_round_msg.eta_a,
+            eta_b: first_round_msg.eta_b,
+            eta_c: first_round_msg.eta_c,
+            beta: state.second_round_message.unwrap().beta,
+            gamma: state.gamma.unwrap(),
+        };
+        let public = verifier::PublicEvaluations::new(&domain_h, &domain_k, &x_domain, &public_input, &challenges);
+
+        let alpha = challenges.alpha;
+        let beta = challenges.beta;
+        let gamma = challenges.gamma;
 
         let mut linear_combinations = Vec::with_capacity(9);
 
@@ -167,38 +172,20 @@ impl<F: PrimeField> AHPForR1CS<F> {
         let g_1 = LinearCombination::new("g_1", vec![(F::one(), "g_1")]);
         let t = LinearCombination::new("t", vec![(F::one(), "t")]);
 
-        let r_alpha_at_beta = domain_h.eval_unnormalized_bivariate_lagrange_poly(alpha, beta);
-        let v_H_at_alpha = domain_h.evaluate_vanishing_polynomial(alpha);
-        let v_H_at_beta = domain_h.evaluate_vanishing_polynomial(beta);
-        let v_X_at_beta = x_domain.evaluate_vanishing_polynomial(beta);
-
         let z_b_at_beta = evals.get_lc_eval(&z_b, beta)?;
         let t_at_beta = evals.get_lc_eval(&t, beta)?;
         let g_1_at_beta = evals.get_lc_eval(&g_1, beta)?;
 
-        let x_at_beta = x_domain
-            .evaluate_all_lagrange_coefficients(beta)
-            .into_iter()
-            .zip(public_input)
-            .map(|(l, x)| l * x)
-            .fold(F::zero(), |x, y| x + y);
-
-        #[rustfmt::skip]
-        let outer_sumcheck = LinearCombination::new(
-            "outer_sumcheck",
-            vec![
-                (F::one(), "mask_poly".into()),
-
-                (r_alpha_at_beta * (eta_a + (eta_c * z_b_at_beta)), "z_a".into()),
-                (r_alpha_at_beta * eta_b * z_b_at_beta, LCTerm::One),
-
-                (-t_at_beta * v_X_at_beta, "w".into()),
-                (-t_at_beta * x_at_beta, LCTerm::One),
-
-                (-v_H_at_beta, "h_1".into()),
-                (-beta * g_1_at_beta, LCTerm::One),
-            ],
-        );
+        let outer = verifier::outer_sumcheck_coefficients(&challenges, &public, z_b_at_beta, t_at_beta, g_1_at_beta);
+        let outer_sumcheck = LinearCombination::new("outer_sumcheck", vec![
+            (outer.mask_poly, "mask_poly".into()),
+            (outer.z_a, "z_a".into()),
+            (outer.z_b_constant, LCTerm::One),
+            (outer.w, "w".into()),
+            (outer.x_constant, LCTerm::One),
+            (outer.h_1, "h_1".into()),
+            (outer.g_1_constant, LCTerm::One),
+        ]);
         debug_assert!(evals.get_lc_eval(&outer_sumcheck, beta)?.is_zero());
 
         linear_combinations.push(z_b);
@@ -236,23 +223,20 @@ impl<F: PrimeField> AHPForR1CS<F> {
         let c_denom_at_gamma = evals.get_lc_eval(&c_denom, gamma)?;
         let g_2_at_gamma = evals.get_lc_eval(&g_2, gamma)?;
 
-        let v_K_at_gamma = domain_k.evaluate_vanishing_polynomial(gamma);
-
-        let mut a = LinearCombination::new("a_poly", vec![
-            (eta_a * b_denom_at_gamma * c_denom_at_gamma, "a_val"),
-            (eta_b * a_denom_at_gamma * c_denom_at_gamma, "b_val"),
-            (eta_c * b_denom_at_gamma * a_denom_at_gamma, "c_val"),
+        let inner = verifier::inner_sumcheck_coefficients(
+            &challenges,
+            &public,
+            [a_denom_at_gamma, b_denom_at_gamma, c_denom_at_gamma],
+            g_2_at_gamma,
+            t_at_beta,
+        );
+        let inner_sumcheck = LinearCombination::new("inner_sumcheck", vec![
+            (inner.a_val, "a_val".into()),
+            (inner.b_val, "b_val".into()),
+            (inner.c_val, "c_val".into()),
+            (inner.constant, LCTerm::One),
+            (inner.h_2, "h_2".into()),
         ]);
-
-        a *= v_H_at_alpha * v_H_at_beta;
-        let b_at_gamma = a_denom_at_gamma * b_denom_at_gamma * c_denom_at_gamma;
-        let b_expr_at_gamma = b_at_gamma * (gamma * g_2_at_gamma + (t_at_beta / &k_size));
-
-        a -= &LinearCombination::new("b_expr", vec![(b_expr_at_gamma, LCTerm::One)]);
-        a -= &LinearCombination::new("h_2", vec![(v_K_at_gamma, "h_2")]);
-
-        a.label = "inner_sumcheck".into();
-        let inner_sumcheck = a;
         debug_assert!(evals.get_lc_eval(&inner_sumcheck, gamma)?.is_zero());
 
         linear_combinations.push(g_2);
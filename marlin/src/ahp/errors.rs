@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use std::{error::Error, fmt};
+
 /// Describes the failure modes of the AHP scheme.
 #[derive(Debug)]
 pub enum AHPError {
@@ -42,3 +44,26 @@ impl From<snarkvm_r1cs::errors::SynthesisError> for AHPError {
         AHPError::ConstraintSystemError(other)
     }
 }
+
+impl fmt::Display for AHPError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AHPError::ConstraintSystemError(error) => write!(f, "{}", error),
+            AHPError::FiatShamirError(error) => write!(f, "{}", error),
+            AHPError::InstanceDoesNotMatchIndex => write!(f, "instance does not match index"),
+            AHPError::InvalidPublicInputLength => write!(f, "invalid public input length"),
+            AHPError::MissingEval(eval) => write!(f, "missing eval: {}", eval),
+            AHPError::NonSquareMatrix => write!(f, "non-square matrix"),
+        }
+    }
+}
+
+impl Error for AHPError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AHPError::ConstraintSystemError(error) => Some(error),
+            AHPError::FiatShamirError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
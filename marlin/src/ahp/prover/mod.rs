@@ -22,6 +22,9 @@ pub(crate) use constraint_system::*;
 mod message;
 pub(crate) use message::*;
 
+mod precomputation;
+pub(crate) use precomputation::*;
+
 mod prover;
 
 mod state;
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    ahp::{indexer::CircuitInfo, AHPError},
+    Vec,
+};
+use snarkvm_algorithms::{cfg_iter, cfg_iter_mut, fft::EvaluationDomain};
+use snarkvm_fields::{batch_inversion, PrimeField};
+use snarkvm_polycommit::Polynomial;
+use snarkvm_r1cs::errors::SynthesisError;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The parts of the AHP prover's work that depend only on the index, and not on the witness,
+/// so that they can be computed once and reused across proofs of the same circuit.
+#[derive(Clone, Debug)]
+pub struct ProverPrecomputation<F: PrimeField> {
+    /// domain H, sized for constraints
+    pub(super) domain_h: EvaluationDomain<F>,
+
+    /// domain K, sized for matrix nonzero elements
+    pub(super) domain_k: EvaluationDomain<F>,
+
+    /// domain B, on which the third round polynomials are interpolated
+    pub(super) domain_b: EvaluationDomain<F>,
+
+    /// the vanishing polynomial of domain H
+    pub(super) v_H: Polynomial<F>,
+
+    /// the elements of domain H, in order
+    pub(super) elements_h: Vec<F>,
+}
+
+impl<F: PrimeField> ProverPrecomputation<F> {
+    /// Computes the domains of the given index, and the elements of domain H.
+    pub fn new(index_info: &CircuitInfo<F>) -> Result<Self, AHPError> {
+        let domain_h =
+            EvaluationDomain::new(index_info.num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+        let domain_k =
+            EvaluationDomain::new(index_info.num_non_zero).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+        let domain_b =
+            EvaluationDomain::new(3 * domain_k.size() - 3).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+        Ok(Self {
+            domain_h,
+            domain_k,
+            domain_b,
+            v_H: domain_h.vanishing_polynomial().into(),
+            elements_h: domain_h.elements().collect(),
+        })
+    }
+
+    /// Returns domain H, sized for constraints.
+    pub fn domain_h(&self) -> EvaluationDomain<F> {
+        self.domain_h
+    }
+
+    /// Returns domain K, sized for matrix nonzero elements.
+    pub fn domain_k(&self) -> EvaluationDomain<F> {
+        self.domain_k
+    }
+
+    /// Evaluates the unnormalized bivariate Lagrange polynomial of domain H at `(x, y)` for every
+    /// `y` in domain H, as `batch_eval_unnormalized_bivariate_lagrange_poly_with_diff_inputs` does,
+    /// but using the cached elements of domain H.
+    pub(super) fn batch_eval_unnormalized_bivariate_lagrange_poly_on_h(&self, x: F) -> Vec<F> {
+        let vanish_x = self.domain_h.evaluate_vanishing_polynomial(x);
+        let mut inverses: Vec<F> = cfg_iter!(self.elements_h).map(|y| x - y).collect();
+        batch_inversion(&mut inverses);
+
+        cfg_iter_mut!(inverses).for_each(|denominator| *denominator *= &vanish_x);
+        inverses
+    }
+}
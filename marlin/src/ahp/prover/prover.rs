@@ -21,9 +21,8 @@ use crate::{
         verifier::{VerifierFirstMessage, VerifierSecondMessage},
         AHPError,
         AHPForR1CS,
-        UnnormalizedBivariateLagrangePoly,
     },
-    prover::{state::ProverState, ProverMessage},
+    prover::{state::ProverState, ProverMessage, ProverPrecomputation},
     ToString,
     Vec,
 };
@@ -40,6 +39,7 @@ use snarkvm_polycommit::{LabeledPolynomial, Polynomial};
 use snarkvm_r1cs::ConstraintSynthesizer;
 
 use rand_core::RngCore;
+use std::borrow::Cow;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -100,6 +100,34 @@ impl<F: PrimeField> AHPForR1CS<F> {
     pub fn prover_init<'a, C: ConstraintSynthesizer<F>>(
         index: &'a Circuit<F>,
         circuit: &C,
+    ) -> Result<ProverState<'a, F>, AHPError> {
+        let precomputation = Self::prover_precompute(index)?;
+        Self::prover_init_inner(index, Cow::Owned(precomputation), circuit)
+    }
+
+    /// Compute the parts of the AHP prover's work that depend only on the index.
+    pub fn prover_precompute(index: &Circuit<F>) -> Result<ProverPrecomputation<F>, AHPError> {
+        let precompute_time = start_timer!(|| "AHP::Prover::Precompute");
+        let precomputation = ProverPrecomputation::new(&index.index_info)?;
+        end_timer!(precompute_time);
+
+        Ok(precomputation)
+    }
+
+    /// Initialize the AHP prover, reusing the precomputation of the given index,
+    /// which must be the output of `prover_precompute` on the same index.
+    pub(crate) fn prover_init_with_precomputation<'a, C: ConstraintSynthesizer<F>>(
+        index: &'a Circuit<F>,
+        precomputation: &'a ProverPrecomputation<F>,
+        circuit: &C,
+    ) -> Result<ProverState<'a, F>, AHPError> {
+        Self::prover_init_inner(index, Cow::Borrowed(precomputation), circuit)
+    }
+
+    fn prover_init_inner<'a, C: ConstraintSynthesizer<F>>(
+        index: &'a Circuit<F>,
+        precomputation: Cow<'a, ProverPrecomputation<F>>,
+        circuit: &C,
     ) -> Result<ProverState<'a, F>, AHPError> {
         let init_time = start_timer!(|| "AHP::Prover::Init");
 
@@ -175,10 +203,6 @@ impl<F: PrimeField> AHPForR1CS<F> {
 
         let zk_bound = 1; // One query is sufficient for our desired soundness
 
-        let domain_h = EvaluationDomain::new(num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-
-        let domain_k = EvaluationDomain::new(num_non_zero).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-
         let domain_x = EvaluationDomain::new(num_public_variables).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
 
         end_timer!(init_time);
@@ -194,9 +218,8 @@ impl<F: PrimeField> AHPForR1CS<F> {
             index,
             verifier_first_message: None,
             mask_poly: None,
-            domain_h,
-            domain_k,
             domain_x,
+            precomputation,
         })
     }
 
@@ -208,10 +231,10 @@ impl<F: PrimeField> AHPForR1CS<F> {
         hiding: bool,
     ) -> Result<(ProverMessage<F>, ProverFirstOracles<F>, ProverState<'a, F>), AHPError> {
         let round_time = start_timer!(|| "AHP::Prover::FirstRound");
-        let domain_h = state.domain_h;
+        let domain_h = state.precomputation.domain_h;
         let zk_bound = state.zk_bound;
 
-        let v_H = domain_h.vanishing_polynomial().into();
+        let v_H = &state.precomputation.v_H;
 
         let x_time = start_timer!(|| "Computing x polynomial and evals");
         let domain_x = state.domain_x;
@@ -240,7 +263,7 @@ impl<F: PrimeField> AHPForR1CS<F> {
             .collect();
 
         let w_poly = &EvaluationsOnDomain::from_vec_and_domain(w_poly_evals, domain_h).interpolate()
-            + &(&Polynomial::from_coefficients_slice(&[F::rand(rng)]) * &v_H);
+            + &(&Polynomial::from_coefficients_slice(&[F::rand(rng)]) * v_H);
         let (w_poly, remainder) = w_poly.divide_by_vanishing_poly(domain_x).unwrap();
         assert!(remainder.is_zero());
         end_timer!(w_poly_time);
@@ -248,13 +271,13 @@ impl<F: PrimeField> AHPForR1CS<F> {
         let z_a_poly_time = start_timer!(|| "Computing z_A polynomial");
         let z_a = state.z_a.clone().unwrap();
         let z_a_poly = &EvaluationsOnDomain::from_vec_and_domain(z_a, domain_h).interpolate()
-            + &(&Polynomial::from_coefficients_slice(&[F::rand(rng)]) * &v_H);
+            + &(&Polynomial::from_coefficients_slice(&[F::rand(rng)]) * v_H);
         end_timer!(z_a_poly_time);
 
         let z_b_poly_time = start_timer!(|| "Computing z_B polynomial");
         let z_b = state.z_b.clone().unwrap();
         let z_b_poly = &EvaluationsOnDomain::from_vec_and_domain(z_b, domain_h).interpolate()
-            + &(&Polynomial::from_coefficients_slice(&[F::rand(rng)]) * &v_H);
+            + &(&Polynomial::from_coefficients_slice(&[F::rand(rng)]) * v_H);
         end_timer!(z_b_poly_time);
 
         let mask_poly_time = start_timer!(|| "Computing mask polynomial");
@@ -339,7 +362,7 @@ impl<F: PrimeField> AHPForR1CS<F> {
     ) -> (ProverMessage<F>, ProverSecondOracles<F>, ProverState<'a, F>) {
         let round_time = start_timer!(|| "AHP::Prover::SecondRound");
 
-        let domain_h = state.domain_h;
+        let domain_h = state.precomputation.domain_h;
         let zk_bound = state.zk_bound;
 
         let mask_poly = state
@@ -372,7 +395,9 @@ impl<F: PrimeField> AHPForR1CS<F> {
         end_timer!(summed_z_m_poly_time);
 
         let r_alpha_x_evals_time = start_timer!(|| "Compute r_alpha_x evals");
-        let r_alpha_x_evals = domain_h.batch_eval_unnormalized_bivariate_lagrange_poly_with_diff_inputs(alpha);
+        let r_alpha_x_evals = state
+            .precomputation
+            .batch_eval_unnormalized_bivariate_lagrange_poly_on_h(alpha);
         end_timer!(r_alpha_x_evals_time);
 
         let r_alpha_poly_time = start_timer!(|| "Compute r_alpha_x poly");
@@ -384,16 +409,14 @@ impl<F: PrimeField> AHPForR1CS<F> {
             vec![&state.index.a, &state.index.b, &state.index.c].into_iter(),
             &[eta_a, eta_b, eta_c],
             state.domain_x,
-            state.domain_h,
+            domain_h,
             r_alpha_x_evals,
         );
         end_timer!(t_poly_time);
 
         let z_poly_time = start_timer!(|| "Compute z poly");
 
-        let domain_x = EvaluationDomain::new(state.padded_public_variables.len())
-            .ok_or(SynthesisError::PolynomialDegreeTooLarge)
-            .unwrap();
+        let domain_x = state.domain_x;
         let x_poly =
             EvaluationsOnDomain::from_vec_and_domain(state.padded_public_variables.clone(), domain_x).interpolate();
         let w_poly = state.w_poly.as_ref().unwrap();
@@ -488,11 +511,13 @@ impl<F: PrimeField> AHPForR1CS<F> {
         let ProverState {
             index,
             verifier_first_message,
-            domain_h,
-            domain_k,
+            precomputation,
             ..
         } = prover_state;
 
+        let domain_h = precomputation.domain_h;
+        let domain_k = precomputation.domain_k;
+
         let VerifierFirstMessage {
             eta_a,
             eta_b,
@@ -538,8 +563,7 @@ impl<F: PrimeField> AHPForR1CS<F> {
 
         let g_2 = Polynomial::from_coefficients_slice(&f.coeffs[1..]);
 
-        let domain_b =
-            EvaluationDomain::<F>::new(3 * domain_k.size() - 3).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let domain_b = precomputation.domain_b;
 
         let denom_eval_time = start_timer!(|| "Computing denominator evals on B");
         let a_denom: Vec<_> = cfg_iter!(a_star.evals_on_B.row.evaluations)
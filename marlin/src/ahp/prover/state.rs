@@ -15,13 +15,19 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    ahp::{indexer::Circuit, prover::ProverConstraintSystem, verifier::VerifierFirstMessage},
+    ahp::{
+        indexer::Circuit,
+        prover::{ProverConstraintSystem, ProverPrecomputation},
+        verifier::VerifierFirstMessage,
+    },
     Vec,
 };
 use snarkvm_algorithms::fft::EvaluationDomain;
 use snarkvm_fields::PrimeField;
 use snarkvm_polycommit::LabeledPolynomial;
 
+use std::borrow::Cow;
+
 /// State for the AHP prover.
 pub struct ProverState<'a, F: PrimeField> {
     pub(super) padded_public_variables: Vec<F>,
@@ -47,11 +53,8 @@ pub struct ProverState<'a, F: PrimeField> {
     /// domain X, sized for the public input
     pub(super) domain_x: EvaluationDomain<F>,
 
-    /// domain H, sized for constraints
-    pub(super) domain_h: EvaluationDomain<F>,
-
-    /// domain K, sized for matrix nonzero elements
-    pub(super) domain_k: EvaluationDomain<F>,
+    /// the domains H, K, and B, and the other precomputations of the index
+    pub(super) precomputation: Cow<'a, ProverPrecomputation<F>>,
 }
 
 impl<'a, F: PrimeField> ProverState<'a, F> {
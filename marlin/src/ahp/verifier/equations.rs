@@ -0,0 +1,607 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The algebraic checks of the AHP verifier, as pure functions of field elements.
+//!
+//! The symbols follow [CHMMVW19](https://eprint.iacr.org/2019/1047): `H` is the constraint domain,
+//! `K` is the domain of the non-zero matrix entries, and `X` is the domain of the public input.
+//! `v_S` denotes the vanishing polynomial of a domain `S`, and `r(X, Y)` is the unnormalized
+//! bivariate Lagrange polynomial of `H`.
+//!
+//! The outer (first) sumcheck checks, at `β`:
+//!
+//! ```text
+//!   s(β) + r(α, β) · (η_A · z_A(β) + η_B · z_B(β) + η_C · z_A(β) · z_B(β)) − t(β) · z(β)
+//! = h_1(β) · v_H(β) + β · g_1(β)
+//! ```
+//!
+//! where `z(β) = x(β) + v_X(β) · w(β)` and `z_C = z_A · z_B` is a virtual oracle.
+//!
+//! The inner (second) sumcheck checks, at `γ`:
+//!
+//! ```text
+//!   h_2(γ) · v_K(γ) = a(γ) − b(γ) · (γ · g_2(γ) + t(β) / |K|)
+//! ```
+//!
+//! where, for `M, N ∈ {A, B, C}` and `denom_M(γ) = (β − row_M(γ)) · (α − col_M(γ))`,
+//!
+//! ```text
+//!   a(γ) = v_H(α) · v_H(β) · Σ_M η_M · val_M(γ) · Π_{N ≠ M} denom_N(γ)
+//!   b(γ) = Π_M denom_M(γ)
+//! ```
+//!
+//! The polynomial commitment only opens some of these polynomials individually, so the verifier
+//! checks each equation as a linear combination that must evaluate to zero. The coefficients of these
+//! linear combinations are computed by `outer_sumcheck_coefficients` and `inner_sumcheck_coefficients`.
+
+use crate::ahp::{
+    verifier::{VerifierFirstMessage, VerifierSecondMessage},
+    UnnormalizedBivariateLagrangePoly,
+};
+use snarkvm_algorithms::fft::EvaluationDomain;
+use snarkvm_fields::PrimeField;
+
+/// Returns the first verifier message `(α, η_A, η_B, η_C)` from the elements squeezed in the first round.
+///
+/// # Panics
+///
+/// Panics if `α` is in `H`.
+pub fn first_round_challenges<F: PrimeField>(
+    domain_h: &EvaluationDomain<F>,
+    [alpha, eta_a, eta_b, eta_c]: [F; 4],
+) -> VerifierFirstMessage<F> {
+    assert!(!domain_h.evaluate_vanishing_polynomial(alpha).is_zero());
+
+    VerifierFirstMessage {
+        alpha,
+        eta_a,
+        eta_b,
+        eta_c,
+    }
+}
+
+/// Returns the second verifier message `β` from the element squeezed in the second round.
+///
+/// # Panics
+///
+/// Panics if `β` is in `H`.
+pub fn second_round_challenges<F: PrimeField>(
+    domain_h: &EvaluationDomain<F>,
+    [beta]: [F; 1],
+) -> VerifierSecondMessage<F> {
+    assert!(!domain_h.evaluate_vanishing_polynomial(beta).is_zero());
+
+    VerifierSecondMessage { beta }
+}
+
+/// Returns the third verifier challenge `γ` from the element squeezed in the third round.
+pub fn third_round_challenges<F: PrimeField>([gamma]: [F; 1]) -> F {
+    gamma
+}
+
+/// The verifier challenges of all rounds.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VerifierChallenges<F> {
+    /// `α`, the query for the random polynomial.
+    pub alpha: F,
+    /// `η_A`, the randomizer for the lincheck for `A`.
+    pub eta_a: F,
+    /// `η_B`, the randomizer for the lincheck for `B`.
+    pub eta_b: F,
+    /// `η_C`, the randomizer for the lincheck for `C`.
+    pub eta_c: F,
+    /// `β`, the query for the outer sumcheck.
+    pub beta: F,
+    /// `γ`, the query for the inner sumcheck.
+    pub gamma: F,
+}
+
+/// The evaluations that the verifier computes itself, from the domains and the public input.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PublicEvaluations<F> {
+    /// `r(α, β)`.
+    pub r_alpha_at_beta: F,
+    /// `v_H(α)`.
+    pub v_H_at_alpha: F,
+    /// `v_H(β)`.
+    pub v_H_at_beta: F,
+    /// `v_X(β)`.
+    pub v_X_at_beta: F,
+    /// `x(β)`, the low-degree extension of the formatted public input.
+    pub x_at_beta: F,
+    /// `v_K(γ)`.
+    pub v_K_at_gamma: F,
+    /// `|K|`.
+    pub k_size: F,
+}
+
+impl<F: PrimeField> PublicEvaluations<F> {
+    /// Computes the public evaluations, where `x_domain` is the domain of the formatted public input.
+    pub fn new(
+        domain_h: &EvaluationDomain<F>,
+        domain_k: &EvaluationDomain<F>,
+        x_domain: &EvaluationDomain<F>,
+        formatted_public_input: &[F],
+        challenges: &VerifierChallenges<F>,
+    ) -> Self {
+        let VerifierChallenges { alpha, beta, gamma, .. } = *challenges;
+
+        let x_at_beta = x_domain
+            .evaluate_all_lagrange_coefficients(beta)
+            .into_iter()
+            .zip(formatted_public_input)
+            .map(|(l, x)| l * x)
+            .fold(F::zero(), |x, y| x + y);
+
+        Self {
+            r_alpha_at_beta: domain_h.eval_unnormalized_bivariate_lagrange_poly(alpha, beta),
+            v_H_at_alpha: domain_h.evaluate_vanishing_polynomial(alpha),
+            v_H_at_beta: domain_h.evaluate_vanishing_polynomial(beta),
+            v_X_at_beta: x_domain.evaluate_vanishing_polynomial(beta),
+            x_at_beta,
+            v_K_at_gamma: domain_k.evaluate_vanishing_polynomial(gamma),
+            k_size: domain_k.size_as_field_element,
+        }
+    }
+}
+
+/// The evaluations at `β` in the outer sumcheck equation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OuterSumcheckEvaluations<F> {
+    /// `s(β)`, the evaluation of the masking polynomial.
+    pub mask_poly: F,
+    /// `z_A(β)`.
+    pub z_a: F,
+    /// `z_B(β)`.
+    pub z_b: F,
+    /// `w(β)`, the evaluation of the shifted witness polynomial.
+    pub w: F,
+    /// `t(β)`.
+    pub t: F,
+    /// `g_1(β)`.
+    pub g_1: F,
+    /// `h_1(β)`.
+    pub h_1: F,
+}
+
+/// The evaluations in the inner sumcheck equation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InnerSumcheckEvaluations<F> {
+    /// `val_A(γ)`.
+    pub a_val: F,
+    /// `val_B(γ)`.
+    pub b_val: F,
+    /// `val_C(γ)`.
+    pub c_val: F,
+    /// `denom_A(γ)`.
+    pub a_denom: F,
+    /// `denom_B(γ)`.
+    pub b_denom: F,
+    /// `denom_C(γ)`.
+    pub c_denom: F,
+    /// `g_2(γ)`.
+    pub g_2: F,
+    /// `h_2(γ)`.
+    pub h_2: F,
+    /// `t(β)`, which is shared with the outer sumcheck.
+    pub t: F,
+}
+
+/// Returns `true` if the evaluations satisfy the outer sumcheck equation.
+pub fn verify_outer_sumcheck_equation<F: PrimeField>(
+    challenges: &VerifierChallenges<F>,
+    public: &PublicEvaluations<F>,
+    evaluations: &OuterSumcheckEvaluations<F>,
+) -> bool {
+    let VerifierChallenges {
+        eta_a,
+        eta_b,
+        eta_c,
+        beta,
+        ..
+    } = *challenges;
+    let OuterSumcheckEvaluations {
+        mask_poly: s,
+        z_a,
+        z_b,
+        w,
+        t,
+        g_1,
+        h_1,
+    } = *evaluations;
+
+    let z = public.x_at_beta + public.v_X_at_beta * w;
+    let lincheck = eta_a * z_a + eta_b * z_b + eta_c * z_a * z_b;
+
+    s + public.r_alpha_at_beta * lincheck - t * z == h_1 * public.v_H_at_beta + beta * g_1
+}
+
+/// Returns `true` if the evaluations satisfy the inner sumcheck equation.
+pub fn verify_inner_sumcheck_equation<F: PrimeField>(
+    challenges: &VerifierChallenges<F>,
+    public: &PublicEvaluations<F>,
+    evaluations: &InnerSumcheckEvaluations<F>,
+) -> bool {
+    let VerifierChallenges {
+        eta_a,
+        eta_b,
+        eta_c,
+        gamma,
+        ..
+    } = *challenges;
+    let InnerSumcheckEvaluations {
+        a_val,
+        b_val,
+        c_val,
+        a_denom,
+        b_denom,
+        c_denom,
+        g_2,
+        h_2,
+        t,
+    } = *evaluations;
+
+    let a = public.v_H_at_alpha
+        * public.v_H_at_beta
+        * (eta_a * a_val * b_denom * c_denom + eta_b * b_val * a_denom * c_denom + eta_c * c_val * a_denom * b_denom);
+    let b = a_denom * b_denom * c_denom;
+
+    h_2 * public.v_K_at_gamma == a - b * (gamma * g_2 + t / public.k_size)
+}
+
+/// The outer sumcheck equation as a linear combination, in the polynomials that are not opened
+/// individually, that evaluates to zero at `β`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OuterSumcheckCoefficients<F> {
+    /// The coefficient of `s`.
+    pub mask_poly: F,
+    /// The coefficient of `z_A`.
+    pub z_a: F,
+    /// The constant term `r(α, β) · η_B · z_B(β)`.
+    pub z_b_constant: F,
+    /// The coefficient of `w`.
+    pub w: F,
+    /// The constant term `−t(β) · x(β)`.
+    pub x_constant: F,
+    /// The coefficient of `h_1`.
+    pub h_1: F,
+    /// The constant term `−β · g_1(β)`.
+    pub g_1_constant: F,
+}
+
+/// Returns the outer sumcheck linear combination, given the opened evaluations `z_B(β)`, `t(β)` and `g_1(β)`.
+pub fn outer_sumcheck_coefficients<F: PrimeField>(
+    challenges: &VerifierChallenges<F>,
+    public: &PublicEvaluations<F>,
+    z_b: F,
+    t: F,
+    g_1: F,
+) -> OuterSumcheckCoefficients<F> {
+    let VerifierChallenges {
+        eta_a,
+        eta_b,
+        eta_c,
+        beta,
+        ..
+    } = *challenges;
+
+    OuterSumcheckCoefficients {
+        mask_poly: F::one(),
+        z_a: public.r_alpha_at_beta * (eta_a + eta_c * z_b),
+        z_b_constant: public.r_alpha_at_beta * eta_b * z_b,
+        w: -t * public.v_X_at_beta,
+        x_constant: -t * public.x_at_beta,
+        h_1: -public.v_H_at_beta,
+        g_1_constant: -beta * g_1,
+    }
+}
+
+/// The inner sumcheck equation as a linear combination, in the polynomials that are not opened
+/// individually, that evaluates to zero at `γ`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InnerSumcheckCoefficients<F> {
+    /// The coefficient of `val_A`.
+    pub a_val: F,
+    /// The coefficient of `val_B`.
+    pub b_val: F,
+    /// The coefficient of `val_C`.
+    pub c_val: F,
+    /// The constant term `−b(γ) · (γ · g_2(γ) + t(β) / |K|)`.
+    pub constant: F,
+    /// The coefficient of `h_2`.
+    pub h_2: F,
+}
+
+/// Returns the inner sumcheck linear combination, given the opened evaluations `denom_A(γ)`,
+/// `denom_B(γ)`, `denom_C(γ)` and `g_2(γ)`, and `t(β)`.
+pub fn inner_sumcheck_coefficients<F: PrimeField>(
+    challenges: &VerifierChallenges<F>,
+    public: &PublicEvaluations<F>,
+    [a_denom, b_denom, c_denom]: [F; 3],
+    g_2: F,
+    t: F,
+) -> InnerSumcheckCoefficients<F> {
+    let VerifierChallenges {
+        eta_a,
+        eta_b,
+        eta_c,
+        gamma,
+        ..
+    } = *challenges;
+    let v_H_at_alpha_beta = public.v_H_at_alpha * public.v_H_at_beta;
+    let b = a_denom * b_denom * c_denom;
+
+    InnerSumcheckCoefficients {
+        a_val: v_H_at_alpha_beta * eta_a * b_denom * c_denom,
+        b_val: v_H_at_alpha_beta * eta_b * a_denom * c_denom,
+        c_val: v_H_at_alpha_beta * eta_c * b_denom * a_denom,
+        constant: -b * (gamma * g_2 + t / public.k_size),
+        h_2: -public.v_K_at_gamma,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::fr::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::rand::{test_rng, UniformRand};
+
+    use rand_core::RngCore;
+
+    const ITERATIONS: usize = 20;
+
+    fn fr(value: u64) -> Fr {
+        Fr::from(value)
+    }
+
+    /// `α = 2, η = (1, 2, 3), β = 3, γ = 4`.
+    fn small_challenges() -> VerifierChallenges<Fr> {
+        VerifierChallenges {
+            alpha: fr(2),
+            eta_a: fr(1),
+            eta_b: fr(2),
+            eta_c: fr(3),
+            beta: fr(3),
+            gamma: fr(4),
+        }
+    }
+
+    /// `|H| = |K| = 2` and `|X| = 1`, so that `v_H(X) = v_K(X) = X^2 - 1` and `v_X(X) = X - 1`.
+    fn small_public_evaluations() -> PublicEvaluations<Fr> {
+        let domain_h = EvaluationDomain::new(2).unwrap();
+        let domain_k = EvaluationDomain::new(2).unwrap();
+        let x_domain = EvaluationDomain::new(1).unwrap();
+        PublicEvaluations::new(&domain_h, &domain_k, &x_domain, &[Fr::one()], &small_challenges())
+    }
+
+    fn random_challenges<R: RngCore>(rng: &mut R) -> VerifierChallenges<Fr> {
+        VerifierChallenges {
+            alpha: Fr::rand(rng),
+            eta_a: Fr::rand(rng),
+            eta_b: Fr::rand(rng),
+            eta_c: Fr::rand(rng),
+            beta: Fr::rand(rng),
+            gamma: Fr::rand(rng),
+        }
+    }
+
+    fn random_public_evaluations<R: RngCore>(rng: &mut R) -> PublicEvaluations<Fr> {
+        PublicEvaluations {
+            r_alpha_at_beta: Fr::rand(rng),
+            v_H_at_alpha: Fr::rand(rng),
+            v_H_at_beta: Fr::rand(rng),
+            v_X_at_beta: Fr::rand(rng),
+            x_at_beta: Fr::rand(rng),
+            v_K_at_gamma: Fr::rand(rng),
+            k_size: fr(1 << (rng.next_u32() % 16)),
+        }
+    }
+
+    fn outer_sumcheck_evaluation(
+        challenges: &VerifierChallenges<Fr>,
+        public: &PublicEvaluations<Fr>,
+        evaluations: &OuterSumcheckEvaluations<Fr>,
+    ) -> Fr {
+        let c = outer_sumcheck_coefficients(challenges, public, evaluations.z_b, evaluations.t, evaluations.g_1);
+        c.mask_poly * evaluations.mask_poly
+            + c.z_a * evaluations.z_a
+            + c.z_b_constant
+            + c.w * evaluations.w
+            + c.x_constant
+            + c.h_1 * evaluations.h_1
+            + c.g_1_constant
+    }
+
+    fn inner_sumcheck_evaluation(
+        challenges: &VerifierChallenges<Fr>,
+        public: &PublicEvaluations<Fr>,
+        evaluations: &InnerSumcheckEvaluations<Fr>,
+    ) -> Fr {
+        let denominators = [evaluations.a_denom, evaluations.b_denom, evaluations.c_denom];
+        let c = inner_sumcheck_coefficients(challenges, public, denominators, evaluations.g_2, evaluations.t);
+        c.a_val * evaluations.a_val
+            + c.b_val * evaluations.b_val
+            + c.c_val * evaluations.c_val
+            + c.constant
+            + c.h_2 * evaluations.h_2
+    }
+
+    #[test]
+    fn test_round_challenges() {
+        let rng = &mut test_rng();
+        let domain_h = EvaluationDomain::<Fr>::new(4).unwrap();
+        let elems = [Fr::rand(rng), Fr::rand(rng), Fr::rand(rng), Fr::rand(rng)];
+
+        let first = first_round_challenges(&domain_h, elems);
+        assert_eq!([first.alpha, first.eta_a, first.eta_b, first.eta_c], elems);
+        assert_eq!(second_round_challenges(&domain_h, [elems[1]]).beta, elems[1]);
+        assert_eq!(third_round_challenges([elems[2]]), elems[2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_first_round_challenges_reject_alpha_in_h() {
+        let domain_h = EvaluationDomain::<Fr>::new(4).unwrap();
+        first_round_challenges(&domain_h, [domain_h.group_gen, fr(1), fr(2), fr(3)]);
+    }
+
+    #[test]
+    fn test_small_public_evaluations() {
+        // r(α, β) = (v_H(α) - v_H(β)) / (α - β) = (3 - 8) / (2 - 3) = 5, and x(β) = 1 for the input `[1]`.
+        assert_eq!(small_public_evaluations(), PublicEvaluations {
+            r_alpha_at_beta: fr(5),
+            v_H_at_alpha: fr(3),
+            v_H_at_beta: fr(8),
+            v_X_at_beta: fr(2),
+            x_at_beta: fr(1),
+            v_K_at_gamma: fr(15),
+            k_size: fr(2),
+        });
+    }
+
+    #[test]
+    fn test_small_outer_sumcheck_equation() {
+        let challenges = small_challenges();
+        let public = small_public_evaluations();
+
+        // z(β) = 1 + 2 * 4 = 9, and the lincheck term is 1 * 2 + 2 * 3 + 3 * 2 * 3 = 26, so the left-hand side
+        // is 1 + 5 * 26 - 4 * 9 = 95, and the right-hand side is 10 * 8 + 3 * 5 = 95.
+        let mut evaluations = OuterSumcheckEvaluations {
+            mask_poly: fr(1),
+            z_a: fr(2),
+            z_b: fr(3),
+            w: fr(4),
+            t: fr(4),
+            g_1: fr(5),
+            h_1: fr(10),
+        };
+        assert!(verify_outer_sumcheck_equation(&challenges, &public, &evaluations));
+        assert!(outer_sumcheck_evaluation(&challenges, &public, &evaluations).is_zero());
+
+        evaluations.h_1 = fr(11);
+        assert!(!verify_outer_sumcheck_equation(&challenges, &public, &evaluations));
+        assert_eq!(outer_sumcheck_evaluation(&challenges, &public, &evaluations), -fr(8));
+    }
+
+    #[test]
+    fn test_small_inner_sumcheck_equation() {
+        let challenges = small_challenges();
+        let public = small_public_evaluations();
+
+        // denom_M(γ) = (β - row_M(γ)) * (α - col_M(γ)) for the rows (2, 1, 0) and the columns (1, 1, 1).
+        let denom = |row: u64, col: u64| (fr(3) - fr(row)) * (fr(2) - fr(col));
+        assert_eq!([denom(2, 1), denom(1, 1), denom(0, 1)], [fr(1), fr(2), fr(3)]);
+
+        // a(γ) = 3 * 8 * (1 * 2 * 3 + 2 * 1 * 3 + 3 * 1 * 2) = 432 and b(γ) = 6, so the right-hand side
+        // is 432 - 6 * (4 * 5 + 4 / 2) = 300, and the left-hand side is 20 * 15 = 300.
+        let mut evaluations = InnerSumcheckEvaluations {
+            a_val: fr(1),
+            b_val: fr(1),
+            c_val: fr(1),
+            a_denom: denom(2, 1),
+            b_denom: denom(1, 1),
+            c_denom: denom(0, 1),
+            g_2: fr(5),
+            h_2: fr(20),
+            t: fr(4),
+        };
+        assert!(verify_inner_sumcheck_equation(&challenges, &public, &evaluations));
+        assert!(inner_sumcheck_evaluation(&challenges, &public, &evaluations).is_zero());
+
+        evaluations.g_2 = fr(6);
+        assert!(!verify_inner_sumcheck_equation(&challenges, &public, &evaluations));
+        assert_eq!(inner_sumcheck_evaluation(&challenges, &public, &evaluations), -fr(24));
+    }
+
+    #[test]
+    fn test_outer_sumcheck_equation_perturbations() {
+        let rng = &mut test_rng();
+
+        #[rustfmt::skip]
+        let fields: [fn(&mut OuterSumcheckEvaluations<Fr>) -> &mut Fr; 7] = [
+            |e| &mut e.mask_poly, |e| &mut e.z_a, |e| &mut e.z_b, |e| &mut e.w, |e| &mut e.t, |e| &mut e.g_1,
+            |e| &mut e.h_1,
+        ];
+
+        for _ in 0..ITERATIONS {
+            let challenges = random_challenges(rng);
+            let public = random_public_evaluations(rng);
+            let mut evaluations = OuterSumcheckEvaluations {
+                mask_poly: Fr::rand(rng),
+                z_a: Fr::rand(rng),
+                z_b: Fr::rand(rng),
+                w: Fr::rand(rng),
+                t: Fr::rand(rng),
+                g_1: Fr::rand(rng),
+                h_1: Fr::zero(),
+            };
+
+            // The linear combination evaluates to the difference of the two sides of the equation.
+            let difference = outer_sumcheck_evaluation(&challenges, &public, &evaluations);
+            evaluations.h_1 = difference / public.v_H_at_beta;
+            assert!(verify_outer_sumcheck_equation(&challenges, &public, &evaluations));
+            assert!(outer_sumcheck_evaluation(&challenges, &public, &evaluations).is_zero());
+
+            for field in fields.iter() {
+                let mut perturbed = evaluations;
+                *field(&mut perturbed) += Fr::one();
+                assert!(!verify_outer_sumcheck_equation(&challenges, &public, &perturbed));
+                assert!(!outer_sumcheck_evaluation(&challenges, &public, &perturbed).is_zero());
+            }
+        }
+    }
+
+    #[test]
+    fn test_inner_sumcheck_equation_perturbations() {
+        let rng = &mut test_rng();
+
+        #[rustfmt::skip]
+        let fields: [fn(&mut InnerSumcheckEvaluations<Fr>) -> &mut Fr; 9] = [
+            |e| &mut e.a_val, |e| &mut e.b_val, |e| &mut e.c_val, |e| &mut e.a_denom, |e| &mut e.b_denom,
+            |e| &mut e.c_denom, |e| &mut e.g_2, |e| &mut e.h_2, |e| &mut e.t,
+        ];
+
+        for _ in 0..ITERATIONS {
+            let challenges = random_challenges(rng);
+            let public = random_public_evaluations(rng);
+            let mut evaluations = InnerSumcheckEvaluations {
+                a_val: Fr::rand(rng),
+                b_val: Fr::rand(rng),
+                c_val: Fr::rand(rng),
+                a_denom: Fr::rand(rng),
+                b_denom: Fr::rand(rng),
+                c_denom: Fr::rand(rng),
+                g_2: Fr::rand(rng),
+                h_2: Fr::zero(),
+                t: Fr::rand(rng),
+            };
+
+            // The linear combination evaluates to the difference of the two sides of the equation.
+            let difference = inner_sumcheck_evaluation(&challenges, &public, &evaluations);
+            evaluations.h_2 = difference / public.v_K_at_gamma;
+            assert!(verify_inner_sumcheck_equation(&challenges, &public, &evaluations));
+            assert!(inner_sumcheck_evaluation(&challenges, &public, &evaluations).is_zero());
+
+            for field in fields.iter() {
+                let mut perturbed = evaluations;
+                *field(&mut perturbed) += Fr::one();
+                assert!(!verify_inner_sumcheck_equation(&challenges, &public, &perturbed));
+                assert!(!inner_sumcheck_evaluation(&challenges, &public, &perturbed).is_zero());
+            }
+        }
+    }
+}
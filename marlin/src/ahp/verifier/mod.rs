@@ -16,11 +16,14 @@
 
 #![allow(non_snake_case)]
 
+mod equations;
+pub use equations::*;
+
 mod first_message;
-pub(crate) use first_message::*;
+pub use first_message::*;
 
 mod second_message;
-pub(crate) use second_message::*;
+pub use second_message::*;
 
 mod state;
 pub(crate) use state::*;
@@ -17,7 +17,14 @@
 use crate::{
     ahp::{
         indexer::CircuitInfo,
-        verifier::{VerifierFirstMessage, VerifierSecondMessage, VerifierState},
+        verifier::{
+            first_round_challenges,
+            second_round_challenges,
+            third_round_challenges,
+            VerifierFirstMessage,
+            VerifierSecondMessage,
+            VerifierState,
+        },
         AHPError,
         AHPForR1CS,
     },
@@ -50,18 +57,7 @@ impl<TargetField: PrimeField> AHPForR1CS<TargetField> {
             EvaluationDomain::new(index_info.num_non_zero).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
 
         let elems = fs_rng.squeeze_nonnative_field_elements(4, OptimizationType::Weight)?;
-        let alpha = elems[0];
-        let eta_a = elems[1];
-        let eta_b = elems[2];
-        let eta_c = elems[3];
-        assert!(!domain_h.evaluate_vanishing_polynomial(alpha).is_zero());
-
-        let message = VerifierFirstMessage {
-            alpha,
-            eta_a,
-            eta_b,
-            eta_c,
-        };
+        let message = first_round_challenges(&domain_h, [elems[0], elems[1], elems[2], elems[3]]);
 
         let new_state = VerifierState {
             domain_h,
@@ -80,10 +76,7 @@ impl<TargetField: PrimeField> AHPForR1CS<TargetField> {
         fs_rng: &mut R,
     ) -> Result<(VerifierSecondMessage<TargetField>, VerifierState<TargetField>), AHPError> {
         let elems = fs_rng.squeeze_nonnative_field_elements(1, OptimizationType::Weight)?;
-        let beta = elems[0];
-        assert!(!state.domain_h.evaluate_vanishing_polynomial(beta).is_zero());
-
-        let message = VerifierSecondMessage { beta };
+        let message = second_round_challenges(&state.domain_h, [elems[0]]);
         state.second_round_message = Some(message);
 
         Ok((message, state))
@@ -95,7 +88,7 @@ impl<TargetField: PrimeField> AHPForR1CS<TargetField> {
         fs_rng: &mut R,
     ) -> Result<VerifierState<TargetField>, AHPError> {
         let elems = fs_rng.squeeze_nonnative_field_elements(1, OptimizationType::Weight)?;
-        let gamma = elems[0];
+        let gamma = third_round_challenges([elems[0]]);
 
         state.gamma = Some(gamma);
         Ok(state)
@@ -143,15 +143,7 @@ where
     > {
         // absorb the first commitments and messages
         {
-            let mut elems = Vec::<FpGadget<BaseField>>::new();
-            comms.iter().enumerate().for_each(|(i, comm)| {
-                elems.append(
-                    &mut comm
-                        .to_constraint_field(cs.ns(|| format!("comm_to_constraint_field_{}", i)))
-                        .unwrap(),
-                );
-            });
-            fs_rng.absorb_native_field_elements(cs.ns(|| "absorb_native_field_elements"), &elems)?;
+            fs_rng.absorb_constraint_field_gadgets(cs.ns(|| "absorb_commitments"), comms)?;
 
             if !message.is_empty() {
                 fs_rng.absorb_nonnative_field_elements(
@@ -216,16 +208,7 @@ where
 
         // absorb the second commitments and messages
         {
-            let mut elems = Vec::<FpGadget<BaseField>>::new();
-            comms.iter().enumerate().for_each(|(i, comm)| {
-                elems.append(
-                    &mut comm
-                        .to_constraint_field(cs.ns(|| format!("comm_to_constraint_field_{}", i)))
-                        .unwrap(),
-                );
-            });
-
-            fs_rng.absorb_native_field_elements(cs.ns(|| "absorb_native_field_elements"), &elems)?;
+            fs_rng.absorb_constraint_field_gadgets(cs.ns(|| "absorb_commitments"), comms)?;
             fs_rng.absorb_nonnative_field_elements(
                 cs.ns(|| "absorb_nonnative_field_elements"),
                 &message,
@@ -273,15 +256,7 @@ where
 
         // absorb the third commitments and messages
         {
-            let mut elems = Vec::<FpGadget<BaseField>>::new();
-            comms.iter().enumerate().for_each(|(i, comm)| {
-                elems.append(
-                    &mut comm
-                        .to_constraint_field(cs.ns(|| format!("comm_to_constraint_field_{}", i)))
-                        .unwrap(),
-                );
-            });
-            fs_rng.absorb_native_field_elements(cs.ns(|| "absorb_native_field_elements"), &elems)?;
+            fs_rng.absorb_constraint_field_gadgets(cs.ns(|| "absorb_commitments"), comms)?;
             fs_rng.absorb_nonnative_field_elements(
                 cs.ns(|| "absorb_nonnative_field_elements"),
                 &message,
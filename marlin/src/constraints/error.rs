@@ -30,39 +30,8 @@ where
     E: std::error::Error,
 {
     fn from(e: MarlinError<E>) -> Self {
-        match e {
-            crate::marlin::MarlinError::<E>::IndexTooLarge(u, v) => Self {
-                error_msg: format!("index of {} is too large, maximum degree of {}", v, u),
-            },
-            crate::marlin::MarlinError::<E>::AHPError(err) => match err {
-                crate::ahp::AHPError::ConstraintSystemError(error) => Self {
-                    error_msg: error.to_string(),
-                },
-                crate::ahp::AHPError::FiatShamirError(error) => Self {
-                    error_msg: error.to_string(),
-                },
-                crate::ahp::AHPError::InvalidPublicInputLength => Self {
-                    error_msg: String::from("invalid public input length"),
-                },
-                crate::ahp::AHPError::InstanceDoesNotMatchIndex => Self {
-                    error_msg: String::from("instance does not match index"),
-                },
-                crate::ahp::AHPError::MissingEval(str) => Self {
-                    error_msg: String::from("missing eval: ") + &*str,
-                },
-                crate::ahp::AHPError::NonSquareMatrix => Self {
-                    error_msg: String::from("non-sqaure matrix"),
-                },
-            },
-            crate::marlin::MarlinError::<E>::R1CSError(err) => Self {
-                error_msg: err.to_string(),
-            },
-            crate::marlin::MarlinError::<E>::FiatShamirError(err) => Self {
-                error_msg: err.to_string(),
-            },
-            crate::marlin::MarlinError::<E>::PolynomialCommitmentError(err) => Self {
-                error_msg: err.to_string(),
-            },
+        Self {
+            error_msg: e.to_string(),
         }
     }
 }
@@ -16,7 +16,7 @@
 
 use snarkvm_fields::{batch_inversion, Field, PrimeField};
 use snarkvm_gadgets::{
-    nonnative::NonNativeFieldVar,
+    nonnative::{NonNativeFieldMulResultVar, NonNativeFieldVar},
     traits::{alloc::AllocGadget, eq::EqGadget, fields::FieldGadget},
 };
 use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
@@ -224,23 +224,24 @@ impl<TargetField: PrimeField, BaseField: PrimeField> LagrangeInterpolationVar<Ta
             &interpolation_point,
         )?;
 
-        let mut interpolation = NonNativeFieldVar::<TargetField, BaseField>::zero(cs.ns(|| "zero"))?;
+        // Accumulate the products without reducing them, and reduce the sum once.
+        let mut interpolation = NonNativeFieldMulResultVar::<TargetField, BaseField>::zero();
 
         for (i, (lagrange_coefficient, polynomial_evaluation)) in lagrange_coefficients
             .iter()
             .zip(self.poly_evaluations.iter())
             .enumerate()
         {
-            let intermediate = lagrange_coefficient.mul(
+            let intermediate = lagrange_coefficient.mul_without_reduce(
                 cs.ns(|| format!("lagrange_coeff_mul_poly_evaluation_{}", i)),
                 polynomial_evaluation,
             )?;
 
             interpolation = interpolation.add(
-                cs.ns(|| format!("interpolation_plus_intermediate_{}", i)),
+                &mut cs.ns(|| format!("interpolation_plus_intermediate_{}", i)),
                 &intermediate,
             )?;
         }
-        Ok(interpolation)
+        interpolation.reduce(&mut cs.ns(|| "reduce_interpolation"))
     }
 }
@@ -170,6 +170,7 @@ where
 {
     type AllocatedCircuit = C;
     type Circuit = (C, UniversalSRS<TargetField, PC>);
+    type PreparedProvingKey = CircuitProvingKey<TargetField, PC>;
     type PreparedVerifyingKey = PreparedCircuitVerifyingKey<TargetField, PC>;
     type Proof = Proof<TargetField, PC>;
     type ProvingKey = CircuitProvingKey<TargetField, PC>;
@@ -197,6 +198,18 @@ where
         }
     }
 
+    fn prepare_proving_key(proving_key: Self::ProvingKey) -> Result<Self::PreparedProvingKey, SNARKError> {
+        Ok(proving_key)
+    }
+
+    fn prove_with_prepared_key<R: Rng>(
+        prepared_proving_key: &Self::PreparedProvingKey,
+        circuit: &Self::AllocatedCircuit,
+        rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        Self::prove(prepared_proving_key, circuit, rng)
+    }
+
     fn verify(
         verifying_key: &Self::PreparedVerifyingKey,
         input: &Self::VerifierInput,
@@ -363,6 +376,7 @@ pub mod test {
     use snarkvm_fields::Field;
     use snarkvm_gadgets::{
         curves::bls12_377::PairingGadget as Bls12_377PairingGadget,
+        fields::FpGadget,
         traits::{alloc::AllocGadget, eq::EqGadget},
     };
     use snarkvm_polycommit::marlin_pc::{marlin_kzg10::MarlinKZG10Gadget, MarlinKZG10};
@@ -372,16 +386,22 @@ pub mod test {
     use crate::{
         constraints::snark::{MarlinSNARK, MarlinSNARKGadget},
         fiat_shamir::{
+            AlgebraicSponge,
+            AlgebraicSpongeVar,
             FiatShamirAlgebraicSpongeRng,
             FiatShamirAlgebraicSpongeRngVar,
             PoseidonSponge,
             PoseidonSpongeVar,
+            TranscriptCost,
+            TranscriptOp,
         },
         marlin::MarlinRecursiveMode,
     };
 
     use super::*;
 
+    use std::cell::RefCell;
+
     const ITERATIONS: usize = 10;
 
     #[derive(Copy, Clone)]
@@ -595,11 +615,202 @@ pub mod test {
         const INPUT_GADGET_CONSTRAINTS: usize = 383;
         const PROOF_GADGET_CONSTRAINTS: usize = 56;
         const VK_GADGET_CONSTRAINTS: usize = 136;
-        const VERIFIER_GADGET_CONSTRAINTS: usize = 152885;
+        const VERIFIER_GADGET_CONSTRAINTS: usize = 152516;
 
         assert_eq!(input_gadget_constraints, INPUT_GADGET_CONSTRAINTS);
         assert_eq!(proof_gadget_constraints, PROOF_GADGET_CONSTRAINTS);
         assert_eq!(vk_gadget_constraints, VK_GADGET_CONSTRAINTS);
         assert_eq!(verifier_gadget_constraints, VERIFIER_GADGET_CONSTRAINTS);
     }
+
+    thread_local! {
+        /// The schedules and costs of the recording sponges dropped so far.
+        static TRANSCRIPTS: RefCell<Vec<(Vec<TranscriptOp>, TranscriptCost)>> = RefCell::new(Vec::new());
+    }
+
+    /// Returns the longest transcript recorded since the last call, after checking that
+    /// the cost of every recorded transcript is estimated exactly from its schedule.
+    fn take_verifier_transcript() -> (Vec<TranscriptOp>, TranscriptCost) {
+        let transcripts = TRANSCRIPTS.with(|transcripts| transcripts.take());
+        for (schedule, cost) in transcripts.iter() {
+            assert_eq!(*cost, PoseidonSponge::<Fq>::estimate_cost(schedule));
+        }
+        transcripts
+            .into_iter()
+            .max_by_key(|(_, cost)| cost.permutations)
+            .unwrap()
+    }
+
+    /// A Poseidon sponge recording its schedule.
+    #[derive(Clone)]
+    struct RecordingSponge {
+        sponge: PoseidonSponge<Fq>,
+        schedule: Vec<TranscriptOp>,
+    }
+
+    impl Drop for RecordingSponge {
+        fn drop(&mut self) {
+            let transcript = (self.schedule.clone(), self.sponge.transcript_cost());
+            TRANSCRIPTS.with(|transcripts| transcripts.borrow_mut().push(transcript));
+        }
+    }
+
+    impl AlgebraicSponge<Fq> for RecordingSponge {
+        fn new() -> Self {
+            Self {
+                sponge: PoseidonSponge::new(),
+                schedule: vec![],
+            }
+        }
+
+        fn absorb(&mut self, elems: &[Fq]) {
+            self.schedule.push(TranscriptOp::Absorb(elems.len()));
+            self.sponge.absorb(elems)
+        }
+
+        fn squeeze(&mut self, num: usize) -> Vec<Fq> {
+            self.schedule.push(TranscriptOp::Squeeze(num));
+            self.sponge.squeeze(num)
+        }
+
+        fn transcript_cost(&self) -> TranscriptCost {
+            self.sponge.transcript_cost()
+        }
+
+        fn estimate_cost(schedule: &[TranscriptOp]) -> TranscriptCost {
+            PoseidonSponge::<Fq>::estimate_cost(schedule)
+        }
+    }
+
+    /// A Poseidon sponge gadget recording its schedule, including the schedule
+    /// of the plaintext sponge it was instantiated from.
+    #[derive(Clone)]
+    struct RecordingSpongeVar {
+        sponge: PoseidonSpongeVar<Fq>,
+        schedule: Vec<TranscriptOp>,
+    }
+
+    impl Drop for RecordingSpongeVar {
+        fn drop(&mut self) {
+            let transcript = (self.schedule.clone(), self.sponge.transcript_cost());
+            TRANSCRIPTS.with(|transcripts| transcripts.borrow_mut().push(transcript));
+        }
+    }
+
+    impl AlgebraicSpongeVar<Fq, RecordingSponge> for RecordingSpongeVar {
+        fn new<CS: ConstraintSystem<Fq>>(cs: CS) -> Self {
+            Self {
+                sponge: PoseidonSpongeVar::new(cs),
+                schedule: vec![],
+            }
+        }
+
+        fn constant<CS: ConstraintSystem<Fq>>(cs: CS, ps: &RecordingSponge) -> Self {
+            Self {
+                sponge: PoseidonSpongeVar::constant(cs, &ps.sponge),
+                schedule: ps.schedule.clone(),
+            }
+        }
+
+        fn absorb<CS: ConstraintSystem<Fq>>(&mut self, cs: CS, elems: &[FpGadget<Fq>]) -> Result<(), SynthesisError> {
+            self.schedule.push(TranscriptOp::Absorb(elems.len()));
+            self.sponge.absorb(cs, elems)
+        }
+
+        fn squeeze<CS: ConstraintSystem<Fq>>(
+            &mut self,
+            cs: CS,
+            num: usize,
+        ) -> Result<Vec<FpGadget<Fq>>, SynthesisError> {
+            self.schedule.push(TranscriptOp::Squeeze(num));
+            self.sponge.squeeze(cs, num)
+        }
+
+        fn transcript_cost(&self) -> TranscriptCost {
+            self.sponge.transcript_cost()
+        }
+    }
+
+    type RecordingFS = FiatShamirAlgebraicSpongeRng<Fr, Fq, RecordingSponge>;
+    type RecordingFSG = FiatShamirAlgebraicSpongeRngVar<Fr, Fq, RecordingSponge, RecordingSpongeVar>;
+
+    type RecordingSNARK = MarlinSNARK<Fr, Fq, PC, RecordingFS, MarlinRecursiveMode, Circuit<Fr>>;
+    type RecordingSNARKGadget = MarlinSNARKGadget<Fr, Fq, PC, RecordingFS, MarlinRecursiveMode, PCGadget, RecordingFSG>;
+
+    #[test]
+    fn marlin_verifier_transcript_cost_test() {
+        let mut rng = test_rng();
+
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        let mut c = a;
+        c.mul_assign(&b);
+
+        let circ = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 100,
+            num_variables: 25,
+        };
+
+        let (pk, vk) = RecordingSNARK::circuit_specific_setup(circ, &mut rng).unwrap();
+        let proof = RecordingSNARK::prove(&pk, &circ, &mut rng).unwrap();
+
+        // Record the transcript of the native verifier.
+        TRANSCRIPTS.with(|transcripts| transcripts.borrow_mut().clear());
+        assert!(RecordingSNARK::verify(&vk.clone().into(), &[c], &proof).unwrap());
+        let (native_schedule, native_cost) = take_verifier_transcript();
+
+        // Record the transcript of the verifier gadget.
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let input_gadget = <RecordingSNARKGadget as SNARKGadget<Fr, Fq, RecordingSNARK>>::InputVar::alloc_input(
+            cs.ns(|| "alloc_input_gadget"),
+            || Ok(vec![c]),
+        )
+        .unwrap();
+        let proof_gadget = <RecordingSNARKGadget as SNARKGadget<Fr, Fq, RecordingSNARK>>::ProofVar::alloc(
+            cs.ns(|| "alloc_proof"),
+            || Ok(proof),
+        )
+        .unwrap();
+        let vk_gadget = <RecordingSNARKGadget as SNARKGadget<Fr, Fq, RecordingSNARK>>::VerifyingKeyVar::alloc(
+            cs.ns(|| "alloc_vk"),
+            || Ok(vk),
+        )
+        .unwrap();
+        let verification_result = <RecordingSNARKGadget as SNARKGadget<Fr, Fq, RecordingSNARK>>::verify(
+            cs.ns(|| "marlin_verify"),
+            &vk_gadget,
+            &input_gadget,
+            &proof_gadget,
+        )
+        .unwrap();
+        verification_result
+            .enforce_equal(cs.ns(|| "enforce_equal_verification"), &Boolean::Constant(true))
+            .unwrap();
+        assert!(cs.is_satisfied());
+        let (gadget_schedule, gadget_cost) = take_verifier_transcript();
+
+        // Empty absorbs leave the sponge untouched, and are free.
+        let gadget_schedule: Vec<_> = gadget_schedule
+            .into_iter()
+            .filter(|op| *op != TranscriptOp::Absorb(0))
+            .collect();
+        assert_eq!(gadget_cost, RecordingFS::estimate_cost(&gadget_schedule));
+
+        // The native and gadget verifiers run the same transcript up to the last challenge, or their challenges
+        // would diverge. Past it, the native verifier samples the batching randomness of the polynomial commitment
+        // check through `RngCore`, while the gadget squeezes it as field elements.
+        let num_challenge_ops = gadget_schedule.len() - 1;
+        assert_eq!(
+            native_schedule[..num_challenge_ops],
+            gadget_schedule[..num_challenge_ops]
+        );
+        assert_eq!(
+            RecordingFS::estimate_cost(&native_schedule[..num_challenge_ops]),
+            RecordingFS::estimate_cost(&gadget_schedule[..num_challenge_ops])
+        );
+        assert_eq!(native_cost, RecordingFS::estimate_cost(&native_schedule));
+        assert!(gadget_cost.permutations > 0);
+    }
 }
@@ -255,16 +255,33 @@ where
         CS: ConstraintSystem<BaseField>,
         PR: FiatShamirRng<TargetField, BaseField>,
         R: FiatShamirRngVar<TargetField, BaseField, PR>,
+    >(
+        cs: CS,
+        verifying_key: &CircuitVerifyingKeyVar<TargetField, BaseField, PC, PCG>,
+        public_input: &[NonNativeFieldVar<TargetField, BaseField>],
+        proof: &ProofVar<TargetField, BaseField, PC, PCG>,
+    ) -> Result<Boolean, MarlinError<PC::Error>> {
+        Self::verify_with_domain::<CS, PR, R>(cs, verifying_key, public_input, proof, None)
+    }
+
+    /// Verify with an established hashchain initial state, separated by the given domain tag, if any.
+    pub fn verify_with_domain<
+        CS: ConstraintSystem<BaseField>,
+        PR: FiatShamirRng<TargetField, BaseField>,
+        R: FiatShamirRngVar<TargetField, BaseField, PR>,
     >(
         mut cs: CS,
         verifying_key: &CircuitVerifyingKeyVar<TargetField, BaseField, PC, PCG>,
         public_input: &[NonNativeFieldVar<TargetField, BaseField>],
         proof: &ProofVar<TargetField, BaseField, PC, PCG>,
+        domain: Option<&[u8]>,
     ) -> Result<Boolean, MarlinError<PC::Error>> {
-        let prepared_verifying_key = PreparedCircuitVerifyingKeyVar::<TargetField, BaseField, PC, PCG, PR, R>::prepare(
-            cs.ns(|| "prepare"),
-            &verifying_key,
-        )?;
+        let prepared_verifying_key =
+            PreparedCircuitVerifyingKeyVar::<TargetField, BaseField, PC, PCG, PR, R>::prepare_with_domain(
+                cs.ns(|| "prepare"),
+                &verifying_key,
+                domain,
+            )?;
         Self::prepared_verify(
             cs.ns(|| "prepared_verify"),
             &prepared_verifying_key,
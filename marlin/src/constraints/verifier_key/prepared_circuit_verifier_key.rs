@@ -102,10 +102,23 @@ where
 {
     /// Returns an instance of a `PreparedCircuitVerifyingKeyGadget`.
     pub fn prepare<CS: ConstraintSystem<BaseField>>(
+        cs: CS,
+        vk: &CircuitVerifyingKeyVar<TargetField, BaseField, PC, PCG>,
+    ) -> Result<Self, SynthesisError> {
+        Self::prepare_with_domain(cs, vk, None)
+    }
+
+    /// Returns an instance of a `PreparedCircuitVerifyingKeyGadget`, whose Fiat-Shamir RNG
+    /// is separated by the given domain tag, if any.
+    pub fn prepare_with_domain<CS: ConstraintSystem<BaseField>>(
         mut cs: CS,
         vk: &CircuitVerifyingKeyVar<TargetField, BaseField, PC, PCG>,
+        domain: Option<&[u8]>,
     ) -> Result<Self, SynthesisError> {
-        let mut fs_rng_raw = PR::new();
+        let mut fs_rng_raw = match domain {
+            Some(tag) => PR::with_domain(tag),
+            None => PR::new(),
+        };
         fs_rng_raw.absorb_bytes(&to_bytes![
             &MarlinVerificationGadget::<TargetField, BaseField, PC, PCG>::PROTOCOL_NAME
         ]?);
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::fmt;
+use std::{error::Error, fmt};
 
 /// A `enum` specifying the possible failure modes of `FiatShamir`.
 #[derive(Debug)]
@@ -34,12 +34,17 @@ impl From<snarkvm_r1cs::SynthesisError> for FiatShamirError {
 impl fmt::Display for FiatShamirError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         match self {
-            FiatShamirError::R1CSError(error) => {
-                write!(f, "{:?}", error.to_string())
-            }
-            FiatShamirError::UninitializedRNG => {
-                write!(f, "{:?}", "uninitialized rng")
-            }
+            FiatShamirError::R1CSError(error) => write!(f, "{}", error),
+            FiatShamirError::UninitializedRNG => write!(f, "uninitialized rng"),
+        }
+    }
+}
+
+impl Error for FiatShamirError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FiatShamirError::R1CSError(error) => Some(error),
+            FiatShamirError::UninitializedRNG => None,
         }
     }
 }
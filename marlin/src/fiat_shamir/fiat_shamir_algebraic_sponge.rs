@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    fiat_shamir::{AlgebraicSponge, FiatShamirError, FiatShamirRng},
+    fiat_shamir::{AlgebraicSponge, FiatShamirError, FiatShamirRng, TranscriptCost, TranscriptOp},
     PhantomData,
 };
 use snarkvm_fields::{FieldParameters, PrimeField, ToConstraintField};
@@ -101,17 +101,32 @@ impl<TargetField: PrimeField, BaseField: PrimeField, S: AlgebraicSponge<BaseFiel
     fn squeeze_128_bits_nonnative_field_elements(&mut self, num: usize) -> Result<Vec<TargetField>, FiatShamirError> {
         Ok(Self::get_elements_from_sponge(&mut self.s, num, true))
     }
+
+    fn squeeze_bytes(&mut self, num_bytes: usize) -> Result<Vec<u8>, FiatShamirError> {
+        let num_bits = num_bytes * 8;
+        let bits = Self::get_bits_from_sponge(&mut self.s, num_bits);
+
+        // Each byte is read from the bits in big-endian order, as in `absorb_bytes`.
+        Ok(bits[..num_bits]
+            .chunks(8)
+            .map(|bits_per_byte| bits_per_byte.iter().fold(0u8, |byte, bit| (byte << 1) | *bit as u8))
+            .collect())
+    }
 }
 
+/// The bytes are read from the sponge by the same rule as `squeeze_bytes`, and its gadget
+/// counterpart `squeeze_bytes_gadget`: each squeezed native field element contributes its
+/// `BaseField::size_in_bits() - 1` lowest bits, in big-endian order, and elements are squeezed
+/// for as long as there are bytes to fill.
+///
+/// The bytes are biased. Dropping the highest bit of an element maps the elements below the
+/// modulus minus `2^(BaseField::size_in_bits() - 1)` to the same bits as the elements above
+/// `2^(BaseField::size_in_bits() - 1)`, so the leading bits of each element are more likely to
+/// be zero. Use `squeeze_nonnative_with_rejection` where uniformity is required.
 impl<TargetField: PrimeField, BaseField: PrimeField, S: AlgebraicSponge<BaseField>> RngCore
     for FiatShamirAlgebraicSpongeRng<TargetField, BaseField, S>
 {
     fn next_u32(&mut self) -> u32 {
-        assert!(
-            BaseField::size_in_bits() > 128,
-            "The native field of the algebraic sponge is too small."
-        );
-
         let mut dest = [0u8; 4];
         self.fill_bytes(&mut dest);
 
@@ -119,11 +134,6 @@ impl<TargetField: PrimeField, BaseField: PrimeField, S: AlgebraicSponge<BaseFiel
     }
 
     fn next_u64(&mut self) -> u64 {
-        assert!(
-            BaseField::size_in_bits() > 128,
-            "The native field of the algebraic sponge is too small."
-        );
-
         let mut dest = [0u8; 8];
         self.fill_bytes(&mut dest);
 
@@ -131,42 +141,18 @@ impl<TargetField: PrimeField, BaseField: PrimeField, S: AlgebraicSponge<BaseFiel
     }
 
     fn fill_bytes(&mut self, dest: &mut [u8]) {
-        assert!(
-            BaseField::size_in_bits() > 128,
-            "The native field of the algebraic sponge is too small."
-        );
-
-        let capacity = BaseField::size_in_bits() - 128;
-        let len = dest.len() * 8;
-
-        let num_of_elements = (capacity + len - 1) / len;
-        let elements = self.s.squeeze(num_of_elements);
-
-        let mut bits = Vec::<bool>::new();
-        for elem in elements.iter() {
-            let mut elem_bits = elem.into_repr().to_bits_be();
-            elem_bits.reverse();
-            bits.extend_from_slice(&elem_bits[0..capacity]);
-        }
-
-        bits.truncate(len);
-        bits.chunks_exact(8).enumerate().for_each(|(i, bits_per_byte)| {
-            let mut byte = 0;
-            for (j, bit) in bits_per_byte.iter().enumerate() {
-                if *bit {
-                    byte += 1 << j;
-                }
-            }
-            dest[i] = byte;
-        });
+        let num_bits = dest.len() * 8;
+        let bits = Self::get_bits_from_sponge(&mut self.s, num_bits);
+
+        // Each byte is read from the bits in big-endian order, as in `squeeze_bytes`.
+        dest.iter_mut()
+            .zip(bits[..num_bits].chunks(8))
+            .for_each(|(byte, bits_per_byte)| {
+                *byte = bits_per_byte.iter().fold(0u8, |byte, bit| (byte << 1) | *bit as u8)
+            });
     }
 
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        assert!(
-            BaseField::size_in_bits() > 128,
-            "The native field of the algebraic sponge is too small."
-        );
-
         self.fill_bytes(dest);
         Ok(())
     }
@@ -175,6 +161,39 @@ impl<TargetField: PrimeField, BaseField: PrimeField, S: AlgebraicSponge<BaseFiel
 impl<TargetField: PrimeField, BaseField: PrimeField, S: AlgebraicSponge<BaseField>>
     FiatShamirAlgebraicSpongeRng<TargetField, BaseField, S>
 {
+    /// Returns the cost of the transcript so far.
+    pub fn transcript_cost(&self) -> TranscriptCost {
+        self.s.transcript_cost()
+    }
+
+    /// Returns the cost of running the given schedule on a new sponge, without running it.
+    pub fn estimate_cost(schedule: &[TranscriptOp]) -> TranscriptCost {
+        S::estimate_cost(schedule)
+    }
+
+    /// Squeezes elements of the target field by rejection sampling, which are uniformly
+    /// distributed if the sponge outputs uniformly distributed native field elements.
+    ///
+    /// Unlike `squeeze_nonnative_field_elements`, the number of permutations of the sponge
+    /// depends on its output, so this has no gadget counterpart, and must not be used for
+    /// challenges that are also derived in a circuit.
+    pub fn squeeze_nonnative_with_rejection(&mut self, num: usize) -> Vec<TargetField> {
+        let num_bits_per_nonnative = TargetField::size_in_bits();
+
+        let mut dest_elements = Vec::with_capacity(num);
+        while dest_elements.len() < num {
+            let bits = Self::get_uniform_bits_from_sponge(&mut self.s, num_bits_per_nonnative);
+            let repr = TargetField::BigInteger::from_bits_be(bits[..num_bits_per_nonnative].to_vec());
+
+            // Reject the bits if they are not below the modulus of the target field.
+            if let Some(element) = TargetField::from_repr(repr) {
+                dest_elements.push(element);
+            }
+        }
+
+        dest_elements
+    }
+
     /// Compress every two elements if possible. Provides a vector of (limb, num_of_additions), both of which are P::BaseField.
     pub fn compress_elements(src_limbs: &[(BaseField, BaseField)], ty: OptimizationType) -> Vec<BaseField> {
         let capacity = BaseField::size_in_bits() - 1;
@@ -262,6 +281,22 @@ impl<TargetField: PrimeField, BaseField: PrimeField, S: AlgebraicSponge<BaseFiel
         dest_bits
     }
 
+    /// Obtain uniformly distributed random bits from the hashchain, by rejecting the squeezed
+    /// elements whose highest bit is set, and keeping the remaining bits of the others.
+    fn get_uniform_bits_from_sponge(sponge: &mut S, num_bits: usize) -> Vec<bool> {
+        let skip = (BaseField::Parameters::REPR_SHAVE_BITS + 1) as usize;
+
+        let mut dest_bits = Vec::<bool>::with_capacity(num_bits + BaseField::size_in_bits());
+        while dest_bits.len() < num_bits {
+            let elem_bits = sponge.squeeze(1)[0].into_repr().to_bits_be();
+            if !elem_bits[skip - 1] {
+                dest_bits.extend_from_slice(&elem_bits[skip..]);
+            }
+        }
+
+        dest_bits
+    }
+
     /// obtain random elements from hashchain.
     /// not guaranteed to be uniformly distributed, should only be used in certain situations.
     pub fn get_elements_from_sponge(
@@ -18,7 +18,7 @@ use std::marker::PhantomData;
 
 use snarkvm_fields::PrimeField;
 use snarkvm_gadgets::{
-    bits::{Boolean, ToBitsBEGadget},
+    bits::{Boolean, ToBitsBEGadget, ToBitsLEGadget},
     fields::{AllocatedFp, FpGadget},
     integers::uint::UInt8,
     nonnative::{
@@ -35,6 +35,7 @@ use crate::fiat_shamir::{
     traits::{AlgebraicSpongeVar, FiatShamirRngVar},
     AlgebraicSponge,
     FiatShamirAlgebraicSpongeRng,
+    TranscriptCost,
 };
 
 /// Building the Fiat-Shamir sponge's gadget from any algebraic sponge's gadget.
@@ -62,11 +63,19 @@ impl<
     S: AlgebraicSpongeVar<BaseField, PS>,
 > FiatShamirAlgebraicSpongeRngVar<TargetField, BaseField, PS, S>
 {
-    /// Compress every two elements if possible. Provides a vector of (limb, num_of_additions),
-    /// both of which are BaseField.
+    /// Returns the cost of the transcript so far, including the transcript of the
+    /// native RNG it was instantiated from.
+    pub fn transcript_cost(&self) -> TranscriptCost {
+        self.s.transcript_cost()
+    }
+
+    /// Compress every two elements if possible. Provides a vector of (limb, constant, num_of_additions),
+    /// where the value of each limb is the sum of its optional gadget and its constant. Constant
+    /// parts are folded into the linear combinations, so constant limbs allocate no variables.
+    #[allow(clippy::type_complexity)]
     pub fn compress_gadgets<CS: ConstraintSystem<BaseField>>(
         mut cs: CS,
-        src_limbs: &[(FpGadget<BaseField>, BaseField)],
+        src_limbs: &[(Option<FpGadget<BaseField>>, BaseField, BaseField)],
         ty: OptimizationType,
     ) -> Result<Vec<FpGadget<BaseField>>, SynthesisError> {
         let capacity = BaseField::size_in_bits() - 1;
@@ -96,32 +105,89 @@ impl<
             let first = &src_limbs[i];
             let second = if i + 1 < src_len { Some(&src_limbs[i + 1]) } else { None };
 
-            let first_max_bits_per_limb = params.bits_per_limb + overhead!(first.1 + BaseField::one());
+            let first_max_bits_per_limb = params.bits_per_limb + overhead!(first.2 + BaseField::one());
             let second_max_bits_per_limb = if second.is_some() {
-                params.bits_per_limb + overhead!(second.unwrap().1 + BaseField::one())
+                params.bits_per_limb + overhead!(second.unwrap().2 + BaseField::one())
             } else {
                 0
             };
 
-            if second.is_some() && first_max_bits_per_limb + second_max_bits_per_limb <= capacity {
-                let adjustment_factor = &adjustment_factor_lookup_table[second_max_bits_per_limb];
+            let (gadget, constant) =
+                if second.is_some() && first_max_bits_per_limb + second_max_bits_per_limb <= capacity {
+                    let second = second.unwrap();
+                    let adjustment_factor = &adjustment_factor_lookup_table[second_max_bits_per_limb];
+
+                    let first_gadget = match &first.0 {
+                        Some(gadget) => Some(gadget.mul_by_constant(
+                            cs.ns(|| format!("first_mul_by_constant_adjustment_factor_{}", i)),
+                            adjustment_factor,
+                        )?),
+                        None => None,
+                    };
+
+                    let gadget = match (first_gadget, &second.0) {
+                        (Some(first_gadget), Some(second_gadget)) => {
+                            Some(first_gadget.add(cs.ns(|| format!("value_add_second_{}", i)), second_gadget)?)
+                        }
+                        (None, Some(second_gadget)) => Some(second_gadget.clone()),
+                        (first_gadget, None) => first_gadget,
+                    };
+
+                    i += 2;
+                    (gadget, first.1 * adjustment_factor + second.1)
+                } else {
+                    i += 1;
+                    (first.0.clone(), first.1)
+                };
+
+            let value = match gadget {
+                Some(gadget) if constant.is_zero() => gadget,
+                Some(gadget) => gadget.add_constant(cs.ns(|| format!("value_add_constant_{}", i)), &constant)?,
+                None => FpGadget::Constant(constant),
+            };
+            dest_limbs.push(value);
+        }
 
-                let mut value = first.0.mul_by_constant(
-                    cs.ns(|| format!("first_mul_by_constant_adjustment_factor_{}", i)),
-                    adjustment_factor,
-                )?;
+        Ok(dest_limbs)
+    }
 
-                value = value.add(cs.ns(|| format!("value_add_second_{}", i)), &second.unwrap().0)?;
+    /// Recomposes the limbs of `elem` in the representation of the given optimization type,
+    /// from its bits in the normal form.
+    fn get_limbs_from_bits<CS: ConstraintSystem<BaseField>>(
+        mut cs: CS,
+        elem: &AllocatedNonNativeFieldVar<TargetField, BaseField>,
+        ty: OptimizationType,
+    ) -> Result<Vec<FpGadget<BaseField>>, SynthesisError> {
+        let params = get_params(TargetField::size_in_bits(), BaseField::size_in_bits(), ty);
+        let bits = elem.to_bits_le(cs.ns(|| "to_bits_le"))?;
 
-                dest_limbs.push(value);
-                i += 2;
-            } else {
-                dest_limbs.push(first.0.clone());
-                i += 1;
+        // The bits beyond `num_limbs * bits_per_limb` are zero, since the element is in the normal form.
+        let mut limbs = Vec::with_capacity(params.num_limbs);
+        for limb_bits in bits.chunks(params.bits_per_limb).take(params.num_limbs) {
+            let mut value = Some(BaseField::zero());
+            let mut lc = LinearCombination::zero();
+            let mut coeff = BaseField::one();
+            for bit in limb_bits {
+                value = match (value, bit.get_value()) {
+                    (Some(value), Some(true)) => Some(value + coeff),
+                    (Some(value), Some(false)) => Some(value),
+                    _ => None,
+                };
+                lc = &lc + bit.lc(CS::one(), BaseField::one()) * coeff;
+                coeff.double_in_place();
             }
+            limbs.push(FpGadget::from(AllocatedFp {
+                value,
+                variable: ConstraintVariable::LC(lc),
+            }));
+        }
+        while limbs.len() < params.num_limbs {
+            limbs.push(FpGadget::zero(cs.ns(|| format!("zero_limb_{}", limbs.len())))?);
         }
 
-        Ok(dest_limbs)
+        // The limbs are big limb first.
+        limbs.reverse();
+        Ok(limbs)
     }
 
     /// Push gadgets to sponge.
@@ -131,25 +197,23 @@ impl<
         src: &[NonNativeFieldVar<TargetField, BaseField>],
         ty: OptimizationType,
     ) -> Result<(), SynthesisError> {
-        let mut src_limbs: Vec<(FpGadget<BaseField>, BaseField)> = Vec::new();
+        let mut src_limbs: Vec<(Option<FpGadget<BaseField>>, BaseField, BaseField)> = Vec::new();
 
         for (i, elem) in src.iter().enumerate() {
             match elem {
                 NonNativeFieldVar::Constant(c) => {
-                    let v = AllocatedNonNativeFieldVar::<TargetField, BaseField>::alloc_constant(
-                        cs.ns(|| format!("alloc_constant_{}", i)),
-                        || Ok(c),
-                    )?;
-
-                    for limb in v.limbs.iter() {
-                        let num_of_additions_over_normal_form =
-                            if v.num_of_additions_over_normal_form == BaseField::zero() {
-                                BaseField::one()
-                            } else {
-                                v.num_of_additions_over_normal_form
-                            };
-                        src_limbs.push((limb.clone(), num_of_additions_over_normal_form));
-                    }
+                    // Constant limbs are folded into the compressed limbs, without allocating them.
+                    let limbs = AllocatedNonNativeFieldVar::<TargetField, BaseField>::get_limbs_representations(c, ty)?;
+                    src_limbs.extend(limbs.into_iter().map(|limb| (None, limb, BaseField::one())));
+                }
+                NonNativeFieldVar::Var(v) if v.get_optimization_type() != ty => {
+                    // The native sponge absorbs the limbs in the representation of `ty`.
+                    let limbs = Self::get_limbs_from_bits(cs.ns(|| format!("get_limbs_from_bits_{}", i)), v, ty)?;
+                    src_limbs.extend(
+                        limbs
+                            .into_iter()
+                            .map(|limb| (Some(limb), BaseField::zero(), BaseField::one())),
+                    );
                 }
                 NonNativeFieldVar::Var(v) => {
                     for limb in v.limbs.iter() {
@@ -159,7 +223,7 @@ impl<
                             } else {
                                 v.num_of_additions_over_normal_form
                             };
-                        src_limbs.push((limb.clone(), num_of_additions_over_normal_form));
+                        src_limbs.push((Some(limb.clone()), BaseField::zero(), num_of_additions_over_normal_form));
                     }
                 }
             }
@@ -362,12 +426,8 @@ impl<
         elems: &[UInt8],
     ) -> Result<(), SynthesisError> {
         let capacity = BaseField::size_in_bits() - 1;
-        let mut bits = Vec::<Boolean>::new();
-        for elem in elems.iter() {
-            let mut bits_le = elem.to_bits_le(); // UInt8's to_bits is le, which is an exception in Zexe.
-            bits_le.reverse();
-            bits.extend_from_slice(&bits_le);
-        }
+        // The bytes are packed into field elements in big-endian order, as in the native sponge.
+        let bits: Vec<Boolean> = elems.iter().flat_map(|elem| elem.to_bits_be()).collect();
 
         let mut adjustment_factors = Vec::<BaseField>::new();
         let mut cur = BaseField::one();
@@ -447,19 +507,31 @@ impl<
     ) -> Result<(Vec<NonNativeFieldVar<TargetField, BaseField>>, Vec<Vec<Boolean>>), SynthesisError> {
         Self::get_gadgets_and_bits_from_sponge(cs, &mut self.s, num, true)
     }
+
+    fn squeeze_bytes_gadget<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        num_bytes: usize,
+    ) -> Result<Vec<UInt8>, SynthesisError> {
+        let num_bits = num_bytes * 8;
+        let bits = Self::get_booleans_from_sponge(cs, &mut self.s, num_bits)?;
+
+        // Each byte is read from the bits in big-endian order, as in `absorb_bytes`.
+        Ok(bits[..num_bits].chunks(8).map(UInt8::from_bits_be).collect())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use rand::Rng;
     use rand_chacha::ChaChaRng;
-    use rand_core::SeedableRng;
+    use rand_core::{RngCore, SeedableRng};
 
-    use snarkvm_curves::bls12_377::Fq;
-    use snarkvm_fields::One;
+    use snarkvm_curves::bls12_377::{Fq, Fr};
+    use snarkvm_fields::{One, Zero};
     use snarkvm_gadgets::{bits::ToBitsLEGadget, traits::eq::EqGadget};
     use snarkvm_r1cs::TestConstraintSystem;
-    use snarkvm_utilities::rand::UniformRand;
+    use snarkvm_utilities::{rand::UniformRand, BigInteger};
 
     use crate::fiat_shamir::{
         fiat_shamir_poseidon_sponge::PoseidonSponge,
@@ -473,10 +545,13 @@ mod tests {
     type PSGadget = PoseidonSpongeVar<Fq>;
     type FS = FiatShamirAlgebraicSpongeRng<Fq, Fq, PS>;
     type FSGadget = FiatShamirAlgebraicSpongeRngVar<Fq, Fq, PS, PSGadget>;
+    type GenericFS<TargetField> = FiatShamirAlgebraicSpongeRng<TargetField, Fq, PS>;
+    type GenericFSGadget<TargetField> = FiatShamirAlgebraicSpongeRngVar<TargetField, Fq, PS, PSGadget>;
 
     const MAX_ELEMENTS: usize = 50;
     const MAX_ELEMENT_SIZE: usize = 100;
     const ITERATIONS: usize = 50;
+    const NONNATIVE_ITERATIONS: usize = 10;
 
     const NUM_ABSORBED_RAND_FIELD_ELEMS: usize = 10;
     const NUM_ABSORBED_RAND_BYTE_ELEMS: usize = 10;
@@ -485,7 +560,7 @@ mod tests {
     const NUM_SQUEEZED_FIELD_ELEMS: usize = 10;
     const NUM_SQUEEZED_SHORT_FIELD_ELEMS: usize = 10;
 
-    // TODO (raychu86): Make a macro to test different optimization types.
+    const OPTIMIZATION_TYPES: [OptimizationType; 2] = [OptimizationType::Weight, OptimizationType::Constraints];
 
     #[test]
     fn test_poseidon() {
@@ -598,6 +673,9 @@ mod tests {
             println!("\n=========================================================");
         }
         assert!(cs.is_satisfied());
+
+        // The plaintext and constraint world run the same transcript.
+        assert_eq!(fs_rng.transcript_cost(), fs_rng_gadget.transcript_cost());
     }
 
     #[test]
@@ -638,6 +716,8 @@ mod tests {
                     .enforce_equal(cs.ns(|| format!("enforce_equal_bit_{}_{}", i, j)), &alloc_boolean)
                     .unwrap();
             }
+
+            assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
         }
     }
 
@@ -654,7 +734,7 @@ mod tests {
 
             // Construct elements limb representations
             let mut element_limbs = Vec::<(Fq, Fq)>::new();
-            let mut element_limb_gadgets = Vec::<(FpGadget<Fq>, Fq)>::new();
+            let mut element_limb_gadgets = Vec::<(Option<FpGadget<Fq>>, Fq, Fq)>::new();
 
             for (j, elem) in elements.iter().enumerate() {
                 let limbs =
@@ -665,7 +745,7 @@ mod tests {
                         FpGadget::alloc(cs.ns(|| format!("alloc_limb_{}_{}_{}", i, j, k)), || Ok(limb)).unwrap();
 
                     element_limbs.push((*limb, Fq::one()));
-                    element_limb_gadgets.push((allocated_limb, Fq::one()));
+                    element_limb_gadgets.push((Some(allocated_limb), Fq::zero(), Fq::one()));
                     // Specifically set to one, since most gadgets in the constraint world would not have zero noise (due to the relatively weak normal form testing in `alloc`)
                 }
             }
@@ -690,6 +770,8 @@ mod tests {
                     .enforce_equal(cs.ns(|| format!("enforce_equal_element_{}_{}", i, j)), &alloc_element)
                     .unwrap();
             }
+
+            assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
         }
     }
 
@@ -706,7 +788,7 @@ mod tests {
 
             // Construct elements limb representations
             let mut element_limbs = Vec::<(Fq, Fq)>::new();
-            let mut element_limb_gadgets = Vec::<(FpGadget<Fq>, Fq)>::new();
+            let mut element_limb_gadgets = Vec::<(Option<FpGadget<Fq>>, Fq, Fq)>::new();
 
             for (j, elem) in elements.iter().enumerate() {
                 let limbs = AllocatedNonNativeFieldVar::<Fq, Fq>::get_limbs_representations(
@@ -719,7 +801,7 @@ mod tests {
                         FpGadget::alloc(cs.ns(|| format!("alloc_limb_{}_{}_{}", i, j, k)), || Ok(limb)).unwrap();
 
                     element_limbs.push((*limb, Fq::one()));
-                    element_limb_gadgets.push((allocated_limb, Fq::one()));
+                    element_limb_gadgets.push((Some(allocated_limb), Fq::zero(), Fq::one()));
                     // Specifically set to one, since most gadgets in the constraint world would not have zero noise (due to the relatively weak normal form testing in `alloc`)
                 }
             }
@@ -744,6 +826,8 @@ mod tests {
                     .enforce_equal(cs.ns(|| format!("enforce_equal_element_{}_{}", i, j)), &alloc_element)
                     .unwrap();
             }
+
+            assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
         }
     }
 
@@ -785,6 +869,7 @@ mod tests {
                 num_bits,
             )
             .unwrap();
+            assert_eq!(bits.len(), bit_gadgets.len());
 
             // Check that the bit results are equivalent.
             for (j, (bit_gadget, bit)) in bit_gadgets.iter().zip(bits).enumerate() {
@@ -797,6 +882,8 @@ mod tests {
                     .enforce_equal(cs.ns(|| format!("enforce_equal_bit_{}_{}", i, j)), &alloc_boolean)
                     .unwrap();
             }
+
+            assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
         }
     }
 
@@ -819,7 +906,7 @@ mod tests {
 
             let mut element_gadgets = vec![];
             for (j, element) in elements.iter().enumerate() {
-                // Allocate the nonnative field gadget from the base element.
+                // Allocate the native field gadget from the base element.
                 let alloc_element =
                     FpGadget::alloc(cs.ns(|| format!("native_alloc_field_{}_{}", i, j)), || Ok(element)).unwrap();
 
@@ -837,10 +924,11 @@ mod tests {
             let gadget_squeeze_result = fs_rng_gadget
                 .squeeze_native_field_elements(cs.ns(|| format!("squeeze_field_elements_{}", i)), num_elements)
                 .unwrap();
+            assert_eq!(squeeze_result.len(), gadget_squeeze_result.len());
 
             // Check that the squeeze results are equivalent.
             for (j, (gadget, element)) in gadget_squeeze_result.iter().zip(squeeze_result).enumerate() {
-                // Allocate the nonnative field gadget from the base element.
+                // Allocate the native field gadget from the base element.
                 let alloc_element = FpGadget::alloc(cs.ns(|| format!("native_alloc_field_result{}_{}", i, j)), || {
                     Ok(element)
                 })
@@ -851,57 +939,96 @@ mod tests {
                     .enforce_equal(cs.ns(|| format!("enforce_equal_element_{}_{}", i, j)), &alloc_element)
                     .unwrap();
             }
+
+            assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
         }
     }
 
-    #[test]
-    fn test_squeeze_nonnative_field_elements() {
-        let mut rng = ChaChaRng::seed_from_u64(123456789u64);
-
-        for i in 0..ITERATIONS {
-            let mut cs = TestConstraintSystem::<Fq>::new();
-
-            // Create a new FS rng.
-            let mut fs_rng = FS::new();
+    /// Absorbs the same random nonnative field elements, some allocated as constants and the rest
+    /// as variables, into a new native FS rng and a new FS rng gadget.
+    fn absorb_random_nonnative_field_elements<TargetField: PrimeField, CS: ConstraintSystem<Fq>, R: Rng>(
+        mut cs: CS,
+        rng: &mut R,
+        ty: OptimizationType,
+    ) -> (GenericFS<TargetField>, GenericFSGadget<TargetField>) {
+        let mut fs_rng = GenericFS::<TargetField>::new();
+        let mut fs_rng_gadget = GenericFSGadget::<TargetField>::new(cs.ns(|| "fs_rng_gadget_new"));
+
+        // Generate random elements.
+        let num_elements: usize = rng.gen_range(0..MAX_ELEMENTS);
+        let elements: Vec<_> = (0..num_elements).map(|_| TargetField::rand(rng)).collect();
+
+        let mut element_gadgets = vec![];
+        for (j, element) in elements.iter().enumerate() {
+            // Allocate the nonnative field gadget from the target element.
+            let alloc_element = match rng.gen() {
+                true => NonNativeFieldVar::Constant(*element),
+                false => {
+                    NonNativeFieldVar::alloc(cs.ns(|| format!("nonnative_alloc_field_{}", j)), || Ok(element)).unwrap()
+                }
+            };
 
-            // Allocate a new fs_rng gadget.
-            let mut fs_rng_gadget = FSGadget::new(cs.ns(|| format!("fs_rng_gadget_new{}", i)));
+            element_gadgets.push(alloc_element);
+        }
 
-            // Generate random elements.
-            let num_elements: usize = rng.gen_range(0..MAX_ELEMENTS);
-            let elements: Vec<_> = (0..num_elements).map(|_| Fq::rand(&mut rng)).collect();
+        // Push elements to the sponge
+        fs_rng.absorb_nonnative_field_elements(&elements, ty);
+        fs_rng_gadget
+            .absorb_nonnative_field_elements(cs.ns(|| "push_gadgets_to_sponge"), &element_gadgets, ty)
+            .unwrap();
 
-            let mut element_gadgets = vec![];
-            for (j, element) in elements.iter().enumerate() {
-                // Allocate the nonnative field gadget from the base element.
-                let alloc_element =
-                    NonNativeFieldVar::alloc(cs.ns(|| format!("nonnative_alloc_field_{}_{}", i, j)), || Ok(element))
-                        .unwrap();
+        (fs_rng, fs_rng_gadget)
+    }
 
-                element_gadgets.push(alloc_element);
-            }
+    /// Absorbs random nonnative field elements with the given optimization type, then checks that
+    /// the native FS rng and the FS rng gadget squeeze the same nonnative field elements, and if
+    /// `check_bits` is set, the same bits. Short elements are 128 bits long.
+    fn check_squeeze_nonnative_field_elements<TargetField: PrimeField>(
+        ty: OptimizationType,
+        short: bool,
+        check_bits: bool,
+    ) {
+        let mut rng = ChaChaRng::seed_from_u64(123456789u64);
 
-            // Push elements to the sponge
-            fs_rng.absorb_nonnative_field_elements(&elements, OptimizationType::Constraints);
-            fs_rng_gadget
-                .absorb_nonnative_field_elements(
-                    cs.ns(|| format!("push_gadgets_to_sponge{}", i)),
-                    &element_gadgets,
-                    OptimizationType::Constraints,
-                )
-                .unwrap();
+        for i in 0..NONNATIVE_ITERATIONS {
+            let mut cs = TestConstraintSystem::<Fq>::new();
+            let (mut fs_rng, mut fs_rng_gadget) = absorb_random_nonnative_field_elements::<TargetField, _, _>(
+                cs.ns(|| format!("absorb_{}", i)),
+                &mut rng,
+                ty,
+            );
 
             // Get the elements from the `fs_rng` and `fs_rng_gadget`.
-            let squeeze_result = fs_rng
-                .squeeze_nonnative_field_elements(num_elements, OptimizationType::Constraints)
-                .unwrap();
-            let gadget_squeeze_result = fs_rng_gadget
-                .squeeze_field_elements(cs.ns(|| format!("squeeze_field_elements_{}", i)), num_elements)
-                .unwrap();
+            let num_elements: usize = rng.gen_range(0..MAX_ELEMENTS);
+            let squeeze_result = match short {
+                true => fs_rng.squeeze_128_bits_nonnative_field_elements(num_elements),
+                false => fs_rng.squeeze_nonnative_field_elements(num_elements, ty),
+            }
+            .unwrap();
+            let squeeze_cs = cs.ns(|| format!("squeeze_field_elements_{}", i));
+            let (gadget_squeeze_result, gadget_squeeze_bits) = match (short, check_bits) {
+                (true, true) => fs_rng_gadget
+                    .squeeze_128_bits_field_elements_and_bits(squeeze_cs, num_elements)
+                    .unwrap(),
+                (true, false) => (
+                    fs_rng_gadget
+                        .squeeze_128_bits_field_elements(squeeze_cs, num_elements)
+                        .unwrap(),
+                    vec![],
+                ),
+                (false, true) => fs_rng_gadget
+                    .squeeze_field_elements_and_bits(squeeze_cs, num_elements)
+                    .unwrap(),
+                (false, false) => (
+                    fs_rng_gadget.squeeze_field_elements(squeeze_cs, num_elements).unwrap(),
+                    vec![],
+                ),
+            };
+            assert_eq!(squeeze_result.len(), gadget_squeeze_result.len());
 
             // Check that the squeeze results are equivalent.
-            for (j, (gadget, element)) in gadget_squeeze_result.iter().zip(squeeze_result).enumerate() {
-                // Allocate the nonnative field gadget from the base element.
+            for (j, (gadget, element)) in gadget_squeeze_result.iter().zip(&squeeze_result).enumerate() {
+                // Allocate the nonnative field gadget from the target element.
                 let alloc_element =
                     NonNativeFieldVar::alloc(cs.ns(|| format!("nonnative_alloc_field_result{}_{}", i, j)), || {
                         Ok(element)
@@ -912,227 +1039,516 @@ mod tests {
                 gadget
                     .enforce_equal(cs.ns(|| format!("enforce_equal_element_{}_{}", i, j)), &alloc_element)
                     .unwrap();
+
+                if !check_bits {
+                    continue;
+                }
+
+                // Check that the bits are equivalent.
+                let bits = &gadget_squeeze_bits[j];
+                assert_eq!(bits.len(), if short { 128 } else { TargetField::size_in_bits() - 1 });
+
+                let allocated_bits = alloc_element
+                    .to_bits_le(cs.ns(|| format!("to_bits_le_{}_{}", i, j)))
+                    .unwrap();
+
+                for (k, (allocated_bit, bit)) in allocated_bits.iter().zip(bits).enumerate() {
+                    allocated_bit
+                        .enforce_equal(cs.ns(|| format!("enforce_equal_bit_{}_{}_{}", i, j, k)), bit)
+                        .unwrap();
+                }
+                for (k, allocated_bit) in allocated_bits.iter().enumerate().skip(bits.len()) {
+                    allocated_bit
+                        .enforce_equal(
+                            cs.ns(|| format!("enforce_unset_bit_{}_{}_{}", i, j, k)),
+                            &Boolean::constant(false),
+                        )
+                        .unwrap();
+                }
             }
+
+            assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
+        }
+    }
+
+    #[test]
+    fn test_squeeze_nonnative_field_elements() {
+        for ty in OPTIMIZATION_TYPES.iter() {
+            check_squeeze_nonnative_field_elements::<Fq>(*ty, false, false);
+            check_squeeze_nonnative_field_elements::<Fr>(*ty, false, false);
         }
     }
 
     #[test]
     fn test_squeeze_field_elements_and_bits() {
+        for ty in OPTIMIZATION_TYPES.iter() {
+            check_squeeze_nonnative_field_elements::<Fq>(*ty, false, true);
+            check_squeeze_nonnative_field_elements::<Fr>(*ty, false, true);
+        }
+    }
+
+    #[test]
+    fn test_squeeze_128_bits_field_elements() {
+        for ty in OPTIMIZATION_TYPES.iter() {
+            check_squeeze_nonnative_field_elements::<Fq>(*ty, true, false);
+            check_squeeze_nonnative_field_elements::<Fr>(*ty, true, false);
+        }
+    }
+
+    #[test]
+    fn test_squeeze_128_bits_field_elements_and_bits() {
+        for ty in OPTIMIZATION_TYPES.iter() {
+            check_squeeze_nonnative_field_elements::<Fq>(*ty, true, true);
+            check_squeeze_nonnative_field_elements::<Fr>(*ty, true, true);
+        }
+    }
+
+    /// Checks that pushing nonnative field elements to the sponge with the given optimization type
+    /// leaves the native sponge and the sponge gadget in the same state.
+    fn check_push_gadgets_to_sponge<TargetField: PrimeField>(ty: OptimizationType) {
         let mut rng = ChaChaRng::seed_from_u64(123456789u64);
 
-        for i in 0..ITERATIONS {
+        for i in 0..NONNATIVE_ITERATIONS {
             let mut cs = TestConstraintSystem::<Fq>::new();
+            let (mut fs_rng, mut fs_rng_gadget) = absorb_random_nonnative_field_elements::<TargetField, _, _>(
+                cs.ns(|| format!("absorb_{}", i)),
+                &mut rng,
+                ty,
+            );
 
-            // Create a new FS rng.
-            let mut fs_rng = FS::new();
+            // The sponges squeeze the same native field elements.
+            let squeeze_result = fs_rng.s.squeeze(NUM_SQUEEZED_FIELD_ELEMS);
+            let gadget_squeeze_result = fs_rng_gadget
+                .s
+                .squeeze(cs.ns(|| format!("squeeze_{}", i)), NUM_SQUEEZED_FIELD_ELEMS)
+                .unwrap();
 
-            // Allocate a new fs_rng gadget.
-            let mut fs_rng_gadget = FSGadget::new(cs.ns(|| format!("fs_rng_gadget_new{}", i)));
+            for (j, (gadget, element)) in gadget_squeeze_result.iter().zip(squeeze_result).enumerate() {
+                let alloc_element =
+                    FpGadget::alloc(cs.ns(|| format!("alloc_field_result_{}_{}", i, j)), || Ok(element)).unwrap();
 
-            // Generate random elements.
-            let num_elements: usize = rng.gen_range(0..MAX_ELEMENTS);
-            let elements: Vec<_> = (0..num_elements).map(|_| Fq::rand(&mut rng)).collect();
+                gadget
+                    .enforce_equal(cs.ns(|| format!("enforce_equal_element_{}_{}", i, j)), &alloc_element)
+                    .unwrap();
+            }
 
-            let mut element_gadgets = vec![];
-            for (j, element) in elements.iter().enumerate() {
-                // Allocate the nonnative field gadget from the base element.
-                let alloc_element =
-                    NonNativeFieldVar::alloc(cs.ns(|| format!("nonnative_alloc_field_{}_{}", i, j)), || Ok(element))
-                        .unwrap();
+            assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
+        }
+    }
 
-                element_gadgets.push(alloc_element);
+    #[test]
+    fn test_push_gadgets_to_sponge() {
+        for ty in OPTIMIZATION_TYPES.iter() {
+            check_push_gadgets_to_sponge::<Fq>(*ty);
+            check_push_gadgets_to_sponge::<Fr>(*ty);
+        }
+    }
+
+    /// Absorbs the given elements into a new FS rng gadget, allocating the elements that are not
+    /// marked as constant, or all of them if `allocate_constants` is set. Then checks that the gadget
+    /// squeezes `expected`, and returns the numbers of constraints and private variables before squeezing.
+    fn push_mixed_gadgets_to_sponge<TargetField: PrimeField>(
+        expected: &[Fq],
+        elements: &[(TargetField, bool)],
+        ty: OptimizationType,
+        allocate_constants: bool,
+    ) -> (usize, usize) {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let mut fs_rng_gadget = GenericFSGadget::<TargetField>::new(cs.ns(|| "fs_rng_gadget_new"));
+
+        let mut element_gadgets = vec![];
+        for (j, (element, is_constant)) in elements.iter().enumerate() {
+            let element_gadget = match is_constant & !allocate_constants {
+                true => NonNativeFieldVar::Constant(*element),
+                false => {
+                    NonNativeFieldVar::alloc(cs.ns(|| format!("nonnative_alloc_field_{}", j)), || Ok(element)).unwrap()
+                }
+            };
+            element_gadgets.push(element_gadget);
+        }
+
+        fs_rng_gadget
+            .absorb_nonnative_field_elements(cs.ns(|| "push_gadgets_to_sponge"), &element_gadgets, ty)
+            .unwrap();
+        let num_constraints = (cs.num_constraints(), cs.num_private_variables());
+
+        let gadget_squeeze_result = fs_rng_gadget.s.squeeze(cs.ns(|| "squeeze"), expected.len()).unwrap();
+        for (j, (gadget, element)) in gadget_squeeze_result.iter().zip(expected).enumerate() {
+            let alloc_element = FpGadget::alloc(cs.ns(|| format!("alloc_field_result_{}", j)), || Ok(element)).unwrap();
+            gadget
+                .enforce_equal(cs.ns(|| format!("enforce_equal_element_{}", j)), &alloc_element)
+                .unwrap();
+        }
+        assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
+
+        num_constraints
+    }
+
+    /// Checks that compressing the limbs of nonnative constants allocates no variables, and that
+    /// absorbing a mix of constants and variables takes fewer variables, and no more constraints, than
+    /// allocating the constants as witnesses, while the FS rng gadget squeezes the same field elements as the native FS rng.
+    fn check_push_constant_gadgets_to_sponge<TargetField: PrimeField>(ty: OptimizationType) {
+        let mut rng = ChaChaRng::seed_from_u64(123456789u64);
+
+        for i in 0..NONNATIVE_ITERATIONS {
+            // Generate random elements, marking at least one of them as constant.
+            let num_elements: usize = rng.gen_range(1..MAX_ELEMENTS);
+            let mut elements: Vec<(TargetField, bool)> = (0..num_elements)
+                .map(|_| (TargetField::rand(&mut rng), rng.gen()))
+                .collect();
+            elements[0].1 = true;
+
+            let mut fs_rng = GenericFS::<TargetField>::new();
+            fs_rng.absorb_nonnative_field_elements(&elements.iter().map(|(e, _)| *e).collect::<Vec<_>>(), ty);
+            let squeeze_result = fs_rng.s.squeeze(NUM_SQUEEZED_FIELD_ELEMS);
+
+            // Compressing only constant limbs contributes no variables, and yields constants.
+            let mut cs = TestConstraintSystem::<Fq>::new();
+            let limbs: Vec<Fq> = elements
+                .iter()
+                .flat_map(|(e, _)| {
+                    AllocatedNonNativeFieldVar::<TargetField, Fq>::get_limbs_representations(e, ty).unwrap()
+                })
+                .collect();
+            let compressed_limbs = GenericFS::<TargetField>::compress_elements(
+                &limbs.iter().map(|l| (*l, Fq::one())).collect::<Vec<_>>(),
+                ty,
+            );
+            let compressed_limb_gadgets = GenericFSGadget::<TargetField>::compress_gadgets(
+                cs.ns(|| format!("compress_constants_{}", i)),
+                &limbs.iter().map(|l| (None, *l, Fq::one())).collect::<Vec<_>>(),
+                ty,
+            )
+            .unwrap();
+            assert_eq!(cs.num_private_variables(), 0);
+            assert_eq!(compressed_limb_gadgets.len(), compressed_limbs.len());
+            for (gadget, limb) in compressed_limb_gadgets.iter().zip(compressed_limbs) {
+                assert!(matches!(gadget, FpGadget::Constant(c) if *c == limb));
             }
 
-            // Push elements to the sponge
-            fs_rng.absorb_nonnative_field_elements(&elements, OptimizationType::Constraints);
+            // The constants are folded into the absorbed limbs, instead of being allocated as witnesses.
+            let (num_constraints, num_variables) = push_mixed_gadgets_to_sponge(&squeeze_result, &elements, ty, false);
+            let (num_constraints_allocated, num_variables_allocated) =
+                push_mixed_gadgets_to_sponge(&squeeze_result, &elements, ty, true);
+            assert!(num_constraints <= num_constraints_allocated);
+            assert!(num_variables < num_variables_allocated);
+        }
+    }
+
+    #[test]
+    fn test_push_constant_gadgets_to_sponge() {
+        for ty in OPTIMIZATION_TYPES.iter() {
+            check_push_constant_gadgets_to_sponge::<Fq>(*ty);
+            check_push_constant_gadgets_to_sponge::<Fr>(*ty);
+        }
+    }
+
+    /// Checks that the native FS rng and the FS rng gadget get the same nonnative field elements
+    /// from the sponge after absorbing the same bytes.
+    fn check_get_gadgets_from_sponge<TargetField: PrimeField>(outputs_short_elements: bool) {
+        let mut rng = ChaChaRng::seed_from_u64(123456789u64);
+
+        for i in 0..NONNATIVE_ITERATIONS {
+            let mut cs = TestConstraintSystem::<Fq>::new();
+
+            let mut fs_rng = GenericFS::<TargetField>::new();
+            let mut fs_rng_gadget = GenericFSGadget::<TargetField>::new(cs.ns(|| format!("fs_rng_gadget_new_{}", i)));
+
+            // Absorb random bytes.
+            let num_bytes: usize = rng.gen_range(0..MAX_ELEMENT_SIZE);
+            let bytes: Vec<u8> = (0..num_bytes).map(|_| u8::rand(&mut rng)).collect();
+            let byte_gadgets = UInt8::alloc_vec(cs.ns(|| format!("alloc_bytes_{}", i)), &bytes).unwrap();
+
+            fs_rng.absorb_bytes(&bytes);
             fs_rng_gadget
-                .absorb_nonnative_field_elements(
-                    cs.ns(|| format!("push_gadgets_to_sponge{}", i)),
-                    &element_gadgets,
-                    OptimizationType::Constraints,
-                )
+                .absorb_bytes(cs.ns(|| format!("absorb_bytes_{}", i)), &byte_gadgets)
                 .unwrap();
 
-            // Get the elements from the `fs_rng` and `fs_rng_gadget`.
-            let squeeze_result = fs_rng
-                .squeeze_nonnative_field_elements(num_elements, OptimizationType::Constraints)
-                .unwrap();
-            let (gadget_squeeze_result, gadget_squeeze_bits) = fs_rng_gadget
-                .squeeze_field_elements_and_bits(cs.ns(|| format!("squeeze_field_elements_{}", i)), num_elements)
-                .unwrap();
+            // Get the elements from the sponges.
+            let num_elements: usize = rng.gen_range(0..MAX_ELEMENTS);
+            let elements =
+                GenericFS::<TargetField>::get_elements_from_sponge(&mut fs_rng.s, num_elements, outputs_short_elements);
+            let gadgets = GenericFSGadget::<TargetField>::get_gadgets_from_sponge(
+                cs.ns(|| format!("get_gadgets_from_sponge_{}", i)),
+                &mut fs_rng_gadget.s,
+                num_elements,
+                outputs_short_elements,
+            )
+            .unwrap();
+            assert_eq!(elements.len(), gadgets.len());
+
+            for (j, (gadget, element)) in gadgets.iter().zip(elements).enumerate() {
+                assert_eq!(element, gadget.value().unwrap());
 
-            // Check that the squeeze results are equivalent.
-            for (j, ((gadget, bits), element)) in gadget_squeeze_result
-                .iter()
-                .zip(gadget_squeeze_bits)
-                .zip(squeeze_result)
-                .enumerate()
-            {
-                // Allocate the nonnative field gadget from the base element.
                 let alloc_element =
-                    NonNativeFieldVar::alloc(cs.ns(|| format!("nonnative_alloc_field_result{}_{}", i, j)), || {
+                    NonNativeFieldVar::alloc(cs.ns(|| format!("nonnative_alloc_field_result_{}_{}", i, j)), || {
                         Ok(element)
                     })
                     .unwrap();
 
-                // Check that the elements are equivalent.
                 gadget
                     .enforce_equal(cs.ns(|| format!("enforce_equal_element_{}_{}", i, j)), &alloc_element)
                     .unwrap();
-
-                // Check that the bits are equivalent.
-                let allocated_bits = alloc_element
-                    .to_bits_le(cs.ns(|| format!("to_bits_le_{}_{}", i, j)))
-                    .unwrap();
-
-                for (k, (allocated_bit, bit)) in allocated_bits.iter().zip(bits).enumerate() {
-                    allocated_bit
-                        .enforce_equal(cs.ns(|| format!("enforce_equal_bit_{}_{}_{}", i, j, k)), &bit)
-                        .unwrap();
-                }
             }
+
+            assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
         }
     }
 
     #[test]
-    fn test_squeeze_128_bits_field_elements() {
+    fn test_get_gadgets_from_sponge() {
+        check_get_gadgets_from_sponge::<Fq>(false);
+        check_get_gadgets_from_sponge::<Fq>(true);
+        check_get_gadgets_from_sponge::<Fr>(false);
+        check_get_gadgets_from_sponge::<Fr>(true);
+    }
+
+    #[test]
+    fn test_with_domain() {
         let mut rng = ChaChaRng::seed_from_u64(123456789u64);
+        let elements: Vec<_> = (0..NUM_ABSORBED_RAND_FIELD_ELEMS).map(|_| Fq::rand(&mut rng)).collect();
 
-        for i in 0..ITERATIONS {
-            let mut cs = TestConstraintSystem::<Fq>::new();
+        // Squeeze the sponge after absorbing the same elements.
+        let squeeze = |mut fs_rng: FS| {
+            fs_rng.absorb_native_field_elements(&elements);
+            fs_rng.squeeze_native_field_elements(NUM_SQUEEZED_FIELD_ELEMS).unwrap()
+        };
 
-            // Create a new FS rng.
-            let mut fs_rng = FS::new();
+        let squeeze_result = squeeze(FS::with_domain(b"domain_a"));
+        assert_eq!(squeeze_result, squeeze(FS::with_domain(b"domain_a")));
+        assert_ne!(squeeze_result, squeeze(FS::with_domain(b"domain_b")));
+        assert_ne!(squeeze_result, squeeze(FS::new()));
 
-            // Allocate a new fs_rng gadget.
-            let mut fs_rng_gadget = FSGadget::new(cs.ns(|| format!("fs_rng_gadget_new{}", i)));
+        // Check that the gadget agrees with the native sponge, and that the tag costs no constraints.
+        let mut cs = TestConstraintSystem::<Fq>::new();
 
-            // Generate random elements.
-            let num_elements: usize = rng.gen_range(0..MAX_ELEMENTS);
-            let elements: Vec<_> = (0..num_elements).map(|_| Fq::rand(&mut rng)).collect();
+        let mut fs_rng_gadget = FSGadget::with_domain(cs.ns(|| "fs_rng_gadget_with_domain"), b"domain_a");
+        assert_eq!(cs.num_constraints(), 0);
 
-            let mut element_gadgets = vec![];
-            for (j, element) in elements.iter().enumerate() {
-                // Allocate the nonnative field gadget from the base element.
-                let alloc_element =
-                    NonNativeFieldVar::alloc(cs.ns(|| format!("nonnative_alloc_field_{}_{}", i, j)), || Ok(element))
-                        .unwrap();
+        let mut element_gadgets = vec![];
+        for (i, element) in elements.iter().enumerate() {
+            let alloc_element = FpGadget::alloc(cs.ns(|| format!("native_alloc_field_{}", i)), || Ok(element)).unwrap();
+            element_gadgets.push(alloc_element);
+        }
 
-                element_gadgets.push(alloc_element);
-            }
+        fs_rng_gadget
+            .absorb_native_field_elements(cs.ns(|| "absorb_native_field_elements"), &element_gadgets)
+            .unwrap();
+        let gadget_squeeze_result = fs_rng_gadget
+            .squeeze_native_field_elements(cs.ns(|| "squeeze_native_field_elements"), NUM_SQUEEZED_FIELD_ELEMS)
+            .unwrap();
 
-            // Push elements to the sponge
-            fs_rng.absorb_nonnative_field_elements(&elements, OptimizationType::Constraints);
-            fs_rng_gadget
-                .absorb_nonnative_field_elements(
-                    cs.ns(|| format!("push_gadgets_to_sponge{}", i)),
-                    &element_gadgets,
-                    OptimizationType::Constraints,
-                )
+        for (j, (gadget, element)) in gadget_squeeze_result.iter().zip(squeeze_result).enumerate() {
+            assert_eq!(gadget.get_value(), Some(element));
+
+            let alloc_element =
+                FpGadget::alloc(cs.ns(|| format!("native_alloc_field_result_{}", j)), || Ok(element)).unwrap();
+            gadget
+                .enforce_equal(cs.ns(|| format!("enforce_equal_element_{}", j)), &alloc_element)
                 .unwrap();
+        }
 
-            // Get the elements from the `fs_rng` and `fs_rng_gadget`.
-            let squeeze_result = fs_rng.squeeze_128_bits_nonnative_field_elements(num_elements).unwrap();
+        assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
+    }
+
+    #[test]
+    fn test_squeeze_bytes() {
+        let mut rng = ChaChaRng::seed_from_u64(123456789u64);
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let mut fs_rng = FS::new();
+        let mut fs_rng_gadget = FSGadget::new(cs.ns(|| "fs_rng_gadget_new"));
+
+        // Absorb the same random elements and bytes into both sponges.
+        let elements: Vec<_> = (0..NUM_ABSORBED_RAND_FIELD_ELEMS).map(|_| Fq::rand(&mut rng)).collect();
+        let bytes: Vec<_> = (0..SIZE_ABSORBED_BYTE_ELEM).map(|_| u8::rand(&mut rng)).collect();
+
+        let mut element_gadgets = vec![];
+        for (i, element) in elements.iter().enumerate() {
+            let alloc_element = FpGadget::alloc(cs.ns(|| format!("native_alloc_field_{}", i)), || Ok(element)).unwrap();
+            element_gadgets.push(alloc_element);
+        }
+        let byte_gadgets = UInt8::alloc_vec(cs.ns(|| "alloc_bytes"), &bytes).unwrap();
+
+        fs_rng.absorb_native_field_elements(&elements);
+        fs_rng.absorb_bytes(&bytes);
+        fs_rng_gadget
+            .absorb_native_field_elements(cs.ns(|| "absorb_native_field_elements"), &element_gadgets)
+            .unwrap();
+        fs_rng_gadget
+            .absorb_bytes(cs.ns(|| "absorb_bytes"), &byte_gadgets)
+            .unwrap();
+
+        // Squeeze bytes of various lengths, spanning one or more field elements.
+        for num_bytes in [1, 31, 32, 100].iter() {
+            let squeeze_result = fs_rng.squeeze_bytes(*num_bytes).unwrap();
             let gadget_squeeze_result = fs_rng_gadget
-                .squeeze_128_bits_field_elements(
-                    cs.ns(|| format!("squeeze_128_bit_field_elements_{}", i)),
-                    num_elements,
-                )
+                .squeeze_bytes_gadget(cs.ns(|| format!("squeeze_bytes_{}", num_bytes)), *num_bytes)
                 .unwrap();
+            assert_eq!(squeeze_result.len(), *num_bytes);
+            assert_eq!(gadget_squeeze_result.len(), *num_bytes);
 
-            // Check that the squeeze results are equivalent.
-            for (j, (gadget, element)) in gadget_squeeze_result.iter().zip(squeeze_result).enumerate() {
-                // Allocate the nonnative field gadget from the base element.
-                let alloc_element =
-                    NonNativeFieldVar::alloc(cs.ns(|| format!("nonnative_alloc_field_result{}_{}", i, j)), || {
-                        Ok(element)
-                    })
-                    .unwrap();
+            // Check that the squeezed bytes are equivalent.
+            let alloc_bytes =
+                UInt8::alloc_vec(cs.ns(|| format!("alloc_byte_results_{}", num_bytes)), &squeeze_result).unwrap();
+            for (j, (gadget, alloc_byte)) in gadget_squeeze_result.iter().zip(alloc_bytes).enumerate() {
+                assert_eq!(gadget.value, Some(squeeze_result[j]));
 
-                // Check that the elements are equivalent.
                 gadget
-                    .enforce_equal(cs.ns(|| format!("enforce_equal_element_{}_{}", i, j)), &alloc_element)
+                    .enforce_equal(cs.ns(|| format!("enforce_equal_byte_{}_{}", num_bytes, j)), &alloc_byte)
                     .unwrap();
             }
         }
+
+        assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
     }
 
     #[test]
-    fn test_squeeze_128_bits_field_elements_and_bits() {
+    fn test_absorb_random_bytes() {
         let mut rng = ChaChaRng::seed_from_u64(123456789u64);
 
         for i in 0..ITERATIONS {
             let mut cs = TestConstraintSystem::<Fq>::new();
 
-            // Create a new FS rng.
             let mut fs_rng = FS::new();
+            let mut fs_rng_gadget = FSGadget::new(cs.ns(|| "fs_rng_gadget_new"));
+
+            // Absorb byte strings of random lengths, which need not fill whole field elements.
+            let num_byte_strings: usize = rng.gen_range(1..4);
+            for j in 0..num_byte_strings {
+                let num_bytes: usize = rng.gen_range(0..MAX_ELEMENT_SIZE);
+                let bytes: Vec<u8> = (0..num_bytes).map(|_| rng.gen()).collect();
+                let byte_gadgets = UInt8::alloc_vec(cs.ns(|| format!("alloc_bytes_{}", j)), &bytes).unwrap();
+
+                fs_rng.absorb_bytes(&bytes);
+                fs_rng_gadget
+                    .absorb_bytes(cs.ns(|| format!("absorb_bytes_{}", j)), &byte_gadgets)
+                    .unwrap();
+            }
 
-            // Allocate a new fs_rng gadget.
-            let mut fs_rng_gadget = FSGadget::new(cs.ns(|| format!("fs_rng_gadget_new{}", i)));
+            // The squeezed elements only match if both sponges packed the bytes in the same bit order.
+            let squeeze_result = fs_rng.squeeze_native_field_elements(NUM_SQUEEZED_FIELD_ELEMS).unwrap();
+            let gadget_squeeze_result = fs_rng_gadget
+                .squeeze_native_field_elements(cs.ns(|| "squeeze_field_elements"), NUM_SQUEEZED_FIELD_ELEMS)
+                .unwrap();
+            for (gadget, element) in gadget_squeeze_result.iter().zip(squeeze_result) {
+                assert_eq!(gadget.get_value(), Some(element), "iteration {}", i);
+            }
 
-            // Generate random elements.
-            let num_elements: usize = rng.gen_range(0..MAX_ELEMENTS);
-            let elements: Vec<_> = (0..num_elements).map(|_| Fq::rand(&mut rng)).collect();
+            assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
+        }
+    }
 
-            let mut element_gadgets = vec![];
-            for (j, element) in elements.iter().enumerate() {
-                // Allocate the nonnative field gadget from the base element.
-                let alloc_element =
-                    NonNativeFieldVar::alloc(cs.ns(|| format!("nonnative_alloc_field_{}_{}", i, j)), || Ok(element))
-                        .unwrap();
+    /// Returns a sponge that has absorbed the given bytes.
+    fn fs_rng_with_bytes(bytes: &[u8]) -> FS {
+        let mut fs_rng = FS::new();
+        fs_rng.absorb_bytes(bytes);
+        fs_rng
+    }
 
-                element_gadgets.push(alloc_element);
-            }
+    #[test]
+    fn test_fill_bytes_is_deterministic() {
+        let mut rng = ChaChaRng::seed_from_u64(123456789u64);
+        let bytes: Vec<_> = (0..SIZE_ABSORBED_BYTE_ELEM).map(|_| u8::rand(&mut rng)).collect();
+
+        // Fill buffers that span several squeezed elements.
+        let mut fs_rng = fs_rng_with_bytes(&bytes);
+        let mut other_fs_rng = fs_rng_with_bytes(&bytes);
+        for num_bytes in [1, 47, 48, 200].iter() {
+            let mut dest = vec![0u8; *num_bytes];
+            let mut other_dest = vec![0u8; *num_bytes];
+            fs_rng.fill_bytes(&mut dest);
+            other_fs_rng.fill_bytes(&mut other_dest);
+            assert_eq!(dest, other_dest);
+        }
+        assert_eq!(fs_rng.next_u64(), other_fs_rng.next_u64());
 
-            // Push elements to the sponge
-            fs_rng.absorb_nonnative_field_elements(&elements, OptimizationType::Constraints);
-            fs_rng_gadget
-                .absorb_nonnative_field_elements(
-                    cs.ns(|| format!("push_gadgets_to_sponge{}", i)),
-                    &element_gadgets,
-                    OptimizationType::Constraints,
-                )
-                .unwrap();
+        // The bytes are the ones `squeeze_bytes` returns, including past the first element.
+        let mut dest = vec![0u8; 200];
+        fs_rng_with_bytes(&bytes).fill_bytes(&mut dest);
+        assert_eq!(dest, fs_rng_with_bytes(&bytes).squeeze_bytes(200).unwrap());
 
-            // Get the elements from the `fs_rng` and `fs_rng_gadget`.
-            let squeeze_result = fs_rng.squeeze_128_bits_nonnative_field_elements(num_elements).unwrap();
-            let (gadget_squeeze_result, gadget_squeeze_bits) = fs_rng_gadget
-                .squeeze_128_bits_field_elements_and_bits(
-                    cs.ns(|| format!("squeeze_128_bit_field_elements_{}", i)),
-                    num_elements,
-                )
-                .unwrap();
+        // A different absorb sequence gives different bytes.
+        let mut other_dest = vec![0u8; 200];
+        fs_rng_with_bytes(&bytes[1..]).fill_bytes(&mut other_dest);
+        assert_ne!(dest, other_dest);
+    }
 
-            // Check that the squeeze results are equivalent.
-            for (j, ((gadget, bits), element)) in gadget_squeeze_result
-                .iter()
-                .zip(gadget_squeeze_bits)
-                .zip(squeeze_result)
-                .enumerate()
-            {
-                // Allocate the nonnative field gadget from the base element.
-                let alloc_element =
-                    NonNativeFieldVar::alloc(cs.ns(|| format!("nonnative_alloc_field_result{}_{}", i, j)), || {
-                        Ok(element)
-                    })
-                    .unwrap();
+    #[test]
+    fn test_fill_bytes_chi_square() {
+        const NUM_BYTES: usize = 1 << 20;
+        // The critical value of the chi-square distribution with 255 degrees of freedom, at 0.001.
+        // The leading byte of each squeezed element is biased, which raises the statistic, but
+        // it is only one in 47 bytes when squeezing from `Fq`.
+        const CRITICAL_VALUE: f64 = 330.52;
 
-                // Check that the elements are equivalent.
-                gadget
-                    .enforce_equal(cs.ns(|| format!("enforce_equal_element_{}_{}", i, j)), &alloc_element)
-                    .unwrap();
+        let mut dest = vec![0u8; NUM_BYTES];
+        fs_rng_with_bytes(b"chi-square").fill_bytes(&mut dest);
 
-                // Check that the bits are equivalent.
-                let allocated_bits = alloc_element
-                    .to_bits_le(cs.ns(|| format!("to_bits_le_{}_{}", i, j)))
-                    .unwrap();
+        let mut frequencies = [0usize; 256];
+        dest.iter().for_each(|byte| frequencies[*byte as usize] += 1);
 
-                for (k, (allocated_bit, bit)) in allocated_bits.iter().zip(bits).enumerate() {
-                    allocated_bit
-                        .enforce_equal(cs.ns(|| format!("enforce_equal_bit_{}_{}_{}", i, j, k)), &bit)
-                        .unwrap();
-                }
-            }
+        let expected = NUM_BYTES as f64 / 256.0;
+        let chi_square: f64 = frequencies
+            .iter()
+            .map(|frequency| (*frequency as f64 - expected).powi(2) / expected)
+            .sum();
+        assert!(chi_square < CRITICAL_VALUE, "chi-square of {}", chi_square);
+    }
+
+    #[test]
+    fn test_squeeze_nonnative_with_rejection() {
+        let mut fs_rng = GenericFS::<Fr>::new();
+        let mut other_fs_rng = GenericFS::<Fr>::new();
+        fs_rng.absorb_bytes(b"rejection");
+        other_fs_rng.absorb_bytes(b"rejection");
+
+        let elements = fs_rng.squeeze_nonnative_with_rejection(NUM_SQUEEZED_FIELD_ELEMS);
+        assert_eq!(elements.len(), NUM_SQUEEZED_FIELD_ELEMS);
+        assert_eq!(
+            elements,
+            other_fs_rng.squeeze_nonnative_with_rejection(NUM_SQUEEZED_FIELD_ELEMS)
+        );
+
+        // Unlike the biased squeeze, which never sets the highest bit, about 14% of the elements
+        // below the modulus of `Fr` have their highest bit set.
+        let elements = fs_rng.squeeze_nonnative_with_rejection(1000);
+        let num_top_bits_set = elements
+            .iter()
+            .filter(|element| element.into_repr().get_bit(Fr::size_in_bits() - 1))
+            .count();
+        assert!(
+            (100..190).contains(&num_top_bits_set),
+            "{} top bits set",
+            num_top_bits_set
+        );
+    }
+
+    #[test]
+    fn test_fill_bytes_matches_squeeze_bytes_gadget() {
+        let mut rng = ChaChaRng::seed_from_u64(123456789u64);
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let bytes: Vec<_> = (0..SIZE_ABSORBED_BYTE_ELEM).map(|_| u8::rand(&mut rng)).collect();
+        let byte_gadgets = UInt8::alloc_vec(cs.ns(|| "alloc_bytes"), &bytes).unwrap();
+
+        let mut fs_rng = fs_rng_with_bytes(&bytes);
+        let mut fs_rng_gadget = FSGadget::new(cs.ns(|| "fs_rng_gadget_new"));
+        fs_rng_gadget
+            .absorb_bytes(cs.ns(|| "absorb_bytes"), &byte_gadgets)
+            .unwrap();
+
+        for num_bytes in [1, 47, 48, 100].iter() {
+            let mut dest = vec![0u8; *num_bytes];
+            fs_rng.fill_bytes(&mut dest);
+
+            let gadget_bytes = fs_rng_gadget
+                .squeeze_bytes_gadget(cs.ns(|| format!("squeeze_bytes_{}", num_bytes)), *num_bytes)
+                .unwrap();
+            let gadget_bytes: Vec<_> = gadget_bytes.iter().map(|byte| byte.value.unwrap()).collect();
+            assert_eq!(dest, gadget_bytes);
         }
+
+        assert!(cs.is_satisfied(), "{:?}", cs.which_is_unsatisfied());
     }
 }
@@ -175,6 +175,18 @@ impl<TargetField: PrimeField, BaseField: PrimeField, D: Digest> FiatShamirRng<Ta
         }
         Ok(res)
     }
+
+    fn squeeze_bytes(&mut self, num_bytes: usize) -> Result<Vec<u8>, FiatShamirError> {
+        // Ensure the RNG is initialized.
+        let rng = match &mut self.r {
+            Some(rng) => rng,
+            None => return Err(FiatShamirError::UninitializedRNG),
+        };
+
+        let mut res = vec![0u8; num_bytes];
+        rng.fill_bytes(&mut res);
+        Ok(res)
+    }
 }
 
 #[cfg(test)]
@@ -27,6 +27,16 @@ pub trait FiatShamirRng<TargetField: PrimeField, BaseField: PrimeField>: RngCore
     /// Initializes an RNG.
     fn new() -> Self;
 
+    /// Initializes an RNG that is separated from other domains by first absorbing the given tag.
+    fn with_domain(tag: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        let mut rng = Self::new();
+        rng.absorb_bytes(tag);
+        rng
+    }
+
     /// Takes in field elements.
     fn absorb_nonnative_field_elements(&mut self, elements: &[TargetField], ty: OptimizationType);
 
@@ -48,4 +58,7 @@ pub trait FiatShamirRng<TargetField: PrimeField, BaseField: PrimeField>: RngCore
 
     /// Takes out field elements of 128 bits.
     fn squeeze_128_bits_nonnative_field_elements(&mut self, num: usize) -> Result<Vec<TargetField>, FiatShamirError>;
+
+    /// Takes out bytes.
+    fn squeeze_bytes(&mut self, num_bytes: usize) -> Result<Vec<u8>, FiatShamirError>;
 }
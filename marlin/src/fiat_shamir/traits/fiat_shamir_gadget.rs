@@ -21,6 +21,7 @@ use snarkvm_gadgets::{
     fields::FpGadget,
     integers::uint::UInt8,
     nonnative::{params::OptimizationType, NonNativeFieldVar},
+    traits::fields::ToConstraintFieldGadget,
 };
 use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
 
@@ -34,6 +35,12 @@ pub trait FiatShamirRngVar<TargetField: PrimeField, BaseField: PrimeField, PFS:
     /// Instantiate from a plaintext fs_rng.
     fn constant<CS: ConstraintSystem<BaseField>>(cs: CS, pfs: &PFS) -> Self;
 
+    /// Create a new RNG separated by the given domain tag. The tag is public, so it is absorbed
+    /// by the plaintext fs_rng, and the resulting state is instantiated as a constant.
+    fn with_domain<CS: ConstraintSystem<BaseField>>(cs: CS, tag: &[u8]) -> Self {
+        Self::constant(cs, &PFS::with_domain(tag))
+    }
+
     /// Take in field elements.
     fn absorb_nonnative_field_elements<CS: ConstraintSystem<BaseField>>(
         &mut self,
@@ -49,6 +56,20 @@ pub trait FiatShamirRngVar<TargetField: PrimeField, BaseField: PrimeField, PFS:
         elems: &[FpGadget<BaseField>],
     ) -> Result<(), SynthesisError>;
 
+    /// Take in gadgets that convert to field elements, such as group elements and commitments.
+    fn absorb_constraint_field_gadgets<CS: ConstraintSystem<BaseField>, G: ToConstraintFieldGadget<BaseField>>(
+        &mut self,
+        mut cs: CS,
+        elems: &[G],
+    ) -> Result<(), SynthesisError> {
+        let mut field_elems = Vec::<FpGadget<BaseField>>::new();
+        for (i, elem) in elems.iter().enumerate() {
+            field_elems.extend(elem.to_constraint_field(cs.ns(|| format!("to_constraint_field_{}", i)))?);
+        }
+
+        self.absorb_native_field_elements(cs.ns(|| "absorb_native_field_elements"), &field_elems)
+    }
+
     /// Take in bytes.
     fn absorb_bytes<CS: ConstraintSystem<BaseField>>(&mut self, cs: CS, elems: &[UInt8]) -> Result<(), SynthesisError>;
 
@@ -89,4 +110,11 @@ pub trait FiatShamirRngVar<TargetField: PrimeField, BaseField: PrimeField, PFS:
         cs: CS,
         num: usize,
     ) -> Result<(Vec<NonNativeFieldVar<TargetField, BaseField>>, Vec<Vec<Boolean>>), SynthesisError>;
+
+    /// Output bytes.
+    fn squeeze_bytes_gadget<CS: ConstraintSystem<BaseField>>(
+        &mut self,
+        cs: CS,
+        num_bytes: usize,
+    ) -> Result<Vec<UInt8>, SynthesisError>;
 }
@@ -14,11 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-mod algebraic_sponge;
-pub use algebraic_sponge::*;
-
-mod algebraic_sponge_gadget;
-pub use algebraic_sponge_gadget::*;
+pub use snarkvm_algorithms::traits::{AlgebraicSponge, TranscriptCost, TranscriptOp};
+pub use snarkvm_gadgets::traits::algorithms::AlgebraicSpongeVar;
 
 mod fiat_shamir;
 pub use fiat_shamir::*;
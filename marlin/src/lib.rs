@@ -84,3 +84,16 @@ pub use snark::*;
 /// RNGs for the Marlin SNARK.
 pub mod fiat_shamir;
 pub use fiat_shamir::*;
+
+/// Whether `snarkvm-marlin` was compiled with the `parallel` feature.
+pub const PARALLEL_ENABLED: bool = cfg!(feature = "parallel");
+
+/// Registers `snarkvm-marlin` and the crates it depends on.
+pub fn register_capabilities(capabilities: &mut snarkvm_utilities::Capabilities) {
+    capabilities.register(snarkvm_utilities::CrateCapabilities::new("snarkvm-marlin", &[
+        ("std", cfg!(feature = "std")),
+        ("parallel", PARALLEL_ENABLED),
+        ("print-trace", cfg!(feature = "print-trace")),
+    ]));
+    snarkvm_polycommit::register_capabilities(capabilities);
+}
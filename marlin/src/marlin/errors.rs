@@ -16,7 +16,7 @@
 
 use snarkvm_algorithms::SNARKError;
 
-use std::fmt::Debug;
+use std::{error::Error, fmt};
 
 /// A `enum` specifying the possible failure modes of `Marlin`.
 #[derive(Debug)]
@@ -59,8 +59,38 @@ impl<E> MarlinError<E> {
     }
 }
 
-impl<E: Debug> From<MarlinError<E>> for SNARKError {
+impl<E: fmt::Display> fmt::Display for MarlinError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarlinError::IndexTooLarge(u, v) => write!(f, "index of {} is too large, maximum degree of {}", v, u),
+            MarlinError::AHPError(error) => write!(f, "{}", error),
+            MarlinError::FiatShamirError(error) => write!(f, "{}", error),
+            MarlinError::R1CSError(error) => write!(f, "{}", error),
+            MarlinError::PolynomialCommitmentError(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for MarlinError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MarlinError::IndexTooLarge(..) => None,
+            MarlinError::AHPError(error) => Some(error),
+            MarlinError::FiatShamirError(error) => Some(error),
+            MarlinError::R1CSError(error) => Some(error),
+            MarlinError::PolynomialCommitmentError(error) => Some(error),
+        }
+    }
+}
+
+impl<E: fmt::Display> From<MarlinError<E>> for SNARKError {
     fn from(error: MarlinError<E>) -> Self {
-        SNARKError::Crate("marlin", format!("{:?}", error))
+        match error {
+            MarlinError::R1CSError(error)
+            | MarlinError::AHPError(crate::ahp::AHPError::ConstraintSystemError(error)) => {
+                SNARKError::SynthesisError(error)
+            }
+            error => SNARKError::Crate("marlin", error.to_string()),
+        }
     }
 }
@@ -17,7 +17,16 @@
 use crate::{
     ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
     fiat_shamir::traits::FiatShamirRng,
-    marlin::{compute_vk_hash, CircuitProvingKey, CircuitVerifyingKey, MarlinError, MarlinMode, Proof, UniversalSRS},
+    marlin::{
+        compute_vk_hash,
+        CircuitProvingKey,
+        CircuitVerifyingKey,
+        MarlinError,
+        MarlinMode,
+        MarlinProvingContext,
+        Proof,
+        UniversalSRS,
+    },
 };
 use snarkvm_algorithms::fft::EvaluationDomain;
 use snarkvm_fields::{PrimeField, ToConstraintField};
@@ -244,34 +253,116 @@ where
         circuit_proving_key: &CircuitProvingKey<TargetField, PC>,
         circuit: &C,
         zk_rng: &mut R,
+    ) -> Result<Proof<TargetField, PC>, MarlinError<PC::Error>> {
+        Self::prove_with_domain(circuit_proving_key, circuit, None, zk_rng)
+    }
+
+    /// Create a zkSNARK asserting that the constraint system is satisfied,
+    /// with the Fiat-Shamir transcript separated by the given domain tag, if any.
+    pub fn prove_with_domain<C: ConstraintSynthesizer<TargetField>, R: RngCore>(
+        circuit_proving_key: &CircuitProvingKey<TargetField, PC>,
+        circuit: &C,
+        domain: Option<&[u8]>,
+        zk_rng: &mut R,
+    ) -> Result<Proof<TargetField, PC>, MarlinError<PC::Error>> {
+        let proving_context = Self::proving_context(circuit_proving_key)?;
+        Self::prove_with_context_and_domain(&proving_context, circuit, domain, zk_rng)
+    }
+
+    /// Computes the parts of proving that depend only on the circuit, so that repeated proofs of
+    /// the same circuit with `prove_with_context` do not recompute them.
+    #[allow(clippy::type_complexity)]
+    pub fn proving_context(
+        circuit_proving_key: &CircuitProvingKey<TargetField, PC>,
+    ) -> Result<MarlinProvingContext<'_, TargetField, BaseField, PC, FS, MM>, MarlinError<PC::Error>> {
+        let context_time = start_timer!(|| "Marlin::ProvingContext");
+
+        let precomputation = AHPForR1CS::prover_precompute(&circuit_proving_key.circuit)?;
+
+        let (circuit_verifying_key_hash, circuit_verifying_key_bytes, vanishing_polys) = if MM::RECURSION {
+            let circuit_verifying_key_hash =
+                compute_vk_hash::<TargetField, BaseField, PC, FS>(&circuit_proving_key.circuit_verifying_key)?;
+
+            let vanishing_polys = vec![
+                LabeledPolynomial::new(
+                    "vanishing_poly_h".to_string(),
+                    precomputation.domain_h().vanishing_polynomial().into(),
+                    None,
+                    None,
+                ),
+                LabeledPolynomial::new(
+                    "vanishing_poly_k".to_string(),
+                    precomputation.domain_k().vanishing_polynomial().into(),
+                    None,
+                    None,
+                ),
+            ];
+
+            (circuit_verifying_key_hash, vec![], vanishing_polys)
+        } else {
+            let circuit_verifying_key_bytes =
+                to_bytes![&Self::PROTOCOL_NAME, &circuit_proving_key.circuit_verifying_key].unwrap();
+
+            (vec![], circuit_verifying_key_bytes, vec![])
+        };
+
+        end_timer!(context_time);
+
+        Ok(MarlinProvingContext {
+            circuit_proving_key,
+            precomputation,
+            circuit_verifying_key_hash,
+            circuit_verifying_key_bytes,
+            vanishing_polys,
+            _mode: PhantomData,
+        })
+    }
+
+    /// Create a zkSNARK asserting that the constraint system is satisfied, reusing the given
+    /// proving context of the circuit. The proof is the same as one created by `prove`.
+    pub fn prove_with_context<C: ConstraintSynthesizer<TargetField>, R: RngCore>(
+        proving_context: &MarlinProvingContext<'_, TargetField, BaseField, PC, FS, MM>,
+        circuit: &C,
+        zk_rng: &mut R,
+    ) -> Result<Proof<TargetField, PC>, MarlinError<PC::Error>> {
+        Self::prove_with_context_and_domain(proving_context, circuit, None, zk_rng)
+    }
+
+    /// Create a zkSNARK asserting that the constraint system is satisfied, reusing the given
+    /// proving context of the circuit, with the Fiat-Shamir transcript separated by the given
+    /// domain tag, if any.
+    pub fn prove_with_context_and_domain<C: ConstraintSynthesizer<TargetField>, R: RngCore>(
+        proving_context: &MarlinProvingContext<'_, TargetField, BaseField, PC, FS, MM>,
+        circuit: &C,
+        domain: Option<&[u8]>,
+        zk_rng: &mut R,
     ) -> Result<Proof<TargetField, PC>, MarlinError<PC::Error>> {
         let prover_time = start_timer!(|| "Marlin::Prover");
         // TODO: Add check that c is in the correct mode.
 
         let is_recursion = MM::RECURSION;
 
-        let prover_init_state = AHPForR1CS::prover_init(&circuit_proving_key.circuit, circuit)?;
+        let circuit_proving_key = proving_context.circuit_proving_key;
+
+        let prover_init_state = AHPForR1CS::prover_init_with_precomputation(
+            &circuit_proving_key.circuit,
+            &proving_context.precomputation,
+            circuit,
+        )?;
         let public_input = prover_init_state.public_input();
 
-        let mut fs_rng = FS::new();
+        let mut fs_rng = Self::init_fs_rng(domain);
 
         let hiding = !is_recursion;
 
         if is_recursion {
             fs_rng.absorb_bytes(&to_bytes![&Self::PROTOCOL_NAME].unwrap());
-            fs_rng.absorb_native_field_elements(&compute_vk_hash::<TargetField, BaseField, PC, FS>(
-                &circuit_proving_key.circuit_verifying_key,
-            )?);
+            fs_rng.absorb_native_field_elements(&proving_context.circuit_verifying_key_hash);
             fs_rng.absorb_nonnative_field_elements(&public_input, OptimizationType::Weight);
         } else {
-            fs_rng.absorb_bytes(
-                &to_bytes![
-                    &Self::PROTOCOL_NAME,
-                    &circuit_proving_key.circuit_verifying_key,
-                    &public_input
-                ]
-                .unwrap(),
-            );
+            let mut transcript_bytes = proving_context.circuit_verifying_key_bytes.clone();
+            transcript_bytes.extend(to_bytes![&public_input].unwrap());
+            fs_rng.absorb_bytes(&transcript_bytes);
         }
 
         // --------------------------------------------------------------------
@@ -355,35 +446,11 @@ where
         let verifier_state = AHPForR1CS::verifier_third_round(verifier_state, &mut fs_rng)?;
         // --------------------------------------------------------------------
 
-        let vanishing_polys = if is_recursion {
-            let domain_h = EvaluationDomain::new(circuit_proving_key.circuit.index_info.num_constraints)
-                .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-            let domain_k = EvaluationDomain::new(circuit_proving_key.circuit.index_info.num_non_zero)
-                .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-
-            vec![
-                LabeledPolynomial::new(
-                    "vanishing_poly_h".to_string(),
-                    domain_h.vanishing_polynomial().into(),
-                    None,
-                    None,
-                ),
-                LabeledPolynomial::new(
-                    "vanishing_poly_k".to_string(),
-                    domain_k.vanishing_polynomial().into(),
-                    None,
-                    None,
-                ),
-            ]
-        } else {
-            vec![]
-        };
-
         // Gather prover polynomials in one vector.
         let polynomials: Vec<_> = circuit_proving_key
             .circuit
             .iter() // 12 items
-            .chain(vanishing_polys.iter()) // 0 or 2 items
+            .chain(proving_context.vanishing_polys.iter()) // 0 or 2 items
             .chain(prover_first_oracles.iter()) // 4 items
             .chain(prover_second_oracles.iter())// 3 items
             .chain(prover_third_oracles.iter())// 2 items
@@ -510,6 +577,18 @@ where
         circuit_verifying_key: &CircuitVerifyingKey<TargetField, PC>,
         public_input: &[TargetField],
         proof: &Proof<TargetField, PC>,
+    ) -> Result<bool, MarlinError<PC::Error>> {
+        Self::verify_with_domain(circuit_verifying_key, public_input, proof, None)
+    }
+
+    /// Verify that a proof for the constraint system defined by `C` asserts that
+    /// all constraints are satisfied, with the Fiat-Shamir transcript separated by
+    /// the given domain tag, if any.
+    pub fn verify_with_domain(
+        circuit_verifying_key: &CircuitVerifyingKey<TargetField, PC>,
+        public_input: &[TargetField],
+        proof: &Proof<TargetField, PC>,
+        domain: Option<&[u8]>,
     ) -> Result<bool, MarlinError<PC::Error>> {
         let verifier_time = start_timer!(|| "Marlin::Verify");
 
@@ -535,7 +614,7 @@ where
 
         let is_recursion = MM::RECURSION;
 
-        let mut fs_rng = FS::new();
+        let mut fs_rng = Self::init_fs_rng(domain);
 
         if is_recursion {
             fs_rng.absorb_bytes(&to_bytes![&Self::PROTOCOL_NAME].unwrap());
@@ -714,4 +793,12 @@ where
     ) -> Result<bool, MarlinError<PC::Error>> {
         Self::verify(&prepared_vk.orig_vk, public_input, proof)
     }
+
+    /// Initializes the Fiat-Shamir RNG, separated by the given domain tag, if any.
+    fn init_fs_rng(domain: Option<&[u8]>) -> FS {
+        match domain {
+            Some(tag) => FS::with_domain(tag),
+            None => FS::new(),
+        }
+    }
 }
@@ -42,6 +42,10 @@ pub use prepared_circuit_verifying_key::*;
 mod proof;
 pub use proof::*;
 
+/// The Marlin proving context.
+mod proving_context;
+pub use proving_context::*;
+
 #[cfg(test)]
 pub mod tests;
 
@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    ahp::prover::ProverPrecomputation,
+    fiat_shamir::FiatShamirRng,
+    marlin::{CircuitProvingKey, MarlinMode},
+    Vec,
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_polycommit::{LabeledPolynomial, PolynomialCommitment};
+
+use core::marker::PhantomData;
+
+/// A circuit proving key, together with the parts of proving that depend only on the circuit,
+/// which are computed once by `MarlinSNARK::proving_context` and reused by every call to
+/// `MarlinSNARK::prove_with_context`.
+pub struct MarlinProvingContext<
+    'a,
+    TargetField: PrimeField,
+    BaseField: PrimeField,
+    PC: PolynomialCommitment<TargetField>,
+    FS: FiatShamirRng<TargetField, BaseField>,
+    MM: MarlinMode,
+> {
+    /// The circuit proving key.
+    pub(crate) circuit_proving_key: &'a CircuitProvingKey<TargetField, PC>,
+    /// The domains and the elements of domain H of the index.
+    pub(crate) precomputation: ProverPrecomputation<TargetField>,
+    /// The hash of the circuit verifying key, absorbed by the Fiat-Shamir RNG in recursion mode.
+    pub(crate) circuit_verifying_key_hash: Vec<BaseField>,
+    /// The protocol name and the circuit verifying key as bytes, absorbed by the Fiat-Shamir RNG
+    /// before the public input outside of recursion mode.
+    pub(crate) circuit_verifying_key_bytes: Vec<u8>,
+    /// The vanishing polynomials of domains H and K, which are opened in recursion mode.
+    pub(crate) vanishing_polys: Vec<LabeledPolynomial<TargetField>>,
+    #[doc(hidden)]
+    pub(crate) _mode: PhantomData<(FS, MM)>,
+}
+
+impl<
+    'a,
+    TargetField: PrimeField,
+    BaseField: PrimeField,
+    PC: PolynomialCommitment<TargetField>,
+    FS: FiatShamirRng<TargetField, BaseField>,
+    MM: MarlinMode,
+> MarlinProvingContext<'a, TargetField, BaseField, PC, FS, MM>
+{
+    /// Returns the circuit proving key of this context.
+    pub fn circuit_proving_key(&self) -> &'a CircuitProvingKey<TargetField, PC> {
+        self.circuit_proving_key
+    }
+}
@@ -77,7 +77,11 @@ mod marlin {
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_polycommit::{marlin_pc::MarlinKZG10, sonic_pc::SonicKZG10};
-    use snarkvm_utilities::rand::{test_rng, UniformRand};
+    use snarkvm_utilities::{
+        bytes::ToBytes,
+        rand::{test_rng, UniformRand},
+        to_bytes,
+    };
 
     use blake2::Blake2s;
     use core::ops::MulAssign;
@@ -175,6 +179,48 @@ mod marlin {
         MarlinPCTest::test_circuit(num_constraints, num_variables);
         SonicPCTest::test_circuit(num_constraints, num_variables);
     }
+
+    #[test]
+    fn prove_with_context_matches_prove() {
+        let rng = &mut test_rng();
+
+        let universal_srs = MarlinInst::universal_setup(100, 25, 100, rng).unwrap();
+
+        let setup_circuit = Circuit {
+            a: Some(Fr::rand(rng)),
+            b: Some(Fr::rand(rng)),
+            num_constraints: 25,
+            num_variables: 25,
+        };
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &setup_circuit).unwrap();
+        let proving_context = MarlinInst::proving_context(&index_pk).unwrap();
+
+        for _ in 0..3 {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let mut c = a;
+            c.mul_assign(&b);
+            let mut d = c;
+            d.mul_assign(&b);
+
+            let circuit = Circuit {
+                a: Some(a),
+                b: Some(b),
+                num_constraints: 25,
+                num_variables: 25,
+            };
+
+            // With the same randomness, both paths create the same proof.
+            let mut context_rng = rng.clone();
+            let proof = MarlinInst::prove(&index_pk, &circuit, rng).unwrap();
+            let context_proof = MarlinInst::prove_with_context(&proving_context, &circuit, &mut context_rng).unwrap();
+            assert_eq!(to_bytes![proof].unwrap(), to_bytes![context_proof].unwrap());
+
+            assert!(MarlinInst::verify(&index_vk, &[c, d], &proof).unwrap());
+            assert!(MarlinInst::verify(&index_vk, &[c, d], &context_proof).unwrap());
+            assert!(!MarlinInst::verify(&index_vk, &[a, a], &context_proof).unwrap());
+        }
+    }
 }
 
 mod marlin_recursion {
@@ -185,7 +231,11 @@ mod marlin_recursion {
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_polycommit::marlin_pc::MarlinKZG10;
-    use snarkvm_utilities::rand::{test_rng, UniformRand};
+    use snarkvm_utilities::{
+        bytes::ToBytes,
+        rand::{test_rng, UniformRand},
+        to_bytes,
+    };
 
     use core::ops::MulAssign;
 
@@ -266,6 +316,86 @@ mod marlin_recursion {
         test_circuit(num_constraints, num_variables);
     }
 
+    #[test]
+    fn prove_and_verify_with_domain() {
+        let rng = &mut test_rng();
+
+        let universal_srs = MarlinInst::universal_setup(100, 25, 100, rng).unwrap();
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let mut d = c;
+        d.mul_assign(&b);
+
+        let circuit = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 25,
+            num_variables: 25,
+        };
+
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        let domain = Some(&b"MARLIN-TEST-DOMAIN"[..]);
+        let proof = MarlinInst::prove_with_domain(&index_pk, &circuit, domain, rng).unwrap();
+
+        assert!(MarlinInst::verify_with_domain(&index_vk, &[c, d], &proof, domain).unwrap());
+
+        println!("\nShould not verify (i.e. verifier messages should print below):");
+        assert!(!MarlinInst::verify_with_domain(&index_vk, &[c, d], &proof, Some(b"MARLIN-OTHER-DOMAIN")).unwrap());
+        assert!(!MarlinInst::verify(&index_vk, &[c, d], &proof).unwrap());
+    }
+
+    #[test]
+    fn prove_with_context_matches_prove() {
+        let rng = &mut test_rng();
+
+        let universal_srs = MarlinInst::universal_setup(100, 25, 100, rng).unwrap();
+
+        let setup_circuit = Circuit {
+            a: Some(Fr::rand(rng)),
+            b: Some(Fr::rand(rng)),
+            num_constraints: 25,
+            num_variables: 25,
+        };
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &setup_circuit).unwrap();
+        let proving_context = MarlinInst::proving_context(&index_pk).unwrap();
+
+        let domain = Some(&b"MARLIN-TEST-DOMAIN"[..]);
+
+        for _ in 0..3 {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let mut c = a;
+            c.mul_assign(&b);
+            let mut d = c;
+            d.mul_assign(&b);
+
+            let circuit = Circuit {
+                a: Some(a),
+                b: Some(b),
+                num_constraints: 25,
+                num_variables: 25,
+            };
+
+            // With the same randomness, both paths create the same proof.
+            let mut context_rng = rng.clone();
+            let proof = MarlinInst::prove(&index_pk, &circuit, rng).unwrap();
+            let context_proof = MarlinInst::prove_with_context(&proving_context, &circuit, &mut context_rng).unwrap();
+            assert_eq!(to_bytes![proof].unwrap(), to_bytes![context_proof].unwrap());
+
+            assert!(MarlinInst::verify(&index_vk, &[c, d], &proof).unwrap());
+            assert!(MarlinInst::verify(&index_vk, &[c, d], &context_proof).unwrap());
+            assert!(!MarlinInst::verify(&index_vk, &[a, a], &context_proof).unwrap());
+
+            let proof = MarlinInst::prove_with_context_and_domain(&proving_context, &circuit, domain, rng).unwrap();
+            assert!(MarlinInst::verify_with_domain(&index_vk, &[c, d], &proof, domain).unwrap());
+            assert!(!MarlinInst::verify(&index_vk, &[c, d], &proof).unwrap());
+        }
+    }
+
     // #[test]
     // /// Test on a constraint system that will trigger outlining.
     // fn prove_and_test_outlining() {
@@ -86,6 +86,7 @@ where
     type AllocatedCircuit = C;
     type Circuit = (C, SRS<E>);
     // Abuse the Circuit type to pass the SRS as well.
+    type PreparedProvingKey = Parameters<E>;
     type PreparedVerifyingKey = VerifyingKey<E>;
     type Proof = Proof<<E as PairingEngine>::Fr, MultiPC<E>>;
     type ProvingKey = Parameters<E>;
@@ -110,12 +111,24 @@ where
         rng: &mut R,
     ) -> Result<Self::Proof, SNARKError> {
         let proving_time = start_timer!(|| "{Marlin}::Proving");
-        let proof = MarlinTestnet1::<E>::prove(&proving_key.proving_key, input_and_witness, rng)
-            .map_err(|error| SNARKError::Crate("marlin", format!("Failed to generate proof - {:?}", error)))?;
+        let proof =
+            MarlinTestnet1::<E>::prove(&proving_key.proving_key, input_and_witness, rng).map_err(SNARKError::from)?;
         end_timer!(proving_time);
         Ok(proof)
     }
 
+    fn prepare_proving_key(proving_key: Self::ProvingKey) -> Result<Self::PreparedProvingKey, SNARKError> {
+        Ok(proving_key)
+    }
+
+    fn prove_with_prepared_key<R: RngCore>(
+        prepared_proving_key: &Self::PreparedProvingKey,
+        input_and_witness: &Self::AllocatedCircuit,
+        rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        Self::prove(prepared_proving_key, input_and_witness, rng)
+    }
+
     fn verify(
         verifying_key: &Self::PreparedVerifyingKey,
         input: &Self::VerifierInput,
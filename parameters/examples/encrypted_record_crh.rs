@@ -14,45 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_algorithms::{crh::sha256::sha256, errors::CRHError, traits::CRH};
-use snarkvm_dpc::{testnet1::instantiated::Components, traits::DPCComponents};
-use snarkvm_utilities::{bytes::ToBytes, to_bytes};
+use snarkvm_dpc::testnet1::parameters::GenerationConfig;
 
-use rand::thread_rng;
-use std::{
-    fs::{
-        File,
-        {self},
-    },
-    io::{Result as IoResult, Write},
-    path::PathBuf,
-};
-
-pub fn setup<C: DPCComponents>() -> Result<Vec<u8>, CRHError> {
-    let rng = &mut thread_rng();
-    let encrypted_record_crh = <C::EncryptedRecordCRH as CRH>::setup(rng);
-    let encrypted_record_crh_parameters = encrypted_record_crh.parameters();
-    let encrypted_record_crh_parameters_bytes = to_bytes![encrypted_record_crh_parameters]?;
-
-    let size = encrypted_record_crh_parameters_bytes.len();
-    println!("encrypted_record_crh.params\n\tsize - {}", size);
-    Ok(encrypted_record_crh_parameters_bytes)
-}
-
-pub fn store(file_path: &PathBuf, checksum_path: &PathBuf, bytes: &[u8]) -> IoResult<()> {
-    // Save checksum to file
-    fs::write(checksum_path, hex::encode(sha256(bytes)))?;
-
-    // Save buffer to file
-    let mut file = File::create(file_path)?;
-    file.write_all(&bytes)?;
-    drop(file);
-    Ok(())
-}
+mod utils;
+use utils::generate;
 
 pub fn main() {
-    let bytes = setup::<Components>().unwrap();
-    let filename = PathBuf::from("encrypted_record_crh.params");
-    let sumname = PathBuf::from("encrypted_record_crh.checksum");
-    store(&filename, &sumname, &bytes).unwrap();
+    generate(GenerationConfig {
+        encrypted_record_crh: true,
+        ..Default::default()
+    })
+    .unwrap();
 }
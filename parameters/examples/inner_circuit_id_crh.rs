@@ -14,30 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_algorithms::{errors::CRHError, traits::CRH};
-use snarkvm_dpc::{testnet1::instantiated::Components, traits::DPCComponents};
-use snarkvm_utilities::{bytes::ToBytes, to_bytes};
-
-use rand::thread_rng;
-use std::path::PathBuf;
+use snarkvm_dpc::testnet1::parameters::GenerationConfig;
 
 mod utils;
-use utils::store;
-
-pub fn setup<C: DPCComponents>() -> Result<Vec<u8>, CRHError> {
-    let rng = &mut thread_rng();
-    let inner_circuit_id_crh = <C::InnerCircuitIDCRH as CRH>::setup(rng);
-    let inner_circuit_id_crh_parameters = inner_circuit_id_crh.parameters();
-    let inner_circuit_id_crh_parameters_bytes = to_bytes![inner_circuit_id_crh_parameters]?;
-
-    let size = inner_circuit_id_crh_parameters_bytes.len();
-    println!("inner_circuit_id_crh.params\n\tsize - {}", size);
-    Ok(inner_circuit_id_crh_parameters_bytes)
-}
+use utils::generate;
 
 pub fn main() {
-    let bytes = setup::<Components>().unwrap();
-    let filename = PathBuf::from("inner_circuit_id_crh.params");
-    let sumname = PathBuf::from("inner_circuit_id_crh.checksum");
-    store(&filename, &sumname, &bytes).unwrap();
+    generate(GenerationConfig {
+        inner_circuit_id_crh: true,
+        ..Default::default()
+    })
+    .unwrap();
 }
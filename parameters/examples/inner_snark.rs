@@ -14,73 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_algorithms::{
-    crh::sha256::sha256,
-    traits::{MerkleParameters, SNARK},
-};
-use snarkvm_dpc::{
-    errors::DPCError,
-    testnet1::{
-        inner_circuit::InnerCircuit,
-        instantiated::Components,
-        parameters::SystemParameters,
-        BaseDPCComponents,
-    },
-};
-use snarkvm_parameters::{traits::Parameter, LedgerMerkleTreeParameters};
-use snarkvm_utilities::{
-    bytes::{FromBytes, ToBytes},
-    to_bytes,
-};
-
-use rand::thread_rng;
-use std::{path::PathBuf, sync::Arc};
+use snarkvm_dpc::testnet1::parameters::GenerationConfig;
 
 mod utils;
-use utils::store;
-
-pub fn setup<C: BaseDPCComponents>() -> Result<(Vec<u8>, Vec<u8>), DPCError> {
-    let rng = &mut thread_rng();
-
-    // TODO (howardwu): Resolve this inconsistency on import structure with a new model once MerkleParameters are refactored.
-    let merkle_tree_hash_parameters: <C::MerkleParameters as MerkleParameters>::H =
-        From::from(FromBytes::read(&LedgerMerkleTreeParameters::load_bytes()?[..])?);
-    let ledger_merkle_tree_parameters = Arc::new(From::from(merkle_tree_hash_parameters));
-
-    let system_parameters = SystemParameters::<C>::load()?;
-    let inner_snark_parameters = C::InnerSNARK::setup(
-        &InnerCircuit::blank(&system_parameters, &ledger_merkle_tree_parameters),
-        rng,
-    )?;
-    let inner_snark_pk = to_bytes![inner_snark_parameters.0]?;
-    let inner_snark_vk: <C::InnerSNARK as SNARK>::VerifyingKey = inner_snark_parameters.1.into();
-    let inner_snark_vk = to_bytes![inner_snark_vk]?;
-
-    println!("inner_snark_pk.params\n\tsize - {}", inner_snark_pk.len());
-    println!("inner_snark_vk.params\n\tsize - {}", inner_snark_vk.len());
-    Ok((inner_snark_pk, inner_snark_vk))
-}
-
-fn versioned_filename(checksum: &str) -> String {
-    match checksum.get(0..7) {
-        Some(sum) => format!("inner_snark_pk-{}.params", sum),
-        _ => "inner_snark_pk.params".to_string(),
-    }
-}
+use utils::generate;
 
 pub fn main() {
-    let (inner_snark_pk, inner_snark_vk) = setup::<Components>().unwrap();
-    let inner_snark_pk_checksum = hex::encode(sha256(&inner_snark_pk));
-    store(
-        &PathBuf::from(&versioned_filename(&inner_snark_pk_checksum)),
-        &PathBuf::from("inner_snark_pk.checksum"),
-        &inner_snark_pk,
-    )
-    .unwrap();
-    store(
-        &PathBuf::from("inner_snark_vk.params"),
-        &PathBuf::from("inner_snark_vk.checksum"),
-        &inner_snark_vk,
-    )
+    generate(GenerationConfig {
+        inner_snark: true,
+        ..Default::default()
+    })
     .unwrap();
 }
@@ -14,30 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_algorithms::{errors::MerkleError, traits::MerkleParameters};
-use snarkvm_dpc::testnet1::{instantiated::Components, BaseDPCComponents};
-use snarkvm_utilities::{bytes::ToBytes, to_bytes};
-
-use rand::thread_rng;
-use std::path::PathBuf;
+use snarkvm_dpc::testnet1::parameters::GenerationConfig;
 
 mod utils;
-use utils::store;
-
-pub fn setup<C: BaseDPCComponents>() -> Result<Vec<u8>, MerkleError> {
-    let rng = &mut thread_rng();
-
-    let ledger_merkle_tree_parameters = <C::MerkleParameters as MerkleParameters>::setup(rng);
-    let ledger_merkle_tree_parameters_bytes = to_bytes![ledger_merkle_tree_parameters.parameters()]?;
-
-    let size = ledger_merkle_tree_parameters_bytes.len();
-    println!("ledger_merkle_tree.params\n\tsize - {}", size);
-    Ok(ledger_merkle_tree_parameters_bytes)
-}
+use utils::generate;
 
 pub fn main() {
-    let bytes = setup::<Components>().unwrap();
-    let filename = PathBuf::from("ledger_merkle_tree.params");
-    let sumname = PathBuf::from("ledger_merkle_tree.checksum");
-    store(&filename, &sumname, &bytes).unwrap();
+    generate(GenerationConfig {
+        ledger_merkle_tree: true,
+        ..Default::default()
+    })
+    .unwrap();
 }
@@ -14,30 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_algorithms::{errors::CommitmentError, traits::CommitmentScheme};
-use snarkvm_dpc::{testnet1::instantiated::Components, traits::DPCComponents};
-use snarkvm_utilities::{bytes::ToBytes, to_bytes};
-
-use rand::thread_rng;
-use std::path::PathBuf;
+use snarkvm_dpc::testnet1::parameters::GenerationConfig;
 
 mod utils;
-use utils::store;
-
-pub fn setup<C: DPCComponents>() -> Result<Vec<u8>, CommitmentError> {
-    let rng = &mut thread_rng();
-    let record_commitment = <C::RecordCommitment as CommitmentScheme>::setup(rng);
-    let record_commitment_parameters = record_commitment.parameters();
-    let record_commitment_parameters_bytes = to_bytes![record_commitment_parameters]?;
-
-    let size = record_commitment_parameters_bytes.len();
-    println!("record_commitment.params\n\tsize - {}", size);
-    Ok(record_commitment_parameters_bytes)
-}
+use utils::generate;
 
 pub fn main() {
-    let bytes = setup::<Components>().unwrap();
-    let filename = PathBuf::from("record_commitment.params");
-    let sumname = PathBuf::from("record_commitment.checksum");
-    store(&filename, &sumname, &bytes).unwrap();
+    generate(GenerationConfig {
+        record_commitment: true,
+        ..Default::default()
+    })
+    .unwrap();
 }
@@ -15,16 +15,27 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_algorithms::crh::sha256::sha256;
+use snarkvm_dpc::{
+    errors::DPCError,
+    testnet1::{
+        instantiated::Components,
+        parameters::{generate_all, GenerationConfig},
+    },
+    Network,
+};
 
+use rand::thread_rng;
 use std::{
     fs::{
         File,
         {self},
     },
     io::{BufWriter, Result as IoResult, Write},
-    path::PathBuf,
+    path::{Path, PathBuf},
+    sync::Mutex,
 };
 
+#[allow(dead_code)]
 pub fn store(file_path: &PathBuf, checksum_path: &PathBuf, bytes: &[u8]) -> IoResult<()> {
     // Save checksum to file
     fs::write(checksum_path, hex::encode(sha256(bytes)))?;
@@ -35,3 +46,23 @@ pub fn store(file_path: &PathBuf, checksum_path: &PathBuf, bytes: &[u8]) -> IoRe
     drop(file);
     Ok(())
 }
+
+/// Regenerates the parameters selected by `config`, and writes them to the current directory.
+#[allow(dead_code)]
+pub fn generate(config: GenerationConfig) -> Result<(), DPCError> {
+    // Print the progress of each phase once per percent.
+    let last_reported = Mutex::new(None);
+    let generated = generate_all::<Components, _>(config, Network::Mainnet, &mut thread_rng(), |phase, fraction| {
+        let percent = (fraction * 100.0) as u32;
+        let mut last_reported = last_reported.lock().unwrap();
+        if *last_reported < Some((phase, percent)) {
+            println!("{:?} - {}%", phase, percent);
+            *last_reported = Some((phase, percent));
+        }
+    })?;
+
+    for (name, bytes) in generated.files() {
+        println!("{}.params\n\tsize - {}", name, bytes.len());
+    }
+    generated.write_to_dir(Path::new("."))
+}
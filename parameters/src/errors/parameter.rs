@@ -17,6 +17,7 @@
 use std::fmt::Debug;
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum ParameterError {
     #[error("expected checksum of {}, found checksum of {}", _0, _1)]
     ChecksumMismatch(String, String),
@@ -24,34 +25,25 @@ pub enum ParameterError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[cfg(any(test, feature = "remote"))]
+    #[error("remote fetch failed: {}", _0)]
+    CurlError(#[from] curl::Error),
+
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] std::io::Error),
+
     #[error("{}", _0)]
     Message(String),
 
     #[error("Remote fetch is disabled, enable compiler flag for feature")]
     RemoteFetchDisabled,
-}
-
-#[cfg(any(test, feature = "remote"))]
-impl From<curl::Error> for ParameterError {
-    fn from(error: curl::Error) -> Self {
-        ParameterError::Crate("curl::error", format!("{:?}", error))
-    }
-}
 
-impl From<std::io::Error> for ParameterError {
-    fn from(error: std::io::Error) -> Self {
-        ParameterError::Crate("std::io", format!("{:?}", error))
-    }
-}
-
-impl From<std::path::StripPrefixError> for ParameterError {
-    fn from(error: std::path::StripPrefixError) -> Self {
-        ParameterError::Crate("std::path", format!("{:?}", error))
-    }
+    #[error("invalid path prefix: {}", _0)]
+    StripPrefixError(#[from] std::path::StripPrefixError),
 }
 
 impl From<ParameterError> for std::io::Error {
     fn from(error: ParameterError) -> Self {
-        std::io::Error::new(std::io::ErrorKind::Other, format!("{:?}", error))
+        std::io::Error::new(std::io::ErrorKind::Other, error)
     }
 }
@@ -33,6 +33,14 @@ pub mod testnet1;
 pub mod traits;
 pub use traits::*;
 
+/// Registers `snarkvm-parameters` and the crates it depends on.
+pub fn register_capabilities(capabilities: &mut snarkvm_utilities::Capabilities) {
+    capabilities.register(snarkvm_utilities::CrateCapabilities::new("snarkvm-parameters", &[
+        ("remote", cfg!(feature = "remote")),
+    ]));
+    snarkvm_algorithms::register_capabilities(capabilities);
+}
+
 pub mod prelude {
     pub use crate::{errors::*, global::*, traits::*};
 }
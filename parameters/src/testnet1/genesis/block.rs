@@ -23,7 +23,7 @@ use snarkvm_utilities::variable_length_integer::variable_length_integer;
 pub struct GenesisBlock;
 
 impl Genesis for GenesisBlock {
-    const CHECKSUM: &'static str = "";
+    const CHECKSUM: &'static str = "78d2509c48e0525c49e2c08ca75254ff12a84ce3a603f57ec9104c4136d56624";
     const SIZE: u64 = 2627;
 
     fn load_bytes() -> Vec<u8> {
@@ -46,5 +46,6 @@ mod tests {
     fn test_genesis_block() {
         let block = GenesisBlock::load_bytes();
         assert_eq!(GenesisBlock::SIZE, block.len() as u64);
+        assert_eq!(GenesisBlock::CHECKSUM, checksum!(&block));
     }
 }
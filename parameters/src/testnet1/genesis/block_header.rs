@@ -19,7 +19,7 @@ use crate::traits::Genesis;
 pub struct GenesisBlockHeader;
 
 impl Genesis for GenesisBlockHeader {
-    const CHECKSUM: &'static str = "";
+    const CHECKSUM: &'static str = "bc25ecdd409d84e3ba82153db5406477cde0eb5bcc4c633fdd8db0b732a8c2e8";
     const SIZE: u64 = 1088;
 
     fn load_bytes() -> Vec<u8> {
@@ -36,5 +36,6 @@ mod tests {
     fn test_genesis_block_header() {
         let header = GenesisBlockHeader::load_bytes();
         assert_eq!(GenesisBlockHeader::SIZE, header.len() as u64);
+        assert_eq!(GenesisBlockHeader::CHECKSUM, checksum!(&header));
     }
 }
@@ -19,7 +19,7 @@ use crate::traits::Genesis;
 pub struct Transaction1;
 
 impl Genesis for Transaction1 {
-    const CHECKSUM: &'static str = "";
+    const CHECKSUM: &'static str = "82bd9ccbe99079d658a8bec9a12f53ccc4598eabe1a9849d75557731ff468c8f";
     const SIZE: u64 = 1538;
 
     fn load_bytes() -> Vec<u8> {
@@ -36,5 +36,6 @@ mod tests {
     fn test_transaction_1() {
         let parameters = Transaction1::load_bytes();
         assert_eq!(Transaction1::SIZE, parameters.len() as u64);
+        assert_eq!(Transaction1::CHECKSUM, checksum!(&parameters));
     }
 }
@@ -535,6 +535,15 @@ fn lc_query_set_to_poly_query_set<'a, F: 'a + Field>(
     poly_query_set
 }
 
+/// Registers `snarkvm-polycommit` and the crates it depends on.
+pub fn register_capabilities(capabilities: &mut snarkvm_utilities::Capabilities) {
+    capabilities.register(snarkvm_utilities::CrateCapabilities::new("snarkvm-polycommit", &[
+        ("std", cfg!(feature = "std")),
+        ("print-trace", cfg!(feature = "print-trace")),
+    ]));
+    snarkvm_gadgets::register_capabilities(capabilities);
+}
+
 #[cfg(test)]
 pub mod tests {
     use crate::*;
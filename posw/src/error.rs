@@ -28,6 +28,14 @@ pub enum PoswError {
     #[error("could not load PoSW parameters: {0}")]
     Parameters(#[from] ParameterError),
 
+    /// Thrown when a proof does not meet the difficulty target
+    #[error("the PoSW has difficulty {0}, which does not meet the target {1}")]
+    DifficultyTargetNotMet(u64, u64),
+
+    /// Thrown when a serialized proof has the wrong size
+    #[error("invalid PoSW of {0} bytes, expected {1} bytes")]
+    InvalidProofSize(usize, usize),
+
     /// Thrown when a proof fails verification
     #[error("could not verify PoSW")]
     PoswVerificationFailed,
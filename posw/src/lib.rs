@@ -20,16 +20,21 @@ mod posw;
 use posw::{HG, M};
 
 pub mod error;
+use error::PoswError;
 
-use snarkvm_algorithms::snark;
+use snarkvm_algorithms::{snark, traits::SNARK};
 use snarkvm_curves::{bls12_377::Bls12_377, traits::PairingEngine};
 use snarkvm_dpc::block::{
     merkle_root_with_subroots,
     pedersen_merkle_root,
+    BlockHeader,
     MerkleRootHash,
     PedersenMerkleRootHash,
+    ProofOfSuccinctWork,
     MASKED_TREE_DEPTH,
 };
+use snarkvm_utilities::bytes::FromBytes;
+pub use snarkvm_dpc::block::{proof_to_difficulty_hash, DifficultyTarget};
 
 /// PoSW instantiated over BLS12-377 with GM17.
 pub type PoswGM17 = Posw<GM17<Bls12_377>, Bls12_377>;
@@ -67,18 +72,68 @@ pub fn txids_to_roots(transaction_ids: &[[u8; 32]]) -> (MerkleRootHash, Pedersen
     merkle_root_bytes[..].copy_from_slice(&root);
 
     (
-        MerkleRootHash(merkle_root_bytes),
+        MerkleRootHash::new(merkle_root_bytes),
         pedersen_merkle_root(&subroots),
         subroots,
     )
 }
 
+/// Checked deserialization and full verification of a `ProofOfSuccinctWork` produced by `PoswMarlin`.
+pub trait ProofOfSuccinctWorkExt: Sized {
+    /// Reads a proof from its serialized bytes, and checks that it has the Marlin proof size
+    /// and that its commitments are valid curve points.
+    fn from_bytes_checked(bytes: &[u8]) -> Result<Self, PoswError>;
+
+    /// Verifies the proof for the nonce and the pedersen merkle root hash of the given block header,
+    /// and checks that the difficulty of the proof meets the difficulty target.
+    fn verify(&self, header: &BlockHeader, difficulty_target: u64, posw: &PoswMarlin) -> Result<(), PoswError>;
+}
+
+impl ProofOfSuccinctWorkExt for ProofOfSuccinctWork {
+    fn from_bytes_checked(bytes: &[u8]) -> Result<Self, PoswError> {
+        if bytes.len() != ProofOfSuccinctWork::size() {
+            return Err(PoswError::InvalidProofSize(bytes.len(), ProofOfSuccinctWork::size()));
+        }
+
+        // Deserializing the proof checks that its commitments are in the prime order subgroup.
+        <Marlin<Bls12_377> as SNARK>::Proof::read(bytes)?;
+
+        Ok(ProofOfSuccinctWork::from(bytes))
+    }
+
+    fn verify(&self, header: &BlockHeader, difficulty_target: u64, posw: &PoswMarlin) -> Result<(), PoswError> {
+        // Check the difficulty first, as it is much cheaper than the SNARK verification.
+        let difficulty_hash = proof_to_difficulty_hash(&self.0);
+        if !DifficultyTarget(difficulty_target).meets(&difficulty_hash) {
+            return Err(PoswError::DifficultyTargetNotMet(
+                DifficultyTarget::difficulty_of(&difficulty_hash),
+                difficulty_target,
+            ));
+        }
+
+        // The mask is derived from the nonce and the pedersen merkle root hash of the header.
+        let proof = <Marlin<Bls12_377> as SNARK>::Proof::read(&self.0[..])?;
+        posw.verify(header.nonce, &proof, &header.pedersen_merkle_root_hash)
+    }
+}
+
+/// Registers `snarkvm-posw` and the crates it depends on.
+pub fn register_capabilities(capabilities: &mut snarkvm_utilities::Capabilities) {
+    capabilities.register(snarkvm_utilities::CrateCapabilities::new("snarkvm-posw", &[
+        ("print-trace", cfg!(feature = "print-trace")),
+        ("test-helpers", cfg!(feature = "test-helpers")),
+    ]));
+    snarkvm_dpc::register_capabilities(capabilities);
+    snarkvm_marlin::register_capabilities(capabilities);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rand::SeedableRng;
     use rand_xorshift::XorShiftRng;
     use snarkvm_algorithms::traits::SNARK;
+    use snarkvm_dpc::block::{BlockHeader, BlockHeaderHash, PoswVerifier, ProofOfSuccinctWork};
     use snarkvm_utilities::bytes::FromBytes;
 
     #[test]
@@ -137,7 +192,54 @@ mod tests {
 
         assert_eq!(proof.len(), 972); // NOTE: Marlin proofs use compressed serialization
 
+        let posw_proof = ProofOfSuccinctWork::from(&proof[..]);
         let proof = <Marlin<Bls12_377> as SNARK>::Proof::read(&proof[..]).unwrap();
         posw.verify(nonce, &proof, &pedersen_merkle_root).unwrap();
+
+        // The block verifier agrees, and rejects the proof for any other nonce.
+        assert!(posw.verify_proof(nonce, &posw_proof, &pedersen_merkle_root));
+        assert!(!posw.verify_proof(nonce.wrapping_add(1), &posw_proof, &pedersen_merkle_root));
+    }
+
+    #[test]
+    fn test_mining_and_validation_agree_on_difficulty() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567);
+
+        let universal_srs = snarkvm_marlin::MarlinTestnet1::universal_setup(10000, 10000, 100000, rng).unwrap();
+        let posw = PoswMarlin::index(universal_srs).unwrap();
+
+        // Roughly one in four proofs meets this target, so mining has to reject some proofs.
+        let difficulty_target = u64::MAX / 4;
+
+        let transaction_ids = vec![[1u8; 32]; 8];
+        let (_, pedersen_merkle_root, subroots) = txids_to_roots(&transaction_ids);
+        let (nonce, proof) = posw.mine(&subroots, difficulty_target, rng, std::u32::MAX).unwrap();
+
+        let proof = ProofOfSuccinctWork::from(&proof[..]);
+        let difficulty = DifficultyTarget::difficulty_of(&proof_to_difficulty_hash(&proof.0));
+        assert!(difficulty <= difficulty_target);
+
+        // The mined proof meets the target it was mined for, and exactly the targets at or above its difficulty.
+        for (target, meets) in &[
+            (difficulty_target, true),
+            (difficulty, true),
+            (difficulty.saturating_add(1), true),
+            (difficulty.wrapping_sub(1), difficulty == 0),
+        ] {
+            let header = BlockHeader {
+                previous_block_hash: BlockHeaderHash::zero(),
+                merkle_root_hash: MerkleRootHash::zero(),
+                pedersen_merkle_root_hash: pedersen_merkle_root.clone(),
+                proof: proof.clone(),
+                time: 0,
+                difficulty_target: *target,
+                nonce,
+            };
+
+            assert_eq!(DifficultyTarget(*target).meets(&proof_to_difficulty_hash(&proof.0)), *meets);
+            assert_eq!(posw::check_difficulty(&proof.0, *target), *meets);
+            assert_eq!(header.meets_difficulty_target(), *meets);
+            assert_eq!(header.to_difficulty_hash(), difficulty);
+        }
     }
 }
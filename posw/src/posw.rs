@@ -20,10 +20,7 @@ use crate::{
     circuit::{POSWCircuit, POSWCircuitParameters},
     error::PoswError,
 };
-use snarkvm_algorithms::{
-    crh::sha256d_to_u64,
-    traits::{MaskedMerkleParameters, SNARK},
-};
+use snarkvm_algorithms::traits::{MaskedMerkleParameters, SNARK};
 use snarkvm_curves::{
     bls12_377::Fr,
     edwards_bls12::{EdwardsProjective, Fq},
@@ -31,7 +28,11 @@ use snarkvm_curves::{
 };
 use snarkvm_dpc::block::{
     pedersen_merkle_tree::{pedersen_merkle_root_hash_with_leaves, PedersenMerkleRootHash, PARAMS},
+    proof_to_difficulty_hash,
+    DifficultyTarget,
     MaskedMerkleTreeParameters,
+    PoswVerifier,
+    ProofOfSuccinctWork,
 };
 use snarkvm_fields::{PrimeField, ToConstraintField};
 use snarkvm_gadgets::{
@@ -59,10 +60,15 @@ use std::marker::PhantomData;
 pub fn commit(nonce: u32, root: &PedersenMerkleRootHash) -> Vec<u8> {
     let mut h = Blake2s::new();
     h.update(&nonce.to_le_bytes());
-    h.update(root.0.as_ref());
+    h.update(root.as_ref());
     h.finalize().to_vec()
 }
 
+/// Hashes the serialized proof and checks it against the difficulty target
+pub(crate) fn check_difficulty(proof: &[u8], difficulty_target: u64) -> bool {
+    DifficultyTarget(difficulty_target).meets(&proof_to_difficulty_hash(proof))
+}
+
 // We need to instantiate the Merkle tree and the Gadget, but these should not be
 // proving system specific
 pub type M = MaskedMerkleTreeParameters;
@@ -138,12 +144,6 @@ where
             circuit_parameters_type: PhantomData,
         }
     }
-
-    /// Hashes the proof and checks it against the difficulty
-    fn check_difficulty(&self, proof: &[u8], difficulty_target: u64) -> bool {
-        let hash_result = sha256d_to_u64(proof);
-        hash_result <= difficulty_target
-    }
 }
 
 impl<S, CP> Posw<S, F, M, HG, CP>
@@ -233,7 +233,7 @@ where
             proof = Self::prove(&pk, nonce, subroots, rng)?;
 
             serialized_proof = to_bytes!(proof)?;
-            if self.check_difficulty(&serialized_proof, difficulty_target) {
+            if check_difficulty(&serialized_proof, difficulty_target) {
                 break;
             }
         }
@@ -273,7 +273,7 @@ where
         let mask = commit(nonce, pedersen_merkle_root);
 
         // get the mask and the root in public inputs format
-        let merkle_root = F::read(&pedersen_merkle_root.0[..])?;
+        let merkle_root = F::read(pedersen_merkle_root.as_ref())?;
         let inputs = [mask.to_field_elements()?, vec![merkle_root]].concat();
 
         let res = S::verify(&self.vk, &inputs, &proof)?;
@@ -284,3 +284,21 @@ where
         Ok(())
     }
 }
+
+impl<S, CP> PoswVerifier for Posw<S, F, M, HG, CP>
+where
+    S: SNARK<VerifierInput = Vec<F>, AllocatedCircuit = POSWCircuit<F, M, HG, CP>>,
+    CP: POSWCircuitParameters,
+{
+    fn verify_proof(
+        &self,
+        nonce: u32,
+        proof: &ProofOfSuccinctWork,
+        pedersen_merkle_root: &PedersenMerkleRootHash,
+    ) -> bool {
+        match S::Proof::read(&proof.0[..]) {
+            Ok(proof) => self.verify(nonce, &proof, pedersen_merkle_root).is_ok(),
+            Err(_) => false,
+        }
+    }
+}
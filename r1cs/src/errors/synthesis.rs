@@ -21,6 +21,7 @@ pub type SynthesisResult<T> = Result<T, SynthesisError>;
 /// This is an error that could occur during circuit synthesis contexts,
 /// such as CRS generation, proving or verification.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum SynthesisError {
     /// During synthesis, we lacked knowledge of a variable assignment.
     AssignmentMissing,
@@ -36,6 +37,8 @@ pub enum SynthesisError {
     IoError(io::Error),
     /// During verification, our verifying key was malformed.
     MalformedVerifyingKey,
+    /// During proof generation, our proving key was malformed.
+    MalformedProvingKey,
     /// During CRS generation, we observed an unconstrained auxiliary variable
     UnconstrainedVariable,
 }
@@ -47,27 +50,26 @@ impl From<io::Error> for SynthesisError {
 }
 
 impl Error for SynthesisError {
-    fn description(&self) -> &str {
-        match *self {
-            SynthesisError::AssignmentMissing => "an assignment for a variable could not be computed",
-            SynthesisError::DivisionByZero => "division by zero",
-            SynthesisError::Unsatisfiable => "unsatisfiable constraint system",
-            SynthesisError::PolynomialDegreeTooLarge => "polynomial degree is too large",
-            SynthesisError::UnexpectedIdentity => "encountered an identity element in the CRS",
-            SynthesisError::IoError(_) => "encountered an I/O error",
-            SynthesisError::MalformedVerifyingKey => "malformed verifying key",
-            SynthesisError::UnconstrainedVariable => "auxiliary variable was unconstrained",
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SynthesisError::IoError(error) => Some(error),
+            _ => None,
         }
     }
 }
 
 impl fmt::Display for SynthesisError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
-        if let SynthesisError::IoError(ref e) = *self {
-            write!(f, "I/O error: ")?;
-            e.fmt(f)
-        } else {
-            write!(f, "{:?}", self)
+        match self {
+            SynthesisError::AssignmentMissing => write!(f, "an assignment for a variable could not be computed"),
+            SynthesisError::DivisionByZero => write!(f, "division by zero"),
+            SynthesisError::Unsatisfiable => write!(f, "unsatisfiable constraint system"),
+            SynthesisError::PolynomialDegreeTooLarge => write!(f, "polynomial degree is too large"),
+            SynthesisError::UnexpectedIdentity => write!(f, "encountered an identity element in the CRS"),
+            SynthesisError::IoError(error) => write!(f, "I/O error: {}", error),
+            SynthesisError::MalformedVerifyingKey => write!(f, "malformed verifying key"),
+            SynthesisError::MalformedProvingKey => write!(f, "malformed proving key"),
+            SynthesisError::UnconstrainedVariable => write!(f, "auxiliary variable was unconstrained"),
         }
     }
 }
@@ -38,6 +38,12 @@ pub use namespace::*;
 mod optional_vec;
 pub use optional_vec::*;
 
+mod packed_assignment;
+pub use packed_assignment::*;
+
+mod test_constraint_checker;
+pub use test_constraint_checker::TestConstraintChecker;
+
 mod test_constraint_system;
 pub use test_constraint_system::TestConstraintSystem;
 
@@ -141,6 +147,12 @@ impl CanonicalDeserialize for Index {
     }
 }
 
+/// Registers `snarkvm-r1cs` and the crates it depends on.
+pub fn register_capabilities(capabilities: &mut snarkvm_utilities::Capabilities) {
+    capabilities.register(snarkvm_utilities::CrateCapabilities::new("snarkvm-r1cs", &[]));
+    snarkvm_curves::register_capabilities(capabilities);
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::Field;
+
+use std::mem::size_of;
+
+const BLOCK_SIZE: usize = 64;
+
+// A list of variable assignments that stores the values zero and one as single bits;
+// any other value is escaped into a separate list of field elements. Circuits consisting
+// mostly of Booleans thus use a fraction of the memory of a plain vector of field elements.
+#[derive(Clone, Debug)]
+pub struct PackedAssignment<F: Field> {
+    // one bit per value, set if the value is one
+    bits: Vec<u64>,
+    // one bit per value, set if the value is stored in the escaped list
+    escape_flags: Vec<u64>,
+    // the number of escaped values preceding each block of values
+    escape_ranks: Vec<usize>,
+    // the values that are neither zero nor one, in order of insertion
+    escaped: Vec<F>,
+    // the number of values
+    len: usize,
+}
+
+impl<F: Field> Default for PackedAssignment<F> {
+    fn default() -> Self {
+        Self {
+            bits: Default::default(),
+            escape_flags: Default::default(),
+            escape_ranks: Default::default(),
+            escaped: Default::default(),
+            len: 0,
+        }
+    }
+}
+
+impl<F: Field> PackedAssignment<F> {
+    /// Creates a new, empty `PackedAssignment`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a value to the end of the assignment.
+    #[inline]
+    pub fn push(&mut self, value: F) {
+        let (block, offset) = (self.len / BLOCK_SIZE, self.len % BLOCK_SIZE);
+        if offset == 0 {
+            self.bits.push(0);
+            self.escape_flags.push(0);
+            self.escape_ranks.push(self.escaped.len());
+        }
+
+        if value.is_one() {
+            self.bits[block] |= 1 << offset;
+        } else if !value.is_zero() {
+            self.escape_flags[block] |= 1 << offset;
+            self.escaped.push(value);
+        }
+
+        self.len += 1;
+    }
+
+    /// Returns the value at the specified index; panics if the index is out of bounds.
+    #[inline]
+    pub fn get(&self, idx: usize) -> F {
+        assert!(
+            idx < self.len,
+            "index {} is out of bounds for an assignment of length {}",
+            idx,
+            self.len
+        );
+
+        let (block, offset) = (idx / BLOCK_SIZE, idx % BLOCK_SIZE);
+        let mask = 1u64 << offset;
+
+        if self.escape_flags[block] & mask != 0 {
+            let rank = (self.escape_flags[block] & (mask - 1)).count_ones() as usize;
+            self.escaped[self.escape_ranks[block] + rank]
+        } else if self.bits[block] & mask != 0 {
+            F::one()
+        } else {
+            F::zero()
+        }
+    }
+
+    /// Iterates over all the values, unpacking them on the fly.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = F> + '_ {
+        (0..self.len).map(move |idx| self.get(idx))
+    }
+
+    /// Returns the number of values.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of bytes allocated on the heap for the values.
+    pub fn heap_size(&self) -> usize {
+        (self.bits.capacity() + self.escape_flags.capacity()) * size_of::<u64>()
+            + self.escape_ranks.capacity() * size_of::<usize>()
+            + self.escaped.capacity() * size_of::<F>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    #[test]
+    fn test_packed_assignment_roundtrip() {
+        let values = (0..1000u64)
+            .map(|i| match i % 7 {
+                0 | 3 => Fr::one(),
+                1 | 5 | 6 => Fr::zero(),
+                _ => Fr::from(i),
+            })
+            .collect::<Vec<_>>();
+
+        let mut packed = PackedAssignment::new();
+        assert!(packed.is_empty());
+        for value in &values {
+            packed.push(*value);
+        }
+
+        assert_eq!(packed.len(), values.len());
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(packed.get(i), *value);
+        }
+        assert_eq!(packed.iter().collect::<Vec<_>>(), values);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_packed_assignment_out_of_bounds() {
+        let mut packed = PackedAssignment::new();
+        packed.push(Fr::one());
+        packed.get(1);
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, Variable};
+use snarkvm_fields::Field;
+
+/// Constraint system for testing purposes, which checks every constraint as it is enforced
+/// instead of storing it. It needs far less memory than `TestConstraintSystem` for large
+/// circuits, but it only reports the first constraint that is not satisfied.
+pub struct TestConstraintChecker<F: Field> {
+    // the segments of the current namespace path
+    segments: Vec<String>,
+    // the path of the first constraint that was not satisfied
+    first_unsatisfied_constraint: Option<String>,
+    // the assignments of the public variables, starting with the "one" variable
+    public_variables: Vec<F>,
+    // the assignments of the private variables
+    private_variables: Vec<F>,
+    num_constraints: usize,
+}
+
+impl<F: Field> Default for TestConstraintChecker<F> {
+    fn default() -> Self {
+        Self {
+            segments: vec![],
+            first_unsatisfied_constraint: None,
+            public_variables: vec![F::one()],
+            private_variables: vec![],
+            num_constraints: 0,
+        }
+    }
+}
+
+impl<F: Field> TestConstraintChecker<F> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn eval_lc(&self, lc: &LinearCombination<F>) -> F {
+        let mut acc = F::zero();
+
+        for &(var, coeff) in lc.as_ref() {
+            let mut tmp = match var.get_unchecked() {
+                Index::Public(index) => self.public_variables[index],
+                Index::Private(index) => self.private_variables[index],
+            };
+
+            tmp.mul_assign(&coeff);
+            acc.add_assign(tmp);
+        }
+
+        acc
+    }
+
+    #[inline]
+    pub fn which_is_unsatisfied(&self) -> Option<&str> {
+        self.first_unsatisfied_constraint.as_deref()
+    }
+
+    #[inline]
+    pub fn is_satisfied(&self) -> bool {
+        self.first_unsatisfied_constraint.is_none()
+    }
+
+    #[inline]
+    pub fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+}
+
+impl<F: Field> ConstraintSystem<F> for TestConstraintChecker<F> {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, _annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let index = self.private_variables.len();
+        self.private_variables.push(f()?);
+
+        Ok(Variable::new_unchecked(Index::Private(index)))
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, _annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let index = self.public_variables.len();
+        self.public_variables.push(f()?);
+
+        Ok(Variable::new_unchecked(Index::Public(index)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.num_constraints += 1;
+
+        if self.first_unsatisfied_constraint.is_some() {
+            return;
+        }
+
+        let mut a = self.eval_lc(&a(LinearCombination::zero()));
+        let b = self.eval_lc(&b(LinearCombination::zero()));
+        let c = self.eval_lc(&c(LinearCombination::zero()));
+
+        a.mul_assign(&b);
+
+        if a != c {
+            let mut path = self.segments.clone();
+            path.push(annotation().as_ref().to_owned());
+            self.first_unsatisfied_constraint = Some(path.join("/"));
+        }
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        self.segments.push(name_fn().as_ref().to_owned());
+    }
+
+    fn pop_namespace(&mut self) {
+        assert!(self.segments.pop().is_some());
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.public_variables.len()
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.private_variables.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConstraintSystem, Fr, TestConstraintSystem};
+    use snarkvm_fields::One;
+
+    /// Enforces `x * x = y` in a namespace, for the given assignments.
+    fn enforce_square<CS: ConstraintSystem<Fr>>(cs: &mut CS, x: Fr, y: Fr) {
+        let mut cs = cs.ns(|| "square");
+        let x = cs.alloc(|| "x", || Ok(x)).unwrap();
+        let y = cs.alloc_input(|| "y", || Ok(y)).unwrap();
+        cs.enforce(|| "x * x = y", |lc| lc + x, |lc| lc + x, |lc| lc + y);
+    }
+
+    #[test]
+    fn test_checker_agrees_with_test_constraint_system() {
+        let two = Fr::one() + Fr::one();
+        let four = two + two;
+
+        for (y, expected) in [(four, None), (two, Some("square/x * x = y"))] {
+            let mut checker = TestConstraintChecker::<Fr>::new();
+            enforce_square(&mut checker, two, y);
+
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            enforce_square(&mut cs, two, y);
+
+            assert_eq!(checker.which_is_unsatisfied(), expected);
+            assert_eq!(cs.which_is_unsatisfied().as_deref(), expected);
+            assert_eq!(checker.num_constraints(), cs.num_constraints());
+            assert_eq!(checker.num_public_variables(), cs.num_public_variables());
+            assert_eq!(checker.num_private_variables(), cs.num_private_variables());
+        }
+    }
+}
@@ -477,3 +477,42 @@ impl<F: Field> ConstraintSystem<F> for TestConstraintSystem<F> {
         self.private_variables.len()
     }
 }
+
+/// Asserts that the number of constraints synthesized between `cs_before` and `cs_after`,
+/// the numbers of constraints before and after synthesizing a component, is within
+/// `tolerance_percent` percent of `expected`. On failure, the delta is printed.
+#[macro_export]
+macro_rules! assert_constraint_count {
+    ($cs_before:expr, $cs_after:expr, $expected:expr, $tolerance_percent:expr) => {{
+        let actual: usize = $cs_after - $cs_before;
+        let expected: usize = $expected;
+        let tolerance_percent: usize = $tolerance_percent;
+
+        let delta = actual as i64 - expected as i64;
+        assert!(
+            delta.abs() as usize * 100 <= expected * tolerance_percent,
+            "expected {} constraints, found {} (delta {:+}, {:+.2}%), which exceeds the tolerance of {}%",
+            expected,
+            actual,
+            delta,
+            delta as f64 * 100.0 / expected as f64,
+            tolerance_percent,
+        );
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_assert_constraint_count_within_tolerance() {
+        assert_constraint_count!(10, 110, 100, 0);
+        assert_constraint_count!(10, 111, 100, 1);
+        assert_constraint_count!(10, 109, 100, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 100 constraints, found 102 (delta +2, +2.00%)")]
+    fn test_assert_constraint_count_beyond_tolerance() {
+        assert_constraint_count!(10, 112, 100, 1);
+    }
+}
@@ -15,16 +15,14 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum UpdaterError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
     #[error("The current version {} is more recent than the release version {}", _0, _1)]
     OldReleaseVersion(String, String),
-}
 
-impl From<self_update::errors::Error> for UpdaterError {
-    fn from(error: self_update::errors::Error) -> Self {
-        UpdaterError::Crate("self_update", error.to_string())
-    }
+    #[error("self update failed: {}", _0)]
+    SelfUpdateError(#[from] self_update::errors::Error),
 }
@@ -14,6 +14,68 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+macro_rules! bigint_bitwise_impl {
+    ($name:ident, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl ::std::ops::$assign_trait<&Self> for $name {
+            #[inline]
+            fn $assign_method(&mut self, other: &Self) {
+                for (a, b) in self.0.iter_mut().zip(other.0.iter()) {
+                    ::std::ops::$assign_trait::$assign_method(a, *b);
+                }
+            }
+        }
+
+        impl ::std::ops::$assign_trait<Self> for $name {
+            #[inline]
+            fn $assign_method(&mut self, other: Self) {
+                ::std::ops::$assign_trait::$assign_method(self, &other);
+            }
+        }
+
+        impl ::std::ops::$trait<Self> for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $method(mut self, other: Self) -> Self {
+                ::std::ops::$assign_trait::$assign_method(&mut self, &other);
+                self
+            }
+        }
+
+        impl<'a> ::std::ops::$trait<&'a Self> for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $method(mut self, other: &'a Self) -> Self {
+                ::std::ops::$assign_trait::$assign_method(&mut self, other);
+                self
+            }
+        }
+
+        impl<'a> ::std::ops::$trait<$name> for &'a $name {
+            type Output = $name;
+
+            #[inline]
+            fn $method(self, other: $name) -> $name {
+                let mut result = *self;
+                ::std::ops::$assign_trait::$assign_method(&mut result, &other);
+                result
+            }
+        }
+
+        impl<'a, 'b> ::std::ops::$trait<&'b $name> for &'a $name {
+            type Output = $name;
+
+            #[inline]
+            fn $method(self, other: &'b $name) -> $name {
+                let mut result = *self;
+                ::std::ops::$assign_trait::$assign_method(&mut result, other);
+                result
+            }
+        }
+    };
+}
+
 macro_rules! bigint_impl {
     ($name:ident, $num_limbs:expr) => {
         #[derive(Copy, Clone, PartialEq, Eq, Default, Hash)]
@@ -183,11 +245,7 @@ macro_rules! bigint_impl {
 
             #[inline]
             fn to_bits_be(&self) -> Vec<bool> {
-                let mut res = Vec::with_capacity(256);
-                for b in BitIteratorBE::new(self.0) {
-                    res.push(b);
-                }
-                res
+                BitIteratorBE::new(self.0).collect()
             }
 
             #[inline]
@@ -314,5 +372,30 @@ macro_rules! bigint_impl {
                 repr
             }
         }
+
+        bigint_bitwise_impl!($name, BitAnd, bitand, BitAndAssign, bitand_assign);
+        bigint_bitwise_impl!($name, BitOr, bitor, BitOrAssign, bitor_assign);
+        bigint_bitwise_impl!($name, BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+        impl ::std::ops::Not for $name {
+            type Output = Self;
+
+            #[inline]
+            fn not(mut self) -> Self {
+                for limb in self.0.iter_mut() {
+                    *limb = !*limb;
+                }
+                self
+            }
+        }
+
+        impl<'a> ::std::ops::Not for &'a $name {
+            type Output = $name;
+
+            #[inline]
+            fn not(self) -> $name {
+                !*self
+            }
+        }
     };
 }
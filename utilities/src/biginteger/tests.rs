@@ -77,6 +77,66 @@ fn biginteger_bytes_test<B: BigInteger>() {
     assert_eq!(x, y);
 }
 
+macro_rules! biginteger_bitwise_op_test {
+    ($a:ident, $b:ident, $op:tt, $op_assign:tt, $expected:expr) => {{
+        let expected = $expected;
+
+        assert_eq!(expected, $a $op $b);
+        assert_eq!(expected, $a $op &$b);
+        assert_eq!(expected, &$a $op $b);
+        assert_eq!(expected, &$a $op &$b);
+
+        let mut result = $a;
+        result $op_assign $b;
+        assert_eq!(expected, result);
+
+        let mut result = $a;
+        result $op_assign &$b;
+        assert_eq!(expected, result);
+    }};
+}
+
+macro_rules! biginteger_bitwise_test {
+    ($test_name:ident, $type:ident) => {
+        #[test]
+        fn $test_name() {
+            let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+            let a: $type = UniformRand::rand(&mut rng);
+            let b: $type = UniformRand::rand(&mut rng);
+
+            let limbwise = |f: fn(u64, u64) -> u64| {
+                let mut result = $type::default();
+                for ((r, x), y) in result.0.iter_mut().zip(a.0.iter()).zip(b.0.iter()) {
+                    *r = f(*x, *y);
+                }
+                result
+            };
+
+            biginteger_bitwise_op_test!(a, b, &, &=, limbwise(|x, y| x & y));
+            biginteger_bitwise_op_test!(a, b, |, |=, limbwise(|x, y| x | y));
+            biginteger_bitwise_op_test!(a, b, ^, ^=, limbwise(|x, y| x ^ y));
+
+            let not = limbwise(|x, _| !x);
+            assert_eq!(not, !a);
+            assert_eq!(not, !&a);
+            assert_eq!(a, !not);
+
+            // a ^ a = 0
+            assert!((a ^ a).is_zero());
+            // a & !a = 0
+            assert!((a & !a).is_zero());
+        }
+    };
+}
+
+biginteger_bitwise_test!(test_biginteger64_bitwise, BigInteger64);
+biginteger_bitwise_test!(test_biginteger128_bitwise, BigInteger128);
+biginteger_bitwise_test!(test_biginteger256_bitwise, BigInteger256);
+biginteger_bitwise_test!(test_biginteger320_bitwise, BigInteger320);
+biginteger_bitwise_test!(test_biginteger384_bitwise, BigInteger384);
+biginteger_bitwise_test!(test_biginteger768_bitwise, BigInteger768);
+biginteger_bitwise_test!(test_biginteger832_bitwise, BigInteger832);
+
 fn test_biginteger<B: BigInteger>(zero: B) {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
     let a: B = UniformRand::rand(&mut rng);
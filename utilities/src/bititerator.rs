@@ -21,7 +21,6 @@ pub struct BitIteratorBE<Slice> {
     n: usize,
 }
 
-#[allow(clippy::len_without_is_empty)]
 impl<Slice: AsRef<[u64]>> BitIteratorBE<Slice> {
     pub fn new(s: Slice) -> Self {
         let n = s.as_ref().len() * 64;
@@ -30,12 +29,14 @@ impl<Slice: AsRef<[u64]>> BitIteratorBE<Slice> {
 
     /// Construct an iterator that automatically skips any leading zeros.
     /// That is, it skips all zeros before the most-significant one.
-    pub fn new_without_leading_zeros(s: Slice) -> impl Iterator<Item = bool> {
-        Self::new(s).skip_while(|b| !b)
-    }
-
-    pub fn len(&self) -> usize {
-        self.n
+    pub fn new_without_leading_zeros(s: Slice) -> Self {
+        let n = s
+            .as_ref()
+            .iter()
+            .rposition(|limb| *limb != 0)
+            .map(|i| i * 64 + (64 - s.as_ref()[i].leading_zeros()) as usize)
+            .unwrap_or(0);
+        BitIteratorBE { s, n }
     }
 }
 
@@ -53,8 +54,14 @@ impl<Slice: AsRef<[u64]>> Iterator for BitIteratorBE<Slice> {
             Some(self.s.as_ref()[part] & (1 << bit) > 0)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.n, Some(self.n))
+    }
 }
 
+impl<Slice: AsRef<[u64]>> ExactSizeIterator for BitIteratorBE<Slice> {}
+
 /// Iterates over a slice of `u64` in *little-endian* order.
 #[derive(Debug)]
 pub struct BitIteratorLE<Slice: AsRef<[u64]>> {
@@ -72,7 +79,7 @@ impl<Slice: AsRef<[u64]>> BitIteratorLE<Slice> {
 
     /// Construct an iterator that automatically skips any trailing zeros.
     /// That is, it skips all zeros after the most-significant one.
-    pub fn new_without_trailing_zeros(s: Slice) -> impl Iterator<Item = bool> {
+    pub fn new_without_trailing_zeros(s: Slice) -> Self {
         let mut first_trailing_zero = 0;
         for (i, limb) in s.as_ref().iter().enumerate().rev() {
             first_trailing_zero = i * 64 + (64 - limb.leading_zeros()) as usize;
@@ -100,8 +107,15 @@ impl<Slice: AsRef<[u64]>> Iterator for BitIteratorLE<Slice> {
             Some(self.s.as_ref()[part] & (1 << bit) > 0)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.max_len - self.n;
+        (len, Some(len))
+    }
 }
 
+impl<Slice: AsRef<[u64]>> ExactSizeIterator for BitIteratorLE<Slice> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,4 +165,23 @@ mod tests {
         assert_eq!(Some(true), five.next());
         assert_eq!(None, five.next());
     }
+
+    #[test]
+    fn test_bititerator_len() {
+        let limbs = [5u64, 1 << 10, 0];
+
+        assert_eq!(BitIteratorBE::new(&limbs).len(), 192);
+        assert_eq!(BitIteratorBE::new_without_leading_zeros(&limbs).len(), 75);
+        assert_eq!(BitIteratorBE::new_without_leading_zeros(&[0u64; 2]).len(), 0);
+        assert_eq!(BitIteratorLE::new(&limbs).len(), 192);
+        assert_eq!(BitIteratorLE::new_without_trailing_zeros(&limbs).len(), 75);
+        assert_eq!(BitIteratorLE::new_without_trailing_zeros(&[0u64; 2]).len(), 0);
+
+        assert!(BitIteratorBE::new_without_leading_zeros(&limbs).eq(BitIteratorBE::new(&limbs).skip_while(|b| !b)));
+
+        let mut bits = BitIteratorBE::new_without_leading_zeros(&limbs);
+        bits.next();
+        assert_eq!(bits.len(), 74);
+        assert_eq!(bits.count(), 74);
+    }
 }
@@ -303,6 +303,40 @@ impl<'a, T: 'a + ToBytes> ToBytes for &'a T {
     }
 }
 
+/// A writer that discards its input, and counts the number of bytes written to it.
+#[derive(Default)]
+struct ByteCounter(usize);
+
+impl Write for ByteCounter {
+    #[cfg(feature = "std")]
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> IoResult<()> {
+        self.0 += buf.len();
+        Ok(())
+    }
+}
+
+/// Returns the number of bytes written by `ToBytes::write`, without allocating for them.
+#[inline]
+pub fn to_bytes_len<T: ToBytes>(value: &T) -> IoResult<usize> {
+    let mut counter = ByteCounter::default();
+    value.write(&mut counter)?;
+    Ok(counter.0)
+}
+
 pub fn bytes_to_bits(bytes: &[u8]) -> impl Iterator<Item = bool> + '_ {
     bytes
         .iter()
@@ -332,7 +366,7 @@ pub fn bits_to_bytes(bits: &[bool]) -> Vec<u8> {
 
 #[cfg(test)]
 mod test {
-    use super::{bits_to_bytes, bytes_to_bits, ToBytes};
+    use super::{bits_to_bytes, bytes_to_bits, to_bytes_len, ToBytes};
     use crate::Vec;
 
     use rand::{Rng, SeedableRng};
@@ -363,6 +397,17 @@ mod test {
         assert_eq!(bytes, actual_bytes);
     }
 
+    #[test]
+    fn test_to_bytes_len() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        for _ in 0..ITERATIONS {
+            let array: Vec<u64> = (0..rng.gen_range(0..64)).map(|_| rng.gen()).collect();
+            assert_eq!(to_bytes_len(&array).unwrap(), to_bytes![array].unwrap().len());
+        }
+        assert_eq!(to_bytes_len(&[0u8; 32]).unwrap(), 32);
+    }
+
     #[test]
     fn test_bits_to_bytes() {
         let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{fmt, sync::Once};
+
+/// Whether `snarkvm-utilities` was compiled with the `std` feature.
+pub const STD_ENABLED: bool = cfg!(feature = "std");
+
+/// The features that one crate was compiled with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CrateCapabilities {
+    pub name: &'static str,
+    pub features: Vec<(&'static str, bool)>,
+}
+
+impl CrateCapabilities {
+    pub fn new(name: &'static str, features: &[(&'static str, bool)]) -> Self {
+        Self {
+            name,
+            features: features.to_vec(),
+        }
+    }
+}
+
+/// The features that the crates of the workspace were compiled with.
+///
+/// Each crate exposes a `register_capabilities` function, which registers the crate and
+/// the crates it depends on. A crate that is registered more than once is listed once.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    crates: Vec<CrateCapabilities>,
+}
+
+impl Capabilities {
+    /// Registers the given crate, unless a crate with the same name is already registered.
+    pub fn register(&mut self, capabilities: CrateCapabilities) {
+        if !self
+            .crates
+            .iter()
+            .any(|registered| registered.name == capabilities.name)
+        {
+            self.crates.push(capabilities);
+        }
+    }
+
+    /// Returns the registered crates, in the order they were registered.
+    pub fn crates(&self) -> &[CrateCapabilities] {
+        &self.crates
+    }
+
+    /// Returns whether the given crate was compiled with the given feature, or `None`
+    /// if the crate is not registered or has no such feature.
+    pub fn is_enabled(&self, name: &str, feature: &str) -> Option<bool> {
+        self.crates
+            .iter()
+            .find(|registered| registered.name == name)?
+            .features
+            .iter()
+            .find(|(registered, _)| *registered == feature)
+            .map(|(_, enabled)| *enabled)
+    }
+
+    /// Returns the capabilities as a JSON object, which maps every crate name to an object
+    /// that maps each of its features to whether it is enabled.
+    pub fn to_json(&self) -> String {
+        let crates = self
+            .crates
+            .iter()
+            .map(|registered| {
+                let features = registered
+                    .features
+                    .iter()
+                    .map(|(feature, enabled)| format!("\"{}\":{}", feature, enabled))
+                    .collect::<Vec<_>>();
+                format!("\"{}\":{{{}}}", registered.name, features.join(","))
+            })
+            .collect::<Vec<_>>();
+        format!("{{{}}}", crates.join(","))
+    }
+}
+
+impl fmt::Display for Capabilities {
+    /// Lists the enabled features of every crate on one line, such as
+    /// `snarkvm-curves: parallel; snarkvm-fields: -`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, registered) in self.crates.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            let enabled = registered
+                .features
+                .iter()
+                .filter(|(_, enabled)| *enabled)
+                .map(|(feature, _)| *feature)
+                .collect::<Vec<_>>();
+            if enabled.is_empty() {
+                write!(f, "{}: -", registered.name)?;
+            } else {
+                write!(f, "{}: {}", registered.name, enabled.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Registers `snarkvm-utilities`.
+pub fn register_capabilities(capabilities: &mut Capabilities) {
+    capabilities.register(CrateCapabilities::new("snarkvm-utilities", &[
+        ("std", STD_ENABLED),
+        ("derive", cfg!(feature = "derive")),
+    ]));
+}
+
+/// In debug builds, prints a warning the first time a large workload runs in a crate that
+/// was compiled without the `parallel` feature.
+pub fn warn_if_sequential(workload: &str, parallel_enabled: bool) {
+    static WARNING: Once = Once::new();
+
+    if cfg!(debug_assertions) && !parallel_enabled {
+        WARNING.call_once(|| {
+            eprintln!(
+                "warning: {} is running without the `parallel` feature, and will be much slower",
+                workload
+            )
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn capabilities() -> Capabilities {
+        let mut capabilities = Capabilities::default();
+        capabilities.register(CrateCapabilities::new("a", &[("parallel", true), ("wasm", false)]));
+        capabilities.register(CrateCapabilities::new("b", &[("std", false)]));
+        capabilities.register(CrateCapabilities::new("a", &[]));
+        capabilities
+    }
+
+    #[test]
+    fn test_capabilities_register() {
+        let capabilities = capabilities();
+
+        assert_eq!(capabilities.crates().len(), 2);
+        assert_eq!(capabilities.is_enabled("a", "parallel"), Some(true));
+        assert_eq!(capabilities.is_enabled("a", "wasm"), Some(false));
+        assert_eq!(capabilities.is_enabled("a", "std"), None);
+        assert_eq!(capabilities.is_enabled("c", "std"), None);
+    }
+
+    #[test]
+    fn test_capabilities_display_and_json() {
+        let capabilities = capabilities();
+
+        assert_eq!(capabilities.to_string(), "a: parallel; b: -");
+        assert_eq!(
+            capabilities.to_json(),
+            r#"{"a":{"parallel":true,"wasm":false},"b":{"std":false}}"#
+        );
+    }
+
+    #[test]
+    fn test_utilities_capabilities() {
+        let mut capabilities = Capabilities::default();
+        register_capabilities(&mut capabilities);
+
+        assert_eq!(
+            capabilities.is_enabled("snarkvm-utilities", "std"),
+            Some(cfg!(feature = "std"))
+        );
+        assert_eq!(
+            capabilities.is_enabled("snarkvm-utilities", "derive"),
+            Some(cfg!(feature = "derive"))
+        );
+    }
+}
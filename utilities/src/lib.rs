@@ -41,6 +41,9 @@ pub use biginteger::*;
 pub mod bititerator;
 pub use bititerator::*;
 
+pub mod capabilities;
+pub use capabilities::{Capabilities, CrateCapabilities};
+
 #[macro_use]
 pub mod bytes;
 pub use bytes::*;
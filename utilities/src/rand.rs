@@ -14,9 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::Vec;
+
+use core::num::NonZeroU32;
 use rand::{
     distributions::{Distribution, Standard},
+    CryptoRng,
+    Error,
     Rng,
+    RngCore,
+    SeedableRng,
 };
 
 pub trait UniformRand: Sized {
@@ -43,3 +50,293 @@ pub fn test_rng() -> rand::rngs::StdRng {
     ];
     rand::rngs::StdRng::from_seed(seed)
 }
+
+/// The randomness consumed through a `RecordingRng`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RngRecording {
+    /// The seed of the inner RNG, which reproduces its whole output stream.
+    Seed(Vec<u8>),
+    /// The raw output stream of the inner RNG. If `truncated` is set, the stream exceeded the
+    /// recording limit and only its prefix was kept.
+    Stream { bytes: Vec<u8>, truncated: bool },
+}
+
+impl RngRecording {
+    /// Returns an RNG that replays the recorded output stream, or `None` if only the seed
+    /// was recorded, in which case the inner RNG should be reseeded instead.
+    pub fn replay(&self) -> Option<ReplayRng> {
+        match self {
+            Self::Seed(_) => None,
+            Self::Stream { bytes, .. } => Some(ReplayRng::new(bytes.clone())),
+        }
+    }
+}
+
+/// An RNG that forwards to an inner RNG while recording the randomness it produces, so that a
+/// failing execution can be reproduced from logs.
+///
+/// For seedable inner RNGs, only the seed is kept. Otherwise, at most `max_bytes` of the output
+/// stream are kept, and the recording is marked as truncated past that point.
+pub struct RecordingRng<R: RngCore> {
+    rng: R,
+    seed: Option<Vec<u8>>,
+    bytes: Vec<u8>,
+    max_bytes: usize,
+    truncated: bool,
+}
+
+impl<R: RngCore> RecordingRng<R> {
+    /// Wraps the given RNG, recording up to `max_bytes` of its output stream.
+    pub fn new(rng: R, max_bytes: usize) -> Self {
+        Self {
+            rng,
+            seed: None,
+            bytes: Vec::new(),
+            max_bytes,
+            truncated: false,
+        }
+    }
+
+    /// Returns the randomness recorded so far.
+    pub fn recording(&self) -> RngRecording {
+        match &self.seed {
+            Some(seed) => RngRecording::Seed(seed.clone()),
+            None => RngRecording::Stream {
+                bytes: self.bytes.clone(),
+                truncated: self.truncated,
+            },
+        }
+    }
+
+    /// Returns the recorded randomness, consuming the RNG.
+    pub fn into_recording(self) -> RngRecording {
+        match self.seed {
+            Some(seed) => RngRecording::Seed(seed),
+            None => RngRecording::Stream {
+                bytes: self.bytes,
+                truncated: self.truncated,
+            },
+        }
+    }
+
+    fn record(&mut self, bytes: &[u8]) {
+        if self.seed.is_some() || self.truncated {
+            return;
+        }
+
+        if self.bytes.len() + bytes.len() > self.max_bytes {
+            self.truncated = true;
+        } else {
+            self.bytes.extend_from_slice(bytes);
+        }
+    }
+}
+
+impl<R: RngCore + SeedableRng> RecordingRng<R> {
+    /// Seeds a new inner RNG and records only its seed.
+    pub fn from_seed(mut seed: R::Seed) -> Self {
+        Self {
+            seed: Some(seed.as_mut().to_vec()),
+            rng: R::from_seed(seed),
+            bytes: Vec::new(),
+            max_bytes: 0,
+            truncated: false,
+        }
+    }
+}
+
+impl<R: RngCore> RngCore for RecordingRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        let value = self.rng.next_u32();
+        self.record(&value.to_le_bytes());
+        value
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let value = self.rng.next_u64();
+        self.record(&value.to_le_bytes());
+        value
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+        self.record(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.rng.try_fill_bytes(dest)?;
+        self.record(dest);
+        Ok(())
+    }
+}
+
+impl<R: RngCore + CryptoRng> CryptoRng for RecordingRng<R> {}
+
+/// An RNG that replays an output stream recorded by a `RecordingRng`, and fails once more
+/// bytes are requested than were recorded.
+///
+/// Should be used only to reproduce failures, not for any real world usage.
+#[derive(Clone, Debug)]
+pub struct ReplayRng {
+    bytes: Vec<u8>,
+    position: usize,
+}
+
+impl ReplayRng {
+    /// The error code returned by `try_fill_bytes` once the recorded stream is exhausted.
+    pub const EXHAUSTED: u32 = Error::CUSTOM_START;
+
+    /// Replays the given recorded output stream.
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Returns the number of recorded bytes that have not been replayed yet.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+}
+
+impl RngCore for ReplayRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Panics if fewer than `dest.len()` recorded bytes remain.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(error) = self.try_fill_bytes(dest) {
+            panic!(
+                "{}: requested {} bytes, but only {} remain",
+                error,
+                dest.len(),
+                self.remaining()
+            );
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        if dest.len() > self.remaining() {
+            // The code is non-zero, as `Error::CUSTOM_START` is.
+            return Err(Error::from(NonZeroU32::new(Self::EXHAUSTED).unwrap()));
+        }
+
+        dest.copy_from_slice(&self.bytes[self.position..self.position + dest.len()]);
+        self.position += dest.len();
+        Ok(())
+    }
+}
+
+impl CryptoRng for ReplayRng {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand_xorshift::XorShiftRng;
+
+    /// Samples values until one of them falls below the threshold, which simulates a failure
+    /// occurring after an unpredictable amount of randomness has been consumed.
+    fn sample_until_failure<R: Rng>(rng: &mut R) -> Result<Vec<u64>, u64> {
+        let mut samples = Vec::new();
+        for _ in 0..1000 {
+            let sample: u64 = rng.gen();
+            if sample < u64::MAX / 16 {
+                return Err(sample);
+            }
+            let mut bytes = [0u8; 13];
+            rng.fill_bytes(&mut bytes);
+            samples.push(sample ^ u64::from(bytes[12]) ^ u64::from(rng.gen::<u32>()));
+        }
+        Ok(samples)
+    }
+
+    #[test]
+    fn test_recording_rng_is_transparent() {
+        let mut expected = XorShiftRng::seed_from_u64(1234567);
+        let mut rng = RecordingRng::new(XorShiftRng::seed_from_u64(1234567), 1 << 16);
+
+        for _ in 0..100 {
+            assert_eq!(rng.gen::<u64>(), expected.gen::<u64>());
+            assert_eq!(rng.gen::<u32>(), expected.gen::<u32>());
+            assert_eq!(rng.gen::<[u8; 7]>(), expected.gen::<[u8; 7]>());
+        }
+    }
+
+    #[test]
+    fn test_replay_reproduces_failure() {
+        let mut rng = RecordingRng::new(XorShiftRng::seed_from_u64(1234567), 1 << 20);
+        let mut attempts = Vec::new();
+        let failure = loop {
+            match sample_until_failure(&mut rng) {
+                Ok(samples) => attempts.push(samples),
+                Err(failure) => break failure,
+            }
+        };
+
+        let recording = rng.into_recording();
+        assert!(matches!(recording, RngRecording::Stream { truncated: false, .. }));
+
+        let mut replay = recording.replay().unwrap();
+        for samples in attempts {
+            assert_eq!(sample_until_failure(&mut replay), Ok(samples));
+        }
+        assert_eq!(sample_until_failure(&mut replay), Err(failure));
+        assert_eq!(replay.remaining(), 0);
+
+        // Requesting more randomness than was recorded fails.
+        let error = replay.try_fill_bytes(&mut [0u8; 1]).unwrap_err();
+        assert_eq!(error.code(), NonZeroU32::new(ReplayRng::EXHAUSTED));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_replay_panics_when_exhausted() {
+        let mut replay = ReplayRng::new(vec![0u8; 7]);
+        replay.next_u64();
+    }
+
+    #[test]
+    fn test_recording_is_bounded() {
+        let max_bytes = 100;
+        let mut rng = RecordingRng::new(XorShiftRng::seed_from_u64(1234567), max_bytes);
+
+        let mut expected = Vec::new();
+        while expected.len() + 8 <= max_bytes {
+            expected.extend_from_slice(&rng.next_u64().to_le_bytes());
+        }
+        for _ in 0..10_000 {
+            rng.next_u64();
+        }
+
+        match rng.into_recording() {
+            RngRecording::Stream { bytes, truncated } => {
+                assert!(truncated);
+                assert!(bytes.capacity() <= 2 * max_bytes);
+                assert_eq!(bytes, expected);
+            }
+            RngRecording::Seed(_) => panic!("expected a recorded stream"),
+        }
+    }
+
+    #[test]
+    fn test_recording_seed() {
+        let seed = [7u8; 16];
+        let mut rng = RecordingRng::<XorShiftRng>::from_seed(seed);
+        let samples: Vec<u64> = (0..10_000).map(|_| rng.gen()).collect();
+
+        let recording = rng.into_recording();
+        assert_eq!(recording, RngRecording::Seed(seed.to_vec()));
+        assert!(recording.replay().is_none());
+
+        let mut reseeded = XorShiftRng::from_seed(seed);
+        assert!(samples.iter().all(|sample| *sample == reseeded.gen::<u64>()));
+    }
+}
@@ -34,6 +34,16 @@ pub fn variable_length_integer(value: u64) -> Vec<u8> {
     }
 }
 
+/// Returns the number of bytes in the variable length integer of the given value.
+pub fn variable_length_integer_size(value: u64) -> usize {
+    match value {
+        0..=252 => 1,
+        253..=65535 => 3,
+        65536..=4_294_967_295 => 5,
+        _ => 9,
+    }
+}
+
 /// Decode the value of a variable length integer.
 /// https://en.bitcoin.it/wiki/Protocol_documentation#Variable_length_integer
 pub fn read_variable_length_integer<R: Read>(mut reader: R) -> IoResult<usize> {
@@ -103,6 +113,14 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_variable_length_integer_size() {
+        LENGTH_VALUES.iter().for_each(|(size, _expected_output)| {
+            let variable_length_int = variable_length_integer(*size);
+            assert_eq!(variable_length_integer_size(*size), variable_length_int.len());
+        });
+    }
+
     #[test]
     fn test_read_variable_length_integer() {
         LENGTH_VALUES.iter().for_each(|(expected_size, _expected_output)| {